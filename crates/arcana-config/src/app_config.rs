@@ -4,10 +4,11 @@ use crate::{CommunicationProtocol, DeploymentLayer, DeploymentMode};
 use arcana_core::Interface;
 use serde::{Deserialize, Serialize};
 use shaku::Component;
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Root application configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AppConfig {
     /// Application name and metadata.
     #[serde(default)]
@@ -44,22 +45,14 @@ pub struct AppConfig {
     /// Observability configuration.
     #[serde(default)]
     pub observability: ObservabilityConfig,
-}
 
-impl Default for AppConfig {
-    fn default() -> Self {
-        Self {
-            app: AppMetadata::default(),
-            server: ServerConfig::default(),
-            deployment: DeploymentConfig::default(),
-            database: DatabaseConfig::default(),
-            redis: RedisConfig::default(),
-            security: SecurityConfig::default(),
-            plugins: PluginConfig::default(),
-            ssr: SsrConfig::default(),
-            observability: ObservabilityConfig::default(),
-        }
-    }
+    /// Outgoing mail configuration.
+    #[serde(default)]
+    pub mail: MailConfig,
+
+    /// Domain event delivery configuration.
+    #[serde(default)]
+    pub events: EventsConfig,
 }
 
 /// Application metadata.
@@ -241,6 +234,137 @@ pub trait SecurityConfigInterface: Interface + Send + Sync {
     fn tls_key_path(&self) -> Option<&str>;
     /// Returns the password hashing cost.
     fn password_hash_cost(&self) -> u32;
+    /// Returns the JWT signing algorithm.
+    fn jwt_algorithm(&self) -> JwtAlgorithm;
+    /// Returns the path to the PEM private key used to sign JWTs.
+    fn jwt_private_key_path(&self) -> Option<&str>;
+    /// Returns the path to the PEM public key used to verify JWTs.
+    fn jwt_public_key_path(&self) -> Option<&str>;
+    /// Returns the JWT key ring used for key rotation.
+    fn jwt_keys(&self) -> &[JwtKeyConfig];
+    /// Returns the `kid` of the key ring entry that signs new tokens.
+    fn jwt_signing_kid(&self) -> Option<&str>;
+    /// Returns the email verification token expiration in seconds.
+    fn email_verification_expiration_secs(&self) -> u64;
+    /// Returns the password reset token expiration in seconds.
+    fn password_reset_expiration_secs(&self) -> u64;
+    /// Returns the issuer shown in authenticator apps for TOTP enrolments.
+    fn mfa_issuer(&self) -> &str;
+    /// Returns the MFA challenge token expiration in seconds.
+    fn mfa_challenge_expiration_secs(&self) -> u64;
+    /// Returns the brute-force protection settings for logins.
+    fn lockout(&self) -> &LockoutConfig;
+}
+
+/// JWT signing algorithm.
+///
+/// `HS256` signs with the shared `jwt_secret`; the asymmetric algorithms sign
+/// with a PEM private key and publish the matching public key as a JWKS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum JwtAlgorithm {
+    /// HMAC with SHA-256 (shared secret).
+    #[default]
+    #[serde(rename = "HS256", alias = "hs256")]
+    HS256,
+    /// RSASSA-PKCS1-v1_5 with SHA-256.
+    #[serde(rename = "RS256", alias = "rs256")]
+    RS256,
+    /// ECDSA using P-256 and SHA-256.
+    #[serde(rename = "ES256", alias = "es256")]
+    ES256,
+    /// EdDSA using Ed25519.
+    #[serde(rename = "EdDSA", alias = "eddsa")]
+    EdDSA,
+}
+
+impl JwtAlgorithm {
+    /// Returns true if tokens are signed with a key pair rather than a shared secret.
+    #[must_use]
+    pub const fn is_asymmetric(&self) -> bool {
+        !matches!(self, Self::HS256)
+    }
+}
+
+/// A JWT key ring entry.
+///
+/// HS256 entries carry a `secret`; asymmetric entries carry PEM key paths,
+/// where the private key may be omitted for keys that only verify tokens.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JwtKeyConfig {
+    /// Key identifier, written to the `kid` header of tokens signed with this key.
+    pub kid: String,
+    /// Signing algorithm.
+    #[serde(default)]
+    pub algorithm: JwtAlgorithm,
+    /// Shared secret (HS256).
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// Path to the PEM private key (asymmetric algorithms).
+    #[serde(default)]
+    pub private_key_path: Option<String>,
+    /// Path to the PEM public key (asymmetric algorithms).
+    #[serde(default)]
+    pub public_key_path: Option<String>,
+}
+
+/// Brute-force protection for logins.
+///
+/// Failed attempts are counted per account and per client IP within a
+/// sliding window. Each failure delays the response a little longer, and an
+/// account that reaches `max_failed_attempts` is locked for
+/// `lockout_duration_secs`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct LockoutConfig {
+    /// Failed logins after which an account is locked (0 disables locking).
+    pub max_failed_attempts: u32,
+    /// Failed logins from one IP after which it is refused (0 disables the limit).
+    pub max_failed_attempts_per_ip: u32,
+    /// How long failed attempts are counted, in seconds.
+    pub failure_window_secs: u64,
+    /// How long an automatic lock lasts, in seconds.
+    pub lockout_duration_secs: u64,
+    /// Delay after the first failure in milliseconds, doubled on every further failure.
+    pub delay_base_ms: u64,
+    /// Upper bound for the failure delay in milliseconds.
+    pub delay_max_ms: u64,
+}
+
+impl Default for LockoutConfig {
+    fn default() -> Self {
+        Self {
+            max_failed_attempts: 5,
+            max_failed_attempts_per_ip: 50,
+            failure_window_secs: 900,    // 15 minutes
+            lockout_duration_secs: 900,  // 15 minutes
+            delay_base_ms: 250,
+            delay_max_ms: 4000,
+        }
+    }
+}
+
+impl LockoutConfig {
+    /// Returns the failure counting window as a Duration.
+    #[must_use]
+    pub const fn failure_window(&self) -> Duration {
+        Duration::from_secs(self.failure_window_secs)
+    }
+
+    /// Returns the automatic lock duration as a Duration.
+    #[must_use]
+    pub const fn lockout_duration(&self) -> Duration {
+        Duration::from_secs(self.lockout_duration_secs)
+    }
+
+    /// Returns how long to delay the response to the `failures`-th failed attempt.
+    #[must_use]
+    pub fn failure_delay(&self, failures: u32) -> Duration {
+        if failures == 0 {
+            return Duration::ZERO;
+        }
+        let factor = 1u64 << (failures - 1).min(16);
+        Duration::from_millis(self.delay_base_ms.saturating_mul(factor).min(self.delay_max_ms))
+    }
 }
 
 /// Security configuration.
@@ -274,6 +398,73 @@ pub struct SecurityConfig {
     /// Password hashing cost (Argon2).
     #[shaku(default)]
     pub password_hash_cost: u32,
+    /// JWT signing algorithm.
+    #[serde(default)]
+    #[shaku(default)]
+    pub jwt_algorithm: JwtAlgorithm,
+    /// Path to the PEM private key used to sign JWTs (asymmetric algorithms).
+    ///
+    /// May be omitted on instances that only verify tokens.
+    #[serde(default)]
+    #[shaku(default)]
+    pub jwt_private_key_path: Option<String>,
+    /// Path to the PEM public key used to verify JWTs (asymmetric algorithms).
+    #[serde(default)]
+    #[shaku(default)]
+    pub jwt_public_key_path: Option<String>,
+    /// JWT key ring for rotation. When set, it replaces the single key above:
+    /// every entry is accepted for validation until it is removed.
+    #[serde(default)]
+    #[shaku(default)]
+    pub jwt_keys: Vec<JwtKeyConfig>,
+    /// `kid` of the key ring entry that signs new tokens (defaults to the first entry).
+    #[serde(default)]
+    #[shaku(default)]
+    pub jwt_signing_kid: Option<String>,
+    /// Email verification token expiration in seconds.
+    #[serde(default = "default_email_verification_expiration_secs")]
+    #[shaku(default)]
+    pub email_verification_expiration_secs: u64,
+    /// Password reset token expiration in seconds.
+    #[serde(default = "default_password_reset_expiration_secs")]
+    #[shaku(default)]
+    pub password_reset_expiration_secs: u64,
+    /// Issuer shown in authenticator apps for TOTP enrolments.
+    #[serde(default = "default_mfa_issuer")]
+    #[shaku(default)]
+    pub mfa_issuer: String,
+    /// MFA challenge token expiration in seconds.
+    #[serde(default = "default_mfa_challenge_expiration_secs")]
+    #[shaku(default)]
+    pub mfa_challenge_expiration_secs: u64,
+    /// How long resolved user permissions are cached, in seconds.
+    #[serde(default = "default_permission_cache_ttl_secs")]
+    #[shaku(default)]
+    pub permission_cache_ttl_secs: u64,
+    /// Brute-force protection for logins.
+    #[serde(default)]
+    #[shaku(default)]
+    pub lockout: LockoutConfig,
+}
+
+fn default_email_verification_expiration_secs() -> u64 {
+    86400 // 24 hours
+}
+
+fn default_password_reset_expiration_secs() -> u64 {
+    1800 // 30 minutes
+}
+
+fn default_mfa_issuer() -> String {
+    "Arcana Cloud".to_string()
+}
+
+fn default_mfa_challenge_expiration_secs() -> u64 {
+    300 // 5 minutes
+}
+
+fn default_permission_cache_ttl_secs() -> u64 {
+    60 // 1 minute
 }
 
 impl Default for SecurityConfig {
@@ -288,6 +479,17 @@ impl Default for SecurityConfig {
             tls_cert_path: None,
             tls_key_path: None,
             password_hash_cost: 12,
+            jwt_algorithm: JwtAlgorithm::HS256,
+            jwt_private_key_path: None,
+            jwt_public_key_path: None,
+            jwt_keys: Vec::new(),
+            jwt_signing_kid: None,
+            email_verification_expiration_secs: default_email_verification_expiration_secs(),
+            password_reset_expiration_secs: default_password_reset_expiration_secs(),
+            mfa_issuer: default_mfa_issuer(),
+            mfa_challenge_expiration_secs: default_mfa_challenge_expiration_secs(),
+            permission_cache_ttl_secs: default_permission_cache_ttl_secs(),
+            lockout: LockoutConfig::default(),
         }
     }
 }
@@ -304,6 +506,24 @@ impl SecurityConfig {
     pub const fn refresh_token_expiration(&self) -> Duration {
         Duration::from_secs(self.jwt_refresh_expiration_secs)
     }
+
+    /// Returns the email verification token expiration as a Duration.
+    #[must_use]
+    pub const fn email_verification_expiration(&self) -> Duration {
+        Duration::from_secs(self.email_verification_expiration_secs)
+    }
+
+    /// Returns the password reset token expiration as a Duration.
+    #[must_use]
+    pub const fn password_reset_expiration(&self) -> Duration {
+        Duration::from_secs(self.password_reset_expiration_secs)
+    }
+
+    /// Returns how long resolved user permissions are cached as a Duration.
+    #[must_use]
+    pub const fn permission_cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.permission_cache_ttl_secs)
+    }
 }
 
 impl SecurityConfigInterface for SecurityConfig {
@@ -342,6 +562,46 @@ impl SecurityConfigInterface for SecurityConfig {
     fn password_hash_cost(&self) -> u32 {
         self.password_hash_cost
     }
+
+    fn jwt_algorithm(&self) -> JwtAlgorithm {
+        self.jwt_algorithm
+    }
+
+    fn jwt_private_key_path(&self) -> Option<&str> {
+        self.jwt_private_key_path.as_deref()
+    }
+
+    fn jwt_public_key_path(&self) -> Option<&str> {
+        self.jwt_public_key_path.as_deref()
+    }
+
+    fn jwt_keys(&self) -> &[JwtKeyConfig] {
+        &self.jwt_keys
+    }
+
+    fn jwt_signing_kid(&self) -> Option<&str> {
+        self.jwt_signing_kid.as_deref()
+    }
+
+    fn email_verification_expiration_secs(&self) -> u64 {
+        self.email_verification_expiration_secs
+    }
+
+    fn password_reset_expiration_secs(&self) -> u64 {
+        self.password_reset_expiration_secs
+    }
+
+    fn mfa_issuer(&self) -> &str {
+        &self.mfa_issuer
+    }
+
+    fn mfa_challenge_expiration_secs(&self) -> u64 {
+        self.mfa_challenge_expiration_secs
+    }
+
+    fn lockout(&self) -> &LockoutConfig {
+        &self.lockout
+    }
 }
 
 /// Plugin configuration.
@@ -352,13 +612,32 @@ pub struct PluginConfig {
     /// Plugin directory path.
     pub directory: String,
     /// Enable plugin hot reload.
+    ///
+    /// The plugin directory is polled rather than watched through OS file
+    /// notifications, which behave differently per platform and on network
+    /// or container mounts. A plugin counts as changed when its `.wasm` file
+    /// gets another size or modification time, or its manifest or signature
+    /// appears, disappears or gets another content.
     pub hot_reload: bool,
+    /// How often the plugin directory is checked for changes, in seconds.
+    #[serde(default = "default_hot_reload_interval_secs")]
+    pub hot_reload_interval_secs: u64,
     /// Plugin execution timeout in seconds.
     pub execution_timeout_secs: u64,
     /// Maximum plugin memory in bytes.
     pub max_memory_bytes: u64,
     /// Enable plugin signature verification.
     pub verify_signatures: bool,
+    /// Hex-encoded Ed25519 public keys of trusted plugin publishers.
+    #[serde(default)]
+    pub trusted_keys: Vec<String>,
+    /// Directory of `.pub` files holding further trusted publisher keys.
+    #[serde(default)]
+    pub trusted_keys_dir: Option<String>,
+    /// Capabilities approved per plugin key; plugins requesting anything
+    /// else are not installed.
+    #[serde(default)]
+    pub grants: HashMap<String, PluginGrant>,
 }
 
 impl Default for PluginConfig {
@@ -367,13 +646,21 @@ impl Default for PluginConfig {
             enabled: true,
             directory: "./plugins".to_string(),
             hot_reload: true,
+            hot_reload_interval_secs: default_hot_reload_interval_secs(),
             execution_timeout_secs: 30,
             max_memory_bytes: 64 * 1024 * 1024, // 64MB
             verify_signatures: true,
+            trusted_keys: Vec::new(),
+            trusted_keys_dir: None,
+            grants: HashMap::new(),
         }
     }
 }
 
+const fn default_hot_reload_interval_secs() -> u64 {
+    2
+}
+
 impl PluginConfig {
     /// Returns the execution timeout as a Duration.
     #[must_use]
@@ -382,6 +669,24 @@ impl PluginConfig {
     }
 }
 
+/// Host capabilities an administrator approved for one plugin.
+///
+/// Matched against the capabilities the plugin's manifest requests.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct PluginGrant {
+    /// Allow network access.
+    pub network: bool,
+    /// Host directories the plugin may mount read-only.
+    pub read_dirs: Vec<String>,
+    /// Host directories the plugin may mount read-write.
+    pub write_dirs: Vec<String>,
+    /// Host environment variables the plugin may read.
+    pub env: Vec<String>,
+    /// Host functions the plugin may call.
+    pub host_functions: Vec<String>,
+}
+
 /// SSR (Server-Side Rendering) configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SsrConfig {
@@ -486,6 +791,164 @@ impl ObservabilityConfig {
     }
 }
 
+/// How outgoing mail is delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum MailTransport {
+    /// Log messages instead of sending them (development and tests).
+    #[default]
+    Log,
+    /// Send messages through the SMTP server in `mail.smtp`.
+    Smtp,
+}
+
+/// Encryption of the connection to the SMTP server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpTls {
+    /// Plain connection (local relays only).
+    None,
+    /// Plain connection upgraded with `STARTTLS`, which the server must support.
+    #[default]
+    Starttls,
+    /// TLS from the start of the connection (SMTPS).
+    Tls,
+}
+
+/// SMTP server configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct SmtpConfig {
+    /// Server host name.
+    pub host: String,
+    /// Server port.
+    pub port: u16,
+    /// Connection encryption.
+    pub tls: SmtpTls,
+    /// User name to authenticate with (no authentication when unset).
+    pub username: Option<String>,
+    /// Password to authenticate with.
+    pub password: Option<String>,
+    /// Timeout of each SMTP command in seconds.
+    pub timeout_secs: u64,
+}
+
+impl Default for SmtpConfig {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 587,
+            tls: SmtpTls::default(),
+            username: None,
+            password: None,
+            timeout_secs: 30,
+        }
+    }
+}
+
+/// Outgoing mail configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MailConfig {
+    /// How messages are delivered.
+    #[serde(default)]
+    pub transport: MailTransport,
+    /// SMTP server used by the `smtp` transport.
+    #[serde(default)]
+    pub smtp: SmtpConfig,
+    /// Sender address of outgoing mail.
+    #[serde(default = "default_mail_from_address")]
+    pub from_address: String,
+    /// Page that completes email verification; the token is appended as `?token=`.
+    #[serde(default = "default_verification_url")]
+    pub verification_url: String,
+    /// Page that lets the user choose a new password; the token is appended as `?token=`.
+    #[serde(default = "default_password_reset_url")]
+    pub password_reset_url: String,
+    /// Directory the `log` transport writes each message to (development and tests).
+    #[serde(default)]
+    pub outbox_dir: Option<String>,
+}
+
+fn default_mail_from_address() -> String {
+    "no-reply@arcana.local".to_string()
+}
+
+fn default_verification_url() -> String {
+    "http://localhost:8080/verify-email".to_string()
+}
+
+fn default_password_reset_url() -> String {
+    "http://localhost:8080/reset-password".to_string()
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        Self {
+            transport: MailTransport::default(),
+            smtp: SmtpConfig::default(),
+            from_address: default_mail_from_address(),
+            verification_url: default_verification_url(),
+            password_reset_url: default_password_reset_url(),
+            outbox_dir: None,
+        }
+    }
+}
+
+impl MailConfig {
+    /// Returns the link that verifies an email address with `token`.
+    #[must_use]
+    pub fn verification_link(&self, token: &str) -> String {
+        link_with_token(&self.verification_url, token)
+    }
+
+    /// Returns the link that resets a password with `token`.
+    #[must_use]
+    pub fn password_reset_link(&self, token: &str) -> String {
+        link_with_token(&self.password_reset_url, token)
+    }
+}
+
+fn link_with_token(url: &str, token: &str) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{}{}token={}", url, separator, token)
+}
+
+/// Domain event delivery configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventsConfig {
+    /// Cron schedule (with seconds) on which the outbox relay runs.
+    #[serde(default = "default_outbox_relay_schedule")]
+    pub outbox_relay_schedule: String,
+    /// Number of outbox events loaded per batch.
+    #[serde(default = "default_outbox_batch_size")]
+    pub outbox_batch_size: usize,
+    /// Failed delivery attempts after which an outbox event is left for inspection.
+    #[serde(default = "default_outbox_max_attempts")]
+    pub outbox_max_attempts: u32,
+}
+
+fn default_outbox_relay_schedule() -> String {
+    "*/10 * * * * *".to_string()
+}
+
+fn default_outbox_batch_size() -> usize {
+    100
+}
+
+fn default_outbox_max_attempts() -> u32 {
+    5
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self {
+            outbox_relay_schedule: default_outbox_relay_schedule(),
+            outbox_batch_size: default_outbox_batch_size(),
+            outbox_max_attempts: default_outbox_max_attempts(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -639,6 +1102,46 @@ mod tests {
         assert_eq!(config.refresh_token_expiration().as_secs(), 604800);
     }
 
+    #[test]
+    fn test_security_config_email_verification_expiration() {
+        let config = SecurityConfig::default();
+        assert_eq!(config.email_verification_expiration().as_secs(), 86400);
+        assert_eq!(config.email_verification_expiration_secs(), 86400);
+    }
+
+    #[test]
+    fn test_security_config_password_reset_expiration() {
+        let config = SecurityConfig::default();
+        assert_eq!(config.password_reset_expiration().as_secs(), 1800);
+        assert_eq!(config.password_reset_expiration_secs(), 1800);
+    }
+
+    #[test]
+    fn test_security_config_mfa_defaults() {
+        let config = SecurityConfig::default();
+        assert_eq!(config.mfa_issuer(), "Arcana Cloud");
+        assert_eq!(config.mfa_challenge_expiration_secs(), 300);
+    }
+
+    #[test]
+    fn test_lockout_defaults() {
+        let config = SecurityConfig::default();
+        assert_eq!(config.lockout().max_failed_attempts, 5);
+        assert_eq!(config.lockout().lockout_duration().as_secs(), 900);
+        assert_eq!(config.lockout().failure_window().as_secs(), 900);
+    }
+
+    #[test]
+    fn test_lockout_failure_delay_doubles_up_to_max() {
+        let lockout = LockoutConfig::default();
+        assert_eq!(lockout.failure_delay(0), Duration::ZERO);
+        assert_eq!(lockout.failure_delay(1), Duration::from_millis(250));
+        assert_eq!(lockout.failure_delay(2), Duration::from_millis(500));
+        assert_eq!(lockout.failure_delay(3), Duration::from_millis(1000));
+        assert_eq!(lockout.failure_delay(10), Duration::from_millis(4000));
+        assert_eq!(lockout.failure_delay(u32::MAX), Duration::from_millis(4000));
+    }
+
     #[test]
     fn test_security_config_interface_methods() {
         let config = SecurityConfig::default();
@@ -666,6 +1169,69 @@ mod tests {
         assert_eq!(config.tls_key_path(), Some("/etc/certs/server.key"));
     }
 
+    #[test]
+    fn test_security_config_jwt_algorithm_deserialization() {
+        let config: SecurityConfig = toml::from_str(
+            r#"
+            jwt_secret = "secret"
+            jwt_access_expiration_secs = 900
+            jwt_refresh_expiration_secs = 86400
+            jwt_issuer = "arcana-cloud"
+            jwt_audience = "arcana-api"
+            grpc_tls_enabled = false
+            password_hash_cost = 12
+            jwt_algorithm = "EdDSA"
+            jwt_public_key_path = "/etc/arcana/jwt.pub.pem"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.jwt_algorithm(), JwtAlgorithm::EdDSA);
+        assert!(config.jwt_algorithm().is_asymmetric());
+        assert_eq!(config.jwt_public_key_path(), Some("/etc/arcana/jwt.pub.pem"));
+        assert!(config.jwt_private_key_path().is_none());
+    }
+
+    #[test]
+    fn test_security_config_jwt_key_ring_deserialization() {
+        let config: SecurityConfig = toml::from_str(
+            r#"
+            jwt_secret = "secret"
+            jwt_access_expiration_secs = 900
+            jwt_refresh_expiration_secs = 86400
+            jwt_issuer = "arcana-cloud"
+            jwt_audience = "arcana-api"
+            grpc_tls_enabled = false
+            password_hash_cost = 12
+            jwt_signing_kid = "2026-10"
+
+            [[jwt_keys]]
+            kid = "2026-10"
+            algorithm = "ES256"
+            private_key_path = "/etc/arcana/2026-10.pem"
+            public_key_path = "/etc/arcana/2026-10.pub.pem"
+
+            [[jwt_keys]]
+            kid = "legacy"
+            secret = "previous-shared-secret"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.jwt_signing_kid(), Some("2026-10"));
+        assert_eq!(config.jwt_keys().len(), 2);
+        assert_eq!(config.jwt_keys()[0].algorithm, JwtAlgorithm::ES256);
+        assert_eq!(config.jwt_keys()[1].algorithm, JwtAlgorithm::HS256);
+        assert_eq!(config.jwt_keys()[1].secret.as_deref(), Some("previous-shared-secret"));
+    }
+
+    #[test]
+    fn test_security_config_jwt_algorithm_defaults_to_hs256() {
+        let config = SecurityConfig::default();
+        assert_eq!(config.jwt_algorithm(), JwtAlgorithm::HS256);
+        assert!(!config.jwt_algorithm().is_asymmetric());
+    }
+
     // =========================================================================
     // PluginConfig tests
     // =========================================================================
@@ -677,6 +1243,7 @@ mod tests {
         assert_eq!(config.directory, "./plugins");
         assert!(config.hot_reload);
         assert_eq!(config.max_memory_bytes, 64 * 1024 * 1024);
+        assert!(config.grants.is_empty());
     }
 
     #[test]
@@ -786,6 +1353,78 @@ mod tests {
         assert!(config.enabled);
     }
 
+    // =========================================================================
+    // MailConfig tests
+    // =========================================================================
+
+    #[test]
+    fn test_mail_config_default() {
+        let config = MailConfig::default();
+        assert_eq!(config.from_address, "no-reply@arcana.local");
+        assert_eq!(config.verification_url, "http://localhost:8080/verify-email");
+        assert_eq!(config.password_reset_url, "http://localhost:8080/reset-password");
+        assert!(config.outbox_dir.is_none());
+        assert_eq!(config.transport, MailTransport::Log);
+    }
+
+    #[test]
+    fn test_mail_config_smtp_deserialization() {
+        let config: MailConfig = toml::from_str(
+            r#"
+            transport = "smtp"
+
+            [smtp]
+            host = "smtp.example.com"
+            port = 465
+            tls = "tls"
+            username = "mailer"
+            password = "secret"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.transport, MailTransport::Smtp);
+        assert_eq!(config.smtp.host, "smtp.example.com");
+        assert_eq!(config.smtp.port, 465);
+        assert_eq!(config.smtp.tls, SmtpTls::Tls);
+        assert_eq!(config.smtp.username.as_deref(), Some("mailer"));
+        assert_eq!(config.smtp.timeout_secs, 30);
+    }
+
+    #[test]
+    fn test_mail_config_partial_deserialization() {
+        let config: MailConfig = toml::from_str(r#"outbox_dir = "/tmp/arcana-mail""#).unwrap();
+        assert_eq!(config.outbox_dir.as_deref(), Some("/tmp/arcana-mail"));
+        assert_eq!(config.from_address, "no-reply@arcana.local");
+    }
+
+    #[test]
+    fn test_mail_config_verification_link() {
+        let config = MailConfig::default();
+        assert_eq!(
+            config.verification_link("abc.def"),
+            "http://localhost:8080/verify-email?token=abc.def"
+        );
+
+        let config = MailConfig {
+            verification_url: "https://app.example.com/account?step=verify".to_string(),
+            ..MailConfig::default()
+        };
+        assert_eq!(
+            config.verification_link("abc"),
+            "https://app.example.com/account?step=verify&token=abc"
+        );
+    }
+
+    #[test]
+    fn test_mail_config_password_reset_link() {
+        let config = MailConfig::default();
+        assert_eq!(
+            config.password_reset_link("0123abcd"),
+            "http://localhost:8080/reset-password?token=0123abcd"
+        );
+    }
+
     // =========================================================================
     // DeploymentConfig tests
     // =========================================================================
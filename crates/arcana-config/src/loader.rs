@@ -1,7 +1,7 @@
 //! Configuration loader with layered sources.
 
 use crate::validation::{format_validation_errors, ConfigValidator};
-use crate::AppConfig;
+use crate::{AppConfig, MailTransport};
 use arcana_core::ArcanaError;
 use config::{Config, ConfigError, Environment, File};
 use std::path::Path;
@@ -107,11 +107,11 @@ impl ConfigLoader {
 
         let config = builder
             .build()
-            .map_err(|e| config_error_to_arcana_error(e))?;
+            .map_err(config_error_to_arcana_error)?;
 
         let app_config: AppConfig = config
             .try_deserialize()
-            .map_err(|e| config_error_to_arcana_error(e))?;
+            .map_err(config_error_to_arcana_error)?;
 
         // Validate critical configuration
         Self::validate_config(&app_config)?;
@@ -128,7 +128,10 @@ impl ConfigLoader {
 
         // Production-specific warnings (non-fatal)
         if config.app.environment == "production" {
-            if config.security.jwt_secret.starts_with("change-me") {
+            if config.security.jwt_keys.is_empty()
+                && !config.security.jwt_algorithm.is_asymmetric()
+                && config.security.jwt_secret.starts_with("change-me")
+            {
                 warn!("Using default JWT secret in production! This is a security risk.");
             }
             if !config.security.grpc_tls_enabled {
@@ -137,6 +140,9 @@ impl ConfigLoader {
             if config.observability.sampling_ratio < 0.01 {
                 warn!("Very low trace sampling ratio in production. Consider increasing for better observability.");
             }
+            if config.mail.transport == MailTransport::Log {
+                warn!("Outgoing mail is only logged in production. Set mail.transport = \"smtp\" to deliver it.");
+            }
         }
 
         Ok(())
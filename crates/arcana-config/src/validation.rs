@@ -26,6 +26,12 @@ pub enum ConfigValidationError {
     MissingTlsCert,
     /// TLS key path required when TLS is enabled.
     MissingTlsKey,
+    /// Public key path required for asymmetric JWT algorithms.
+    MissingJwtPublicKey,
+    /// JWT key ring entry is invalid.
+    InvalidJwtKey { kid: String, reason: String },
+    /// JWT signing kid does not name a key ring entry.
+    UnknownJwtSigningKid { kid: String },
     /// Sampling ratio must be between 0.0 and 1.0.
     InvalidSamplingRatio { value: f64 },
     /// Timeout value must be positive.
@@ -38,6 +44,8 @@ pub enum ConfigValidationError {
     MissingServiceUrl,
     /// Repository URL required for layered deployment.
     MissingRepositoryUrl,
+    /// SMTP settings are incomplete for the `smtp` mail transport.
+    InvalidSmtpConfig { reason: String },
 }
 
 impl fmt::Display for ConfigValidationError {
@@ -83,6 +91,15 @@ impl fmt::Display for ConfigValidationError {
             Self::MissingTlsKey => {
                 write!(f, "TLS key path required when gRPC TLS is enabled")
             }
+            Self::MissingJwtPublicKey => {
+                write!(f, "JWT public key path required for asymmetric JWT algorithms")
+            }
+            Self::InvalidJwtKey { kid, reason } => {
+                write!(f, "Invalid JWT key '{}': {}", kid, reason)
+            }
+            Self::UnknownJwtSigningKid { kid } => {
+                write!(f, "JWT signing kid '{}' is not in the key ring", kid)
+            }
             Self::InvalidSamplingRatio { value } => {
                 write!(
                     f,
@@ -113,6 +130,9 @@ impl fmt::Display for ConfigValidationError {
             Self::MissingRepositoryUrl => {
                 write!(f, "Repository URL required for service layer in layered deployment")
             }
+            Self::InvalidSmtpConfig { reason } => {
+                write!(f, "Invalid SMTP configuration: {}", reason)
+            }
         }
     }
 }
@@ -185,14 +205,62 @@ impl ConfigValidator {
         Self::validate_deployment(&config.deployment, &mut result);
         Self::validate_ssr(&config.ssr, &mut result);
         Self::validate_plugins(&config.plugins, &mut result);
+        Self::validate_mail(&config.mail, &mut result);
 
         result.into_result()
     }
 
+    /// Validates the JWT key ring, which replaces the single-key settings when present.
+    fn validate_jwt_key_ring(config: &crate::SecurityConfig, result: &mut ValidationResult) {
+        let mut seen = std::collections::HashSet::new();
+
+        for key in &config.jwt_keys {
+            let reason = if key.kid.is_empty() {
+                Some("kid must not be empty".to_string())
+            } else if !seen.insert(key.kid.as_str()) {
+                Some("duplicate kid".to_string())
+            } else if key.algorithm.is_asymmetric() {
+                key.public_key_path
+                    .is_none()
+                    .then(|| "public_key_path is required".to_string())
+            } else {
+                match key.secret.as_deref() {
+                    None => Some("secret is required for HS256".to_string()),
+                    Some(secret) if secret.len() < Self::MIN_JWT_SECRET_LENGTH => Some(format!(
+                        "secret too short: {} characters (minimum {})",
+                        secret.len(),
+                        Self::MIN_JWT_SECRET_LENGTH
+                    )),
+                    Some(_) => None,
+                }
+            };
+
+            if let Some(reason) = reason {
+                result.add_error(ConfigValidationError::InvalidJwtKey {
+                    kid: key.kid.clone(),
+                    reason,
+                });
+            }
+        }
+
+        if let Some(kid) = &config.jwt_signing_kid {
+            if !config.jwt_keys.iter().any(|key| &key.kid == kid) {
+                result.add_error(ConfigValidationError::UnknownJwtSigningKid { kid: kid.clone() });
+            }
+        }
+    }
+
     /// Validates security configuration.
     fn validate_security(config: &crate::SecurityConfig, result: &mut ValidationResult) {
-        // JWT secret length
-        if config.jwt_secret.len() < Self::MIN_JWT_SECRET_LENGTH {
+        // JWT signing keys: the secret only matters for HS256, key pairs need
+        // at least the public key (the private key is optional on verify-only layers)
+        if !config.jwt_keys.is_empty() {
+            Self::validate_jwt_key_ring(config, result);
+        } else if config.jwt_algorithm.is_asymmetric() {
+            if config.jwt_public_key_path.is_none() {
+                result.add_error(ConfigValidationError::MissingJwtPublicKey);
+            }
+        } else if config.jwt_secret.len() < Self::MIN_JWT_SECRET_LENGTH {
             result.add_error(ConfigValidationError::JwtSecretTooShort {
                 actual: config.jwt_secret.len(),
                 minimum: Self::MIN_JWT_SECRET_LENGTH,
@@ -436,6 +504,36 @@ impl ConfigValidator {
             });
         }
     }
+
+    /// Validates mail configuration.
+    fn validate_mail(config: &crate::MailConfig, result: &mut ValidationResult) {
+        if config.transport != crate::MailTransport::Smtp {
+            return;
+        }
+
+        if config.smtp.host.trim().is_empty() {
+            result.add_error(ConfigValidationError::InvalidSmtpConfig {
+                reason: "mail.smtp.host is required".to_string(),
+            });
+        }
+        if config.smtp.port == 0 {
+            result.add_error(ConfigValidationError::InvalidPort {
+                name: "mail.smtp.port".to_string(),
+                value: 0,
+            });
+        }
+        if config.smtp.username.is_some() != config.smtp.password.is_some() {
+            result.add_error(ConfigValidationError::InvalidSmtpConfig {
+                reason: "mail.smtp.username and mail.smtp.password must be set together".to_string(),
+            });
+        }
+        if config.smtp.timeout_secs == 0 {
+            result.add_error(ConfigValidationError::NonPositiveTimeout {
+                name: "mail.smtp.timeout_secs".to_string(),
+                value: 0,
+            });
+        }
+    }
 }
 
 /// Formats validation errors for display.
@@ -566,6 +664,87 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn test_asymmetric_jwt_without_public_key() {
+        let mut config = valid_config();
+        config.security.jwt_algorithm = crate::JwtAlgorithm::RS256;
+        config.security.jwt_private_key_path = Some("/path/to/jwt.pem".to_string());
+
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        let errors = result.unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigValidationError::MissingJwtPublicKey
+        )));
+    }
+
+    #[test]
+    fn test_asymmetric_jwt_ignores_secret_length() {
+        let mut config = valid_config();
+        config.security.jwt_secret = "short".to_string();
+        config.security.jwt_algorithm = crate::JwtAlgorithm::ES256;
+        config.security.jwt_public_key_path = Some("/path/to/jwt.pub.pem".to_string());
+
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+
+    fn jwt_key(kid: &str, secret: &str) -> crate::JwtKeyConfig {
+        crate::JwtKeyConfig {
+            kid: kid.to_string(),
+            algorithm: crate::JwtAlgorithm::HS256,
+            secret: Some(secret.to_string()),
+            private_key_path: None,
+            public_key_path: None,
+        }
+    }
+
+    #[test]
+    fn test_jwt_key_ring_valid() {
+        let mut config = valid_config();
+        config.security.jwt_secret = "short".to_string();
+        config.security.jwt_keys = vec![jwt_key("new", &"n".repeat(32)), jwt_key("old", &"o".repeat(32))];
+        config.security.jwt_signing_kid = Some("new".to_string());
+
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+
+    #[test]
+    fn test_jwt_key_ring_rejects_invalid_entries() {
+        let mut config = valid_config();
+        let mut asymmetric = jwt_key("rsa", "unused");
+        asymmetric.algorithm = crate::JwtAlgorithm::RS256;
+        config.security.jwt_keys = vec![
+            jwt_key("a", &"a".repeat(32)),
+            jwt_key("a", &"b".repeat(32)),
+            jwt_key("short", "short"),
+            asymmetric,
+        ];
+
+        let errors = ConfigValidator::validate(&config).unwrap_err();
+        let invalid: Vec<&str> = errors
+            .iter()
+            .filter_map(|e| match e {
+                ConfigValidationError::InvalidJwtKey { kid, .. } => Some(kid.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(invalid, vec!["a", "short", "rsa"]);
+    }
+
+    #[test]
+    fn test_jwt_key_ring_unknown_signing_kid() {
+        let mut config = valid_config();
+        config.security.jwt_keys = vec![jwt_key("current", &"c".repeat(32))];
+        config.security.jwt_signing_kid = Some("missing".to_string());
+
+        let errors = ConfigValidator::validate(&config).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigValidationError::UnknownJwtSigningKid { kid } if kid == "missing"
+        )));
+    }
+
     #[test]
     fn test_tls_enabled_without_cert() {
         let mut config = valid_config();
@@ -640,6 +819,20 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn test_smtp_transport_requires_complete_credentials() {
+        let mut config = valid_config();
+        config.mail.transport = crate::MailTransport::Smtp;
+        assert!(ConfigValidator::validate(&config).is_ok());
+
+        config.mail.smtp.username = Some("mailer".to_string());
+        let errors = ConfigValidator::validate(&config).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigValidationError::InvalidSmtpConfig { reason } if reason.contains("password")
+        )));
+    }
+
     #[test]
     fn test_multiple_errors() {
         let mut config = valid_config();
@@ -1,9 +1,10 @@
 //! Audit log entity.
 
-use crate::{AuditLogId, Entity, UserId};
+use crate::{ArcanaError, AuditLogId, Entity, UserId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use serde_json::Value as JsonValue;
+use serde_json::{json, Value as JsonValue};
+use sha2::{Digest, Sha256};
 
 /// Audit log entry for tracking system activities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,6 +41,18 @@ pub struct AuditLog {
 
     /// Timestamp of the action.
     pub timestamp: DateTime<Utc>,
+
+    /// Position in the hash chain, assigned when the entry is stored.
+    #[serde(default)]
+    pub sequence: Option<u64>,
+
+    /// Hash of the previous entry in the chain.
+    #[serde(default)]
+    pub prev_hash: Option<String>,
+
+    /// Hash over the contents of this entry and `prev_hash`.
+    #[serde(default)]
+    pub hash: Option<String>,
 }
 
 impl AuditLog {
@@ -63,6 +76,9 @@ impl AuditLog {
             success: true,
             error_message: None,
             timestamp: Utc::now(),
+            sequence: None,
+            prev_hash: None,
+            hash: None,
         }
     }
 
@@ -112,6 +128,38 @@ impl AuditLog {
         self.user_agent = Some(ua.into());
         self
     }
+
+    /// Appends the entry to the hash chain after the entry with `prev_hash`.
+    ///
+    /// `prev_hash` is `None` for the first entry of the chain.
+    pub fn chain(&mut self, sequence: u64, prev_hash: Option<String>) {
+        self.sequence = Some(sequence);
+        self.prev_hash = prev_hash;
+        self.hash = Some(self.compute_hash());
+    }
+
+    /// Computes the chain hash of the entry from its current contents.
+    ///
+    /// The timestamp is hashed with second precision, as stored.
+    #[must_use]
+    pub fn compute_hash(&self) -> String {
+        let contents = json!([
+            self.sequence,
+            self.id.to_string(),
+            self.timestamp.timestamp(),
+            self.user_id.map(|id| id.to_string()),
+            self.action.to_string(),
+            self.resource_type,
+            self.resource_id,
+            self.details,
+            self.ip_address,
+            self.user_agent,
+            self.success,
+            self.error_message,
+            self.prev_hash,
+        ]);
+        hex::encode(Sha256::digest(contents.to_string().as_bytes()))
+    }
 }
 
 impl Entity<AuditLogId> for AuditLog {
@@ -122,6 +170,7 @@ impl Entity<AuditLogId> for AuditLog {
 
 /// Audit action types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AuditAction {
     // Authentication actions
@@ -216,6 +265,260 @@ impl std::fmt::Display for AuditAction {
     }
 }
 
+impl std::str::FromStr for AuditAction {
+    type Err = ArcanaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "LOGIN" => Ok(Self::Login),
+            "LOGOUT" => Ok(Self::Logout),
+            "TOKEN_REFRESH" => Ok(Self::TokenRefresh),
+            "PASSWORD_CHANGE" => Ok(Self::PasswordChange),
+            "PASSWORD_RESET_REQUEST" => Ok(Self::PasswordResetRequest),
+            "PASSWORD_RESET_COMPLETE" => Ok(Self::PasswordResetComplete),
+            "USER_CREATE" => Ok(Self::UserCreate),
+            "USER_UPDATE" => Ok(Self::UserUpdate),
+            "USER_DELETE" => Ok(Self::UserDelete),
+            "USER_ROLE_CHANGE" => Ok(Self::UserRoleChange),
+            "USER_STATUS_CHANGE" => Ok(Self::UserStatusChange),
+            "PLUGIN_INSTALL" => Ok(Self::PluginInstall),
+            "PLUGIN_UNINSTALL" => Ok(Self::PluginUninstall),
+            "PLUGIN_ENABLE" => Ok(Self::PluginEnable),
+            "PLUGIN_DISABLE" => Ok(Self::PluginDisable),
+            "PLUGIN_CONFIG_CHANGE" => Ok(Self::PluginConfigChange),
+            "CONFIG_CHANGE" => Ok(Self::ConfigChange),
+            "SYSTEM_START" => Ok(Self::SystemStart),
+            "SYSTEM_SHUTDOWN" => Ok(Self::SystemShutdown),
+            "CREATE" => Ok(Self::Create),
+            "READ" => Ok(Self::Read),
+            "UPDATE" => Ok(Self::Update),
+            "DELETE" => Ok(Self::Delete),
+            "CUSTOM" => Ok(Self::Custom),
+            _ => Err(ArcanaError::Validation(format!("Unknown audit action: {}", s))),
+        }
+    }
+}
+
+/// Criteria for searching the audit trail.
+///
+/// Every criterion that is set must match; an empty filter matches all entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditLogFilter {
+    /// Only entries of this user.
+    pub user_id: Option<UserId>,
+    /// Only entries of this action.
+    pub action: Option<AuditAction>,
+    /// Only entries about this resource type.
+    pub resource_type: Option<String>,
+    /// Only successful (`true`) or failed (`false`) operations.
+    pub success: Option<bool>,
+    /// Only entries at or after this time.
+    pub from: Option<DateTime<Utc>>,
+    /// Only entries before this time.
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl AuditLogFilter {
+    /// Returns true if the entry satisfies every criterion.
+    #[must_use]
+    pub fn matches(&self, entry: &AuditLog) -> bool {
+        self.user_id.is_none_or(|user_id| entry.user_id == Some(user_id))
+            && self.action.is_none_or(|action| entry.action == action)
+            && self
+                .resource_type
+                .as_deref()
+                .is_none_or(|resource_type| entry.resource_type == resource_type)
+            && self.success.is_none_or(|success| entry.success == success)
+            && self.from.is_none_or(|from| entry.timestamp >= from)
+            && self.to.is_none_or(|to| entry.timestamp < to)
+    }
+}
+
+/// Position in the audit trail for cursor pagination.
+///
+/// The trail is read newest first, ordered by timestamp and then by ID; a
+/// cursor points at the last entry of a page and the next page starts right
+/// after it. Its string form is opaque to clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditLogCursor {
+    /// Timestamp of the last entry read.
+    pub timestamp: DateTime<Utc>,
+    /// ID of the last entry read.
+    pub id: AuditLogId,
+}
+
+impl AuditLogCursor {
+    /// Creates a cursor pointing at the given entry.
+    #[must_use]
+    pub fn after(entry: &AuditLog) -> Self {
+        Self {
+            timestamp: entry.timestamp,
+            id: entry.id,
+        }
+    }
+
+    /// Returns true if the entry comes after the cursor in trail order.
+    #[must_use]
+    pub fn precedes(&self, entry: &AuditLog) -> bool {
+        (entry.timestamp, entry.id.into_inner()) < (self.timestamp, self.id.into_inner())
+    }
+}
+
+impl std::fmt::Display for AuditLogCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", self.timestamp.timestamp_micros(), self.id.into_inner().simple())
+    }
+}
+
+impl std::str::FromStr for AuditLogCursor {
+    type Err = ArcanaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ArcanaError::Validation(format!("Invalid audit log cursor: {}", s));
+
+        let (micros, id) = s.split_once('_').ok_or_else(invalid)?;
+        let timestamp = micros
+            .parse()
+            .ok()
+            .and_then(DateTime::from_timestamp_micros)
+            .ok_or_else(invalid)?;
+        let id = AuditLogId::parse(id).map_err(|_| invalid())?;
+
+        Ok(Self { timestamp, id })
+    }
+}
+
+/// Why the audit hash chain is broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AuditChainBreakReason {
+    /// The entry was stored without a place in the chain.
+    NotChained,
+    /// Entries are missing at this point.
+    MissingEntries,
+    /// The entry does not link to the hash of the entry before it.
+    PrevHashMismatch,
+    /// The contents of the entry no longer match its hash.
+    HashMismatch,
+}
+
+impl std::fmt::Display for AuditChainBreakReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotChained => write!(f, "not_chained"),
+            Self::MissingEntries => write!(f, "missing_entries"),
+            Self::PrevHashMismatch => write!(f, "prev_hash_mismatch"),
+            Self::HashMismatch => write!(f, "hash_mismatch"),
+        }
+    }
+}
+
+impl std::str::FromStr for AuditChainBreakReason {
+    type Err = ArcanaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "not_chained" => Ok(Self::NotChained),
+            "missing_entries" => Ok(Self::MissingEntries),
+            "prev_hash_mismatch" => Ok(Self::PrevHashMismatch),
+            "hash_mismatch" => Ok(Self::HashMismatch),
+            _ => Err(ArcanaError::Validation(format!("Unknown audit chain break reason: {}", s))),
+        }
+    }
+}
+
+/// The first broken link found in the audit hash chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AuditChainBreak {
+    /// Sequence number at which the chain breaks.
+    pub sequence: u64,
+    /// Entry found at that point, absent if the chain ends early.
+    pub id: Option<AuditLogId>,
+    /// What is wrong with the link.
+    pub reason: AuditChainBreakReason,
+}
+
+/// Walks the audit hash chain and finds the first broken link.
+///
+/// Feed consecutive entries in sequence order to [`check`](Self::check),
+/// then call [`finish`](Self::finish) with the last sequence number that
+/// should exist, so that entries removed from the end are noticed too.
+#[derive(Debug, Clone)]
+pub struct AuditChainVerifier {
+    next_sequence: u64,
+    /// Hash the next entry must link to; `None` until it is known.
+    prev_hash: Option<Option<String>>,
+    checked: u64,
+}
+
+impl AuditChainVerifier {
+    /// Starts the walk at the entry with the given sequence number.
+    ///
+    /// Unless the walk starts at the beginning of the chain, the link of
+    /// the first entry to its predecessor cannot be checked.
+    #[must_use]
+    pub fn new(start: u64) -> Self {
+        Self {
+            next_sequence: start,
+            prev_hash: (start <= 1).then_some(None),
+            checked: 0,
+        }
+    }
+
+    /// Checks the next entry of the walk.
+    pub fn check(&mut self, entry: &AuditLog) -> Result<(), AuditChainBreak> {
+        let broken = |sequence, reason| AuditChainBreak {
+            sequence,
+            id: Some(entry.id),
+            reason,
+        };
+
+        let Some(sequence) = entry.sequence else {
+            return Err(broken(self.next_sequence, AuditChainBreakReason::NotChained));
+        };
+        if sequence != self.next_sequence {
+            return Err(broken(self.next_sequence, AuditChainBreakReason::MissingEntries));
+        }
+        if self.prev_hash.as_ref().is_some_and(|prev_hash| *prev_hash != entry.prev_hash) {
+            return Err(broken(sequence, AuditChainBreakReason::PrevHashMismatch));
+        }
+        if entry.hash.as_deref() != Some(entry.compute_hash().as_str()) {
+            return Err(broken(sequence, AuditChainBreakReason::HashMismatch));
+        }
+
+        self.next_sequence += 1;
+        self.prev_hash = Some(entry.hash.clone());
+        self.checked += 1;
+        Ok(())
+    }
+
+    /// Checks that no entries up to `last` are missing after the checked ones.
+    pub fn finish(&self, last: u64) -> Result<(), AuditChainBreak> {
+        if self.next_sequence <= last {
+            return Err(AuditChainBreak {
+                sequence: self.next_sequence,
+                id: None,
+                reason: AuditChainBreakReason::MissingEntries,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the sequence number the next entry must have.
+    #[must_use]
+    pub fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
+    /// Returns the number of entries checked so far.
+    #[must_use]
+    pub fn checked(&self) -> u64 {
+        self.checked
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +611,20 @@ mod tests {
         assert_eq!(AuditAction::PasswordResetComplete.to_string(), "PASSWORD_RESET_COMPLETE");
     }
 
+    #[test]
+    fn test_audit_action_from_str_roundtrip() {
+        for action in [
+            AuditAction::Login,
+            AuditAction::PasswordResetComplete,
+            AuditAction::UserRoleChange,
+            AuditAction::PluginConfigChange,
+            AuditAction::Custom,
+        ] {
+            assert_eq!(action.to_string().parse::<AuditAction>().unwrap(), action);
+        }
+        assert!("login".parse::<AuditAction>().is_err());
+    }
+
     #[test]
     fn test_entity_id() {
         let log = AuditLog::success(None, AuditAction::Create, "resource", None);
@@ -393,4 +710,132 @@ mod tests {
         assert_eq!(log.ip_address, Some("172.16.0.1".into()));
         assert_eq!(log.user_agent, Some("curl/7.x".into()));
     }
+
+    #[test]
+    fn test_filter_matches() {
+        let user_id = UserId::new();
+        let entry = AuditLog::failure(Some(user_id), AuditAction::Login, "user", None, "bad password");
+
+        assert!(AuditLogFilter::default().matches(&entry));
+        assert!(AuditLogFilter {
+            user_id: Some(user_id),
+            action: Some(AuditAction::Login),
+            resource_type: Some("user".to_string()),
+            success: Some(false),
+            from: Some(entry.timestamp),
+            to: Some(entry.timestamp + chrono::Duration::seconds(1)),
+        }
+        .matches(&entry));
+        assert!(!AuditLogFilter { success: Some(true), ..Default::default() }.matches(&entry));
+        assert!(!AuditLogFilter { to: Some(entry.timestamp), ..Default::default() }.matches(&entry));
+        assert!(!AuditLogFilter { user_id: Some(UserId::new()), ..Default::default() }.matches(&entry));
+    }
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let entry = AuditLog::success(None, AuditAction::Login, "user", None);
+        let cursor = AuditLogCursor::after(&entry);
+
+        let parsed: AuditLogCursor = cursor.to_string().parse().unwrap();
+        assert_eq!(parsed.id, entry.id);
+        assert_eq!(parsed.timestamp.timestamp_micros(), entry.timestamp.timestamp_micros());
+        assert!("not-a-cursor".parse::<AuditLogCursor>().is_err());
+        assert!("123_not-a-uuid".parse::<AuditLogCursor>().is_err());
+    }
+
+    #[test]
+    fn test_cursor_precedes_older_entries() {
+        let newer = AuditLog::success(None, AuditAction::Login, "user", None);
+        let mut older = AuditLog::success(None, AuditAction::Logout, "user", None);
+        older.timestamp = newer.timestamp - chrono::Duration::seconds(1);
+
+        let cursor = AuditLogCursor::after(&newer);
+        assert!(cursor.precedes(&older));
+        assert!(!cursor.precedes(&newer));
+    }
+
+    fn chained_entries(count: u64) -> Vec<AuditLog> {
+        let mut prev_hash = None;
+        (1..=count)
+            .map(|sequence| {
+                let mut entry = AuditLog::success(None, AuditAction::UserUpdate, "user", Some(sequence.to_string()))
+                    .with_details(serde_json::json!({ "first_name": "Ada" }));
+                entry.chain(sequence, prev_hash.take());
+                prev_hash = entry.hash.clone();
+                entry
+            })
+            .collect()
+    }
+
+    fn verify(start: u64, entries: &[AuditLog], last: u64) -> Result<u64, AuditChainBreak> {
+        let mut verifier = AuditChainVerifier::new(start);
+        for entry in entries {
+            verifier.check(entry)?;
+        }
+        verifier.finish(last)?;
+        Ok(verifier.checked())
+    }
+
+    #[test]
+    fn test_intact_chain_verifies() {
+        let entries = chained_entries(3);
+        assert!(entries[0].prev_hash.is_none());
+        assert_eq!(entries[1].prev_hash, entries[0].hash);
+        assert_eq!(verify(1, &entries, 3), Ok(3));
+        assert_eq!(verify(2, &entries[1..], 3), Ok(2));
+    }
+
+    #[test]
+    fn test_edited_entry_breaks_chain() {
+        let mut entries = chained_entries(3);
+        entries[1].details = Some(serde_json::json!({ "first_name": "Eve" }));
+
+        let broken = verify(1, &entries, 3).unwrap_err();
+        assert_eq!(broken.sequence, 2);
+        assert_eq!(broken.id, Some(entries[1].id));
+        assert_eq!(broken.reason, AuditChainBreakReason::HashMismatch);
+    }
+
+    #[test]
+    fn test_rehashed_entry_breaks_next_link() {
+        let mut entries = chained_entries(3);
+        entries[1].success = false;
+        let prev_hash = entries[1].prev_hash.clone();
+        entries[1].chain(2, prev_hash);
+
+        let broken = verify(1, &entries, 3).unwrap_err();
+        assert_eq!(broken.sequence, 3);
+        assert_eq!(broken.reason, AuditChainBreakReason::PrevHashMismatch);
+    }
+
+    #[test]
+    fn test_deleted_entries_break_chain() {
+        let mut entries = chained_entries(4);
+        entries.remove(1);
+
+        let broken = verify(1, &entries, 4).unwrap_err();
+        assert_eq!(broken.sequence, 2);
+        assert_eq!(broken.id, Some(entries[1].id));
+        assert_eq!(broken.reason, AuditChainBreakReason::MissingEntries);
+
+        let entries = chained_entries(4);
+        let broken = verify(1, &entries[..3], 4).unwrap_err();
+        assert_eq!(broken.sequence, 4);
+        assert_eq!(broken.id, None);
+    }
+
+    #[test]
+    fn test_unchained_entry_breaks_chain() {
+        let entry = AuditLog::success(None, AuditAction::Login, "user", None);
+        let broken = verify(1, &[entry], 1).unwrap_err();
+        assert_eq!(broken.reason, AuditChainBreakReason::NotChained);
+    }
+
+    #[test]
+    fn test_hash_ignores_sub_second_precision() {
+        let mut entry = chained_entries(1).remove(0);
+        let hash = entry.hash.clone();
+        entry.timestamp = DateTime::from_timestamp(entry.timestamp.timestamp(), 0).unwrap();
+        assert_eq!(Some(entry.compute_hash()), hash);
+    }
 }
@@ -2,8 +2,20 @@
 
 mod user;
 mod oauth_token;
+mod password_reset_token;
+mod totp_credential;
 mod audit_log;
+mod outbox_event;
+mod webhook;
+mod role;
+mod tenant;
 
 pub use user::*;
 pub use oauth_token::*;
+pub use password_reset_token::*;
+pub use totp_credential::*;
 pub use audit_log::*;
+pub use outbox_event::*;
+pub use webhook::*;
+pub use role::*;
+pub use tenant::*;
@@ -0,0 +1,92 @@
+//! Outbox event entity.
+
+use crate::{decode_event, ArcanaResult, DomainEvent, Entity, OutboxEventId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Domain event stored in the transactional outbox.
+///
+/// Written in the same transaction as the change that raised the event and
+/// kept until the outbox relay has handed it to the event publisher.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxEvent {
+    /// Unique identifier of the outbox entry.
+    pub id: OutboxEventId,
+
+    /// Event type name, e.g. `user.created`.
+    pub event_type: String,
+
+    /// ID of the aggregate the event belongs to.
+    pub aggregate_id: String,
+
+    /// Event serialized as JSON.
+    pub payload: String,
+
+    /// When the event happened.
+    pub occurred_at: DateTime<Utc>,
+
+    /// When the event was delivered, `None` while it is pending.
+    pub dispatched_at: Option<DateTime<Utc>>,
+
+    /// Number of failed delivery attempts.
+    pub attempts: u32,
+
+    /// Error of the last failed delivery attempt.
+    pub last_error: Option<String>,
+}
+
+impl OutboxEvent {
+    /// Creates a pending outbox entry for an event.
+    pub fn from_event(event: &dyn DomainEvent) -> ArcanaResult<Self> {
+        Ok(Self {
+            id: OutboxEventId::new(),
+            event_type: event.event_type().to_string(),
+            aggregate_id: event.aggregate_id(),
+            payload: event.to_json()?,
+            occurred_at: event.timestamp(),
+            dispatched_at: None,
+            attempts: 0,
+            last_error: None,
+        })
+    }
+
+    /// Restores the stored event.
+    pub fn to_domain_event(&self) -> ArcanaResult<Box<dyn DomainEvent>> {
+        decode_event(&self.event_type, &self.payload)
+    }
+
+    /// Returns `true` once the event has been delivered.
+    #[must_use]
+    pub fn is_dispatched(&self) -> bool {
+        self.dispatched_at.is_some()
+    }
+}
+
+impl Entity<OutboxEventId> for OutboxEvent {
+    fn id(&self) -> &OutboxEventId {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UserId, UserRole, UserRoleChanged};
+
+    #[test]
+    fn test_outbox_event_round_trip() {
+        let user_id = UserId::new();
+        let event = UserRoleChanged::new(user_id, UserRole::User, UserRole::Admin, UserId::new());
+
+        let outbox = OutboxEvent::from_event(&event).unwrap();
+        assert_eq!(outbox.event_type, "user.role_changed");
+        assert_eq!(outbox.aggregate_id, user_id.to_string());
+        assert_eq!(outbox.occurred_at, event.timestamp);
+        assert!(!outbox.is_dispatched());
+
+        let restored = outbox.to_domain_event().unwrap();
+        let restored = restored.downcast_ref::<UserRoleChanged>().unwrap();
+        assert_eq!(restored.user_id, user_id);
+        assert_eq!(restored.new_role, UserRole::Admin);
+    }
+}
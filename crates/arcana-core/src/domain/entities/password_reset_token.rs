@@ -0,0 +1,116 @@
+//! Password reset token entity.
+
+use crate::{Entity, PasswordResetTokenId, UserId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Single-use token that lets a user choose a new password.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordResetToken {
+    /// Unique identifier for the token.
+    pub id: PasswordResetTokenId,
+
+    /// User whose password the token resets.
+    pub user_id: UserId,
+
+    /// The reset token value (hashed).
+    #[serde(skip_serializing)]
+    pub token_hash: String,
+
+    /// Token expiration timestamp.
+    pub expires_at: DateTime<Utc>,
+
+    /// When the token was used (or invalidated by a newer one).
+    pub used_at: Option<DateTime<Utc>>,
+
+    /// Token creation timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+impl PasswordResetToken {
+    /// Creates a new password reset token.
+    #[must_use]
+    pub fn new(user_id: UserId, token_hash: String, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: PasswordResetTokenId::new(),
+            user_id,
+            token_hash,
+            expires_at,
+            used_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Checks if the token is expired.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        Utc::now() > self.expires_at
+    }
+
+    /// Checks if the token has been used.
+    #[must_use]
+    pub const fn is_used(&self) -> bool {
+        self.used_at.is_some()
+    }
+
+    /// Checks if the token is valid (not expired and not used).
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        !self.is_expired() && !self.is_used()
+    }
+
+    /// Marks the token as used.
+    pub fn mark_used(&mut self) {
+        self.used_at = Some(Utc::now());
+    }
+}
+
+impl Entity<PasswordResetTokenId> for PasswordResetToken {
+    fn id(&self) -> &PasswordResetTokenId {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_token_creation() {
+        let token = PasswordResetToken::new(
+            UserId::new(),
+            "hash".to_string(),
+            Utc::now() + Duration::minutes(30),
+        );
+
+        assert!(!token.is_expired());
+        assert!(!token.is_used());
+        assert!(token.is_valid());
+    }
+
+    #[test]
+    fn test_token_is_single_use() {
+        let mut token = PasswordResetToken::new(
+            UserId::new(),
+            "hash".to_string(),
+            Utc::now() + Duration::minutes(30),
+        );
+
+        token.mark_used();
+        assert!(token.is_used());
+        assert!(!token.is_valid());
+    }
+
+    #[test]
+    fn test_expired_token() {
+        let token = PasswordResetToken::new(
+            UserId::new(),
+            "hash".to_string(),
+            Utc::now() - Duration::minutes(1),
+        );
+
+        assert!(token.is_expired());
+        assert!(!token.is_valid());
+    }
+}
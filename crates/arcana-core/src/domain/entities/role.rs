@@ -0,0 +1,93 @@
+//! Role entity for data-driven RBAC.
+
+use crate::{Entity, PermissionSet, RoleId, UserRole};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Named set of permission grants.
+///
+/// Each [`UserRole`] has a built-in role of the same name whose grants
+/// every user with that role holds. Custom roles are created by
+/// administrators and assigned to individual users on top of that.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Role {
+    /// Unique identifier of the role.
+    pub id: RoleId,
+
+    /// Unique name of the role.
+    pub name: String,
+
+    /// Free-form description.
+    pub description: Option<String>,
+
+    /// Permissions granted by the role.
+    pub permissions: PermissionSet,
+
+    /// Whether this is the role of a [`UserRole`]; built-in roles cannot be
+    /// renamed or deleted.
+    pub built_in: bool,
+
+    /// When the role was created.
+    pub created_at: DateTime<Utc>,
+
+    /// When the role was last changed.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Role {
+    /// Creates a custom role.
+    #[must_use]
+    pub fn new(name: String, description: Option<String>, permissions: PermissionSet) -> Self {
+        let now = Utc::now();
+        Self {
+            id: RoleId::new(),
+            name,
+            description,
+            permissions,
+            built_in: false,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Returns the user role this built-in role belongs to.
+    #[must_use]
+    pub fn user_role(&self) -> Option<UserRole> {
+        if self.built_in {
+            UserRole::from_str(&self.name)
+        } else {
+            None
+        }
+    }
+}
+
+impl Entity<RoleId> for Role {
+    fn id(&self) -> &RoleId {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Permission;
+
+    #[test]
+    fn test_custom_role_has_no_user_role() {
+        let role = Role::new(
+            "support".to_string(),
+            None,
+            [Permission::UserRead, Permission::UserUpdate].into_iter().collect(),
+        );
+        assert!(!role.built_in);
+        assert_eq!(role.user_role(), None);
+        assert!(role.permissions.contains(Permission::UserUpdate));
+    }
+
+    #[test]
+    fn test_built_in_role_maps_to_user_role() {
+        let mut role = Role::new("moderator".to_string(), None, UserRole::Moderator.default_permissions());
+        role.built_in = true;
+        assert_eq!(role.user_role(), Some(UserRole::Moderator));
+    }
+}
@@ -0,0 +1,93 @@
+//! Tenant entity for multi-tenancy.
+
+use crate::{Entity, TenantId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Organisation served by the deployment.
+///
+/// Every user belongs to exactly one tenant; usernames and emails are
+/// unique within it. The [default tenant](TenantId::DEFAULT) always exists
+/// and holds the users of single-tenant deployments and the operators of
+/// multi-tenant ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tenant {
+    /// Unique identifier of the tenant.
+    pub id: TenantId,
+
+    /// Unique URL-safe name of the tenant, e.g. `acme`.
+    pub slug: String,
+
+    /// Display name.
+    pub name: String,
+
+    /// Whether the tenant's users may sign in.
+    pub active: bool,
+
+    /// When the tenant was created.
+    pub created_at: DateTime<Utc>,
+
+    /// When the tenant was last changed.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Tenant {
+    /// Creates an active tenant.
+    #[must_use]
+    pub fn new(slug: String, name: String) -> Self {
+        let now = Utc::now();
+        Self {
+            id: TenantId::new(),
+            slug,
+            name,
+            active: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Checks if this is the default tenant.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.id.is_default()
+    }
+
+    /// Renames the tenant.
+    pub fn rename(&mut self, name: String) {
+        self.name = name;
+        self.updated_at = Utc::now();
+    }
+
+    /// Allows or stops sign-ins of the tenant's users.
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+        self.updated_at = Utc::now();
+    }
+}
+
+impl Entity<TenantId> for Tenant {
+    fn id(&self) -> &TenantId {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_tenant_is_active() {
+        let tenant = Tenant::new("acme".to_string(), "Acme Corp".to_string());
+        assert!(tenant.active);
+        assert!(!tenant.is_default());
+    }
+
+    #[test]
+    fn test_deactivate_tenant() {
+        let mut tenant = Tenant::new("acme".to_string(), "Acme Corp".to_string());
+        let created_at = tenant.updated_at;
+        tenant.set_active(false);
+        assert!(!tenant.active);
+        assert!(tenant.updated_at >= created_at);
+    }
+}
@@ -0,0 +1,89 @@
+//! TOTP credential entity.
+
+use crate::{Entity, UserId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Authenticator app (TOTP) second factor of a user.
+///
+/// A credential is created on enrolment and only protects logins once the
+/// user has confirmed it with a valid code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotpCredential {
+    /// User the credential belongs to (at most one per user).
+    pub user_id: UserId,
+
+    /// Shared secret (base32), needed to compute codes.
+    #[serde(skip_serializing)]
+    pub secret: String,
+
+    /// When the user confirmed the credential (`None` while enrolling).
+    pub enabled_at: Option<DateTime<Utc>>,
+
+    /// Time step of the last accepted code, so a code cannot be replayed.
+    pub last_used_step: Option<i64>,
+
+    /// Credential creation timestamp.
+    pub created_at: DateTime<Utc>,
+
+    /// Last update timestamp.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TotpCredential {
+    /// Creates a credential that is pending confirmation.
+    #[must_use]
+    pub fn new(user_id: UserId, secret: String) -> Self {
+        let now = Utc::now();
+        Self {
+            user_id,
+            secret,
+            enabled_at: None,
+            last_used_step: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Checks if the credential has been confirmed and protects logins.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.enabled_at.is_some()
+    }
+
+    /// Confirms the credential after the user proved they can compute codes.
+    pub fn enable(&mut self, step: i64) {
+        let now = Utc::now();
+        self.enabled_at = Some(now);
+        self.last_used_step = Some(step);
+        self.updated_at = now;
+    }
+}
+
+impl Entity<UserId> for TotpCredential {
+    fn id(&self) -> &UserId {
+        &self.user_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_credential_is_pending() {
+        let credential = TotpCredential::new(UserId::new(), "SECRET".to_string());
+
+        assert!(!credential.is_enabled());
+        assert!(credential.last_used_step.is_none());
+    }
+
+    #[test]
+    fn test_enable_records_step() {
+        let mut credential = TotpCredential::new(UserId::new(), "SECRET".to_string());
+
+        credential.enable(42);
+        assert!(credential.is_enabled());
+        assert_eq!(credential.last_used_step, Some(42));
+    }
+}
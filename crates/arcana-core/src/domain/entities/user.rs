@@ -1,7 +1,7 @@
 //! User entity.
 
 use super::super::value_objects::{Email, UserRole, UserStatus};
-use crate::{Entity, UserId};
+use crate::{Entity, TenantId, UserId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
@@ -12,7 +12,11 @@ pub struct User {
     /// Unique identifier for the user.
     pub id: UserId,
 
-    /// Unique username.
+    /// Tenant the user belongs to.
+    #[serde(default)]
+    pub tenant_id: TenantId,
+
+    /// Unique username within the tenant.
     #[validate(length(min = 3, max = 32))]
     pub username: String,
 
@@ -46,6 +50,9 @@ pub struct User {
     /// Last login timestamp.
     pub last_login_at: Option<DateTime<Utc>>,
 
+    /// When an automatic lock expires (`None` for manual locks).
+    pub locked_until: Option<DateTime<Utc>>,
+
     /// Account creation timestamp.
     pub created_at: DateTime<Utc>,
 
@@ -66,6 +73,7 @@ impl User {
         let now = Utc::now();
         Self {
             id: UserId::new(),
+            tenant_id: TenantId::DEFAULT,
             username,
             email,
             password_hash,
@@ -76,6 +84,7 @@ impl User {
             email_verified: false,
             avatar_url: None,
             last_login_at: None,
+            locked_until: None,
             created_at: now,
             updated_at: now,
         }
@@ -97,6 +106,13 @@ impl User {
         user
     }
 
+    /// Places the user in a tenant.
+    #[must_use]
+    pub fn in_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = tenant_id;
+        self
+    }
+
     /// Returns the user's full name.
     #[must_use]
     pub fn full_name(&self) -> Option<String> {
@@ -142,6 +158,19 @@ impl User {
     pub fn activate(&mut self) {
         self.status = UserStatus::Active;
         self.email_verified = true;
+        self.locked_until = None;
+        self.updated_at = Utc::now();
+    }
+
+    /// Marks the user's email as verified.
+    ///
+    /// Accounts still pending verification become active; any other status
+    /// (e.g. suspended) is left as is.
+    pub fn verify_email(&mut self) {
+        self.email_verified = true;
+        if self.status == UserStatus::PendingVerification {
+            self.status = UserStatus::Active;
+        }
         self.updated_at = Utc::now();
     }
 
@@ -151,6 +180,32 @@ impl User {
         self.updated_at = Utc::now();
     }
 
+    /// Locks the account until the given time.
+    pub fn lock_until(&mut self, until: DateTime<Utc>) {
+        self.status = UserStatus::Locked;
+        self.locked_until = Some(until);
+        self.updated_at = Utc::now();
+    }
+
+    /// Returns whether the account is under an automatic lock that has expired.
+    #[must_use]
+    pub fn lock_expired(&self, now: DateTime<Utc>) -> bool {
+        self.status == UserStatus::Locked && self.locked_until.is_some_and(|until| until <= now)
+    }
+
+    /// Lifts a lock, returning the account to the status its email verification allows.
+    pub fn unlock(&mut self) {
+        if self.status == UserStatus::Locked {
+            self.status = if self.email_verified {
+                UserStatus::Active
+            } else {
+                UserStatus::PendingVerification
+            };
+        }
+        self.locked_until = None;
+        self.updated_at = Utc::now();
+    }
+
     /// Records a successful login.
     pub fn record_login(&mut self) {
         self.last_login_at = Some(Utc::now());
@@ -187,6 +242,7 @@ impl Entity<UserId> for User {
 /// Builder for creating User instances.
 #[derive(Debug, Default)]
 pub struct UserBuilder {
+    tenant_id: Option<TenantId>,
     username: Option<String>,
     email: Option<Email>,
     password_hash: Option<String>,
@@ -203,6 +259,13 @@ impl UserBuilder {
         Self::default()
     }
 
+    /// Sets the tenant.
+    #[must_use]
+    pub fn tenant_id(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = Some(tenant_id);
+        self
+    }
+
     /// Sets the username.
     #[must_use]
     pub fn username(mut self, username: impl Into<String>) -> Self {
@@ -267,6 +330,9 @@ impl UserBuilder {
             self.last_name,
         );
 
+        if let Some(tenant_id) = self.tenant_id {
+            user.tenant_id = tenant_id;
+        }
         if let Some(role) = self.role {
             user.role = role;
         }
@@ -311,6 +377,22 @@ mod tests {
         assert!(!user.email_verified);
         assert!(user.avatar_url.is_none());
         assert!(user.last_login_at.is_none());
+        assert!(user.tenant_id.is_default());
+    }
+
+    #[test]
+    fn test_user_in_tenant() {
+        let tenant_id = TenantId::new();
+        let user = create_user("tenantuser").in_tenant(tenant_id);
+        assert_eq!(user.tenant_id, tenant_id);
+
+        let built = UserBuilder::new()
+            .tenant_id(tenant_id)
+            .username("builder")
+            .email(Email::new("builder@example.com").unwrap())
+            .password_hash("hashed")
+            .build();
+        assert_eq!(built.tenant_id, tenant_id);
     }
 
     #[test]
@@ -366,6 +448,20 @@ mod tests {
         assert_eq!(user.status, UserStatus::Active);
     }
 
+    #[test]
+    fn test_user_verify_email() {
+        let mut pending = create_user("pending");
+        pending.verify_email();
+        assert!(pending.email_verified);
+        assert_eq!(pending.status, UserStatus::Active);
+
+        let mut suspended = create_user("suspended");
+        suspended.suspend();
+        suspended.verify_email();
+        assert!(suspended.email_verified);
+        assert_eq!(suspended.status, UserStatus::Suspended);
+    }
+
     #[test]
     fn test_user_suspension() {
         let mut user = create_user("testuser");
@@ -376,6 +472,38 @@ mod tests {
         assert_eq!(user.status, UserStatus::Suspended);
     }
 
+    #[test]
+    fn test_user_timed_lock() {
+        let mut user = create_user("testuser");
+        user.activate();
+        let now = Utc::now();
+        user.lock_until(now + chrono::Duration::minutes(15));
+
+        assert_eq!(user.status, UserStatus::Locked);
+        assert!(!user.can_login());
+        assert!(!user.lock_expired(now));
+        assert!(user.lock_expired(now + chrono::Duration::minutes(15)));
+
+        user.unlock();
+        assert_eq!(user.status, UserStatus::Active);
+        assert!(user.locked_until.is_none());
+    }
+
+    #[test]
+    fn test_user_unlock_keeps_verification_pending() {
+        let mut user = create_user("testuser");
+        user.lock_until(Utc::now());
+        user.unlock();
+        assert_eq!(user.status, UserStatus::PendingVerification);
+    }
+
+    #[test]
+    fn test_manual_lock_never_expires() {
+        let mut user = create_user("testuser");
+        user.status = UserStatus::Locked;
+        assert!(!user.lock_expired(Utc::now() + chrono::Duration::days(365)));
+    }
+
     #[test]
     fn test_user_record_login() {
         let mut user = create_user("testuser");
@@ -0,0 +1,189 @@
+//! Webhook subscription and delivery entities.
+
+use crate::{ArcanaError, Entity, WebhookDeliveryId, WebhookSubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Event type filter entry that matches every event.
+pub const WEBHOOK_ALL_EVENTS: &str = "*";
+
+/// Endpoint that receives domain events as signed HTTP callbacks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSubscription {
+    /// Unique identifier of the subscription.
+    pub id: WebhookSubscriptionId,
+
+    /// URL the events are posted to.
+    pub url: String,
+
+    /// Event types delivered to the endpoint; `*` selects all of them.
+    pub event_types: Vec<String>,
+
+    /// Shared secret the payloads are signed with.
+    pub secret: String,
+
+    /// Free-form description.
+    pub description: Option<String>,
+
+    /// Whether events are delivered.
+    pub active: bool,
+
+    /// When the subscription was created.
+    pub created_at: DateTime<Utc>,
+
+    /// When the subscription was last changed.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    /// Creates an active subscription.
+    #[must_use]
+    pub fn new(url: String, event_types: Vec<String>, secret: String, description: Option<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: WebhookSubscriptionId::new(),
+            url,
+            event_types,
+            secret,
+            description,
+            active: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Returns `true` if events of this type are delivered to the endpoint.
+    #[must_use]
+    pub fn matches(&self, event_type: &str) -> bool {
+        self.active
+            && self
+                .event_types
+                .iter()
+                .any(|t| t == WEBHOOK_ALL_EVENTS || t == event_type)
+    }
+}
+
+impl Entity<WebhookSubscriptionId> for WebhookSubscription {
+    fn id(&self) -> &WebhookSubscriptionId {
+        &self.id
+    }
+}
+
+/// Outcome of a delivery attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// The endpoint accepted the event.
+    Succeeded,
+    /// The attempt failed and will be retried.
+    Failed,
+    /// The last attempt failed; the event was moved to the dead letter queue.
+    DeadLettered,
+}
+
+impl std::fmt::Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Succeeded => write!(f, "succeeded"),
+            Self::Failed => write!(f, "failed"),
+            Self::DeadLettered => write!(f, "dead_lettered"),
+        }
+    }
+}
+
+impl std::str::FromStr for WebhookDeliveryStatus {
+    type Err = ArcanaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "succeeded" => Ok(Self::Succeeded),
+            "failed" => Ok(Self::Failed),
+            "dead_lettered" => Ok(Self::DeadLettered),
+            _ => Err(ArcanaError::Validation(format!("Unknown webhook delivery status: {}", s))),
+        }
+    }
+}
+
+/// Delivery log entry: one attempt to post an event to a subscription.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    /// Unique identifier of the log entry.
+    pub id: WebhookDeliveryId,
+
+    /// Subscription the event was posted to.
+    pub subscription_id: WebhookSubscriptionId,
+
+    /// ID of the posted event, the same for every attempt.
+    pub event_id: Uuid,
+
+    /// Type of the posted event.
+    pub event_type: String,
+
+    /// Attempt number, starting at 1.
+    pub attempt: u32,
+
+    /// Outcome of the attempt.
+    pub status: WebhookDeliveryStatus,
+
+    /// HTTP status the endpoint answered with, if it answered.
+    pub response_status: Option<u16>,
+
+    /// Why the attempt failed.
+    pub error: Option<String>,
+
+    /// Time the request took, in milliseconds.
+    pub duration_ms: u64,
+
+    /// When the attempt was made.
+    pub attempted_at: DateTime<Utc>,
+}
+
+impl Entity<WebhookDeliveryId> for WebhookDelivery {
+    fn id(&self) -> &WebhookDeliveryId {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subscription(event_types: &[&str]) -> WebhookSubscription {
+        WebhookSubscription::new(
+            "https://example.com/hooks".to_string(),
+            event_types.iter().map(|t| t.to_string()).collect(),
+            "secret".to_string(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_subscription_matches_event_types() {
+        let subscription = subscription(&["user.created", "auth.login_succeeded"]);
+        assert!(subscription.matches("user.created"));
+        assert!(!subscription.matches("user.deleted"));
+
+        assert!(self::subscription(&[WEBHOOK_ALL_EVENTS]).matches("user.deleted"));
+    }
+
+    #[test]
+    fn test_inactive_subscription_matches_nothing() {
+        let mut subscription = subscription(&[WEBHOOK_ALL_EVENTS]);
+        subscription.active = false;
+        assert!(!subscription.matches("user.created"));
+    }
+
+    #[test]
+    fn test_delivery_status_round_trip() {
+        for status in [
+            WebhookDeliveryStatus::Succeeded,
+            WebhookDeliveryStatus::Failed,
+            WebhookDeliveryStatus::DeadLettered,
+        ] {
+            assert_eq!(status.to_string().parse::<WebhookDeliveryStatus>().unwrap(), status);
+        }
+        assert!("lost".parse::<WebhookDeliveryStatus>().is_err());
+    }
+}
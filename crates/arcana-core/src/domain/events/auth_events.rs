@@ -105,6 +105,89 @@ pub enum LoginFailureReason {
     AccountNotVerified,
     /// Too many failed attempts.
     TooManyAttempts,
+    /// Invalid second-factor code.
+    InvalidMfaCode,
+}
+
+/// Security event emitted when an account is locked after repeated failed logins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountLocked {
+    pub user_id: UserId,
+    pub failed_attempts: u32,
+    pub locked_until: DateTime<Utc>,
+    pub ip_address: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl AccountLocked {
+    #[must_use]
+    pub fn new(
+        user_id: UserId,
+        failed_attempts: u32,
+        locked_until: DateTime<Utc>,
+        ip_address: Option<String>,
+    ) -> Self {
+        Self {
+            user_id,
+            failed_attempts,
+            locked_until,
+            ip_address,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+impl DomainEvent for AccountLocked {
+    fn event_type(&self) -> &'static str {
+        "auth.account_locked"
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    fn to_json(&self) -> ArcanaResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// Event emitted when an automatic account lock expires.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountUnlocked {
+    pub user_id: UserId,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl AccountUnlocked {
+    #[must_use]
+    pub fn new(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+impl DomainEvent for AccountUnlocked {
+    fn event_type(&self) -> &'static str {
+        "auth.account_unlocked"
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    fn to_json(&self) -> ArcanaResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
 }
 
 /// Event emitted when a user logs out.
@@ -183,6 +266,48 @@ impl DomainEvent for TokenRefreshed {
     }
 }
 
+/// Security event emitted when an already-rotated refresh token is presented again.
+///
+/// The whole token family is revoked in response, since either the legitimate
+/// client or an attacker holds a stolen copy of the token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshTokenReuseDetected {
+    pub user_id: UserId,
+    pub token_family: String,
+    pub revoked_tokens: u64,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl RefreshTokenReuseDetected {
+    #[must_use]
+    pub fn new(user_id: UserId, token_family: String, revoked_tokens: u64) -> Self {
+        Self {
+            user_id,
+            token_family,
+            revoked_tokens,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+impl DomainEvent for RefreshTokenReuseDetected {
+    fn event_type(&self) -> &'static str {
+        "auth.refresh_token_reuse_detected"
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    fn to_json(&self) -> ArcanaResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
 /// Event emitted when a password is changed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PasswordChanged {
@@ -315,6 +440,7 @@ mod tests {
             LoginFailureReason::AccountSuspended,
             LoginFailureReason::AccountNotVerified,
             LoginFailureReason::TooManyAttempts,
+            LoginFailureReason::InvalidMfaCode,
         ];
 
         for reason in reasons {
@@ -325,6 +451,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_account_locked_event() {
+        let user_id = UserId::new();
+        let until = Utc::now() + chrono::Duration::minutes(15);
+        let event = AccountLocked::new(user_id, 5, until, Some("10.0.0.1".to_string()));
+
+        assert_eq!(event.event_type(), "auth.account_locked");
+        assert_eq!(event.aggregate_id(), user_id.to_string());
+        assert_eq!(event.failed_attempts, 5);
+        assert!(event.to_json().unwrap().contains("10.0.0.1"));
+    }
+
+    #[test]
+    fn test_account_unlocked_event() {
+        let user_id = UserId::new();
+        let event = AccountUnlocked::new(user_id);
+
+        assert_eq!(event.event_type(), "auth.account_unlocked");
+        assert_eq!(event.aggregate_id(), user_id.to_string());
+    }
+
     #[test]
     fn test_logout_occurred_event() {
         let user_id = UserId::new();
@@ -359,6 +506,19 @@ mod tests {
         assert_eq!(event.new_token_family, "new-family-456");
     }
 
+    #[test]
+    fn test_refresh_token_reuse_detected_event() {
+        let user_id = UserId::new();
+        let event = RefreshTokenReuseDetected::new(user_id, "family-123".to_string(), 2);
+
+        assert_eq!(event.event_type(), "auth.refresh_token_reuse_detected");
+        assert_eq!(event.aggregate_id(), user_id.to_string());
+        assert_eq!(event.revoked_tokens, 2);
+
+        let json = event.to_json().unwrap();
+        assert!(json.contains("family-123"));
+    }
+
     #[test]
     fn test_password_changed_event() {
         let user_id = UserId::new();
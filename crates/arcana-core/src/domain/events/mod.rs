@@ -5,3 +5,85 @@ mod auth_events;
 
 pub use user_events::*;
 pub use auth_events::*;
+
+use crate::{ArcanaError, ArcanaResult, DomainEvent};
+
+/// Type names of all domain events, as returned by [`DomainEvent::event_type`].
+pub const EVENT_TYPES: &[&str] = &[
+    "user.created",
+    "user.updated",
+    "user.deleted",
+    "user.role_changed",
+    "user.status_changed",
+    "user.email_verified",
+    "auth.login_succeeded",
+    "auth.login_failed",
+    "auth.account_locked",
+    "auth.account_unlocked",
+    "auth.logout",
+    "auth.token_refreshed",
+    "auth.refresh_token_reuse_detected",
+    "auth.password_changed",
+    "auth.password_reset_requested",
+];
+
+/// Restores an event from its type name and JSON payload.
+///
+/// Inverse of [`DomainEvent::event_type`] and [`DomainEvent::to_json`], used
+/// to deliver events that were stored before being published.
+pub fn decode_event(event_type: &str, payload: &str) -> ArcanaResult<Box<dyn DomainEvent>> {
+    fn decode<E: DomainEvent + serde::de::DeserializeOwned + 'static>(
+        payload: &str,
+    ) -> ArcanaResult<Box<dyn DomainEvent>> {
+        Ok(Box::new(serde_json::from_str::<E>(payload)?))
+    }
+
+    match event_type {
+        "user.created" => decode::<UserCreated>(payload),
+        "user.updated" => decode::<UserUpdated>(payload),
+        "user.deleted" => decode::<UserDeleted>(payload),
+        "user.role_changed" => decode::<UserRoleChanged>(payload),
+        "user.status_changed" => decode::<UserStatusChanged>(payload),
+        "user.email_verified" => decode::<UserEmailVerified>(payload),
+        "auth.login_succeeded" => decode::<LoginSucceeded>(payload),
+        "auth.login_failed" => decode::<LoginFailed>(payload),
+        "auth.account_locked" => decode::<AccountLocked>(payload),
+        "auth.account_unlocked" => decode::<AccountUnlocked>(payload),
+        "auth.logout" => decode::<LogoutOccurred>(payload),
+        "auth.token_refreshed" => decode::<TokenRefreshed>(payload),
+        "auth.refresh_token_reuse_detected" => decode::<RefreshTokenReuseDetected>(payload),
+        "auth.password_changed" => decode::<PasswordChanged>(payload),
+        "auth.password_reset_requested" => decode::<PasswordResetRequested>(payload),
+        _ => Err(ArcanaError::Internal(format!("Unknown event type: {}", event_type))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UserId;
+
+    #[test]
+    fn test_decode_event_restores_concrete_type() {
+        let event = LogoutOccurred::new(UserId::new(), Some("session-1".to_string()));
+
+        let decoded = decode_event(event.event_type(), &event.to_json().unwrap()).unwrap();
+        assert_eq!(decoded.event_type(), "auth.logout");
+        assert_eq!(decoded.aggregate_id(), event.aggregate_id());
+        assert!(decoded.downcast_ref::<LogoutOccurred>().is_some());
+    }
+
+    #[test]
+    fn test_decode_event_rejects_unknown_types() {
+        assert!(decode_event("user.teleported", "{}").is_err());
+        assert!(decode_event("user.created", "not json").is_err());
+    }
+
+    #[test]
+    fn test_event_types_are_decodable() {
+        for event_type in EVENT_TYPES {
+            let error = decode_event(event_type, "{}").err().unwrap();
+            assert!(!error.to_string().contains("Unknown event type"), "{}", event_type);
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! User role value object.
 
+use crate::ArcanaError;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::fmt;
 
 /// User roles with hierarchical permissions.
@@ -54,6 +56,15 @@ impl UserRole {
         [Self::User, Self::Moderator, Self::Admin, Self::SuperAdmin]
     }
 
+    /// Returns the permissions granted to this role out of the box.
+    ///
+    /// The grants actually in force are stored with the built-in roles and
+    /// may have been changed by an administrator.
+    #[must_use]
+    pub fn default_permissions(&self) -> PermissionSet {
+        Permission::all().into_iter().filter(|p| p.is_allowed_for(*self)).collect()
+    }
+
     /// Parses a role from a string.
     #[must_use]
     pub fn from_str(s: &str) -> Option<Self> {
@@ -79,37 +90,108 @@ impl fmt::Display for UserRole {
 }
 
 /// Permission types for RBAC.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+///
+/// Serialized as `resource:action` (`"user:read"`), the same form as
+/// [`Display`](fmt::Display) and [`FromStr`](std::str::FromStr). Earlier
+/// versions serialized permissions in snake case (`"user_read"`); that
+/// spelling is still accepted when deserializing, so tokens, role tables and
+/// API clients written against it keep working.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum Permission {
     // User permissions
+    #[serde(rename = "user:read", alias = "user_read")]
     UserRead,
+    #[serde(rename = "user:create", alias = "user_create")]
     UserCreate,
+    #[serde(rename = "user:update", alias = "user_update")]
     UserUpdate,
+    #[serde(rename = "user:delete", alias = "user_delete")]
     UserDelete,
+    #[serde(rename = "user:manage_roles", alias = "user_manage_roles")]
     UserManageRoles,
 
     // Plugin permissions
+    #[serde(rename = "plugin:read", alias = "plugin_read")]
     PluginRead,
+    #[serde(rename = "plugin:install", alias = "plugin_install")]
     PluginInstall,
+    #[serde(rename = "plugin:uninstall", alias = "plugin_uninstall")]
     PluginUninstall,
+    #[serde(rename = "plugin:configure", alias = "plugin_configure")]
     PluginConfigure,
 
     // System permissions
+    #[serde(rename = "system:config", alias = "system_config")]
     SystemConfig,
+    #[serde(rename = "system:monitor", alias = "system_monitor")]
     SystemMonitor,
+    #[serde(rename = "system:admin", alias = "system_admin")]
     SystemAdmin,
 
     // Content permissions
+    #[serde(rename = "content:read", alias = "content_read")]
     ContentRead,
+    #[serde(rename = "content:create", alias = "content_create")]
     ContentCreate,
+    #[serde(rename = "content:update", alias = "content_update")]
     ContentUpdate,
+    #[serde(rename = "content:delete", alias = "content_delete")]
     ContentDelete,
+    #[serde(rename = "content:moderate", alias = "content_moderate")]
     ContentModerate,
 }
 
 impl Permission {
-    /// Returns the minimum role required for this permission.
+    /// Returns all permissions.
+    #[must_use]
+    pub const fn all() -> [Self; 17] {
+        [
+            Self::UserRead,
+            Self::UserCreate,
+            Self::UserUpdate,
+            Self::UserDelete,
+            Self::UserManageRoles,
+            Self::PluginRead,
+            Self::PluginInstall,
+            Self::PluginUninstall,
+            Self::PluginConfigure,
+            Self::SystemConfig,
+            Self::SystemMonitor,
+            Self::SystemAdmin,
+            Self::ContentRead,
+            Self::ContentCreate,
+            Self::ContentUpdate,
+            Self::ContentDelete,
+            Self::ContentModerate,
+        ]
+    }
+
+    /// Returns the permission in its `resource:action` form.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::UserRead => "user:read",
+            Self::UserCreate => "user:create",
+            Self::UserUpdate => "user:update",
+            Self::UserDelete => "user:delete",
+            Self::UserManageRoles => "user:manage_roles",
+            Self::PluginRead => "plugin:read",
+            Self::PluginInstall => "plugin:install",
+            Self::PluginUninstall => "plugin:uninstall",
+            Self::PluginConfigure => "plugin:configure",
+            Self::SystemConfig => "system:config",
+            Self::SystemMonitor => "system:monitor",
+            Self::SystemAdmin => "system:admin",
+            Self::ContentRead => "content:read",
+            Self::ContentCreate => "content:create",
+            Self::ContentUpdate => "content:update",
+            Self::ContentDelete => "content:delete",
+            Self::ContentModerate => "content:moderate",
+        }
+    }
+
+    /// Returns the lowest built-in role granted this permission by default.
     #[must_use]
     pub const fn minimum_role(&self) -> UserRole {
         match self {
@@ -132,34 +214,102 @@ impl Permission {
         }
     }
 
-    /// Checks if the given role has this permission.
+    /// Checks if the given role is granted this permission by default.
     #[must_use]
     pub const fn is_allowed_for(&self, role: UserRole) -> bool {
         role.has_permission(self.minimum_role())
     }
+
+    /// Checks if this is a `system:*` permission, covering the whole
+    /// deployment rather than a single tenant.
+    #[must_use]
+    pub const fn is_system(&self) -> bool {
+        matches!(self, Self::SystemConfig | Self::SystemMonitor | Self::SystemAdmin)
+    }
 }
 
 impl fmt::Display for Permission {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::UserRead => write!(f, "user:read"),
-            Self::UserCreate => write!(f, "user:create"),
-            Self::UserUpdate => write!(f, "user:update"),
-            Self::UserDelete => write!(f, "user:delete"),
-            Self::UserManageRoles => write!(f, "user:manage_roles"),
-            Self::PluginRead => write!(f, "plugin:read"),
-            Self::PluginInstall => write!(f, "plugin:install"),
-            Self::PluginUninstall => write!(f, "plugin:uninstall"),
-            Self::PluginConfigure => write!(f, "plugin:configure"),
-            Self::SystemConfig => write!(f, "system:config"),
-            Self::SystemMonitor => write!(f, "system:monitor"),
-            Self::SystemAdmin => write!(f, "system:admin"),
-            Self::ContentRead => write!(f, "content:read"),
-            Self::ContentCreate => write!(f, "content:create"),
-            Self::ContentUpdate => write!(f, "content:update"),
-            Self::ContentDelete => write!(f, "content:delete"),
-            Self::ContentModerate => write!(f, "content:moderate"),
-        }
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Permission {
+    type Err = ArcanaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .into_iter()
+            .find(|p| p.as_str() == s)
+            .ok_or_else(|| ArcanaError::Validation(format!("Unknown permission: {}", s)))
+    }
+}
+
+/// Set of permissions held by a user or granted to a role.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(transparent)]
+pub struct PermissionSet(BTreeSet<Permission>);
+
+impl PermissionSet {
+    /// Creates an empty set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the set holds the permission.
+    #[must_use]
+    pub fn contains(&self, permission: Permission) -> bool {
+        self.0.contains(&permission)
+    }
+
+    /// Adds a permission. Returns `true` if it was not present yet.
+    pub fn insert(&mut self, permission: Permission) -> bool {
+        self.0.insert(permission)
+    }
+
+    /// Removes a permission. Returns `true` if it was present.
+    pub fn remove(&mut self, permission: Permission) -> bool {
+        self.0.remove(&permission)
+    }
+
+    /// Returns the number of permissions in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the set holds no permission.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the permissions in a stable order.
+    pub fn iter(&self) -> impl Iterator<Item = Permission> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromIterator<Permission> for PermissionSet {
+    fn from_iter<I: IntoIterator<Item = Permission>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Permission> for PermissionSet {
+    fn extend<I: IntoIterator<Item = Permission>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl IntoIterator for PermissionSet {
+    type Item = Permission;
+    type IntoIter = std::collections::btree_set::IntoIter<Permission>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
     }
 }
 
@@ -309,6 +459,37 @@ mod tests {
         assert_eq!(Permission::PluginInstall.to_string(), "plugin:install");
     }
 
+    #[test]
+    fn test_permission_round_trip() {
+        for permission in Permission::all() {
+            assert_eq!(permission.to_string().parse::<Permission>().unwrap(), permission);
+            let json = serde_json::to_string(&permission).unwrap();
+            assert_eq!(json, format!("\"{}\"", permission));
+            assert_eq!(serde_json::from_str::<Permission>(&json).unwrap(), permission);
+        }
+        assert!("user:fly".parse::<Permission>().is_err());
+    }
+
+    #[test]
+    fn test_permission_accepts_snake_case_spelling() {
+        for permission in Permission::all() {
+            let legacy = format!("\"{}\"", permission.as_str().replace(':', "_"));
+            assert_eq!(serde_json::from_str::<Permission>(&legacy).unwrap(), permission);
+        }
+        let set: PermissionSet = serde_json::from_str(r#"["user_manage_roles","system:admin"]"#).unwrap();
+        assert!(set.contains(Permission::UserManageRoles) && set.contains(Permission::SystemAdmin));
+    }
+
+    #[test]
+    fn test_default_permissions_follow_minimum_role() {
+        let moderator = UserRole::Moderator.default_permissions();
+        assert!(moderator.contains(Permission::UserUpdate));
+        assert!(moderator.contains(Permission::SystemMonitor));
+        assert!(!moderator.contains(Permission::UserDelete));
+
+        assert_eq!(UserRole::SuperAdmin.default_permissions().len(), Permission::all().len());
+    }
+
     #[test]
     fn test_plugin_permissions() {
         assert!(Permission::PluginRead.is_allowed_for(UserRole::User));
@@ -322,6 +503,8 @@ mod tests {
         assert!(!Permission::SystemConfig.is_allowed_for(UserRole::Admin));
         assert!(Permission::SystemConfig.is_allowed_for(UserRole::SuperAdmin));
         assert!(Permission::SystemMonitor.is_allowed_for(UserRole::Moderator));
+        assert!(Permission::SystemMonitor.is_system());
+        assert!(!Permission::UserManageRoles.is_system());
     }
 
     #[test]
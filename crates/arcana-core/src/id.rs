@@ -140,6 +140,17 @@ impl OAuthTokenId {
         Self(Uuid::now_v7())
     }
 
+    /// Creates an OAuth token ID from a UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Parses an OAuth token ID from a string.
+    pub fn parse(s: &str) -> Result<Self, uuid::Error> {
+        Ok(Self(Uuid::parse_str(s)?))
+    }
+
     /// Returns the inner UUID.
     #[must_use]
     pub const fn into_inner(self) -> Uuid {
@@ -159,8 +170,52 @@ impl Display for OAuthTokenId {
     }
 }
 
+/// A strongly-typed wrapper for password reset token IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PasswordResetTokenId(pub Uuid);
+
+impl PasswordResetTokenId {
+    /// Creates a new random password reset token ID.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Creates a password reset token ID from a UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Parses a password reset token ID from a string.
+    pub fn parse(s: &str) -> Result<Self, uuid::Error> {
+        Ok(Self(Uuid::parse_str(s)?))
+    }
+
+    /// Returns the inner UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for PasswordResetTokenId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for PasswordResetTokenId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// A strongly-typed wrapper for audit log IDs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(value_type = String, format = "uuid"))]
 #[serde(transparent)]
 pub struct AuditLogId(pub Uuid);
 
@@ -171,6 +226,17 @@ impl AuditLogId {
         Self(Uuid::now_v7())
     }
 
+    /// Creates an audit log ID from a UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Parses an audit log ID from a string.
+    pub fn parse(s: &str) -> Result<Self, uuid::Error> {
+        Ok(Self(Uuid::parse_str(s)?))
+    }
+
     /// Returns the inner UUID.
     #[must_use]
     pub const fn into_inner(self) -> Uuid {
@@ -190,6 +256,235 @@ impl Display for AuditLogId {
     }
 }
 
+/// A strongly-typed wrapper for outbox event IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct OutboxEventId(pub Uuid);
+
+impl OutboxEventId {
+    /// Creates a new random outbox event ID.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Creates an outbox event ID from a UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Parses an outbox event ID from a string.
+    pub fn parse(s: &str) -> Result<Self, uuid::Error> {
+        Ok(Self(Uuid::parse_str(s)?))
+    }
+
+    /// Returns the inner UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for OutboxEventId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for OutboxEventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A strongly-typed wrapper for webhook subscription IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(value_type = String, format = "uuid"))]
+#[serde(transparent)]
+pub struct WebhookSubscriptionId(pub Uuid);
+
+impl WebhookSubscriptionId {
+    /// Creates a new random webhook subscription ID.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Creates a webhook subscription ID from a UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Parses a webhook subscription ID from a string.
+    pub fn parse(s: &str) -> Result<Self, uuid::Error> {
+        Ok(Self(Uuid::parse_str(s)?))
+    }
+
+    /// Returns the inner UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for WebhookSubscriptionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for WebhookSubscriptionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A strongly-typed wrapper for webhook delivery IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(value_type = String, format = "uuid"))]
+#[serde(transparent)]
+pub struct WebhookDeliveryId(pub Uuid);
+
+impl WebhookDeliveryId {
+    /// Creates a new random webhook delivery ID.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Creates a webhook delivery ID from a UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Parses a webhook delivery ID from a string.
+    pub fn parse(s: &str) -> Result<Self, uuid::Error> {
+        Ok(Self(Uuid::parse_str(s)?))
+    }
+
+    /// Returns the inner UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for WebhookDeliveryId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for WebhookDeliveryId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A strongly-typed wrapper for role IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(value_type = String, format = "uuid"))]
+#[serde(transparent)]
+pub struct RoleId(pub Uuid);
+
+impl RoleId {
+    /// Creates a new random role ID.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Creates a role ID from a UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Parses a role ID from a string.
+    pub fn parse(s: &str) -> Result<Self, uuid::Error> {
+        Ok(Self(Uuid::parse_str(s)?))
+    }
+
+    /// Returns the inner UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for RoleId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for RoleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A strongly-typed wrapper for tenant IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(value_type = String, format = "uuid"))]
+#[serde(transparent)]
+pub struct TenantId(pub Uuid);
+
+impl TenantId {
+    /// The default tenant, which every user belongs to unless placed in
+    /// another one. Deployments serving a single organisation only use this.
+    pub const DEFAULT: Self = Self(Uuid::from_u128(0x0000_0000_0000_7000_8000_0000_0000_0001));
+
+    /// Creates a new random tenant ID.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Creates a tenant ID from a UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Parses a tenant ID from a string.
+    pub fn parse(s: &str) -> Result<Self, uuid::Error> {
+        Ok(Self(Uuid::parse_str(s)?))
+    }
+
+    /// Returns the inner UUID.
+    #[must_use]
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+
+    /// Checks if this is the default tenant.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::DEFAULT
+    }
+}
+
+/// The default tenant, not a random ID: data without a tenant belongs to it.
+impl Default for TenantId {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Display for TenantId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,6 +625,34 @@ mod tests {
         assert!(!id.to_string().is_empty());
     }
 
+    #[test]
+    fn test_oauth_token_id_parsing() {
+        let uuid_str = "550e8400-e29b-41d4-a716-446655440000";
+        let id = OAuthTokenId::parse(uuid_str).unwrap();
+        assert_eq!(id.to_string(), uuid_str);
+        assert_eq!(OAuthTokenId::from_uuid(id.into_inner()), id);
+        assert!(OAuthTokenId::parse("not-a-uuid").is_err());
+    }
+
+    #[test]
+    fn test_password_reset_token_id_parsing() {
+        let uuid_str = "550e8400-e29b-41d4-a716-446655440000";
+        let id = PasswordResetTokenId::parse(uuid_str).unwrap();
+        assert_eq!(id.to_string(), uuid_str);
+        assert_eq!(PasswordResetTokenId::from_uuid(id.into_inner()), id);
+        assert!(PasswordResetTokenId::parse("not-a-uuid").is_err());
+        assert_ne!(PasswordResetTokenId::new(), PasswordResetTokenId::default());
+    }
+
+    #[test]
+    fn test_audit_log_id_parsing() {
+        let uuid_str = "550e8400-e29b-41d4-a716-446655440000";
+        let id = AuditLogId::parse(uuid_str).unwrap();
+        assert_eq!(id.to_string(), uuid_str);
+        assert_eq!(AuditLogId::from_uuid(id.into_inner()), id);
+        assert!(AuditLogId::parse("not-a-uuid").is_err());
+    }
+
     #[test]
     fn test_audit_log_id_creation() {
         let id1 = AuditLogId::new();
@@ -342,4 +665,23 @@ mod tests {
         let id = AuditLogId::default();
         assert!(!id.to_string().is_empty());
     }
+
+    #[test]
+    fn test_webhook_ids_parsing() {
+        let uuid_str = "550e8400-e29b-41d4-a716-446655440000";
+        let id = WebhookSubscriptionId::parse(uuid_str).unwrap();
+        assert_eq!(id.to_string(), uuid_str);
+        assert_eq!(WebhookSubscriptionId::from_uuid(id.into_inner()), id);
+        assert!(WebhookDeliveryId::parse("not-a-uuid").is_err());
+        assert_ne!(WebhookDeliveryId::new(), WebhookDeliveryId::default());
+    }
+
+    #[test]
+    fn test_default_tenant_id() {
+        assert_eq!(TenantId::default(), TenantId::DEFAULT);
+        assert!(TenantId::DEFAULT.is_default());
+        assert!(!TenantId::new().is_default());
+        assert_eq!(TenantId::DEFAULT.to_string(), "00000000-0000-7000-8000-000000000001");
+        assert_eq!(TenantId::parse("00000000-0000-7000-8000-000000000001").unwrap(), TenantId::DEFAULT);
+    }
 }
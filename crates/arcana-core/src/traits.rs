@@ -2,6 +2,7 @@
 
 use crate::{ArcanaResult, Page, PageRequest};
 use async_trait::async_trait;
+use std::any::Any;
 
 /// Base repository trait for CRUD operations.
 ///
@@ -41,11 +42,26 @@ where
 /// across multiple repositories and external services.
 pub trait Service: Send + Sync {}
 
+/// Access to a value as [`Any`].
+///
+/// Implemented for every `'static` type, so that trait objects such as
+/// `dyn DomainEvent` can be downcast to their concrete type.
+pub trait AsAny {
+    /// Returns the value as [`Any`].
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 /// Trait for domain events.
 ///
 /// Domain events represent something significant that happened
 /// in the domain and can be used for event-driven architectures.
-pub trait DomainEvent: Send + Sync {
+pub trait DomainEvent: AsAny + Send + Sync {
     /// Returns the event type name.
     fn event_type(&self) -> &'static str;
 
@@ -59,6 +75,14 @@ pub trait DomainEvent: Send + Sync {
     fn to_json(&self) -> ArcanaResult<String>;
 }
 
+impl<'a> dyn DomainEvent + 'a {
+    /// Returns the event as `E` if that is its concrete type.
+    #[must_use]
+    pub fn downcast_ref<E: DomainEvent + 'static>(&self) -> Option<&E> {
+        self.as_any().downcast_ref::<E>()
+    }
+}
+
 /// Trait for entities with a unique identifier.
 pub trait Entity<ID> {
     /// Returns the entity's unique identifier.
@@ -126,13 +150,18 @@ where
 }
 
 /// Trait for event handlers.
+///
+/// `E` may be `dyn DomainEvent` for handlers that accept every event.
 #[async_trait]
-pub trait EventHandler<E: DomainEvent>: Send + Sync {
+pub trait EventHandler<E: DomainEvent + ?Sized>: Send + Sync {
     /// Handles the event.
     async fn handle(&self, event: &E) -> ArcanaResult<()>;
 }
 
 /// Trait for event publishers.
+///
+/// Publishing does not fail because a handler failed; errors are reserved
+/// for the publisher itself being unable to accept the event.
 #[async_trait]
 pub trait EventPublisher: Send + Sync {
     /// Publishes a domain event.
@@ -181,6 +210,34 @@ impl HealthStatus {
 mod tests {
     use super::*;
 
+    #[derive(serde::Serialize)]
+    struct Pinged;
+
+    impl DomainEvent for Pinged {
+        fn event_type(&self) -> &'static str {
+            "test.pinged"
+        }
+
+        fn aggregate_id(&self) -> String {
+            String::new()
+        }
+
+        fn timestamp(&self) -> chrono::DateTime<chrono::Utc> {
+            chrono::Utc::now()
+        }
+
+        fn to_json(&self) -> ArcanaResult<String> {
+            Ok(serde_json::to_string(self)?)
+        }
+    }
+
+    #[test]
+    fn domain_event_downcasts_to_concrete_type() {
+        let event: Box<dyn DomainEvent> = Box::new(Pinged);
+        assert!(event.downcast_ref::<Pinged>().is_some());
+        assert!(event.downcast_ref::<crate::UserCreated>().is_none());
+    }
+
     #[test]
     fn health_status_is_healthy_returns_true_for_healthy() {
         assert!(HealthStatus::Healthy.is_healthy());
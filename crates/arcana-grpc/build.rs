@@ -13,6 +13,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "../../proto/health.proto",
                 "../../proto/repository_service.proto",
                 "../../proto/jobs.proto",
+                "../../proto/audit_service.proto",
             ],
             &["../../proto"],
         )?;
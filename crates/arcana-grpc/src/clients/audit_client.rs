@@ -0,0 +1,174 @@
+//! Remote audit service client via gRPC.
+
+use crate::interceptors::RequestContextForwarder;
+use crate::proto::{audit, common};
+use arcana_core::{ArcanaError, ArcanaResult, AuditChainBreak, AuditLogId, UserId};
+use arcana_service::dto::{AuditChainQuery, AuditChainReport, AuditLogListResponse, AuditLogQuery, AuditLogResponse};
+use arcana_service::AuditService;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tonic::codegen::InterceptedService;
+use tonic::transport::Channel;
+use tracing::debug;
+
+/// Remote audit service client that communicates via gRPC.
+///
+/// NOTE: The service layer requires the caller's bearer token, which this
+/// client does not forward yet, so queries are rejected as unauthenticated
+/// until caller credentials are propagated.
+pub struct RemoteAuditServiceClient {
+    client: audit::audit_service_client::AuditServiceClient<InterceptedService<Channel, RequestContextForwarder>>,
+}
+
+impl RemoteAuditServiceClient {
+    /// Creates a new remote audit service client.
+    pub async fn connect(addr: &str) -> ArcanaResult<Self> {
+        let channel = Channel::from_shared(addr.to_string())
+            .map_err(|e| ArcanaError::Internal(format!("Failed to connect to audit service: {}", e)))?
+            .connect()
+            .await
+            .map_err(|e| ArcanaError::Internal(format!("Failed to connect to audit service: {}", e)))?;
+
+        Ok(Self::from_channel(channel))
+    }
+
+    /// Creates from an existing channel.
+    ///
+    /// Calls forward the IP address and user agent of the current request.
+    pub fn from_channel(channel: Channel) -> Self {
+        Self {
+            client: audit::audit_service_client::AuditServiceClient::with_interceptor(channel, RequestContextForwarder),
+        }
+    }
+}
+
+#[async_trait]
+impl AuditService for RemoteAuditServiceClient {
+    async fn query_audit_logs(&self, query: AuditLogQuery) -> ArcanaResult<AuditLogListResponse> {
+        debug!("Remote QueryAuditLogs: {:?}", query);
+
+        let response = self
+            .client
+            .clone()
+            .query_audit_logs(audit::QueryAuditLogsRequest {
+                user_id: query.user_id.map(|id| id.to_string()),
+                action: query.action.map(|action| action.to_string()),
+                resource_type: query.resource_type,
+                success: query.success,
+                from: query.from.map(to_proto_timestamp),
+                to: query.to.map(to_proto_timestamp),
+                cursor: query.cursor,
+                limit: query.limit.map(|limit| limit as u32),
+            })
+            .await
+            .map_err(map_grpc_error)?
+            .into_inner();
+
+        Ok(AuditLogListResponse {
+            entries: response
+                .entries
+                .iter()
+                .map(from_proto_entry)
+                .collect::<ArcanaResult<_>>()?,
+            next_cursor: response.next_cursor,
+        })
+    }
+
+    async fn verify_audit_chain(&self, query: AuditChainQuery) -> ArcanaResult<AuditChainReport> {
+        debug!("Remote VerifyAuditChain: {:?}", query);
+
+        let response = self
+            .client
+            .clone()
+            .verify_audit_chain(audit::VerifyAuditChainRequest {
+                from: query.from.map(to_proto_timestamp),
+                to: query.to.map(to_proto_timestamp),
+            })
+            .await
+            .map_err(map_grpc_error)?
+            .into_inner();
+
+        Ok(AuditChainReport {
+            intact: response.intact,
+            checked: response.checked,
+            first_sequence: response.first_sequence,
+            last_sequence: response.last_sequence,
+            broken_link: response.broken_link.as_ref().map(from_proto_break).transpose()?,
+        })
+    }
+}
+
+/// Creates a shareable audit service client.
+pub async fn create_remote_audit_service(addr: &str) -> ArcanaResult<Arc<dyn AuditService>> {
+    let client = RemoteAuditServiceClient::connect(addr).await?;
+    Ok(Arc::new(client))
+}
+
+// Helper functions
+
+fn map_grpc_error(status: tonic::Status) -> ArcanaError {
+    match status.code() {
+        tonic::Code::InvalidArgument => ArcanaError::Validation(status.message().to_string()),
+        tonic::Code::Unauthenticated => ArcanaError::Unauthorized(status.message().to_string()),
+        tonic::Code::PermissionDenied => ArcanaError::Forbidden(status.message().to_string()),
+        _ => ArcanaError::Internal(format!("gRPC error: {}", status.message())),
+    }
+}
+
+fn to_proto_timestamp(dt: chrono::DateTime<chrono::Utc>) -> common::Timestamp {
+    common::Timestamp {
+        seconds: dt.timestamp(),
+        nanos: dt.timestamp_subsec_nanos() as i32,
+    }
+}
+
+fn from_proto_entry(entry: &audit::AuditLogEntry) -> ArcanaResult<AuditLogResponse> {
+    let id = AuditLogId::parse(&entry.id)
+        .map_err(|e| ArcanaError::Internal(format!("Invalid audit log ID from service: {}", e)))?;
+    let user_id = entry
+        .user_id
+        .as_deref()
+        .map(UserId::parse)
+        .transpose()
+        .map_err(|e| ArcanaError::Internal(format!("Invalid user ID from service: {}", e)))?;
+    let details = entry
+        .details
+        .as_deref()
+        .map(serde_json::from_str)
+        .transpose()
+        .map_err(|e| ArcanaError::Internal(format!("Invalid audit log details from service: {}", e)))?;
+
+    Ok(AuditLogResponse {
+        id,
+        user_id,
+        action: entry.action.parse()?,
+        resource_type: entry.resource_type.clone(),
+        resource_id: entry.resource_id.clone(),
+        details,
+        ip_address: entry.ip_address.clone(),
+        user_agent: entry.user_agent.clone(),
+        success: entry.success,
+        error_message: entry.error_message.clone(),
+        timestamp: entry
+            .timestamp
+            .as_ref()
+            .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32))
+            .unwrap_or_else(chrono::Utc::now),
+        sequence: entry.sequence,
+        prev_hash: entry.prev_hash.clone(),
+        hash: entry.hash.clone(),
+    })
+}
+
+fn from_proto_break(broken: &audit::AuditChainBreak) -> ArcanaResult<AuditChainBreak> {
+    Ok(AuditChainBreak {
+        sequence: broken.sequence,
+        id: broken
+            .id
+            .as_deref()
+            .map(AuditLogId::parse)
+            .transpose()
+            .map_err(|e| ArcanaError::Internal(format!("Invalid audit log ID from service: {}", e)))?,
+        reason: broken.reason.parse()?,
+    })
+}
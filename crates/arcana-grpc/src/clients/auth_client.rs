@@ -1,37 +1,51 @@
 //! Remote auth service client via gRPC.
 
-use crate::proto::{auth, user as user_proto};
+use crate::interceptors::RequestContextForwarder;
+use crate::proto::{auth, common, user as user_proto};
 use arcana_core::{ArcanaError, ArcanaResult, UserId};
 use arcana_security::Claims;
 use arcana_service::dto::{
-    AuthResponse, AuthUserInfo, LoginRequest, MessageResponse, RefreshTokenRequest, RegisterRequest,
+    AuthResponse, AuthUserInfo, LoginRequest, LoginResponse, MessageResponse,
+    MfaChallengeResponse, MfaLoginRequest, PasswordResetConfirmRequest, PasswordResetRequest,
+    RecoveryCodesResponse, RefreshTokenRequest, RegisterRequest, ResendVerificationRequest,
+    SessionInfo, TotpCodeRequest, TotpEnrollmentResponse, VerifyEmailRequest,
 };
 use arcana_service::AuthService;
 use async_trait::async_trait;
 use chrono::{Duration, Utc};
 use std::sync::Arc;
+use tonic::codegen::InterceptedService;
 use tonic::transport::Channel;
 use tracing::debug;
 
 /// Remote auth service client that communicates via gRPC.
+///
+/// NOTE: Calls acting on the current user (logout, session and MFA management) are
+/// authenticated by the service layer from the caller's bearer token, which
+/// this client does not forward yet, so they are rejected as unauthenticated
+/// until caller credentials are propagated.
 pub struct RemoteAuthServiceClient {
-    client: auth::auth_service_client::AuthServiceClient<Channel>,
+    client: auth::auth_service_client::AuthServiceClient<InterceptedService<Channel, RequestContextForwarder>>,
 }
 
 impl RemoteAuthServiceClient {
     /// Creates a new remote auth service client.
     pub async fn connect(addr: &str) -> ArcanaResult<Self> {
-        let client = auth::auth_service_client::AuthServiceClient::connect(addr.to_string())
+        let channel = Channel::from_shared(addr.to_string())
+            .map_err(|e| ArcanaError::Internal(format!("Failed to connect to auth service: {}", e)))?
+            .connect()
             .await
             .map_err(|e| ArcanaError::Internal(format!("Failed to connect to auth service: {}", e)))?;
 
-        Ok(Self { client })
+        Ok(Self::from_channel(channel))
     }
 
     /// Creates from an existing channel.
+    ///
+    /// Calls forward the IP address and user agent of the current request.
     pub fn from_channel(channel: Channel) -> Self {
         Self {
-            client: auth::auth_service_client::AuthServiceClient::new(channel),
+            client: auth::auth_service_client::AuthServiceClient::with_interceptor(channel, RequestContextForwarder),
         }
     }
 }
@@ -54,18 +68,19 @@ impl AuthService for RemoteAuthServiceClient {
             .clone()
             .register(proto_request)
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(from_proto_auth_response(response.into_inner()))
     }
 
-    async fn login(&self, request: LoginRequest) -> ArcanaResult<AuthResponse> {
+    async fn login(&self, request: LoginRequest) -> ArcanaResult<LoginResponse> {
         debug!("Remote Login: {}", request.username_or_email);
 
         let proto_request = auth::LoginRequest {
             username_or_email: request.username_or_email,
             password: request.password,
             device_id: request.device_id,
+            ip_address: request.ip_address,
         };
 
         let response = self
@@ -73,7 +88,36 @@ impl AuthService for RemoteAuthServiceClient {
             .clone()
             .login(proto_request)
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
+
+        match response.into_inner().result {
+            Some(auth::login_response::Result::Authenticated(response)) => {
+                Ok(LoginResponse::Authenticated(from_proto_auth_response(response)))
+            }
+            Some(auth::login_response::Result::MfaRequired(challenge)) => {
+                Ok(LoginResponse::MfaRequired(MfaChallengeResponse {
+                    mfa_required: true,
+                    mfa_token: challenge.mfa_token,
+                    expires_in: challenge.expires_in,
+                }))
+            }
+            None => Err(ArcanaError::Internal("Empty login response".to_string())),
+        }
+    }
+
+    async fn complete_mfa_login(&self, request: MfaLoginRequest) -> ArcanaResult<AuthResponse> {
+        debug!("Remote CompleteMfaLogin");
+
+        let response = self
+            .client
+            .clone()
+            .complete_mfa_login(auth::CompleteMfaLoginRequest {
+                mfa_token: request.mfa_token,
+                code: request.code,
+                device_id: request.device_id,
+            })
+            .await
+            .map_err(map_grpc_error)?;
 
         Ok(from_proto_auth_response(response.into_inner()))
     }
@@ -90,7 +134,7 @@ impl AuthService for RemoteAuthServiceClient {
             .clone()
             .refresh_token(proto_request)
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(from_proto_auth_response(response.into_inner()))
     }
@@ -107,7 +151,7 @@ impl AuthService for RemoteAuthServiceClient {
             .clone()
             .validate_token(proto_request)
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let inner = response.into_inner();
 
@@ -142,8 +186,8 @@ impl AuthService for RemoteAuthServiceClient {
         ))
     }
 
-    async fn logout(&self, user_id: UserId) -> ArcanaResult<MessageResponse> {
-        debug!("Remote Logout: {}", user_id);
+    async fn logout(&self, claims: &Claims) -> ArcanaResult<MessageResponse> {
+        debug!("Remote Logout: {}", claims.sub);
 
         let proto_request = auth::LogoutRequest { all_sessions: false };
 
@@ -151,7 +195,7 @@ impl AuthService for RemoteAuthServiceClient {
             .clone()
             .logout(proto_request)
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(MessageResponse::new("Successfully logged out"))
     }
@@ -174,6 +218,186 @@ impl AuthService for RemoteAuthServiceClient {
             last_name: None,
         })
     }
+
+    async fn list_sessions(&self, claims: &Claims) -> ArcanaResult<Vec<SessionInfo>> {
+        debug!("Remote ListSessions: {}", claims.sub);
+
+        let response = self
+            .client
+            .clone()
+            .list_sessions(common::Empty {})
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(response
+            .into_inner()
+            .sessions
+            .into_iter()
+            .map(from_proto_session)
+            .collect())
+    }
+
+    async fn revoke_session(&self, claims: &Claims, session_id: &str) -> ArcanaResult<MessageResponse> {
+        debug!("Remote RevokeSession: {} ({})", session_id, claims.sub);
+
+        self.client
+            .clone()
+            .revoke_session(auth::RevokeSessionRequest {
+                session_id: session_id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(MessageResponse::new("Session revoked"))
+    }
+
+    async fn revoke_other_sessions(&self, claims: &Claims) -> ArcanaResult<MessageResponse> {
+        debug!("Remote RevokeOtherSessions: {}", claims.sub);
+
+        let response = self
+            .client
+            .clone()
+            .revoke_other_sessions(common::Empty {})
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(MessageResponse::new(response.into_inner().message))
+    }
+
+    async fn verify_email(&self, request: VerifyEmailRequest) -> ArcanaResult<MessageResponse> {
+        debug!("Remote VerifyEmail");
+
+        let response = self
+            .client
+            .clone()
+            .verify_email(auth::VerifyEmailRequest {
+                token: request.token,
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(MessageResponse::new(response.into_inner().message))
+    }
+
+    async fn resend_verification(
+        &self,
+        request: ResendVerificationRequest,
+    ) -> ArcanaResult<MessageResponse> {
+        debug!("Remote ResendVerification");
+
+        let response = self
+            .client
+            .clone()
+            .resend_verification(auth::ResendVerificationRequest {
+                email: request.email,
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(MessageResponse::new(response.into_inner().message))
+    }
+
+    async fn forgot_password(&self, request: PasswordResetRequest) -> ArcanaResult<MessageResponse> {
+        debug!("Remote ForgotPassword");
+
+        let response = self
+            .client
+            .clone()
+            .forgot_password(auth::ForgotPasswordRequest {
+                email: request.email,
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(MessageResponse::new(response.into_inner().message))
+    }
+
+    async fn reset_password(
+        &self,
+        request: PasswordResetConfirmRequest,
+    ) -> ArcanaResult<MessageResponse> {
+        debug!("Remote ResetPassword");
+
+        let response = self
+            .client
+            .clone()
+            .reset_password(auth::ResetPasswordRequest {
+                token: request.token,
+                new_password: request.new_password,
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(MessageResponse::new(response.into_inner().message))
+    }
+
+    async fn enroll_totp(&self, claims: &Claims) -> ArcanaResult<TotpEnrollmentResponse> {
+        debug!("Remote EnrollTotp: {}", claims.sub);
+
+        let response = self
+            .client
+            .clone()
+            .enroll_totp(common::Empty {})
+            .await
+            .map_err(map_grpc_error)?
+            .into_inner();
+
+        Ok(TotpEnrollmentResponse {
+            secret: response.secret,
+            otpauth_uri: response.otpauth_uri,
+        })
+    }
+
+    async fn enable_totp(
+        &self,
+        claims: &Claims,
+        request: TotpCodeRequest,
+    ) -> ArcanaResult<RecoveryCodesResponse> {
+        debug!("Remote EnableTotp: {}", claims.sub);
+
+        let response = self
+            .client
+            .clone()
+            .enable_totp(auth::TotpCodeRequest { code: request.code })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(RecoveryCodesResponse {
+            recovery_codes: response.into_inner().recovery_codes,
+        })
+    }
+
+    async fn disable_totp(&self, claims: &Claims, request: TotpCodeRequest) -> ArcanaResult<MessageResponse> {
+        debug!("Remote DisableTotp: {}", claims.sub);
+
+        let response = self
+            .client
+            .clone()
+            .disable_totp(auth::TotpCodeRequest { code: request.code })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(MessageResponse::new(response.into_inner().message))
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        claims: &Claims,
+        request: TotpCodeRequest,
+    ) -> ArcanaResult<RecoveryCodesResponse> {
+        debug!("Remote RegenerateRecoveryCodes: {}", claims.sub);
+
+        let response = self
+            .client
+            .clone()
+            .regenerate_recovery_codes(auth::TotpCodeRequest { code: request.code })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(RecoveryCodesResponse {
+            recovery_codes: response.into_inner().recovery_codes,
+        })
+    }
 }
 
 /// Creates a shareable auth service client.
@@ -192,8 +416,10 @@ fn map_grpc_error(status: tonic::Status) -> ArcanaError {
         },
         tonic::Code::InvalidArgument => ArcanaError::Validation(status.message().to_string()),
         tonic::Code::AlreadyExists => ArcanaError::Conflict(status.message().to_string()),
+        tonic::Code::FailedPrecondition => ArcanaError::BusinessRule(status.message().to_string()),
         tonic::Code::Unauthenticated => ArcanaError::InvalidCredentials,
         tonic::Code::PermissionDenied => ArcanaError::Forbidden(status.message().to_string()),
+        tonic::Code::ResourceExhausted => ArcanaError::RateLimitExceeded,
         _ => ArcanaError::Internal(format!("gRPC error: {}", status.message())),
     }
 }
@@ -224,6 +450,23 @@ fn from_proto_auth_response(response: auth::AuthResponse) -> AuthResponse {
     }
 }
 
+fn from_proto_session(session: auth::SessionInfo) -> SessionInfo {
+    let to_datetime = |t: Option<common::Timestamp>| {
+        t.and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32))
+            .unwrap_or_else(Utc::now)
+    };
+
+    SessionInfo {
+        id: session.id,
+        device_id: session.device_id,
+        user_agent: session.user_agent,
+        ip_address: session.ip_address,
+        last_active_at: to_datetime(session.last_active_at),
+        expires_at: to_datetime(session.expires_at),
+        current: session.current,
+    }
+}
+
 fn from_proto_role(role: user_proto::UserRole) -> arcana_core::UserRole {
     match role {
         user_proto::UserRole::User | user_proto::UserRole::Unspecified => arcana_core::UserRole::User,
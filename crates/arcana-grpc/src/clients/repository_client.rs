@@ -3,9 +3,13 @@
 use crate::proto::{common, repository, user as user_proto};
 use crate::tls::build_client_tls_from_config;
 use arcana_config::SecurityConfig;
-use arcana_core::{ArcanaError, ArcanaResult, Page, PageRequest, UserId};
-use arcana_core::{Email, User, UserRole, UserStatus};
-use arcana_repository::UserRepository;
+use arcana_core::{ArcanaError, ArcanaResult, OAuthTokenId, Page, PageRequest, Tenant, TenantId, UserId};
+use arcana_core::{Email, OAuthToken, User, UserRole, UserStatus};
+use arcana_core::{AuditLog, AuditLogCursor, AuditLogFilter, AuditLogId, PasswordResetToken, PasswordResetTokenId, TotpCredential};
+use arcana_repository::{
+    AuditLogRepository, MfaRepository, OAuthTokenRepository, PasswordResetTokenRepository, TenantRepository,
+    UserRepository,
+};
 use async_trait::async_trait;
 use shaku::Component;
 use std::sync::Arc;
@@ -96,12 +100,12 @@ impl UserRepository for RemoteUserRepository {
                 user_id: id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().user.map(|u| from_proto_user_data(&u)))
     }
 
-    async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>> {
+    async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>> {
         debug!("Remote FindUserByUsername: {}", username);
 
         let response = self
@@ -109,14 +113,15 @@ impl UserRepository for RemoteUserRepository {
             .clone()
             .find_user_by_username(repository::FindUserByUsernameRequest {
                 username: username.to_string(),
+                tenant_id: tenant_id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().user.map(|u| from_proto_user_data(&u)))
     }
 
-    async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>> {
+    async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>> {
         debug!("Remote FindUserByEmail: {}", email);
 
         let response = self
@@ -124,14 +129,15 @@ impl UserRepository for RemoteUserRepository {
             .clone()
             .find_user_by_email(repository::FindUserByEmailRequest {
                 email: email.to_string(),
+                tenant_id: tenant_id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().user.map(|u| from_proto_user_data(&u)))
     }
 
-    async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>> {
+    async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>> {
         debug!("Remote FindUserByUsernameOrEmail: {}", identifier);
 
         let response = self
@@ -139,14 +145,15 @@ impl UserRepository for RemoteUserRepository {
             .clone()
             .find_user_by_username_or_email(repository::FindUserByUsernameOrEmailRequest {
                 identifier: identifier.to_string(),
+                tenant_id: tenant_id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().user.map(|u| from_proto_user_data(&u)))
     }
 
-    async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool> {
+    async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool> {
         debug!("Remote ExistsByUsername: {}", username);
 
         let response = self
@@ -154,14 +161,15 @@ impl UserRepository for RemoteUserRepository {
             .clone()
             .exists_by_username(repository::ExistsByUsernameRequest {
                 username: username.to_string(),
+                tenant_id: tenant_id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().exists)
     }
 
-    async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool> {
+    async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool> {
         debug!("Remote ExistsByEmail: {}", email);
 
         let response = self
@@ -169,14 +177,15 @@ impl UserRepository for RemoteUserRepository {
             .clone()
             .exists_by_email(repository::ExistsByEmailRequest {
                 email: email.to_string(),
+                tenant_id: tenant_id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().exists)
     }
 
-    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<User>> {
+    async fn find_all(&self, tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>> {
         debug!("Remote FindAllUsers");
 
         let response = self
@@ -187,9 +196,10 @@ impl UserRepository for RemoteUserRepository {
                     page: page.page as i32,
                     size: page.size as i32,
                 }),
+                tenant_id: tenant_id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let inner = response.into_inner();
         let page_info = inner.page_info.unwrap_or_default();
@@ -203,7 +213,7 @@ impl UserRepository for RemoteUserRepository {
         ))
     }
 
-    async fn find_by_role(&self, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
+    async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
         debug!("Remote FindUsersByRole: {:?}", role);
 
         let response = self
@@ -215,9 +225,10 @@ impl UserRepository for RemoteUserRepository {
                     page: page.page as i32,
                     size: page.size as i32,
                 }),
+                tenant_id: tenant_id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let inner = response.into_inner();
         let page_info = inner.page_info.unwrap_or_default();
@@ -241,7 +252,7 @@ impl UserRepository for RemoteUserRepository {
                 user: Some(to_proto_user_data(user)),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let saved = response
             .into_inner()
@@ -261,7 +272,7 @@ impl UserRepository for RemoteUserRepository {
                 user: Some(to_proto_user_data(user)),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let updated = response
             .into_inner()
@@ -281,25 +292,27 @@ impl UserRepository for RemoteUserRepository {
                 user_id: id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().deleted)
     }
 
-    async fn count(&self) -> ArcanaResult<u64> {
+    async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64> {
         debug!("Remote CountUsers");
 
         let response = self
             .client
             .clone()
-            .count_users(repository::CountUsersRequest {})
+            .count_users(repository::CountUsersRequest {
+                tenant_id: tenant_id.to_string(),
+            })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().count)
     }
 
-    async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64> {
+    async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64> {
         debug!("Remote CountUsersByRole: {:?}", role);
 
         let response = self
@@ -307,12 +320,20 @@ impl UserRepository for RemoteUserRepository {
             .clone()
             .count_users_by_role(repository::CountUsersByRoleRequest {
                 role: to_proto_role(role) as i32,
+                tenant_id: tenant_id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().count)
     }
+
+    /// The outbox is monolith-only: the relay runs only in monolithic
+    /// deployments, so the service layer publishes the events of remote
+    /// changes itself.
+    fn stores_events(&self) -> bool {
+        false
+    }
 }
 
 /// Creates a shareable remote user repository.
@@ -330,6 +351,712 @@ pub async fn create_remote_user_repository_with_tls(
     Ok(Arc::new(client))
 }
 
+/// Remote tenant repository client that communicates via gRPC.
+///
+/// Shares the repository service channel with [`RemoteUserRepository`].
+#[derive(Component, Clone)]
+#[shaku(interface = TenantRepository)]
+pub struct RemoteTenantRepository {
+    client: repository::repository_service_client::RepositoryServiceClient<Channel>,
+}
+
+impl RemoteTenantRepository {
+    /// Creates from an existing gRPC client.
+    pub fn with_client(client: repository::repository_service_client::RepositoryServiceClient<Channel>) -> Self {
+        Self { client }
+    }
+
+    /// Returns the gRPC client.
+    ///
+    /// This is used for Shaku component parameter extraction.
+    #[must_use]
+    pub fn client(&self) -> &repository::repository_service_client::RepositoryServiceClient<Channel> {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl TenantRepository for RemoteTenantRepository {
+    async fn find_by_id(&self, id: TenantId) -> ArcanaResult<Option<Tenant>> {
+        debug!("Remote FindTenantById: {}", id);
+
+        let response = self
+            .client
+            .clone()
+            .find_tenant_by_id(repository::FindTenantByIdRequest {
+                tenant_id: id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response.into_inner().tenant.map(|t| from_proto_tenant_data(&t)).transpose()
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> ArcanaResult<Option<Tenant>> {
+        debug!("Remote FindTenantBySlug: {}", slug);
+
+        let response = self
+            .client
+            .clone()
+            .find_tenant_by_slug(repository::FindTenantBySlugRequest {
+                slug: slug.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response.into_inner().tenant.map(|t| from_proto_tenant_data(&t)).transpose()
+    }
+
+    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<Tenant>> {
+        debug!("Remote FindAllTenants");
+
+        let response = self
+            .client
+            .clone()
+            .find_all_tenants(repository::FindAllTenantsRequest {
+                page: Some(common::PageRequest {
+                    page: page.page as i32,
+                    size: page.size as i32,
+                }),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        let inner = response.into_inner();
+        let page_info = inner.page_info.unwrap_or_default();
+        let tenants = inner
+            .tenants
+            .iter()
+            .map(from_proto_tenant_data)
+            .collect::<ArcanaResult<Vec<_>>>()?;
+
+        Ok(Page::new(
+            tenants,
+            page_info.page as usize,
+            page_info.size as usize,
+            page_info.total_elements as u64,
+        ))
+    }
+
+    async fn save(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+        debug!("Remote SaveTenant: {}", tenant.id);
+
+        let response = self
+            .client
+            .clone()
+            .save_tenant(repository::SaveTenantRequest {
+                tenant: Some(to_proto_tenant_data(tenant)),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        let saved = response
+            .into_inner()
+            .tenant
+            .ok_or_else(|| ArcanaError::Internal("No tenant in save response".to_string()))?;
+
+        from_proto_tenant_data(&saved)
+    }
+
+    async fn update(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+        debug!("Remote UpdateTenant: {}", tenant.id);
+
+        let response = self
+            .client
+            .clone()
+            .update_tenant(repository::UpdateTenantRequest {
+                tenant: Some(to_proto_tenant_data(tenant)),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        let updated = response
+            .into_inner()
+            .tenant
+            .ok_or_else(|| ArcanaError::Internal("No tenant in update response".to_string()))?;
+
+        from_proto_tenant_data(&updated)
+    }
+}
+
+/// Remote OAuth token repository client that communicates via gRPC.
+///
+/// Shares the repository service channel with [`RemoteUserRepository`].
+#[derive(Component, Clone)]
+#[shaku(interface = OAuthTokenRepository)]
+pub struct RemoteOAuthTokenRepository {
+    client: repository::repository_service_client::RepositoryServiceClient<Channel>,
+}
+
+impl RemoteOAuthTokenRepository {
+    /// Creates from an existing gRPC client.
+    pub fn with_client(client: repository::repository_service_client::RepositoryServiceClient<Channel>) -> Self {
+        Self { client }
+    }
+
+    /// Returns the gRPC client.
+    ///
+    /// This is used for Shaku component parameter extraction.
+    #[must_use]
+    pub fn client(&self) -> &repository::repository_service_client::RepositoryServiceClient<Channel> {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl OAuthTokenRepository for RemoteOAuthTokenRepository {
+    async fn find_by_id(&self, id: OAuthTokenId) -> ArcanaResult<Option<OAuthToken>> {
+        debug!("Remote FindOAuthTokenById: {}", id);
+
+        let response = self
+            .client
+            .clone()
+            .find_o_auth_token_by_id(repository::FindOAuthTokenByIdRequest {
+                token_id: id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response.into_inner().token.map(|t| from_proto_oauth_token_data(&t)).transpose()
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<OAuthToken>> {
+        debug!("Remote FindOAuthTokenByHash");
+
+        let response = self
+            .client
+            .clone()
+            .find_o_auth_token_by_hash(repository::FindOAuthTokenByHashRequest {
+                token_hash: token_hash.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response.into_inner().token.map(|t| from_proto_oauth_token_data(&t)).transpose()
+    }
+
+    async fn save(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken> {
+        debug!("Remote SaveOAuthToken: {}", token.id);
+
+        let response = self
+            .client
+            .clone()
+            .save_o_auth_token(repository::SaveOAuthTokenRequest {
+                token: Some(to_proto_oauth_token_data(token)),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        let saved = response
+            .into_inner()
+            .token
+            .ok_or_else(|| ArcanaError::Internal("No token in save response".to_string()))?;
+
+        from_proto_oauth_token_data(&saved)
+    }
+
+    async fn update(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken> {
+        debug!("Remote UpdateOAuthToken: {}", token.id);
+
+        let response = self
+            .client
+            .clone()
+            .update_o_auth_token(repository::UpdateOAuthTokenRequest {
+                token: Some(to_proto_oauth_token_data(token)),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        let updated = response
+            .into_inner()
+            .token
+            .ok_or_else(|| ArcanaError::Internal("No token in update response".to_string()))?;
+
+        from_proto_oauth_token_data(&updated)
+    }
+
+    async fn find_active_by_user(&self, user_id: UserId) -> ArcanaResult<Vec<OAuthToken>> {
+        debug!("Remote FindActiveOAuthTokensByUser: {}", user_id);
+
+        let response = self
+            .client
+            .clone()
+            .find_active_o_auth_tokens_by_user(repository::FindActiveOAuthTokensByUserRequest {
+                user_id: user_id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response
+            .into_inner()
+            .tokens
+            .iter()
+            .map(from_proto_oauth_token_data)
+            .collect()
+    }
+
+    async fn revoke_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64> {
+        debug!("Remote RevokeAllOAuthTokensByUser: {}", user_id);
+
+        let response = self
+            .client
+            .clone()
+            .revoke_all_o_auth_tokens_by_user(repository::RevokeAllOAuthTokensByUserRequest {
+                user_id: user_id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(response.into_inner().count)
+    }
+
+    async fn mark_rotated(&self, id: OAuthTokenId) -> ArcanaResult<bool> {
+        debug!("Remote MarkOAuthTokenRotated: {}", id);
+
+        let response = self
+            .client
+            .clone()
+            .mark_o_auth_token_rotated(repository::MarkOAuthTokenRotatedRequest {
+                token_id: id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(response.into_inner().rotated)
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> ArcanaResult<u64> {
+        debug!("Remote RevokeOAuthTokenFamily: {}", family_id);
+
+        let response = self
+            .client
+            .clone()
+            .revoke_o_auth_token_family(repository::RevokeOAuthTokenFamilyRequest {
+                family_id: family_id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(response.into_inner().count)
+    }
+}
+
+/// Remote password reset token repository client that communicates via gRPC.
+///
+/// Shares the repository service channel with [`RemoteUserRepository`].
+#[derive(Component, Clone)]
+#[shaku(interface = PasswordResetTokenRepository)]
+pub struct RemotePasswordResetTokenRepository {
+    client: repository::repository_service_client::RepositoryServiceClient<Channel>,
+}
+
+impl RemotePasswordResetTokenRepository {
+    /// Creates from an existing gRPC client.
+    pub fn with_client(client: repository::repository_service_client::RepositoryServiceClient<Channel>) -> Self {
+        Self { client }
+    }
+
+    /// Returns the gRPC client.
+    ///
+    /// This is used for Shaku component parameter extraction.
+    #[must_use]
+    pub fn client(&self) -> &repository::repository_service_client::RepositoryServiceClient<Channel> {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl PasswordResetTokenRepository for RemotePasswordResetTokenRepository {
+    async fn find_by_id(&self, id: PasswordResetTokenId) -> ArcanaResult<Option<PasswordResetToken>> {
+        debug!("Remote FindPasswordResetTokenById: {}", id);
+
+        let response = self
+            .client
+            .clone()
+            .find_password_reset_token_by_id(repository::FindPasswordResetTokenByIdRequest {
+                token_id: id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response
+            .into_inner()
+            .token
+            .map(|t| from_proto_password_reset_token_data(&t))
+            .transpose()
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<PasswordResetToken>> {
+        debug!("Remote FindPasswordResetTokenByHash");
+
+        let response = self
+            .client
+            .clone()
+            .find_password_reset_token_by_hash(repository::FindPasswordResetTokenByHashRequest {
+                token_hash: token_hash.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response
+            .into_inner()
+            .token
+            .map(|t| from_proto_password_reset_token_data(&t))
+            .transpose()
+    }
+
+    async fn save(&self, token: &PasswordResetToken) -> ArcanaResult<PasswordResetToken> {
+        debug!("Remote SavePasswordResetToken: {}", token.id);
+
+        let response = self
+            .client
+            .clone()
+            .save_password_reset_token(repository::SavePasswordResetTokenRequest {
+                token: Some(to_proto_password_reset_token_data(token)),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        let saved = response
+            .into_inner()
+            .token
+            .ok_or_else(|| ArcanaError::Internal("No token in save response".to_string()))?;
+
+        from_proto_password_reset_token_data(&saved)
+    }
+
+    async fn mark_used(&self, id: PasswordResetTokenId) -> ArcanaResult<bool> {
+        debug!("Remote MarkPasswordResetTokenUsed: {}", id);
+
+        let response = self
+            .client
+            .clone()
+            .mark_password_reset_token_used(repository::MarkPasswordResetTokenUsedRequest {
+                token_id: id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(response.into_inner().used)
+    }
+
+    async fn invalidate_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64> {
+        debug!("Remote InvalidatePasswordResetTokensByUser: {}", user_id);
+
+        let response = self
+            .client
+            .clone()
+            .invalidate_password_reset_tokens_by_user(
+                repository::InvalidatePasswordResetTokensByUserRequest {
+                    user_id: user_id.to_string(),
+                },
+            )
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(response.into_inner().count)
+    }
+}
+
+/// Remote MFA repository client that communicates via gRPC.
+///
+/// Shares the repository service channel with [`RemoteUserRepository`].
+#[derive(Component, Clone)]
+#[shaku(interface = MfaRepository)]
+pub struct RemoteMfaRepository {
+    client: repository::repository_service_client::RepositoryServiceClient<Channel>,
+}
+
+impl RemoteMfaRepository {
+    /// Creates from an existing gRPC client.
+    pub fn with_client(client: repository::repository_service_client::RepositoryServiceClient<Channel>) -> Self {
+        Self { client }
+    }
+
+    /// Returns the gRPC client.
+    ///
+    /// This is used for Shaku component parameter extraction.
+    #[must_use]
+    pub fn client(&self) -> &repository::repository_service_client::RepositoryServiceClient<Channel> {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl MfaRepository for RemoteMfaRepository {
+    async fn find_totp_by_user(&self, user_id: UserId) -> ArcanaResult<Option<TotpCredential>> {
+        debug!("Remote FindTotpCredentialByUser: {}", user_id);
+
+        let response = self
+            .client
+            .clone()
+            .find_totp_credential_by_user(repository::FindTotpCredentialByUserRequest {
+                user_id: user_id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response
+            .into_inner()
+            .credential
+            .map(|c| from_proto_totp_credential_data(&c))
+            .transpose()
+    }
+
+    async fn save_totp(&self, credential: &TotpCredential) -> ArcanaResult<TotpCredential> {
+        debug!("Remote SaveTotpCredential: {}", credential.user_id);
+
+        let response = self
+            .client
+            .clone()
+            .save_totp_credential(repository::SaveTotpCredentialRequest {
+                credential: Some(to_proto_totp_credential_data(credential)),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        let saved = response
+            .into_inner()
+            .credential
+            .ok_or_else(|| ArcanaError::Internal("No credential in save response".to_string()))?;
+
+        from_proto_totp_credential_data(&saved)
+    }
+
+    async fn record_totp_step(&self, user_id: UserId, step: i64) -> ArcanaResult<bool> {
+        debug!("Remote RecordTotpStep: {}", user_id);
+
+        let response = self
+            .client
+            .clone()
+            .record_totp_step(repository::RecordTotpStepRequest {
+                user_id: user_id.to_string(),
+                step,
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(response.into_inner().recorded)
+    }
+
+    async fn delete_by_user(&self, user_id: UserId) -> ArcanaResult<bool> {
+        debug!("Remote DeleteMfaByUser: {}", user_id);
+
+        let response = self
+            .client
+            .clone()
+            .delete_mfa_by_user(repository::DeleteMfaByUserRequest {
+                user_id: user_id.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(response.into_inner().deleted)
+    }
+
+    async fn replace_recovery_codes(&self, user_id: UserId, code_hashes: &[String]) -> ArcanaResult<()> {
+        debug!("Remote ReplaceRecoveryCodes: {}", user_id);
+
+        self.client
+            .clone()
+            .replace_recovery_codes(repository::ReplaceRecoveryCodesRequest {
+                user_id: user_id.to_string(),
+                code_hashes: code_hashes.to_vec(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(())
+    }
+
+    async fn consume_recovery_code(&self, user_id: UserId, code_hash: &str) -> ArcanaResult<bool> {
+        debug!("Remote ConsumeRecoveryCode: {}", user_id);
+
+        let response = self
+            .client
+            .clone()
+            .consume_recovery_code(repository::ConsumeRecoveryCodeRequest {
+                user_id: user_id.to_string(),
+                code_hash: code_hash.to_string(),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        Ok(response.into_inner().consumed)
+    }
+}
+
+/// Remote audit log repository client that communicates via gRPC.
+///
+/// Shares the repository service channel with [`RemoteUserRepository`].
+#[derive(Component, Clone)]
+#[shaku(interface = AuditLogRepository)]
+pub struct RemoteAuditLogRepository {
+    client: repository::repository_service_client::RepositoryServiceClient<Channel>,
+}
+
+impl RemoteAuditLogRepository {
+    /// Creates from an existing gRPC client.
+    pub fn with_client(client: repository::repository_service_client::RepositoryServiceClient<Channel>) -> Self {
+        Self { client }
+    }
+
+    /// Returns the gRPC client.
+    ///
+    /// This is used for Shaku component parameter extraction.
+    #[must_use]
+    pub fn client(&self) -> &repository::repository_service_client::RepositoryServiceClient<Channel> {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for RemoteAuditLogRepository {
+    async fn save(&self, entry: &AuditLog) -> ArcanaResult<AuditLog> {
+        debug!("Remote SaveAuditLog: {}", entry.id);
+
+        let response = self
+            .client
+            .clone()
+            .save_audit_log(repository::SaveAuditLogRequest {
+                entry: Some(to_proto_audit_log_data(entry)),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        let saved = response
+            .into_inner()
+            .entry
+            .ok_or_else(|| ArcanaError::Internal("No entry in save response".to_string()))?;
+
+        from_proto_audit_log_data(&saved)
+    }
+
+    async fn find_by_id(&self, id: AuditLogId) -> ArcanaResult<Option<AuditLog>> {
+        debug!("Remote FindAuditLogById: {}", id);
+
+        let response = self
+            .client
+            .clone()
+            .find_audit_log_by_id(repository::FindAuditLogByIdRequest { id: id.to_string() })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response
+            .into_inner()
+            .entry
+            .map(|e| from_proto_audit_log_data(&e))
+            .transpose()
+    }
+
+    async fn find_by_user(&self, user_id: UserId, page: PageRequest) -> ArcanaResult<Page<AuditLog>> {
+        debug!("Remote FindAuditLogsByUser: {}", user_id);
+
+        let response = self
+            .client
+            .clone()
+            .find_audit_logs_by_user(repository::FindAuditLogsByUserRequest {
+                user_id: user_id.to_string(),
+                page: Some(common::PageRequest {
+                    page: page.page as i32,
+                    size: page.size as i32,
+                }),
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        let inner = response.into_inner();
+        let page_info = inner.page_info.unwrap_or_default();
+        let entries = inner
+            .entries
+            .iter()
+            .map(from_proto_audit_log_data)
+            .collect::<ArcanaResult<Vec<_>>>()?;
+
+        Ok(Page::new(
+            entries,
+            page_info.page as usize,
+            page_info.size as usize,
+            page_info.total_elements as u64,
+        ))
+    }
+
+    async fn search(
+        &self,
+        filter: &AuditLogFilter,
+        after: Option<AuditLogCursor>,
+        limit: usize,
+    ) -> ArcanaResult<Vec<AuditLog>> {
+        debug!("Remote SearchAuditLogs: {:?}", filter);
+
+        let response = self
+            .client
+            .clone()
+            .search_audit_logs(repository::SearchAuditLogsRequest {
+                user_id: filter.user_id.map(|id| id.to_string()),
+                action: filter.action.map(|action| action.to_string()),
+                resource_type: filter.resource_type.clone(),
+                success: filter.success,
+                from: filter.from.map(to_proto_timestamp),
+                to: filter.to.map(to_proto_timestamp),
+                after: after.map(|cursor| cursor.to_string()),
+                limit: limit as u32,
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response
+            .into_inner()
+            .entries
+            .iter()
+            .map(from_proto_audit_log_data)
+            .collect()
+    }
+
+    async fn chain_bounds(
+        &self,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ArcanaResult<Option<(u64, u64)>> {
+        debug!("Remote GetAuditChainBounds: {:?}..{:?}", from, to);
+
+        let response = self
+            .client
+            .clone()
+            .get_audit_chain_bounds(repository::GetAuditChainBoundsRequest {
+                from: from.map(to_proto_timestamp),
+                to: to.map(to_proto_timestamp),
+            })
+            .await
+            .map_err(map_grpc_error)?
+            .into_inner();
+
+        Ok(response.first_sequence.zip(response.last_sequence))
+    }
+
+    async fn find_chain(&self, from_sequence: u64, to_sequence: u64, limit: usize) -> ArcanaResult<Vec<AuditLog>> {
+        debug!("Remote FindAuditChain: {}..={}", from_sequence, to_sequence);
+
+        let response = self
+            .client
+            .clone()
+            .find_audit_chain(repository::FindAuditChainRequest {
+                from_sequence,
+                to_sequence,
+                limit: limit as u32,
+            })
+            .await
+            .map_err(map_grpc_error)?;
+
+        response
+            .into_inner()
+            .entries
+            .iter()
+            .map(from_proto_audit_log_data)
+            .collect()
+    }
+}
+
 // Helper functions
 
 fn map_grpc_error(status: tonic::Status) -> ArcanaError {
@@ -348,6 +1075,7 @@ fn map_grpc_error(status: tonic::Status) -> ArcanaError {
 
 fn from_proto_user_data(user: &repository::UserData) -> User {
     let id = UserId::parse(&user.id).unwrap_or_else(|_| UserId::new());
+    let tenant_id = TenantId::parse(&user.tenant_id).unwrap_or_default();
     let email = Email::new_unchecked(user.email.clone());
     let role = from_proto_role(user_proto::UserRole::try_from(user.role).unwrap_or(user_proto::UserRole::User));
     let status = from_proto_status(user_proto::UserStatus::try_from(user.status).unwrap_or(user_proto::UserStatus::Active));
@@ -356,21 +1084,27 @@ fn from_proto_user_data(user: &repository::UserData) -> User {
         .created_at
         .as_ref()
         .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32))
-        .unwrap_or_else(|| chrono::Utc::now());
+        .unwrap_or_else(chrono::Utc::now);
 
     let updated_at = user
         .updated_at
         .as_ref()
         .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32))
-        .unwrap_or_else(|| chrono::Utc::now());
+        .unwrap_or_else(chrono::Utc::now);
 
     let last_login_at = user
         .last_login_at
         .as_ref()
         .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32));
 
+    let locked_until = user
+        .locked_until
+        .as_ref()
+        .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32));
+
     User {
         id,
+        tenant_id,
         username: user.username.clone(),
         email,
         password_hash: user.password_hash.clone(),
@@ -381,6 +1115,7 @@ fn from_proto_user_data(user: &repository::UserData) -> User {
         email_verified: user.email_verified,
         avatar_url: user.avatar_url.clone(),
         last_login_at,
+        locked_until,
         created_at,
         updated_at,
     }
@@ -389,6 +1124,7 @@ fn from_proto_user_data(user: &repository::UserData) -> User {
 fn to_proto_user_data(user: &User) -> repository::UserData {
     repository::UserData {
         id: user.id.to_string(),
+        tenant_id: user.tenant_id.to_string(),
         username: user.username.clone(),
         email: user.email.as_str().to_string(),
         password_hash: user.password_hash.clone(),
@@ -402,6 +1138,7 @@ fn to_proto_user_data(user: &User) -> repository::UserData {
             seconds: dt.timestamp(),
             nanos: dt.timestamp_subsec_nanos() as i32,
         }),
+        locked_until: user.locked_until.map(to_proto_timestamp),
         created_at: Some(common::Timestamp {
             seconds: user.created_at.timestamp(),
             nanos: user.created_at.timestamp_subsec_nanos() as i32,
@@ -413,6 +1150,131 @@ fn to_proto_user_data(user: &User) -> repository::UserData {
     }
 }
 
+fn to_proto_timestamp(dt: chrono::DateTime<chrono::Utc>) -> common::Timestamp {
+    common::Timestamp {
+        seconds: dt.timestamp(),
+        nanos: dt.timestamp_subsec_nanos() as i32,
+    }
+}
+
+fn from_proto_timestamp(ts: &common::Timestamp) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp(ts.seconds, ts.nanos as u32)
+}
+
+fn to_proto_tenant_data(tenant: &Tenant) -> repository::TenantData {
+    repository::TenantData {
+        id: tenant.id.to_string(),
+        slug: tenant.slug.clone(),
+        name: tenant.name.clone(),
+        active: tenant.active,
+        created_at: Some(to_proto_timestamp(tenant.created_at)),
+        updated_at: Some(to_proto_timestamp(tenant.updated_at)),
+    }
+}
+
+fn from_proto_tenant_data(tenant: &repository::TenantData) -> ArcanaResult<Tenant> {
+    let id = TenantId::parse(&tenant.id)
+        .map_err(|e| ArcanaError::Internal(format!("Invalid tenant ID from repository: {}", e)))?;
+    let timestamp = |ts: &Option<common::Timestamp>| {
+        ts.as_ref().and_then(from_proto_timestamp).unwrap_or_else(chrono::Utc::now)
+    };
+
+    Ok(Tenant {
+        id,
+        slug: tenant.slug.clone(),
+        name: tenant.name.clone(),
+        active: tenant.active,
+        created_at: timestamp(&tenant.created_at),
+        updated_at: timestamp(&tenant.updated_at),
+    })
+}
+
+fn to_proto_oauth_token_data(token: &OAuthToken) -> repository::OAuthTokenData {
+    repository::OAuthTokenData {
+        id: token.id.to_string(),
+        user_id: token.user_id.to_string(),
+        token_hash: token.token_hash.clone(),
+        family_id: token.family_id.clone(),
+        device_id: token.device_id.clone(),
+        user_agent: token.user_agent.clone(),
+        ip_address: token.ip_address.clone(),
+        expires_at: Some(to_proto_timestamp(token.expires_at)),
+        revoked: token.revoked,
+        revoked_at: token.revoked_at.map(to_proto_timestamp),
+        created_at: Some(to_proto_timestamp(token.created_at)),
+        last_used_at: token.last_used_at.map(to_proto_timestamp),
+    }
+}
+
+fn from_proto_oauth_token_data(token: &repository::OAuthTokenData) -> ArcanaResult<OAuthToken> {
+    let id = OAuthTokenId::parse(&token.id)
+        .map_err(|e| ArcanaError::Internal(format!("Invalid token ID from repository: {}", e)))?;
+    let user_id = UserId::parse(&token.user_id)
+        .map_err(|e| ArcanaError::Internal(format!("Invalid user ID from repository: {}", e)))?;
+    let expires_at = token
+        .expires_at
+        .as_ref()
+        .and_then(from_proto_timestamp)
+        .ok_or_else(|| ArcanaError::Internal("Token expiration missing from repository".to_string()))?;
+
+    Ok(OAuthToken {
+        id,
+        user_id,
+        token_hash: token.token_hash.clone(),
+        family_id: token.family_id.clone(),
+        device_id: token.device_id.clone(),
+        user_agent: token.user_agent.clone(),
+        ip_address: token.ip_address.clone(),
+        expires_at,
+        revoked: token.revoked,
+        revoked_at: token.revoked_at.as_ref().and_then(from_proto_timestamp),
+        created_at: token
+            .created_at
+            .as_ref()
+            .and_then(from_proto_timestamp)
+            .unwrap_or_else(chrono::Utc::now),
+        last_used_at: token.last_used_at.as_ref().and_then(from_proto_timestamp),
+    })
+}
+
+fn to_proto_password_reset_token_data(token: &PasswordResetToken) -> repository::PasswordResetTokenData {
+    repository::PasswordResetTokenData {
+        id: token.id.to_string(),
+        user_id: token.user_id.to_string(),
+        token_hash: token.token_hash.clone(),
+        expires_at: Some(to_proto_timestamp(token.expires_at)),
+        used_at: token.used_at.map(to_proto_timestamp),
+        created_at: Some(to_proto_timestamp(token.created_at)),
+    }
+}
+
+fn from_proto_password_reset_token_data(
+    token: &repository::PasswordResetTokenData,
+) -> ArcanaResult<PasswordResetToken> {
+    let id = PasswordResetTokenId::parse(&token.id)
+        .map_err(|e| ArcanaError::Internal(format!("Invalid token ID from repository: {}", e)))?;
+    let user_id = UserId::parse(&token.user_id)
+        .map_err(|e| ArcanaError::Internal(format!("Invalid user ID from repository: {}", e)))?;
+    let expires_at = token
+        .expires_at
+        .as_ref()
+        .and_then(from_proto_timestamp)
+        .ok_or_else(|| ArcanaError::Internal("Token expiration missing from repository".to_string()))?;
+
+    Ok(PasswordResetToken {
+        id,
+        user_id,
+        token_hash: token.token_hash.clone(),
+        expires_at,
+        used_at: token.used_at.as_ref().and_then(from_proto_timestamp),
+        created_at: token
+            .created_at
+            .as_ref()
+            .and_then(from_proto_timestamp)
+            .unwrap_or_else(chrono::Utc::now),
+    })
+}
+
 fn to_proto_role(role: UserRole) -> user_proto::UserRole {
     match role {
         UserRole::User => user_proto::UserRole::User,
@@ -452,3 +1314,90 @@ fn from_proto_status(status: user_proto::UserStatus) -> UserStatus {
         user_proto::UserStatus::Deleted => UserStatus::Deleted,
     }
 }
+
+fn to_proto_totp_credential_data(credential: &TotpCredential) -> repository::TotpCredentialData {
+    repository::TotpCredentialData {
+        user_id: credential.user_id.to_string(),
+        secret: credential.secret.clone(),
+        enabled_at: credential.enabled_at.map(to_proto_timestamp),
+        last_used_step: credential.last_used_step,
+        created_at: Some(to_proto_timestamp(credential.created_at)),
+        updated_at: Some(to_proto_timestamp(credential.updated_at)),
+    }
+}
+
+fn from_proto_totp_credential_data(
+    credential: &repository::TotpCredentialData,
+) -> ArcanaResult<TotpCredential> {
+    let user_id = UserId::parse(&credential.user_id)
+        .map_err(|e| ArcanaError::Internal(format!("Invalid user ID from repository: {}", e)))?;
+    let timestamp = |ts: &Option<common::Timestamp>| {
+        ts.as_ref().and_then(from_proto_timestamp).unwrap_or_else(chrono::Utc::now)
+    };
+
+    Ok(TotpCredential {
+        user_id,
+        secret: credential.secret.clone(),
+        enabled_at: credential.enabled_at.as_ref().and_then(from_proto_timestamp),
+        last_used_step: credential.last_used_step,
+        created_at: timestamp(&credential.created_at),
+        updated_at: timestamp(&credential.updated_at),
+    })
+}
+
+fn to_proto_audit_log_data(entry: &AuditLog) -> repository::AuditLogData {
+    repository::AuditLogData {
+        id: entry.id.to_string(),
+        user_id: entry.user_id.map(|id| id.to_string()),
+        action: entry.action.to_string(),
+        resource_type: entry.resource_type.clone(),
+        resource_id: entry.resource_id.clone(),
+        details: entry.details.as_ref().map(ToString::to_string),
+        ip_address: entry.ip_address.clone(),
+        user_agent: entry.user_agent.clone(),
+        success: entry.success,
+        error_message: entry.error_message.clone(),
+        timestamp: Some(to_proto_timestamp(entry.timestamp)),
+        sequence: entry.sequence,
+        prev_hash: entry.prev_hash.clone(),
+        hash: entry.hash.clone(),
+    }
+}
+
+fn from_proto_audit_log_data(entry: &repository::AuditLogData) -> ArcanaResult<AuditLog> {
+    let id = AuditLogId::parse(&entry.id)
+        .map_err(|e| ArcanaError::Internal(format!("Invalid audit log ID from repository: {}", e)))?;
+    let user_id = entry
+        .user_id
+        .as_deref()
+        .map(UserId::parse)
+        .transpose()
+        .map_err(|e| ArcanaError::Internal(format!("Invalid user ID from repository: {}", e)))?;
+    let details = entry
+        .details
+        .as_deref()
+        .map(serde_json::from_str)
+        .transpose()
+        .map_err(|e| ArcanaError::Internal(format!("Invalid audit log details from repository: {}", e)))?;
+
+    Ok(AuditLog {
+        id,
+        user_id,
+        action: entry.action.parse()?,
+        resource_type: entry.resource_type.clone(),
+        resource_id: entry.resource_id.clone(),
+        details,
+        ip_address: entry.ip_address.clone(),
+        user_agent: entry.user_agent.clone(),
+        success: entry.success,
+        error_message: entry.error_message.clone(),
+        timestamp: entry
+            .timestamp
+            .as_ref()
+            .and_then(from_proto_timestamp)
+            .unwrap_or_else(chrono::Utc::now),
+        sequence: entry.sequence,
+        prev_hash: entry.prev_hash.clone(),
+        hash: entry.hash.clone(),
+    })
+}
@@ -1,7 +1,8 @@
 //! Remote user service client via gRPC.
 
+use crate::interceptors::RequestContextForwarder;
 use crate::proto::{common, user};
-use arcana_core::{ArcanaError, ArcanaResult, PageRequest, UserId};
+use arcana_core::{ArcanaError, ArcanaResult, PageRequest, TenantId, UserId};
 use arcana_service::dto::{
     ChangePasswordRequest, CreateUserRequest, UpdateUserRequest, UpdateUserRoleRequest,
     UpdateUserStatusRequest, UserListResponse, UserResponse,
@@ -9,28 +10,33 @@ use arcana_service::dto::{
 use arcana_service::UserService;
 use async_trait::async_trait;
 use std::sync::Arc;
+use tonic::codegen::InterceptedService;
 use tonic::transport::Channel;
 use tracing::debug;
 
 /// Remote user service client that communicates via gRPC.
 pub struct RemoteUserServiceClient {
-    client: user::user_service_client::UserServiceClient<Channel>,
+    client: user::user_service_client::UserServiceClient<InterceptedService<Channel, RequestContextForwarder>>,
 }
 
 impl RemoteUserServiceClient {
     /// Creates a new remote user service client.
     pub async fn connect(addr: &str) -> ArcanaResult<Self> {
-        let client = user::user_service_client::UserServiceClient::connect(addr.to_string())
+        let channel = Channel::from_shared(addr.to_string())
+            .map_err(|e| ArcanaError::Internal(format!("Failed to connect to user service: {}", e)))?
+            .connect()
             .await
             .map_err(|e| ArcanaError::Internal(format!("Failed to connect to user service: {}", e)))?;
 
-        Ok(Self { client })
+        Ok(Self::from_channel(channel))
     }
 
     /// Creates from an existing channel.
+    ///
+    /// Calls forward the IP address and user agent of the current request.
     pub fn from_channel(channel: Channel) -> Self {
         Self {
-            client: user::user_service_client::UserServiceClient::new(channel),
+            client: user::user_service_client::UserServiceClient::with_interceptor(channel, RequestContextForwarder),
         }
     }
 }
@@ -73,7 +79,7 @@ impl UserService for RemoteUserServiceClient {
                 user_id: id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let user = response
             .into_inner()
@@ -93,7 +99,7 @@ impl UserService for RemoteUserServiceClient {
                 username: username.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let user = response
             .into_inner()
@@ -117,7 +123,7 @@ impl UserService for RemoteUserServiceClient {
                 role_filter: None,
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let inner = response.into_inner();
         let page_info = inner.page_info.unwrap_or_default();
@@ -144,7 +150,7 @@ impl UserService for RemoteUserServiceClient {
                 avatar_url: request.avatar_url,
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let user = response
             .into_inner()
@@ -165,7 +171,7 @@ impl UserService for RemoteUserServiceClient {
                 role: to_proto_role(request.role) as i32,
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let user = response
             .into_inner()
@@ -187,7 +193,7 @@ impl UserService for RemoteUserServiceClient {
                 reason: request.reason,
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         let user = response
             .into_inner()
@@ -211,7 +217,7 @@ impl UserService for RemoteUserServiceClient {
                 user_id: id.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(())
     }
@@ -224,7 +230,7 @@ impl UserService for RemoteUserServiceClient {
                 username: username.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().exists)
     }
@@ -237,7 +243,7 @@ impl UserService for RemoteUserServiceClient {
                 email: email.to_string(),
             })
             .await
-            .map_err(|e| map_grpc_error(e))?;
+            .map_err(map_grpc_error)?;
 
         Ok(response.into_inner().exists)
     }
@@ -268,6 +274,7 @@ fn map_grpc_error(status: tonic::Status) -> ArcanaError {
 fn from_proto_user(user: &user::User) -> UserResponse {
     UserResponse {
         id: UserId::parse(&user.id).unwrap_or_else(|_| UserId::new()),
+        tenant_id: TenantId::parse(&user.tenant_id).unwrap_or_default(),
         username: user.username.clone(),
         email: user.email.clone(),
         first_name: user.first_name.clone(),
@@ -278,16 +285,16 @@ fn from_proto_user(user: &user::User) -> UserResponse {
         avatar_url: user.avatar_url.clone(),
         last_login_at: user.last_login_at.as_ref().map(|t| {
             chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)
-                .unwrap_or_else(|| chrono::Utc::now())
+                .unwrap_or_else(chrono::Utc::now)
         }),
         created_at: user
             .created_at
             .as_ref()
             .map(|t| {
                 chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)
-                    .unwrap_or_else(|| chrono::Utc::now())
+                    .unwrap_or_else(chrono::Utc::now)
             })
-            .unwrap_or_else(|| chrono::Utc::now()),
+            .unwrap_or_else(chrono::Utc::now),
     }
 }
 
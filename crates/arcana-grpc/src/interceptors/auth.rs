@@ -1,41 +1,126 @@
 //! Authentication interceptor for gRPC.
 
-use arcana_security::{Claims, TokenProvider};
+use super::MethodPolicies;
+use arcana_core::PermissionSet;
+use arcana_security::{
+    authenticate_access_token, Claims, PermissionResolverInterface, TokenProviderInterface, TokenRevocationInterface,
+};
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
+use tonic::codegen::http;
 use tonic::{Request, Status};
-use tracing::debug;
-
-/// Authentication interceptor that validates JWT tokens.
-pub fn auth_interceptor(
-    token_provider: Arc<TokenProvider>,
-) -> impl Fn(Request<()>) -> Result<Request<()>, Status> + Clone {
-    move |mut request: Request<()>| {
-        // Extract authorization header
-        let auth_header = request
-            .metadata()
+use tower::filter::{AsyncFilterLayer, AsyncPredicate};
+use tower::BoxError;
+use tracing::{debug, warn};
+
+/// Asynchronous authenticator applied to every request reaching the server.
+///
+/// Validates the bearer token, checks it against the revocation list and adds
+/// the claims to the request extensions. With a permission resolver, the
+/// permissions embedded in the token are replaced by freshly resolved ones,
+/// as the REST auth middleware does; if they cannot be resolved, none are
+/// granted. Requests are then checked against the policy of the called
+/// method; methods without a policy accept any caller and leave it to the
+/// service to decide whether auth is required.
+#[derive(Clone)]
+pub struct GrpcAuthenticator {
+    token_provider: Arc<dyn TokenProviderInterface>,
+    token_revocation: Arc<dyn TokenRevocationInterface>,
+    permission_resolver: Option<Arc<dyn PermissionResolverInterface>>,
+    policies: Arc<MethodPolicies>,
+}
+
+impl GrpcAuthenticator {
+    /// Creates a new authenticator enforcing [`MethodPolicies::service_layer`].
+    pub fn new(
+        token_provider: Arc<dyn TokenProviderInterface>,
+        token_revocation: Arc<dyn TokenRevocationInterface>,
+    ) -> Self {
+        Self {
+            token_provider,
+            token_revocation,
+            permission_resolver: None,
+            policies: Arc::new(MethodPolicies::service_layer()),
+        }
+    }
+
+    /// Resolves the effective permissions of authenticated users with `resolver`.
+    #[must_use]
+    pub fn with_permission_resolver(mut self, resolver: Arc<dyn PermissionResolverInterface>) -> Self {
+        self.permission_resolver = Some(resolver);
+        self
+    }
+
+    /// Enforces `policies` instead of the default table.
+    #[must_use]
+    pub fn with_policies(mut self, policies: MethodPolicies) -> Self {
+        self.policies = Arc::new(policies);
+        self
+    }
+}
+
+impl<B: Send + 'static> AsyncPredicate<http::Request<B>> for GrpcAuthenticator {
+    type Future = Pin<Box<dyn Future<Output = Result<http::Request<B>, BoxError>> + Send>>;
+    type Request = http::Request<B>;
+
+    fn check(&mut self, mut request: http::Request<B>) -> Self::Future {
+        let token = request
+            .headers()
             .get("authorization")
-            .and_then(|v| v.to_str().ok());
+            .and_then(|v| v.to_str().ok())
+            .and_then(|h| h.strip_prefix("Bearer "))
+            .map(str::to_owned);
+        let authenticator = self.clone();
+        let policy = self.policies.policy(request.uri().path());
 
-        if let Some(auth_header) = auth_header {
-            if let Some(token) = auth_header.strip_prefix("Bearer ") {
-                // Validate token
-                match token_provider.validate_access_token(token) {
-                    Ok(claims) => {
+        Box::pin(async move {
+            if let Some(token) = token {
+                match authenticate_access_token(
+                    authenticator.token_provider.as_ref(),
+                    authenticator.token_revocation.as_ref(),
+                    &token,
+                )
+                .await
+                {
+                    Ok(mut claims) => {
                         debug!("gRPC: Authenticated user: {}", claims.username);
+                        if let (Some(resolver), Some(user_id)) = (&authenticator.permission_resolver, claims.user_id()) {
+                            let permissions = resolver.resolve(user_id, claims.role).await.unwrap_or_else(|e| {
+                                warn!("gRPC: Failed to resolve permissions of {}: {}", claims.username, e);
+                                PermissionSet::new()
+                            });
+                            claims.permissions = Some(permissions);
+                        }
                         request.extensions_mut().insert(claims);
                     }
                     Err(e) => {
                         debug!("gRPC: Token validation failed: {}", e);
-                        // Don't reject - let the service decide if auth is required
                     }
                 }
             }
-        }
 
-        Ok(request)
+            policy.check(request.extensions().get::<Claims>())?;
+            Ok(request)
+        })
     }
 }
 
+/// Creates the server layer that authenticates every request, resolving
+/// permissions with `permission_resolver` if given, and enforces
+/// [`MethodPolicies::service_layer`].
+pub fn auth_layer(
+    token_provider: Arc<dyn TokenProviderInterface>,
+    token_revocation: Arc<dyn TokenRevocationInterface>,
+    permission_resolver: Option<Arc<dyn PermissionResolverInterface>>,
+) -> AsyncFilterLayer<GrpcAuthenticator> {
+    let authenticator = GrpcAuthenticator::new(token_provider, token_revocation);
+    AsyncFilterLayer::new(match permission_resolver {
+        Some(resolver) => authenticator.with_permission_resolver(resolver),
+        None => authenticator,
+    })
+}
+
 /// Extracts claims from a gRPC request.
 pub fn extract_claims<T>(request: &Request<T>) -> Option<&Claims> {
     request.extensions().get::<Claims>()
@@ -45,3 +130,81 @@ pub fn extract_claims<T>(request: &Request<T>) -> Option<&Claims> {
 pub fn require_auth<T>(request: &Request<T>) -> Result<&Claims, Status> {
     extract_claims(request).ok_or_else(|| Status::unauthenticated("Authentication required"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interceptors::MethodPolicy;
+    use arcana_config::SecurityConfig;
+    use arcana_core::{ArcanaResult, UserId, UserRole};
+    use arcana_security::{TokenProvider, TokenRevocationStore, DEFAULT_USER_REVOCATION_TTL};
+
+    /// Resolver for which every grant has been withdrawn.
+    struct NothingGranted;
+
+    #[async_trait::async_trait]
+    impl PermissionResolverInterface for NothingGranted {
+        async fn resolve(&self, _user_id: UserId, _role: UserRole) -> ArcanaResult<PermissionSet> {
+            Ok(PermissionSet::new())
+        }
+
+        async fn invalidate_user(&self, _user_id: UserId) {}
+
+        async fn invalidate_all(&self) {}
+    }
+
+    fn token_provider() -> Arc<TokenProvider> {
+        let config = SecurityConfig {
+            jwt_secret: "test-secret-key-that-is-at-least-32-characters-long".to_string(),
+            ..SecurityConfig::default()
+        };
+        Arc::new(TokenProvider::new(Arc::new(config)))
+    }
+
+    fn authenticator() -> GrpcAuthenticator {
+        GrpcAuthenticator::new(
+            token_provider(),
+            Arc::new(TokenRevocationStore::in_memory(DEFAULT_USER_REVOCATION_TTL)),
+        )
+    }
+
+    fn call(path: &str) -> http::Request<()> {
+        http::Request::builder().uri(path).body(()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_authenticator_enforces_method_policies() {
+        let mut authenticator = authenticator();
+
+        assert!(authenticator.check(call("/arcana.auth.AuthService/Login")).await.is_ok());
+
+        let error = authenticator.check(call("/arcana.auth.AuthService/Logout")).await.unwrap_err();
+        let status = error.downcast::<Status>().unwrap();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        let open = MethodPolicies::new().with("/arcana.auth.AuthService/Logout", MethodPolicy::Public);
+        let mut open = authenticator.with_policies(open);
+        assert!(open.check(call("/arcana.auth.AuthService/Logout")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_authenticator_resolves_current_permissions() {
+        let token = token_provider()
+            .generate_access_token(UserId::new(), "user", "user@example.com", UserRole::User)
+            .unwrap();
+        let list_users = || {
+            http::Request::builder()
+                .uri("/arcana.user.UserService/ListUsers")
+                .header("authorization", format!("Bearer {}", token))
+                .body(())
+                .unwrap()
+        };
+
+        assert!(authenticator().check(list_users()).await.is_ok());
+
+        // The token still grants user:read, but the resolver no longer does
+        let mut resolving = authenticator().with_permission_resolver(Arc::new(NothingGranted));
+        let error = resolving.check(list_users()).await.unwrap_err();
+        assert_eq!(error.downcast::<Status>().unwrap().code(), tonic::Code::PermissionDenied);
+    }
+}
@@ -0,0 +1,233 @@
+//! Request context propagation for gRPC.
+
+use arcana_core::TenantId;
+use arcana_security::Claims;
+use arcana_service::RequestContext;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tonic::codegen::http;
+use tonic::metadata::MetadataValue;
+use tonic::service::Interceptor;
+use tonic::transport::server::{TcpConnectInfo, TlsConnectInfo};
+use tonic::{Request, Status};
+use tower::{Layer, Service};
+
+/// Metadata key carrying the original client IP from a calling layer.
+pub const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// Metadata key carrying the original client user agent from a calling layer.
+///
+/// Kept apart from `user-agent`, which tonic clients overwrite with their own.
+pub const FORWARDED_USER_AGENT_HEADER: &str = "x-forwarded-user-agent";
+
+/// Metadata key selecting the tenant a request acts on.
+///
+/// Honoured for anonymous calls (sign-in, registration) and for holders of
+/// `system:admin`; everyone else acts on the tenant of their token.
+pub const TENANT_HEADER: &str = "x-tenant-id";
+
+/// Server layer that runs every request inside a [`RequestContext`].
+///
+/// Must be applied inside [`auth_layer`](super::auth_layer) to see the
+/// claims. The client IP and user agent forwarded by a calling layer take
+/// precedence over the peer address and the caller's own user agent.
+/// A malformed [`TENANT_HEADER`] is ignored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestContextLayer;
+
+impl<S> Layer<S> for RequestContextLayer {
+    type Service = RequestContextService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestContextService { inner }
+    }
+}
+
+/// Service created by [`RequestContextLayer`].
+#[derive(Debug, Clone)]
+pub struct RequestContextService<S> {
+    inner: S,
+}
+
+impl<S, B> Service<http::Request<B>> for RequestContextService<S>
+where
+    S: Service<http::Request<B>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let context = request_context(&request);
+        Box::pin(context.scope(self.inner.call(request)))
+    }
+}
+
+/// Builds the context of an incoming request.
+fn request_context<B>(request: &http::Request<B>) -> RequestContext {
+    let header = |name: &str| {
+        request
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+
+    let extensions = request.extensions();
+    let ip_address = header(FORWARDED_FOR_HEADER).or_else(|| {
+        extensions
+            .get::<TcpConnectInfo>()
+            .and_then(TcpConnectInfo::remote_addr)
+            .or_else(|| {
+                extensions
+                    .get::<TlsConnectInfo<TcpConnectInfo>>()
+                    .and_then(|info| info.get_ref().remote_addr())
+            })
+            .map(|addr| addr.ip().to_string())
+    });
+    let user_agent = header(FORWARDED_USER_AGENT_HEADER).or_else(|| {
+        request
+            .headers()
+            .get(http::header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    });
+    let claims = extensions.get::<Claims>();
+    let requested_tenant = header(TENANT_HEADER).and_then(|value| TenantId::parse(&value).ok());
+    let tenant = match claims {
+        Some(claims) => claims.acting_tenant(requested_tenant),
+        None => requested_tenant.unwrap_or_default(),
+    };
+
+    RequestContext::new(ip_address, user_agent)
+        .with_actor(claims.and_then(Claims::user_id))
+        .with_tenant(Some(tenant))
+}
+
+/// Client interceptor that forwards the current [`RequestContext`].
+///
+/// Used by the remote service clients so the service layer audits calls
+/// with the IP address and user agent of the original client rather than
+/// those of the controller layer, and acts on the same tenant.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestContextForwarder;
+
+impl Interceptor for RequestContextForwarder {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let context = RequestContext::current();
+        let metadata = request.metadata_mut();
+
+        let forwarded = [
+            (FORWARDED_FOR_HEADER, context.ip_address),
+            (FORWARDED_USER_AGENT_HEADER, context.user_agent),
+            (TENANT_HEADER, context.tenant.map(|tenant| tenant.to_string())),
+        ];
+        for (key, value) in forwarded {
+            if let Some(value) = value.and_then(|v| MetadataValue::try_from(v).ok()) {
+                metadata.insert(key, value);
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::SocketAddr;
+
+    #[test]
+    fn test_forwarded_values_take_precedence() {
+        let mut request = http::Request::builder()
+            .header(FORWARDED_FOR_HEADER, "203.0.113.7, 10.0.0.1")
+            .header(FORWARDED_USER_AGENT_HEADER, "curl/8.0")
+            .header(http::header::USER_AGENT, "tonic/0.14")
+            .body(())
+            .unwrap();
+        request.extensions_mut().insert(TcpConnectInfo {
+            local_addr: None,
+            remote_addr: Some(SocketAddr::from(([10, 0, 0, 1], 4711))),
+        });
+
+        let context = request_context(&request);
+        assert_eq!(context.ip_address.as_deref(), Some("203.0.113.7"));
+        assert_eq!(context.user_agent.as_deref(), Some("curl/8.0"));
+        assert_eq!(context.actor, None);
+    }
+
+    #[test]
+    fn test_falls_back_to_peer_and_user_agent() {
+        let mut request = http::Request::builder()
+            .header(http::header::USER_AGENT, "grpcurl/1.9")
+            .body(())
+            .unwrap();
+        request.extensions_mut().insert(TcpConnectInfo {
+            local_addr: None,
+            remote_addr: Some(SocketAddr::from(([192, 0, 2, 1], 4711))),
+        });
+
+        let context = request_context(&request);
+        assert_eq!(context.ip_address.as_deref(), Some("192.0.2.1"));
+        assert_eq!(context.user_agent.as_deref(), Some("grpcurl/1.9"));
+        assert_eq!(context.tenant, Some(TenantId::DEFAULT));
+    }
+
+    #[test]
+    fn test_tenant_header_selects_tenant_of_anonymous_calls_only() {
+        let tenant_id = TenantId::new();
+        let request = |claims: Option<Claims>| {
+            let mut request = http::Request::builder()
+                .header(TENANT_HEADER, tenant_id.to_string())
+                .body(())
+                .unwrap();
+            if let Some(claims) = claims {
+                request.extensions_mut().insert(claims);
+            }
+            request_context(&request)
+        };
+
+        assert_eq!(request(None).tenant, Some(tenant_id));
+
+        let claims = Claims::new_access(
+            arcana_core::UserId::new(),
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            arcana_core::UserRole::User,
+            "arcana".to_string(),
+            "arcana-api".to_string(),
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        );
+        assert_eq!(request(Some(claims)).tenant, Some(TenantId::DEFAULT));
+    }
+
+    #[tokio::test]
+    async fn test_forwarder_copies_current_context() {
+        let tenant_id = TenantId::new();
+        let context = RequestContext::new(Some("203.0.113.7".to_string()), Some("curl/8.0".to_string()))
+            .with_tenant(Some(tenant_id));
+
+        let request = context
+            .scope(async { RequestContextForwarder.call(Request::new(())).unwrap() })
+            .await;
+
+        assert_eq!(request.metadata().get(FORWARDED_FOR_HEADER).unwrap(), "203.0.113.7");
+        assert_eq!(request.metadata().get(FORWARDED_USER_AGENT_HEADER).unwrap(), "curl/8.0");
+        assert_eq!(request.metadata().get(TENANT_HEADER).unwrap(), tenant_id.to_string().as_str());
+    }
+
+    #[test]
+    fn test_forwarder_outside_request_adds_nothing() {
+        let request = RequestContextForwarder.call(Request::new(())).unwrap();
+        assert!(request.metadata().is_empty());
+    }
+}
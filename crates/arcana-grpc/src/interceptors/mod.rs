@@ -1,5 +1,9 @@
 //! gRPC interceptors.
 
 mod auth;
+mod context;
+mod policy;
 
 pub use auth::*;
+pub use context::*;
+pub use policy::*;
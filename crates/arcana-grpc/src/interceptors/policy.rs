@@ -0,0 +1,249 @@
+//! Per-method authorization policies for gRPC.
+
+use arcana_core::Permission;
+use arcana_security::{Claims, ClaimsExt};
+use std::collections::HashMap;
+use tonic::Status;
+
+/// Access requirement of a gRPC method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MethodPolicy {
+    /// Anyone may call the method; the service decides what to do with the caller.
+    Public,
+    /// The caller must present a valid access token.
+    Authenticated,
+    /// The caller must be granted the permission.
+    Permission(Permission),
+}
+
+impl MethodPolicy {
+    /// Checks the claims of a caller (`None` if unauthenticated) against the policy.
+    pub fn check(&self, claims: Option<&Claims>) -> Result<(), Status> {
+        let required = match self {
+            Self::Public => return Ok(()),
+            Self::Authenticated => None,
+            Self::Permission(permission) => Some(*permission),
+        };
+
+        let claims = claims.ok_or_else(|| Status::unauthenticated("Authentication required"))?;
+        if let Some(permission) = required {
+            claims
+                .require_permission(permission)
+                .map_err(|e| Status::permission_denied(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Policy table keyed by full method path (`/package.Service/Method`).
+///
+/// Methods without an entry are [`MethodPolicy::Authenticated`], so a method
+/// added without a policy never becomes callable anonymously.
+#[derive(Debug, Clone, Default)]
+pub struct MethodPolicies {
+    policies: HashMap<String, MethodPolicy>,
+}
+
+impl MethodPolicies {
+    /// Creates an empty table, requiring a login for every method.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the policy of a method.
+    #[must_use]
+    pub fn with(mut self, path: impl Into<String>, policy: MethodPolicy) -> Self {
+        self.policies.insert(path.into(), policy);
+        self
+    }
+
+    /// Returns the policy of a method.
+    #[must_use]
+    pub fn policy(&self, path: &str) -> MethodPolicy {
+        self.policies.get(path).copied().unwrap_or(MethodPolicy::Authenticated)
+    }
+
+    /// Policies of the services exposed by the service layer.
+    ///
+    /// Lists every method of those services and mirrors the REST routes:
+    /// signing in and health checks are public, session and MFA management
+    /// need a login, user management the `user:*` permission of the
+    /// matching REST route, the audit trail `system:monitor`, inspecting
+    /// jobs `system:monitor` and changing them or working them off
+    /// `system:admin`. Viewing or updating a single user needs a login; the
+    /// service admits owners and checks `user:update` for anyone else.
+    #[must_use]
+    pub fn service_layer() -> Self {
+        const PUBLIC: [&str; 11] = [
+            "/arcana.health.Health/Check",
+            "/arcana.health.Health/Watch",
+            "/arcana.auth.AuthService/Register",
+            "/arcana.auth.AuthService/Login",
+            "/arcana.auth.AuthService/CompleteMfaLogin",
+            "/arcana.auth.AuthService/RefreshToken",
+            "/arcana.auth.AuthService/ValidateToken",
+            "/arcana.auth.AuthService/VerifyEmail",
+            "/arcana.auth.AuthService/ResendVerification",
+            "/arcana.auth.AuthService/ForgotPassword",
+            "/arcana.auth.AuthService/ResetPassword",
+        ];
+        const AUTHENTICATED: [&str; 13] = [
+            "/arcana.auth.AuthService/Logout",
+            "/arcana.auth.AuthService/GetCurrentUser",
+            "/arcana.auth.AuthService/ListSessions",
+            "/arcana.auth.AuthService/RevokeSession",
+            "/arcana.auth.AuthService/RevokeOtherSessions",
+            "/arcana.auth.AuthService/EnrollTotp",
+            "/arcana.auth.AuthService/EnableTotp",
+            "/arcana.auth.AuthService/DisableTotp",
+            "/arcana.auth.AuthService/RegenerateRecoveryCodes",
+            "/arcana.user.UserService/GetUser",
+            "/arcana.user.UserService/GetUserByUsername",
+            "/arcana.user.UserService/UpdateUser",
+            "/arcana.audit.AuditService/VerifyAuditChain",
+        ];
+        const PERMISSIONS: [(&str, Permission); 20] = [
+            ("/arcana.user.UserService/ListUsers", Permission::UserRead),
+            ("/arcana.user.UserService/UsernameExists", Permission::UserRead),
+            ("/arcana.user.UserService/EmailExists", Permission::UserRead),
+            ("/arcana.user.UserService/CreateUser", Permission::UserCreate),
+            ("/arcana.user.UserService/UpdateUserStatus", Permission::UserUpdate),
+            ("/arcana.user.UserService/DeleteUser", Permission::UserDelete),
+            ("/arcana.user.UserService/UpdateUserRole", Permission::UserManageRoles),
+            ("/arcana.audit.AuditService/QueryAuditLogs", Permission::SystemMonitor),
+            ("/arcana.jobs.v1.JobQueueService/GetJob", Permission::SystemMonitor),
+            ("/arcana.jobs.v1.JobQueueService/GetQueueStats", Permission::SystemMonitor),
+            ("/arcana.jobs.v1.JobQueueService/WatchJobs", Permission::SystemMonitor),
+            ("/arcana.jobs.v1.JobQueueService/Enqueue", Permission::SystemAdmin),
+            ("/arcana.jobs.v1.JobQueueService/EnqueueBatch", Permission::SystemAdmin),
+            ("/arcana.jobs.v1.JobQueueService/CancelJob", Permission::SystemAdmin),
+            ("/arcana.jobs.v1.JobQueueService/RetryJob", Permission::SystemAdmin),
+            ("/arcana.jobs.v1.WorkerService/RegisterWorker", Permission::SystemAdmin),
+            ("/arcana.jobs.v1.WorkerService/Heartbeat", Permission::SystemAdmin),
+            ("/arcana.jobs.v1.WorkerService/Dequeue", Permission::SystemAdmin),
+            ("/arcana.jobs.v1.WorkerService/Complete", Permission::SystemAdmin),
+            ("/arcana.jobs.v1.WorkerService/Fail", Permission::SystemAdmin),
+        ];
+
+        let policies = PUBLIC
+            .into_iter()
+            .fold(Self::new(), |policies, path| policies.with(path, MethodPolicy::Public));
+        let policies = AUTHENTICATED
+            .into_iter()
+            .fold(policies, |policies, path| policies.with(path, MethodPolicy::Authenticated));
+        PERMISSIONS.into_iter().fold(policies, |policies, (path, permission)| {
+            policies.with(path, MethodPolicy::Permission(permission))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{UserId, UserRole};
+    use chrono::{Duration, Utc};
+
+    fn claims(role: UserRole) -> Claims {
+        Claims::new_access(
+            UserId::new(),
+            "caller".to_string(),
+            "caller@example.com".to_string(),
+            role,
+            "issuer".to_string(),
+            "audience".to_string(),
+            Utc::now() + Duration::hours(1),
+        )
+    }
+
+    /// Lists the `/package.Service/Method` paths declared in a proto file.
+    fn proto_methods(file: &str) -> Vec<String> {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../proto").join(file);
+        let source = std::fs::read_to_string(path).unwrap();
+
+        let mut package = "";
+        let mut service = "";
+        let mut methods = Vec::new();
+        for line in source.lines().map(str::trim) {
+            let word = |prefix: &str| {
+                line.strip_prefix(prefix)
+                    .and_then(|rest| rest.split(|c: char| c == ';' || c == '(' || c.is_whitespace()).next())
+            };
+            if let Some(name) = word("package ") {
+                package = name;
+            } else if let Some(name) = word("service ") {
+                service = name;
+            } else if let Some(name) = word("rpc ") {
+                methods.push(format!("/{}.{}/{}", package, service, name));
+            }
+        }
+        methods
+    }
+
+    #[test]
+    fn test_every_service_layer_method_has_a_policy() {
+        let policies = MethodPolicies::service_layer();
+        let files = ["auth_service.proto", "user_service.proto", "audit_service.proto", "jobs.proto", "health.proto"];
+
+        let methods: Vec<String> = files.into_iter().flat_map(proto_methods).collect();
+        assert!(methods.contains(&"/arcana.user.UserService/DeleteUser".to_string()));
+
+        let missing: Vec<&String> = methods.iter().filter(|m| !policies.policies.contains_key(*m)).collect();
+        assert!(missing.is_empty(), "methods without a policy: {:?}", missing);
+    }
+
+    #[test]
+    fn test_unlisted_methods_require_login() {
+        let policies = MethodPolicies::service_layer();
+        assert_eq!(policies.policy("/arcana.auth.AuthService/Login"), MethodPolicy::Public);
+        assert!(policies.policy("/arcana.auth.AuthService/Login").check(None).is_ok());
+        assert_eq!(policies.policy("/arcana.example.Unknown/Call"), MethodPolicy::Authenticated);
+        assert!(policies.policy("/arcana.example.Unknown/Call").check(None).is_err());
+    }
+
+    #[test]
+    fn test_user_service_mirrors_rest_permissions() {
+        let policies = MethodPolicies::service_layer();
+        let user = claims(UserRole::User);
+        let moderator = claims(UserRole::Moderator);
+
+        assert!(policies.policy("/arcana.user.UserService/ListUsers").check(Some(&user)).is_ok());
+        assert!(policies.policy("/arcana.user.UserService/ListUsers").check(None).is_err());
+        for method in ["CreateUser", "UpdateUserStatus"] {
+            let policy = policies.policy(&format!("/arcana.user.UserService/{}", method));
+            assert!(policy.check(Some(&user)).is_err());
+            assert!(policy.check(Some(&moderator)).is_ok());
+        }
+        for method in ["DeleteUser", "UpdateUserRole"] {
+            let policy = policies.policy(&format!("/arcana.user.UserService/{}", method));
+            assert!(policy.check(Some(&moderator)).is_err());
+            assert!(policy.check(Some(&claims(UserRole::Admin))).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_policies_check_authentication_and_permissions() {
+        let policies = MethodPolicies::service_layer();
+
+        let logout = policies.policy("/arcana.auth.AuthService/Logout");
+        assert_eq!(logout.check(None).unwrap_err().code(), tonic::Code::Unauthenticated);
+        assert!(logout.check(Some(&claims(UserRole::User))).is_ok());
+
+        let query = policies.policy("/arcana.audit.AuditService/QueryAuditLogs");
+        assert_eq!(query.check(None).unwrap_err().code(), tonic::Code::Unauthenticated);
+        assert_eq!(
+            query.check(Some(&claims(UserRole::User))).unwrap_err().code(),
+            tonic::Code::PermissionDenied
+        );
+        assert!(query.check(Some(&claims(UserRole::Moderator))).is_ok());
+    }
+
+    #[test]
+    fn test_policies_use_embedded_permissions() {
+        let policy = MethodPolicies::service_layer().policy("/arcana.jobs.v1.JobQueueService/RetryJob");
+        assert!(policy.check(Some(&claims(UserRole::Admin))).is_err());
+
+        let operator = claims(UserRole::User).with_permissions([Permission::SystemAdmin].into_iter().collect());
+        assert!(policy.check(Some(&operator)).is_ok());
+    }
+}
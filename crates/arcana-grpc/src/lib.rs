@@ -23,15 +23,18 @@ mod tests {
     use super::services::{AuthGrpcService, UserGrpcService};
     use arcana_config::SecurityConfig;
     use arcana_core::{ArcanaError, ArcanaResult, Page, PageRequest, UserId};
-    use arcana_core::{Email, User};
+    use arcana_core::{Email, User, UserRole};
     use arcana_security::{Claims, TokenProvider};
     use arcana_service::{
         AuthResponse, AuthService, AuthUserInfo, ChangePasswordRequest, CreateUserRequest,
-        LoginRequest, MessageResponse, RefreshTokenRequest, RegisterRequest, UpdateUserRequest,
-        UpdateUserRoleRequest, UpdateUserStatusRequest, UserListResponse, UserResponse, UserService,
+        LoginRequest, LoginResponse, MessageResponse, MfaChallengeResponse, MfaLoginRequest,
+        RecoveryCodesResponse, RefreshTokenRequest, RegisterRequest, TotpCodeRequest,
+        TotpEnrollmentResponse,
+        PasswordResetConfirmRequest, PasswordResetRequest, ResendVerificationRequest, SessionInfo, UpdateUserRequest, UpdateUserRoleRequest, UpdateUserStatusRequest, UserListResponse,
+        UserResponse, UserService, VerifyEmailRequest,
     };
     use async_trait::async_trait;
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     use std::sync::{Arc, Mutex};
     use tonic::Request;
 
@@ -50,6 +53,7 @@ mod tests {
             tls_cert_path: None,
             tls_key_path: None,
             password_hash_cost: 4,
+            ..Default::default()
         })
     }
 
@@ -65,6 +69,21 @@ mod tests {
         user
     }
 
+    /// Wraps a message in a request authenticated as `user_id` with `role`.
+    fn as_caller<T>(message: T, user_id: UserId, role: UserRole) -> Request<T> {
+        let mut request = Request::new(message);
+        request.extensions_mut().insert(Claims::new_access(
+            user_id,
+            "caller".to_string(),
+            "caller@example.com".to_string(),
+            role,
+            "issuer".to_string(),
+            "audience".to_string(),
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        ));
+        request
+    }
+
     /// Mock user service for gRPC tests.
     struct MockUserService {
         users: Arc<Mutex<HashMap<UserId, User>>>,
@@ -157,10 +176,14 @@ mod tests {
         }
     }
 
+    /// The only TOTP code the mock auth service accepts.
+    const MOCK_TOTP_CODE: &str = "123456";
+
     /// Mock auth service for gRPC tests.
     struct MockAuthService {
         token_provider: Arc<TokenProvider>,
         users: Arc<Mutex<HashMap<UserId, User>>>,
+        mfa_users: Arc<Mutex<HashSet<UserId>>>,
     }
 
     impl MockAuthService {
@@ -168,9 +191,38 @@ mod tests {
             Self {
                 token_provider: Arc::new(TokenProvider::new(config)),
                 users: Arc::new(Mutex::new(HashMap::new())),
+                mfa_users: Arc::new(Mutex::new(HashSet::new())),
             }
         }
 
+        fn auth_response(&self, user: User) -> ArcanaResult<AuthResponse> {
+            let tokens = self.token_provider.generate_tokens(
+                user.id, &user.username, user.email.as_str(), user.role,
+            )?;
+
+            Ok(AuthResponse {
+                access_token: tokens.access_token,
+                refresh_token: tokens.refresh_token,
+                token_type: tokens.token_type,
+                expires_in: tokens.access_expires_at - chrono::Utc::now().timestamp(),
+                user: AuthUserInfo {
+                    id: user.id,
+                    username: user.username,
+                    email: user.email.to_string(),
+                    role: user.role,
+                    first_name: user.first_name,
+                    last_name: user.last_name,
+                },
+            })
+        }
+
+        fn check_code(code: &str) -> ArcanaResult<()> {
+            if code != MOCK_TOTP_CODE {
+                return Err(ArcanaError::Validation("Invalid authentication code".to_string()));
+            }
+            Ok(())
+        }
+
         fn with_user(config: Arc<SecurityConfig>, user: User) -> Self {
             let service = Self::new(config);
             service.users.lock().unwrap().insert(user.id, user);
@@ -211,30 +263,35 @@ mod tests {
             })
         }
 
-        async fn login(&self, request: LoginRequest) -> ArcanaResult<AuthResponse> {
+        async fn login(&self, request: LoginRequest) -> ArcanaResult<LoginResponse> {
             let user = self.users.lock().unwrap().values()
                 .find(|u| u.username == request.username_or_email || u.email.as_str() == request.username_or_email)
                 .cloned()
                 .ok_or(ArcanaError::InvalidCredentials)?;
 
-            let tokens = self.token_provider.generate_tokens(
-                user.id, &user.username, user.email.as_str(), user.role,
-            )?;
+            if self.mfa_users.lock().unwrap().contains(&user.id) {
+                let mfa_token = self.token_provider.generate_mfa_challenge_token(
+                    user.id, &user.username, user.email.as_str(), user.role,
+                )?;
+                return Ok(LoginResponse::MfaRequired(MfaChallengeResponse {
+                    mfa_required: true,
+                    mfa_token,
+                    expires_in: 300,
+                }));
+            }
 
-            Ok(AuthResponse {
-                access_token: tokens.access_token,
-                refresh_token: tokens.refresh_token,
-                token_type: tokens.token_type,
-                expires_in: tokens.access_expires_at - chrono::Utc::now().timestamp(),
-                user: AuthUserInfo {
-                    id: user.id,
-                    username: user.username,
-                    email: user.email.to_string(),
-                    role: user.role,
-                    first_name: user.first_name,
-                    last_name: user.last_name,
-                },
-            })
+            self.auth_response(user).map(LoginResponse::Authenticated)
+        }
+
+        async fn complete_mfa_login(&self, request: MfaLoginRequest) -> ArcanaResult<AuthResponse> {
+            let claims = self.token_provider.validate_mfa_challenge_token(&request.mfa_token)?;
+            if request.code != MOCK_TOTP_CODE {
+                return Err(ArcanaError::InvalidCredentials);
+            }
+            let user = claims.user_id()
+                .and_then(|id| self.users.lock().unwrap().get(&id).cloned())
+                .ok_or(ArcanaError::InvalidCredentials)?;
+            self.auth_response(user)
         }
 
         async fn refresh_token(&self, request: RefreshTokenRequest) -> ArcanaResult<AuthResponse> {
@@ -267,7 +324,7 @@ mod tests {
             self.token_provider.validate_access_token(token)
         }
 
-        async fn logout(&self, _user_id: UserId) -> ArcanaResult<MessageResponse> {
+        async fn logout(&self, _claims: &Claims) -> ArcanaResult<MessageResponse> {
             Ok(MessageResponse::new("Successfully logged out"))
         }
 
@@ -285,6 +342,96 @@ mod tests {
                 last_name: user.last_name,
             })
         }
+
+        async fn list_sessions(&self, claims: &Claims) -> ArcanaResult<Vec<SessionInfo>> {
+            Ok(vec![SessionInfo {
+                id: claims.session_id.clone().unwrap_or_default(),
+                device_id: Some("device-1".to_string()),
+                user_agent: None,
+                ip_address: None,
+                last_active_at: chrono::Utc::now(),
+                expires_at: chrono::Utc::now() + chrono::Duration::days(7),
+                current: true,
+            }])
+        }
+
+        async fn revoke_session(&self, claims: &Claims, session_id: &str) -> ArcanaResult<MessageResponse> {
+            if claims.session_id.as_deref() != Some(session_id) {
+                return Err(ArcanaError::not_found("Session", session_id));
+            }
+            Ok(MessageResponse::new("Session revoked"))
+        }
+
+        async fn revoke_other_sessions(&self, _claims: &Claims) -> ArcanaResult<MessageResponse> {
+            Ok(MessageResponse::new("Revoked 0 other sessions"))
+        }
+
+        async fn verify_email(&self, request: VerifyEmailRequest) -> ArcanaResult<MessageResponse> {
+            let claims = self.token_provider.validate_email_verification_token(&request.token)?;
+            let user_id = claims.user_id().ok_or(ArcanaError::InvalidToken("No user ID".to_string()))?;
+            let mut users = self.users.lock().unwrap();
+            let user = users.get_mut(&user_id)
+                .ok_or(ArcanaError::InvalidToken("User not found".to_string()))?;
+            user.verify_email();
+            Ok(MessageResponse::new("Email verified"))
+        }
+
+        async fn resend_verification(
+            &self,
+            _request: ResendVerificationRequest,
+        ) -> ArcanaResult<MessageResponse> {
+            Ok(MessageResponse::new("Verification email sent"))
+        }
+
+        async fn forgot_password(&self, _request: PasswordResetRequest) -> ArcanaResult<MessageResponse> {
+            Ok(MessageResponse::new("Password reset email sent"))
+        }
+
+        async fn reset_password(
+            &self,
+            request: PasswordResetConfirmRequest,
+        ) -> ArcanaResult<MessageResponse> {
+            if request.token != "valid-reset-token" {
+                return Err(ArcanaError::InvalidToken("Invalid reset token".to_string()));
+            }
+            Ok(MessageResponse::new("Password has been reset"))
+        }
+
+        async fn enroll_totp(&self, claims: &Claims) -> ArcanaResult<TotpEnrollmentResponse> {
+            Ok(TotpEnrollmentResponse {
+                secret: "JBSWY3DPEHPK3PXP".to_string(),
+                otpauth_uri: format!("otpauth://totp/Arcana:{}?secret=JBSWY3DPEHPK3PXP", claims.username),
+            })
+        }
+
+        async fn enable_totp(
+            &self,
+            claims: &Claims,
+            request: TotpCodeRequest,
+        ) -> ArcanaResult<RecoveryCodesResponse> {
+            Self::check_code(&request.code)?;
+            let user_id = claims.user_id().ok_or(ArcanaError::InvalidToken("No user ID".to_string()))?;
+            self.mfa_users.lock().unwrap().insert(user_id);
+            Ok(RecoveryCodesResponse { recovery_codes: vec!["abcde-fghjk".to_string()] })
+        }
+
+        async fn disable_totp(&self, claims: &Claims, request: TotpCodeRequest) -> ArcanaResult<MessageResponse> {
+            Self::check_code(&request.code)?;
+            let user_id = claims.user_id().ok_or(ArcanaError::InvalidToken("No user ID".to_string()))?;
+            if !self.mfa_users.lock().unwrap().remove(&user_id) {
+                return Err(ArcanaError::BusinessRule("Two-factor authentication is not enabled".to_string()));
+            }
+            Ok(MessageResponse::new("Two-factor authentication disabled"))
+        }
+
+        async fn regenerate_recovery_codes(
+            &self,
+            _claims: &Claims,
+            request: TotpCodeRequest,
+        ) -> ArcanaResult<RecoveryCodesResponse> {
+            Self::check_code(&request.code)?;
+            Ok(RecoveryCodesResponse { recovery_codes: vec!["mnpqr-stuvw".to_string()] })
+        }
     }
 
     // =============================================================================
@@ -297,9 +444,7 @@ mod tests {
         let user_id = user.id;
         let service = UserGrpcService::new(Arc::new(MockUserService::with_user(user)));
 
-        let request = Request::new(user::GetUserRequest {
-            user_id: user_id.to_string(),
-        });
+        let request = as_caller(user::GetUserRequest { user_id: user_id.to_string() }, user_id, UserRole::User);
 
         let response = user::user_service_server::UserService::get_user(&service, request)
             .await
@@ -313,9 +458,11 @@ mod tests {
     async fn test_grpc_get_user_not_found() {
         let service = UserGrpcService::new(Arc::new(MockUserService::new()));
 
-        let request = Request::new(user::GetUserRequest {
-            user_id: UserId::new().to_string(),
-        });
+        let request = as_caller(
+            user::GetUserRequest { user_id: UserId::new().to_string() },
+            UserId::new(),
+            UserRole::Admin,
+        );
 
         let result = user::user_service_server::UserService::get_user(&service, request).await;
         assert!(result.is_err());
@@ -325,11 +472,14 @@ mod tests {
     #[tokio::test]
     async fn test_grpc_get_user_by_username() {
         let user = create_test_user();
+        let user_id = user.id;
         let service = UserGrpcService::new(Arc::new(MockUserService::with_user(user)));
 
-        let request = Request::new(user::GetUserByUsernameRequest {
-            username: "testuser".to_string(),
-        });
+        let request = as_caller(
+            user::GetUserByUsernameRequest { username: "testuser".to_string() },
+            user_id,
+            UserRole::User,
+        );
 
         let response = user::user_service_server::UserService::get_user_by_username(&service, request)
             .await
@@ -382,12 +532,16 @@ mod tests {
         let user_id = user.id;
         let service = UserGrpcService::new(Arc::new(MockUserService::with_user(user)));
 
-        let request = Request::new(user::UpdateUserRequest {
-            user_id: user_id.to_string(),
-            first_name: Some("Updated".to_string()),
-            last_name: Some("Name".to_string()),
-            avatar_url: None,
-        });
+        let request = as_caller(
+            user::UpdateUserRequest {
+                user_id: user_id.to_string(),
+                first_name: Some("Updated".to_string()),
+                last_name: Some("Name".to_string()),
+                avatar_url: None,
+            },
+            user_id,
+            UserRole::User,
+        );
 
         let response = user::user_service_server::UserService::update_user(&service, request)
             .await
@@ -417,16 +571,65 @@ mod tests {
         let user_id = user.id;
         let service = UserGrpcService::new(Arc::new(MockUserService::with_user(user)));
 
-        let request = Request::new(user::UpdateUserRoleRequest {
-            user_id: user_id.to_string(),
-            role: user::UserRole::Admin.into(),
-        });
+        let request = as_caller(
+            user::UpdateUserRoleRequest { user_id: user_id.to_string(), role: user::UserRole::Admin.into() },
+            UserId::new(),
+            UserRole::Admin,
+        );
 
         let response = user::user_service_server::UserService::update_user_role(&service, request)
             .await
             .unwrap();
         let user = response.into_inner().user.unwrap();
         assert_eq!(user.role, user::UserRole::Admin as i32);
+
+        let request = as_caller(
+            user::UpdateUserRoleRequest { user_id: user_id.to_string(), role: user::UserRole::SuperAdmin.into() },
+            UserId::new(),
+            UserRole::Admin,
+        );
+        let result = user::user_service_server::UserService::update_user_role(&service, request).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn test_grpc_user_profiles_are_limited_to_owners_and_editors() {
+        let user = create_test_user();
+        let user_id = user.id;
+        let service = UserGrpcService::new(Arc::new(MockUserService::with_user(user)));
+        let get = |caller, role| as_caller(user::GetUserRequest { user_id: user_id.to_string() }, caller, role);
+
+        let result = user::user_service_server::UserService::get_user(&service, get(UserId::new(), UserRole::User)).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::PermissionDenied);
+        let result = user::user_service_server::UserService::get_user(&service, Request::new(user::GetUserRequest {
+            user_id: user_id.to_string(),
+        }))
+        .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::Unauthenticated);
+        assert!(user::user_service_server::UserService::get_user(&service, get(UserId::new(), UserRole::Moderator))
+            .await
+            .is_ok());
+
+        let request = as_caller(
+            user::GetUserByUsernameRequest { username: "testuser".to_string() },
+            UserId::new(),
+            UserRole::User,
+        );
+        let result = user::user_service_server::UserService::get_user_by_username(&service, request).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::PermissionDenied);
+
+        let request = as_caller(
+            user::UpdateUserRequest {
+                user_id: user_id.to_string(),
+                first_name: Some("Mallory".to_string()),
+                last_name: None,
+                avatar_url: None,
+            },
+            UserId::new(),
+            UserRole::User,
+        );
+        let result = user::user_service_server::UserService::update_user(&service, request).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::PermissionDenied);
     }
 
     #[tokio::test]
@@ -508,16 +711,76 @@ mod tests {
             username_or_email: "testuser".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         });
 
         let response = auth::auth_service_server::AuthService::login(&service, request)
             .await
             .unwrap();
-        let inner = response.into_inner();
+        let Some(auth::login_response::Result::Authenticated(inner)) = response.into_inner().result else {
+            panic!("expected tokens");
+        };
         assert!(!inner.access_token.is_empty());
         assert_eq!(inner.user.unwrap().username, "testuser");
     }
 
+    #[tokio::test]
+    async fn test_grpc_two_factor_login() {
+        let config = create_test_security_config();
+        let user = create_test_user();
+        let claims = create_session_claims(config.clone(), &user);
+        let service = AuthGrpcService::new(Arc::new(MockAuthService::with_user(config, user)));
+
+        let mut request = Request::new(common::Empty {});
+        request.extensions_mut().insert(claims.clone());
+        let enrollment = auth::auth_service_server::AuthService::enroll_totp(&service, request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(enrollment.otpauth_uri.starts_with("otpauth://totp/"));
+
+        let mut request = Request::new(auth::TotpCodeRequest { code: MOCK_TOTP_CODE.to_string() });
+        request.extensions_mut().insert(claims.clone());
+        let codes = auth::auth_service_server::AuthService::enable_totp(&service, request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(codes.recovery_codes.len(), 1);
+
+        let request = Request::new(auth::LoginRequest {
+            username_or_email: "testuser".to_string(),
+            password: "Password123".to_string(),
+            device_id: None,
+            ip_address: None,
+        });
+        let response = auth::auth_service_server::AuthService::login(&service, request)
+            .await
+            .unwrap();
+        let Some(auth::login_response::Result::MfaRequired(challenge)) = response.into_inner().result else {
+            panic!("expected an MFA challenge");
+        };
+
+        let request = Request::new(auth::CompleteMfaLoginRequest {
+            mfa_token: challenge.mfa_token,
+            code: MOCK_TOTP_CODE.to_string(),
+            device_id: None,
+        });
+        let response = auth::auth_service_server::AuthService::complete_mfa_login(&service, request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(!response.access_token.is_empty());
+
+        let mut request = Request::new(auth::TotpCodeRequest { code: MOCK_TOTP_CODE.to_string() });
+        request.extensions_mut().insert(claims.clone());
+        assert!(auth::auth_service_server::AuthService::disable_totp(&service, request).await.is_ok());
+
+        let mut request = Request::new(auth::TotpCodeRequest { code: MOCK_TOTP_CODE.to_string() });
+        request.extensions_mut().insert(claims);
+        let result = auth::auth_service_server::AuthService::disable_totp(&service, request).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::FailedPrecondition);
+    }
+
     #[tokio::test]
     async fn test_grpc_login_invalid_credentials() {
         let config = create_test_security_config();
@@ -527,6 +790,7 @@ mod tests {
             username_or_email: "nonexistent".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         });
 
         let result = auth::auth_service_server::AuthService::login(&service, request).await;
@@ -598,6 +862,26 @@ mod tests {
 
     #[tokio::test]
     async fn test_grpc_logout() {
+        let config = create_test_security_config();
+        let token_provider = TokenProvider::new(config.clone());
+        let user = create_test_user();
+        let access_token = token_provider
+            .generate_access_token(user.id, &user.username, user.email.as_str(), user.role)
+            .unwrap();
+        let claims = token_provider.validate_access_token(&access_token).unwrap();
+        let service = AuthGrpcService::new(Arc::new(MockAuthService::new(config)));
+
+        let mut request = Request::new(auth::LogoutRequest {
+            all_sessions: false,
+        });
+        request.extensions_mut().insert(claims);
+
+        let result = auth::auth_service_server::AuthService::logout(&service, request).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_grpc_logout_requires_auth() {
         let config = create_test_security_config();
         let service = AuthGrpcService::new(Arc::new(MockAuthService::new(config)));
 
@@ -606,6 +890,167 @@ mod tests {
         });
 
         let result = auth::auth_service_server::AuthService::logout(&service, request).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::Unauthenticated);
+    }
+
+    /// Creates claims for a fresh login session.
+    fn create_session_claims(config: Arc<SecurityConfig>, user: &User) -> Claims {
+        let token_provider = TokenProvider::new(config);
+        let tokens = token_provider
+            .generate_tokens(user.id, &user.username, user.email.as_str(), user.role)
+            .unwrap();
+        token_provider.validate_access_token(&tokens.access_token).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_grpc_list_sessions() {
+        let config = create_test_security_config();
+        let claims = create_session_claims(config.clone(), &create_test_user());
+        let service = AuthGrpcService::new(Arc::new(MockAuthService::new(config)));
+
+        let mut request = Request::new(common::Empty {});
+        request.extensions_mut().insert(claims.clone());
+
+        let response = auth::auth_service_server::AuthService::list_sessions(&service, request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.sessions.len(), 1);
+        assert_eq!(Some(&response.sessions[0].id), claims.session_id.as_ref());
+        assert!(response.sessions[0].current);
+        assert!(response.sessions[0].expires_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_grpc_revoke_session() {
+        let config = create_test_security_config();
+        let claims = create_session_claims(config.clone(), &create_test_user());
+        let service = AuthGrpcService::new(Arc::new(MockAuthService::new(config)));
+
+        let mut request = Request::new(auth::RevokeSessionRequest {
+            session_id: claims.session_id.clone().unwrap(),
+        });
+        request.extensions_mut().insert(claims.clone());
+        let result = auth::auth_service_server::AuthService::revoke_session(&service, request).await;
         assert!(result.is_ok());
+
+        let mut request = Request::new(auth::RevokeSessionRequest {
+            session_id: "unknown".to_string(),
+        });
+        request.extensions_mut().insert(claims);
+        let result = auth::auth_service_server::AuthService::revoke_session(&service, request).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_grpc_revoke_other_sessions() {
+        let config = create_test_security_config();
+        let claims = create_session_claims(config.clone(), &create_test_user());
+        let service = AuthGrpcService::new(Arc::new(MockAuthService::new(config)));
+
+        let mut request = Request::new(common::Empty {});
+        request.extensions_mut().insert(claims);
+
+        let response =
+            auth::auth_service_server::AuthService::revoke_other_sessions(&service, request)
+                .await
+                .unwrap()
+                .into_inner();
+        assert!(response.message.contains("other sessions"));
+    }
+
+    #[tokio::test]
+    async fn test_grpc_verify_email() {
+        let config = create_test_security_config();
+        let user = create_test_user();
+        let token = TokenProvider::new(config.clone())
+            .generate_email_verification_token(user.id, &user.username, user.email.as_str(), user.role)
+            .unwrap();
+        let service = AuthGrpcService::new(Arc::new(MockAuthService::with_user(config, user)));
+
+        let request = Request::new(auth::VerifyEmailRequest { token });
+        let response = auth::auth_service_server::AuthService::verify_email(&service, request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.message, "Email verified");
+
+        let request = Request::new(auth::VerifyEmailRequest {
+            token: "not-a-token".to_string(),
+        });
+        let result = auth::auth_service_server::AuthService::verify_email(&service, request).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn test_grpc_resend_verification() {
+        let config = create_test_security_config();
+        let service = AuthGrpcService::new(Arc::new(MockAuthService::new(config)));
+
+        let request = Request::new(auth::ResendVerificationRequest {
+            email: "user@example.com".to_string(),
+        });
+        let result =
+            auth::auth_service_server::AuthService::resend_verification(&service, request).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_grpc_forgot_and_reset_password() {
+        let config = create_test_security_config();
+        let service = AuthGrpcService::new(Arc::new(MockAuthService::new(config)));
+
+        let request = Request::new(auth::ForgotPasswordRequest {
+            email: "user@example.com".to_string(),
+        });
+        let result = auth::auth_service_server::AuthService::forgot_password(&service, request).await;
+        assert!(result.is_ok());
+
+        let request = Request::new(auth::ResetPasswordRequest {
+            token: "valid-reset-token".to_string(),
+            new_password: "NewPassword456".to_string(),
+        });
+        let result = auth::auth_service_server::AuthService::reset_password(&service, request).await;
+        assert!(result.is_ok());
+
+        let request = Request::new(auth::ResetPasswordRequest {
+            token: "bogus".to_string(),
+            new_password: "NewPassword456".to_string(),
+        });
+        let result = auth::auth_service_server::AuthService::reset_password(&service, request).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn test_grpc_session_calls_require_auth() {
+        let config = create_test_security_config();
+        let service = AuthGrpcService::new(Arc::new(MockAuthService::new(config)));
+
+        let result =
+            auth::auth_service_server::AuthService::list_sessions(&service, Request::new(common::Empty {}))
+                .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::Unauthenticated);
+
+        let result = auth::auth_service_server::AuthService::revoke_other_sessions(
+            &service,
+            Request::new(common::Empty {}),
+        )
+        .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::Unauthenticated);
+
+        let result =
+            auth::auth_service_server::AuthService::enroll_totp(&service, Request::new(common::Empty {}))
+                .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn test_remote_user_repository_leaves_events_to_the_service_layer() {
+        use arcana_repository::UserRepository;
+
+        // The outbox relay only runs in monolithic deployments
+        let channel = tonic::transport::Endpoint::from_static("http://127.0.0.1:1").connect_lazy();
+        let repository = super::clients::RemoteUserRepository::from_channel(channel);
+        assert!(!repository.stores_events());
     }
 }
@@ -31,6 +31,11 @@ pub mod repository {
     include!(concat!(env!("OUT_DIR"), "/arcana.repository.rs"));
 }
 
+/// Audit trail service definitions.
+pub mod audit {
+    include!(concat!(env!("OUT_DIR"), "/arcana.audit.rs"));
+}
+
 /// Job queue service definitions.
 pub mod jobs {
     pub mod v1 {
@@ -1,25 +1,38 @@
 //! gRPC server setup.
 
-use crate::proto::{auth, health, jobs, repository, user};
+use crate::interceptors::{auth_layer, RequestContextLayer};
+use crate::proto::{audit, auth, health, jobs, repository, user};
 use crate::services::{
-    AuthGrpcService, HealthServiceImpl, JobQueueServiceImpl, RepositoryGrpcService,
+    AuditGrpcService, AuthGrpcService, HealthServiceImpl, JobQueueServiceImpl, RepositoryGrpcService,
     UserGrpcService, WorkerServiceImpl,
 };
 use crate::tls::TlsConfigBuilder;
 use arcana_config::{SecurityConfig, ServerConfig};
 use arcana_core::ArcanaResult;
-use arcana_repository::UserRepository;
-use arcana_service::{AuthService, UserService};
+use arcana_repository::{
+    AuditLogRepository, MfaRepository, OAuthTokenRepository, PasswordResetTokenRepository, TenantRepository,
+    UserRepository,
+};
+use arcana_security::{PermissionResolverInterface, TokenProviderInterface, TokenRevocationInterface};
+use arcana_service::{AuditService, AuthService, UserService};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tonic::transport::{Server, ServerTlsConfig};
+use tower::ServiceBuilder;
 use tracing::info;
 
-/// gRPC server builder for service layer (exposes UserService and AuthService).
+/// gRPC server builder for service layer (exposes UserService, AuthService and AuditService).
+///
+/// Every request is authenticated (including the revocation check) and runs
+/// inside a request context for the audit trail before it reaches a service.
 pub struct GrpcServer {
     addr: SocketAddr,
     user_service: Arc<dyn UserService>,
     auth_service: Arc<dyn AuthService>,
+    audit_service: Arc<dyn AuditService>,
+    token_provider: Arc<dyn TokenProviderInterface>,
+    token_revocation: Arc<dyn TokenRevocationInterface>,
+    permission_resolver: Option<Arc<dyn PermissionResolverInterface>>,
     tls_config: Option<ServerTlsConfig>,
 }
 
@@ -29,6 +42,9 @@ impl GrpcServer {
         config: &ServerConfig,
         user_service: Arc<dyn UserService>,
         auth_service: Arc<dyn AuthService>,
+        audit_service: Arc<dyn AuditService>,
+        token_provider: Arc<dyn TokenProviderInterface>,
+        token_revocation: Arc<dyn TokenRevocationInterface>,
     ) -> ArcanaResult<Self> {
         let addr = config.grpc_addr().parse().map_err(|e| {
             arcana_core::ArcanaError::Configuration(format!("Invalid gRPC address: {}", e))
@@ -38,6 +54,10 @@ impl GrpcServer {
             addr,
             user_service,
             auth_service,
+            audit_service,
+            token_provider,
+            token_revocation,
+            permission_resolver: None,
             tls_config: None,
         })
     }
@@ -48,6 +68,9 @@ impl GrpcServer {
         security_config: &SecurityConfig,
         user_service: Arc<dyn UserService>,
         auth_service: Arc<dyn AuthService>,
+        audit_service: Arc<dyn AuditService>,
+        token_provider: Arc<dyn TokenProviderInterface>,
+        token_revocation: Arc<dyn TokenRevocationInterface>,
     ) -> ArcanaResult<Self> {
         let addr = config.grpc_addr().parse().map_err(|e| {
             arcana_core::ArcanaError::Configuration(format!("Invalid gRPC address: {}", e))
@@ -61,10 +84,22 @@ impl GrpcServer {
             addr,
             user_service,
             auth_service,
+            audit_service,
+            token_provider,
+            token_revocation,
+            permission_resolver: None,
             tls_config,
         })
     }
 
+    /// Resolves the effective permissions of callers with `resolver` on every
+    /// request, instead of trusting the permissions embedded in their tokens.
+    #[must_use]
+    pub fn with_permission_resolver(mut self, resolver: Arc<dyn PermissionResolverInterface>) -> Self {
+        self.permission_resolver = Some(resolver);
+        self
+    }
+
     /// Starts the gRPC server.
     pub async fn serve(self) -> ArcanaResult<()> {
         let tls_status = if self.tls_config.is_some() { "with TLS" } else { "without TLS" };
@@ -74,6 +109,7 @@ impl GrpcServer {
         let health_service = HealthServiceImpl::new();
         let user_grpc_service = UserGrpcService::new(self.user_service);
         let auth_grpc_service = AuthGrpcService::new(self.auth_service);
+        let audit_grpc_service = AuditGrpcService::new(self.audit_service);
         let job_queue_service = JobQueueServiceImpl::new();
         let worker_service = WorkerServiceImpl::new();
 
@@ -87,9 +123,15 @@ impl GrpcServer {
         }
 
         builder
+            .layer(
+                ServiceBuilder::new()
+                    .layer(auth_layer(self.token_provider, self.token_revocation, self.permission_resolver))
+                    .layer(RequestContextLayer),
+            )
             .add_service(health::health_server::HealthServer::new(health_service))
             .add_service(user::user_service_server::UserServiceServer::new(user_grpc_service))
             .add_service(auth::auth_service_server::AuthServiceServer::new(auth_grpc_service))
+            .add_service(audit::audit_service_server::AuditServiceServer::new(audit_grpc_service))
             .add_service(jobs::v1::job_queue_service_server::JobQueueServiceServer::new(job_queue_service))
             .add_service(jobs::v1::worker_service_server::WorkerServiceServer::new(worker_service))
             .serve(self.addr)
@@ -100,10 +142,16 @@ impl GrpcServer {
     }
 }
 
-/// gRPC server for repository layer (exposes UserRepository).
+/// gRPC server for repository layer (exposes the user, tenant, OAuth token,
+/// password reset token, MFA and audit log repositories).
 pub struct RepositoryGrpcServer {
     addr: SocketAddr,
     user_repository: Arc<dyn UserRepository>,
+    oauth_token_repository: Arc<dyn OAuthTokenRepository>,
+    password_reset_token_repository: Arc<dyn PasswordResetTokenRepository>,
+    mfa_repository: Arc<dyn MfaRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+    tenant_repository: Arc<dyn TenantRepository>,
     tls_config: Option<ServerTlsConfig>,
 }
 
@@ -112,6 +160,11 @@ impl RepositoryGrpcServer {
     pub fn new(
         config: &ServerConfig,
         user_repository: Arc<dyn UserRepository>,
+        oauth_token_repository: Arc<dyn OAuthTokenRepository>,
+        password_reset_token_repository: Arc<dyn PasswordResetTokenRepository>,
+        mfa_repository: Arc<dyn MfaRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+        tenant_repository: Arc<dyn TenantRepository>,
     ) -> ArcanaResult<Self> {
         let addr = config.grpc_addr().parse().map_err(|e| {
             arcana_core::ArcanaError::Configuration(format!("Invalid gRPC address: {}", e))
@@ -120,29 +173,23 @@ impl RepositoryGrpcServer {
         Ok(Self {
             addr,
             user_repository,
+            oauth_token_repository,
+            password_reset_token_repository,
+            mfa_repository,
+            audit_log_repository,
+            tenant_repository,
             tls_config: None,
         })
     }
 
-    /// Creates a new repository gRPC server with TLS configuration.
-    pub fn with_tls(
-        config: &ServerConfig,
-        security_config: &SecurityConfig,
-        user_repository: Arc<dyn UserRepository>,
-    ) -> ArcanaResult<Self> {
-        let addr = config.grpc_addr().parse().map_err(|e| {
-            arcana_core::ArcanaError::Configuration(format!("Invalid gRPC address: {}", e))
-        })?;
-
-        let tls_config = TlsConfigBuilder::from_security_config(security_config)?
+    /// Enables TLS as configured in the security config.
+    ///
+    /// If TLS is disabled in the security config, the server stays plain.
+    pub fn with_tls(mut self, security_config: &SecurityConfig) -> ArcanaResult<Self> {
+        self.tls_config = TlsConfigBuilder::from_security_config(security_config)?
             .map(|builder| builder.build_server_config())
             .transpose()?;
-
-        Ok(Self {
-            addr,
-            user_repository,
-            tls_config,
-        })
+        Ok(self)
     }
 
     /// Starts the repository gRPC server.
@@ -151,7 +198,14 @@ impl RepositoryGrpcServer {
         info!("Starting Repository gRPC server on {} {}", self.addr, tls_status);
 
         let health_service = HealthServiceImpl::new();
-        let repository_service = RepositoryGrpcService::new(self.user_repository);
+        let repository_service = RepositoryGrpcService::new(
+            self.user_repository,
+            self.oauth_token_repository,
+            self.password_reset_token_repository,
+            self.mfa_repository,
+            self.audit_log_repository,
+            self.tenant_repository,
+        );
 
         let mut builder = Server::builder();
 
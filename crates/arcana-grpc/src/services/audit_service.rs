@@ -0,0 +1,272 @@
+//! Audit gRPC service implementation.
+
+use crate::interceptors::require_auth;
+use crate::proto::{audit, common};
+use arcana_core::UserId;
+use arcana_security::guards;
+use arcana_core::AuditChainBreak;
+use arcana_service::dto::{AuditChainQuery, AuditChainReport, AuditLogQuery, AuditLogResponse};
+use arcana_service::AuditService;
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+use tracing::{debug, error};
+
+/// Audit gRPC service implementation.
+///
+/// Queries require the `system:monitor` permission, verifying the hash
+/// chain requires the admin role.
+pub struct AuditGrpcService {
+    audit_service: Arc<dyn AuditService>,
+}
+
+impl AuditGrpcService {
+    /// Creates a new audit gRPC service.
+    pub fn new(audit_service: Arc<dyn AuditService>) -> Self {
+        Self { audit_service }
+    }
+}
+
+#[tonic::async_trait]
+impl audit::audit_service_server::AuditService for AuditGrpcService {
+    async fn query_audit_logs(
+        &self,
+        request: Request<audit::QueryAuditLogsRequest>,
+    ) -> Result<Response<audit::QueryAuditLogsResponse>, Status> {
+        debug!("gRPC QueryAuditLogs");
+
+        let claims = require_auth(&request)?;
+        guards::system_monitor().check(claims, None).map_err(to_status)?;
+
+        let query = from_proto_query(request.into_inner())?;
+        let response = self
+            .audit_service
+            .query_audit_logs(query)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(audit::QueryAuditLogsResponse {
+            entries: response.entries.iter().map(to_proto_entry).collect(),
+            next_cursor: response.next_cursor,
+        }))
+    }
+
+    async fn verify_audit_chain(
+        &self,
+        request: Request<audit::VerifyAuditChainRequest>,
+    ) -> Result<Response<audit::VerifyAuditChainResponse>, Status> {
+        debug!("gRPC VerifyAuditChain");
+
+        let claims = require_auth(&request)?;
+        guards::admin().default_tenant_only().check(claims, None).map_err(to_status)?;
+
+        let req = request.into_inner();
+        let report = self
+            .audit_service
+            .verify_audit_chain(AuditChainQuery {
+                from: req.from.as_ref().and_then(from_proto_timestamp),
+                to: req.to.as_ref().and_then(from_proto_timestamp),
+            })
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(to_proto_report(&report)))
+    }
+}
+
+// Helper functions
+
+fn from_proto_query(req: audit::QueryAuditLogsRequest) -> Result<AuditLogQuery, Status> {
+    Ok(AuditLogQuery {
+        user_id: req
+            .user_id
+            .as_deref()
+            .map(UserId::parse)
+            .transpose()
+            .map_err(|e| Status::invalid_argument(format!("Invalid user ID: {}", e)))?,
+        action: req
+            .action
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(|e: arcana_core::ArcanaError| Status::invalid_argument(e.to_string()))?,
+        resource_type: req.resource_type,
+        success: req.success,
+        from: req.from.as_ref().and_then(from_proto_timestamp),
+        to: req.to.as_ref().and_then(from_proto_timestamp),
+        cursor: req.cursor,
+        limit: req.limit.map(|limit| limit as usize),
+    })
+}
+
+fn to_proto_entry(entry: &AuditLogResponse) -> audit::AuditLogEntry {
+    audit::AuditLogEntry {
+        id: entry.id.to_string(),
+        user_id: entry.user_id.map(|id| id.to_string()),
+        action: entry.action.to_string(),
+        resource_type: entry.resource_type.clone(),
+        resource_id: entry.resource_id.clone(),
+        details: entry.details.as_ref().map(ToString::to_string),
+        ip_address: entry.ip_address.clone(),
+        user_agent: entry.user_agent.clone(),
+        success: entry.success,
+        error_message: entry.error_message.clone(),
+        timestamp: Some(common::Timestamp {
+            seconds: entry.timestamp.timestamp(),
+            nanos: entry.timestamp.timestamp_subsec_nanos() as i32,
+        }),
+        sequence: entry.sequence,
+        prev_hash: entry.prev_hash.clone(),
+        hash: entry.hash.clone(),
+    }
+}
+
+fn to_proto_report(report: &AuditChainReport) -> audit::VerifyAuditChainResponse {
+    audit::VerifyAuditChainResponse {
+        intact: report.intact,
+        checked: report.checked,
+        first_sequence: report.first_sequence,
+        last_sequence: report.last_sequence,
+        broken_link: report.broken_link.as_ref().map(to_proto_break),
+    }
+}
+
+fn to_proto_break(broken: &AuditChainBreak) -> audit::AuditChainBreak {
+    audit::AuditChainBreak {
+        sequence: broken.sequence,
+        id: broken.id.map(|id| id.to_string()),
+        reason: broken.reason.to_string(),
+    }
+}
+
+fn from_proto_timestamp(ts: &common::Timestamp) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp(ts.seconds, ts.nanos as u32)
+}
+
+fn to_status(err: arcana_core::ArcanaError) -> Status {
+    use arcana_core::ArcanaError;
+
+    error!("gRPC audit error: {:?}", err);
+
+    match err {
+        ArcanaError::Validation(msg) => Status::invalid_argument(msg),
+        ArcanaError::Unauthorized(_) => Status::unauthenticated("Unauthorized"),
+        ArcanaError::Forbidden(msg) => Status::permission_denied(msg),
+        _ => Status::internal(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{ArcanaResult, AuditAction, AuditChainBreakReason, AuditLog, TenantId, UserRole};
+    use arcana_security::Claims;
+    use arcana_service::dto::AuditLogListResponse;
+    use async_trait::async_trait;
+    use audit::audit_service_server::AuditService as _;
+    use chrono::{Duration, Utc};
+
+    /// Audit service that returns a single failed login.
+    struct StubAuditService;
+
+    #[async_trait]
+    impl AuditService for StubAuditService {
+        async fn query_audit_logs(&self, query: AuditLogQuery) -> ArcanaResult<AuditLogListResponse> {
+            let entry = AuditLog::failure(query.user_id, AuditAction::Login, "user", None, "Invalid credentials");
+            Ok(AuditLogListResponse {
+                entries: vec![entry.into()],
+                next_cursor: None,
+            })
+        }
+
+        async fn verify_audit_chain(&self, _query: AuditChainQuery) -> ArcanaResult<AuditChainReport> {
+            Ok(AuditChainReport {
+                intact: false,
+                checked: 6,
+                first_sequence: Some(1),
+                last_sequence: Some(9),
+                broken_link: Some(AuditChainBreak {
+                    sequence: 7,
+                    id: None,
+                    reason: AuditChainBreakReason::MissingEntries,
+                }),
+            })
+        }
+    }
+
+    fn request_as(role: Option<UserRole>) -> Request<audit::QueryAuditLogsRequest> {
+        with_claims(
+            Request::new(audit::QueryAuditLogsRequest {
+                action: Some("LOGIN".to_string()),
+                ..Default::default()
+            }),
+            role,
+        )
+    }
+
+    fn with_claims<T>(mut request: Request<T>, role: Option<UserRole>) -> Request<T> {
+        if let Some(role) = role {
+            request.extensions_mut().insert(Claims::new_access(
+                UserId::new(),
+                "monitor".to_string(),
+                "monitor@example.com".to_string(),
+                role,
+                "arcana".to_string(),
+                "arcana".to_string(),
+                Utc::now() + Duration::minutes(15),
+            ));
+        }
+        request
+    }
+
+    #[tokio::test]
+    async fn test_query_requires_system_monitor() {
+        let service = AuditGrpcService::new(Arc::new(StubAuditService));
+
+        let status = service.query_audit_logs(request_as(None)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        let status = service.query_audit_logs(request_as(Some(UserRole::User))).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        let response = service
+            .query_audit_logs(request_as(Some(UserRole::Moderator)))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.entries[0].action, "LOGIN");
+        assert!(!response.entries[0].success);
+    }
+
+    #[tokio::test]
+    async fn test_verify_requires_admin() {
+        let service = AuditGrpcService::new(Arc::new(StubAuditService));
+        let request = |role| with_claims(Request::new(audit::VerifyAuditChainRequest::default()), Some(role));
+
+        let status = service.verify_audit_chain(request(UserRole::Moderator)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        let response = service.verify_audit_chain(request(UserRole::Admin)).await.unwrap().into_inner();
+        assert!(!response.intact);
+        let broken = response.broken_link.unwrap();
+        assert_eq!(broken.sequence, 7);
+        assert_eq!(broken.reason, "missing_entries");
+        assert!(broken.id.is_none());
+
+        // The audit chain covers every tenant, so tenant admins may not verify it
+        let mut request = request(UserRole::Admin);
+        let claims = request.extensions().get::<Claims>().unwrap().clone().with_tenant(TenantId::new());
+        request.extensions_mut().insert(claims);
+        let status = service.verify_audit_chain(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[test]
+    fn test_from_proto_query_rejects_unknown_action() {
+        let status = from_proto_query(audit::QueryAuditLogsRequest {
+            action: Some("login".to_string()),
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+}
@@ -1,7 +1,12 @@
 //! Authentication gRPC service implementation.
 
+use crate::interceptors::require_auth;
 use crate::proto::{auth, common, user as user_proto};
-use arcana_service::dto::{LoginRequest, RefreshTokenRequest, RegisterRequest};
+use arcana_service::dto::{
+    LoginRequest, LoginResponse, MfaLoginRequest, PasswordResetConfirmRequest,
+    PasswordResetRequest, RecoveryCodesResponse, RefreshTokenRequest, RegisterRequest,
+    ResendVerificationRequest, SessionInfo, TotpCodeRequest, VerifyEmailRequest,
+};
 use arcana_service::AuthService;
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
@@ -48,7 +53,8 @@ impl auth::auth_service_server::AuthService for AuthGrpcService {
     async fn login(
         &self,
         request: Request<auth::LoginRequest>,
-    ) -> Result<Response<auth::AuthResponse>, Status> {
+    ) -> Result<Response<auth::LoginResponse>, Status> {
+        let peer_ip = request.remote_addr().map(|addr| addr.ip().to_string());
         let req = request.into_inner();
         debug!("gRPC Login: {}", req.username_or_email);
 
@@ -56,6 +62,7 @@ impl auth::auth_service_server::AuthService for AuthGrpcService {
             username_or_email: req.username_or_email,
             password: req.password,
             device_id: req.device_id.filter(|s| !s.is_empty()),
+            ip_address: req.ip_address.filter(|s| !s.is_empty()).or(peer_ip),
         };
 
         let response = self
@@ -64,6 +71,38 @@ impl auth::auth_service_server::AuthService for AuthGrpcService {
             .await
             .map_err(to_status)?;
 
+        let result = match response {
+            LoginResponse::Authenticated(response) => {
+                auth::login_response::Result::Authenticated(to_proto_auth_response(response))
+            }
+            LoginResponse::MfaRequired(challenge) => {
+                auth::login_response::Result::MfaRequired(auth::MfaChallenge {
+                    mfa_token: challenge.mfa_token,
+                    expires_in: challenge.expires_in,
+                })
+            }
+        };
+
+        Ok(Response::new(auth::LoginResponse { result: Some(result) }))
+    }
+
+    async fn complete_mfa_login(
+        &self,
+        request: Request<auth::CompleteMfaLoginRequest>,
+    ) -> Result<Response<auth::AuthResponse>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC CompleteMfaLogin");
+
+        let response = self
+            .auth_service
+            .complete_mfa_login(MfaLoginRequest {
+                mfa_token: req.mfa_token,
+                code: req.code,
+                device_id: req.device_id.filter(|s| !s.is_empty()),
+            })
+            .await
+            .map_err(to_status)?;
+
         Ok(Response::new(to_proto_auth_response(response)))
     }
 
@@ -113,23 +152,247 @@ impl auth::auth_service_server::AuthService for AuthGrpcService {
         &self,
         request: Request<auth::LogoutRequest>,
     ) -> Result<Response<common::Empty>, Status> {
-        let _req = request.into_inner();
         debug!("gRPC Logout");
 
-        // For logout without user_id in request, we'd typically get it from the auth context
-        // For now, just return success
+        let claims = require_auth(&request)?;
+
+        self.auth_service
+            .logout(claims)
+            .await
+            .map_err(to_status)?;
+
         Ok(Response::new(common::Empty {}))
     }
 
     async fn get_current_user(
         &self,
-        _request: Request<common::Empty>,
+        request: Request<common::Empty>,
     ) -> Result<Response<auth::CurrentUserResponse>, Status> {
         debug!("gRPC GetCurrentUser");
 
-        // In a real implementation, we'd extract the claims from the request metadata
-        // For now, return an error since we don't have the auth context
-        Err(Status::unauthenticated("No authentication context available"))
+        let claims = require_auth(&request)?;
+
+        let user = self
+            .auth_service
+            .get_current_user(claims)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(auth::CurrentUserResponse {
+            user: Some(auth::AuthUserInfo {
+                id: user.id.to_string(),
+                username: user.username,
+                email: user.email,
+                role: to_proto_role(user.role).into(),
+                first_name: user.first_name,
+                last_name: user.last_name,
+            }),
+        }))
+    }
+
+    async fn list_sessions(
+        &self,
+        request: Request<common::Empty>,
+    ) -> Result<Response<auth::ListSessionsResponse>, Status> {
+        debug!("gRPC ListSessions");
+
+        let claims = require_auth(&request)?;
+
+        let sessions = self
+            .auth_service
+            .list_sessions(claims)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(auth::ListSessionsResponse {
+            sessions: sessions.into_iter().map(to_proto_session).collect(),
+        }))
+    }
+
+    async fn revoke_session(
+        &self,
+        request: Request<auth::RevokeSessionRequest>,
+    ) -> Result<Response<common::Empty>, Status> {
+        debug!("gRPC RevokeSession: {}", request.get_ref().session_id);
+
+        let claims = require_auth(&request)?;
+
+        self.auth_service
+            .revoke_session(claims, &request.get_ref().session_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(common::Empty {}))
+    }
+
+    async fn revoke_other_sessions(
+        &self,
+        request: Request<common::Empty>,
+    ) -> Result<Response<auth::RevokeOtherSessionsResponse>, Status> {
+        debug!("gRPC RevokeOtherSessions");
+
+        let claims = require_auth(&request)?;
+
+        let response = self
+            .auth_service
+            .revoke_other_sessions(claims)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(auth::RevokeOtherSessionsResponse {
+            message: response.message,
+        }))
+    }
+
+    async fn verify_email(
+        &self,
+        request: Request<auth::VerifyEmailRequest>,
+    ) -> Result<Response<auth::MessageResponse>, Status> {
+        debug!("gRPC VerifyEmail");
+
+        let response = self
+            .auth_service
+            .verify_email(VerifyEmailRequest {
+                token: request.into_inner().token,
+            })
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(auth::MessageResponse {
+            message: response.message,
+        }))
+    }
+
+    async fn resend_verification(
+        &self,
+        request: Request<auth::ResendVerificationRequest>,
+    ) -> Result<Response<auth::MessageResponse>, Status> {
+        debug!("gRPC ResendVerification");
+
+        let response = self
+            .auth_service
+            .resend_verification(ResendVerificationRequest {
+                email: request.into_inner().email,
+            })
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(auth::MessageResponse {
+            message: response.message,
+        }))
+    }
+
+    async fn forgot_password(
+        &self,
+        request: Request<auth::ForgotPasswordRequest>,
+    ) -> Result<Response<auth::MessageResponse>, Status> {
+        debug!("gRPC ForgotPassword");
+
+        let response = self
+            .auth_service
+            .forgot_password(PasswordResetRequest {
+                email: request.into_inner().email,
+            })
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(auth::MessageResponse {
+            message: response.message,
+        }))
+    }
+
+    async fn reset_password(
+        &self,
+        request: Request<auth::ResetPasswordRequest>,
+    ) -> Result<Response<auth::MessageResponse>, Status> {
+        debug!("gRPC ResetPassword");
+
+        let req = request.into_inner();
+        let response = self
+            .auth_service
+            .reset_password(PasswordResetConfirmRequest {
+                token: req.token,
+                new_password: req.new_password,
+            })
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(auth::MessageResponse {
+            message: response.message,
+        }))
+    }
+
+    async fn enroll_totp(
+        &self,
+        request: Request<common::Empty>,
+    ) -> Result<Response<auth::TotpEnrollmentResponse>, Status> {
+        debug!("gRPC EnrollTotp");
+
+        let claims = require_auth(&request)?;
+
+        let response = self
+            .auth_service
+            .enroll_totp(claims)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(auth::TotpEnrollmentResponse {
+            secret: response.secret,
+            otpauth_uri: response.otpauth_uri,
+        }))
+    }
+
+    async fn enable_totp(
+        &self,
+        request: Request<auth::TotpCodeRequest>,
+    ) -> Result<Response<auth::RecoveryCodesResponse>, Status> {
+        debug!("gRPC EnableTotp");
+
+        let claims = require_auth(&request)?;
+
+        let response = self
+            .auth_service
+            .enable_totp(claims, to_totp_code_request(&request))
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(to_proto_recovery_codes(response)))
+    }
+
+    async fn disable_totp(
+        &self,
+        request: Request<auth::TotpCodeRequest>,
+    ) -> Result<Response<auth::MessageResponse>, Status> {
+        debug!("gRPC DisableTotp");
+
+        let claims = require_auth(&request)?;
+
+        let response = self
+            .auth_service
+            .disable_totp(claims, to_totp_code_request(&request))
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(auth::MessageResponse {
+            message: response.message,
+        }))
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        request: Request<auth::TotpCodeRequest>,
+    ) -> Result<Response<auth::RecoveryCodesResponse>, Status> {
+        debug!("gRPC RegenerateRecoveryCodes");
+
+        let claims = require_auth(&request)?;
+
+        let response = self
+            .auth_service
+            .regenerate_recovery_codes(claims, to_totp_code_request(&request))
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(to_proto_recovery_codes(response)))
     }
 }
 
@@ -143,6 +406,7 @@ fn to_status(err: arcana_core::ArcanaError) -> Status {
     match err {
         ArcanaError::NotFound { .. } => Status::not_found(err.to_string()),
         ArcanaError::Validation(msg) => Status::invalid_argument(msg),
+        ArcanaError::BusinessRule(msg) => Status::failed_precondition(msg),
         ArcanaError::Conflict(msg) => Status::already_exists(msg),
         ArcanaError::Unauthorized(_) => Status::unauthenticated("Unauthorized"),
         ArcanaError::Forbidden(msg) => Status::permission_denied(msg),
@@ -170,6 +434,36 @@ fn to_proto_auth_response(response: arcana_service::dto::AuthResponse) -> auth::
     }
 }
 
+fn to_totp_code_request(request: &Request<auth::TotpCodeRequest>) -> TotpCodeRequest {
+    TotpCodeRequest {
+        code: request.get_ref().code.clone(),
+    }
+}
+
+fn to_proto_recovery_codes(response: RecoveryCodesResponse) -> auth::RecoveryCodesResponse {
+    auth::RecoveryCodesResponse {
+        recovery_codes: response.recovery_codes,
+    }
+}
+
+fn to_proto_session(session: SessionInfo) -> auth::SessionInfo {
+    auth::SessionInfo {
+        id: session.id,
+        device_id: session.device_id,
+        user_agent: session.user_agent,
+        ip_address: session.ip_address,
+        last_active_at: Some(common::Timestamp {
+            seconds: session.last_active_at.timestamp(),
+            nanos: session.last_active_at.timestamp_subsec_nanos() as i32,
+        }),
+        expires_at: Some(common::Timestamp {
+            seconds: session.expires_at.timestamp(),
+            nanos: session.expires_at.timestamp_subsec_nanos() as i32,
+        }),
+        current: session.current,
+    }
+}
+
 fn to_proto_role(role: arcana_core::UserRole) -> user_proto::UserRole {
     match role {
         arcana_core::UserRole::User => user_proto::UserRole::User,
@@ -1,11 +1,13 @@
 //! gRPC service implementations.
 
+mod audit_service;
 mod auth_service;
 mod health_service;
 mod jobs_service;
 mod repository_service;
 mod user_service;
 
+pub use audit_service::*;
 pub use auth_service::*;
 pub use health_service::*;
 pub use jobs_service::*;
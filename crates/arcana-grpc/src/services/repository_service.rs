@@ -3,9 +3,13 @@
 //! This service exposes the repository layer via gRPC for distributed deployments.
 
 use crate::proto::{common, repository, user as user_proto};
-use arcana_core::{Page, PageRequest, UserId};
+use arcana_core::{OAuthToken, OAuthTokenId, Page, PageRequest, Tenant, TenantId, UserId};
+use arcana_core::{AuditLog, AuditLogCursor, AuditLogFilter, AuditLogId, PasswordResetToken, PasswordResetTokenId, TotpCredential};
 use arcana_core::{Email, User, UserRole, UserStatus};
-use arcana_repository::UserRepository;
+use arcana_repository::{
+    AuditLogRepository, MfaRepository, OAuthTokenRepository, PasswordResetTokenRepository, TenantRepository,
+    UserRepository,
+};
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 use tracing::{debug, error};
@@ -13,12 +17,31 @@ use tracing::{debug, error};
 /// Repository gRPC service implementation.
 pub struct RepositoryGrpcService {
     user_repository: Arc<dyn UserRepository>,
+    oauth_token_repository: Arc<dyn OAuthTokenRepository>,
+    password_reset_token_repository: Arc<dyn PasswordResetTokenRepository>,
+    mfa_repository: Arc<dyn MfaRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+    tenant_repository: Arc<dyn TenantRepository>,
 }
 
 impl RepositoryGrpcService {
     /// Creates a new repository gRPC service.
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        oauth_token_repository: Arc<dyn OAuthTokenRepository>,
+        password_reset_token_repository: Arc<dyn PasswordResetTokenRepository>,
+        mfa_repository: Arc<dyn MfaRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+        tenant_repository: Arc<dyn TenantRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            oauth_token_repository,
+            password_reset_token_repository,
+            mfa_repository,
+            audit_log_repository,
+            tenant_repository,
+        }
     }
 }
 
@@ -53,7 +76,7 @@ impl repository::repository_service_server::RepositoryService for RepositoryGrpc
 
         let user = self
             .user_repository
-            .find_by_username(&req.username)
+            .find_by_username(parse_tenant_id(&req.tenant_id)?, &req.username)
             .await
             .map_err(to_status)?;
 
@@ -71,7 +94,7 @@ impl repository::repository_service_server::RepositoryService for RepositoryGrpc
 
         let user = self
             .user_repository
-            .find_by_email(&req.email)
+            .find_by_email(parse_tenant_id(&req.tenant_id)?, &req.email)
             .await
             .map_err(to_status)?;
 
@@ -89,7 +112,7 @@ impl repository::repository_service_server::RepositoryService for RepositoryGrpc
 
         let user = self
             .user_repository
-            .find_by_username_or_email(&req.identifier)
+            .find_by_username_or_email(parse_tenant_id(&req.tenant_id)?, &req.identifier)
             .await
             .map_err(to_status)?;
 
@@ -107,7 +130,7 @@ impl repository::repository_service_server::RepositoryService for RepositoryGrpc
 
         let exists = self
             .user_repository
-            .exists_by_username(&req.username)
+            .exists_by_username(parse_tenant_id(&req.tenant_id)?, &req.username)
             .await
             .map_err(to_status)?;
 
@@ -123,7 +146,7 @@ impl repository::repository_service_server::RepositoryService for RepositoryGrpc
 
         let exists = self
             .user_repository
-            .exists_by_email(&req.email)
+            .exists_by_email(parse_tenant_id(&req.tenant_id)?, &req.email)
             .await
             .map_err(to_status)?;
 
@@ -138,13 +161,13 @@ impl repository::repository_service_server::RepositoryService for RepositoryGrpc
         debug!("gRPC FindAllUsers");
 
         let page_request = req.page.map_or_else(
-            || PageRequest::default(),
+            PageRequest::default,
             |p| PageRequest::new(p.page as usize, p.size as usize),
         );
 
         let page = self
             .user_repository
-            .find_all(page_request)
+            .find_all(parse_tenant_id(&req.tenant_id)?, page_request)
             .await
             .map_err(to_status)?;
 
@@ -160,13 +183,13 @@ impl repository::repository_service_server::RepositoryService for RepositoryGrpc
 
         let role = from_proto_role(user_proto::UserRole::try_from(req.role).unwrap_or(user_proto::UserRole::User));
         let page_request = req.page.map_or_else(
-            || PageRequest::default(),
+            PageRequest::default,
             |p| PageRequest::new(p.page as usize, p.size as usize),
         );
 
         let page = self
             .user_repository
-            .find_by_role(role, page_request)
+            .find_by_role(parse_tenant_id(&req.tenant_id)?, role, page_request)
             .await
             .map_err(to_status)?;
 
@@ -235,13 +258,14 @@ impl repository::repository_service_server::RepositoryService for RepositoryGrpc
 
     async fn count_users(
         &self,
-        _request: Request<repository::CountUsersRequest>,
+        request: Request<repository::CountUsersRequest>,
     ) -> Result<Response<repository::CountResult>, Status> {
+        let req = request.into_inner();
         debug!("gRPC CountUsers");
 
         let count = self
             .user_repository
-            .count()
+            .count(parse_tenant_id(&req.tenant_id)?)
             .await
             .map_err(to_status)?;
 
@@ -259,12 +283,640 @@ impl repository::repository_service_server::RepositoryService for RepositoryGrpc
 
         let count = self
             .user_repository
-            .count_by_role(role)
+            .count_by_role(parse_tenant_id(&req.tenant_id)?, role)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::CountResult { count }))
+    }
+
+    async fn find_tenant_by_id(
+        &self,
+        request: Request<repository::FindTenantByIdRequest>,
+    ) -> Result<Response<repository::TenantResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindTenantById: {}", req.tenant_id);
+
+        let tenant = self
+            .tenant_repository
+            .find_by_id(parse_tenant_id(&req.tenant_id)?)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::TenantResult {
+            tenant: tenant.map(|t| to_proto_tenant_data(&t)),
+        }))
+    }
+
+    async fn find_tenant_by_slug(
+        &self,
+        request: Request<repository::FindTenantBySlugRequest>,
+    ) -> Result<Response<repository::TenantResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindTenantBySlug: {}", req.slug);
+
+        let tenant = self
+            .tenant_repository
+            .find_by_slug(&req.slug)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::TenantResult {
+            tenant: tenant.map(|t| to_proto_tenant_data(&t)),
+        }))
+    }
+
+    async fn find_all_tenants(
+        &self,
+        request: Request<repository::FindAllTenantsRequest>,
+    ) -> Result<Response<repository::TenantListResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindAllTenants");
+
+        let page_request = req.page.map_or_else(
+            PageRequest::default,
+            |p| PageRequest::new(p.page as usize, p.size as usize),
+        );
+
+        let page = self
+            .tenant_repository
+            .find_all(page_request)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::TenantListResult {
+            tenants: page.content.iter().map(to_proto_tenant_data).collect(),
+            page_info: Some(common::PageInfo {
+                page: page.info.page as i32,
+                size: page.info.size as i32,
+                total_elements: page.info.total_elements as i64,
+                total_pages: page.info.total_pages as i64,
+                first: page.info.first,
+                last: page.info.last,
+            }),
+        }))
+    }
+
+    async fn save_tenant(
+        &self,
+        request: Request<repository::SaveTenantRequest>,
+    ) -> Result<Response<repository::TenantResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC SaveTenant");
+
+        let tenant_data = req.tenant.ok_or_else(|| Status::invalid_argument("Tenant is required"))?;
+        let tenant = from_proto_tenant_data(&tenant_data)?;
+
+        let saved = self
+            .tenant_repository
+            .save(&tenant)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::TenantResult {
+            tenant: Some(to_proto_tenant_data(&saved)),
+        }))
+    }
+
+    async fn update_tenant(
+        &self,
+        request: Request<repository::UpdateTenantRequest>,
+    ) -> Result<Response<repository::TenantResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC UpdateTenant");
+
+        let tenant_data = req.tenant.ok_or_else(|| Status::invalid_argument("Tenant is required"))?;
+        let tenant = from_proto_tenant_data(&tenant_data)?;
+
+        let updated = self
+            .tenant_repository
+            .update(&tenant)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::TenantResult {
+            tenant: Some(to_proto_tenant_data(&updated)),
+        }))
+    }
+
+    async fn find_o_auth_token_by_id(
+        &self,
+        request: Request<repository::FindOAuthTokenByIdRequest>,
+    ) -> Result<Response<repository::OAuthTokenResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindOAuthTokenById: {}", req.token_id);
+
+        let token_id = OAuthTokenId::parse(&req.token_id)
+            .map_err(|e| Status::invalid_argument(format!("Invalid token ID: {}", e)))?;
+
+        let token = self
+            .oauth_token_repository
+            .find_by_id(token_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::OAuthTokenResult {
+            token: token.map(|t| to_proto_oauth_token_data(&t)),
+        }))
+    }
+
+    async fn find_o_auth_token_by_hash(
+        &self,
+        request: Request<repository::FindOAuthTokenByHashRequest>,
+    ) -> Result<Response<repository::OAuthTokenResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindOAuthTokenByHash");
+
+        let token = self
+            .oauth_token_repository
+            .find_by_token_hash(&req.token_hash)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::OAuthTokenResult {
+            token: token.map(|t| to_proto_oauth_token_data(&t)),
+        }))
+    }
+
+    async fn save_o_auth_token(
+        &self,
+        request: Request<repository::SaveOAuthTokenRequest>,
+    ) -> Result<Response<repository::OAuthTokenResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC SaveOAuthToken");
+
+        let token_data = req.token.ok_or_else(|| Status::invalid_argument("Token is required"))?;
+        let token = from_proto_oauth_token_data(&token_data)?;
+
+        let saved = self
+            .oauth_token_repository
+            .save(&token)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::OAuthTokenResult {
+            token: Some(to_proto_oauth_token_data(&saved)),
+        }))
+    }
+
+    async fn update_o_auth_token(
+        &self,
+        request: Request<repository::UpdateOAuthTokenRequest>,
+    ) -> Result<Response<repository::OAuthTokenResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC UpdateOAuthToken");
+
+        let token_data = req.token.ok_or_else(|| Status::invalid_argument("Token is required"))?;
+        let token = from_proto_oauth_token_data(&token_data)?;
+
+        let updated = self
+            .oauth_token_repository
+            .update(&token)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::OAuthTokenResult {
+            token: Some(to_proto_oauth_token_data(&updated)),
+        }))
+    }
+
+    async fn find_active_o_auth_tokens_by_user(
+        &self,
+        request: Request<repository::FindActiveOAuthTokensByUserRequest>,
+    ) -> Result<Response<repository::OAuthTokenListResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindActiveOAuthTokensByUser: {}", req.user_id);
+
+        let user_id = parse_user_id(&req.user_id)?;
+
+        let tokens = self
+            .oauth_token_repository
+            .find_active_by_user(user_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::OAuthTokenListResult {
+            tokens: tokens.iter().map(to_proto_oauth_token_data).collect(),
+        }))
+    }
+
+    async fn revoke_all_o_auth_tokens_by_user(
+        &self,
+        request: Request<repository::RevokeAllOAuthTokensByUserRequest>,
+    ) -> Result<Response<repository::CountResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC RevokeAllOAuthTokensByUser: {}", req.user_id);
+
+        let user_id = parse_user_id(&req.user_id)?;
+
+        let count = self
+            .oauth_token_repository
+            .revoke_all_by_user(user_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::CountResult { count }))
+    }
+
+    async fn mark_o_auth_token_rotated(
+        &self,
+        request: Request<repository::MarkOAuthTokenRotatedRequest>,
+    ) -> Result<Response<repository::MarkOAuthTokenRotatedResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC MarkOAuthTokenRotated: {}", req.token_id);
+
+        let token_id = OAuthTokenId::parse(&req.token_id)
+            .map_err(|_| Status::invalid_argument("Invalid token ID format"))?;
+
+        let rotated = self
+            .oauth_token_repository
+            .mark_rotated(token_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::MarkOAuthTokenRotatedResult { rotated }))
+    }
+
+    async fn revoke_o_auth_token_family(
+        &self,
+        request: Request<repository::RevokeOAuthTokenFamilyRequest>,
+    ) -> Result<Response<repository::CountResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC RevokeOAuthTokenFamily: {}", req.family_id);
+
+        let count = self
+            .oauth_token_repository
+            .revoke_family(&req.family_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::CountResult { count }))
+    }
+
+    async fn find_password_reset_token_by_id(
+        &self,
+        request: Request<repository::FindPasswordResetTokenByIdRequest>,
+    ) -> Result<Response<repository::PasswordResetTokenResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindPasswordResetTokenById: {}", req.token_id);
+
+        let token_id = PasswordResetTokenId::parse(&req.token_id)
+            .map_err(|e| Status::invalid_argument(format!("Invalid token ID: {}", e)))?;
+
+        let token = self
+            .password_reset_token_repository
+            .find_by_id(token_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::PasswordResetTokenResult {
+            token: token.map(|t| to_proto_password_reset_token_data(&t)),
+        }))
+    }
+
+    async fn find_password_reset_token_by_hash(
+        &self,
+        request: Request<repository::FindPasswordResetTokenByHashRequest>,
+    ) -> Result<Response<repository::PasswordResetTokenResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindPasswordResetTokenByHash");
+
+        let token = self
+            .password_reset_token_repository
+            .find_by_token_hash(&req.token_hash)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::PasswordResetTokenResult {
+            token: token.map(|t| to_proto_password_reset_token_data(&t)),
+        }))
+    }
+
+    async fn save_password_reset_token(
+        &self,
+        request: Request<repository::SavePasswordResetTokenRequest>,
+    ) -> Result<Response<repository::PasswordResetTokenResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC SavePasswordResetToken");
+
+        let token_data = req.token.ok_or_else(|| Status::invalid_argument("Token is required"))?;
+        let token = from_proto_password_reset_token_data(&token_data)?;
+
+        let saved = self
+            .password_reset_token_repository
+            .save(&token)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::PasswordResetTokenResult {
+            token: Some(to_proto_password_reset_token_data(&saved)),
+        }))
+    }
+
+    async fn mark_password_reset_token_used(
+        &self,
+        request: Request<repository::MarkPasswordResetTokenUsedRequest>,
+    ) -> Result<Response<repository::MarkPasswordResetTokenUsedResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC MarkPasswordResetTokenUsed: {}", req.token_id);
+
+        let token_id = PasswordResetTokenId::parse(&req.token_id)
+            .map_err(|_| Status::invalid_argument("Invalid token ID format"))?;
+
+        let used = self
+            .password_reset_token_repository
+            .mark_used(token_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::MarkPasswordResetTokenUsedResult { used }))
+    }
+
+    async fn invalidate_password_reset_tokens_by_user(
+        &self,
+        request: Request<repository::InvalidatePasswordResetTokensByUserRequest>,
+    ) -> Result<Response<repository::CountResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC InvalidatePasswordResetTokensByUser: {}", req.user_id);
+
+        let user_id = parse_user_id(&req.user_id)?;
+
+        let count = self
+            .password_reset_token_repository
+            .invalidate_all_by_user(user_id)
             .await
             .map_err(to_status)?;
 
         Ok(Response::new(repository::CountResult { count }))
     }
+
+    async fn find_totp_credential_by_user(
+        &self,
+        request: Request<repository::FindTotpCredentialByUserRequest>,
+    ) -> Result<Response<repository::TotpCredentialResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindTotpCredentialByUser: {}", req.user_id);
+
+        let user_id = parse_user_id(&req.user_id)?;
+
+        let credential = self
+            .mfa_repository
+            .find_totp_by_user(user_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::TotpCredentialResult {
+            credential: credential.map(|c| to_proto_totp_credential_data(&c)),
+        }))
+    }
+
+    async fn save_totp_credential(
+        &self,
+        request: Request<repository::SaveTotpCredentialRequest>,
+    ) -> Result<Response<repository::TotpCredentialResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC SaveTotpCredential");
+
+        let credential_data = req
+            .credential
+            .ok_or_else(|| Status::invalid_argument("Credential is required"))?;
+        let credential = from_proto_totp_credential_data(&credential_data)?;
+
+        let saved = self
+            .mfa_repository
+            .save_totp(&credential)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::TotpCredentialResult {
+            credential: Some(to_proto_totp_credential_data(&saved)),
+        }))
+    }
+
+    async fn record_totp_step(
+        &self,
+        request: Request<repository::RecordTotpStepRequest>,
+    ) -> Result<Response<repository::RecordTotpStepResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC RecordTotpStep: {}", req.user_id);
+
+        let user_id = parse_user_id(&req.user_id)?;
+
+        let recorded = self
+            .mfa_repository
+            .record_totp_step(user_id, req.step)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::RecordTotpStepResult { recorded }))
+    }
+
+    async fn delete_mfa_by_user(
+        &self,
+        request: Request<repository::DeleteMfaByUserRequest>,
+    ) -> Result<Response<repository::DeleteResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC DeleteMfaByUser: {}", req.user_id);
+
+        let user_id = parse_user_id(&req.user_id)?;
+
+        let deleted = self
+            .mfa_repository
+            .delete_by_user(user_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::DeleteResult { deleted }))
+    }
+
+    async fn replace_recovery_codes(
+        &self,
+        request: Request<repository::ReplaceRecoveryCodesRequest>,
+    ) -> Result<Response<common::Empty>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC ReplaceRecoveryCodes: {}", req.user_id);
+
+        let user_id = parse_user_id(&req.user_id)?;
+
+        self.mfa_repository
+            .replace_recovery_codes(user_id, &req.code_hashes)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(common::Empty {}))
+    }
+
+    async fn consume_recovery_code(
+        &self,
+        request: Request<repository::ConsumeRecoveryCodeRequest>,
+    ) -> Result<Response<repository::ConsumeRecoveryCodeResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC ConsumeRecoveryCode: {}", req.user_id);
+
+        let user_id = parse_user_id(&req.user_id)?;
+
+        let consumed = self
+            .mfa_repository
+            .consume_recovery_code(user_id, &req.code_hash)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::ConsumeRecoveryCodeResult { consumed }))
+    }
+
+    async fn save_audit_log(
+        &self,
+        request: Request<repository::SaveAuditLogRequest>,
+    ) -> Result<Response<repository::AuditLogResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC SaveAuditLog");
+
+        let entry_data = req
+            .entry
+            .ok_or_else(|| Status::invalid_argument("Entry is required"))?;
+        let entry = from_proto_audit_log_data(&entry_data)?;
+
+        let saved = self
+            .audit_log_repository
+            .save(&entry)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::AuditLogResult {
+            entry: Some(to_proto_audit_log_data(&saved)),
+        }))
+    }
+
+    async fn find_audit_log_by_id(
+        &self,
+        request: Request<repository::FindAuditLogByIdRequest>,
+    ) -> Result<Response<repository::AuditLogResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindAuditLogById: {}", req.id);
+
+        let id = AuditLogId::parse(&req.id)
+            .map_err(|e| Status::invalid_argument(format!("Invalid audit log ID: {}", e)))?;
+
+        let entry = self
+            .audit_log_repository
+            .find_by_id(id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::AuditLogResult {
+            entry: entry.map(|e| to_proto_audit_log_data(&e)),
+        }))
+    }
+
+    async fn find_audit_logs_by_user(
+        &self,
+        request: Request<repository::FindAuditLogsByUserRequest>,
+    ) -> Result<Response<repository::AuditLogListResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindAuditLogsByUser: {}", req.user_id);
+
+        let user_id = parse_user_id(&req.user_id)?;
+        let page_request = req.page.map_or_else(
+            PageRequest::default,
+            |p| PageRequest::new(p.page as usize, p.size as usize),
+        );
+
+        let page = self
+            .audit_log_repository
+            .find_by_user(user_id, page_request)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::AuditLogListResult {
+            entries: page.content.iter().map(to_proto_audit_log_data).collect(),
+            page_info: Some(common::PageInfo {
+                page: page.info.page as i32,
+                size: page.info.size as i32,
+                total_elements: page.info.total_elements as i64,
+                total_pages: page.info.total_pages as i64,
+                first: page.info.first,
+                last: page.info.last,
+            }),
+        }))
+    }
+
+    async fn search_audit_logs(
+        &self,
+        request: Request<repository::SearchAuditLogsRequest>,
+    ) -> Result<Response<repository::AuditLogSearchResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC SearchAuditLogs");
+
+        let filter = AuditLogFilter {
+            user_id: req.user_id.as_deref().map(parse_user_id).transpose()?,
+            action: req
+                .action
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map_err(|e: arcana_core::ArcanaError| Status::invalid_argument(e.to_string()))?,
+            resource_type: req.resource_type,
+            success: req.success,
+            from: req.from.as_ref().and_then(from_proto_timestamp),
+            to: req.to.as_ref().and_then(from_proto_timestamp),
+        };
+        let after = req
+            .after
+            .as_deref()
+            .map(str::parse::<AuditLogCursor>)
+            .transpose()
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let entries = self
+            .audit_log_repository
+            .search(&filter, after, req.limit as usize)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::AuditLogSearchResult {
+            entries: entries.iter().map(to_proto_audit_log_data).collect(),
+        }))
+    }
+
+    async fn get_audit_chain_bounds(
+        &self,
+        request: Request<repository::GetAuditChainBoundsRequest>,
+    ) -> Result<Response<repository::AuditChainBoundsResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC GetAuditChainBounds");
+
+        let bounds = self
+            .audit_log_repository
+            .chain_bounds(
+                req.from.as_ref().and_then(from_proto_timestamp),
+                req.to.as_ref().and_then(from_proto_timestamp),
+            )
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::AuditChainBoundsResult {
+            first_sequence: bounds.map(|(first, _)| first),
+            last_sequence: bounds.map(|(_, last)| last),
+        }))
+    }
+
+    async fn find_audit_chain(
+        &self,
+        request: Request<repository::FindAuditChainRequest>,
+    ) -> Result<Response<repository::AuditLogSearchResult>, Status> {
+        let req = request.into_inner();
+        debug!("gRPC FindAuditChain: {}..={}", req.from_sequence, req.to_sequence);
+
+        let entries = self
+            .audit_log_repository
+            .find_chain(req.from_sequence, req.to_sequence, req.limit as usize)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(repository::AuditLogSearchResult {
+            entries: entries.iter().map(to_proto_audit_log_data).collect(),
+        }))
+    }
 }
 
 // Helper functions
@@ -275,6 +927,15 @@ fn parse_user_id(id: &str) -> Result<UserId, Status> {
         .map_err(|e| Status::invalid_argument(format!("Invalid user ID: {}", e)))
 }
 
+/// Parses a tenant ID; an empty ID (a client without tenant support)
+/// stands for the default tenant.
+fn parse_tenant_id(id: &str) -> Result<TenantId, Status> {
+    if id.is_empty() {
+        return Ok(TenantId::DEFAULT);
+    }
+    TenantId::parse(id).map_err(|e| Status::invalid_argument(format!("Invalid tenant ID: {}", e)))
+}
+
 fn to_status(err: arcana_core::ArcanaError) -> Status {
     use arcana_core::ArcanaError;
 
@@ -292,6 +953,7 @@ fn to_status(err: arcana_core::ArcanaError) -> Status {
 fn to_proto_user_data(user: &User) -> repository::UserData {
     repository::UserData {
         id: user.id.to_string(),
+        tenant_id: user.tenant_id.to_string(),
         username: user.username.clone(),
         email: user.email.as_str().to_string(),
         password_hash: user.password_hash.clone(),
@@ -305,6 +967,7 @@ fn to_proto_user_data(user: &User) -> repository::UserData {
             seconds: dt.timestamp(),
             nanos: dt.timestamp_subsec_nanos() as i32,
         }),
+        locked_until: user.locked_until.map(to_proto_timestamp),
         created_at: Some(common::Timestamp {
             seconds: user.created_at.timestamp(),
             nanos: user.created_at.timestamp_subsec_nanos() as i32,
@@ -318,6 +981,7 @@ fn to_proto_user_data(user: &User) -> repository::UserData {
 
 fn from_proto_user_data(user: &repository::UserData) -> User {
     let id = UserId::parse(&user.id).unwrap_or_else(|_| UserId::new());
+    let tenant_id = TenantId::parse(&user.tenant_id).unwrap_or_default();
     let email = Email::new_unchecked(user.email.clone());
     let role = from_proto_role(user_proto::UserRole::try_from(user.role).unwrap_or(user_proto::UserRole::User));
     let status = from_proto_status(user_proto::UserStatus::try_from(user.status).unwrap_or(user_proto::UserStatus::Active));
@@ -326,21 +990,27 @@ fn from_proto_user_data(user: &repository::UserData) -> User {
         .created_at
         .as_ref()
         .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32))
-        .unwrap_or_else(|| chrono::Utc::now());
+        .unwrap_or_else(chrono::Utc::now);
 
     let updated_at = user
         .updated_at
         .as_ref()
         .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32))
-        .unwrap_or_else(|| chrono::Utc::now());
+        .unwrap_or_else(chrono::Utc::now);
 
     let last_login_at = user
         .last_login_at
         .as_ref()
         .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32));
 
+    let locked_until = user
+        .locked_until
+        .as_ref()
+        .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32));
+
     User {
         id,
+        tenant_id,
         username: user.username.clone(),
         email,
         password_hash: user.password_hash.clone(),
@@ -351,11 +1021,220 @@ fn from_proto_user_data(user: &repository::UserData) -> User {
         email_verified: user.email_verified,
         avatar_url: user.avatar_url.clone(),
         last_login_at,
+        locked_until,
         created_at,
         updated_at,
     }
 }
 
+fn to_proto_timestamp(dt: chrono::DateTime<chrono::Utc>) -> common::Timestamp {
+    common::Timestamp {
+        seconds: dt.timestamp(),
+        nanos: dt.timestamp_subsec_nanos() as i32,
+    }
+}
+
+fn from_proto_timestamp(ts: &common::Timestamp) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp(ts.seconds, ts.nanos as u32)
+}
+
+fn to_proto_tenant_data(tenant: &Tenant) -> repository::TenantData {
+    repository::TenantData {
+        id: tenant.id.to_string(),
+        slug: tenant.slug.clone(),
+        name: tenant.name.clone(),
+        active: tenant.active,
+        created_at: Some(to_proto_timestamp(tenant.created_at)),
+        updated_at: Some(to_proto_timestamp(tenant.updated_at)),
+    }
+}
+
+fn from_proto_tenant_data(tenant: &repository::TenantData) -> Result<Tenant, Status> {
+    let id = TenantId::parse(&tenant.id)
+        .map_err(|e| Status::invalid_argument(format!("Invalid tenant ID: {}", e)))?;
+    let timestamp = |ts: &Option<common::Timestamp>| {
+        ts.as_ref().and_then(from_proto_timestamp).unwrap_or_else(chrono::Utc::now)
+    };
+
+    Ok(Tenant {
+        id,
+        slug: tenant.slug.clone(),
+        name: tenant.name.clone(),
+        active: tenant.active,
+        created_at: timestamp(&tenant.created_at),
+        updated_at: timestamp(&tenant.updated_at),
+    })
+}
+
+fn to_proto_oauth_token_data(token: &OAuthToken) -> repository::OAuthTokenData {
+    repository::OAuthTokenData {
+        id: token.id.to_string(),
+        user_id: token.user_id.to_string(),
+        token_hash: token.token_hash.clone(),
+        family_id: token.family_id.clone(),
+        device_id: token.device_id.clone(),
+        user_agent: token.user_agent.clone(),
+        ip_address: token.ip_address.clone(),
+        expires_at: Some(to_proto_timestamp(token.expires_at)),
+        revoked: token.revoked,
+        revoked_at: token.revoked_at.map(to_proto_timestamp),
+        created_at: Some(to_proto_timestamp(token.created_at)),
+        last_used_at: token.last_used_at.map(to_proto_timestamp),
+    }
+}
+
+fn from_proto_oauth_token_data(token: &repository::OAuthTokenData) -> Result<OAuthToken, Status> {
+    let id = OAuthTokenId::parse(&token.id)
+        .map_err(|e| Status::invalid_argument(format!("Invalid token ID: {}", e)))?;
+    let user_id = parse_user_id(&token.user_id)?;
+    let expires_at = token
+        .expires_at
+        .as_ref()
+        .and_then(from_proto_timestamp)
+        .ok_or_else(|| Status::invalid_argument("Token expiration is required"))?;
+
+    Ok(OAuthToken {
+        id,
+        user_id,
+        token_hash: token.token_hash.clone(),
+        family_id: token.family_id.clone(),
+        device_id: token.device_id.clone(),
+        user_agent: token.user_agent.clone(),
+        ip_address: token.ip_address.clone(),
+        expires_at,
+        revoked: token.revoked,
+        revoked_at: token.revoked_at.as_ref().and_then(from_proto_timestamp),
+        created_at: token
+            .created_at
+            .as_ref()
+            .and_then(from_proto_timestamp)
+            .unwrap_or_else(chrono::Utc::now),
+        last_used_at: token.last_used_at.as_ref().and_then(from_proto_timestamp),
+    })
+}
+
+fn to_proto_password_reset_token_data(token: &PasswordResetToken) -> repository::PasswordResetTokenData {
+    repository::PasswordResetTokenData {
+        id: token.id.to_string(),
+        user_id: token.user_id.to_string(),
+        token_hash: token.token_hash.clone(),
+        expires_at: Some(to_proto_timestamp(token.expires_at)),
+        used_at: token.used_at.map(to_proto_timestamp),
+        created_at: Some(to_proto_timestamp(token.created_at)),
+    }
+}
+
+fn from_proto_password_reset_token_data(
+    token: &repository::PasswordResetTokenData,
+) -> Result<PasswordResetToken, Status> {
+    let id = PasswordResetTokenId::parse(&token.id)
+        .map_err(|e| Status::invalid_argument(format!("Invalid token ID: {}", e)))?;
+    let user_id = parse_user_id(&token.user_id)?;
+    let expires_at = token
+        .expires_at
+        .as_ref()
+        .and_then(from_proto_timestamp)
+        .ok_or_else(|| Status::invalid_argument("Token expiration is required"))?;
+
+    Ok(PasswordResetToken {
+        id,
+        user_id,
+        token_hash: token.token_hash.clone(),
+        expires_at,
+        used_at: token.used_at.as_ref().and_then(from_proto_timestamp),
+        created_at: token
+            .created_at
+            .as_ref()
+            .and_then(from_proto_timestamp)
+            .unwrap_or_else(chrono::Utc::now),
+    })
+}
+
+fn to_proto_totp_credential_data(credential: &TotpCredential) -> repository::TotpCredentialData {
+    repository::TotpCredentialData {
+        user_id: credential.user_id.to_string(),
+        secret: credential.secret.clone(),
+        enabled_at: credential.enabled_at.map(to_proto_timestamp),
+        last_used_step: credential.last_used_step,
+        created_at: Some(to_proto_timestamp(credential.created_at)),
+        updated_at: Some(to_proto_timestamp(credential.updated_at)),
+    }
+}
+
+fn from_proto_totp_credential_data(
+    credential: &repository::TotpCredentialData,
+) -> Result<TotpCredential, Status> {
+    let user_id = parse_user_id(&credential.user_id)?;
+    let timestamp = |ts: &Option<common::Timestamp>| {
+        ts.as_ref().and_then(from_proto_timestamp).unwrap_or_else(chrono::Utc::now)
+    };
+
+    Ok(TotpCredential {
+        user_id,
+        secret: credential.secret.clone(),
+        enabled_at: credential.enabled_at.as_ref().and_then(from_proto_timestamp),
+        last_used_step: credential.last_used_step,
+        created_at: timestamp(&credential.created_at),
+        updated_at: timestamp(&credential.updated_at),
+    })
+}
+
+fn to_proto_audit_log_data(entry: &AuditLog) -> repository::AuditLogData {
+    repository::AuditLogData {
+        id: entry.id.to_string(),
+        user_id: entry.user_id.map(|id| id.to_string()),
+        action: entry.action.to_string(),
+        resource_type: entry.resource_type.clone(),
+        resource_id: entry.resource_id.clone(),
+        details: entry.details.as_ref().map(ToString::to_string),
+        ip_address: entry.ip_address.clone(),
+        user_agent: entry.user_agent.clone(),
+        success: entry.success,
+        error_message: entry.error_message.clone(),
+        timestamp: Some(to_proto_timestamp(entry.timestamp)),
+        sequence: entry.sequence,
+        prev_hash: entry.prev_hash.clone(),
+        hash: entry.hash.clone(),
+    }
+}
+
+fn from_proto_audit_log_data(entry: &repository::AuditLogData) -> Result<AuditLog, Status> {
+    let id = AuditLogId::parse(&entry.id)
+        .map_err(|e| Status::invalid_argument(format!("Invalid audit log ID: {}", e)))?;
+    let user_id = entry.user_id.as_deref().map(parse_user_id).transpose()?;
+    let action = entry
+        .action
+        .parse()
+        .map_err(|e: arcana_core::ArcanaError| Status::invalid_argument(e.to_string()))?;
+    let details = entry
+        .details
+        .as_deref()
+        .map(serde_json::from_str)
+        .transpose()
+        .map_err(|e| Status::invalid_argument(format!("Invalid audit log details: {}", e)))?;
+
+    Ok(AuditLog {
+        id,
+        user_id,
+        action,
+        resource_type: entry.resource_type.clone(),
+        resource_id: entry.resource_id.clone(),
+        details,
+        ip_address: entry.ip_address.clone(),
+        user_agent: entry.user_agent.clone(),
+        success: entry.success,
+        error_message: entry.error_message.clone(),
+        timestamp: entry
+            .timestamp
+            .as_ref()
+            .and_then(from_proto_timestamp)
+            .unwrap_or_else(chrono::Utc::now),
+        sequence: entry.sequence,
+        prev_hash: entry.prev_hash.clone(),
+        hash: entry.hash.clone(),
+    })
+}
+
 fn to_proto_user_list_result(page: Page<User>) -> repository::UserListResult {
     repository::UserListResult {
         users: page.content.iter().map(to_proto_user_data).collect(),
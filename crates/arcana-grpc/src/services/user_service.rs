@@ -1,7 +1,9 @@
 //! User gRPC service implementation.
 
+use crate::interceptors::require_auth;
 use crate::proto::{common, user};
-use arcana_core::{PageRequest, UserId};
+use arcana_core::{Permission, PageRequest, UserId, UserRole};
+use arcana_security::{ClaimsExt, PermissionGuard};
 use arcana_service::dto::{CreateUserRequest, UpdateUserRequest, UpdateUserRoleRequest, UpdateUserStatusRequest};
 use arcana_service::UserService;
 use std::sync::Arc;
@@ -27,10 +29,13 @@ impl user::user_service_server::UserService for UserGrpcService {
         &self,
         request: Request<user::GetUserRequest>,
     ) -> Result<Response<user::UserResponse>, Status> {
-        let req = request.into_inner();
-        debug!("gRPC GetUser: {}", req.user_id);
+        debug!("gRPC GetUser: {}", request.get_ref().user_id);
 
-        let user_id = parse_user_id(&req.user_id)?;
+        let user_id = parse_user_id(&request.get_ref().user_id)?;
+        // Users can view themselves, those who may edit users can view anyone
+        owner_or_user_editor()
+            .check(require_auth(&request)?, Some(user_id))
+            .map_err(to_status)?;
 
         let response = self
             .user_service
@@ -47,6 +52,7 @@ impl user::user_service_server::UserService for UserGrpcService {
         &self,
         request: Request<user::GetUserByUsernameRequest>,
     ) -> Result<Response<user::UserResponse>, Status> {
+        let claims = require_auth(&request)?.clone();
         let req = request.into_inner();
         debug!("gRPC GetUserByUsername: {}", req.username);
 
@@ -55,6 +61,9 @@ impl user::user_service_server::UserService for UserGrpcService {
             .get_user_by_username(&req.username)
             .await
             .map_err(to_status)?;
+        owner_or_user_editor()
+            .check(&claims, Some(response.id))
+            .map_err(to_status)?;
 
         Ok(Response::new(user::UserResponse {
             user: Some(to_proto_user(&response)),
@@ -69,7 +78,7 @@ impl user::user_service_server::UserService for UserGrpcService {
         debug!("gRPC ListUsers");
 
         let page_request = req.page.map_or_else(
-            || PageRequest::default(),
+            PageRequest::default,
             |p| PageRequest::new(p.page as usize, p.size as usize),
         );
 
@@ -124,10 +133,14 @@ impl user::user_service_server::UserService for UserGrpcService {
         &self,
         request: Request<user::UpdateUserRequest>,
     ) -> Result<Response<user::UserResponse>, Status> {
-        let req = request.into_inner();
-        debug!("gRPC UpdateUser: {}", req.user_id);
+        debug!("gRPC UpdateUser: {}", request.get_ref().user_id);
 
-        let user_id = parse_user_id(&req.user_id)?;
+        let user_id = parse_user_id(&request.get_ref().user_id)?;
+        // Users can update themselves, those who may edit users anyone
+        owner_or_user_editor()
+            .check(require_auth(&request)?, Some(user_id))
+            .map_err(to_status)?;
+        let req = request.into_inner();
 
         let update_request = UpdateUserRequest {
             first_name: req.first_name.filter(|s| !s.is_empty()),
@@ -167,12 +180,18 @@ impl user::user_service_server::UserService for UserGrpcService {
         &self,
         request: Request<user::UpdateUserRoleRequest>,
     ) -> Result<Response<user::UserResponse>, Status> {
+        let claims = require_auth(&request)?.clone();
         let req = request.into_inner();
         debug!("gRPC UpdateUserRole: {}", req.user_id);
 
         let user_id = parse_user_id(&req.user_id)?;
         let role = from_proto_role(req.role());
 
+        // Prevent changing to super admin unless you're a super admin
+        if role == UserRole::SuperAdmin {
+            claims.require_super_admin().map_err(to_status)?;
+        }
+
         let update_request = UpdateUserRoleRequest { role };
 
         let response = self
@@ -247,6 +266,11 @@ impl user::user_service_server::UserService for UserGrpcService {
 
 // Helper functions
 
+/// Admits the user themselves and anyone granted `user:update`.
+fn owner_or_user_editor() -> PermissionGuard {
+    PermissionGuard::new().permission(Permission::UserUpdate).allow_owner()
+}
+
 fn parse_user_id(id: &str) -> Result<UserId, Status> {
     Uuid::parse_str(id)
         .map(UserId::from)
@@ -274,6 +298,7 @@ fn to_status(err: arcana_core::ArcanaError) -> Status {
 fn to_proto_user(user: &arcana_service::dto::UserResponse) -> user::User {
     user::User {
         id: user.id.to_string(),
+        tenant_id: user.tenant_id.to_string(),
         username: user.username.clone(),
         email: user.email.clone(),
         first_name: user.first_name.clone(),
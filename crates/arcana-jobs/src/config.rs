@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 /// Configuration for the job queue system.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct JobsConfig {
     /// Redis connection configuration.
     #[serde(default)]
@@ -23,16 +23,6 @@ pub struct JobsConfig {
     pub scheduler: SchedulerConfig,
 }
 
-impl Default for JobsConfig {
-    fn default() -> Self {
-        Self {
-            redis: RedisConfig::default(),
-            worker: WorkerConfig::default(),
-            queue: QueueConfig::default(),
-            scheduler: SchedulerConfig::default(),
-        }
-    }
-}
 
 /// Redis connection configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
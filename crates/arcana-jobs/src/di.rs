@@ -198,6 +198,7 @@ impl JobQueueInterface for JobQueueService {
                 retry_policy: None,
                 unique_key: None,
                 last_error: None,
+                tenant_id: info.tenant_id,
             };
             self.redis_queue.retry(&job_data).await
         } else {
@@ -2,6 +2,7 @@
 
 use crate::error::{JobError, JobResult};
 use crate::retry::RetryPolicy;
+use arcana_core::TenantId;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -80,6 +81,9 @@ pub struct JobContext {
 
     /// Worker ID processing this job.
     pub worker_id: String,
+
+    /// Tenant the job was enqueued for, if any.
+    pub tenant_id: Option<TenantId>,
 }
 
 impl JobContext {
@@ -226,6 +230,10 @@ pub struct JobData {
 
     /// Error from last failed attempt.
     pub last_error: Option<String>,
+
+    /// Tenant the job was enqueued for (`None` for system jobs).
+    #[serde(default)]
+    pub tenant_id: Option<TenantId>,
 }
 
 impl JobData {
@@ -249,6 +257,7 @@ impl JobData {
             retry_policy: Some(serde_json::to_string(&job.retry_policy())?),
             unique_key: job.unique_key(),
             last_error: None,
+            tenant_id: None,
         })
     }
 
@@ -283,6 +292,7 @@ impl JobData {
             started_at: Utc::now(),
             correlation_id: self.correlation_id.clone(),
             worker_id: worker_id.to_string(),
+            tenant_id: self.tenant_id,
         }
     }
 
@@ -341,6 +351,10 @@ pub struct JobInfo {
 
     /// Worker ID (if being processed).
     pub worker_id: Option<String>,
+
+    /// Tenant the job was enqueued for.
+    #[serde(default)]
+    pub tenant_id: Option<TenantId>,
 }
 
 impl From<JobData> for JobInfo {
@@ -360,15 +374,17 @@ impl From<JobData> for JobInfo {
             last_error: data.last_error,
             tags: data.tags,
             worker_id: None,
+            tenant_id: data.tenant_id,
         }
     }
 }
 
 /// Job status enumeration.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum JobStatus {
     /// Job is pending execution.
+    #[default]
     Pending,
     /// Job is scheduled for later execution.
     Scheduled,
@@ -384,11 +400,6 @@ pub enum JobStatus {
     Cancelled,
 }
 
-impl Default for JobStatus {
-    fn default() -> Self {
-        JobStatus::Pending
-    }
-}
 
 impl fmt::Display for JobStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -651,6 +662,30 @@ mod tests {
         assert!(info.worker_id.is_none());
     }
 
+    #[test]
+    fn test_tenant_carries_from_data_to_context_and_info() {
+        let job = TestJob { message: "tenant".to_string() };
+        let mut data = JobData::new(&job).unwrap();
+        assert!(data.tenant_id.is_none());
+
+        let tenant_id = TenantId::new();
+        data.tenant_id = Some(tenant_id);
+        assert_eq!(data.to_context("worker-1").tenant_id, Some(tenant_id));
+
+        let restored = JobData::from_json(&data.to_json().unwrap()).unwrap();
+        assert_eq!(JobInfo::from(restored).tenant_id, Some(tenant_id));
+    }
+
+    #[test]
+    fn test_job_data_without_tenant_deserializes() {
+        let job = TestJob { message: "legacy".to_string() };
+        let mut value = serde_json::to_value(JobData::new(&job).unwrap()).unwrap();
+        value.as_object_mut().unwrap().remove("tenant_id");
+
+        let data = JobData::from_json(&value.to_string()).unwrap();
+        assert!(data.tenant_id.is_none());
+    }
+
     // =========================================================================
     // JobStatus tests
     // =========================================================================
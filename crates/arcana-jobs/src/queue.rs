@@ -3,18 +3,20 @@
 use crate::error::{JobError, JobResult};
 use crate::job::{Job, JobData, JobId, JobInfo};
 use crate::retry::RetryPolicy;
+use arcana_core::TenantId;
 use async_trait::async_trait;
 use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 /// Job priority levels.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[repr(i8)]
 pub enum Priority {
     /// Low priority (background tasks).
     Low = -10,
     /// Normal priority (default).
+    #[default]
     Normal = 0,
     /// High priority (important tasks).
     High = 10,
@@ -22,11 +24,6 @@ pub enum Priority {
     Critical = 20,
 }
 
-impl Default for Priority {
-    fn default() -> Self {
-        Priority::Normal
-    }
-}
 
 impl From<i8> for Priority {
     fn from(value: i8) -> Self {
@@ -54,6 +51,7 @@ pub struct QueuedJob<J: Job> {
     correlation_id: Option<String>,
     tags: Vec<String>,
     retry_policy: Option<RetryPolicy>,
+    tenant_id: Option<TenantId>,
 }
 
 impl<J: Job> QueuedJob<J> {
@@ -67,6 +65,7 @@ impl<J: Job> QueuedJob<J> {
             correlation_id: None,
             tags: Vec::new(),
             retry_policy: None,
+            tenant_id: None,
         }
     }
 
@@ -114,6 +113,12 @@ impl<J: Job> QueuedJob<J> {
         self
     }
 
+    /// Enqueue the job on behalf of a tenant.
+    pub fn tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = Some(tenant_id);
+        self
+    }
+
     /// Build the job data.
     pub fn build(self) -> JobResult<JobData> {
         let mut data = JobData::new(&self.job)?;
@@ -121,6 +126,7 @@ impl<J: Job> QueuedJob<J> {
         data.priority = self.priority.into();
         data.correlation_id = self.correlation_id;
         data.tags = self.tags;
+        data.tenant_id = self.tenant_id;
 
         if let Some(policy) = self.retry_policy {
             data.retry_policy = Some(serde_json::to_string(&policy)?);
@@ -183,6 +189,9 @@ pub trait JobQueue: Send + Sync {
     /// Get jobs in queue.
     async fn list_jobs(&self, queue: &str, limit: usize, offset: usize) -> JobResult<Vec<JobInfo>>;
 
+    /// Get jobs enqueued for a tenant that have not completed yet, newest first.
+    async fn list_tenant_jobs(&self, tenant_id: TenantId, limit: usize, offset: usize) -> JobResult<Vec<JobInfo>>;
+
     /// Get dead letter queue jobs.
     async fn list_dlq(&self, limit: usize, offset: usize) -> JobResult<Vec<JobInfo>>;
 
@@ -245,4 +254,26 @@ mod tests {
         assert_eq!(Priority::from(0), Priority::Normal);
         assert_eq!(Priority::from(-15), Priority::Low);
     }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct NoopJob;
+
+    #[async_trait]
+    impl Job for NoopJob {
+        const NAME: &'static str = "noop";
+
+        async fn execute(&self, _ctx: crate::job::JobContext) -> Result<(), JobError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_queued_job_tenant() {
+        let tenant_id = TenantId::new();
+        let data = QueuedJob::new(NoopJob).tenant(tenant_id).build().unwrap();
+        assert_eq!(data.tenant_id, Some(tenant_id));
+
+        let data = QueuedJob::new(NoopJob).build().unwrap();
+        assert!(data.tenant_id.is_none());
+    }
 }
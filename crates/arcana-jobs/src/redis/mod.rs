@@ -6,6 +6,7 @@ pub use queue::RedisJobQueue;
 
 use crate::config::RedisConfig;
 use crate::error::{JobError, JobResult};
+use arcana_core::TenantId;
 use deadpool_redis::{Config, Pool, Runtime};
 use tracing::info;
 
@@ -87,6 +88,11 @@ impl RedisKeys {
         format!("{}:unique:{}", self.prefix, key)
     }
 
+    /// Tenant job index key (sorted set: job_id by creation time).
+    pub fn tenant_jobs(&self, tenant_id: TenantId) -> String {
+        format!("{}:tenant:{}:jobs", self.prefix, tenant_id)
+    }
+
     /// Worker heartbeat key.
     pub fn worker(&self, worker_id: &str) -> String {
         format!("{}:worker:{}", self.prefix, worker_id)
@@ -126,5 +132,8 @@ mod tests {
         assert_eq!(keys.job("123"), "test:job:123");
         assert_eq!(keys.dlq(), "test:dlq");
         assert_eq!(keys.worker("w1"), "test:worker:w1");
+
+        let tenant_id = TenantId::new();
+        assert_eq!(keys.tenant_jobs(tenant_id), format!("test:tenant:{tenant_id}:jobs"));
     }
 }
@@ -345,7 +345,13 @@ impl<Q: JobQueue + 'static> Scheduler<Q> {
         let mut conn = self.pool.get().await?;
 
         let jobs_to_run: Vec<(String, JobData)> = {
-            let jobs = self.jobs.read();
+            // Snapshot the jobs: the lock must not be held across awaits
+            let jobs: Vec<(String, ScheduledJob)> = self
+                .jobs
+                .read()
+                .iter()
+                .map(|(name, job)| (name.clone(), job.clone()))
+                .collect();
             let mut to_run = Vec::new();
 
             for (name, scheduled_job) in jobs.iter() {
@@ -432,15 +438,15 @@ impl<Q: JobQueue + 'static> Scheduler<Q> {
 
         let job_id_str = job_data.id.as_str();
 
-        // Store job data
-        let job_key = format!("{}:{}", self.keys.job(job_id_str), "data");
-        let serialized = serde_json::to_string(&job_data)?;
+        // Store job data where the job queue looks it up
+        let job_key = self.keys.job(job_id_str);
+        let serialized = job_data.to_json()?;
         let _: () = conn.set(&job_key, &serialized).await?;
 
-        // Add to queue
+        // Add to queue; like the job queue, members are the serialized job
         let queue_key = self.keys.priority_queue(&job_data.queue);
         let score = Self::calculate_priority_score(&job_data);
-        let _: () = conn.zadd(&queue_key, job_id_str, score).await?;
+        let _: () = conn.zadd(&queue_key, &serialized, score).await?;
 
         Ok(job_id_str.to_string())
     }
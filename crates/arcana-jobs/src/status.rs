@@ -4,6 +4,7 @@ use crate::error::JobResult;
 use crate::job::{JobInfo, JobStatus};
 use crate::queue::QueueStats;
 use crate::redis::RedisKeys;
+use arcana_core::TenantId;
 use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use deadpool_redis::Pool;
 use redis::AsyncCommands;
@@ -104,6 +105,10 @@ impl JobStatusTracker {
                     }
                 }
 
+                if query.tenant_id.is_some() && info.tenant_id != query.tenant_id {
+                    continue;
+                }
+
                 jobs.push(info);
             }
         }
@@ -208,7 +213,7 @@ impl JobStatusTracker {
         }
 
         // Sort by created_at
-        jobs.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        jobs.sort_by_key(|a| a.created_at);
 
         Ok(jobs)
     }
@@ -264,7 +269,7 @@ impl JobStatusTracker {
         }
 
         // Sort by timestamp descending
-        activities.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        activities.sort_by_key(|a| std::cmp::Reverse(a.timestamp));
         activities.truncate(limit);
 
         Ok(activities)
@@ -389,6 +394,9 @@ pub struct JobSearchQuery {
     /// Filter by tag.
     pub tag: Option<String>,
 
+    /// Filter by tenant.
+    pub tenant_id: Option<TenantId>,
+
     /// Pagination offset.
     pub offset: usize,
 
@@ -430,6 +438,12 @@ impl JobSearchQuery {
         self
     }
 
+    /// Filter by tenant.
+    pub fn tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = Some(tenant_id);
+        self
+    }
+
     /// Set pagination offset.
     pub fn offset(mut self, offset: usize) -> Self {
         self.offset = offset;
@@ -623,10 +637,12 @@ mod tests {
         let query = JobSearchQuery::new()
             .status(JobStatus::Pending)
             .queue("high-priority")
+            .tenant(TenantId::DEFAULT)
             .limit(100);
 
         assert_eq!(query.status, Some(JobStatus::Pending));
         assert_eq!(query.queue.as_deref(), Some("high-priority"));
+        assert_eq!(query.tenant_id, Some(TenantId::DEFAULT));
         assert_eq!(query.limit, 100);
     }
 
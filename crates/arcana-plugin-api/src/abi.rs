@@ -0,0 +1,133 @@
+//! Binary interface between the platform and WASM plugins.
+//!
+//! A plugin is a `wasm32-wasip1` module exporting its linear memory as
+//! [`MEMORY`] and the functions named here. Values cross the boundary as
+//! JSON in guest memory, addressed by a pointer and a length packed into one
+//! `i64` (see [`pack`]):
+//!
+//! | Export | Signature | Returns |
+//! |--------|-----------|---------|
+//! | [`ALLOC`] | `(len: i32) -> i32` | pointer to `len` fresh bytes |
+//! | [`FREE`] | `(ptr: i32, len: i32)` | |
+//! | [`DESCRIPTOR`] | `() -> i64` | the [`PluginDescriptor`](crate::PluginDescriptor) |
+//! | [`LifecycleHook`] exports | `() -> i64` | `0`, or an error message |
+//! | [`SUBSCRIPTIONS`] | `() -> i64` | the [`EventSubscription`](crate::extensions::EventSubscription) |
+//! | [`HANDLE_EVENT`] | `(ptr: i32, len: i32) -> i64` | `0`, or an error message |
+//! | [`ROUTES`] | `() -> i64` | the [`RouteDefinition`](crate::extensions::RouteDefinition)s |
+//! | [`HANDLE_REQUEST`] | `(ptr: i32, len: i32) -> i64` | the [`HttpResponse`](crate::extensions::HttpResponse) |
+//! | [`JOB_CONFIG`] | `() -> i64` | the [`JobConfig`](crate::extensions::JobConfig) |
+//! | [`EXECUTE_JOB`] | `(ptr: i32, len: i32) -> i64` | `0`, or an error message |
+//!
+//! Functions taking `(ptr, len)` receive the JSON of a
+//! [`PluginEvent`](crate::extensions::PluginEvent),
+//! [`HttpRequest`](crate::extensions::HttpRequest) or
+//! [`JobContext`](crate::extensions::JobContext) respectively, written into a
+//! buffer the platform got from [`ALLOC`].
+//!
+//! Buffers returned by the plugin, and buffers the platform allocates to pass
+//! arguments, are owned by the platform, which frees them with [`FREE`] once
+//! the call is over. The lifecycle hooks are optional; a plugin without one
+//! accepts that transition unconditionally. The extension functions come in
+//! pairs, exported by plugins providing that extension.
+//!
+//! Besides WASI, plugins may import the host functions of [`HOST_MODULE`]
+//! their [manifest](crate::manifest) declares:
+//!
+//! | Import | Signature | Effect |
+//! |--------|-----------|--------|
+//! | [`LOG`] | `(level: i32, ptr: i32, len: i32)` | logs the UTF-8 message at `ptr` |
+
+/// Linear memory of the plugin.
+pub const MEMORY: &str = "memory";
+
+/// Allocates guest memory for the platform.
+pub const ALLOC: &str = "arcana_alloc";
+
+/// Frees guest memory allocated by [`ALLOC`] or returned by the plugin.
+pub const FREE: &str = "arcana_free";
+
+/// Returns the plugin descriptor as JSON.
+pub const DESCRIPTOR: &str = "arcana_descriptor";
+
+/// Initializes a WASI reactor; called once, before anything else, if exported.
+pub const INITIALIZE: &str = "_initialize";
+
+/// Returns the subscription of the plugin's event listener as JSON.
+pub const SUBSCRIPTIONS: &str = "arcana_subscriptions";
+
+/// Passes a platform event to the plugin's event listener.
+pub const HANDLE_EVENT: &str = "arcana_handle_event";
+
+/// Returns the routes of the plugin's REST endpoint as JSON.
+pub const ROUTES: &str = "arcana_routes";
+
+/// Passes an HTTP request to the plugin's REST endpoint.
+pub const HANDLE_REQUEST: &str = "arcana_handle_request";
+
+/// Returns the configuration of the plugin's scheduled job as JSON.
+pub const JOB_CONFIG: &str = "arcana_job_config";
+
+/// Runs the plugin's scheduled job.
+pub const EXECUTE_JOB: &str = "arcana_execute_job";
+
+/// Module the platform's host functions are imported from.
+pub const HOST_MODULE: &str = "arcana";
+
+/// Logs a message on behalf of the plugin.
+///
+/// Levels are `0` (error), `1` (warn), `2` (info), `3` (debug) and `4`
+/// (trace).
+pub const LOG: &str = "log";
+
+/// Plugin lifecycle transition the platform notifies the plugin of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleHook {
+    /// [`Plugin::on_install`](crate::Plugin::on_install).
+    Install,
+    /// [`Plugin::on_enable`](crate::Plugin::on_enable).
+    Enable,
+    /// [`Plugin::on_disable`](crate::Plugin::on_disable).
+    Disable,
+    /// [`Plugin::on_uninstall`](crate::Plugin::on_uninstall).
+    Uninstall,
+}
+
+impl LifecycleHook {
+    /// Returns the name of the function the plugin exports for this hook.
+    #[must_use]
+    pub const fn export_name(self) -> &'static str {
+        match self {
+            Self::Install => "arcana_on_install",
+            Self::Enable => "arcana_on_enable",
+            Self::Disable => "arcana_on_disable",
+            Self::Uninstall => "arcana_on_uninstall",
+        }
+    }
+}
+
+/// Packs a guest pointer and length into the `i64` passed across the boundary.
+///
+/// The pointer is in the high 32 bits; `pack(0, 0) == 0` means "nothing".
+#[must_use]
+pub const fn pack(ptr: u32, len: u32) -> i64 {
+    (((ptr as u64) << 32) | len as u64) as i64
+}
+
+/// Splits a value made by [`pack`] into pointer and length.
+#[must_use]
+pub const fn unpack(packed: i64) -> (u32, u32) {
+    let packed = packed as u64;
+    ((packed >> 32) as u32, packed as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_roundtrip() {
+        assert_eq!(pack(0, 0), 0);
+        assert_eq!(unpack(pack(1024, 17)), (1024, 17));
+        assert_eq!(unpack(pack(u32::MAX, u32::MAX)), (u32::MAX, u32::MAX));
+    }
+}
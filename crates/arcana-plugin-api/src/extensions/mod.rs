@@ -80,6 +80,10 @@ pub trait EventListenerExtension: Send + Sync {
 }
 
 /// Event subscription configuration.
+///
+/// Event types may end in `*` to match every type with that prefix
+/// (`"user.*"`); `"*"` alone matches all events. Listeners with a lower
+/// `order` are called first.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventSubscription {
     pub event_types: Vec<String>,
@@ -87,6 +91,16 @@ pub struct EventSubscription {
     pub async_handling: bool,
 }
 
+impl EventSubscription {
+    /// Returns `true` if events of this type are delivered to the listener.
+    pub fn matches(&self, event_type: &str) -> bool {
+        self.event_types.iter().any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => event_type.starts_with(prefix),
+            None => pattern == event_type,
+        })
+    }
+}
+
 /// Event from the platform.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginEvent {
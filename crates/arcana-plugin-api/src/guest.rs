@@ -0,0 +1,353 @@
+//! Guest-side SDK for building plugins as WASM modules.
+//!
+//! Implement [`Plugin`](crate::Plugin) and the extension traits the plugin
+//! provides on a type with a `Default`, then export it:
+//!
+//! ```ignore
+//! arcana_plugin_api::export_plugin!(AuditPlugin, extensions: [event_listener]);
+//! ```
+//!
+//! The macro generates the functions of the [plugin ABI](crate::abi) for the
+//! type. Extensions are named `event_listener`
+//! ([`EventListenerExtension`]), `rest_endpoint` ([`RestEndpointExtension`])
+//! and `scheduled_job` ([`ScheduledJobExtension`]). The plugin is created
+//! with `Default` on the first call and lives as long as its instance.
+//!
+//! Build the plugin as a `cdylib` for `wasm32-wasip1`:
+//!
+//! ```bash
+//! cargo build --target wasm32-wasip1 --release -p my-plugin
+//! ```
+
+use crate::abi;
+use crate::extensions::{EventListenerExtension, HttpResponse, RestEndpointExtension, ScheduledJobExtension};
+use serde::Serialize;
+
+/// Allocates `len` zeroed bytes of guest memory for the platform.
+///
+/// Returns `0` if `len` is negative.
+#[must_use]
+pub fn alloc(len: i32) -> i32 {
+    let Ok(len) = usize::try_from(len) else {
+        return 0;
+    };
+    let buffer = vec![0_u8; len].into_boxed_slice();
+    Box::into_raw(buffer).cast::<u8>() as usize as i32
+}
+
+/// Frees a buffer made by [`alloc`] or [`output`].
+///
+/// # Safety
+///
+/// `ptr` and `len` must describe a buffer made by [`alloc`] or [`output`]
+/// that has not been freed yet.
+pub unsafe fn free(ptr: i32, len: i32) {
+    let buffer = std::ptr::slice_from_raw_parts_mut(ptr as usize as *mut u8, len as usize);
+    drop(Box::from_raw(buffer));
+}
+
+/// Borrows an argument the platform wrote into guest memory.
+///
+/// # Safety
+///
+/// `ptr` and `len` must describe a live buffer made by [`alloc`].
+#[must_use]
+pub unsafe fn input<'a>(ptr: i32, len: i32) -> &'a [u8] {
+    std::slice::from_raw_parts(ptr as usize as *const u8, len as usize)
+}
+
+/// Hands bytes over to the platform, which frees them after reading.
+#[must_use]
+pub fn output(bytes: Vec<u8>) -> i64 {
+    let len = bytes.len() as u32;
+    let ptr = Box::into_raw(bytes.into_boxed_slice()).cast::<u8>() as usize as u32;
+    abi::pack(ptr, len)
+}
+
+/// Hands a value over to the platform as JSON.
+#[must_use]
+pub fn output_json<T: Serialize>(value: &T) -> i64 {
+    output(serde_json::to_vec(value).expect("plugin API types serialize to JSON"))
+}
+
+/// Encodes the outcome of a call: `0` on success, the message otherwise.
+#[must_use]
+pub fn status(result: Result<(), String>) -> i64 {
+    match result {
+        Ok(()) => 0,
+        Err(message) => output(message.into_bytes()),
+    }
+}
+
+/// Severity of a message passed to [`log`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum LogLevel {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Debug = 3,
+    Trace = 4,
+}
+
+/// Logs a message through the platform.
+///
+/// Calls the [`LOG`](abi::LOG) host function, which the plugin must declare
+/// in its [manifest](crate::manifest). Outside WASM the message goes to
+/// stderr.
+pub fn log(level: LogLevel, message: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        #[link(wasm_import_module = "arcana")]
+        extern "C" {
+            #[link_name = "log"]
+            fn host_log(level: i32, ptr: i32, len: i32);
+        }
+        // SAFETY: the host only reads `len` bytes at `ptr` during the call.
+        unsafe { host_log(level as i32, message.as_ptr() as i32, message.len() as i32) }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    eprintln!("[{:?}] {}", level, message);
+}
+
+/// Decodes a platform event and passes it to the listener.
+pub fn handle_event<L: EventListenerExtension + ?Sized>(listener: &L, input: &[u8]) -> Result<(), String> {
+    let event = serde_json::from_slice(input).map_err(|e| format!("Invalid event: {}", e))?;
+    listener.handle_event(event)
+}
+
+/// Decodes an HTTP request and passes it to the endpoint.
+///
+/// A request that cannot be decoded is answered with `400 Bad Request`.
+pub fn handle_request<E: RestEndpointExtension + ?Sized>(endpoint: &E, input: &[u8]) -> HttpResponse {
+    match serde_json::from_slice(input) {
+        Ok(request) => endpoint.handle_request(request),
+        Err(e) => HttpResponse {
+            status: 400,
+            headers: vec![("content-type".to_string(), "text/plain".to_string())],
+            body: Some(format!("Invalid request: {}", e).into_bytes()),
+        },
+    }
+}
+
+/// Decodes a job context and runs the job.
+pub fn execute_job<J: ScheduledJobExtension + ?Sized>(job: &J, input: &[u8]) -> Result<(), String> {
+    let ctx = serde_json::from_slice(input).map_err(|e| format!("Invalid job context: {}", e))?;
+    job.execute(ctx)
+}
+
+/// Exports a plugin type through the [plugin ABI](crate::abi).
+///
+/// See the [`guest`](crate::guest) module for usage.
+#[macro_export]
+macro_rules! export_plugin {
+    ($plugin:ty) => {
+        $crate::export_plugin!($plugin, extensions: []);
+    };
+    ($plugin:ty, extensions: [$($extension:ident),* $(,)?]) => {
+        const _: () = {
+            static PLUGIN: ::std::sync::Mutex<::std::option::Option<$plugin>> = ::std::sync::Mutex::new(None);
+
+            fn with_plugin<R>(f: impl FnOnce(&mut $plugin) -> R) -> R {
+                let mut plugin = PLUGIN.lock().unwrap_or_else(::std::sync::PoisonError::into_inner);
+                f(plugin.get_or_insert_with(<$plugin as ::std::default::Default>::default))
+            }
+
+            #[no_mangle]
+            pub extern "C" fn arcana_alloc(len: i32) -> i32 {
+                $crate::guest::alloc(len)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn arcana_free(ptr: i32, len: i32) {
+                $crate::guest::free(ptr, len);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn arcana_descriptor() -> i64 {
+                with_plugin(|plugin| $crate::guest::output_json($crate::Plugin::descriptor(&*plugin)))
+            }
+
+            #[no_mangle]
+            pub extern "C" fn arcana_on_install() -> i64 {
+                $crate::guest::status(with_plugin($crate::Plugin::on_install))
+            }
+
+            #[no_mangle]
+            pub extern "C" fn arcana_on_enable() -> i64 {
+                $crate::guest::status(with_plugin($crate::Plugin::on_enable))
+            }
+
+            #[no_mangle]
+            pub extern "C" fn arcana_on_disable() -> i64 {
+                $crate::guest::status(with_plugin($crate::Plugin::on_disable))
+            }
+
+            #[no_mangle]
+            pub extern "C" fn arcana_on_uninstall() -> i64 {
+                $crate::guest::status(with_plugin($crate::Plugin::on_uninstall))
+            }
+
+            $($crate::__export_extension!($extension, $plugin, with_plugin);)*
+        };
+    };
+}
+
+/// Generates the exports of one extension; used by [`export_plugin!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __export_extension {
+    (event_listener, $plugin:ty, $with_plugin:ident) => {
+        #[no_mangle]
+        pub extern "C" fn arcana_subscriptions() -> i64 {
+            $with_plugin(|plugin: &mut $plugin| {
+                $crate::guest::output_json(&$crate::extensions::EventListenerExtension::subscriptions(&*plugin))
+            })
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn arcana_handle_event(ptr: i32, len: i32) -> i64 {
+            let input = $crate::guest::input(ptr, len);
+            $crate::guest::status($with_plugin(|plugin: &mut $plugin| $crate::guest::handle_event(&*plugin, input)))
+        }
+    };
+    (rest_endpoint, $plugin:ty, $with_plugin:ident) => {
+        #[no_mangle]
+        pub extern "C" fn arcana_routes() -> i64 {
+            $with_plugin(|plugin: &mut $plugin| {
+                $crate::guest::output_json(&$crate::extensions::RestEndpointExtension::routes(&*plugin))
+            })
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn arcana_handle_request(ptr: i32, len: i32) -> i64 {
+            let input = $crate::guest::input(ptr, len);
+            $crate::guest::output_json(&$with_plugin(|plugin: &mut $plugin| {
+                $crate::guest::handle_request(&*plugin, input)
+            }))
+        }
+    };
+    (scheduled_job, $plugin:ty, $with_plugin:ident) => {
+        #[no_mangle]
+        pub extern "C" fn arcana_job_config() -> i64 {
+            $with_plugin(|plugin: &mut $plugin| {
+                $crate::guest::output_json(&$crate::extensions::ScheduledJobExtension::config(&*plugin))
+            })
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn arcana_execute_job(ptr: i32, len: i32) -> i64 {
+            let input = $crate::guest::input(ptr, len);
+            $crate::guest::status($with_plugin(|plugin: &mut $plugin| $crate::guest::execute_job(&*plugin, input)))
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extensions::{
+        EventSubscription, HttpMethod, HttpRequest, JobConfig, JobContext, PluginEvent, RouteDefinition, Schedule,
+    };
+    use std::sync::Mutex;
+
+    /// Extension recording what it was called with.
+    #[derive(Default)]
+    struct Recorder {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl EventListenerExtension for Recorder {
+        fn subscriptions(&self) -> EventSubscription {
+            EventSubscription { event_types: vec!["*".to_string()], order: 0, async_handling: false }
+        }
+
+        fn handle_event(&self, event: PluginEvent) -> Result<(), String> {
+            self.calls.lock().unwrap().push(event.event_type);
+            Ok(())
+        }
+    }
+
+    impl RestEndpointExtension for Recorder {
+        fn routes(&self) -> Vec<RouteDefinition> {
+            Vec::new()
+        }
+
+        fn handle_request(&self, request: HttpRequest) -> HttpResponse {
+            HttpResponse { status: 200, headers: Vec::new(), body: Some(request.path.into_bytes()) }
+        }
+    }
+
+    impl ScheduledJobExtension for Recorder {
+        fn config(&self) -> JobConfig {
+            JobConfig {
+                key: "recorder".to_string(),
+                schedule: Schedule::FixedRate(60_000),
+                description: String::new(),
+                enabled: true,
+                allow_concurrent: false,
+            }
+        }
+
+        fn execute(&self, ctx: JobContext) -> Result<(), String> {
+            self.calls.lock().unwrap().push(ctx.execution_id);
+            Ok(())
+        }
+
+        fn is_cancelled(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn test_arguments_are_decoded_from_json() {
+        let recorder = Recorder::default();
+
+        let event = PluginEvent {
+            event_type: "user.created".to_string(),
+            timestamp: 0,
+            payload: "{}".to_string(),
+            source_plugin: None,
+        };
+        handle_event(&recorder, &serde_json::to_vec(&event).unwrap()).unwrap();
+
+        let ctx = JobContext { job_key: "recorder".to_string(), scheduled_time: 0, execution_id: "run-1".to_string() };
+        execute_job(&recorder, &serde_json::to_vec(&ctx).unwrap()).unwrap();
+
+        let request = HttpRequest {
+            method: HttpMethod::Get,
+            path: "/hello".to_string(),
+            headers: Vec::new(),
+            query_params: Vec::new(),
+            path_params: Vec::new(),
+            body: None,
+            user_id: None,
+        };
+        let response = handle_request(&recorder, &serde_json::to_vec(&request).unwrap());
+        assert_eq!(response.body.unwrap(), b"/hello");
+
+        assert_eq!(*recorder.calls.lock().unwrap(), vec!["user.created", "run-1"]);
+    }
+
+    #[test]
+    fn test_negative_allocations_are_refused() {
+        assert_eq!(alloc(-1), 0);
+        assert_eq!(alloc(i32::MIN), 0);
+    }
+
+    #[test]
+    fn test_malformed_arguments_are_rejected() {
+        let recorder = Recorder::default();
+
+        assert!(handle_event(&recorder, b"{").unwrap_err().starts_with("Invalid event"));
+        assert!(execute_job(&recorder, b"[]").unwrap_err().starts_with("Invalid job context"));
+        assert_eq!(handle_request(&recorder, b"null").status, 400);
+        assert!(recorder.calls.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_status_encoding() {
+        assert_eq!(status(Ok(())), 0);
+        assert_ne!(status(Err("failed".to_string())), 0);
+    }
+}
@@ -5,7 +5,11 @@
 //!
 //! This crate can be compiled for both host (native) and WASM targets.
 
+pub mod abi;
 pub mod extensions;
+#[cfg(feature = "guest")]
+pub mod guest;
+pub mod manifest;
 
 use serde::{Deserialize, Serialize};
 
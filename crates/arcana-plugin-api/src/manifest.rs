@@ -0,0 +1,80 @@
+//! Plugin manifest declaring what a plugin needs from the host.
+//!
+//! The manifest ships next to the module as `<name>.manifest.json`:
+//!
+//! ```json
+//! {
+//!   "key": "arcana-audit-plugin",
+//!   "capabilities": {
+//!     "network": false,
+//!     "preopens": [{ "host_path": "/var/log/arcana", "guest_path": "/logs", "writable": true }],
+//!     "env": ["TZ"],
+//!     "host_functions": ["log"]
+//!   }
+//! }
+//! ```
+//!
+//! A plugin without a manifest runs without any capabilities. The platform
+//! only installs a plugin whose capabilities the administrator approved.
+
+use serde::{Deserialize, Serialize};
+
+/// Manifest of a plugin module.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginManifest {
+    /// Key of the plugin; must match the key of its descriptor.
+    pub key: String,
+    /// Capabilities the plugin requests.
+    #[serde(default)]
+    pub capabilities: Capabilities,
+}
+
+/// Host capabilities a plugin requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Capabilities {
+    /// Network access.
+    pub network: bool,
+    /// Host directories mounted into the plugin.
+    pub preopens: Vec<Preopen>,
+    /// Host environment variables passed to the plugin.
+    pub env: Vec<String>,
+    /// Host functions of [`HOST_MODULE`](crate::abi::HOST_MODULE) the plugin imports.
+    pub host_functions: Vec<String>,
+}
+
+impl Capabilities {
+    /// Returns true if no capability is requested.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self.network && self.preopens.is_empty() && self.env.is_empty() && self.host_functions.is_empty()
+    }
+}
+
+/// Host directory mounted into a plugin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Preopen {
+    /// Directory on the host.
+    pub host_path: String,
+    /// Path the plugin sees the directory at.
+    pub guest_path: String,
+    /// Whether the plugin may modify the directory.
+    #[serde(default)]
+    pub writable: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities_default_to_none() {
+        let manifest: PluginManifest = serde_json::from_str(r#"{"key":"quiet"}"#).unwrap();
+        assert!(manifest.capabilities.is_empty());
+
+        let manifest: PluginManifest =
+            serde_json::from_str(r#"{"key":"chatty","capabilities":{"host_functions":["log"]}}"#).unwrap();
+        assert_eq!(manifest.capabilities.host_functions, vec!["log"]);
+        assert!(!manifest.capabilities.network);
+    }
+}
@@ -0,0 +1,115 @@
+//! Creates publisher keys and signs plugins for signature verification.
+//!
+//! ```bash
+//! arcana-plugin-sign keygen ci                      # writes ci.key and ci.pub
+//! arcana-plugin-sign sign ci.key plugins/audit.wasm # writes plugins/audit.sig
+//! arcana-plugin-sign verify ci.pub plugins/audit.wasm
+//! ```
+//!
+//! Signatures cover the module and its `<name>.manifest.json`, if present.
+//! Trust a key by adding the contents of its `.pub` file to
+//! `[plugins] trusted_keys`, or the file itself to `trusted_keys_dir`.
+
+use arcana_plugin_runtime::signature::{self, TrustedKeys};
+use ed25519_dalek::SigningKey;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage:
+  arcana-plugin-sign keygen <name>
+  arcana-plugin-sign sign <name>.key <plugin>.wasm
+  arcana-plugin-sign verify <name>.pub <plugin>.wasm";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["keygen", name] => keygen(name),
+        ["sign", key, plugin] => sign(Path::new(key), Path::new(plugin)),
+        ["verify", key, plugin] => verify(Path::new(key), Path::new(plugin)),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Writes a new key pair to `<name>.key` and `<name>.pub`.
+fn keygen(name: &str) -> Result<(), String> {
+    let mut seed = [0; 32];
+    getrandom::getrandom(&mut seed).map_err(|e| format!("Failed to generate a key: {}", e))?;
+    let key = SigningKey::from_bytes(&seed);
+
+    let secret_path = PathBuf::from(format!("{}.key", name));
+    write_secret(&secret_path, &hex::encode(key.to_bytes()))?;
+    let public_path = PathBuf::from(format!("{}.pub", name));
+    write(&public_path, &hex::encode(key.verifying_key().to_bytes()))?;
+
+    println!("Wrote {} and {}", secret_path.display(), public_path.display());
+    Ok(())
+}
+
+/// Signs a plugin and its manifest, writing `<plugin>.sig`.
+fn sign(key_path: &Path, plugin_path: &Path) -> Result<(), String> {
+    let key = signature::parse_signing_key(&read_to_string(key_path)?).map_err(|e| e.to_string())?;
+    let (wasm, manifest) = read_plugin(plugin_path)?;
+
+    let signature_path = plugin_path.with_extension("sig");
+    write(&signature_path, &signature::sign(&key, &wasm, manifest.as_deref()))?;
+
+    println!("Wrote {}", signature_path.display());
+    Ok(())
+}
+
+/// Checks the signature of a plugin against one public key.
+fn verify(key_path: &Path, plugin_path: &Path) -> Result<(), String> {
+    let key = signature::parse_verifying_key(&read_to_string(key_path)?).map_err(|e| e.to_string())?;
+    let (wasm, manifest) = read_plugin(plugin_path)?;
+    let signature = read_to_string(&plugin_path.with_extension("sig"))?;
+
+    TrustedKeys::new(vec![key])
+        .verify(&wasm, manifest.as_deref(), &signature)
+        .map_err(|e| e.to_string())?;
+
+    println!("{} is signed by {}", plugin_path.display(), key_path.display());
+    Ok(())
+}
+
+/// Reads a plugin module and its manifest, if any.
+fn read_plugin(path: &Path) -> Result<(Vec<u8>, Option<Vec<u8>>), String> {
+    let wasm = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let manifest_path = path.with_extension("manifest.json");
+    let manifest = if manifest_path.exists() {
+        Some(std::fs::read(&manifest_path).map_err(|e| format!("Failed to read {}: {}", manifest_path.display(), e))?)
+    } else {
+        None
+    };
+    Ok((wasm, manifest))
+}
+
+fn read_to_string(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    std::fs::write(path, format!("{}\n", contents)).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Writes a file only the current user can read.
+fn write_secret(path: &Path, contents: &str) -> Result<(), String> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path).map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+    writeln!(file, "{}", contents).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
@@ -0,0 +1,70 @@
+//! Routing of platform domain events to plugin event listeners.
+
+use crate::PluginManager;
+use arcana_core::{ArcanaResult, DomainEvent, EventHandler};
+use arcana_plugin_api::extensions::PluginEvent;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Event handler forwarding every domain event to the plugins' listeners.
+///
+/// Subscribe it to the platform event bus to make domain events visible
+/// to plugins implementing `EventListenerExtension`.
+pub struct PluginEventBridge {
+    plugins: Arc<PluginManager>,
+}
+
+impl PluginEventBridge {
+    /// Creates a bridge delivering to the plugins of the given manager.
+    pub fn new(plugins: Arc<PluginManager>) -> Self {
+        Self { plugins }
+    }
+}
+
+#[async_trait]
+impl EventHandler<dyn DomainEvent> for PluginEventBridge {
+    async fn handle(&self, event: &dyn DomainEvent) -> ArcanaResult<()> {
+        self.plugins.dispatch_event(to_plugin_event(event)?).await;
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for PluginEventBridge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginEventBridge")
+            .field("plugins", &self.plugins)
+            .finish()
+    }
+}
+
+/// Converts a domain event into the event passed to plugins.
+///
+/// The timestamp is in milliseconds since the Unix epoch and the payload
+/// is the event serialized as JSON.
+pub fn to_plugin_event(event: &dyn DomainEvent) -> ArcanaResult<PluginEvent> {
+    Ok(PluginEvent {
+        event_type: event.event_type().to_string(),
+        timestamp: u64::try_from(event.timestamp().timestamp_millis()).unwrap_or_default(),
+        payload: event.to_json()?,
+        source_plugin: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{UserCreated, UserId};
+
+    #[test]
+    fn test_to_plugin_event() {
+        let created = UserCreated::new(UserId::new(), "ada".to_string(), "ada@example.com".to_string());
+        let event = to_plugin_event(&created).unwrap();
+
+        assert_eq!(event.event_type, "user.created");
+        assert_eq!(event.timestamp, created.timestamp.timestamp_millis() as u64);
+        assert!(event.source_plugin.is_none());
+
+        let payload: serde_json::Value = serde_json::from_str(&event.payload).unwrap();
+        assert_eq!(payload["username"], "ada");
+    }
+}
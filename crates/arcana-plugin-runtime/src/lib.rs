@@ -1,8 +1,17 @@
 //! # Arcana Plugin Runtime
 //!
 //! Plugin runtime for Arcana Cloud Rust using Wasmtime.
-//! Manages loading, execution, and lifecycle of WASM plugins.
+//! Manages loading, sandboxed execution, and lifecycle of WASM plugins, and routes
+//! platform events to the plugins' event listeners.
 
+pub mod events;
 pub mod manager;
+pub mod sandbox;
+pub mod signature;
+pub mod wasm;
 
+pub use events::*;
 pub use manager::*;
+pub use sandbox::*;
+pub use signature::*;
+pub use wasm::*;
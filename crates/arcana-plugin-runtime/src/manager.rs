@@ -1,34 +1,89 @@
 //! Plugin manager for loading and managing WASM plugins.
 
+use crate::sandbox::{self, Sandbox};
+use crate::signature::TrustedKeys;
+use crate::wasm::{WasmEventListener, WasmPlugin};
 use arcana_config::PluginConfig;
 use arcana_core::{ArcanaError, ArcanaResult, PluginId};
+use arcana_plugin_api::abi::LifecycleHook;
+use arcana_plugin_api::extensions::{EventListenerExtension, EventSubscription, PluginEvent};
+use arcana_plugin_api::manifest::{Capabilities, PluginManifest};
 use arcana_plugin_api::{PluginDescriptor, PluginState};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
 use tokio::sync::RwLock;
-use tracing::{debug, info};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, info, warn};
+use wasmtime::Engine;
 
 /// Plugin manager for loading and managing WASM plugins.
 pub struct PluginManager {
     plugins: Arc<RwLock<HashMap<PluginId, LoadedPlugin>>>,
+    /// Serializes lifecycle changes, whose hooks run without holding `plugins`
+    /// so event dispatch and lookups are never blocked by plugin code.
+    lifecycle: tokio::sync::Mutex<()>,
+    engine: Engine,
     config: PluginConfig,
+    /// Plugin files seen by the last scan of the plugin directory.
+    files: tokio::sync::Mutex<HashMap<PathBuf, WatchedFile>>,
+}
+
+/// A `.wasm` file in the plugin directory and the plugin loaded from it.
+struct WatchedFile {
+    fingerprint: Fingerprint,
+    plugin_id: Option<PluginId>,
 }
 
 /// A loaded plugin instance.
 pub struct LoadedPlugin {
     pub descriptor: PluginDescriptor,
     pub state: PluginState,
-    // In a full implementation, this would contain the Wasmtime instance
+    /// The instantiated plugin module.
+    pub instance: Arc<Mutex<WasmPlugin>>,
+    /// Event listener the plugin provides, if any.
+    pub event_listener: Option<PluginEventListener>,
+}
+
+/// Event listener of a plugin with the subscription it declared when it
+/// was attached.
+#[derive(Clone)]
+pub struct PluginEventListener {
+    pub listener: Arc<dyn EventListenerExtension>,
+    pub subscription: EventSubscription,
+}
+
+impl PluginEventListener {
+    /// Wraps a listener, reading its subscription once.
+    pub fn new(listener: Arc<dyn EventListenerExtension>) -> Self {
+        let subscription = listener.subscriptions();
+        Self { listener, subscription }
+    }
+}
+
+impl std::fmt::Debug for PluginEventListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginEventListener")
+            .field("subscription", &self.subscription)
+            .finish_non_exhaustive()
+    }
 }
 
 impl PluginManager {
     /// Creates a new plugin manager.
-    pub fn new(config: PluginConfig) -> Self {
-        Self {
+    ///
+    /// Fails if the sandboxed Wasmtime engine cannot be set up.
+    pub fn new(config: PluginConfig) -> ArcanaResult<Self> {
+        Ok(Self {
             plugins: Arc::new(RwLock::new(HashMap::new())),
+            lifecycle: tokio::sync::Mutex::new(()),
+            engine: sandbox::sandboxed_engine()?,
             config,
-        }
+            files: tokio::sync::Mutex::new(HashMap::new()),
+        })
     }
 
     /// Initializes the plugin manager and loads plugins from the configured directory.
@@ -53,91 +108,405 @@ impl PluginManager {
         Ok(())
     }
 
-    /// Scans the plugin directory for plugins.
-    async fn scan_plugins(&self) -> ArcanaResult<()> {
+    /// Synchronizes the installed plugins with the `.wasm` files in the
+    /// plugin directory.
+    ///
+    /// New files are installed and enabled, changed files are reloaded (see
+    /// [`reload_plugin`](Self::reload_plugin)) and the plugins of removed
+    /// files are uninstalled. A file counts as changed when the module gets
+    /// another size or modification time, or its manifest or signature
+    /// another content.
+    /// Plugins that fail to load, or lack a valid signature while
+    /// `verify_signatures` is set, are logged and skipped until their files
+    /// change again.
+    pub async fn scan_plugins(&self) -> ArcanaResult<()> {
         let plugin_dir = Path::new(&self.config.directory);
 
         if !plugin_dir.is_dir() {
             return Ok(());
         }
 
+        let trusted_keys = if self.config.verify_signatures {
+            let keys = TrustedKeys::load(&self.config)?;
+            if keys.is_empty() {
+                warn!("Plugin signatures are verified but no publisher keys are trusted");
+            }
+            Some(keys)
+        } else {
+            None
+        };
+
         let entries = std::fs::read_dir(plugin_dir).map_err(|e| {
             ArcanaError::PluginLoading(format!("Failed to read plugin directory: {}", e))
         })?;
+        let mut current: Vec<(PathBuf, Fingerprint)> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "wasm"))
+            .map(|path| {
+                let fingerprint = fingerprint(&path);
+                (path, fingerprint)
+            })
+            .collect();
+        current.sort();
+
+        let mut files = self.files.lock().await;
 
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.extension().map_or(false, |ext| ext == "wasm") {
-                debug!("Found plugin: {:?}", path);
-                // In a full implementation, load the plugin here
+        let removed: Vec<PathBuf> = files
+            .keys()
+            .filter(|path| !current.iter().any(|(p, _)| p == *path))
+            .cloned()
+            .collect();
+        for path in removed {
+            let Some(plugin_id) = files.remove(&path).and_then(|file| file.plugin_id) else {
+                continue;
+            };
+            info!("Plugin file {:?} was removed", path);
+            if let Err(e) = self.uninstall_plugin(&plugin_id).await {
+                warn!("Failed to uninstall plugin {}: {}", plugin_id, e);
             }
         }
 
+        for (path, fingerprint) in current {
+            let plugin_id = match files.get(&path) {
+                Some(file) if file.fingerprint == fingerprint => continue,
+                Some(WatchedFile { plugin_id: Some(plugin_id), .. }) => {
+                    info!("Plugin file {:?} changed", path);
+                    if let Err(e) = self.reload_plugin_file(&path, plugin_id, trusted_keys.as_ref()).await {
+                        warn!("Failed to reload plugin {:?}, keeping the running version: {}", path, e);
+                    }
+                    Some(plugin_id.clone())
+                }
+                _ => {
+                    debug!("Found plugin: {:?}", path);
+                    self.load_plugin_file(&path, trusted_keys.as_ref())
+                        .await
+                        .map_err(|e| warn!("Failed to load plugin {:?}: {}", path, e))
+                        .ok()
+                }
+            };
+            files.insert(path, WatchedFile { fingerprint, plugin_id });
+        }
+
         Ok(())
     }
 
+    /// Starts rescanning the plugin directory every `hot_reload_interval_secs`
+    /// if `hot_reload` is enabled.
+    ///
+    /// The watcher stops when the manager is dropped.
+    pub fn watch(self: &Arc<Self>) -> Option<JoinHandle<()>> {
+        if !self.config.enabled || !self.config.hot_reload {
+            return None;
+        }
+
+        let period = Duration::from_secs(self.config.hot_reload_interval_secs.max(1));
+        info!("Watching {} for plugin changes every {:?}", self.config.directory, period);
+        let manager = Arc::downgrade(self);
+        Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let Some(manager) = manager.upgrade() else {
+                    break;
+                };
+                if let Err(e) = manager.scan_plugins().await {
+                    warn!("Failed to scan plugin directory: {}", e);
+                }
+            }
+        }))
+    }
+
+    /// Installs and enables a plugin from a `.wasm` file.
+    async fn load_plugin_file(&self, path: &Path, trusted_keys: Option<&TrustedKeys>) -> ArcanaResult<PluginId> {
+        let (wasm_bytes, manifest) = read_plugin(path, trusted_keys)?;
+        let plugin_id = self.install(self.load(&wasm_bytes, manifest.as_ref()).await?).await?;
+        self.enable_plugin(&plugin_id).await?;
+        Ok(plugin_id)
+    }
+
+    /// Reloads an installed plugin from its changed `.wasm` file, or loads
+    /// it again if it was uninstalled in the meantime.
+    async fn reload_plugin_file(
+        &self,
+        path: &Path,
+        plugin_id: &PluginId,
+        trusted_keys: Option<&TrustedKeys>,
+    ) -> ArcanaResult<()> {
+        if self.get_plugin(plugin_id).await.is_none() {
+            return self.load_plugin_file(path, trusted_keys).await.map(|_| ());
+        }
+
+        let (wasm_bytes, manifest) = read_plugin(path, trusted_keys)?;
+        self.reload_plugin(plugin_id, &wasm_bytes, manifest.as_ref()).await
+    }
+
     /// Installs a plugin from WASM bytes.
+    ///
+    /// Compiles and instantiates the module, reads the descriptor it exports
+    /// and calls its `on_install` hook. The plugin is keyed by the
+    /// descriptor's `key`, which must not be installed already. An event
+    /// listener the module exports is attached right away.
+    ///
+    /// The plugin runs without any capabilities; see
+    /// [`install_plugin_with_manifest`](Self::install_plugin_with_manifest).
     pub async fn install_plugin(&self, wasm_bytes: &[u8]) -> ArcanaResult<PluginId> {
-        info!("Installing plugin from WASM bytes ({} bytes)", wasm_bytes.len());
-
-        // In a full implementation:
-        // 1. Compile the WASM module with Wasmtime
-        // 2. Instantiate the module
-        // 3. Call the plugin's get_descriptor function
-        // 4. Store the plugin instance
-
-        // For now, return a placeholder
-        let plugin_id = PluginId::new("placeholder-plugin");
-
-        let loaded = LoadedPlugin {
-            descriptor: PluginDescriptor {
-                key: plugin_id.as_str().to_string(),
-                name: "Placeholder Plugin".to_string(),
-                version: "1.0.0".to_string(),
-                description: "A placeholder plugin".to_string(),
-                author: "Arcana".to_string(),
-                min_platform_version: "0.1.0".to_string(),
-            },
-            state: PluginState::Installed,
+        self.install(self.load(wasm_bytes, None).await?).await
+    }
+
+    /// Installs a plugin from WASM bytes with the capabilities its manifest
+    /// requests.
+    ///
+    /// Fails if the configured `grants` for the manifest's key do not cover
+    /// every requested capability, or if the module's descriptor has another
+    /// key.
+    pub async fn install_plugin_with_manifest(
+        &self,
+        wasm_bytes: &[u8],
+        manifest: &PluginManifest,
+    ) -> ArcanaResult<PluginId> {
+        self.install(self.load(wasm_bytes, Some(manifest)).await?).await
+    }
+
+    /// Replaces the module of an installed plugin with a new version.
+    ///
+    /// The new module is loaded first; if that fails, nothing changes. An
+    /// active plugin is then disabled on the old instance and enabled on the
+    /// new one, and stays on the old instance, enabled again, if the new one
+    /// refuses. Calls already running finish on the old instance; later
+    /// calls, and the event listener, go to the new one. The new instance
+    /// does not get `on_install`.
+    pub async fn reload_plugin(
+        &self,
+        plugin_id: &PluginId,
+        wasm_bytes: &[u8],
+        manifest: Option<&PluginManifest>,
+    ) -> ArcanaResult<()> {
+        let (plugin, subscription) = self.load(wasm_bytes, manifest).await?;
+        let descriptor = plugin.descriptor().clone();
+        if descriptor.key != plugin_id.as_str() {
+            return Err(ArcanaError::PluginLoading(format!(
+                "Plugin {} cannot be replaced by plugin {}",
+                plugin_id, descriptor.key
+            )));
+        }
+        let instance = Arc::new(Mutex::new(plugin));
+        let event_listener = wasm_event_listener(plugin_id, &instance, subscription);
+
+        let _lifecycle = self.lifecycle.lock().await;
+        let (state, old_instance) = self.update(plugin_id, |loaded| (loaded.state, loaded.instance.clone())).await?;
+
+        if state == PluginState::Active {
+            self.set_state(plugin_id, PluginState::Stopping).await?;
+            if let Err(e) = call_hook(&old_instance, LifecycleHook::Disable).await {
+                warn!("Plugin {} failed to disable before reloading: {}", plugin_id, e);
+            }
+
+            self.set_state(plugin_id, PluginState::Starting).await?;
+            if let Err(e) = call_hook(&instance, LifecycleHook::Enable).await {
+                warn!("Plugin {} {} failed to enable, rolling back", plugin_id, descriptor.version);
+                let restored = call_hook(&old_instance, LifecycleHook::Enable).await;
+                let state = if restored.is_ok() { PluginState::Active } else { PluginState::Resolved };
+                self.set_state(plugin_id, state).await?;
+                return Err(e);
+            }
+        }
+
+        self.update(plugin_id, |loaded| {
+            info!("Reloaded plugin {} {} -> {}", plugin_id, loaded.descriptor.version, descriptor.version);
+            loaded.state = state;
+            loaded.descriptor = descriptor;
+            loaded.instance = instance;
+            loaded.event_listener = event_listener;
+        })
+        .await
+    }
+
+    /// Compiles and instantiates a module with the capabilities its
+    /// manifest requests, reading its event subscription.
+    async fn load(
+        &self,
+        wasm_bytes: &[u8],
+        manifest: Option<&PluginManifest>,
+    ) -> ArcanaResult<(WasmPlugin, Option<EventSubscription>)> {
+        info!("Loading plugin from WASM bytes ({} bytes)", wasm_bytes.len());
+
+        let capabilities = match manifest {
+            Some(manifest) => {
+                let grant = self.config.grants.get(&manifest.key).cloned().unwrap_or_default();
+                let unapproved = sandbox::unapproved(&manifest.capabilities, &grant);
+                if !unapproved.is_empty() {
+                    return Err(ArcanaError::PluginLoading(format!(
+                        "Plugin {} requests capabilities that are not approved: {}",
+                        manifest.key,
+                        unapproved.join(", ")
+                    )));
+                }
+                manifest.capabilities.clone()
+            }
+            None => Capabilities::default(),
         };
 
-        self.plugins.write().await.insert(plugin_id.clone(), loaded);
+        let engine = self.engine.clone();
+        let sandbox = Sandbox::new(&self.config, capabilities);
+        let wasm_bytes = wasm_bytes.to_vec();
+        let (plugin, subscription) = tokio::task::spawn_blocking(move || {
+            let mut plugin = WasmPlugin::load(&engine, &wasm_bytes, &sandbox)?;
+            let subscription = plugin.event_subscription()?;
+            Ok::<_, ArcanaError>((plugin, subscription))
+        })
+        .await
+        .map_err(|e| ArcanaError::PluginLoading(format!("Plugin compilation panicked: {}", e)))??;
+
+        if manifest.is_some_and(|manifest| manifest.key != plugin.descriptor().key) {
+            return Err(ArcanaError::PluginLoading(format!(
+                "Plugin {} does not match its manifest",
+                plugin.descriptor().key
+            )));
+        }
+        Ok((plugin, subscription))
+    }
+
+    /// Registers a loaded module as a new plugin and calls its `on_install` hook.
+    async fn install(&self, (plugin, subscription): (WasmPlugin, Option<EventSubscription>)) -> ArcanaResult<PluginId> {
+        let descriptor = plugin.descriptor().clone();
+        let plugin_id = PluginId::new(descriptor.key.clone());
+
+        let _lifecycle = self.lifecycle.lock().await;
+        if self.plugins.read().await.contains_key(&plugin_id) {
+            return Err(ArcanaError::PluginLoading(format!("Plugin {} is already installed", plugin_id)));
+        }
+
+        let instance = Arc::new(Mutex::new(plugin));
+        call_hook(&instance, LifecycleHook::Install).await?;
+        let event_listener = wasm_event_listener(&plugin_id, &instance, subscription);
+
+        info!("Installed plugin {} {}", plugin_id, descriptor.version);
+        self.plugins.write().await.insert(
+            plugin_id.clone(),
+            LoadedPlugin { descriptor, state: PluginState::Installed, instance, event_listener },
+        );
 
         Ok(plugin_id)
     }
 
-    /// Enables a plugin.
-    pub async fn enable_plugin(&self, plugin_id: &PluginId) -> ArcanaResult<()> {
+    /// Attaches the event listener extension of an installed plugin.
+    ///
+    /// The listener receives platform events while the plugin is active.
+    pub async fn attach_event_listener(
+        &self,
+        plugin_id: &PluginId,
+        listener: Arc<dyn EventListenerExtension>,
+    ) -> ArcanaResult<()> {
         let mut plugins = self.plugins.write().await;
         let plugin = plugins.get_mut(plugin_id).ok_or_else(|| {
             ArcanaError::PluginNotFound(plugin_id.as_str().to_string())
         })?;
 
-        info!("Enabling plugin: {}", plugin_id);
-        plugin.state = PluginState::Active;
+        let listener = PluginEventListener::new(listener);
+        debug!("Plugin {} listens to {:?}", plugin_id, listener.subscription.event_types);
+        plugin.event_listener = Some(listener);
 
         Ok(())
     }
 
+    /// Delivers an event to the listeners of all active plugins subscribed to it.
+    ///
+    /// Listeners are called by ascending `order`. Synchronous listeners are
+    /// awaited one after the other; `async_handling` listeners run in the
+    /// background. Every call is bounded by the configured execution timeout,
+    /// and failing, panicking or timed out listeners are logged without
+    /// affecting the others.
+    pub async fn dispatch_event(&self, event: PluginEvent) {
+        let mut listeners: Vec<(PluginId, PluginEventListener)> = {
+            let plugins = self.plugins.read().await;
+            plugins
+                .iter()
+                .filter(|(_, p)| p.state == PluginState::Active)
+                .filter_map(|(id, p)| p.event_listener.clone().map(|l| (id.clone(), l)))
+                .filter(|(_, l)| l.subscription.matches(&event.event_type))
+                .collect()
+        };
+        listeners.sort_by(|(a_id, a), (b_id, b)| {
+            a.subscription.order.cmp(&b.subscription.order).then_with(|| a_id.as_str().cmp(b_id.as_str()))
+        });
+
+        let timeout = self.config.execution_timeout();
+        for (plugin_id, listener) in listeners {
+            let handling = call_listener(plugin_id, listener.listener, event.clone(), timeout);
+            if listener.subscription.async_handling {
+                tokio::spawn(handling);
+            } else {
+                handling.await;
+            }
+        }
+    }
+
+    /// Enables a plugin.
+    ///
+    /// The plugin stays inactive if its `on_enable` hook fails.
+    pub async fn enable_plugin(&self, plugin_id: &PluginId) -> ArcanaResult<()> {
+        let _lifecycle = self.lifecycle.lock().await;
+        let Some((previous, instance)) = self
+            .update(plugin_id, |plugin| {
+                (plugin.state != PluginState::Active).then(|| {
+                    (std::mem::replace(&mut plugin.state, PluginState::Starting), plugin.instance.clone())
+                })
+            })
+            .await?
+        else {
+            return Ok(());
+        };
+
+        info!("Enabling plugin: {}", plugin_id);
+        let enabled = call_hook(&instance, LifecycleHook::Enable).await;
+        self.set_state(plugin_id, if enabled.is_ok() { PluginState::Active } else { previous }).await?;
+
+        enabled
+    }
+
     /// Disables a plugin.
+    ///
+    /// The plugin stops receiving calls even if its `on_disable` hook fails.
     pub async fn disable_plugin(&self, plugin_id: &PluginId) -> ArcanaResult<()> {
-        let mut plugins = self.plugins.write().await;
-        let plugin = plugins.get_mut(plugin_id).ok_or_else(|| {
-            ArcanaError::PluginNotFound(plugin_id.as_str().to_string())
-        })?;
+        let _lifecycle = self.lifecycle.lock().await;
+        let Some(instance) = self
+            .update(plugin_id, |plugin| {
+                (plugin.state == PluginState::Active).then(|| {
+                    plugin.state = PluginState::Stopping;
+                    plugin.instance.clone()
+                })
+            })
+            .await?
+        else {
+            return Ok(());
+        };
 
         info!("Disabling plugin: {}", plugin_id);
-        plugin.state = PluginState::Resolved;
+        let disabled = call_hook(&instance, LifecycleHook::Disable).await;
+        self.set_state(plugin_id, PluginState::Resolved).await?;
 
-        Ok(())
+        disabled
     }
 
-    /// Uninstalls a plugin.
+    /// Uninstalls a plugin, disabling it first if it is active.
+    ///
+    /// The plugin is removed even if its hooks fail; the failures are logged.
     pub async fn uninstall_plugin(&self, plugin_id: &PluginId) -> ArcanaResult<()> {
-        let mut plugins = self.plugins.write().await;
+        let _lifecycle = self.lifecycle.lock().await;
+        let plugin = self.plugins.write().await.remove(plugin_id).ok_or_else(|| {
+            ArcanaError::PluginNotFound(plugin_id.as_str().to_string())
+        })?;
 
-        if plugins.remove(plugin_id).is_none() {
-            return Err(ArcanaError::PluginNotFound(plugin_id.as_str().to_string()));
+        if plugin.state == PluginState::Active {
+            if let Err(e) = call_hook(&plugin.instance, LifecycleHook::Disable).await {
+                warn!("Plugin {} failed to disable: {}", plugin_id, e);
+            }
+        }
+        if let Err(e) = call_hook(&plugin.instance, LifecycleHook::Uninstall).await {
+            warn!("Plugin {} failed to uninstall: {}", plugin_id, e);
         }
 
         info!("Uninstalled plugin: {}", plugin_id);
@@ -158,6 +527,112 @@ impl PluginManager {
         let plugins = self.plugins.read().await;
         plugins.get(plugin_id).map(|p| (p.descriptor.clone(), p.state))
     }
+
+    /// Changes an installed plugin under a short write lock.
+    async fn update<T>(&self, plugin_id: &PluginId, change: impl FnOnce(&mut LoadedPlugin) -> T) -> ArcanaResult<T> {
+        let mut plugins = self.plugins.write().await;
+        let plugin = plugins.get_mut(plugin_id).ok_or_else(|| {
+            ArcanaError::PluginNotFound(plugin_id.as_str().to_string())
+        })?;
+        Ok(change(plugin))
+    }
+
+    async fn set_state(&self, plugin_id: &PluginId, state: PluginState) -> ArcanaResult<()> {
+        self.update(plugin_id, |plugin| plugin.state = state).await
+    }
+}
+
+/// Reads a `.wasm` file and the `<name>.manifest.json` next to it, if any.
+///
+/// With `trusted_keys`, the plugin must come with a `<name>.sig` one of
+/// them made (see [`crate::signature`]).
+fn read_plugin(path: &Path, trusted_keys: Option<&TrustedKeys>) -> ArcanaResult<(Vec<u8>, Option<PluginManifest>)> {
+    let wasm_bytes = read_plugin_file(path)?;
+    let manifest_path = path.with_extension("manifest.json");
+    let manifest_bytes = if manifest_path.exists() { Some(read_plugin_file(&manifest_path)?) } else { None };
+
+    if let Some(trusted_keys) = trusted_keys {
+        let signature_path = path.with_extension("sig");
+        if !signature_path.exists() {
+            return Err(ArcanaError::PluginLoading(format!("Plugin {:?} is not signed", path)));
+        }
+        let signature = String::from_utf8_lossy(&read_plugin_file(&signature_path)?).into_owned();
+        trusted_keys.verify(&wasm_bytes, manifest_bytes.as_deref(), &signature)?;
+    }
+
+    let manifest = manifest_bytes
+        .map(|bytes| serde_json::from_slice(&bytes))
+        .transpose()
+        .map_err(|e| ArcanaError::PluginLoading(format!("Invalid manifest {:?}: {}", manifest_path, e)))?;
+    Ok((wasm_bytes, manifest))
+}
+
+/// What a scan compares to tell whether a plugin's files changed.
+///
+/// Modules can be large, so only their size and modification time are kept.
+/// Manifests and signatures are small and are hashed, so rewriting one
+/// within the timestamp granularity of the file system is noticed too.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Fingerprint {
+    module: Option<(u64, SystemTime)>,
+    manifest: Option<[u8; 32]>,
+    signature: Option<[u8; 32]>,
+}
+
+fn fingerprint(path: &Path) -> Fingerprint {
+    let digest = |path: PathBuf| std::fs::read(path).ok().map(|bytes| Sha256::digest(bytes).into());
+    Fingerprint {
+        module: std::fs::metadata(path).ok().and_then(|metadata| Some((metadata.len(), metadata.modified().ok()?))),
+        manifest: digest(path.with_extension("manifest.json")),
+        signature: digest(path.with_extension("sig")),
+    }
+}
+
+/// Wraps the event listener a module exports, if any.
+fn wasm_event_listener(
+    plugin_id: &PluginId,
+    instance: &Arc<Mutex<WasmPlugin>>,
+    subscription: Option<EventSubscription>,
+) -> Option<PluginEventListener> {
+    subscription.map(|subscription| {
+        debug!("Plugin {} listens to {:?}", plugin_id, subscription.event_types);
+        PluginEventListener::new(Arc::new(WasmEventListener::new(instance.clone(), subscription)))
+    })
+}
+
+fn read_plugin_file(path: &Path) -> ArcanaResult<Vec<u8>> {
+    std::fs::read(path).map_err(|e| ArcanaError::PluginLoading(format!("Failed to read {:?}: {}", path, e)))
+}
+
+/// Runs a lifecycle hook of a plugin on the blocking pool.
+async fn call_hook(instance: &Arc<Mutex<WasmPlugin>>, hook: LifecycleHook) -> ArcanaResult<()> {
+    let instance = instance.clone();
+    tokio::task::spawn_blocking(move || {
+        instance.lock().unwrap_or_else(PoisonError::into_inner).call_hook(hook)
+    })
+    .await
+    .map_err(|e| ArcanaError::Internal(format!("Plugin hook {} panicked: {}", hook.export_name(), e)))?
+}
+
+/// Calls a listener on the blocking pool, giving up after `timeout`.
+///
+/// Plugin code is interrupted once it exceeds the timeout; a native
+/// listener that times out keeps its blocking thread until it returns.
+async fn call_listener(
+    plugin_id: PluginId,
+    listener: Arc<dyn EventListenerExtension>,
+    event: PluginEvent,
+    timeout: Duration,
+) {
+    let event_type = event.event_type.clone();
+    let handling = tokio::task::spawn_blocking(move || listener.handle_event(event));
+
+    match tokio::time::timeout(timeout, handling).await {
+        Ok(Ok(Ok(()))) => debug!("Plugin {} handled {}", plugin_id, event_type),
+        Ok(Ok(Err(e))) => warn!("Plugin {} failed to handle {}: {}", plugin_id, event_type, e),
+        Ok(Err(e)) => warn!("Plugin {} panicked handling {}: {}", plugin_id, event_type, e),
+        Err(_) => warn!("Plugin {} timed out handling {} after {:?}", plugin_id, event_type, timeout),
+    }
 }
 
 impl std::fmt::Debug for PluginManager {
@@ -168,3 +643,611 @@ impl std::fmt::Debug for PluginManager {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_config::PluginGrant;
+    use std::sync::{mpsc, Mutex};
+
+    /// Listener recording the plugin keys of the events it handled.
+    struct Recorder {
+        key: &'static str,
+        subscription: EventSubscription,
+        handled: Arc<Mutex<Vec<String>>>,
+        behavior: Behavior,
+    }
+
+    enum Behavior {
+        Record,
+        Fail,
+        Panic,
+        Sleep(Duration),
+        WaitFor(Mutex<mpsc::Receiver<()>>),
+    }
+
+    impl EventListenerExtension for Recorder {
+        fn subscriptions(&self) -> EventSubscription {
+            self.subscription.clone()
+        }
+
+        fn handle_event(&self, event: PluginEvent) -> Result<(), String> {
+            match &self.behavior {
+                Behavior::Record => {}
+                Behavior::Fail => return Err("listener failed".to_string()),
+                Behavior::Panic => panic!("listener panicked"),
+                Behavior::Sleep(duration) => std::thread::sleep(*duration),
+                Behavior::WaitFor(release) => release.lock().unwrap().recv().unwrap(),
+            }
+            self.handled.lock().unwrap().push(format!("{}:{}", self.key, event.event_type));
+            Ok(())
+        }
+    }
+
+    fn manager() -> PluginManager {
+        PluginManager::new(PluginConfig { execution_timeout_secs: 1, ..PluginConfig::default() }).unwrap()
+    }
+
+    /// Builds a plugin module in the text format, with the descriptor of
+    /// `key` at address 0 and `hooks` spliced in as extra exports.
+    fn plugin_module(key: &str, hooks: &str) -> String {
+        versioned_module(key, "1.0.0", hooks)
+    }
+
+    fn versioned_module(key: &str, version: &str, hooks: &str) -> String {
+        let descriptor = serde_json::to_string(&PluginDescriptor {
+            key: key.to_string(),
+            name: key.to_string(),
+            version: version.to_string(),
+            description: String::new(),
+            author: "Arcana".to_string(),
+            min_platform_version: "0.1.0".to_string(),
+        })
+        .unwrap();
+        format!(
+            r#"(module
+                (memory (export "memory") 1)
+                (data (i32.const 0) "{data}")
+                (data (i32.const 4096) "not today")
+                (func (export "arcana_alloc") (param i32) (result i32) (i32.const 8192))
+                (func (export "arcana_free") (param i32 i32))
+                (func (export "arcana_descriptor") (result i64) (i64.const {len}))
+                {hooks})"#,
+            data = descriptor.replace('"', "\\\""),
+            len = descriptor.len(),
+        )
+    }
+
+    /// Hook export rejecting the transition with the message "not today".
+    fn rejecting_hook(hook: LifecycleHook) -> String {
+        let packed = arcana_plugin_api::abi::pack(4096, 9);
+        format!(r#"(func (export "{}") (result i64) (i64.const {}))"#, hook.export_name(), packed)
+    }
+
+    fn subscription(event_types: &[&str], order: i32, async_handling: bool) -> EventSubscription {
+        EventSubscription {
+            event_types: event_types.iter().map(|t| t.to_string()).collect(),
+            order,
+            async_handling,
+        }
+    }
+
+    async fn add_plugin(
+        manager: &PluginManager,
+        key: &'static str,
+        subscription: EventSubscription,
+        behavior: Behavior,
+        handled: &Arc<Mutex<Vec<String>>>,
+    ) {
+        let plugin_id = manager.install_plugin(plugin_module(key, "").as_bytes()).await.unwrap();
+
+        let listener = Recorder { key, subscription, handled: handled.clone(), behavior };
+        manager.attach_event_listener(&plugin_id, Arc::new(listener)).await.unwrap();
+        manager.enable_plugin(&plugin_id).await.unwrap();
+    }
+
+    fn event(event_type: &str) -> PluginEvent {
+        PluginEvent {
+            event_type: event_type.to_string(),
+            timestamp: 0,
+            payload: "{}".to_string(),
+            source_plugin: None,
+        }
+    }
+
+    #[test]
+    fn test_subscription_wildcards() {
+        assert!(subscription(&["*"], 0, false).matches("auth.login_failed"));
+        assert!(subscription(&["user.*"], 0, false).matches("user.created"));
+        assert!(!subscription(&["user.*"], 0, false).matches("auth.login_failed"));
+        assert!(subscription(&["auth.logout", "user.created"], 0, false).matches("user.created"));
+        assert!(!subscription(&["user.created"], 0, false).matches("user.created_by_admin"));
+    }
+
+    #[tokio::test]
+    async fn test_listeners_run_by_order_for_matching_events() {
+        let manager = manager();
+        let handled = Arc::new(Mutex::new(Vec::new()));
+        add_plugin(&manager, "audit", subscription(&["*"], 1000, false), Behavior::Record, &handled).await;
+        add_plugin(&manager, "users", subscription(&["user.*"], -5, false), Behavior::Record, &handled).await;
+        add_plugin(&manager, "auth", subscription(&["auth.*"], 0, false), Behavior::Record, &handled).await;
+
+        manager.dispatch_event(event("user.created")).await;
+
+        assert_eq!(*handled.lock().unwrap(), vec!["users:user.created", "audit:user.created"]);
+    }
+
+    #[tokio::test]
+    async fn test_inactive_plugins_receive_nothing() {
+        let manager = manager();
+        let handled = Arc::new(Mutex::new(Vec::new()));
+        add_plugin(&manager, "audit", subscription(&["*"], 0, false), Behavior::Record, &handled).await;
+        manager.disable_plugin(&PluginId::new("audit")).await.unwrap();
+
+        manager.dispatch_event(event("user.created")).await;
+
+        assert!(handled.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_async_listeners_do_not_block_dispatch() {
+        let manager = manager();
+        let handled = Arc::new(Mutex::new(Vec::new()));
+        let (release, released) = mpsc::channel();
+        let waiting = Behavior::WaitFor(Mutex::new(released));
+        add_plugin(&manager, "slow", subscription(&["*"], 0, true), waiting, &handled).await;
+        add_plugin(&manager, "audit", subscription(&["*"], 1, false), Behavior::Record, &handled).await;
+
+        manager.dispatch_event(event("user.created")).await;
+        assert_eq!(*handled.lock().unwrap(), vec!["audit:user.created"]);
+
+        release.send(()).unwrap();
+        for _ in 0..100 {
+            if handled.lock().unwrap().len() == 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(handled.lock().unwrap()[1], "slow:user.created");
+    }
+
+    #[tokio::test]
+    async fn test_failing_listeners_are_isolated() {
+        let manager = manager();
+        let handled = Arc::new(Mutex::new(Vec::new()));
+        let sleeping = Behavior::Sleep(Duration::from_secs(3));
+        add_plugin(&manager, "hanging", subscription(&["*"], 0, false), sleeping, &handled).await;
+        add_plugin(&manager, "failing", subscription(&["*"], 1, false), Behavior::Fail, &handled).await;
+        add_plugin(&manager, "panicking", subscription(&["*"], 2, false), Behavior::Panic, &handled).await;
+        add_plugin(&manager, "audit", subscription(&["*"], 3, false), Behavior::Record, &handled).await;
+
+        let started = std::time::Instant::now();
+        manager.dispatch_event(event("user.deleted")).await;
+
+        assert!(started.elapsed() < Duration::from_secs(3), "the hanging listener timed out");
+        assert_eq!(*handled.lock().unwrap(), vec!["audit:user.deleted"]);
+    }
+
+    #[tokio::test]
+    async fn test_install_reads_descriptor_from_module() {
+        let manager = manager();
+        let plugin_id = manager.install_plugin(plugin_module("greeter", "").as_bytes()).await.unwrap();
+
+        assert_eq!(plugin_id, PluginId::new("greeter"));
+        let (descriptor, state) = manager.get_plugin(&plugin_id).await.unwrap();
+        assert_eq!(descriptor.name, "greeter");
+        assert_eq!(state, PluginState::Installed);
+
+        let duplicate = manager.install_plugin(plugin_module("greeter", "").as_bytes()).await;
+        assert!(matches!(duplicate, Err(ArcanaError::PluginLoading(_))));
+    }
+
+    #[tokio::test]
+    async fn test_install_rejects_invalid_modules() {
+        let manager = manager();
+        let garbage = manager.install_plugin(b"\0asm garbage").await;
+        assert!(matches!(garbage, Err(ArcanaError::PluginLoading(_))));
+
+        let without_descriptor = manager.install_plugin(br#"(module (memory (export "memory") 1))"#).await;
+        assert!(matches!(without_descriptor, Err(ArcanaError::PluginLoading(_))));
+        assert!(manager.list_plugins().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lifecycle_hooks_cross_the_boundary() {
+        let manager = manager();
+
+        let trapping = r#"(func (export "arcana_on_install") (result i64) unreachable)"#;
+        let result = manager.install_plugin(plugin_module("trapping", trapping).as_bytes()).await;
+        assert!(matches!(result, Err(ArcanaError::PluginExecution { .. })));
+        assert!(manager.get_plugin(&PluginId::new("trapping")).await.is_none());
+
+        let hooks = rejecting_hook(LifecycleHook::Enable);
+        let plugin_id = manager.install_plugin(plugin_module("reluctant", &hooks).as_bytes()).await.unwrap();
+        match manager.enable_plugin(&plugin_id).await {
+            Err(ArcanaError::PluginExecution { plugin_key, message }) => {
+                assert_eq!(plugin_key, "reluctant");
+                assert_eq!(message, "not today");
+            }
+            other => panic!("expected the plugin to reject enabling, got {:?}", other),
+        }
+        assert_eq!(manager.get_plugin(&plugin_id).await.unwrap().1, PluginState::Installed);
+
+        let hooks = rejecting_hook(LifecycleHook::Disable);
+        let plugin_id = manager.install_plugin(plugin_module("sticky", &hooks).as_bytes()).await.unwrap();
+        manager.enable_plugin(&plugin_id).await.unwrap();
+        assert!(manager.disable_plugin(&plugin_id).await.is_err());
+        assert_eq!(manager.get_plugin(&plugin_id).await.unwrap().1, PluginState::Resolved);
+
+        manager.uninstall_plugin(&plugin_id).await.unwrap();
+        assert!(manager.get_plugin(&plugin_id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_running_hooks_do_not_block_lookups() {
+        let manager = Arc::new(manager());
+        let spinning = r#"(func (export "arcana_on_enable") (result i64) (loop $spin (br $spin)) (i64.const 0))"#;
+        let plugin_id = manager.install_plugin(plugin_module("spinning", spinning).as_bytes()).await.unwrap();
+
+        let enabling = tokio::spawn({
+            let (manager, plugin_id) = (manager.clone(), plugin_id.clone());
+            async move { manager.enable_plugin(&plugin_id).await }
+        });
+        for _ in 0..50 {
+            if manager.get_plugin(&plugin_id).await.unwrap().1 == PluginState::Starting {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        // The hook spins until the 1s execution timeout; lookups and dispatch answer meanwhile
+        let lookup = tokio::time::timeout(Duration::from_millis(200), async {
+            manager.dispatch_event(event("user.created")).await;
+            manager.list_plugins().await
+        });
+        assert_eq!(lookup.await.unwrap()[0].2, PluginState::Starting);
+
+        assert!(enabling.await.unwrap().is_err());
+        assert_eq!(manager.get_plugin(&plugin_id).await.unwrap().1, PluginState::Installed);
+    }
+
+    #[tokio::test]
+    async fn test_event_listener_exports_are_attached() {
+        let manager = manager();
+        let subscription = r#"{\"event_types\":[\"user.*\"],\"order\":3,\"async_handling\":false}"#;
+        // Rejects every event, echoing it back as the error message.
+        let listener = format!(
+            r#"(data (i32.const 2048) "{}")
+               (func (export "arcana_subscriptions") (result i64) (i64.const {}))
+               (func (export "arcana_handle_event") (param i32 i32) (result i64)
+                 (i64.or (i64.shl (i64.extend_i32_u (local.get 0)) (i64.const 32))
+                         (i64.extend_i32_u (local.get 1))))"#,
+            subscription,
+            arcana_plugin_api::abi::pack(2048, subscription.replace('\\', "").len() as u32),
+        );
+        let plugin_id = manager.install_plugin(plugin_module("echo", &listener).as_bytes()).await.unwrap();
+
+        let listener = manager.plugins.read().await[&plugin_id].event_listener.clone().unwrap();
+        assert_eq!(listener.subscription.event_types, vec!["user.*"]);
+        assert_eq!(listener.subscription.order, 3);
+
+        let echoed = listener.listener.handle_event(event("user.created")).unwrap_err();
+        let echoed: PluginEvent = serde_json::from_str(&echoed).unwrap();
+        assert_eq!(echoed.event_type, "user.created");
+
+        let without_listener = manager.install_plugin(plugin_module("quiet", "").as_bytes()).await.unwrap();
+        assert!(manager.plugins.read().await[&without_listener].event_listener.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_event_input_is_freed_when_the_listener_fails() {
+        let manager = manager();
+        let subscription = r#"{\"event_types\":[\"*\"],\"order\":0,\"async_handling\":false}"#;
+        // Counts the input buffers alloc hands out and free takes back; on_disable
+        // refuses while any is outstanding. The listener traps on `user.*` events
+        // and spins on the others.
+        let listener = format!(
+            r#"(global $live (mut i32) (i32.const 0))
+               (data (i32.const 2048) "{}")
+               (func (export "arcana_subscriptions") (result i64) (i64.const {}))
+               (func (export "arcana_handle_event") (param i32 i32) (result i64)
+                 (if (i32.eq (i32.load8_u offset=15 (local.get 0)) (i32.const 117)) (then unreachable))
+                 (loop $spin (br $spin))
+                 (i64.const 0))
+               (func (export "arcana_on_disable") (result i64)
+                 (if (result i64) (global.get $live) (then (i64.const {})) (else (i64.const 0))))"#,
+            subscription,
+            arcana_plugin_api::abi::pack(2048, subscription.replace('\\', "").len() as u32),
+            arcana_plugin_api::abi::pack(4096, 9),
+        );
+        let module = plugin_module("leaky", &listener)
+            .replace(
+                r#"(func (export "arcana_alloc") (param i32) (result i32) (i32.const 8192))"#,
+                r#"(func (export "arcana_alloc") (param i32) (result i32)
+                     (global.set $live (i32.add (global.get $live) (i32.const 1))) (i32.const 8192))"#,
+            )
+            .replace(
+                r#"(func (export "arcana_free") (param i32 i32))"#,
+                r#"(func (export "arcana_free") (param i32 i32)
+                     (if (i32.eq (local.get 0) (i32.const 8192))
+                       (then (global.set $live (i32.sub (global.get $live) (i32.const 1))))))"#,
+            );
+        let plugin_id = manager.install_plugin(module.as_bytes()).await.unwrap();
+        manager.enable_plugin(&plugin_id).await.unwrap();
+        let listener = manager.plugins.read().await[&plugin_id].event_listener.clone().unwrap();
+
+        let trapped = listener.listener.handle_event(event("user.created")).unwrap_err();
+        assert!(trapped.contains("trapped"), "{}", trapped);
+        let timed_out = listener.listener.handle_event(event("auth.login_failed")).unwrap_err();
+        assert!(timed_out.contains("execution timeout"), "{}", timed_out);
+
+        manager.disable_plugin(&plugin_id).await.unwrap();
+    }
+
+    /// Loads the audit plugin compiled by the guest SDK. Run in CI after
+    /// `cargo build --target wasm32-wasip1 --release -p arcana-audit-plugin`;
+    /// `ARCANA_AUDIT_PLUGIN_WASM` overrides where the module is read from.
+    #[tokio::test]
+    #[ignore = "needs the audit plugin built for wasm32-wasip1"]
+    async fn test_audit_plugin_built_for_wasm_loads() {
+        let path = std::env::var("ARCANA_AUDIT_PLUGIN_WASM").unwrap_or_else(|_| {
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../target/wasm32-wasip1/release/arcana_audit_plugin.wasm")
+                .to_string()
+        });
+        let module = std::fs::read(&path).unwrap_or_else(|e| panic!("Cannot read {}: {}", path, e));
+
+        let manager = manager();
+        let plugin_id = manager.install_plugin(&module).await.unwrap();
+        assert_eq!(plugin_id, PluginId::new("arcana-audit-plugin"));
+        manager.enable_plugin(&plugin_id).await.unwrap();
+
+        let listener = manager.plugins.read().await[&plugin_id].event_listener.clone().unwrap();
+        assert_eq!(listener.subscription.event_types, vec!["*"]);
+        listener.listener.handle_event(event("user.created")).unwrap();
+
+        manager.uninstall_plugin(&plugin_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_calls_are_bounded_by_the_sandbox() {
+        let manager = PluginManager::new(PluginConfig {
+            execution_timeout_secs: 1,
+            max_memory_bytes: 1024 * 1024,
+            ..PluginConfig::default()
+        }).unwrap();
+
+        let spinning = r#"(func (export "arcana_on_enable") (result i64) (loop $spin (br $spin)) (i64.const 0))"#;
+        let plugin_id = manager.install_plugin(plugin_module("spinning", spinning).as_bytes()).await.unwrap();
+        match manager.enable_plugin(&plugin_id).await {
+            Err(ArcanaError::PluginExecution { message, .. }) => assert!(message.contains("execution timeout")),
+            other => panic!("expected the plugin to be interrupted, got {:?}", other),
+        }
+
+        let growing = r#"(func (export "arcana_on_enable") (result i64)
+            (if (i32.eq (memory.grow (i32.const 64)) (i32.const -1)) (then unreachable))
+            (i64.const 0))"#;
+        let plugin_id = manager.install_plugin(plugin_module("growing", growing).as_bytes()).await.unwrap();
+        assert!(manager.enable_plugin(&plugin_id).await.is_err());
+
+        let oversized = plugin_module("oversized", "")
+            .replace(r#"(memory (export "memory") 1)"#, r#"(memory (export "memory") 32)"#);
+        let result = manager.install_plugin(oversized.as_bytes()).await;
+        assert!(matches!(result, Err(ArcanaError::PluginLoading(_))));
+    }
+
+    #[tokio::test]
+    async fn test_capabilities_require_approval() {
+        // Logs "not today" when enabled.
+        let hooks = r#"(func (export "arcana_on_enable") (result i64)
+            (call $log (i32.const 2) (i32.const 4096) (i32.const 9))
+            (i64.const 0))"#;
+        let module = plugin_module("logger", hooks)
+            .replacen("(module", r#"(module (import "arcana" "log" (func $log (param i32 i32 i32)))"#, 1);
+        let manifest = PluginManifest {
+            key: "logger".to_string(),
+            capabilities: Capabilities { host_functions: vec!["log".to_string()], ..Capabilities::default() },
+        };
+
+        let undeclared = manager().install_plugin(module.as_bytes()).await;
+        assert!(matches!(undeclared, Err(ArcanaError::PluginLoading(_))));
+
+        match manager().install_plugin_with_manifest(module.as_bytes(), &manifest).await {
+            Err(ArcanaError::PluginLoading(message)) => assert!(message.ends_with("host function log")),
+            other => panic!("expected the capability to need approval, got {:?}", other),
+        }
+
+        let mut config = PluginConfig::default();
+        let grant = PluginGrant { host_functions: vec!["log".to_string()], ..PluginGrant::default() };
+        config.grants.insert("logger".to_string(), grant);
+        let manager = PluginManager::new(config).unwrap();
+
+        let impostor = PluginManifest { key: "impostor".to_string(), capabilities: Capabilities::default() };
+        let result = manager.install_plugin_with_manifest(module.as_bytes(), &impostor).await;
+        assert!(matches!(result, Err(ArcanaError::PluginLoading(_))));
+
+        let plugin_id = manager.install_plugin_with_manifest(module.as_bytes(), &manifest).await.unwrap();
+        manager.enable_plugin(&plugin_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_initialize_loads_plugins_from_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        let module = wat::parse_str(plugin_module("from-disk", "")).unwrap();
+        std::fs::write(directory.path().join("from-disk.wasm"), module).unwrap();
+        std::fs::write(directory.path().join("broken.wasm"), b"not a module").unwrap();
+        std::fs::write(directory.path().join("README.md"), b"# Plugins").unwrap();
+
+        let manager = PluginManager::new(PluginConfig {
+            directory: directory.path().to_string_lossy().into_owned(),
+            verify_signatures: false,
+            ..PluginConfig::default()
+        }).unwrap();
+        manager.initialize().await.unwrap();
+
+        let plugins = manager.list_plugins().await;
+        assert_eq!(plugins.len(), 1);
+        assert_eq!(plugins[0].0, PluginId::new("from-disk"));
+        assert_eq!(plugins[0].2, PluginState::Active);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_only_loads_plugins_signed_by_trusted_keys() {
+        let directory = tempfile::tempdir().unwrap();
+        let publisher = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+        let stranger = ed25519_dalek::SigningKey::from_bytes(&[8; 32]);
+        let write_plugin = |key: &str, signer: Option<&ed25519_dalek::SigningKey>, manifest: Option<&str>| {
+            let module = wat::parse_str(plugin_module(key, "")).unwrap();
+            let path = directory.path().join(format!("{}.wasm", key));
+            if let Some(manifest) = manifest {
+                std::fs::write(path.with_extension("manifest.json"), manifest).unwrap();
+            }
+            if let Some(signer) = signer {
+                let signature = crate::signature::sign(signer, &module, manifest.map(str::as_bytes));
+                std::fs::write(path.with_extension("sig"), signature).unwrap();
+            }
+            std::fs::write(path, module).unwrap();
+        };
+        write_plugin("signed", Some(&publisher), Some(r#"{"key":"signed"}"#));
+        write_plugin("unsigned", None, None);
+        write_plugin("forged", Some(&stranger), None);
+        write_plugin("swapped", Some(&publisher), Some(r#"{"key":"swapped"}"#));
+        std::fs::write(directory.path().join("swapped.manifest.json"), r#"{"key":"swapped","capabilities":{}}"#)
+            .unwrap();
+
+        let manager = PluginManager::new(PluginConfig {
+            directory: directory.path().to_string_lossy().into_owned(),
+            verify_signatures: true,
+            trusted_keys: vec![hex::encode(publisher.verifying_key().to_bytes())],
+            ..PluginConfig::default()
+        }).unwrap();
+        manager.initialize().await.unwrap();
+
+        let plugins = manager.list_plugins().await;
+        assert_eq!(plugins.len(), 1);
+        assert_eq!(plugins[0].0, PluginId::new("signed"));
+    }
+
+    #[tokio::test]
+    async fn test_scan_reloads_changed_plugin_files() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("hot.wasm");
+        let write = |module: String| std::fs::write(&path, wat::parse_str(module).unwrap()).unwrap();
+        let manager = PluginManager::new(PluginConfig {
+            directory: directory.path().to_string_lossy().into_owned(),
+            verify_signatures: false,
+            ..PluginConfig::default()
+        }).unwrap();
+        let plugin_id = PluginId::new("hot");
+        let running = |manager: &PluginManager| {
+            let plugins = manager.plugins.try_read().unwrap();
+            let plugin = &plugins[&plugin_id];
+            (plugin.descriptor.version.clone(), plugin.state, plugin.instance.clone())
+        };
+
+        write(versioned_module("hot", "1.0.0", ""));
+        manager.initialize().await.unwrap();
+        let (version, state, old_instance) = running(&manager);
+        assert_eq!((version.as_str(), state), ("1.0.0", PluginState::Active));
+
+        write(versioned_module("hot", "2.0.0-rc.1", ""));
+        manager.scan_plugins().await.unwrap();
+        let (version, state, instance) = running(&manager);
+        assert_eq!((version.as_str(), state), ("2.0.0-rc.1", PluginState::Active));
+        assert!(!Arc::ptr_eq(&old_instance, &instance), "the instance was swapped");
+
+        manager.scan_plugins().await.unwrap();
+        assert!(Arc::ptr_eq(&running(&manager).2, &instance), "unchanged files are not reloaded");
+
+        // A module that fails to load or to enable leaves the running version in place.
+        std::fs::write(&path, b"not a module").unwrap();
+        manager.scan_plugins().await.unwrap();
+        write(versioned_module("hot", "3.0.0", &rejecting_hook(LifecycleHook::Enable)));
+        manager.scan_plugins().await.unwrap();
+        let (version, state, current) = running(&manager);
+        assert_eq!((version.as_str(), state), ("2.0.0-rc.1", PluginState::Active));
+        assert!(Arc::ptr_eq(&current, &instance));
+
+        std::fs::remove_file(&path).unwrap();
+        manager.scan_plugins().await.unwrap();
+        assert!(manager.get_plugin(&plugin_id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scan_notices_changes_to_only_the_manifest() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("logger.wasm");
+        // Imports the log host function, so it only loads with a manifest requesting it
+        let module = plugin_module("logger", "")
+            .replacen("(module", r#"(module (import "arcana" "log" (func $log (param i32 i32 i32)))"#, 1);
+        std::fs::write(&path, wat::parse_str(module).unwrap()).unwrap();
+        std::fs::write(path.with_extension("manifest.json"), r#"{"key":"logger"}"#).unwrap();
+
+        let mut config = PluginConfig {
+            directory: directory.path().to_string_lossy().into_owned(),
+            verify_signatures: false,
+            ..PluginConfig::default()
+        };
+        let grant = PluginGrant { host_functions: vec!["log".to_string()], ..PluginGrant::default() };
+        config.grants.insert("logger".to_string(), grant);
+        let manager = PluginManager::new(config).unwrap();
+        manager.initialize().await.unwrap();
+        assert!(manager.list_plugins().await.is_empty());
+
+        let manifest = r#"{"key":"logger","capabilities":{"host_functions":["log"]}}"#;
+        std::fs::write(path.with_extension("manifest.json"), manifest).unwrap();
+        manager.scan_plugins().await.unwrap();
+        assert_eq!(manager.get_plugin(&PluginId::new("logger")).await.unwrap().1, PluginState::Active);
+    }
+
+    #[tokio::test]
+    async fn test_scan_notices_changes_to_only_the_signature() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("signed.wasm");
+        let publisher = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+        let stranger = ed25519_dalek::SigningKey::from_bytes(&[8; 32]);
+        let module = wat::parse_str(plugin_module("signed", "")).unwrap();
+        std::fs::write(&path, &module).unwrap();
+        std::fs::write(path.with_extension("sig"), crate::signature::sign(&stranger, &module, None)).unwrap();
+
+        let manager = PluginManager::new(PluginConfig {
+            directory: directory.path().to_string_lossy().into_owned(),
+            verify_signatures: true,
+            trusted_keys: vec![hex::encode(publisher.verifying_key().to_bytes())],
+            ..PluginConfig::default()
+        }).unwrap();
+        manager.initialize().await.unwrap();
+        assert!(manager.list_plugins().await.is_empty());
+
+        // Signatures of both keys have the same length and may get the same modification time
+        std::fs::write(path.with_extension("sig"), crate::signature::sign(&publisher, &module, None)).unwrap();
+        manager.scan_plugins().await.unwrap();
+        assert_eq!(manager.get_plugin(&PluginId::new("signed")).await.unwrap().1, PluginState::Active);
+    }
+
+    #[tokio::test]
+    async fn test_watch_picks_up_new_plugin_files() {
+        let directory = tempfile::tempdir().unwrap();
+        let manager = Arc::new(PluginManager::new(PluginConfig {
+            directory: directory.path().to_string_lossy().into_owned(),
+            hot_reload_interval_secs: 1,
+            verify_signatures: false,
+            ..PluginConfig::default()
+        }).unwrap());
+        manager.initialize().await.unwrap();
+        let watcher = manager.watch().unwrap();
+
+        let module = wat::parse_str(plugin_module("late", "")).unwrap();
+        std::fs::write(directory.path().join("late.wasm"), module).unwrap();
+        for _ in 0..50 {
+            if manager.get_plugin(&PluginId::new("late")).await.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(manager.get_plugin(&PluginId::new("late")).await.unwrap().1, PluginState::Active);
+
+        watcher.abort();
+        let disabled = PluginManager::new(PluginConfig { hot_reload: false, ..PluginConfig::default() }).unwrap();
+        assert!(Arc::new(disabled).watch().is_none());
+    }
+}
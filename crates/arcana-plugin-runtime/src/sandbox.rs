@@ -0,0 +1,289 @@
+//! Resource limits and host capabilities of plugin instances.
+//!
+//! Every call into a plugin is bounded by the configured execution timeout,
+//! enforced with Wasmtime epoch interruption, and its linear memory by
+//! `max_memory_bytes`, enforced with a store limiter. WASI and host
+//! functions are only available as far as the plugin's manifest requests
+//! them and the administrator approved that request.
+
+use arcana_config::{PluginConfig, PluginGrant};
+use arcana_core::{ArcanaError, ArcanaResult};
+use arcana_plugin_api::abi;
+use arcana_plugin_api::manifest::Capabilities;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use wasmtime::{Config, Engine, EngineWeak, Module, StoreLimits, StoreLimitsBuilder};
+use wasmtime_wasi::p1::WasiP1Ctx;
+use wasmtime_wasi::{DirPerms, FilePerms, WasiCtxBuilder};
+
+/// Interval at which the epoch of a sandboxed engine advances.
+pub const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+/// Module WASI preview 1 functions are imported from.
+const WASI_MODULE: &str = "wasi_snapshot_preview1";
+
+/// Engine shared by all plugin managers while any of them is alive.
+static SHARED_ENGINE: Mutex<Option<EngineWeak>> = Mutex::new(None);
+
+/// Returns the engine with epoch interruption plugins run on.
+///
+/// All callers share one engine and one thread advancing its epoch every
+/// [`EPOCH_TICK`]. The thread stops once the engine and all its stores are
+/// dropped; the next call starts a new one.
+pub fn sandboxed_engine() -> ArcanaResult<Engine> {
+    let mut shared = SHARED_ENGINE.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(engine) = shared.as_ref().and_then(EngineWeak::upgrade) {
+        return Ok(engine);
+    }
+
+    let mut config = Config::new();
+    config.epoch_interruption(true);
+    let engine = Engine::new(&config)
+        .map_err(|e| ArcanaError::PluginLoading(format!("Failed to create the plugin engine: {}", e)))?;
+
+    let weak = engine.weak();
+    std::thread::Builder::new()
+        .name("plugin-epoch".to_string())
+        .spawn(move || {
+            while let Some(engine) = weak.upgrade() {
+                engine.increment_epoch();
+                drop(engine);
+                std::thread::sleep(EPOCH_TICK);
+            }
+        })
+        .map_err(|e| ArcanaError::PluginLoading(format!("Failed to start the plugin epoch thread: {}", e)))?;
+
+    *shared = Some(engine.weak());
+    Ok(engine)
+}
+
+/// Limits and capabilities a plugin instance runs with.
+#[derive(Debug, Clone)]
+pub struct Sandbox {
+    /// Longest a single call into the plugin may run.
+    pub execution_timeout: Duration,
+    /// Most linear memory the plugin may use.
+    pub max_memory_bytes: u64,
+    /// Approved capabilities the plugin requested.
+    pub capabilities: Capabilities,
+}
+
+impl Sandbox {
+    /// Creates a sandbox with the configured limits.
+    pub fn new(config: &PluginConfig, capabilities: Capabilities) -> Self {
+        Self {
+            execution_timeout: config.execution_timeout(),
+            max_memory_bytes: config.max_memory_bytes,
+            capabilities,
+        }
+    }
+
+    /// Returns the epoch deadline of a call, in ticks.
+    pub(crate) fn deadline_ticks(&self) -> u64 {
+        let ticks = self.execution_timeout.as_millis() / EPOCH_TICK.as_millis();
+        u64::try_from(ticks).unwrap_or(u64::MAX).max(1)
+    }
+
+    /// Builds the limiter capping the memory of the instance.
+    pub(crate) fn store_limits(&self) -> StoreLimits {
+        StoreLimitsBuilder::new()
+            .memory_size(usize::try_from(self.max_memory_bytes).unwrap_or(usize::MAX))
+            .instances(1)
+            .build()
+    }
+
+    /// Builds the WASI context granting the requested capabilities.
+    pub(crate) fn wasi_ctx(&self) -> ArcanaResult<WasiP1Ctx> {
+        let mut builder = WasiCtxBuilder::new();
+        if self.capabilities.network {
+            builder.inherit_network().allow_ip_name_lookup(true);
+        }
+        for preopen in &self.capabilities.preopens {
+            let (dir_perms, file_perms) = if preopen.writable {
+                (DirPerms::all(), FilePerms::all())
+            } else {
+                (DirPerms::READ, FilePerms::READ)
+            };
+            builder
+                .preopened_dir(&preopen.host_path, &preopen.guest_path, dir_perms, file_perms)
+                .map_err(|e| {
+                    ArcanaError::PluginLoading(format!("Cannot mount {}: {}", preopen.host_path, e))
+                })?;
+        }
+        for name in &self.capabilities.env {
+            if let Ok(value) = std::env::var(name) {
+                builder.env(name, value);
+            }
+        }
+        Ok(builder.build_p1())
+    }
+
+    /// Checks that the module only imports WASI and the host functions the
+    /// plugin requested.
+    pub(crate) fn check_imports(&self, module: &Module) -> ArcanaResult<()> {
+        for import in module.imports() {
+            let allowed = match import.module() {
+                WASI_MODULE => true,
+                m if m == abi::HOST_MODULE => self.allows_host_function(import.name()),
+                _ => false,
+            };
+            if !allowed {
+                return Err(ArcanaError::PluginLoading(format!(
+                    "Plugin imports `{}::{}`, which its manifest does not request",
+                    import.module(),
+                    import.name()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns true if the plugin requested the host function.
+    pub(crate) fn allows_host_function(&self, name: &str) -> bool {
+        self.capabilities.host_functions.iter().any(|f| f == name)
+    }
+}
+
+/// Lists the requested capabilities the grant does not cover.
+pub fn unapproved(requested: &Capabilities, grant: &PluginGrant) -> Vec<String> {
+    let mut missing = Vec::new();
+    if requested.network && !grant.network {
+        missing.push("network".to_string());
+    }
+    for preopen in &requested.preopens {
+        let granted = grant.write_dirs.contains(&preopen.host_path)
+            || (!preopen.writable && grant.read_dirs.contains(&preopen.host_path));
+        if !granted {
+            let access = if preopen.writable { "read-write" } else { "read-only" };
+            missing.push(format!("{} directory {}", access, preopen.host_path));
+        }
+    }
+    missing.extend(
+        requested
+            .env
+            .iter()
+            .filter(|name| !grant.env.contains(name))
+            .map(|name| format!("env {}", name)),
+    );
+    missing.extend(
+        requested
+            .host_functions
+            .iter()
+            .filter(|name| !grant.host_functions.contains(name))
+            .map(|name| format!("host function {}", name)),
+    );
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm::WasmPlugin;
+    use arcana_plugin_api::abi::LifecycleHook;
+    use arcana_plugin_api::manifest::Preopen;
+    use std::time::Instant;
+
+    fn sandbox(execution_timeout: Duration, max_memory_bytes: u64) -> Sandbox {
+        Sandbox { execution_timeout, max_memory_bytes, capabilities: Capabilities::default() }
+    }
+
+    /// Loads a plugin with one page of memory and `hooks` spliced in as exports.
+    fn load(sandbox: &Sandbox, hooks: &str) -> WasmPlugin {
+        let descriptor = serde_json::json!({
+            "key": "sandboxed", "name": "sandboxed", "version": "1.0.0",
+            "description": "", "author": "Arcana", "min_platform_version": "0.1.0",
+        })
+        .to_string();
+        let module = format!(
+            r#"(module
+                (memory (export "memory") 1)
+                (data (i32.const 0) "{}")
+                (func (export "arcana_free") (param i32 i32))
+                (func (export "arcana_descriptor") (result i64) (i64.const {}))
+                {})"#,
+            descriptor.replace('"', "\\\""),
+            descriptor.len(),
+            hooks
+        );
+        WasmPlugin::load(&sandboxed_engine().unwrap(), module.as_bytes(), sandbox).unwrap()
+    }
+
+    fn preopen(host_path: &str, writable: bool) -> Preopen {
+        Preopen { host_path: host_path.to_string(), guest_path: "/data".to_string(), writable }
+    }
+
+    #[test]
+    fn test_unapproved_capabilities() {
+        let requested = Capabilities {
+            network: true,
+            preopens: vec![preopen("/srv/in", false), preopen("/srv/out", true), preopen("/srv/ro", true)],
+            env: vec!["TZ".to_string(), "HOME".to_string()],
+            host_functions: vec!["log".to_string()],
+        };
+        let grant = PluginGrant {
+            network: false,
+            read_dirs: vec!["/srv/ro".to_string()],
+            write_dirs: vec!["/srv/in".to_string(), "/srv/out".to_string()],
+            env: vec!["TZ".to_string()],
+            host_functions: vec!["log".to_string()],
+        };
+
+        assert_eq!(
+            unapproved(&requested, &grant),
+            vec!["network", "read-write directory /srv/ro", "env HOME"]
+        );
+        assert!(unapproved(&Capabilities::default(), &PluginGrant::default()).is_empty());
+    }
+
+    #[test]
+    fn test_engines_share_one_ticker() {
+        let engine = sandboxed_engine().unwrap();
+        assert!(Engine::same(&engine, &sandboxed_engine().unwrap()));
+    }
+
+    #[test]
+    fn test_infinite_loops_are_interrupted() {
+        let spinning = r#"(func (export "arcana_on_enable") (result i64) (loop $spin (br $spin)) (i64.const 0))"#;
+        let mut plugin = load(&sandbox(Duration::from_millis(100), 1 << 20), spinning);
+
+        let started = Instant::now();
+        match plugin.call_hook(LifecycleHook::Enable) {
+            Err(ArcanaError::PluginExecution { message, .. }) => assert!(message.contains("execution timeout")),
+            other => panic!("expected the loop to be interrupted, got {:?}", other),
+        }
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_memory_cannot_grow_past_the_limit() {
+        // Each hook grows memory by one page and traps if that fails
+        let grow = |hook: LifecycleHook| {
+            format!(
+                r#"(func (export "{}") (result i64)
+                    (if (i32.eq (memory.grow (i32.const 1)) (i32.const -1)) (then unreachable))
+                    (i64.const 0))"#,
+                hook.export_name()
+            )
+        };
+        let hooks = grow(LifecycleHook::Install) + &grow(LifecycleHook::Enable);
+        let mut plugin = load(&sandbox(Duration::from_secs(1), 2 * 65536), &hooks);
+
+        assert!(plugin.call_hook(LifecycleHook::Install).is_ok());
+        match plugin.call_hook(LifecycleHook::Enable) {
+            Err(ArcanaError::PluginExecution { message, .. }) => assert!(message.contains("trapped")),
+            other => panic!("expected growing past the limit to trap, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_deadline_covers_the_timeout() {
+        let sandbox = |timeout| Sandbox {
+            execution_timeout: timeout,
+            max_memory_bytes: 0,
+            capabilities: Capabilities::default(),
+        };
+
+        assert_eq!(sandbox(Duration::from_secs(1)).deadline_ticks(), 100);
+        assert_eq!(sandbox(Duration::ZERO).deadline_ticks(), 1);
+    }
+}
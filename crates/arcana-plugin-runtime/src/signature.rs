@@ -0,0 +1,161 @@
+//! Detached Ed25519 signatures of plugin modules.
+//!
+//! A plugin `<name>.wasm` is signed by `<name>.sig`, holding the hex-encoded
+//! signature of [`signed_message`]: the SHA-256 digests of the module and of
+//! its `<name>.manifest.json`, so neither can be swapped without the other.
+//! Keys are hex-encoded too; `arcana-plugin-sign` creates keys and signs
+//! plugins.
+
+use arcana_config::PluginConfig;
+use arcana_core::{ArcanaError, ArcanaResult};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// Domain tag keeping plugin signatures apart from other uses of a key.
+const DOMAIN: &[u8] = b"arcana-plugin-signature-v1\0";
+
+/// Builds the message signed for a plugin module and its manifest, if any.
+pub fn signed_message(wasm: &[u8], manifest: Option<&[u8]>) -> Vec<u8> {
+    let mut message = DOMAIN.to_vec();
+    message.extend_from_slice(&Sha256::digest(wasm));
+    message.extend_from_slice(&Sha256::digest(manifest.unwrap_or_default()));
+    message
+}
+
+/// Signs a plugin module and its manifest, returning the hex-encoded signature.
+pub fn sign(key: &SigningKey, wasm: &[u8], manifest: Option<&[u8]>) -> String {
+    hex::encode(key.sign(&signed_message(wasm, manifest)).to_bytes())
+}
+
+/// Parses a hex-encoded Ed25519 secret key.
+pub fn parse_signing_key(encoded: &str) -> ArcanaResult<SigningKey> {
+    let bytes = decode_hex::<32>(encoded, "signing key")?;
+    Ok(SigningKey::from_bytes(&bytes))
+}
+
+/// Parses a hex-encoded Ed25519 public key.
+pub fn parse_verifying_key(encoded: &str) -> ArcanaResult<VerifyingKey> {
+    let bytes = decode_hex::<32>(encoded, "public key")?;
+    VerifyingKey::from_bytes(&bytes).map_err(|e| ArcanaError::Configuration(format!("Invalid public key: {}", e)))
+}
+
+/// Public keys of the publishers whose plugins are trusted.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedKeys {
+    keys: Vec<VerifyingKey>,
+}
+
+impl TrustedKeys {
+    /// Creates a set of trusted keys.
+    pub fn new(keys: Vec<VerifyingKey>) -> Self {
+        Self { keys }
+    }
+
+    /// Loads the configured `trusted_keys` and the `.pub` files in
+    /// `trusted_keys_dir`.
+    pub fn load(config: &PluginConfig) -> ArcanaResult<Self> {
+        let mut keys = config
+            .trusted_keys
+            .iter()
+            .map(|key| parse_verifying_key(key))
+            .collect::<ArcanaResult<Vec<_>>>()?;
+
+        if let Some(dir) = &config.trusted_keys_dir {
+            let entries = std::fs::read_dir(dir).map_err(|e| {
+                ArcanaError::Configuration(format!("Failed to read trusted keys directory {}: {}", dir, e))
+            })?;
+            for path in entries.flatten().map(|entry| entry.path()) {
+                if path.extension().is_some_and(|ext| ext == "pub") {
+                    keys.push(read_key_file(&path)?);
+                }
+            }
+        }
+
+        Ok(Self { keys })
+    }
+
+    /// Returns true if no key is trusted.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Checks that a trusted key signed the module and its manifest.
+    pub fn verify(&self, wasm: &[u8], manifest: Option<&[u8]>, signature: &str) -> ArcanaResult<()> {
+        let signature = decode_hex::<64>(signature, "signature")
+            .map(|bytes| Signature::from_bytes(&bytes))
+            .map_err(|_| ArcanaError::PluginLoading("Malformed plugin signature".to_string()))?;
+        let message = signed_message(wasm, manifest);
+
+        if self.keys.iter().any(|key| key.verify_strict(&message, &signature).is_ok()) {
+            Ok(())
+        } else {
+            Err(ArcanaError::PluginLoading(
+                "Plugin signature does not match any trusted key".to_string(),
+            ))
+        }
+    }
+}
+
+fn read_key_file(path: &Path) -> ArcanaResult<VerifyingKey> {
+    let encoded = std::fs::read_to_string(path)
+        .map_err(|e| ArcanaError::Configuration(format!("Failed to read {:?}: {}", path, e)))?;
+    parse_verifying_key(&encoded)
+        .map_err(|e| ArcanaError::Configuration(format!("Invalid trusted key {:?}: {}", path, e)))
+}
+
+fn decode_hex<const N: usize>(encoded: &str, what: &str) -> ArcanaResult<[u8; N]> {
+    let mut bytes = [0; N];
+    hex::decode_to_slice(encoded.trim(), &mut bytes)
+        .map_err(|e| ArcanaError::Configuration(format!("Invalid {}: {}", what, e)))?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    #[test]
+    fn test_signatures_cover_module_and_manifest() {
+        let publisher = key(1);
+        let trusted = TrustedKeys::new(vec![publisher.verifying_key()]);
+        let signature = sign(&publisher, b"module", Some(b"manifest"));
+
+        trusted.verify(b"module", Some(b"manifest"), &signature).unwrap();
+        assert!(trusted.verify(b"tampered", Some(b"manifest"), &signature).is_err());
+        assert!(trusted.verify(b"module", Some(b"tampered"), &signature).is_err());
+        assert!(trusted.verify(b"module", None, &signature).is_err());
+        assert!(trusted.verify(b"module", Some(b"manifest"), "not hex").is_err());
+
+        let stranger = sign(&key(2), b"module", Some(b"manifest"));
+        assert!(matches!(
+            trusted.verify(b"module", Some(b"manifest"), &stranger),
+            Err(ArcanaError::PluginLoading(_))
+        ));
+    }
+
+    #[test]
+    fn test_trusted_keys_from_config_and_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        let public_key = |seed| hex::encode(key(seed).verifying_key().to_bytes());
+        std::fs::write(directory.path().join("ci.pub"), format!("{}\n", public_key(2))).unwrap();
+        std::fs::write(directory.path().join("README.md"), "Publisher keys").unwrap();
+
+        let trusted = TrustedKeys::load(&PluginConfig {
+            trusted_keys: vec![public_key(1)],
+            trusted_keys_dir: Some(directory.path().to_string_lossy().into_owned()),
+            ..PluginConfig::default()
+        })
+        .unwrap();
+        for seed in [1, 2] {
+            trusted.verify(b"module", None, &sign(&key(seed), b"module", None)).unwrap();
+        }
+
+        let malformed = PluginConfig { trusted_keys: vec!["abcd".to_string()], ..PluginConfig::default() };
+        assert!(matches!(TrustedKeys::load(&malformed), Err(ArcanaError::Configuration(_))));
+    }
+}
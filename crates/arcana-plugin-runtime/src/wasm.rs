@@ -0,0 +1,321 @@
+//! Wasmtime host side of the plugin ABI.
+//!
+//! See [`arcana_plugin_api::abi`] for the functions a plugin module exports
+//! and how values cross the boundary, and [`crate::sandbox`] for the limits
+//! plugins run under.
+
+use crate::sandbox::Sandbox;
+use arcana_core::{ArcanaError, ArcanaResult};
+use arcana_plugin_api::abi::{self, LifecycleHook};
+use arcana_plugin_api::extensions::{EventListenerExtension, EventSubscription, PluginEvent};
+use arcana_plugin_api::PluginDescriptor;
+use serde::de::DeserializeOwned;
+use std::sync::{Arc, Mutex, PoisonError};
+use tracing::{debug, error, info, trace, warn};
+use wasmtime::{Caller, Engine, Extern, Linker, Memory, Module, Store, StoreLimits, Trap, TypedFunc};
+use wasmtime_wasi::p1::{self, WasiP1Ctx};
+
+/// An instantiated plugin module.
+///
+/// Calls need exclusive access to the store, so the plugin manager keeps
+/// each instance behind a mutex.
+pub struct WasmPlugin {
+    descriptor: PluginDescriptor,
+    store: Store<PluginCtx>,
+    deadline_ticks: u64,
+    guest: GuestMemory,
+    hooks: Vec<(LifecycleHook, TypedFunc<(), i64>)>,
+    listener: Option<ListenerExports>,
+}
+
+/// Host state of a plugin instance.
+struct PluginCtx {
+    wasi: WasiP1Ctx,
+    limits: StoreLimits,
+    plugin_key: String,
+}
+
+/// Linear memory of a plugin and the exports managing buffers in it.
+struct GuestMemory {
+    memory: Memory,
+    alloc: Option<TypedFunc<i32, i32>>,
+    free: TypedFunc<(i32, i32), ()>,
+}
+
+/// Exports of a plugin providing an event listener.
+struct ListenerExports {
+    subscriptions: TypedFunc<(), i64>,
+    handle_event: TypedFunc<(i32, i32), i64>,
+}
+
+impl WasmPlugin {
+    /// Compiles and instantiates a plugin module and reads its descriptor.
+    ///
+    /// The module gets the WASI capabilities and host functions of the
+    /// sandbox and nothing else; stdio is never inherited. The engine must
+    /// have epoch interruption enabled (see
+    /// [`sandboxed_engine`](crate::sandbox::sandboxed_engine)).
+    pub fn load(engine: &Engine, wasm_bytes: &[u8], sandbox: &Sandbox) -> ArcanaResult<Self> {
+        let module = Module::new(engine, wasm_bytes)
+            .map_err(|e| ArcanaError::PluginLoading(format!("Invalid plugin module: {}", e)))?;
+        sandbox.check_imports(&module)?;
+
+        let mut linker = Linker::new(engine);
+        p1::add_to_linker_sync(&mut linker, |ctx: &mut PluginCtx| &mut ctx.wasi).map_err(loading_error)?;
+        if sandbox.allows_host_function(abi::LOG) {
+            linker.func_wrap(abi::HOST_MODULE, abi::LOG, host_log).map_err(loading_error)?;
+        }
+
+        let ctx = PluginCtx {
+            wasi: sandbox.wasi_ctx()?,
+            limits: sandbox.store_limits(),
+            plugin_key: String::new(),
+        };
+        let mut store = Store::new(engine, ctx);
+        store.limiter(|ctx| &mut ctx.limits);
+        let deadline_ticks = sandbox.deadline_ticks();
+
+        store.set_epoch_deadline(deadline_ticks);
+        let instance = linker.instantiate(&mut store, &module).map_err(loading_error)?;
+
+        if let Ok(initialize) = instance.get_typed_func::<(), ()>(&mut store, abi::INITIALIZE) {
+            store.set_epoch_deadline(deadline_ticks);
+            initialize.call(&mut store, ()).map_err(loading_error)?;
+        }
+
+        let memory = instance
+            .get_memory(&mut store, abi::MEMORY)
+            .ok_or_else(|| ArcanaError::PluginLoading(format!("Plugin does not export `{}`", abi::MEMORY)))?;
+        let free = instance
+            .get_typed_func::<(i32, i32), ()>(&mut store, abi::FREE)
+            .map_err(loading_error)?;
+        let describe = instance
+            .get_typed_func::<(), i64>(&mut store, abi::DESCRIPTOR)
+            .map_err(loading_error)?;
+        let hooks = [LifecycleHook::Install, LifecycleHook::Enable, LifecycleHook::Disable, LifecycleHook::Uninstall]
+            .into_iter()
+            .filter_map(|hook| {
+                let func = instance.get_typed_func::<(), i64>(&mut store, hook.export_name()).ok()?;
+                Some((hook, func))
+            })
+            .collect();
+        let alloc = instance.get_typed_func::<i32, i32>(&mut store, abi::ALLOC).ok();
+        let listener = match (
+            instance.get_typed_func::<(), i64>(&mut store, abi::SUBSCRIPTIONS),
+            instance.get_typed_func::<(i32, i32), i64>(&mut store, abi::HANDLE_EVENT),
+        ) {
+            (Ok(subscriptions), Ok(handle_event)) => Some(ListenerExports { subscriptions, handle_event }),
+            _ => None,
+        };
+        if listener.is_some() && alloc.is_none() {
+            return Err(ArcanaError::PluginLoading(format!(
+                "Plugin exports an event listener but not `{}`",
+                abi::ALLOC
+            )));
+        }
+
+        let guest = GuestMemory { memory, alloc, free };
+        store.set_epoch_deadline(deadline_ticks);
+        let packed = describe.call(&mut store, ()).map_err(loading_error)?;
+        let descriptor: PluginDescriptor = guest
+            .take_json(&mut store, packed)
+            .map_err(|e| ArcanaError::PluginLoading(format!("Invalid plugin descriptor: {}", e)))?;
+        store.data_mut().plugin_key.clone_from(&descriptor.key);
+
+        Ok(Self { descriptor, store, deadline_ticks, guest, hooks, listener })
+    }
+
+    /// Returns the descriptor the plugin exported.
+    pub fn descriptor(&self) -> &PluginDescriptor {
+        &self.descriptor
+    }
+
+    /// Notifies the plugin of a lifecycle transition.
+    ///
+    /// Fails if the plugin traps or rejects the transition.
+    pub fn call_hook(&mut self, hook: LifecycleHook) -> ArcanaResult<()> {
+        let Some(func) = self.hooks.iter().find(|(h, _)| *h == hook).map(|(_, f)| f.clone()) else {
+            return Ok(());
+        };
+
+        self.store.set_epoch_deadline(self.deadline_ticks);
+        let packed = func
+            .call(&mut self.store, ())
+            .map_err(|e| self.execution_error(trap_message(hook.export_name(), &e)))?;
+        self.status(packed)
+    }
+
+    /// Reads the subscription of the plugin's event listener.
+    ///
+    /// Returns `None` if the plugin does not provide an event listener.
+    pub fn event_subscription(&mut self) -> ArcanaResult<Option<EventSubscription>> {
+        let Some(subscriptions) = self.listener.as_ref().map(|l| l.subscriptions.clone()) else {
+            return Ok(None);
+        };
+
+        self.store.set_epoch_deadline(self.deadline_ticks);
+        let packed = subscriptions
+            .call(&mut self.store, ())
+            .map_err(|e| self.execution_error(trap_message(abi::SUBSCRIPTIONS, &e)))?;
+        let subscription = self
+            .guest
+            .take_json(&mut self.store, packed)
+            .map_err(|e| self.execution_error(format!("Invalid event subscription: {}", e)))?;
+        Ok(Some(subscription))
+    }
+
+    /// Passes an event to the plugin's event listener.
+    ///
+    /// Fails if the plugin provides no listener, traps, or fails to handle
+    /// the event.
+    pub fn handle_event(&mut self, event: &PluginEvent) -> ArcanaResult<()> {
+        let Some(handle_event) = self.listener.as_ref().map(|l| l.handle_event.clone()) else {
+            return Err(self.execution_error("Plugin does not provide an event listener"));
+        };
+
+        let input = serde_json::to_vec(event).map_err(|e| self.execution_error(e.to_string()))?;
+        self.store.set_epoch_deadline(self.deadline_ticks);
+        let (ptr, len) = self.guest.put(&mut self.store, &input).map_err(|e| self.execution_error(e))?;
+        let handled = handle_event
+            .call(&mut self.store, (ptr, len))
+            .map_err(|e| self.execution_error(trap_message(abi::HANDLE_EVENT, &e)))
+            .and_then(|packed| self.status(packed));
+
+        // The input buffer is freed even if the listener trapped or ran out of time
+        self.store.set_epoch_deadline(self.deadline_ticks);
+        let freed = self
+            .guest
+            .free
+            .call(&mut self.store, (ptr, len))
+            .map_err(|e| self.execution_error(trap_message(abi::FREE, &e)));
+
+        handled.and(freed)
+    }
+
+    /// Interprets the status returned by a call: `0`, or an error message.
+    fn status(&mut self, packed: i64) -> ArcanaResult<()> {
+        if packed == 0 {
+            return Ok(());
+        }
+
+        let message = self.guest.take(&mut self.store, packed).map_err(|e| self.execution_error(e))?;
+        Err(self.execution_error(String::from_utf8_lossy(&message).into_owned()))
+    }
+
+    fn execution_error(&self, message: impl Into<String>) -> ArcanaError {
+        ArcanaError::PluginExecution {
+            plugin_key: self.descriptor.key.clone(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Debug for WasmPlugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasmPlugin")
+            .field("descriptor", &self.descriptor)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Event listener extension backed by the exports of a plugin module.
+pub struct WasmEventListener {
+    instance: Arc<Mutex<WasmPlugin>>,
+    subscription: EventSubscription,
+}
+
+impl WasmEventListener {
+    /// Creates a listener calling into `instance`, with the subscription
+    /// read from it by [`WasmPlugin::event_subscription`].
+    pub fn new(instance: Arc<Mutex<WasmPlugin>>, subscription: EventSubscription) -> Self {
+        Self { instance, subscription }
+    }
+}
+
+impl EventListenerExtension for WasmEventListener {
+    fn subscriptions(&self) -> EventSubscription {
+        self.subscription.clone()
+    }
+
+    fn handle_event(&self, event: PluginEvent) -> Result<(), String> {
+        let mut instance = self.instance.lock().unwrap_or_else(PoisonError::into_inner);
+        match instance.handle_event(&event) {
+            Ok(()) => Ok(()),
+            Err(ArcanaError::PluginExecution { message, .. }) => Err(message),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+impl GuestMemory {
+    /// Copies an argument into a buffer allocated by the plugin.
+    fn put(&self, store: &mut Store<PluginCtx>, bytes: &[u8]) -> Result<(i32, i32), String> {
+        let alloc = self.alloc.as_ref().ok_or_else(|| format!("Plugin does not export `{}`", abi::ALLOC))?;
+        let len = i32::try_from(bytes.len()).map_err(|_| format!("Argument too large ({} bytes)", bytes.len()))?;
+        let ptr = alloc
+            .call(&mut *store, len)
+            .map_err(|e| trap_message(abi::ALLOC, &e))?;
+        self.memory
+            .write(&mut *store, ptr as u32 as usize, bytes)
+            .map_err(|_| format!("Plugin allocated an out-of-bounds buffer ({} bytes at {})", len, ptr))?;
+        Ok((ptr, len))
+    }
+
+    /// Copies a buffer returned by the plugin out of guest memory and frees it.
+    fn take(&self, store: &mut Store<PluginCtx>, packed: i64) -> Result<Vec<u8>, String> {
+        let (ptr, len) = abi::unpack(packed);
+        let out_of_bounds = || format!("Plugin returned an out-of-bounds buffer ({} bytes at {})", len, ptr);
+        if u64::from(ptr) + u64::from(len) > self.memory.data_size(&*store) as u64 {
+            return Err(out_of_bounds());
+        }
+        let mut bytes = vec![0; len as usize];
+        self.memory.read(&*store, ptr as usize, &mut bytes).map_err(|_| out_of_bounds())?;
+        self.free
+            .call(store, (ptr as i32, len as i32))
+            .map_err(|e| trap_message(abi::FREE, &e))?;
+        Ok(bytes)
+    }
+
+    /// Takes a JSON buffer returned by the plugin and deserializes it.
+    fn take_json<T: DeserializeOwned>(&self, store: &mut Store<PluginCtx>, packed: i64) -> Result<T, String> {
+        let bytes = self.take(store, packed)?;
+        serde_json::from_slice(&bytes).map_err(|e| e.to_string())
+    }
+}
+
+/// Implements the [`abi::LOG`] host function.
+fn host_log(mut caller: Caller<'_, PluginCtx>, level: i32, ptr: i32, len: i32) {
+    let Some(Extern::Memory(memory)) = caller.get_export(abi::MEMORY) else {
+        return;
+    };
+    let (start, len) = (ptr as u32 as usize, len as u32 as usize);
+    let Some(bytes) = memory.data(&caller).get(start..).and_then(|data| data.get(..len)) else {
+        return;
+    };
+
+    let message = String::from_utf8_lossy(bytes);
+    let plugin = caller.data().plugin_key.as_str();
+    match level {
+        0 => error!(plugin, "{}", message),
+        1 => warn!(plugin, "{}", message),
+        2 => info!(plugin, "{}", message),
+        3 => debug!(plugin, "{}", message),
+        _ => trace!(plugin, "{}", message),
+    }
+}
+
+/// Describes a failed call, telling timeouts apart from other traps.
+fn trap_message(export: &str, error: &wasmtime::Error) -> String {
+    if error.downcast_ref::<Trap>() == Some(&Trap::Interrupt) {
+        format!("{} exceeded the execution timeout", export)
+    } else {
+        format!("{} trapped: {}", export, error)
+    }
+}
+
+fn loading_error(error: wasmtime::Error) -> ArcanaError {
+    if error.downcast_ref::<Trap>() == Some(&Trap::Interrupt) {
+        return ArcanaError::PluginLoading("Plugin exceeded the execution timeout while loading".to_string());
+    }
+    ArcanaError::PluginLoading(error.to_string())
+}
@@ -0,0 +1,51 @@
+//! AuditLogDao trait — low-level audit trail data access abstraction.
+//!
+//! Backs the append-only `audit_logs` table, whose entries form a hash chain
+//! headed by the single row of `audit_log_chain`.
+//!
+//! [`AuditLogRepository`] uses an `AuditLogDao` to fulfil domain-level operations.
+//!
+//! [`AuditLogRepository`]: crate::traits::AuditLogRepository
+
+use arcana_core::{
+    ArcanaResult, AuditLog, AuditLogCursor, AuditLogFilter, AuditLogId, Interface, Page, PageRequest, UserId,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Low-level audit log data access object.
+#[async_trait]
+pub trait AuditLogDao: Interface + Send + Sync {
+    /// Inserts a new audit log entry, appending it to the hash chain.
+    async fn save(&self, entry: &AuditLog) -> ArcanaResult<AuditLog>;
+
+    /// Finds an audit log entry by ID.
+    async fn find_by_id(&self, id: AuditLogId) -> ArcanaResult<Option<AuditLog>>;
+
+    /// Finds the entries of actions performed by a user, newest first.
+    async fn find_by_user(&self, user_id: UserId, page: PageRequest) -> ArcanaResult<Page<AuditLog>>;
+
+    /// Finds up to `limit` entries matching the filter, newest first,
+    /// starting right after the cursor if one is given.
+    async fn search(
+        &self,
+        filter: &AuditLogFilter,
+        after: Option<AuditLogCursor>,
+        limit: usize,
+    ) -> ArcanaResult<Vec<AuditLog>>;
+    /// Returns the first and last sequence number of the chained entries
+    /// in the time range, or `None` if it has none.
+    ///
+    /// Without an end of the range, the last sequence number is that of the
+    /// head of the chain, so that entries removed from the end of the trail
+    /// are still accounted for.
+    async fn chain_bounds(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> ArcanaResult<Option<(u64, u64)>>;
+
+    /// Finds up to `limit` chained entries with a sequence number in the
+    /// inclusive range, in chain order.
+    async fn find_chain(&self, from_sequence: u64, to_sequence: u64, limit: usize) -> ArcanaResult<Vec<AuditLog>>;
+}
@@ -5,4 +5,7 @@
 
 pub mod mysql;
 
-pub use mysql::MySqlUserDaoImpl;
+pub use mysql::{
+    MySqlAuditLogDaoImpl, MySqlMfaDaoImpl, MySqlOAuthTokenDaoImpl, MySqlOutboxDaoImpl, MySqlPasswordResetTokenDaoImpl,
+    MySqlRoleDaoImpl, MySqlTenantDaoImpl, MySqlUserDaoImpl, MySqlWebhookDaoImpl,
+};
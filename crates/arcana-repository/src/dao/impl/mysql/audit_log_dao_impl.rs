@@ -0,0 +1,289 @@
+//! MySQL AuditLogDao implementation.
+//!
+//! Low-level data access for the `audit_logs` table via SQLx. Implements
+//! [`AuditLogDao`] — the DAO layer directly beneath [`AuditLogRepositoryImpl`].
+//!
+//! [`AuditLogDao`]: crate::dao::AuditLogDao
+//! [`AuditLogRepositoryImpl`]: crate::AuditLogRepositoryImpl
+
+use crate::{dao::AuditLogDao, DatabasePoolInterface};
+use arcana_core::{
+    ArcanaError, ArcanaResult, AuditLog, AuditLogCursor, AuditLogFilter, AuditLogId, Page, PageRequest, UserId,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Timelike, Utc};
+use serde_json::Value as JsonValue;
+use shaku::Component;
+use sqlx::types::Json;
+use sqlx::{FromRow, MySql, QueryBuilder};
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+/// MySQL implementation of [`AuditLogDao`].
+#[derive(Component, Clone)]
+#[shaku(interface = AuditLogDao)]
+pub struct MySqlAuditLogDaoImpl {
+    #[shaku(inject)]
+    pool: Arc<dyn DatabasePoolInterface>,
+}
+
+impl MySqlAuditLogDaoImpl {
+    /// Creates a new `MySqlAuditLogDaoImpl`.
+    #[must_use]
+    pub fn new(pool: Arc<dyn DatabasePoolInterface>) -> Self {
+        Self { pool }
+    }
+}
+
+/// Database row representation of an audit log entry.
+#[derive(Debug, FromRow)]
+struct AuditLogRow {
+    id: String,
+    user_id: Option<String>,
+    action: String,
+    resource_type: String,
+    resource_id: Option<String>,
+    details: Option<Json<JsonValue>>,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+    success: bool,
+    error_message: Option<String>,
+    timestamp: DateTime<Utc>,
+    sequence: Option<u64>,
+    prev_hash: Option<String>,
+    hash: Option<String>,
+}
+
+impl TryFrom<AuditLogRow> for AuditLog {
+    type Error = ArcanaError;
+
+    fn try_from(row: AuditLogRow) -> Result<Self, Self::Error> {
+        let parse_uuid = |value: &str| {
+            Uuid::parse_str(value)
+                .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))
+        };
+
+        Ok(AuditLog {
+            id: AuditLogId::from_uuid(parse_uuid(&row.id)?),
+            user_id: row.user_id.as_deref().map(parse_uuid).transpose()?.map(UserId::from_uuid),
+            action: row.action.parse()?,
+            resource_type: row.resource_type,
+            resource_id: row.resource_id,
+            details: row.details.map(|Json(details)| details),
+            ip_address: row.ip_address,
+            user_agent: row.user_agent,
+            success: row.success,
+            error_message: row.error_message,
+            timestamp: row.timestamp,
+            sequence: row.sequence,
+            prev_hash: row.prev_hash,
+            hash: row.hash,
+        })
+    }
+}
+
+#[async_trait]
+impl AuditLogDao for MySqlAuditLogDaoImpl {
+    async fn save(&self, entry: &AuditLog) -> ArcanaResult<AuditLog> {
+        debug!("MySQL DAO: save audit log {} ({})", entry.id, entry.action);
+        let mut entry = entry.clone();
+        // DATETIME keeps whole seconds; hash exactly what is stored
+        entry.timestamp = entry.timestamp.with_nanosecond(0).unwrap_or(entry.timestamp);
+
+        let mut tx = self.pool.inner().begin().await?;
+        let (last_sequence, last_hash): (u64, Option<String>) =
+            sqlx::query_as("SELECT last_sequence, last_hash FROM audit_log_chain WHERE id = 1 FOR UPDATE")
+                .fetch_one(&mut *tx)
+                .await?;
+        entry.chain(last_sequence + 1, last_hash);
+
+        sqlx::query(
+            r#"
+            INSERT INTO audit_logs (id, user_id, action, resource_type, resource_id, details,
+                                    ip_address, user_agent, success, error_message, timestamp,
+                                    sequence, prev_hash, hash)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(entry.id.into_inner().to_string())
+        .bind(entry.user_id.map(|id| id.into_inner().to_string()))
+        .bind(entry.action.to_string())
+        .bind(&entry.resource_type)
+        .bind(&entry.resource_id)
+        .bind(entry.details.as_ref().map(Json))
+        .bind(&entry.ip_address)
+        .bind(&entry.user_agent)
+        .bind(entry.success)
+        .bind(&entry.error_message)
+        .bind(entry.timestamp)
+        .bind(entry.sequence)
+        .bind(&entry.prev_hash)
+        .bind(&entry.hash)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("UPDATE audit_log_chain SET last_sequence = ?, last_hash = ? WHERE id = 1")
+            .bind(entry.sequence)
+            .bind(&entry.hash)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        self.find_by_id(entry.id)
+            .await?
+            .ok_or_else(|| ArcanaError::Internal("Failed to fetch saved audit log".to_string()))
+    }
+
+    async fn find_by_id(&self, id: AuditLogId) -> ArcanaResult<Option<AuditLog>> {
+        debug!("MySQL DAO: find audit log {}", id);
+        let row = sqlx::query_as::<_, AuditLogRow>(
+            r#"
+            SELECT id, user_id, action, resource_type, resource_id, details,
+                   ip_address, user_agent, success, error_message, timestamp,
+                   sequence, prev_hash, hash
+            FROM audit_logs WHERE id = ?
+            "#,
+        )
+        .bind(id.into_inner().to_string())
+        .fetch_optional(self.pool.inner())
+        .await?;
+        row.map(AuditLog::try_from).transpose()
+    }
+
+    async fn find_by_user(&self, user_id: UserId, page: PageRequest) -> ArcanaResult<Page<AuditLog>> {
+        debug!("MySQL DAO: find audit logs of user {} page={} size={}", user_id, page.page, page.size);
+        let user_id = user_id.into_inner().to_string();
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM audit_logs WHERE user_id = ?")
+            .bind(&user_id)
+            .fetch_one(self.pool.inner())
+            .await?;
+
+        let rows = sqlx::query_as::<_, AuditLogRow>(
+            r#"
+            SELECT id, user_id, action, resource_type, resource_id, details,
+                   ip_address, user_agent, success, error_message, timestamp,
+                   sequence, prev_hash, hash
+            FROM audit_logs WHERE user_id = ?
+            ORDER BY timestamp DESC, id DESC
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(&user_id)
+        .bind(page.limit() as i64)
+        .bind(page.offset() as i64)
+        .fetch_all(self.pool.inner())
+        .await?;
+
+        let entries: Vec<AuditLog> = rows.into_iter().map(AuditLog::try_from).collect::<Result<_, _>>()?;
+        Ok(Page::new(entries, page.page, page.size, total as u64))
+    }
+
+    async fn search(
+        &self,
+        filter: &AuditLogFilter,
+        after: Option<AuditLogCursor>,
+        limit: usize,
+    ) -> ArcanaResult<Vec<AuditLog>> {
+        debug!("MySQL DAO: search audit logs {:?} after {:?} limit={}", filter, after, limit);
+        let mut query = QueryBuilder::<MySql>::new(
+            r#"
+            SELECT id, user_id, action, resource_type, resource_id, details,
+                   ip_address, user_agent, success, error_message, timestamp,
+                   sequence, prev_hash, hash
+            FROM audit_logs WHERE 1 = 1
+            "#,
+        );
+
+        if let Some(user_id) = filter.user_id {
+            query.push(" AND user_id = ").push_bind(user_id.into_inner().to_string());
+        }
+        if let Some(action) = filter.action {
+            query.push(" AND action = ").push_bind(action.to_string());
+        }
+        if let Some(resource_type) = &filter.resource_type {
+            query.push(" AND resource_type = ").push_bind(resource_type.clone());
+        }
+        if let Some(success) = filter.success {
+            query.push(" AND success = ").push_bind(success);
+        }
+        if let Some(from) = filter.from {
+            query.push(" AND timestamp >= ").push_bind(from);
+        }
+        if let Some(to) = filter.to {
+            query.push(" AND timestamp < ").push_bind(to);
+        }
+        if let Some(cursor) = after {
+            let id = cursor.id.into_inner().to_string();
+            query
+                .push(" AND (timestamp < ")
+                .push_bind(cursor.timestamp)
+                .push(" OR (timestamp = ")
+                .push_bind(cursor.timestamp)
+                .push(" AND id < ")
+                .push_bind(id)
+                .push("))");
+        }
+        query.push(" ORDER BY timestamp DESC, id DESC LIMIT ").push_bind(limit as i64);
+
+        let rows = query
+            .build_query_as::<AuditLogRow>()
+            .fetch_all(self.pool.inner())
+            .await?;
+        rows.into_iter().map(AuditLog::try_from).collect()
+    }
+    async fn chain_bounds(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> ArcanaResult<Option<(u64, u64)>> {
+        debug!("MySQL DAO: audit chain bounds from {:?} to {:?}", from, to);
+        let mut query = QueryBuilder::<MySql>::new(
+            "SELECT MIN(sequence), MAX(sequence) FROM audit_logs WHERE sequence IS NOT NULL",
+        );
+        if let Some(from) = from {
+            query.push(" AND timestamp >= ").push_bind(from);
+        }
+        if let Some(to) = to {
+            query.push(" AND timestamp < ").push_bind(to);
+        }
+        let (first, last): (Option<u64>, Option<u64>) =
+            query.build_query_as().fetch_one(self.pool.inner()).await?;
+
+        let Some(first) = first else {
+            return Ok(None);
+        };
+        let last = match to {
+            Some(_) => last.unwrap_or(first),
+            None => sqlx::query_scalar("SELECT last_sequence FROM audit_log_chain WHERE id = 1")
+                .fetch_one(self.pool.inner())
+                .await?,
+        };
+        Ok(Some((first, last)))
+    }
+
+    async fn find_chain(&self, from_sequence: u64, to_sequence: u64, limit: usize) -> ArcanaResult<Vec<AuditLog>> {
+        debug!("MySQL DAO: find audit chain {}..={} limit={}", from_sequence, to_sequence, limit);
+        let rows = sqlx::query_as::<_, AuditLogRow>(
+            r#"
+            SELECT id, user_id, action, resource_type, resource_id, details,
+                   ip_address, user_agent, success, error_message, timestamp,
+                   sequence, prev_hash, hash
+            FROM audit_logs WHERE sequence BETWEEN ? AND ?
+            ORDER BY sequence
+            LIMIT ?
+            "#,
+        )
+        .bind(from_sequence)
+        .bind(to_sequence)
+        .bind(limit as i64)
+        .fetch_all(self.pool.inner())
+        .await?;
+        rows.into_iter().map(AuditLog::try_from).collect()
+    }
+}
+
+impl std::fmt::Debug for MySqlAuditLogDaoImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MySqlAuditLogDaoImpl").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,188 @@
+//! MySQL MfaDao implementation.
+//!
+//! Low-level data access for the `totp_credentials` and `mfa_recovery_codes`
+//! tables via SQLx. Implements [`MfaDao`] — the DAO layer directly beneath
+//! [`MfaRepositoryImpl`].
+//!
+//! [`MfaDao`]: crate::dao::MfaDao
+//! [`MfaRepositoryImpl`]: crate::MfaRepositoryImpl
+
+use crate::{dao::MfaDao, DatabasePoolInterface};
+use arcana_core::{ArcanaError, ArcanaResult, TotpCredential, UserId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shaku::Component;
+use sqlx::FromRow;
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+/// MySQL implementation of [`MfaDao`].
+#[derive(Component, Clone)]
+#[shaku(interface = MfaDao)]
+pub struct MySqlMfaDaoImpl {
+    #[shaku(inject)]
+    pool: Arc<dyn DatabasePoolInterface>,
+}
+
+impl MySqlMfaDaoImpl {
+    /// Creates a new `MySqlMfaDaoImpl`.
+    #[must_use]
+    pub fn new(pool: Arc<dyn DatabasePoolInterface>) -> Self {
+        Self { pool }
+    }
+}
+
+/// Database row representation of a TOTP credential.
+#[derive(Debug, FromRow)]
+struct TotpCredentialRow {
+    user_id: String,
+    secret: String,
+    enabled_at: Option<DateTime<Utc>>,
+    last_used_step: Option<i64>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<TotpCredentialRow> for TotpCredential {
+    type Error = ArcanaError;
+
+    fn try_from(row: TotpCredentialRow) -> Result<Self, Self::Error> {
+        let user_id = Uuid::parse_str(&row.user_id)
+            .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))?;
+
+        Ok(TotpCredential {
+            user_id: UserId::from_uuid(user_id),
+            secret: row.secret,
+            enabled_at: row.enabled_at,
+            last_used_step: row.last_used_step,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[async_trait]
+impl MfaDao for MySqlMfaDaoImpl {
+    async fn find_totp_by_user(&self, user_id: UserId) -> ArcanaResult<Option<TotpCredential>> {
+        debug!("MySQL DAO: find TOTP credential for user {}", user_id);
+        let row = sqlx::query_as::<_, TotpCredentialRow>(
+            r#"
+            SELECT user_id, secret, enabled_at, last_used_step, created_at, updated_at
+            FROM totp_credentials WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id.into_inner().to_string())
+        .fetch_optional(self.pool.inner())
+        .await?;
+        row.map(TotpCredential::try_from).transpose()
+    }
+
+    async fn save_totp(&self, credential: &TotpCredential) -> ArcanaResult<TotpCredential> {
+        debug!("MySQL DAO: save TOTP credential for user {}", credential.user_id);
+        sqlx::query(
+            r#"
+            INSERT INTO totp_credentials (user_id, secret, enabled_at, last_used_step, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                secret = VALUES(secret), enabled_at = VALUES(enabled_at),
+                last_used_step = VALUES(last_used_step), created_at = VALUES(created_at),
+                updated_at = VALUES(updated_at)
+            "#,
+        )
+        .bind(credential.user_id.into_inner().to_string())
+        .bind(&credential.secret)
+        .bind(credential.enabled_at)
+        .bind(credential.last_used_step)
+        .bind(credential.created_at)
+        .bind(credential.updated_at)
+        .execute(self.pool.inner())
+        .await?;
+
+        self.find_totp_by_user(credential.user_id).await?.ok_or_else(|| {
+            ArcanaError::Internal("Failed to fetch saved TOTP credential".to_string())
+        })
+    }
+
+    async fn record_totp_step(&self, user_id: UserId, step: i64) -> ArcanaResult<bool> {
+        debug!("MySQL DAO: record TOTP step for user {}", user_id);
+        let result = sqlx::query(
+            r#"
+            UPDATE totp_credentials SET last_used_step = ?, updated_at = ?
+            WHERE user_id = ? AND (last_used_step IS NULL OR last_used_step < ?)
+            "#,
+        )
+        .bind(step)
+        .bind(Utc::now())
+        .bind(user_id.into_inner().to_string())
+        .bind(step)
+        .execute(self.pool.inner())
+        .await?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete_by_user(&self, user_id: UserId) -> ArcanaResult<bool> {
+        debug!("MySQL DAO: delete MFA data for user {}", user_id);
+        let user_id = user_id.into_inner().to_string();
+        let mut tx = self.pool.inner().begin().await?;
+
+        sqlx::query("DELETE FROM mfa_recovery_codes WHERE user_id = ?")
+            .bind(&user_id)
+            .execute(&mut *tx)
+            .await?;
+        let result = sqlx::query("DELETE FROM totp_credentials WHERE user_id = ?")
+            .bind(&user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn replace_recovery_codes(&self, user_id: UserId, code_hashes: &[String]) -> ArcanaResult<()> {
+        debug!("MySQL DAO: replace recovery codes for user {}", user_id);
+        let user_id = user_id.into_inner().to_string();
+        let mut tx = self.pool.inner().begin().await?;
+
+        sqlx::query("DELETE FROM mfa_recovery_codes WHERE user_id = ?")
+            .bind(&user_id)
+            .execute(&mut *tx)
+            .await?;
+        for code_hash in code_hashes {
+            sqlx::query(
+                "INSERT INTO mfa_recovery_codes (id, user_id, code_hash, created_at) VALUES (?, ?, ?, ?)",
+            )
+            .bind(Uuid::now_v7().to_string())
+            .bind(&user_id)
+            .bind(code_hash)
+            .bind(Utc::now())
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn consume_recovery_code(&self, user_id: UserId, code_hash: &str) -> ArcanaResult<bool> {
+        debug!("MySQL DAO: consume recovery code for user {}", user_id);
+        let result = sqlx::query(
+            r#"
+            UPDATE mfa_recovery_codes SET used_at = ?
+            WHERE user_id = ? AND code_hash = ? AND used_at IS NULL
+            "#,
+        )
+        .bind(Utc::now())
+        .bind(user_id.into_inner().to_string())
+        .bind(code_hash)
+        .execute(self.pool.inner())
+        .await?;
+        Ok(result.rows_affected() == 1)
+    }
+}
+
+impl std::fmt::Debug for MySqlMfaDaoImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MySqlMfaDaoImpl").finish_non_exhaustive()
+    }
+}
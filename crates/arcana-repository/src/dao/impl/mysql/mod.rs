@@ -1,5 +1,21 @@
 //! MySQL DAO implementations.
 
+pub mod audit_log_dao_impl;
+pub mod mfa_dao_impl;
+pub mod oauth_token_dao_impl;
+pub mod outbox_dao_impl;
+pub mod password_reset_token_dao_impl;
+pub mod role_dao_impl;
+pub mod tenant_dao_impl;
 pub mod user_dao_impl;
+pub mod webhook_dao_impl;
 
+pub use audit_log_dao_impl::MySqlAuditLogDaoImpl;
+pub use mfa_dao_impl::MySqlMfaDaoImpl;
+pub use oauth_token_dao_impl::MySqlOAuthTokenDaoImpl;
+pub use outbox_dao_impl::MySqlOutboxDaoImpl;
+pub use password_reset_token_dao_impl::MySqlPasswordResetTokenDaoImpl;
+pub use role_dao_impl::MySqlRoleDaoImpl;
+pub use tenant_dao_impl::MySqlTenantDaoImpl;
 pub use user_dao_impl::MySqlUserDaoImpl;
+pub use webhook_dao_impl::MySqlWebhookDaoImpl;
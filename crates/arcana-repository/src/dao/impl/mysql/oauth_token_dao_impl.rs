@@ -0,0 +1,227 @@
+//! MySQL OAuthTokenDao implementation.
+//!
+//! Low-level data access for the `oauth_tokens` table via SQLx.
+//! Implements [`OAuthTokenDao`] — the DAO layer directly beneath [`OAuthTokenRepositoryImpl`].
+//!
+//! [`OAuthTokenDao`]: crate::dao::OAuthTokenDao
+//! [`OAuthTokenRepositoryImpl`]: crate::OAuthTokenRepositoryImpl
+
+use crate::{dao::OAuthTokenDao, DatabasePoolInterface};
+use arcana_core::{ArcanaError, ArcanaResult, OAuthToken, OAuthTokenId, UserId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shaku::Component;
+use sqlx::FromRow;
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+/// MySQL implementation of [`OAuthTokenDao`].
+#[derive(Component, Clone)]
+#[shaku(interface = OAuthTokenDao)]
+pub struct MySqlOAuthTokenDaoImpl {
+    #[shaku(inject)]
+    pool: Arc<dyn DatabasePoolInterface>,
+}
+
+impl MySqlOAuthTokenDaoImpl {
+    /// Creates a new `MySqlOAuthTokenDaoImpl`.
+    #[must_use]
+    pub fn new(pool: Arc<dyn DatabasePoolInterface>) -> Self {
+        Self { pool }
+    }
+}
+
+/// Database row representation of an OAuth token.
+#[derive(Debug, FromRow)]
+struct OAuthTokenRow {
+    id: String,
+    user_id: String,
+    token_hash: String,
+    family_id: String,
+    device_id: Option<String>,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+    expires_at: DateTime<Utc>,
+    revoked: bool,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<OAuthTokenRow> for OAuthToken {
+    type Error = ArcanaError;
+
+    fn try_from(row: OAuthTokenRow) -> Result<Self, Self::Error> {
+        let id = Uuid::parse_str(&row.id)
+            .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))?;
+        let user_id = Uuid::parse_str(&row.user_id)
+            .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))?;
+
+        Ok(OAuthToken {
+            id: OAuthTokenId::from_uuid(id),
+            user_id: UserId::from_uuid(user_id),
+            token_hash: row.token_hash,
+            family_id: row.family_id,
+            device_id: row.device_id,
+            user_agent: row.user_agent,
+            ip_address: row.ip_address,
+            expires_at: row.expires_at,
+            revoked: row.revoked,
+            revoked_at: row.revoked_at,
+            created_at: row.created_at,
+            last_used_at: row.last_used_at,
+        })
+    }
+}
+
+#[async_trait]
+impl OAuthTokenDao for MySqlOAuthTokenDaoImpl {
+    async fn find_by_id(&self, id: OAuthTokenId) -> ArcanaResult<Option<OAuthToken>> {
+        debug!("MySQL DAO: find oauth token {}", id);
+        let row = sqlx::query_as::<_, OAuthTokenRow>(
+            r#"
+            SELECT id, user_id, token_hash, family_id, device_id, user_agent, ip_address,
+                   expires_at, revoked, revoked_at, created_at, last_used_at
+            FROM oauth_tokens WHERE id = ?
+            "#,
+        )
+        .bind(id.into_inner().to_string())
+        .fetch_optional(self.pool.inner())
+        .await?;
+        row.map(OAuthToken::try_from).transpose()
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<OAuthToken>> {
+        debug!("MySQL DAO: find oauth token by hash");
+        let row = sqlx::query_as::<_, OAuthTokenRow>(
+            r#"
+            SELECT id, user_id, token_hash, family_id, device_id, user_agent, ip_address,
+                   expires_at, revoked, revoked_at, created_at, last_used_at
+            FROM oauth_tokens WHERE token_hash = ?
+            LIMIT 1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(self.pool.inner())
+        .await?;
+        row.map(OAuthToken::try_from).transpose()
+    }
+
+    async fn save(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken> {
+        debug!("MySQL DAO: save oauth token for user {}", token.user_id);
+        sqlx::query(
+            r#"
+            INSERT INTO oauth_tokens (id, user_id, token_hash, family_id, device_id, user_agent,
+                                      ip_address, expires_at, revoked, revoked_at, created_at,
+                                      last_used_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(token.id.into_inner().to_string())
+        .bind(token.user_id.into_inner().to_string())
+        .bind(&token.token_hash)
+        .bind(&token.family_id)
+        .bind(&token.device_id)
+        .bind(&token.user_agent)
+        .bind(&token.ip_address)
+        .bind(token.expires_at)
+        .bind(token.revoked)
+        .bind(token.revoked_at)
+        .bind(token.created_at)
+        .bind(token.last_used_at)
+        .execute(self.pool.inner())
+        .await?;
+
+        self.find_by_id(token.id)
+            .await?
+            .ok_or_else(|| ArcanaError::Internal("Failed to fetch inserted oauth token".to_string()))
+    }
+
+    async fn update(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken> {
+        debug!("MySQL DAO: update oauth token {}", token.id);
+        sqlx::query(
+            r#"
+            UPDATE oauth_tokens
+            SET revoked = ?, revoked_at = ?, last_used_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(token.revoked)
+        .bind(token.revoked_at)
+        .bind(token.last_used_at)
+        .bind(token.id.into_inner().to_string())
+        .execute(self.pool.inner())
+        .await?;
+
+        self.find_by_id(token.id)
+            .await?
+            .ok_or_else(|| ArcanaError::Internal("Failed to fetch updated oauth token".to_string()))
+    }
+
+    async fn find_active_by_user(&self, user_id: UserId) -> ArcanaResult<Vec<OAuthToken>> {
+        debug!("MySQL DAO: find active oauth tokens for user {}", user_id);
+        let rows = sqlx::query_as::<_, OAuthTokenRow>(
+            r#"
+            SELECT id, user_id, token_hash, family_id, device_id, user_agent, ip_address,
+                   expires_at, revoked, revoked_at, created_at, last_used_at
+            FROM oauth_tokens
+            WHERE user_id = ? AND revoked = FALSE AND expires_at > ?
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(user_id.into_inner().to_string())
+        .bind(Utc::now())
+        .fetch_all(self.pool.inner())
+        .await?;
+        rows.into_iter().map(OAuthToken::try_from).collect()
+    }
+
+    async fn revoke_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64> {
+        debug!("MySQL DAO: revoke all oauth tokens for user {}", user_id);
+        let result = sqlx::query(
+            "UPDATE oauth_tokens SET revoked = TRUE, revoked_at = ? WHERE user_id = ? AND revoked = FALSE",
+        )
+        .bind(Utc::now())
+        .bind(user_id.into_inner().to_string())
+        .execute(self.pool.inner())
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn mark_rotated(&self, id: OAuthTokenId) -> ArcanaResult<bool> {
+        debug!("MySQL DAO: mark oauth token {} rotated", id);
+        let now = Utc::now();
+        let result = sqlx::query(
+            r#"
+            UPDATE oauth_tokens
+            SET revoked = TRUE, revoked_at = ?, last_used_at = ?
+            WHERE id = ? AND revoked = FALSE
+            "#,
+        )
+        .bind(now)
+        .bind(now)
+        .bind(id.into_inner().to_string())
+        .execute(self.pool.inner())
+        .await?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> ArcanaResult<u64> {
+        debug!("MySQL DAO: revoke oauth token family {}", family_id);
+        let result = sqlx::query(
+            "UPDATE oauth_tokens SET revoked = TRUE, revoked_at = ? WHERE family_id = ? AND revoked = FALSE",
+        )
+        .bind(Utc::now())
+        .bind(family_id)
+        .execute(self.pool.inner())
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+impl std::fmt::Debug for MySqlOAuthTokenDaoImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MySqlOAuthTokenDaoImpl").finish_non_exhaustive()
+    }
+}
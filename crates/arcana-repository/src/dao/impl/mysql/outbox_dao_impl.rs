@@ -0,0 +1,141 @@
+//! MySQL OutboxDao implementation.
+//!
+//! Low-level data access for the `outbox_events` table via SQLx. Implements
+//! [`OutboxDao`] — the DAO layer directly beneath [`OutboxRepositoryImpl`].
+//!
+//! [`OutboxDao`]: crate::dao::OutboxDao
+//! [`OutboxRepositoryImpl`]: crate::OutboxRepositoryImpl
+
+use crate::{dao::OutboxDao, DatabasePoolInterface};
+use arcana_core::{ArcanaError, ArcanaResult, OutboxEvent, OutboxEventId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shaku::Component;
+use sqlx::{FromRow, MySqlConnection};
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+/// MySQL implementation of [`OutboxDao`].
+#[derive(Component, Clone)]
+#[shaku(interface = OutboxDao)]
+pub struct MySqlOutboxDaoImpl {
+    #[shaku(inject)]
+    pool: Arc<dyn DatabasePoolInterface>,
+}
+
+impl MySqlOutboxDaoImpl {
+    /// Creates a new `MySqlOutboxDaoImpl`.
+    #[must_use]
+    pub fn new(pool: Arc<dyn DatabasePoolInterface>) -> Self {
+        Self { pool }
+    }
+}
+
+/// Database row representation of an outbox event.
+#[derive(Debug, FromRow)]
+struct OutboxEventRow {
+    id: String,
+    event_type: String,
+    aggregate_id: String,
+    payload: String,
+    occurred_at: DateTime<Utc>,
+    dispatched_at: Option<DateTime<Utc>>,
+    attempts: u32,
+    last_error: Option<String>,
+}
+
+impl TryFrom<OutboxEventRow> for OutboxEvent {
+    type Error = ArcanaError;
+
+    fn try_from(row: OutboxEventRow) -> Result<Self, Self::Error> {
+        let id = Uuid::parse_str(&row.id)
+            .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))?;
+
+        Ok(OutboxEvent {
+            id: OutboxEventId::from_uuid(id),
+            event_type: row.event_type,
+            aggregate_id: row.aggregate_id,
+            payload: row.payload,
+            occurred_at: row.occurred_at,
+            dispatched_at: row.dispatched_at,
+            attempts: row.attempts,
+            last_error: row.last_error,
+        })
+    }
+}
+
+/// Inserts events into the outbox on the connection of an open transaction.
+///
+/// Used by the DAOs whose changes raise events, so that the events are
+/// committed or rolled back together with the change.
+pub(crate) async fn insert_outbox_events(conn: &mut MySqlConnection, events: &[OutboxEvent]) -> ArcanaResult<()> {
+    for event in events {
+        debug!("MySQL DAO: add {} for {} to outbox", event.event_type, event.aggregate_id);
+        sqlx::query(
+            r#"
+            INSERT INTO outbox_events (id, event_type, aggregate_id, payload, occurred_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(event.id.into_inner().to_string())
+        .bind(&event.event_type)
+        .bind(&event.aggregate_id)
+        .bind(&event.payload)
+        .bind(event.occurred_at)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl OutboxDao for MySqlOutboxDaoImpl {
+    async fn find_pending(&self, limit: usize, max_attempts: u32) -> ArcanaResult<Vec<OutboxEvent>> {
+        debug!("MySQL DAO: find pending outbox events limit={}", limit);
+        let rows = sqlx::query_as::<_, OutboxEventRow>(
+            r#"
+            SELECT id, event_type, aggregate_id, payload, occurred_at, dispatched_at,
+                   attempts, last_error
+            FROM outbox_events
+            WHERE dispatched_at IS NULL AND attempts < ?
+            ORDER BY occurred_at, id
+            LIMIT ?
+            "#,
+        )
+        .bind(max_attempts)
+        .bind(limit as i64)
+        .fetch_all(self.pool.inner())
+        .await?;
+
+        rows.into_iter().map(OutboxEvent::try_from).collect()
+    }
+
+    async fn mark_dispatched(&self, id: OutboxEventId) -> ArcanaResult<bool> {
+        debug!("MySQL DAO: mark outbox event {} dispatched", id);
+        let result = sqlx::query(
+            "UPDATE outbox_events SET dispatched_at = ? WHERE id = ? AND dispatched_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id.into_inner().to_string())
+        .execute(self.pool.inner())
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn record_failure(&self, id: OutboxEventId, error: &str) -> ArcanaResult<()> {
+        debug!("MySQL DAO: record failed delivery of outbox event {}", id);
+        sqlx::query("UPDATE outbox_events SET attempts = attempts + 1, last_error = ? WHERE id = ?")
+            .bind(error)
+            .bind(id.into_inner().to_string())
+            .execute(self.pool.inner())
+            .await?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for MySqlOutboxDaoImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MySqlOutboxDaoImpl").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,149 @@
+//! MySQL PasswordResetTokenDao implementation.
+//!
+//! Low-level data access for the `password_reset_tokens` table via SQLx.
+//! Implements [`PasswordResetTokenDao`] — the DAO layer directly beneath
+//! [`PasswordResetTokenRepositoryImpl`].
+//!
+//! [`PasswordResetTokenDao`]: crate::dao::PasswordResetTokenDao
+//! [`PasswordResetTokenRepositoryImpl`]: crate::PasswordResetTokenRepositoryImpl
+
+use crate::{dao::PasswordResetTokenDao, DatabasePoolInterface};
+use arcana_core::{ArcanaError, ArcanaResult, PasswordResetToken, PasswordResetTokenId, UserId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shaku::Component;
+use sqlx::FromRow;
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+/// MySQL implementation of [`PasswordResetTokenDao`].
+#[derive(Component, Clone)]
+#[shaku(interface = PasswordResetTokenDao)]
+pub struct MySqlPasswordResetTokenDaoImpl {
+    #[shaku(inject)]
+    pool: Arc<dyn DatabasePoolInterface>,
+}
+
+impl MySqlPasswordResetTokenDaoImpl {
+    /// Creates a new `MySqlPasswordResetTokenDaoImpl`.
+    #[must_use]
+    pub fn new(pool: Arc<dyn DatabasePoolInterface>) -> Self {
+        Self { pool }
+    }
+}
+
+/// Database row representation of a password reset token.
+#[derive(Debug, FromRow)]
+struct PasswordResetTokenRow {
+    id: String,
+    user_id: String,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+    used_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<PasswordResetTokenRow> for PasswordResetToken {
+    type Error = ArcanaError;
+
+    fn try_from(row: PasswordResetTokenRow) -> Result<Self, Self::Error> {
+        let id = Uuid::parse_str(&row.id)
+            .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))?;
+        let user_id = Uuid::parse_str(&row.user_id)
+            .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))?;
+
+        Ok(PasswordResetToken {
+            id: PasswordResetTokenId::from_uuid(id),
+            user_id: UserId::from_uuid(user_id),
+            token_hash: row.token_hash,
+            expires_at: row.expires_at,
+            used_at: row.used_at,
+            created_at: row.created_at,
+        })
+    }
+}
+
+#[async_trait]
+impl PasswordResetTokenDao for MySqlPasswordResetTokenDaoImpl {
+    async fn find_by_id(&self, id: PasswordResetTokenId) -> ArcanaResult<Option<PasswordResetToken>> {
+        debug!("MySQL DAO: find password reset token {}", id);
+        let row = sqlx::query_as::<_, PasswordResetTokenRow>(
+            r#"
+            SELECT id, user_id, token_hash, expires_at, used_at, created_at
+            FROM password_reset_tokens WHERE id = ?
+            "#,
+        )
+        .bind(id.into_inner().to_string())
+        .fetch_optional(self.pool.inner())
+        .await?;
+        row.map(PasswordResetToken::try_from).transpose()
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<PasswordResetToken>> {
+        debug!("MySQL DAO: find password reset token by hash");
+        let row = sqlx::query_as::<_, PasswordResetTokenRow>(
+            r#"
+            SELECT id, user_id, token_hash, expires_at, used_at, created_at
+            FROM password_reset_tokens WHERE token_hash = ?
+            LIMIT 1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(self.pool.inner())
+        .await?;
+        row.map(PasswordResetToken::try_from).transpose()
+    }
+
+    async fn save(&self, token: &PasswordResetToken) -> ArcanaResult<PasswordResetToken> {
+        debug!("MySQL DAO: save password reset token for user {}", token.user_id);
+        sqlx::query(
+            r#"
+            INSERT INTO password_reset_tokens (id, user_id, token_hash, expires_at, used_at, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(token.id.into_inner().to_string())
+        .bind(token.user_id.into_inner().to_string())
+        .bind(&token.token_hash)
+        .bind(token.expires_at)
+        .bind(token.used_at)
+        .bind(token.created_at)
+        .execute(self.pool.inner())
+        .await?;
+
+        self.find_by_id(token.id).await?.ok_or_else(|| {
+            ArcanaError::Internal("Failed to fetch inserted password reset token".to_string())
+        })
+    }
+
+    async fn mark_used(&self, id: PasswordResetTokenId) -> ArcanaResult<bool> {
+        debug!("MySQL DAO: mark password reset token {} used", id);
+        let result = sqlx::query(
+            "UPDATE password_reset_tokens SET used_at = ? WHERE id = ? AND used_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(id.into_inner().to_string())
+        .execute(self.pool.inner())
+        .await?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn invalidate_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64> {
+        debug!("MySQL DAO: invalidate password reset tokens for user {}", user_id);
+        let result = sqlx::query(
+            "UPDATE password_reset_tokens SET used_at = ? WHERE user_id = ? AND used_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(user_id.into_inner().to_string())
+        .execute(self.pool.inner())
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+impl std::fmt::Debug for MySqlPasswordResetTokenDaoImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MySqlPasswordResetTokenDaoImpl").finish_non_exhaustive()
+    }
+}
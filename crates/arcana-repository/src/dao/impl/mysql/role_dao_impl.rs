@@ -0,0 +1,264 @@
+//! MySQL RoleDao implementation.
+//!
+//! Low-level data access for the `roles`, `user_role_assignments` and
+//! `user_permissions` tables via SQLx. Implements [`RoleDao`] — the DAO
+//! layer directly beneath [`RoleRepositoryImpl`].
+//!
+//! Permissions are stored in their `resource:action` form.
+//!
+//! [`RoleDao`]: crate::dao::RoleDao
+//! [`RoleRepositoryImpl`]: crate::RoleRepositoryImpl
+
+use crate::{dao::RoleDao, DatabasePoolInterface};
+use arcana_core::{ArcanaError, ArcanaResult, Permission, PermissionSet, Role, RoleId, UserId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shaku::Component;
+use sqlx::{types::Json, FromRow};
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+/// MySQL implementation of [`RoleDao`].
+#[derive(Component, Clone)]
+#[shaku(interface = RoleDao)]
+pub struct MySqlRoleDaoImpl {
+    #[shaku(inject)]
+    pool: Arc<dyn DatabasePoolInterface>,
+}
+
+impl MySqlRoleDaoImpl {
+    /// Creates a new `MySqlRoleDaoImpl`.
+    #[must_use]
+    pub fn new(pool: Arc<dyn DatabasePoolInterface>) -> Self {
+        Self { pool }
+    }
+}
+
+fn parse_uuid(value: &str) -> ArcanaResult<Uuid> {
+    Uuid::parse_str(value).map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))
+}
+
+fn parse_permission(value: &str) -> ArcanaResult<Permission> {
+    value
+        .parse()
+        .map_err(|_| ArcanaError::Internal(format!("Invalid permission in database: {value}")))
+}
+
+fn permission_names(permissions: &PermissionSet) -> Vec<&'static str> {
+    permissions.iter().map(|p| p.as_str()).collect()
+}
+
+/// Database row representation of a role.
+#[derive(Debug, FromRow)]
+struct RoleRow {
+    id: String,
+    name: String,
+    description: Option<String>,
+    permissions: Json<Vec<String>>,
+    built_in: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<RoleRow> for Role {
+    type Error = ArcanaError;
+
+    fn try_from(row: RoleRow) -> Result<Self, Self::Error> {
+        Ok(Role {
+            id: RoleId::from_uuid(parse_uuid(&row.id)?),
+            name: row.name,
+            description: row.description,
+            permissions: row.permissions.0.iter().map(|p| parse_permission(p)).collect::<Result<_, _>>()?,
+            built_in: row.built_in,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[async_trait]
+impl RoleDao for MySqlRoleDaoImpl {
+    async fn find_by_id(&self, id: RoleId) -> ArcanaResult<Option<Role>> {
+        debug!("MySQL DAO: find role {}", id);
+        let row = sqlx::query_as::<_, RoleRow>(
+            r#"
+            SELECT id, name, description, permissions, built_in, created_at, updated_at
+            FROM roles WHERE id = ?
+            "#,
+        )
+        .bind(id.into_inner().to_string())
+        .fetch_optional(self.pool.inner())
+        .await?;
+
+        row.map(Role::try_from).transpose()
+    }
+
+    async fn find_by_name(&self, name: &str) -> ArcanaResult<Option<Role>> {
+        debug!("MySQL DAO: find role by name {}", name);
+        let row = sqlx::query_as::<_, RoleRow>(
+            r#"
+            SELECT id, name, description, permissions, built_in, created_at, updated_at
+            FROM roles WHERE name = ?
+            "#,
+        )
+        .bind(name)
+        .fetch_optional(self.pool.inner())
+        .await?;
+
+        row.map(Role::try_from).transpose()
+    }
+
+    async fn find_all(&self) -> ArcanaResult<Vec<Role>> {
+        debug!("MySQL DAO: find roles");
+        let rows = sqlx::query_as::<_, RoleRow>(
+            r#"
+            SELECT id, name, description, permissions, built_in, created_at, updated_at
+            FROM roles
+            ORDER BY built_in DESC, name
+            "#,
+        )
+        .fetch_all(self.pool.inner())
+        .await?;
+
+        rows.into_iter().map(Role::try_from).collect()
+    }
+
+    async fn save(&self, role: &Role) -> ArcanaResult<Role> {
+        debug!("MySQL DAO: save role {}", role.name);
+        sqlx::query(
+            r#"
+            INSERT INTO roles (id, name, description, permissions, built_in, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(role.id.into_inner().to_string())
+        .bind(&role.name)
+        .bind(&role.description)
+        .bind(Json(permission_names(&role.permissions)))
+        .bind(role.built_in)
+        .bind(role.created_at)
+        .bind(role.updated_at)
+        .execute(self.pool.inner())
+        .await?;
+        Ok(role.clone())
+    }
+
+    async fn update(&self, role: &Role) -> ArcanaResult<Role> {
+        debug!("MySQL DAO: update role {}", role.id);
+        let result = sqlx::query(
+            r#"
+            UPDATE roles
+            SET name = ?, description = ?, permissions = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&role.name)
+        .bind(&role.description)
+        .bind(Json(permission_names(&role.permissions)))
+        .bind(role.updated_at)
+        .bind(role.id.into_inner().to_string())
+        .execute(self.pool.inner())
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(ArcanaError::not_found("Role", role.id));
+        }
+        Ok(role.clone())
+    }
+
+    async fn delete(&self, id: RoleId) -> ArcanaResult<bool> {
+        debug!("MySQL DAO: delete role {}", id);
+        let result = sqlx::query("DELETE FROM roles WHERE id = ? AND built_in = FALSE")
+            .bind(id.into_inner().to_string())
+            .execute(self.pool.inner())
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_assigned(&self, user_id: UserId) -> ArcanaResult<Vec<Role>> {
+        debug!("MySQL DAO: find roles assigned to user {}", user_id);
+        let rows = sqlx::query_as::<_, RoleRow>(
+            r#"
+            SELECT r.id, r.name, r.description, r.permissions, r.built_in, r.created_at, r.updated_at
+            FROM roles r
+            JOIN user_role_assignments a ON a.role_id = r.id
+            WHERE a.user_id = ?
+            ORDER BY r.name
+            "#,
+        )
+        .bind(user_id.into_inner().to_string())
+        .fetch_all(self.pool.inner())
+        .await?;
+
+        rows.into_iter().map(Role::try_from).collect()
+    }
+
+    async fn assign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<()> {
+        debug!("MySQL DAO: assign role {} to user {}", role_id, user_id);
+        sqlx::query(
+            r#"
+            INSERT IGNORE INTO user_role_assignments (user_id, role_id, assigned_at)
+            VALUES (?, ?, ?)
+            "#,
+        )
+        .bind(user_id.into_inner().to_string())
+        .bind(role_id.into_inner().to_string())
+        .bind(Utc::now())
+        .execute(self.pool.inner())
+        .await?;
+        Ok(())
+    }
+
+    async fn unassign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<bool> {
+        debug!("MySQL DAO: unassign role {} from user {}", role_id, user_id);
+        let result = sqlx::query("DELETE FROM user_role_assignments WHERE user_id = ? AND role_id = ?")
+            .bind(user_id.into_inner().to_string())
+            .bind(role_id.into_inner().to_string())
+            .execute(self.pool.inner())
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_user_permissions(&self, user_id: UserId) -> ArcanaResult<PermissionSet> {
+        debug!("MySQL DAO: find permissions granted to user {}", user_id);
+        let permissions: Vec<String> = sqlx::query_scalar("SELECT permission FROM user_permissions WHERE user_id = ?")
+            .bind(user_id.into_inner().to_string())
+            .fetch_all(self.pool.inner())
+            .await?;
+
+        permissions.iter().map(|p| parse_permission(p)).collect()
+    }
+
+    async fn grant(&self, user_id: UserId, permission: Permission) -> ArcanaResult<()> {
+        debug!("MySQL DAO: grant {} to user {}", permission, user_id);
+        sqlx::query(
+            r#"
+            INSERT IGNORE INTO user_permissions (user_id, permission, granted_at)
+            VALUES (?, ?, ?)
+            "#,
+        )
+        .bind(user_id.into_inner().to_string())
+        .bind(permission.as_str())
+        .bind(Utc::now())
+        .execute(self.pool.inner())
+        .await?;
+        Ok(())
+    }
+
+    async fn revoke(&self, user_id: UserId, permission: Permission) -> ArcanaResult<bool> {
+        debug!("MySQL DAO: revoke {} from user {}", permission, user_id);
+        let result = sqlx::query("DELETE FROM user_permissions WHERE user_id = ? AND permission = ?")
+            .bind(user_id.into_inner().to_string())
+            .bind(permission.as_str())
+            .execute(self.pool.inner())
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+impl std::fmt::Debug for MySqlRoleDaoImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MySqlRoleDaoImpl").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,153 @@
+//! MySQL TenantDao implementation.
+//!
+//! Low-level data access for the `tenants` table via SQLx. Implements
+//! [`TenantDao`] — the DAO layer directly beneath [`TenantRepositoryImpl`].
+//!
+//! [`TenantDao`]: crate::dao::TenantDao
+//! [`TenantRepositoryImpl`]: crate::TenantRepositoryImpl
+
+use crate::{dao::TenantDao, DatabasePoolInterface};
+use arcana_core::{ArcanaError, ArcanaResult, Page, PageRequest, Tenant, TenantId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shaku::Component;
+use sqlx::FromRow;
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+/// MySQL implementation of [`TenantDao`].
+#[derive(Component, Clone)]
+#[shaku(interface = TenantDao)]
+pub struct MySqlTenantDaoImpl {
+    #[shaku(inject)]
+    pool: Arc<dyn DatabasePoolInterface>,
+}
+
+impl MySqlTenantDaoImpl {
+    /// Creates a new `MySqlTenantDaoImpl`.
+    #[must_use]
+    pub fn new(pool: Arc<dyn DatabasePoolInterface>) -> Self {
+        Self { pool }
+    }
+}
+
+/// Database row representation of a tenant.
+#[derive(Debug, FromRow)]
+struct TenantRow {
+    id: String,
+    slug: String,
+    name: String,
+    active: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<TenantRow> for Tenant {
+    type Error = ArcanaError;
+
+    fn try_from(row: TenantRow) -> Result<Self, Self::Error> {
+        let id = Uuid::parse_str(&row.id)
+            .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))?;
+
+        Ok(Tenant {
+            id: TenantId::from_uuid(id),
+            slug: row.slug,
+            name: row.name,
+            active: row.active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[async_trait]
+impl TenantDao for MySqlTenantDaoImpl {
+    async fn find_by_id(&self, id: TenantId) -> ArcanaResult<Option<Tenant>> {
+        debug!("MySQL DAO: find tenant {}", id);
+        let row = sqlx::query_as::<_, TenantRow>(
+            "SELECT id, slug, name, active, created_at, updated_at FROM tenants WHERE id = ?",
+        )
+        .bind(id.into_inner().to_string())
+        .fetch_optional(self.pool.inner())
+        .await?;
+
+        row.map(Tenant::try_from).transpose()
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> ArcanaResult<Option<Tenant>> {
+        debug!("MySQL DAO: find tenant by slug {}", slug);
+        let row = sqlx::query_as::<_, TenantRow>(
+            "SELECT id, slug, name, active, created_at, updated_at FROM tenants WHERE slug = ?",
+        )
+        .bind(slug)
+        .fetch_optional(self.pool.inner())
+        .await?;
+
+        row.map(Tenant::try_from).transpose()
+    }
+
+    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<Tenant>> {
+        debug!("MySQL DAO: find tenants page={} size={}", page.page, page.size);
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tenants")
+            .fetch_one(self.pool.inner())
+            .await?;
+
+        let rows = sqlx::query_as::<_, TenantRow>(
+            r#"
+            SELECT id, slug, name, active, created_at, updated_at
+            FROM tenants
+            ORDER BY created_at, id
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(page.limit() as i64)
+        .bind(page.offset() as i64)
+        .fetch_all(self.pool.inner())
+        .await?;
+
+        let tenants = rows.into_iter().map(Tenant::try_from).collect::<Result<_, _>>()?;
+        Ok(Page::new(tenants, page.page, page.size, total as u64))
+    }
+
+    async fn save(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+        debug!("MySQL DAO: save tenant {}", tenant.slug);
+        sqlx::query(
+            r#"
+            INSERT INTO tenants (id, slug, name, active, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(tenant.id.into_inner().to_string())
+        .bind(&tenant.slug)
+        .bind(&tenant.name)
+        .bind(tenant.active)
+        .bind(tenant.created_at)
+        .bind(tenant.updated_at)
+        .execute(self.pool.inner())
+        .await?;
+        Ok(tenant.clone())
+    }
+
+    async fn update(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+        debug!("MySQL DAO: update tenant {}", tenant.id);
+        let result = sqlx::query("UPDATE tenants SET name = ?, active = ?, updated_at = ? WHERE id = ?")
+            .bind(&tenant.name)
+            .bind(tenant.active)
+            .bind(tenant.updated_at)
+            .bind(tenant.id.into_inner().to_string())
+            .execute(self.pool.inner())
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(ArcanaError::not_found("Tenant", tenant.id));
+        }
+        Ok(tenant.clone())
+    }
+}
+
+impl std::fmt::Debug for MySqlTenantDaoImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MySqlTenantDaoImpl").finish_non_exhaustive()
+    }
+}
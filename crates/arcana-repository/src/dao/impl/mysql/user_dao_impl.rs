@@ -1,14 +1,18 @@
 //! MySQL UserDao implementation.
 //!
-//! Low-level data access for the `users` table via SQLx.
+//! Low-level data access for the `users` table via SQLx. Lookups other than
+//! by ID are restricted to the rows of one tenant. Changes made with
+//! the `*_with_events` methods add their domain events to the `outbox_events`
+//! table in the same transaction.
 //! Implements [`UserDao`] — the DAO layer directly beneath [`UserRepositoryImpl`].
 //!
 //! [`UserDao`]: crate::dao::UserDao
 //! [`UserRepositoryImpl`]: crate::UserRepositoryImpl
 
+use super::outbox_dao_impl::insert_outbox_events;
 use crate::{dao::UserDao, DatabasePoolInterface};
-use arcana_core::{ArcanaError, ArcanaResult, Page, PageRequest, UserId};
-use arcana_core::{Email, User, UserRole, UserStatus};
+use arcana_core::{ArcanaError, ArcanaResult, Page, PageRequest, TenantId, UserId};
+use arcana_core::{Email, OutboxEvent, User, UserRole, UserStatus};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use shaku::Component;
@@ -44,6 +48,7 @@ impl MySqlUserDaoImpl {
 #[derive(Debug, FromRow)]
 struct UserRow {
     id: String,
+    tenant_id: String,
     username: String,
     email: String,
     password_hash: String,
@@ -54,6 +59,7 @@ struct UserRow {
     email_verified: bool,
     avatar_url: Option<String>,
     last_login_at: Option<DateTime<Utc>>,
+    locked_until: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -64,9 +70,12 @@ impl TryFrom<UserRow> for User {
     fn try_from(row: UserRow) -> Result<Self, Self::Error> {
         let id = Uuid::parse_str(&row.id)
             .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))?;
+        let tenant_id = Uuid::parse_str(&row.tenant_id)
+            .map_err(|e| ArcanaError::Internal(format!("Invalid tenant UUID in database: {e}")))?;
 
         Ok(User {
             id: UserId::from_uuid(id),
+            tenant_id: TenantId::from_uuid(tenant_id),
             username: row.username,
             email: Email::new_unchecked(row.email),
             password_hash: row.password_hash,
@@ -77,6 +86,7 @@ impl TryFrom<UserRow> for User {
             email_verified: row.email_verified,
             avatar_url: row.avatar_url,
             last_login_at: row.last_login_at,
+            locked_until: row.locked_until,
             created_at: row.created_at,
             updated_at: row.updated_at,
         })
@@ -108,8 +118,8 @@ impl UserDao for MySqlUserDaoImpl {
         debug!("MySQL DAO: find_by_id {}", id);
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
             FROM users WHERE id = ? AND status != 'deleted'
             "#,
@@ -120,50 +130,53 @@ impl UserDao for MySqlUserDaoImpl {
         row.map(User::try_from).transpose()
     }
 
-    async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>> {
-        debug!("MySQL DAO: find_by_username {}", username);
+    async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>> {
+        debug!("MySQL DAO: find_by_username {} in tenant {}", username, tenant_id);
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
-            FROM users WHERE username = ? AND status != 'deleted'
+            FROM users WHERE tenant_id = ? AND username = ? AND status != 'deleted'
             "#,
         )
+        .bind(tenant_id.to_string())
         .bind(username)
         .fetch_optional(self.pool.inner())
         .await?;
         row.map(User::try_from).transpose()
     }
 
-    async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>> {
-        debug!("MySQL DAO: find_by_email {}", email);
+    async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>> {
+        debug!("MySQL DAO: find_by_email {} in tenant {}", email, tenant_id);
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
-            FROM users WHERE LOWER(email) = LOWER(?) AND status != 'deleted'
+            FROM users WHERE tenant_id = ? AND LOWER(email) = LOWER(?) AND status != 'deleted'
             "#,
         )
+        .bind(tenant_id.to_string())
         .bind(email)
         .fetch_optional(self.pool.inner())
         .await?;
         row.map(User::try_from).transpose()
     }
 
-    async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>> {
-        debug!("MySQL DAO: find_by_username_or_email {}", identifier);
+    async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>> {
+        debug!("MySQL DAO: find_by_username_or_email {} in tenant {}", identifier, tenant_id);
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
             FROM users
-            WHERE (username = ? OR LOWER(email) = LOWER(?)) AND status != 'deleted'
+            WHERE tenant_id = ? AND (username = ? OR LOWER(email) = LOWER(?)) AND status != 'deleted'
             LIMIT 1
             "#,
         )
+        .bind(tenant_id.to_string())
         .bind(identifier)
         .bind(identifier)
         .fetch_optional(self.pool.inner())
@@ -171,41 +184,45 @@ impl UserDao for MySqlUserDaoImpl {
         row.map(User::try_from).transpose()
     }
 
-    async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool> {
+    async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool> {
         let result: Option<i32> =
-            sqlx::query_scalar("SELECT 1 FROM users WHERE username = ? LIMIT 1")
+            sqlx::query_scalar("SELECT 1 FROM users WHERE tenant_id = ? AND username = ? LIMIT 1")
+                .bind(tenant_id.to_string())
                 .bind(username)
                 .fetch_optional(self.pool.inner())
                 .await?;
         Ok(result.is_some())
     }
 
-    async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool> {
+    async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool> {
         let result: Option<i32> =
-            sqlx::query_scalar("SELECT 1 FROM users WHERE LOWER(email) = LOWER(?) LIMIT 1")
+            sqlx::query_scalar("SELECT 1 FROM users WHERE tenant_id = ? AND LOWER(email) = LOWER(?) LIMIT 1")
+                .bind(tenant_id.to_string())
                 .bind(email)
                 .fetch_optional(self.pool.inner())
                 .await?;
         Ok(result.is_some())
     }
 
-    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<User>> {
-        debug!("MySQL DAO: find_all page={} size={}", page.page, page.size);
+    async fn find_all(&self, tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>> {
+        debug!("MySQL DAO: find_all in tenant {} page={} size={}", tenant_id, page.page, page.size);
         let total: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE status != 'deleted'")
+            sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE tenant_id = ? AND status != 'deleted'")
+                .bind(tenant_id.to_string())
                 .fetch_one(self.pool.inner())
                 .await?;
 
         let rows = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
-            FROM users WHERE status != 'deleted'
+            FROM users WHERE tenant_id = ? AND status != 'deleted'
             ORDER BY created_at DESC
             LIMIT ? OFFSET ?
             "#,
         )
+        .bind(tenant_id.to_string())
         .bind(page.limit() as i64)
         .bind(page.offset() as i64)
         .fetch_all(self.pool.inner())
@@ -215,26 +232,29 @@ impl UserDao for MySqlUserDaoImpl {
         Ok(Page::new(users, page.page, page.size, total as u64))
     }
 
-    async fn find_by_role(&self, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
-        debug!("MySQL DAO: find_by_role {:?}", role);
+    async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
+        debug!("MySQL DAO: find_by_role {:?} in tenant {}", role, tenant_id);
+        let tenant_str = tenant_id.to_string();
         let role_str = role.to_string();
         let total: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM users WHERE role = ? AND status != 'deleted'",
+            "SELECT COUNT(*) FROM users WHERE tenant_id = ? AND role = ? AND status != 'deleted'",
         )
+        .bind(&tenant_str)
         .bind(&role_str)
         .fetch_one(self.pool.inner())
         .await?;
 
         let rows = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
-            FROM users WHERE role = ? AND status != 'deleted'
+            FROM users WHERE tenant_id = ? AND role = ? AND status != 'deleted'
             ORDER BY created_at DESC
             LIMIT ? OFFSET ?
             "#,
         )
+        .bind(&tenant_str)
         .bind(&role_str)
         .bind(page.limit() as i64)
         .bind(page.offset() as i64)
@@ -246,16 +266,30 @@ impl UserDao for MySqlUserDaoImpl {
     }
 
     async fn save(&self, user: &User) -> ArcanaResult<User> {
+        self.save_with_events(user, &[]).await
+    }
+
+    async fn update(&self, user: &User) -> ArcanaResult<User> {
+        self.update_with_events(user, &[]).await
+    }
+
+    async fn delete(&self, id: UserId) -> ArcanaResult<bool> {
+        self.delete_with_events(id, &[]).await
+    }
+
+    async fn save_with_events(&self, user: &User, events: &[OutboxEvent]) -> ArcanaResult<User> {
         debug!("MySQL DAO: save user {}", user.username);
         let id_str = user.id.into_inner().to_string();
+        let mut tx = self.pool.inner().begin().await?;
         sqlx::query(
             r#"
-            INSERT INTO users (id, username, email, password_hash, first_name, last_name,
+            INSERT INTO users (id, tenant_id, username, email, password_hash, first_name, last_name,
                               role, status, email_verified, avatar_url, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&id_str)
+        .bind(user.tenant_id.to_string())
         .bind(&user.username)
         .bind(user.email.as_str())
         .bind(&user.password_hash)
@@ -267,23 +301,26 @@ impl UserDao for MySqlUserDaoImpl {
         .bind(&user.avatar_url)
         .bind(user.created_at)
         .bind(user.updated_at)
-        .execute(self.pool.inner())
+        .execute(&mut *tx)
         .await?;
+        insert_outbox_events(&mut tx, events).await?;
+        tx.commit().await?;
 
         self.find_by_id(user.id)
             .await?
             .ok_or_else(|| ArcanaError::Internal("Failed to fetch inserted user".to_string()))
     }
 
-    async fn update(&self, user: &User) -> ArcanaResult<User> {
+    async fn update_with_events(&self, user: &User, events: &[OutboxEvent]) -> ArcanaResult<User> {
         debug!("MySQL DAO: update user {}", user.id);
         let id_str = user.id.into_inner().to_string();
+        let mut tx = self.pool.inner().begin().await?;
         sqlx::query(
             r#"
             UPDATE users
             SET username = ?, email = ?, password_hash = ?, first_name = ?,
                 last_name = ?, role = ?, status = ?, email_verified = ?,
-                avatar_url = ?, last_login_at = ?, updated_at = ?
+                avatar_url = ?, last_login_at = ?, locked_until = ?, updated_at = ?
             WHERE id = ?
             "#,
         )
@@ -297,39 +334,50 @@ impl UserDao for MySqlUserDaoImpl {
         .bind(user.email_verified)
         .bind(&user.avatar_url)
         .bind(user.last_login_at)
+        .bind(user.locked_until)
         .bind(user.updated_at)
         .bind(&id_str)
-        .execute(self.pool.inner())
+        .execute(&mut *tx)
         .await?;
+        insert_outbox_events(&mut tx, events).await?;
+        tx.commit().await?;
 
         self.find_by_id(user.id)
             .await?
             .ok_or_else(|| ArcanaError::Internal("Failed to fetch updated user".to_string()))
     }
 
-    async fn delete(&self, id: UserId) -> ArcanaResult<bool> {
+    async fn delete_with_events(&self, id: UserId, events: &[OutboxEvent]) -> ArcanaResult<bool> {
         debug!("MySQL DAO: soft delete user {}", id);
+        let mut tx = self.pool.inner().begin().await?;
         let result = sqlx::query(
             "UPDATE users SET status = 'deleted', updated_at = NOW() WHERE id = ?",
         )
         .bind(id.into_inner().to_string())
-        .execute(self.pool.inner())
+        .execute(&mut *tx)
         .await?;
-        Ok(result.rows_affected() > 0)
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+        insert_outbox_events(&mut tx, events).await?;
+        tx.commit().await?;
+        Ok(true)
     }
 
-    async fn count(&self) -> ArcanaResult<u64> {
+    async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64> {
         let count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE status != 'deleted'")
+            sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE tenant_id = ? AND status != 'deleted'")
+                .bind(tenant_id.to_string())
                 .fetch_one(self.pool.inner())
                 .await?;
         Ok(count as u64)
     }
 
-    async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64> {
+    async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64> {
         let count: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM users WHERE role = ? AND status != 'deleted'",
+            "SELECT COUNT(*) FROM users WHERE tenant_id = ? AND role = ? AND status != 'deleted'",
         )
+        .bind(tenant_id.to_string())
         .bind(role.to_string())
         .fetch_one(self.pool.inner())
         .await?;
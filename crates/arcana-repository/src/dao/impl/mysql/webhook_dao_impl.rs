@@ -0,0 +1,287 @@
+//! MySQL WebhookDao implementation.
+//!
+//! Low-level data access for the `webhook_subscriptions` and
+//! `webhook_deliveries` tables via SQLx. Implements [`WebhookDao`] — the DAO
+//! layer directly beneath [`WebhookRepositoryImpl`].
+//!
+//! [`WebhookDao`]: crate::dao::WebhookDao
+//! [`WebhookRepositoryImpl`]: crate::WebhookRepositoryImpl
+
+use crate::{dao::WebhookDao, DatabasePoolInterface};
+use arcana_core::{
+    ArcanaError, ArcanaResult, Page, PageRequest, WebhookDelivery, WebhookDeliveryId, WebhookSubscription,
+    WebhookSubscriptionId,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shaku::Component;
+use sqlx::{types::Json, FromRow};
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+/// MySQL implementation of [`WebhookDao`].
+#[derive(Component, Clone)]
+#[shaku(interface = WebhookDao)]
+pub struct MySqlWebhookDaoImpl {
+    #[shaku(inject)]
+    pool: Arc<dyn DatabasePoolInterface>,
+}
+
+impl MySqlWebhookDaoImpl {
+    /// Creates a new `MySqlWebhookDaoImpl`.
+    #[must_use]
+    pub fn new(pool: Arc<dyn DatabasePoolInterface>) -> Self {
+        Self { pool }
+    }
+}
+
+fn parse_uuid(value: &str) -> ArcanaResult<Uuid> {
+    Uuid::parse_str(value).map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {e}")))
+}
+
+/// Database row representation of a webhook subscription.
+#[derive(Debug, FromRow)]
+struct WebhookSubscriptionRow {
+    id: String,
+    url: String,
+    event_types: Json<Vec<String>>,
+    secret: String,
+    description: Option<String>,
+    active: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<WebhookSubscriptionRow> for WebhookSubscription {
+    type Error = ArcanaError;
+
+    fn try_from(row: WebhookSubscriptionRow) -> Result<Self, Self::Error> {
+        Ok(WebhookSubscription {
+            id: WebhookSubscriptionId::from_uuid(parse_uuid(&row.id)?),
+            url: row.url,
+            event_types: row.event_types.0,
+            secret: row.secret,
+            description: row.description,
+            active: row.active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// Database row representation of a delivery log entry.
+#[derive(Debug, FromRow)]
+struct WebhookDeliveryRow {
+    id: String,
+    subscription_id: String,
+    event_id: String,
+    event_type: String,
+    attempt: u32,
+    status: String,
+    response_status: Option<u16>,
+    error: Option<String>,
+    duration_ms: u64,
+    attempted_at: DateTime<Utc>,
+}
+
+impl TryFrom<WebhookDeliveryRow> for WebhookDelivery {
+    type Error = ArcanaError;
+
+    fn try_from(row: WebhookDeliveryRow) -> Result<Self, Self::Error> {
+        Ok(WebhookDelivery {
+            id: WebhookDeliveryId::from_uuid(parse_uuid(&row.id)?),
+            subscription_id: WebhookSubscriptionId::from_uuid(parse_uuid(&row.subscription_id)?),
+            event_id: parse_uuid(&row.event_id)?,
+            event_type: row.event_type,
+            attempt: row.attempt,
+            status: row.status.parse()?,
+            response_status: row.response_status,
+            error: row.error,
+            duration_ms: row.duration_ms,
+            attempted_at: row.attempted_at,
+        })
+    }
+}
+
+#[async_trait]
+impl WebhookDao for MySqlWebhookDaoImpl {
+    async fn find_by_id(&self, id: WebhookSubscriptionId) -> ArcanaResult<Option<WebhookSubscription>> {
+        debug!("MySQL DAO: find webhook subscription {}", id);
+        let row = sqlx::query_as::<_, WebhookSubscriptionRow>(
+            r#"
+            SELECT id, url, event_types, secret, description, active, created_at, updated_at
+            FROM webhook_subscriptions WHERE id = ?
+            "#,
+        )
+        .bind(id.into_inner().to_string())
+        .fetch_optional(self.pool.inner())
+        .await?;
+
+        row.map(WebhookSubscription::try_from).transpose()
+    }
+
+    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<WebhookSubscription>> {
+        debug!("MySQL DAO: find webhook subscriptions page={} size={}", page.page, page.size);
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM webhook_subscriptions")
+            .fetch_one(self.pool.inner())
+            .await?;
+
+        let rows = sqlx::query_as::<_, WebhookSubscriptionRow>(
+            r#"
+            SELECT id, url, event_types, secret, description, active, created_at, updated_at
+            FROM webhook_subscriptions
+            ORDER BY created_at, id
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(page.limit() as i64)
+        .bind(page.offset() as i64)
+        .fetch_all(self.pool.inner())
+        .await?;
+
+        let subscriptions = rows
+            .into_iter()
+            .map(WebhookSubscription::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok(Page::new(subscriptions, page.page, page.size, total as u64))
+    }
+
+    async fn find_active(&self) -> ArcanaResult<Vec<WebhookSubscription>> {
+        debug!("MySQL DAO: find active webhook subscriptions");
+        let rows = sqlx::query_as::<_, WebhookSubscriptionRow>(
+            r#"
+            SELECT id, url, event_types, secret, description, active, created_at, updated_at
+            FROM webhook_subscriptions WHERE active = TRUE
+            ORDER BY created_at, id
+            "#,
+        )
+        .fetch_all(self.pool.inner())
+        .await?;
+
+        rows.into_iter().map(WebhookSubscription::try_from).collect()
+    }
+
+    async fn save(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription> {
+        debug!("MySQL DAO: save webhook subscription {}", subscription.id);
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_subscriptions (id, url, event_types, secret, description, active,
+                                               created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(subscription.id.into_inner().to_string())
+        .bind(&subscription.url)
+        .bind(Json(&subscription.event_types))
+        .bind(&subscription.secret)
+        .bind(&subscription.description)
+        .bind(subscription.active)
+        .bind(subscription.created_at)
+        .bind(subscription.updated_at)
+        .execute(self.pool.inner())
+        .await?;
+        Ok(subscription.clone())
+    }
+
+    async fn update(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription> {
+        debug!("MySQL DAO: update webhook subscription {}", subscription.id);
+        let result = sqlx::query(
+            r#"
+            UPDATE webhook_subscriptions
+            SET url = ?, event_types = ?, secret = ?, description = ?, active = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&subscription.url)
+        .bind(Json(&subscription.event_types))
+        .bind(&subscription.secret)
+        .bind(&subscription.description)
+        .bind(subscription.active)
+        .bind(subscription.updated_at)
+        .bind(subscription.id.into_inner().to_string())
+        .execute(self.pool.inner())
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(ArcanaError::not_found("WebhookSubscription", subscription.id));
+        }
+        Ok(subscription.clone())
+    }
+
+    async fn delete(&self, id: WebhookSubscriptionId) -> ArcanaResult<bool> {
+        debug!("MySQL DAO: delete webhook subscription {}", id);
+        let result = sqlx::query("DELETE FROM webhook_subscriptions WHERE id = ?")
+            .bind(id.into_inner().to_string())
+            .execute(self.pool.inner())
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> ArcanaResult<()> {
+        debug!(
+            "MySQL DAO: log webhook delivery {} attempt {} to {}",
+            delivery.event_id, delivery.attempt, delivery.subscription_id
+        );
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries (id, subscription_id, event_id, event_type, attempt, status,
+                                            response_status, error, duration_ms, attempted_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(delivery.id.into_inner().to_string())
+        .bind(delivery.subscription_id.into_inner().to_string())
+        .bind(delivery.event_id.to_string())
+        .bind(&delivery.event_type)
+        .bind(delivery.attempt)
+        .bind(delivery.status.to_string())
+        .bind(delivery.response_status)
+        .bind(&delivery.error)
+        .bind(delivery.duration_ms)
+        .bind(delivery.attempted_at)
+        .execute(self.pool.inner())
+        .await?;
+        Ok(())
+    }
+
+    async fn find_deliveries(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        page: PageRequest,
+    ) -> ArcanaResult<Page<WebhookDelivery>> {
+        debug!(
+            "MySQL DAO: find deliveries of webhook subscription {} page={} size={}",
+            subscription_id, page.page, page.size
+        );
+        let subscription_id = subscription_id.into_inner().to_string();
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM webhook_deliveries WHERE subscription_id = ?")
+            .bind(&subscription_id)
+            .fetch_one(self.pool.inner())
+            .await?;
+
+        let rows = sqlx::query_as::<_, WebhookDeliveryRow>(
+            r#"
+            SELECT id, subscription_id, event_id, event_type, attempt, status, response_status,
+                   error, duration_ms, attempted_at
+            FROM webhook_deliveries WHERE subscription_id = ?
+            ORDER BY attempted_at DESC, id DESC
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(&subscription_id)
+        .bind(page.limit() as i64)
+        .bind(page.offset() as i64)
+        .fetch_all(self.pool.inner())
+        .await?;
+
+        let deliveries = rows.into_iter().map(WebhookDelivery::try_from).collect::<Result<_, _>>()?;
+        Ok(Page::new(deliveries, page.page, page.size, total as u64))
+    }
+}
+
+impl std::fmt::Debug for MySqlWebhookDaoImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MySqlWebhookDaoImpl").finish_non_exhaustive()
+    }
+}
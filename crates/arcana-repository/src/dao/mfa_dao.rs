@@ -0,0 +1,40 @@
+//! MfaDao trait — low-level multi-factor authentication data access abstraction.
+//!
+//! Backs the `totp_credentials` and `mfa_recovery_codes` tables. Recovery
+//! codes are stored by hash only; raw codes never reach this layer.
+//!
+//! [`MfaRepository`] uses an `MfaDao` to fulfil domain-level operations.
+//!
+//! [`MfaRepository`]: crate::traits::MfaRepository
+
+use arcana_core::{ArcanaResult, Interface, TotpCredential, UserId};
+use async_trait::async_trait;
+
+/// Low-level multi-factor authentication data access object.
+#[async_trait]
+pub trait MfaDao: Interface + Send + Sync {
+    /// Finds the TOTP credential of a user.
+    async fn find_totp_by_user(&self, user_id: UserId) -> ArcanaResult<Option<TotpCredential>>;
+
+    /// Inserts or replaces the TOTP credential of a user.
+    async fn save_totp(&self, credential: &TotpCredential) -> ArcanaResult<TotpCredential>;
+
+    /// Records the time step of an accepted code if it is newer than the last one.
+    ///
+    /// Returns `false` if a code from this or a later step was already
+    /// accepted, so a code cannot be used twice.
+    async fn record_totp_step(&self, user_id: UserId, step: i64) -> ArcanaResult<bool>;
+
+    /// Deletes the TOTP credential and recovery codes of a user.
+    ///
+    /// Returns `false` if the user had no TOTP credential.
+    async fn delete_by_user(&self, user_id: UserId) -> ArcanaResult<bool>;
+
+    /// Replaces every recovery code of a user with the given hashes.
+    async fn replace_recovery_codes(&self, user_id: UserId, code_hashes: &[String]) -> ArcanaResult<()>;
+
+    /// Marks an unused recovery code of a user as used.
+    ///
+    /// Returns `false` if no unused code has the given hash.
+    async fn consume_recovery_code(&self, user_id: UserId, code_hash: &str) -> ArcanaResult<bool>;
+}
@@ -7,11 +7,27 @@
 //!
 //! ```text
 //! dao/
-//!   user_dao.rs              ← UserDao trait
+//!   user_dao.rs                  ← UserDao trait
+//!   oauth_token_dao.rs           ← OAuthTokenDao trait
+//!   password_reset_token_dao.rs  ← PasswordResetTokenDao trait
+//!   mfa_dao.rs                   ← MfaDao trait
+//!   audit_log_dao.rs             ← AuditLogDao trait
+//!   outbox_dao.rs                ← OutboxDao trait
+//!   webhook_dao.rs               ← WebhookDao trait
+//!   role_dao.rs                  ← RoleDao trait
+//!   tenant_dao.rs                ← TenantDao trait
 //!   impl/
-//!     mod.rs                 ← pub use declarations
+//!     mod.rs                     ← pub use declarations
 //!     mysql/
-//!       user_dao_impl.rs     ← MySqlUserDaoImpl
+//!       user_dao_impl.rs         ← MySqlUserDaoImpl
+//!       oauth_token_dao_impl.rs  ← MySqlOAuthTokenDaoImpl
+//!       password_reset_token_dao_impl.rs ← MySqlPasswordResetTokenDaoImpl
+//!       mfa_dao_impl.rs          ← MySqlMfaDaoImpl
+//!       audit_log_dao_impl.rs    ← MySqlAuditLogDaoImpl
+//!       outbox_dao_impl.rs       ← MySqlOutboxDaoImpl
+//!       webhook_dao_impl.rs      ← MySqlWebhookDaoImpl
+//!       role_dao_impl.rs         ← MySqlRoleDaoImpl
+//!       tenant_dao_impl.rs       ← MySqlTenantDaoImpl
 //! ```
 //!
 //! Hierarchy:
@@ -19,8 +35,27 @@
 //! Service → Repository (interface + impl) → DAO (interface + impl) → DB/API
 //! ```
 
+pub mod audit_log_dao;
+pub mod mfa_dao;
+pub mod oauth_token_dao;
+pub mod outbox_dao;
+pub mod password_reset_token_dao;
+pub mod role_dao;
+pub mod tenant_dao;
 pub mod user_dao;
+pub mod webhook_dao;
 pub mod r#impl;
 
+pub use audit_log_dao::AuditLogDao;
+pub use mfa_dao::MfaDao;
+pub use oauth_token_dao::OAuthTokenDao;
+pub use outbox_dao::OutboxDao;
+pub use password_reset_token_dao::PasswordResetTokenDao;
+pub use role_dao::RoleDao;
+pub use tenant_dao::TenantDao;
 pub use user_dao::UserDao;
-pub use r#impl::MySqlUserDaoImpl;
+pub use webhook_dao::WebhookDao;
+pub use r#impl::{
+    MySqlAuditLogDaoImpl, MySqlMfaDaoImpl, MySqlOAuthTokenDaoImpl, MySqlOutboxDaoImpl, MySqlPasswordResetTokenDaoImpl,
+    MySqlRoleDaoImpl, MySqlTenantDaoImpl, MySqlUserDaoImpl, MySqlWebhookDaoImpl,
+};
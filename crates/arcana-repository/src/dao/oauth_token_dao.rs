@@ -0,0 +1,43 @@
+//! OAuthTokenDao trait — low-level refresh token data access abstraction.
+//!
+//! Backs the `oauth_tokens` table. Tokens are stored by hash only; the
+//! raw refresh token never reaches this layer.
+//!
+//! [`OAuthTokenRepository`] uses an `OAuthTokenDao` to fulfil
+//! domain-level operations.
+//!
+//! [`OAuthTokenRepository`]: crate::traits::OAuthTokenRepository
+
+use arcana_core::{ArcanaResult, Interface, OAuthToken, OAuthTokenId, UserId};
+use async_trait::async_trait;
+
+/// Low-level OAuth (refresh) token data access object.
+#[async_trait]
+pub trait OAuthTokenDao: Interface + Send + Sync {
+    /// Finds a token by ID.
+    async fn find_by_id(&self, id: OAuthTokenId) -> ArcanaResult<Option<OAuthToken>>;
+
+    /// Finds a token by its hash.
+    async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<OAuthToken>>;
+
+    /// Persists a new token.
+    async fn save(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken>;
+
+    /// Updates the mutable state of a token (revocation and usage).
+    async fn update(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken>;
+
+    /// Finds every live (non-revoked, unexpired) token of a user, newest first.
+    async fn find_active_by_user(&self, user_id: UserId) -> ArcanaResult<Vec<OAuthToken>>;
+
+    /// Revokes every non-revoked token of a user. Returns the number revoked.
+    async fn revoke_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64>;
+
+    /// Marks a token as used by rotation, revoking it if it is still live.
+    ///
+    /// Returns `false` if the token was already revoked, so concurrent
+    /// refreshes with the same token cannot both succeed.
+    async fn mark_rotated(&self, id: OAuthTokenId) -> ArcanaResult<bool>;
+
+    /// Revokes every non-revoked token in a family. Returns the number revoked.
+    async fn revoke_family(&self, family_id: &str) -> ArcanaResult<u64>;
+}
@@ -0,0 +1,26 @@
+//! OutboxDao trait — low-level transactional outbox data access abstraction.
+//!
+//! Backs the `outbox_events` table. Events are inserted by the DAOs of the
+//! changes that raised them, inside the same transaction; this DAO only reads
+//! pending events and records their delivery.
+//!
+//! [`OutboxRepository`] uses an `OutboxDao` to fulfil domain-level operations.
+//!
+//! [`OutboxRepository`]: crate::traits::OutboxRepository
+
+use arcana_core::{ArcanaResult, Interface, OutboxEvent, OutboxEventId};
+use async_trait::async_trait;
+
+/// Low-level outbox data access object.
+#[async_trait]
+pub trait OutboxDao: Interface + Send + Sync {
+    /// Finds up to `limit` undelivered events with fewer than `max_attempts`
+    /// failed delivery attempts, oldest first.
+    async fn find_pending(&self, limit: usize, max_attempts: u32) -> ArcanaResult<Vec<OutboxEvent>>;
+
+    /// Marks an event as delivered. Returns `false` if it was already marked.
+    async fn mark_dispatched(&self, id: OutboxEventId) -> ArcanaResult<bool>;
+
+    /// Records a failed delivery attempt.
+    async fn record_failure(&self, id: OutboxEventId, error: &str) -> ArcanaResult<()>;
+}
@@ -0,0 +1,34 @@
+//! PasswordResetTokenDao trait — low-level password reset token data access abstraction.
+//!
+//! Backs the `password_reset_tokens` table. Tokens are stored by hash only;
+//! the raw reset token never reaches this layer.
+//!
+//! [`PasswordResetTokenRepository`] uses a `PasswordResetTokenDao` to fulfil
+//! domain-level operations.
+//!
+//! [`PasswordResetTokenRepository`]: crate::traits::PasswordResetTokenRepository
+
+use arcana_core::{ArcanaResult, Interface, PasswordResetToken, PasswordResetTokenId, UserId};
+use async_trait::async_trait;
+
+/// Low-level password reset token data access object.
+#[async_trait]
+pub trait PasswordResetTokenDao: Interface + Send + Sync {
+    /// Finds a token by ID.
+    async fn find_by_id(&self, id: PasswordResetTokenId) -> ArcanaResult<Option<PasswordResetToken>>;
+
+    /// Finds a token by its hash.
+    async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<PasswordResetToken>>;
+
+    /// Persists a new token.
+    async fn save(&self, token: &PasswordResetToken) -> ArcanaResult<PasswordResetToken>;
+
+    /// Marks a token as used if it has not been used yet.
+    ///
+    /// Returns `false` if the token was already used, so concurrent resets
+    /// with the same token cannot both succeed.
+    async fn mark_used(&self, id: PasswordResetTokenId) -> ArcanaResult<bool>;
+
+    /// Marks every unused token of a user as used. Returns the number invalidated.
+    async fn invalidate_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64>;
+}
@@ -0,0 +1,51 @@
+//! RoleDao trait — low-level role data access abstraction.
+//!
+//! Backs the `roles` table, the custom roles assigned to users in
+//! `user_role_assignments` and the direct grants in `user_permissions`.
+//!
+//! [`RoleRepository`] uses a `RoleDao` to fulfil domain-level operations.
+//!
+//! [`RoleRepository`]: crate::traits::RoleRepository
+
+use arcana_core::{ArcanaResult, Interface, Permission, PermissionSet, Role, RoleId, UserId};
+use async_trait::async_trait;
+
+/// Low-level role data access object.
+#[async_trait]
+pub trait RoleDao: Interface + Send + Sync {
+    /// Finds a role by ID.
+    async fn find_by_id(&self, id: RoleId) -> ArcanaResult<Option<Role>>;
+
+    /// Finds a role by name.
+    async fn find_by_name(&self, name: &str) -> ArcanaResult<Option<Role>>;
+
+    /// Finds all roles, built-in roles first, then by name.
+    async fn find_all(&self) -> ArcanaResult<Vec<Role>>;
+
+    /// Inserts a new role.
+    async fn save(&self, role: &Role) -> ArcanaResult<Role>;
+
+    /// Updates an existing role.
+    async fn update(&self, role: &Role) -> ArcanaResult<Role>;
+
+    /// Deletes a role and its assignments. Returns `true` if it existed.
+    async fn delete(&self, id: RoleId) -> ArcanaResult<bool>;
+
+    /// Finds the custom roles assigned to a user, by name.
+    async fn find_assigned(&self, user_id: UserId) -> ArcanaResult<Vec<Role>>;
+
+    /// Assigns a role to a user; assigning it twice has no effect.
+    async fn assign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<()>;
+
+    /// Removes a role from a user. Returns `true` if it was assigned.
+    async fn unassign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<bool>;
+
+    /// Finds the permissions granted to a user directly.
+    async fn find_user_permissions(&self, user_id: UserId) -> ArcanaResult<PermissionSet>;
+
+    /// Grants a permission to a user; granting it twice has no effect.
+    async fn grant(&self, user_id: UserId, permission: Permission) -> ArcanaResult<()>;
+
+    /// Revokes a direct grant. Returns `true` if it was granted.
+    async fn revoke(&self, user_id: UserId, permission: Permission) -> ArcanaResult<bool>;
+}
@@ -0,0 +1,27 @@
+//! TenantDao trait — low-level tenant data access abstraction.
+//!
+//! [`TenantRepository`] uses a `TenantDao` to fulfil domain-level operations.
+//!
+//! [`TenantRepository`]: crate::traits::TenantRepository
+
+use arcana_core::{ArcanaResult, Interface, Page, PageRequest, Tenant, TenantId};
+use async_trait::async_trait;
+
+/// Low-level tenant data access object.
+#[async_trait]
+pub trait TenantDao: Interface + Send + Sync {
+    /// Finds a tenant by ID.
+    async fn find_by_id(&self, id: TenantId) -> ArcanaResult<Option<Tenant>>;
+
+    /// Finds a tenant by slug.
+    async fn find_by_slug(&self, slug: &str) -> ArcanaResult<Option<Tenant>>;
+
+    /// Finds a page of tenants, oldest first.
+    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<Tenant>>;
+
+    /// Inserts a new tenant.
+    async fn save(&self, tenant: &Tenant) -> ArcanaResult<Tenant>;
+
+    /// Updates an existing tenant.
+    async fn update(&self, tenant: &Tenant) -> ArcanaResult<Tenant>;
+}
@@ -9,12 +9,15 @@
 //!
 //! [`UserRepository`]: crate::traits::UserRepository
 
-use arcana_core::{ArcanaResult, Interface, Page, PageRequest, UserId};
-use arcana_core::{User, UserRole};
+use arcana_core::{ArcanaResult, Interface, Page, PageRequest, TenantId, UserId};
+use arcana_core::{OutboxEvent, User, UserRole};
 use async_trait::async_trait;
 
 /// Low-level user data access object.
 ///
+/// Lookups by username or email, listings and counts are scoped to a
+/// tenant; user IDs are unique across tenants.
+///
 /// Each implementation targets a single data source.
 /// Use [`crate::UserRepositoryImpl`] to coordinate multiple DAOs.
 #[async_trait]
@@ -22,26 +25,26 @@ pub trait UserDao: Interface + Send + Sync {
     /// Finds a user by ID.
     async fn find_by_id(&self, id: UserId) -> ArcanaResult<Option<User>>;
 
-    /// Finds a user by username.
-    async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>>;
+    /// Finds a user of a tenant by username.
+    async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>>;
 
-    /// Finds a user by email.
-    async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>>;
+    /// Finds a user of a tenant by email.
+    async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>>;
 
-    /// Finds a user by username or email.
-    async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>>;
+    /// Finds a user of a tenant by username or email.
+    async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>>;
 
-    /// Checks if a username already exists.
-    async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool>;
+    /// Checks if a username already exists in a tenant.
+    async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool>;
 
-    /// Checks if an email already exists.
-    async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool>;
+    /// Checks if an email already exists in a tenant.
+    async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool>;
 
-    /// Finds all users with pagination.
-    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<User>>;
+    /// Finds all users of a tenant with pagination.
+    async fn find_all(&self, tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>>;
 
-    /// Finds users by role.
-    async fn find_by_role(&self, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>>;
+    /// Finds users of a tenant by role.
+    async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>>;
 
     /// Persists a new user.
     async fn save(&self, user: &User) -> ArcanaResult<User>;
@@ -52,9 +55,19 @@ pub trait UserDao: Interface + Send + Sync {
     /// Deletes a user by ID. Returns `true` if deleted.
     async fn delete(&self, id: UserId) -> ArcanaResult<bool>;
 
-    /// Counts all users.
-    async fn count(&self) -> ArcanaResult<u64>;
+    /// Persists a new user and adds `events` to the outbox in the same transaction.
+    async fn save_with_events(&self, user: &User, events: &[OutboxEvent]) -> ArcanaResult<User>;
+
+    /// Updates an existing user and adds `events` to the outbox in the same transaction.
+    async fn update_with_events(&self, user: &User, events: &[OutboxEvent]) -> ArcanaResult<User>;
+
+    /// Deletes a user by ID and adds `events` to the outbox in the same
+    /// transaction. The events are discarded if there was no such user.
+    async fn delete_with_events(&self, id: UserId, events: &[OutboxEvent]) -> ArcanaResult<bool>;
+
+    /// Counts all users of a tenant.
+    async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64>;
 
-    /// Counts users by role.
-    async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64>;
+    /// Counts users of a tenant by role.
+    async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64>;
 }
@@ -0,0 +1,45 @@
+//! WebhookDao trait — low-level webhook data access abstraction.
+//!
+//! Backs the `webhook_subscriptions` table and the per-subscription delivery
+//! log in `webhook_deliveries`.
+//!
+//! [`WebhookRepository`] uses a `WebhookDao` to fulfil domain-level operations.
+//!
+//! [`WebhookRepository`]: crate::traits::WebhookRepository
+
+use arcana_core::{
+    ArcanaResult, Interface, Page, PageRequest, WebhookDelivery, WebhookSubscription, WebhookSubscriptionId,
+};
+use async_trait::async_trait;
+
+/// Low-level webhook data access object.
+#[async_trait]
+pub trait WebhookDao: Interface + Send + Sync {
+    /// Finds a subscription by ID.
+    async fn find_by_id(&self, id: WebhookSubscriptionId) -> ArcanaResult<Option<WebhookSubscription>>;
+
+    /// Finds a page of subscriptions, oldest first.
+    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<WebhookSubscription>>;
+
+    /// Finds all active subscriptions.
+    async fn find_active(&self) -> ArcanaResult<Vec<WebhookSubscription>>;
+
+    /// Inserts a new subscription.
+    async fn save(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription>;
+
+    /// Updates an existing subscription.
+    async fn update(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription>;
+
+    /// Deletes a subscription and its delivery log. Returns `true` if it existed.
+    async fn delete(&self, id: WebhookSubscriptionId) -> ArcanaResult<bool>;
+
+    /// Appends an attempt to the delivery log.
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> ArcanaResult<()>;
+
+    /// Finds a page of the delivery log of a subscription, newest first.
+    async fn find_deliveries(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        page: PageRequest,
+    ) -> ArcanaResult<Page<WebhookDelivery>>;
+}
@@ -0,0 +1,248 @@
+//! `AuditLogRepositoryImpl` — Repository layer implementation for the audit trail.
+//!
+//! Implements the [`AuditLogRepository`] domain interface on top of an [`AuditLogDao`].
+//!
+//! [`AuditLogRepository`]: crate::traits::AuditLogRepository
+//! [`AuditLogDao`]: crate::dao::AuditLogDao
+
+use crate::{dao::AuditLogDao, traits::AuditLogRepository};
+use arcana_core::{
+    ArcanaResult, AuditLog, AuditLogCursor, AuditLogFilter, AuditLogId, Page, PageRequest, UserId,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shaku::Component;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Repository implementation that orchestrates [`AuditLogDao`] access.
+///
+/// [`AuditLogDao`]: crate::dao::AuditLogDao
+#[derive(Component)]
+#[shaku(interface = AuditLogRepository)]
+pub struct AuditLogRepositoryImpl {
+    /// Primary data access object.
+    #[shaku(inject)]
+    audit_log_dao: Arc<dyn AuditLogDao>,
+}
+
+impl AuditLogRepositoryImpl {
+    /// Creates a new `AuditLogRepositoryImpl` with the given DAO.
+    #[must_use]
+    pub fn new(audit_log_dao: Arc<dyn AuditLogDao>) -> Self {
+        Self { audit_log_dao }
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for AuditLogRepositoryImpl {
+    async fn save(&self, entry: &AuditLog) -> ArcanaResult<AuditLog> {
+        debug!("Repository: save audit log {} ({})", entry.id, entry.action);
+        self.audit_log_dao.save(entry).await
+    }
+
+    async fn find_by_id(&self, id: AuditLogId) -> ArcanaResult<Option<AuditLog>> {
+        debug!("Repository: find audit log {}", id);
+        self.audit_log_dao.find_by_id(id).await
+    }
+
+    async fn find_by_user(&self, user_id: UserId, page: PageRequest) -> ArcanaResult<Page<AuditLog>> {
+        debug!("Repository: find audit logs of user {}", user_id);
+        self.audit_log_dao.find_by_user(user_id, page).await
+    }
+
+    async fn search(
+        &self,
+        filter: &AuditLogFilter,
+        after: Option<AuditLogCursor>,
+        limit: usize,
+    ) -> ArcanaResult<Vec<AuditLog>> {
+        debug!("Repository: search audit logs {:?} after {:?}", filter, after);
+        self.audit_log_dao.search(filter, after, limit).await
+    }
+
+    async fn chain_bounds(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> ArcanaResult<Option<(u64, u64)>> {
+        debug!("Repository: audit chain bounds from {:?} to {:?}", from, to);
+        self.audit_log_dao.chain_bounds(from, to).await
+    }
+
+    async fn find_chain(&self, from_sequence: u64, to_sequence: u64, limit: usize) -> ArcanaResult<Vec<AuditLog>> {
+        debug!("Repository: find audit chain {}..={}", from_sequence, to_sequence);
+        self.audit_log_dao.find_chain(from_sequence, to_sequence, limit).await
+    }
+}
+
+impl std::fmt::Debug for AuditLogRepositoryImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditLogRepositoryImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::AuditAction;
+    use std::sync::Mutex;
+
+    // =========================================================================
+    // Mock DAO implementation
+    // =========================================================================
+
+    #[derive(Default)]
+    struct MockAuditLogDao {
+        entries: Mutex<Vec<AuditLog>>,
+    }
+
+    impl std::fmt::Debug for MockAuditLogDao {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockAuditLogDao").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl AuditLogDao for MockAuditLogDao {
+        async fn save(&self, entry: &AuditLog) -> ArcanaResult<AuditLog> {
+            let mut entries = self.entries.lock().unwrap();
+            let mut entry = entry.clone();
+            entry.chain(entries.len() as u64 + 1, entries.last().and_then(|e| e.hash.clone()));
+            entries.push(entry.clone());
+            Ok(entry)
+        }
+
+        async fn find_by_id(&self, id: AuditLogId) -> ArcanaResult<Option<AuditLog>> {
+            Ok(self.entries.lock().unwrap().iter().find(|e| e.id == id).cloned())
+        }
+
+        async fn find_by_user(&self, user_id: UserId, page: PageRequest) -> ArcanaResult<Page<AuditLog>> {
+            let entries: Vec<AuditLog> = self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|e| e.user_id == Some(user_id))
+                .cloned()
+                .collect();
+            let total = entries.len() as u64;
+            let content = entries.into_iter().skip(page.offset()).take(page.limit()).collect();
+            Ok(Page::new(content, page.page, page.size, total))
+        }
+
+        async fn search(
+            &self,
+            filter: &AuditLogFilter,
+            after: Option<AuditLogCursor>,
+            limit: usize,
+        ) -> ArcanaResult<Vec<AuditLog>> {
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|e| filter.matches(e) && after.is_none_or(|cursor| cursor.precedes(e)))
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+
+        async fn chain_bounds(
+            &self,
+            from: Option<DateTime<Utc>>,
+            to: Option<DateTime<Utc>>,
+        ) -> ArcanaResult<Option<(u64, u64)>> {
+            let filter = AuditLogFilter { from, to, ..Default::default() };
+            let entries = self.entries.lock().unwrap();
+            let sequences = || entries.iter().filter(|e| filter.matches(e)).filter_map(|e| e.sequence);
+            Ok(sequences().min().zip(sequences().max()))
+        }
+
+        async fn find_chain(&self, from_sequence: u64, to_sequence: u64, limit: usize) -> ArcanaResult<Vec<AuditLog>> {
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|e| e.sequence.is_some_and(|s| (from_sequence..=to_sequence).contains(&s)))
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn make_repo() -> AuditLogRepositoryImpl {
+        AuditLogRepositoryImpl::new(Arc::new(MockAuditLogDao::default()))
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find_by_id() {
+        let repo = make_repo();
+        let entry = AuditLog::success(None, AuditAction::Login, "user", None).with_ip_address("10.0.0.1");
+        repo.save(&entry).await.unwrap();
+
+        let found = repo.find_by_id(entry.id).await.unwrap().unwrap();
+        assert_eq!(found.action, AuditAction::Login);
+        assert_eq!(found.ip_address, Some("10.0.0.1".to_string()));
+        assert!(repo.find_by_id(AuditLogId::new()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_by_user_newest_first() {
+        let repo = make_repo();
+        let user_id = UserId::new();
+        repo.save(&AuditLog::success(Some(user_id), AuditAction::Login, "user", None)).await.unwrap();
+        repo.save(&AuditLog::success(None, AuditAction::Login, "user", None)).await.unwrap();
+        repo.save(&AuditLog::success(Some(user_id), AuditAction::Logout, "user", None)).await.unwrap();
+
+        let page = repo.find_by_user(user_id, PageRequest::new(0, 10)).await.unwrap();
+        assert_eq!(page.total_elements(), 2);
+        assert_eq!(page.content[0].action, AuditAction::Logout);
+        assert_eq!(page.content[1].action, AuditAction::Login);
+    }
+
+    #[tokio::test]
+    async fn test_search_pages_with_cursor() {
+        let repo = make_repo();
+        for action in [AuditAction::Login, AuditAction::Logout, AuditAction::Login, AuditAction::Login] {
+            repo.save(&AuditLog::success(None, action, "user", None)).await.unwrap();
+        }
+        let filter = AuditLogFilter {
+            action: Some(AuditAction::Login),
+            ..Default::default()
+        };
+
+        let first = repo.search(&filter, None, 2).await.unwrap();
+        assert_eq!(first.len(), 2);
+        let rest = repo
+            .search(&filter, Some(AuditLogCursor::after(&first[1])), 2)
+            .await
+            .unwrap();
+        assert_eq!(rest.len(), 1);
+        assert!(rest.iter().all(|e| e.action == AuditAction::Login));
+        assert!(first.iter().all(|e| e.id != rest[0].id));
+    }
+
+    #[tokio::test]
+    async fn test_find_chain_in_sequence_order() {
+        let repo = make_repo();
+        for action in [AuditAction::Login, AuditAction::UserUpdate, AuditAction::Logout] {
+            repo.save(&AuditLog::success(None, action, "user", None)).await.unwrap();
+        }
+
+        assert_eq!(repo.chain_bounds(None, None).await.unwrap(), Some((1, 3)));
+        let chain = repo.find_chain(2, 3, 10).await.unwrap();
+        let actions: Vec<_> = chain.iter().map(|e| e.action).collect();
+        assert_eq!(actions, vec![AuditAction::UserUpdate, AuditAction::Logout]);
+        assert_eq!(chain[1].prev_hash, chain[0].hash);
+    }
+
+    #[test]
+    fn test_repository_debug() {
+        let repo = make_repo();
+        assert!(format!("{:?}", repo).contains("AuditLogRepositoryImpl"));
+    }
+}
@@ -0,0 +1,201 @@
+//! `MfaRepositoryImpl` — Repository layer implementation for multi-factor authentication.
+//!
+//! Implements the [`MfaRepository`] domain interface on top of an [`MfaDao`].
+//!
+//! [`MfaRepository`]: crate::traits::MfaRepository
+//! [`MfaDao`]: crate::dao::MfaDao
+
+use crate::{dao::MfaDao, traits::MfaRepository};
+use arcana_core::{ArcanaResult, TotpCredential, UserId};
+use async_trait::async_trait;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Repository implementation that orchestrates [`MfaDao`] access.
+///
+/// [`MfaDao`]: crate::dao::MfaDao
+#[derive(Component)]
+#[shaku(interface = MfaRepository)]
+pub struct MfaRepositoryImpl {
+    /// Primary data access object.
+    #[shaku(inject)]
+    mfa_dao: Arc<dyn MfaDao>,
+}
+
+impl MfaRepositoryImpl {
+    /// Creates a new `MfaRepositoryImpl` with the given DAO.
+    #[must_use]
+    pub fn new(mfa_dao: Arc<dyn MfaDao>) -> Self {
+        Self { mfa_dao }
+    }
+}
+
+#[async_trait]
+impl MfaRepository for MfaRepositoryImpl {
+    async fn find_totp_by_user(&self, user_id: UserId) -> ArcanaResult<Option<TotpCredential>> {
+        debug!("Repository: find TOTP credential for user {}", user_id);
+        self.mfa_dao.find_totp_by_user(user_id).await
+    }
+
+    async fn save_totp(&self, credential: &TotpCredential) -> ArcanaResult<TotpCredential> {
+        debug!("Repository: save TOTP credential for user {}", credential.user_id);
+        self.mfa_dao.save_totp(credential).await
+    }
+
+    async fn record_totp_step(&self, user_id: UserId, step: i64) -> ArcanaResult<bool> {
+        self.mfa_dao.record_totp_step(user_id, step).await
+    }
+
+    async fn delete_by_user(&self, user_id: UserId) -> ArcanaResult<bool> {
+        debug!("Repository: delete MFA data for user {}", user_id);
+        self.mfa_dao.delete_by_user(user_id).await
+    }
+
+    async fn replace_recovery_codes(&self, user_id: UserId, code_hashes: &[String]) -> ArcanaResult<()> {
+        debug!("Repository: replace recovery codes for user {}", user_id);
+        self.mfa_dao.replace_recovery_codes(user_id, code_hashes).await
+    }
+
+    async fn consume_recovery_code(&self, user_id: UserId, code_hash: &str) -> ArcanaResult<bool> {
+        debug!("Repository: consume recovery code for user {}", user_id);
+        self.mfa_dao.consume_recovery_code(user_id, code_hash).await
+    }
+}
+
+impl std::fmt::Debug for MfaRepositoryImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MfaRepositoryImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    // =========================================================================
+    // Mock DAO implementation
+    // =========================================================================
+
+    #[derive(Default)]
+    struct MockMfaDao {
+        credentials: Mutex<HashMap<UserId, TotpCredential>>,
+        /// (user, code hash) -> used
+        recovery_codes: Mutex<HashMap<(UserId, String), bool>>,
+    }
+
+    impl std::fmt::Debug for MockMfaDao {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockMfaDao").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl MfaDao for MockMfaDao {
+        async fn find_totp_by_user(&self, user_id: UserId) -> ArcanaResult<Option<TotpCredential>> {
+            Ok(self.credentials.lock().unwrap().get(&user_id).cloned())
+        }
+
+        async fn save_totp(&self, credential: &TotpCredential) -> ArcanaResult<TotpCredential> {
+            self.credentials.lock().unwrap().insert(credential.user_id, credential.clone());
+            Ok(credential.clone())
+        }
+
+        async fn record_totp_step(&self, user_id: UserId, step: i64) -> ArcanaResult<bool> {
+            match self.credentials.lock().unwrap().get_mut(&user_id) {
+                Some(credential) if credential.last_used_step.is_none_or(|last| last < step) => {
+                    credential.last_used_step = Some(step);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn delete_by_user(&self, user_id: UserId) -> ArcanaResult<bool> {
+            self.recovery_codes.lock().unwrap().retain(|(user, _), _| *user != user_id);
+            Ok(self.credentials.lock().unwrap().remove(&user_id).is_some())
+        }
+
+        async fn replace_recovery_codes(&self, user_id: UserId, code_hashes: &[String]) -> ArcanaResult<()> {
+            let mut codes = self.recovery_codes.lock().unwrap();
+            codes.retain(|(user, _), _| *user != user_id);
+            for hash in code_hashes {
+                codes.insert((user_id, hash.clone()), false);
+            }
+            Ok(())
+        }
+
+        async fn consume_recovery_code(&self, user_id: UserId, code_hash: &str) -> ArcanaResult<bool> {
+            match self.recovery_codes.lock().unwrap().get_mut(&(user_id, code_hash.to_string())) {
+                Some(used) if !*used => {
+                    *used = true;
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+    }
+
+    fn make_repo() -> MfaRepositoryImpl {
+        MfaRepositoryImpl::new(Arc::new(MockMfaDao::default()))
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find_totp() {
+        let repo = make_repo();
+        let user_id = UserId::new();
+        repo.save_totp(&TotpCredential::new(user_id, "SECRET".to_string())).await.unwrap();
+
+        let found = repo.find_totp_by_user(user_id).await.unwrap().unwrap();
+        assert_eq!(found.secret, "SECRET");
+        assert!(repo.find_totp_by_user(UserId::new()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_record_totp_step_rejects_replay() {
+        let repo = make_repo();
+        let user_id = UserId::new();
+        repo.save_totp(&TotpCredential::new(user_id, "SECRET".to_string())).await.unwrap();
+
+        assert!(repo.record_totp_step(user_id, 10).await.unwrap());
+        assert!(!repo.record_totp_step(user_id, 10).await.unwrap());
+        assert!(!repo.record_totp_step(user_id, 9).await.unwrap());
+        assert!(repo.record_totp_step(user_id, 11).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_recovery_codes_are_single_use_and_replaceable() {
+        let repo = make_repo();
+        let user_id = UserId::new();
+        repo.replace_recovery_codes(user_id, &["a".to_string(), "b".to_string()]).await.unwrap();
+
+        assert!(repo.consume_recovery_code(user_id, "a").await.unwrap());
+        assert!(!repo.consume_recovery_code(user_id, "a").await.unwrap());
+        assert!(!repo.consume_recovery_code(UserId::new(), "b").await.unwrap());
+
+        repo.replace_recovery_codes(user_id, &["c".to_string()]).await.unwrap();
+        assert!(!repo.consume_recovery_code(user_id, "b").await.unwrap());
+        assert!(repo.consume_recovery_code(user_id, "c").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_user_removes_everything() {
+        let repo = make_repo();
+        let user_id = UserId::new();
+        repo.save_totp(&TotpCredential::new(user_id, "SECRET".to_string())).await.unwrap();
+        repo.replace_recovery_codes(user_id, &["a".to_string()]).await.unwrap();
+
+        assert!(repo.delete_by_user(user_id).await.unwrap());
+        assert!(!repo.delete_by_user(user_id).await.unwrap());
+        assert!(repo.find_totp_by_user(user_id).await.unwrap().is_none());
+        assert!(!repo.consume_recovery_code(user_id, "a").await.unwrap());
+    }
+
+    #[test]
+    fn test_repository_debug() {
+        let repo = make_repo();
+        assert!(format!("{:?}", repo).contains("MfaRepositoryImpl"));
+    }
+}
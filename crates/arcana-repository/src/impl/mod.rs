@@ -3,6 +3,22 @@
 //! Trait definitions live in the parent module (`traits.rs`).
 //! This module contains concrete structs that implement those traits.
 
+pub mod audit_log_repository_impl;
+pub mod mfa_repository_impl;
+pub mod oauth_token_repository_impl;
+pub mod outbox_repository_impl;
+pub mod password_reset_token_repository_impl;
+pub mod role_repository_impl;
+pub mod tenant_repository_impl;
 pub mod user_repository_impl;
+pub mod webhook_repository_impl;
 
+pub use audit_log_repository_impl::AuditLogRepositoryImpl;
+pub use mfa_repository_impl::MfaRepositoryImpl;
+pub use oauth_token_repository_impl::OAuthTokenRepositoryImpl;
+pub use outbox_repository_impl::OutboxRepositoryImpl;
+pub use password_reset_token_repository_impl::PasswordResetTokenRepositoryImpl;
+pub use role_repository_impl::RoleRepositoryImpl;
+pub use tenant_repository_impl::TenantRepositoryImpl;
 pub use user_repository_impl::UserRepositoryImpl;
+pub use webhook_repository_impl::WebhookRepositoryImpl;
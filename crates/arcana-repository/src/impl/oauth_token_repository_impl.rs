@@ -0,0 +1,288 @@
+//! `OAuthTokenRepositoryImpl` — Repository layer implementation for refresh tokens.
+//!
+//! Implements the [`OAuthTokenRepository`] domain interface on top of an
+//! [`OAuthTokenDao`].
+//!
+//! [`OAuthTokenRepository`]: crate::traits::OAuthTokenRepository
+//! [`OAuthTokenDao`]: crate::dao::OAuthTokenDao
+
+use crate::{dao::OAuthTokenDao, traits::OAuthTokenRepository};
+use arcana_core::{ArcanaResult, OAuthToken, OAuthTokenId, UserId};
+use async_trait::async_trait;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Repository implementation that orchestrates [`OAuthTokenDao`] access.
+///
+/// [`OAuthTokenDao`]: crate::dao::OAuthTokenDao
+#[derive(Component)]
+#[shaku(interface = OAuthTokenRepository)]
+pub struct OAuthTokenRepositoryImpl {
+    /// Primary data access object.
+    #[shaku(inject)]
+    oauth_token_dao: Arc<dyn OAuthTokenDao>,
+}
+
+impl OAuthTokenRepositoryImpl {
+    /// Creates a new `OAuthTokenRepositoryImpl` with the given DAO.
+    #[must_use]
+    pub fn new(oauth_token_dao: Arc<dyn OAuthTokenDao>) -> Self {
+        Self { oauth_token_dao }
+    }
+}
+
+#[async_trait]
+impl OAuthTokenRepository for OAuthTokenRepositoryImpl {
+    async fn find_by_id(&self, id: OAuthTokenId) -> ArcanaResult<Option<OAuthToken>> {
+        debug!("Repository: find oauth token {}", id);
+        self.oauth_token_dao.find_by_id(id).await
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<OAuthToken>> {
+        self.oauth_token_dao.find_by_token_hash(token_hash).await
+    }
+
+    async fn save(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken> {
+        debug!("Repository: save oauth token for user {}", token.user_id);
+        self.oauth_token_dao.save(token).await
+    }
+
+    async fn update(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken> {
+        debug!("Repository: update oauth token {}", token.id);
+        self.oauth_token_dao.update(token).await
+    }
+
+    async fn find_active_by_user(&self, user_id: UserId) -> ArcanaResult<Vec<OAuthToken>> {
+        debug!("Repository: find active oauth tokens for user {}", user_id);
+        self.oauth_token_dao.find_active_by_user(user_id).await
+    }
+
+    async fn revoke_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64> {
+        debug!("Repository: revoke all oauth tokens for user {}", user_id);
+        self.oauth_token_dao.revoke_all_by_user(user_id).await
+    }
+
+    async fn mark_rotated(&self, id: OAuthTokenId) -> ArcanaResult<bool> {
+        debug!("Repository: mark oauth token {} rotated", id);
+        self.oauth_token_dao.mark_rotated(id).await
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> ArcanaResult<u64> {
+        debug!("Repository: revoke oauth token family {}", family_id);
+        self.oauth_token_dao.revoke_family(family_id).await
+    }
+}
+
+impl std::fmt::Debug for OAuthTokenRepositoryImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuthTokenRepositoryImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    // =========================================================================
+    // Mock DAO implementation
+    // =========================================================================
+
+    struct MockOAuthTokenDao {
+        tokens: Mutex<HashMap<OAuthTokenId, OAuthToken>>,
+    }
+
+    impl std::fmt::Debug for MockOAuthTokenDao {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockOAuthTokenDao").finish_non_exhaustive()
+        }
+    }
+
+    impl MockOAuthTokenDao {
+        fn new() -> Self {
+            Self {
+                tokens: Mutex::new(HashMap::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OAuthTokenDao for MockOAuthTokenDao {
+        async fn find_by_id(&self, id: OAuthTokenId) -> ArcanaResult<Option<OAuthToken>> {
+            Ok(self.tokens.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<OAuthToken>> {
+            Ok(self.tokens.lock().unwrap().values()
+                .find(|t| t.token_hash == token_hash)
+                .cloned())
+        }
+
+        async fn save(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken> {
+            self.tokens.lock().unwrap().insert(token.id, token.clone());
+            Ok(token.clone())
+        }
+
+        async fn update(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken> {
+            self.tokens.lock().unwrap().insert(token.id, token.clone());
+            Ok(token.clone())
+        }
+
+        async fn find_active_by_user(&self, user_id: UserId) -> ArcanaResult<Vec<OAuthToken>> {
+            let mut tokens: Vec<OAuthToken> = self.tokens.lock().unwrap().values()
+                .filter(|t| t.user_id == user_id && t.is_valid())
+                .cloned()
+                .collect();
+            tokens.sort_by_key(|t| std::cmp::Reverse(t.created_at));
+            Ok(tokens)
+        }
+
+        async fn revoke_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64> {
+            let mut revoked = 0;
+            for token in self.tokens.lock().unwrap().values_mut() {
+                if token.user_id == user_id && !token.revoked {
+                    token.revoke();
+                    revoked += 1;
+                }
+            }
+            Ok(revoked)
+        }
+
+        async fn mark_rotated(&self, id: OAuthTokenId) -> ArcanaResult<bool> {
+            match self.tokens.lock().unwrap().get_mut(&id) {
+                Some(token) if !token.revoked => {
+                    token.record_usage();
+                    token.revoke();
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn revoke_family(&self, family_id: &str) -> ArcanaResult<u64> {
+            let mut revoked = 0;
+            for token in self.tokens.lock().unwrap().values_mut() {
+                if token.family_id == family_id && !token.revoked {
+                    token.revoke();
+                    revoked += 1;
+                }
+            }
+            Ok(revoked)
+        }
+    }
+
+    fn make_token(user_id: UserId, hash: &str) -> OAuthToken {
+        make_family_token(user_id, hash, "family-1")
+    }
+
+    fn make_family_token(user_id: UserId, hash: &str, family_id: &str) -> OAuthToken {
+        OAuthToken::new(
+            user_id,
+            hash.to_string(),
+            family_id.to_string(),
+            Utc::now() + Duration::days(7),
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn make_repo() -> OAuthTokenRepositoryImpl {
+        OAuthTokenRepositoryImpl::new(Arc::new(MockOAuthTokenDao::new()))
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find_by_token_hash() {
+        let repo = make_repo();
+        let token = make_token(UserId::new(), "hash-1");
+        repo.save(&token).await.unwrap();
+
+        let found = repo.find_by_token_hash("hash-1").await.unwrap();
+        assert_eq!(found.map(|t| t.id), Some(token.id));
+        assert!(repo.find_by_token_hash("unknown").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_update_records_revocation() {
+        let repo = make_repo();
+        let mut token = make_token(UserId::new(), "hash-1");
+        repo.save(&token).await.unwrap();
+
+        token.revoke();
+        repo.update(&token).await.unwrap();
+
+        let found = repo.find_by_id(token.id).await.unwrap().unwrap();
+        assert!(found.revoked);
+        assert!(!found.is_valid());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_all_by_user_only_touches_that_user() {
+        let repo = make_repo();
+        let user_id = UserId::new();
+        let other_user = UserId::new();
+        repo.save(&make_token(user_id, "a")).await.unwrap();
+        repo.save(&make_token(user_id, "b")).await.unwrap();
+        repo.save(&make_token(other_user, "c")).await.unwrap();
+
+        assert_eq!(repo.revoke_all_by_user(user_id).await.unwrap(), 2);
+        assert_eq!(repo.revoke_all_by_user(user_id).await.unwrap(), 0);
+
+        let other = repo.find_by_token_hash("c").await.unwrap().unwrap();
+        assert!(other.is_valid());
+    }
+
+    #[tokio::test]
+    async fn test_find_active_by_user_skips_revoked_and_expired() {
+        let repo = make_repo();
+        let user_id = UserId::new();
+        let live = make_family_token(user_id, "a", "family-1");
+        let mut revoked = make_family_token(user_id, "b", "family-2");
+        revoked.revoke();
+        let mut expired = make_family_token(user_id, "c", "family-3");
+        expired.expires_at = Utc::now() - Duration::hours(1);
+        for token in [&live, &revoked, &expired, &make_token(UserId::new(), "d")] {
+            repo.save(token).await.unwrap();
+        }
+
+        let active = repo.find_active_by_user(user_id).await.unwrap();
+        assert_eq!(active.iter().map(|t| t.id).collect::<Vec<_>>(), vec![live.id]);
+    }
+
+    #[tokio::test]
+    async fn test_mark_rotated_only_succeeds_once() {
+        let repo = make_repo();
+        let token = make_token(UserId::new(), "hash-1");
+        repo.save(&token).await.unwrap();
+
+        assert!(repo.mark_rotated(token.id).await.unwrap());
+        assert!(!repo.mark_rotated(token.id).await.unwrap());
+
+        let found = repo.find_by_id(token.id).await.unwrap().unwrap();
+        assert!(found.revoked);
+        assert!(found.last_used_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_family_only_touches_that_family() {
+        let repo = make_repo();
+        let user_id = UserId::new();
+        repo.save(&make_family_token(user_id, "a", "family-1")).await.unwrap();
+        repo.save(&make_family_token(user_id, "b", "family-1")).await.unwrap();
+        repo.save(&make_family_token(user_id, "c", "family-2")).await.unwrap();
+
+        assert_eq!(repo.revoke_family("family-1").await.unwrap(), 2);
+
+        let other = repo.find_by_token_hash("c").await.unwrap().unwrap();
+        assert!(other.is_valid());
+    }
+
+    #[test]
+    fn test_repository_debug() {
+        let repo = make_repo();
+        assert!(format!("{:?}", repo).contains("OAuthTokenRepositoryImpl"));
+    }
+}
@@ -0,0 +1,137 @@
+//! `OutboxRepositoryImpl` — Repository layer implementation for the transactional outbox.
+//!
+//! Implements the [`OutboxRepository`] domain interface on top of an [`OutboxDao`].
+//!
+//! [`OutboxRepository`]: crate::traits::OutboxRepository
+//! [`OutboxDao`]: crate::dao::OutboxDao
+
+use crate::{dao::OutboxDao, traits::OutboxRepository};
+use arcana_core::{ArcanaResult, OutboxEvent, OutboxEventId};
+use async_trait::async_trait;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Repository implementation that orchestrates [`OutboxDao`] access.
+///
+/// [`OutboxDao`]: crate::dao::OutboxDao
+#[derive(Component)]
+#[shaku(interface = OutboxRepository)]
+pub struct OutboxRepositoryImpl {
+    /// Primary data access object.
+    #[shaku(inject)]
+    outbox_dao: Arc<dyn OutboxDao>,
+}
+
+impl OutboxRepositoryImpl {
+    /// Creates a new `OutboxRepositoryImpl` with the given DAO.
+    #[must_use]
+    pub fn new(outbox_dao: Arc<dyn OutboxDao>) -> Self {
+        Self { outbox_dao }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for OutboxRepositoryImpl {
+    async fn find_pending(&self, limit: usize, max_attempts: u32) -> ArcanaResult<Vec<OutboxEvent>> {
+        debug!("Repository: find pending outbox events limit={}", limit);
+        self.outbox_dao.find_pending(limit, max_attempts).await
+    }
+
+    async fn mark_dispatched(&self, id: OutboxEventId) -> ArcanaResult<bool> {
+        debug!("Repository: mark outbox event {} dispatched", id);
+        self.outbox_dao.mark_dispatched(id).await
+    }
+
+    async fn record_failure(&self, id: OutboxEventId, error: &str) -> ArcanaResult<()> {
+        debug!("Repository: record failed delivery of outbox event {}", id);
+        self.outbox_dao.record_failure(id, error).await
+    }
+}
+
+impl std::fmt::Debug for OutboxRepositoryImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OutboxRepositoryImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{UserCreated, UserId};
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockOutboxDao {
+        events: Mutex<Vec<OutboxEvent>>,
+    }
+
+    impl std::fmt::Debug for MockOutboxDao {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockOutboxDao").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl OutboxDao for MockOutboxDao {
+        async fn find_pending(&self, limit: usize, max_attempts: u32) -> ArcanaResult<Vec<OutboxEvent>> {
+            Ok(self
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|event| !event.is_dispatched() && event.attempts < max_attempts)
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+
+        async fn mark_dispatched(&self, id: OutboxEventId) -> ArcanaResult<bool> {
+            let mut events = self.events.lock().unwrap();
+            match events.iter_mut().find(|event| event.id == id && !event.is_dispatched()) {
+                Some(event) => {
+                    event.dispatched_at = Some(Utc::now());
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+
+        async fn record_failure(&self, id: OutboxEventId, error: &str) -> ArcanaResult<()> {
+            if let Some(event) = self.events.lock().unwrap().iter_mut().find(|event| event.id == id) {
+                event.attempts += 1;
+                event.last_error = Some(error.to_string());
+            }
+            Ok(())
+        }
+    }
+
+    fn outbox_event(username: &str) -> OutboxEvent {
+        let event = UserCreated::new(UserId::new(), username.to_string(), format!("{username}@example.com"));
+        OutboxEvent::from_event(&event).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dispatched_and_failing_events_are_no_longer_pending() {
+        let (first, second, third) = (outbox_event("a"), outbox_event("b"), outbox_event("c"));
+        let dao = MockOutboxDao::default();
+        dao.events.lock().unwrap().extend([first.clone(), second.clone(), third.clone()]);
+        let repo = OutboxRepositoryImpl::new(Arc::new(dao));
+
+        assert!(repo.mark_dispatched(first.id).await.unwrap());
+        assert!(!repo.mark_dispatched(first.id).await.unwrap());
+        repo.record_failure(second.id, "decode error").await.unwrap();
+
+        let pending = repo.find_pending(10, 1).await.unwrap();
+        assert_eq!(pending.iter().map(|event| event.id).collect::<Vec<_>>(), vec![third.id]);
+        assert_eq!(repo.find_pending(10, 2).await.unwrap().len(), 2);
+        assert_eq!(repo.find_pending(1, 2).await.unwrap()[0].id, second.id);
+    }
+
+    #[test]
+    fn test_repository_debug() {
+        let repo = OutboxRepositoryImpl::new(Arc::new(MockOutboxDao::default()));
+        assert!(format!("{:?}", repo).contains("OutboxRepositoryImpl"));
+    }
+}
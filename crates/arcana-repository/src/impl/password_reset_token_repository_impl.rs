@@ -0,0 +1,188 @@
+//! `PasswordResetTokenRepositoryImpl` — Repository layer implementation for password reset tokens.
+//!
+//! Implements the [`PasswordResetTokenRepository`] domain interface on top of a
+//! [`PasswordResetTokenDao`].
+//!
+//! [`PasswordResetTokenRepository`]: crate::traits::PasswordResetTokenRepository
+//! [`PasswordResetTokenDao`]: crate::dao::PasswordResetTokenDao
+
+use crate::{dao::PasswordResetTokenDao, traits::PasswordResetTokenRepository};
+use arcana_core::{ArcanaResult, PasswordResetToken, PasswordResetTokenId, UserId};
+use async_trait::async_trait;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Repository implementation that orchestrates [`PasswordResetTokenDao`] access.
+///
+/// [`PasswordResetTokenDao`]: crate::dao::PasswordResetTokenDao
+#[derive(Component)]
+#[shaku(interface = PasswordResetTokenRepository)]
+pub struct PasswordResetTokenRepositoryImpl {
+    /// Primary data access object.
+    #[shaku(inject)]
+    password_reset_token_dao: Arc<dyn PasswordResetTokenDao>,
+}
+
+impl PasswordResetTokenRepositoryImpl {
+    /// Creates a new `PasswordResetTokenRepositoryImpl` with the given DAO.
+    #[must_use]
+    pub fn new(password_reset_token_dao: Arc<dyn PasswordResetTokenDao>) -> Self {
+        Self { password_reset_token_dao }
+    }
+}
+
+#[async_trait]
+impl PasswordResetTokenRepository for PasswordResetTokenRepositoryImpl {
+    async fn find_by_id(&self, id: PasswordResetTokenId) -> ArcanaResult<Option<PasswordResetToken>> {
+        debug!("Repository: find password reset token {}", id);
+        self.password_reset_token_dao.find_by_id(id).await
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<PasswordResetToken>> {
+        self.password_reset_token_dao.find_by_token_hash(token_hash).await
+    }
+
+    async fn save(&self, token: &PasswordResetToken) -> ArcanaResult<PasswordResetToken> {
+        debug!("Repository: save password reset token for user {}", token.user_id);
+        self.password_reset_token_dao.save(token).await
+    }
+
+    async fn mark_used(&self, id: PasswordResetTokenId) -> ArcanaResult<bool> {
+        debug!("Repository: mark password reset token {} used", id);
+        self.password_reset_token_dao.mark_used(id).await
+    }
+
+    async fn invalidate_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64> {
+        debug!("Repository: invalidate password reset tokens for user {}", user_id);
+        self.password_reset_token_dao.invalidate_all_by_user(user_id).await
+    }
+}
+
+impl std::fmt::Debug for PasswordResetTokenRepositoryImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PasswordResetTokenRepositoryImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    // =========================================================================
+    // Mock DAO implementation
+    // =========================================================================
+
+    struct MockPasswordResetTokenDao {
+        tokens: Mutex<HashMap<PasswordResetTokenId, PasswordResetToken>>,
+    }
+
+    impl std::fmt::Debug for MockPasswordResetTokenDao {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockPasswordResetTokenDao").finish_non_exhaustive()
+        }
+    }
+
+    impl MockPasswordResetTokenDao {
+        fn new() -> Self {
+            Self {
+                tokens: Mutex::new(HashMap::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl PasswordResetTokenDao for MockPasswordResetTokenDao {
+        async fn find_by_id(&self, id: PasswordResetTokenId) -> ArcanaResult<Option<PasswordResetToken>> {
+            Ok(self.tokens.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<PasswordResetToken>> {
+            Ok(self.tokens.lock().unwrap().values()
+                .find(|t| t.token_hash == token_hash)
+                .cloned())
+        }
+
+        async fn save(&self, token: &PasswordResetToken) -> ArcanaResult<PasswordResetToken> {
+            self.tokens.lock().unwrap().insert(token.id, token.clone());
+            Ok(token.clone())
+        }
+
+        async fn mark_used(&self, id: PasswordResetTokenId) -> ArcanaResult<bool> {
+            match self.tokens.lock().unwrap().get_mut(&id) {
+                Some(token) if !token.is_used() => {
+                    token.mark_used();
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn invalidate_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64> {
+            let mut invalidated = 0;
+            for token in self.tokens.lock().unwrap().values_mut() {
+                if token.user_id == user_id && !token.is_used() {
+                    token.mark_used();
+                    invalidated += 1;
+                }
+            }
+            Ok(invalidated)
+        }
+    }
+
+    fn make_token(user_id: UserId, hash: &str) -> PasswordResetToken {
+        PasswordResetToken::new(user_id, hash.to_string(), Utc::now() + Duration::minutes(30))
+    }
+
+    fn make_repo() -> PasswordResetTokenRepositoryImpl {
+        PasswordResetTokenRepositoryImpl::new(Arc::new(MockPasswordResetTokenDao::new()))
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find_by_token_hash() {
+        let repo = make_repo();
+        let token = make_token(UserId::new(), "hash-1");
+        repo.save(&token).await.unwrap();
+
+        let found = repo.find_by_token_hash("hash-1").await.unwrap();
+        assert_eq!(found.map(|t| t.id), Some(token.id));
+        assert!(repo.find_by_token_hash("unknown").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_mark_used_only_succeeds_once() {
+        let repo = make_repo();
+        let token = make_token(UserId::new(), "hash-1");
+        repo.save(&token).await.unwrap();
+
+        assert!(repo.mark_used(token.id).await.unwrap());
+        assert!(!repo.mark_used(token.id).await.unwrap());
+
+        let found = repo.find_by_id(token.id).await.unwrap().unwrap();
+        assert!(!found.is_valid());
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_all_by_user_only_touches_that_user() {
+        let repo = make_repo();
+        let user_id = UserId::new();
+        repo.save(&make_token(user_id, "a")).await.unwrap();
+        repo.save(&make_token(user_id, "b")).await.unwrap();
+        repo.save(&make_token(UserId::new(), "c")).await.unwrap();
+
+        assert_eq!(repo.invalidate_all_by_user(user_id).await.unwrap(), 2);
+        assert_eq!(repo.invalidate_all_by_user(user_id).await.unwrap(), 0);
+
+        let other = repo.find_by_token_hash("c").await.unwrap().unwrap();
+        assert!(other.is_valid());
+    }
+
+    #[test]
+    fn test_repository_debug() {
+        let repo = make_repo();
+        assert!(format!("{:?}", repo).contains("PasswordResetTokenRepositoryImpl"));
+    }
+}
@@ -0,0 +1,235 @@
+//! `RoleRepositoryImpl` — Repository layer implementation for roles.
+//!
+//! Implements the [`RoleRepository`] domain interface on top of a [`RoleDao`].
+//!
+//! [`RoleRepository`]: crate::traits::RoleRepository
+//! [`RoleDao`]: crate::dao::RoleDao
+
+use crate::{dao::RoleDao, traits::RoleRepository};
+use arcana_core::{ArcanaResult, Permission, PermissionSet, Role, RoleId, UserId, UserRole};
+use async_trait::async_trait;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Repository implementation that orchestrates [`RoleDao`] access.
+///
+/// [`RoleDao`]: crate::dao::RoleDao
+#[derive(Component)]
+#[shaku(interface = RoleRepository)]
+pub struct RoleRepositoryImpl {
+    /// Primary data access object.
+    #[shaku(inject)]
+    role_dao: Arc<dyn RoleDao>,
+}
+
+impl RoleRepositoryImpl {
+    /// Creates a new `RoleRepositoryImpl` with the given DAO.
+    #[must_use]
+    pub fn new(role_dao: Arc<dyn RoleDao>) -> Self {
+        Self { role_dao }
+    }
+}
+
+#[async_trait]
+impl RoleRepository for RoleRepositoryImpl {
+    async fn find_by_id(&self, id: RoleId) -> ArcanaResult<Option<Role>> {
+        debug!("Repository: find role {}", id);
+        self.role_dao.find_by_id(id).await
+    }
+
+    async fn find_by_name(&self, name: &str) -> ArcanaResult<Option<Role>> {
+        debug!("Repository: find role by name {}", name);
+        self.role_dao.find_by_name(name).await
+    }
+
+    async fn find_all(&self) -> ArcanaResult<Vec<Role>> {
+        debug!("Repository: find roles");
+        self.role_dao.find_all().await
+    }
+
+    async fn save(&self, role: &Role) -> ArcanaResult<Role> {
+        debug!("Repository: save role {}", role.name);
+        self.role_dao.save(role).await
+    }
+
+    async fn update(&self, role: &Role) -> ArcanaResult<Role> {
+        debug!("Repository: update role {}", role.id);
+        self.role_dao.update(role).await
+    }
+
+    async fn delete(&self, id: RoleId) -> ArcanaResult<bool> {
+        debug!("Repository: delete role {}", id);
+        self.role_dao.delete(id).await
+    }
+
+    async fn find_assigned(&self, user_id: UserId) -> ArcanaResult<Vec<Role>> {
+        debug!("Repository: find roles assigned to user {}", user_id);
+        self.role_dao.find_assigned(user_id).await
+    }
+
+    async fn assign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<()> {
+        debug!("Repository: assign role {} to user {}", role_id, user_id);
+        self.role_dao.assign(user_id, role_id).await
+    }
+
+    async fn unassign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<bool> {
+        debug!("Repository: unassign role {} from user {}", role_id, user_id);
+        self.role_dao.unassign(user_id, role_id).await
+    }
+
+    async fn find_user_permissions(&self, user_id: UserId) -> ArcanaResult<PermissionSet> {
+        debug!("Repository: find permissions granted to user {}", user_id);
+        self.role_dao.find_user_permissions(user_id).await
+    }
+
+    async fn grant(&self, user_id: UserId, permission: Permission) -> ArcanaResult<()> {
+        debug!("Repository: grant {} to user {}", permission, user_id);
+        self.role_dao.grant(user_id, permission).await
+    }
+
+    async fn revoke(&self, user_id: UserId, permission: Permission) -> ArcanaResult<bool> {
+        debug!("Repository: revoke {} from user {}", permission, user_id);
+        self.role_dao.revoke(user_id, permission).await
+    }
+
+    async fn find_effective_permissions(&self, user_id: UserId, role: UserRole) -> ArcanaResult<PermissionSet> {
+        debug!("Repository: resolve permissions of user {}", user_id);
+        let mut permissions = match self.role_dao.find_by_name(&role.to_string()).await? {
+            Some(built_in) => built_in.permissions,
+            None => {
+                warn!("Built-in role {} is missing, using its default grants", role);
+                role.default_permissions()
+            }
+        };
+        for assigned in self.role_dao.find_assigned(user_id).await? {
+            permissions.extend(assigned.permissions);
+        }
+        permissions.extend(self.role_dao.find_user_permissions(user_id).await?);
+        Ok(permissions)
+    }
+}
+
+impl std::fmt::Debug for RoleRepositoryImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RoleRepositoryImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockRoleDao {
+        roles: Mutex<Vec<Role>>,
+        assignments: Mutex<Vec<(UserId, RoleId)>>,
+        grants: Mutex<HashMap<UserId, PermissionSet>>,
+    }
+
+    impl std::fmt::Debug for MockRoleDao {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockRoleDao").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl RoleDao for MockRoleDao {
+        async fn find_by_id(&self, id: RoleId) -> ArcanaResult<Option<Role>> {
+            Ok(self.roles.lock().unwrap().iter().find(|r| r.id == id).cloned())
+        }
+
+        async fn find_by_name(&self, name: &str) -> ArcanaResult<Option<Role>> {
+            Ok(self.roles.lock().unwrap().iter().find(|r| r.name == name).cloned())
+        }
+
+        async fn find_all(&self) -> ArcanaResult<Vec<Role>> {
+            Ok(self.roles.lock().unwrap().clone())
+        }
+
+        async fn save(&self, role: &Role) -> ArcanaResult<Role> {
+            self.roles.lock().unwrap().push(role.clone());
+            Ok(role.clone())
+        }
+
+        async fn update(&self, role: &Role) -> ArcanaResult<Role> {
+            Ok(role.clone())
+        }
+
+        async fn delete(&self, id: RoleId) -> ArcanaResult<bool> {
+            let mut roles = self.roles.lock().unwrap();
+            let before = roles.len();
+            roles.retain(|r| r.id != id);
+            Ok(roles.len() != before)
+        }
+
+        async fn find_assigned(&self, user_id: UserId) -> ArcanaResult<Vec<Role>> {
+            let assignments = self.assignments.lock().unwrap();
+            Ok(self
+                .roles
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| assignments.contains(&(user_id, r.id)))
+                .cloned()
+                .collect())
+        }
+
+        async fn assign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<()> {
+            self.assignments.lock().unwrap().push((user_id, role_id));
+            Ok(())
+        }
+
+        async fn unassign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<bool> {
+            let mut assignments = self.assignments.lock().unwrap();
+            let before = assignments.len();
+            assignments.retain(|a| *a != (user_id, role_id));
+            Ok(assignments.len() != before)
+        }
+
+        async fn find_user_permissions(&self, user_id: UserId) -> ArcanaResult<PermissionSet> {
+            Ok(self.grants.lock().unwrap().get(&user_id).cloned().unwrap_or_default())
+        }
+
+        async fn grant(&self, user_id: UserId, permission: Permission) -> ArcanaResult<()> {
+            self.grants.lock().unwrap().entry(user_id).or_default().insert(permission);
+            Ok(())
+        }
+
+        async fn revoke(&self, user_id: UserId, permission: Permission) -> ArcanaResult<bool> {
+            Ok(self.grants.lock().unwrap().entry(user_id).or_default().remove(permission))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_effective_permissions_combine_all_grants() {
+        let repo = RoleRepositoryImpl::new(Arc::new(MockRoleDao::default()));
+        let mut user_role = Role::new("user".to_string(), None, [Permission::UserRead].into_iter().collect());
+        user_role.built_in = true;
+        let support = Role::new("support".to_string(), None, [Permission::UserUpdate].into_iter().collect());
+        repo.save(&user_role).await.unwrap();
+        repo.save(&support).await.unwrap();
+
+        let agent = UserId::new();
+        repo.assign(agent, support.id).await.unwrap();
+        repo.grant(agent, Permission::SystemMonitor).await.unwrap();
+
+        let permissions = repo.find_effective_permissions(agent, UserRole::User).await.unwrap();
+        let expected: PermissionSet = [Permission::UserRead, Permission::UserUpdate, Permission::SystemMonitor]
+            .into_iter()
+            .collect();
+        assert_eq!(permissions, expected);
+
+        let other = repo.find_effective_permissions(UserId::new(), UserRole::User).await.unwrap();
+        assert_eq!(other, [Permission::UserRead].into_iter().collect());
+    }
+
+    #[tokio::test]
+    async fn test_missing_built_in_role_falls_back_to_defaults() {
+        let repo = RoleRepositoryImpl::new(Arc::new(MockRoleDao::default()));
+        let permissions = repo.find_effective_permissions(UserId::new(), UserRole::Moderator).await.unwrap();
+        assert_eq!(permissions, UserRole::Moderator.default_permissions());
+    }
+}
@@ -0,0 +1,123 @@
+//! `TenantRepositoryImpl` — Repository layer implementation for tenants.
+//!
+//! Implements the [`TenantRepository`] domain interface on top of a [`TenantDao`].
+//!
+//! [`TenantRepository`]: crate::traits::TenantRepository
+//! [`TenantDao`]: crate::dao::TenantDao
+
+use crate::{dao::TenantDao, traits::TenantRepository};
+use arcana_core::{ArcanaResult, Page, PageRequest, Tenant, TenantId};
+use async_trait::async_trait;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Repository implementation that orchestrates [`TenantDao`] access.
+///
+/// Slugs are stored lowercase, so lookups by slug are case-insensitive.
+///
+/// [`TenantDao`]: crate::dao::TenantDao
+#[derive(Component)]
+#[shaku(interface = TenantRepository)]
+pub struct TenantRepositoryImpl {
+    /// Primary data access object.
+    #[shaku(inject)]
+    tenant_dao: Arc<dyn TenantDao>,
+}
+
+impl TenantRepositoryImpl {
+    /// Creates a new `TenantRepositoryImpl` with the given DAO.
+    #[must_use]
+    pub fn new(tenant_dao: Arc<dyn TenantDao>) -> Self {
+        Self { tenant_dao }
+    }
+}
+
+#[async_trait]
+impl TenantRepository for TenantRepositoryImpl {
+    async fn find_by_id(&self, id: TenantId) -> ArcanaResult<Option<Tenant>> {
+        debug!("Repository: find tenant {}", id);
+        self.tenant_dao.find_by_id(id).await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> ArcanaResult<Option<Tenant>> {
+        debug!("Repository: find tenant by slug {}", slug);
+        self.tenant_dao.find_by_slug(&slug.to_ascii_lowercase()).await
+    }
+
+    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<Tenant>> {
+        debug!("Repository: find tenants page={} size={}", page.page, page.size);
+        self.tenant_dao.find_all(page).await
+    }
+
+    async fn save(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+        debug!("Repository: save tenant {}", tenant.slug);
+        let mut tenant = tenant.clone();
+        tenant.slug = tenant.slug.to_ascii_lowercase();
+        self.tenant_dao.save(&tenant).await
+    }
+
+    async fn update(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+        debug!("Repository: update tenant {}", tenant.id);
+        self.tenant_dao.update(tenant).await
+    }
+}
+
+impl std::fmt::Debug for TenantRepositoryImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TenantRepositoryImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockTenantDao {
+        tenants: Mutex<Vec<Tenant>>,
+    }
+
+    impl std::fmt::Debug for MockTenantDao {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockTenantDao").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl TenantDao for MockTenantDao {
+        async fn find_by_id(&self, id: TenantId) -> ArcanaResult<Option<Tenant>> {
+            Ok(self.tenants.lock().unwrap().iter().find(|t| t.id == id).cloned())
+        }
+
+        async fn find_by_slug(&self, slug: &str) -> ArcanaResult<Option<Tenant>> {
+            Ok(self.tenants.lock().unwrap().iter().find(|t| t.slug == slug).cloned())
+        }
+
+        async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<Tenant>> {
+            let tenants = self.tenants.lock().unwrap().clone();
+            let total = tenants.len() as u64;
+            Ok(Page::new(tenants, page.page, page.size, total))
+        }
+
+        async fn save(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+            self.tenants.lock().unwrap().push(tenant.clone());
+            Ok(tenant.clone())
+        }
+
+        async fn update(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+            Ok(tenant.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slugs_are_case_insensitive() {
+        let repo = TenantRepositoryImpl::new(Arc::new(MockTenantDao::default()));
+        let saved = repo.save(&Tenant::new("Acme".to_string(), "Acme Corp".to_string())).await.unwrap();
+        assert_eq!(saved.slug, "acme");
+
+        let found = repo.find_by_slug("ACME").await.unwrap().unwrap();
+        assert_eq!(found.id, saved.id);
+    }
+}
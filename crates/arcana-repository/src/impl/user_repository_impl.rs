@@ -19,7 +19,7 @@
 //! [`UserDao`]: crate::dao::UserDao
 
 use crate::{dao::UserDao, traits::UserRepository};
-use arcana_core::{ArcanaResult, Page, PageRequest, UserId};
+use arcana_core::{ArcanaResult, DomainEvent, OutboxEvent, Page, PageRequest, TenantId, UserId};
 use arcana_core::{User, UserRole};
 use async_trait::async_trait;
 use shaku::Component;
@@ -55,37 +55,37 @@ impl UserRepository for UserRepositoryImpl {
         self.user_dao.find_by_id(id).await
     }
 
-    async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>> {
+    async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>> {
         debug!("Repository: find_by_username {}", username);
-        self.user_dao.find_by_username(username).await
+        self.user_dao.find_by_username(tenant_id, username).await
     }
 
-    async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>> {
+    async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>> {
         debug!("Repository: find_by_email {}", email);
-        self.user_dao.find_by_email(email).await
+        self.user_dao.find_by_email(tenant_id, email).await
     }
 
-    async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>> {
+    async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>> {
         debug!("Repository: find_by_username_or_email {}", identifier);
-        self.user_dao.find_by_username_or_email(identifier).await
+        self.user_dao.find_by_username_or_email(tenant_id, identifier).await
     }
 
-    async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool> {
-        self.user_dao.exists_by_username(username).await
+    async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool> {
+        self.user_dao.exists_by_username(tenant_id, username).await
     }
 
-    async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool> {
-        self.user_dao.exists_by_email(email).await
+    async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool> {
+        self.user_dao.exists_by_email(tenant_id, email).await
     }
 
-    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<User>> {
-        debug!("Repository: find_all page={}", page.page);
-        self.user_dao.find_all(page).await
+    async fn find_all(&self, tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>> {
+        debug!("Repository: find_all in tenant {} page={}", tenant_id, page.page);
+        self.user_dao.find_all(tenant_id, page).await
     }
 
-    async fn find_by_role(&self, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
+    async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
         debug!("Repository: find_by_role {:?}", role);
-        self.user_dao.find_by_role(role, page).await
+        self.user_dao.find_by_role(tenant_id, role, page).await
     }
 
     async fn save(&self, user: &User) -> ArcanaResult<User> {
@@ -103,13 +103,37 @@ impl UserRepository for UserRepositoryImpl {
         self.user_dao.delete(id).await
     }
 
-    async fn count(&self) -> ArcanaResult<u64> {
-        self.user_dao.count().await
+    async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64> {
+        self.user_dao.count(tenant_id).await
     }
 
-    async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64> {
-        self.user_dao.count_by_role(role).await
+    async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64> {
+        self.user_dao.count_by_role(tenant_id, role).await
     }
+
+    fn stores_events(&self) -> bool {
+        true
+    }
+
+    async fn save_with_events(&self, user: &User, events: &[Box<dyn DomainEvent>]) -> ArcanaResult<User> {
+        debug!("Repository: save user {} with {} events", user.username, events.len());
+        self.user_dao.save_with_events(user, &outbox_events(events)?).await
+    }
+
+    async fn update_with_events(&self, user: &User, events: &[Box<dyn DomainEvent>]) -> ArcanaResult<User> {
+        debug!("Repository: update user {} with {} events", user.id, events.len());
+        self.user_dao.update_with_events(user, &outbox_events(events)?).await
+    }
+
+    async fn delete_with_events(&self, id: UserId, events: &[Box<dyn DomainEvent>]) -> ArcanaResult<bool> {
+        debug!("Repository: delete user {} with {} events", id, events.len());
+        self.user_dao.delete_with_events(id, &outbox_events(events)?).await
+    }
+}
+
+/// Converts events into outbox entries.
+fn outbox_events(events: &[Box<dyn DomainEvent>]) -> ArcanaResult<Vec<OutboxEvent>> {
+    events.iter().map(|event| OutboxEvent::from_event(event.as_ref())).collect()
 }
 
 impl std::fmt::Debug for UserRepositoryImpl {
@@ -123,7 +147,8 @@ mod tests {
     use super::*;
     use crate::dao::UserDao;
     use crate::traits::UserRepository;
-    use arcana_core::{ArcanaResult, Email, Page, PageRequest, User, UserRole, UserId};
+    use arcana_core::{ArcanaResult, Email, Page, PageRequest, TenantId, User, UserRole, UserId};
+    use arcana_core::{UserDeleted, UserRoleChanged};
     use async_trait::async_trait;
     use std::collections::HashMap;
     use std::sync::{Arc, Mutex};
@@ -134,6 +159,7 @@ mod tests {
 
     struct MockUserDao {
         users: Mutex<HashMap<UserId, User>>,
+        outbox: Mutex<Vec<OutboxEvent>>,
     }
 
     impl std::fmt::Debug for MockUserDao {
@@ -146,6 +172,7 @@ mod tests {
         fn new() -> Self {
             Self {
                 users: Mutex::new(HashMap::new()),
+                outbox: Mutex::new(Vec::new()),
             }
         }
 
@@ -170,35 +197,38 @@ mod tests {
             Ok(self.users.lock().unwrap().get(&id).cloned())
         }
 
-        async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.username == username)
+                .find(|u| u.tenant_id == tenant_id && u.username == username)
                 .cloned())
         }
 
-        async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.email.as_str() == email)
+                .find(|u| u.tenant_id == tenant_id && u.email.as_str() == email)
                 .cloned())
         }
 
-        async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.username == identifier || u.email.as_str() == identifier)
+                .find(|u| u.tenant_id == tenant_id && (u.username == identifier || u.email.as_str() == identifier))
                 .cloned())
         }
 
-        async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool> {
-            Ok(self.users.lock().unwrap().values().any(|u| u.username == username))
+        async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool> {
+            Ok(self.users.lock().unwrap().values().any(|u| u.tenant_id == tenant_id && u.username == username))
         }
 
-        async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool> {
+        async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool> {
             Ok(self.users.lock().unwrap().values()
-                .any(|u| u.email.as_str().to_lowercase() == email.to_lowercase()))
+                .any(|u| u.tenant_id == tenant_id && u.email.as_str().to_lowercase() == email.to_lowercase()))
         }
 
-        async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<User>> {
-            let users: Vec<User> = self.users.lock().unwrap().values().cloned().collect();
+        async fn find_all(&self, tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>> {
+            let users: Vec<User> = self.users.lock().unwrap().values()
+                .filter(|u| u.tenant_id == tenant_id)
+                .cloned()
+                .collect();
             let total = users.len() as u64;
             let start = page.offset();
             let end = std::cmp::min(start + page.limit(), users.len());
@@ -206,9 +236,9 @@ mod tests {
             Ok(Page::new(items, page.page, page.size, total))
         }
 
-        async fn find_by_role(&self, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
+        async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
             let users: Vec<User> = self.users.lock().unwrap().values()
-                .filter(|u| u.role == role)
+                .filter(|u| u.tenant_id == tenant_id && u.role == role)
                 .cloned()
                 .collect();
             let total = users.len() as u64;
@@ -232,15 +262,35 @@ mod tests {
             Ok(self.users.lock().unwrap().remove(&id).is_some())
         }
 
-        async fn count(&self) -> ArcanaResult<u64> {
-            Ok(self.users.lock().unwrap().len() as u64)
+        async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64> {
+            Ok(self.users.lock().unwrap().values()
+                .filter(|u| u.tenant_id == tenant_id)
+                .count() as u64)
         }
 
-        async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64> {
+        async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64> {
             Ok(self.users.lock().unwrap().values()
-                .filter(|u| u.role == role)
+                .filter(|u| u.tenant_id == tenant_id && u.role == role)
                 .count() as u64)
         }
+
+        async fn save_with_events(&self, user: &User, events: &[OutboxEvent]) -> ArcanaResult<User> {
+            self.outbox.lock().unwrap().extend_from_slice(events);
+            self.save(user).await
+        }
+
+        async fn update_with_events(&self, user: &User, events: &[OutboxEvent]) -> ArcanaResult<User> {
+            self.outbox.lock().unwrap().extend_from_slice(events);
+            self.update(user).await
+        }
+
+        async fn delete_with_events(&self, id: UserId, events: &[OutboxEvent]) -> ArcanaResult<bool> {
+            let deleted = self.delete(id).await?;
+            if deleted {
+                self.outbox.lock().unwrap().extend_from_slice(events);
+            }
+            Ok(deleted)
+        }
     }
 
     // =========================================================================
@@ -290,7 +340,7 @@ mod tests {
         let user = create_test_user("bob", "bob@example.com");
         let repo = create_repo(MockUserDao::with_user(user));
 
-        let result = repo.find_by_username("bob").await.unwrap();
+        let result = repo.find_by_username(TenantId::DEFAULT, "bob").await.unwrap();
         assert!(result.is_some());
         assert_eq!(result.unwrap().email.as_str(), "bob@example.com");
     }
@@ -298,7 +348,7 @@ mod tests {
     #[tokio::test]
     async fn test_find_by_username_not_found() {
         let repo = create_repo(MockUserDao::new());
-        let result = repo.find_by_username("nobody").await.unwrap();
+        let result = repo.find_by_username(TenantId::DEFAULT, "nobody").await.unwrap();
         assert!(result.is_none());
     }
 
@@ -307,7 +357,7 @@ mod tests {
         let user = create_test_user("carol", "carol@example.com");
         let repo = create_repo(MockUserDao::with_user(user));
 
-        let result = repo.find_by_email("carol@example.com").await.unwrap();
+        let result = repo.find_by_email(TenantId::DEFAULT, "carol@example.com").await.unwrap();
         assert!(result.is_some());
         assert_eq!(result.unwrap().username, "carol");
     }
@@ -315,7 +365,7 @@ mod tests {
     #[tokio::test]
     async fn test_find_by_email_not_found() {
         let repo = create_repo(MockUserDao::new());
-        let result = repo.find_by_email("ghost@example.com").await.unwrap();
+        let result = repo.find_by_email(TenantId::DEFAULT, "ghost@example.com").await.unwrap();
         assert!(result.is_none());
     }
 
@@ -324,7 +374,7 @@ mod tests {
         let user = create_test_user("dave", "dave@example.com");
         let repo = create_repo(MockUserDao::with_user(user));
 
-        let result = repo.find_by_username_or_email("dave").await.unwrap();
+        let result = repo.find_by_username_or_email(TenantId::DEFAULT, "dave").await.unwrap();
         assert!(result.is_some());
     }
 
@@ -333,7 +383,7 @@ mod tests {
         let user = create_test_user("eve", "eve@example.com");
         let repo = create_repo(MockUserDao::with_user(user));
 
-        let result = repo.find_by_username_or_email("eve@example.com").await.unwrap();
+        let result = repo.find_by_username_or_email(TenantId::DEFAULT, "eve@example.com").await.unwrap();
         assert!(result.is_some());
         assert_eq!(result.unwrap().username, "eve");
     }
@@ -343,13 +393,13 @@ mod tests {
         let user = create_test_user("frank", "frank@example.com");
         let repo = create_repo(MockUserDao::with_user(user));
 
-        assert!(repo.exists_by_username("frank").await.unwrap());
+        assert!(repo.exists_by_username(TenantId::DEFAULT, "frank").await.unwrap());
     }
 
     #[tokio::test]
     async fn test_exists_by_username_false() {
         let repo = create_repo(MockUserDao::new());
-        assert!(!repo.exists_by_username("nobody").await.unwrap());
+        assert!(!repo.exists_by_username(TenantId::DEFAULT, "nobody").await.unwrap());
     }
 
     #[tokio::test]
@@ -357,19 +407,19 @@ mod tests {
         let user = create_test_user("grace", "grace@example.com");
         let repo = create_repo(MockUserDao::with_user(user));
 
-        assert!(repo.exists_by_email("grace@example.com").await.unwrap());
+        assert!(repo.exists_by_email(TenantId::DEFAULT, "grace@example.com").await.unwrap());
     }
 
     #[tokio::test]
     async fn test_exists_by_email_false() {
         let repo = create_repo(MockUserDao::new());
-        assert!(!repo.exists_by_email("nobody@example.com").await.unwrap());
+        assert!(!repo.exists_by_email(TenantId::DEFAULT, "nobody@example.com").await.unwrap());
     }
 
     #[tokio::test]
     async fn test_find_all_empty() {
         let repo = create_repo(MockUserDao::new());
-        let page = repo.find_all(PageRequest::new(0, 10)).await.unwrap();
+        let page = repo.find_all(TenantId::DEFAULT, PageRequest::new(0, 10)).await.unwrap();
         assert_eq!(page.content.len(), 0);
         assert_eq!(page.info.total_elements, 0);
     }
@@ -382,7 +432,7 @@ mod tests {
         ];
         let repo = create_repo(MockUserDao::with_users(users));
 
-        let page = repo.find_all(PageRequest::new(0, 10)).await.unwrap();
+        let page = repo.find_all(TenantId::DEFAULT, PageRequest::new(0, 10)).await.unwrap();
         assert_eq!(page.content.len(), 2);
         assert_eq!(page.info.total_elements, 2);
     }
@@ -394,7 +444,7 @@ mod tests {
         let regular = create_test_user("user", "user@example.com");
         let repo = create_repo(MockUserDao::with_users(vec![admin, regular]));
 
-        let admins = repo.find_by_role(UserRole::Admin, PageRequest::new(0, 10)).await.unwrap();
+        let admins = repo.find_by_role(TenantId::DEFAULT, UserRole::Admin, PageRequest::new(0, 10)).await.unwrap();
         assert_eq!(admins.content.len(), 1);
         assert_eq!(admins.content[0].username, "admin");
     }
@@ -458,13 +508,13 @@ mod tests {
         ];
         let repo = create_repo(MockUserDao::with_users(users));
 
-        assert_eq!(repo.count().await.unwrap(), 3);
+        assert_eq!(repo.count(TenantId::DEFAULT).await.unwrap(), 3);
     }
 
     #[tokio::test]
     async fn test_count_empty() {
         let repo = create_repo(MockUserDao::new());
-        assert_eq!(repo.count().await.unwrap(), 0);
+        assert_eq!(repo.count(TenantId::DEFAULT).await.unwrap(), 0);
     }
 
     #[tokio::test]
@@ -475,9 +525,51 @@ mod tests {
         let regular2 = create_test_user("user2", "user2@example.com");
         let repo = create_repo(MockUserDao::with_users(vec![admin, regular1, regular2]));
 
-        assert_eq!(repo.count_by_role(UserRole::Admin).await.unwrap(), 1);
-        assert_eq!(repo.count_by_role(UserRole::User).await.unwrap(), 2);
-        assert_eq!(repo.count_by_role(UserRole::SuperAdmin).await.unwrap(), 0);
+        assert_eq!(repo.count_by_role(TenantId::DEFAULT, UserRole::Admin).await.unwrap(), 1);
+        assert_eq!(repo.count_by_role(TenantId::DEFAULT, UserRole::User).await.unwrap(), 2);
+        assert_eq!(repo.count_by_role(TenantId::DEFAULT, UserRole::SuperAdmin).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_lookups_are_scoped_to_tenant() {
+        let tenant_id = TenantId::new();
+        let other = create_test_user("alice", "alice@example.com").in_tenant(tenant_id);
+        let repo = create_repo(MockUserDao::with_users(vec![create_test_user("alice", "alice@example.com"), other]));
+
+        let found = repo.find_by_username(tenant_id, "alice").await.unwrap().unwrap();
+        assert_eq!(found.tenant_id, tenant_id);
+        assert!(repo.exists_by_email(tenant_id, "alice@example.com").await.unwrap());
+        assert!(!repo.exists_by_username(TenantId::new(), "alice").await.unwrap());
+        assert_eq!(repo.count(TenantId::DEFAULT).await.unwrap(), 1);
+        assert_eq!(repo.find_all(tenant_id, PageRequest::new(0, 10)).await.unwrap().content.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_with_events_passes_outbox_entries_to_dao() {
+        let user = create_test_user("alice", "alice@example.com");
+        let dao = Arc::new(MockUserDao::with_user(user.clone()));
+        let repo = UserRepositoryImpl::new(dao.clone());
+        assert!(repo.stores_events());
+
+        let event = UserRoleChanged::new(user.id, UserRole::User, UserRole::Admin, UserId::new());
+        repo.update_with_events(&user, &[Box::new(event)]).await.unwrap();
+
+        let outbox = dao.outbox.lock().unwrap();
+        assert_eq!(outbox.len(), 1);
+        assert_eq!(outbox[0].event_type, "user.role_changed");
+        assert_eq!(outbox[0].aggregate_id, user.id.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_delete_with_events_of_nonexistent_user_stores_nothing() {
+        let dao = Arc::new(MockUserDao::new());
+        let repo = UserRepositoryImpl::new(dao.clone());
+        let id = UserId::new();
+
+        let deleted = repo.delete_with_events(id, &[Box::new(UserDeleted::new(id, None))]).await.unwrap();
+
+        assert!(!deleted);
+        assert!(dao.outbox.lock().unwrap().is_empty());
     }
 
     #[test]
@@ -0,0 +1,183 @@
+//! `WebhookRepositoryImpl` — Repository layer implementation for webhooks.
+//!
+//! Implements the [`WebhookRepository`] domain interface on top of a [`WebhookDao`].
+//!
+//! [`WebhookRepository`]: crate::traits::WebhookRepository
+//! [`WebhookDao`]: crate::dao::WebhookDao
+
+use crate::{dao::WebhookDao, traits::WebhookRepository};
+use arcana_core::{
+    ArcanaResult, Page, PageRequest, WebhookDelivery, WebhookSubscription, WebhookSubscriptionId,
+};
+use async_trait::async_trait;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Repository implementation that orchestrates [`WebhookDao`] access.
+///
+/// [`WebhookDao`]: crate::dao::WebhookDao
+#[derive(Component)]
+#[shaku(interface = WebhookRepository)]
+pub struct WebhookRepositoryImpl {
+    /// Primary data access object.
+    #[shaku(inject)]
+    webhook_dao: Arc<dyn WebhookDao>,
+}
+
+impl WebhookRepositoryImpl {
+    /// Creates a new `WebhookRepositoryImpl` with the given DAO.
+    #[must_use]
+    pub fn new(webhook_dao: Arc<dyn WebhookDao>) -> Self {
+        Self { webhook_dao }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookRepositoryImpl {
+    async fn find_by_id(&self, id: WebhookSubscriptionId) -> ArcanaResult<Option<WebhookSubscription>> {
+        debug!("Repository: find webhook subscription {}", id);
+        self.webhook_dao.find_by_id(id).await
+    }
+
+    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<WebhookSubscription>> {
+        debug!("Repository: find webhook subscriptions page={} size={}", page.page, page.size);
+        self.webhook_dao.find_all(page).await
+    }
+
+    async fn find_subscribed(&self, event_type: &str) -> ArcanaResult<Vec<WebhookSubscription>> {
+        debug!("Repository: find webhook subscriptions for {}", event_type);
+        let mut subscriptions = self.webhook_dao.find_active().await?;
+        subscriptions.retain(|subscription| subscription.matches(event_type));
+        Ok(subscriptions)
+    }
+
+    async fn save(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription> {
+        debug!("Repository: save webhook subscription {}", subscription.id);
+        self.webhook_dao.save(subscription).await
+    }
+
+    async fn update(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription> {
+        debug!("Repository: update webhook subscription {}", subscription.id);
+        self.webhook_dao.update(subscription).await
+    }
+
+    async fn delete(&self, id: WebhookSubscriptionId) -> ArcanaResult<bool> {
+        debug!("Repository: delete webhook subscription {}", id);
+        self.webhook_dao.delete(id).await
+    }
+
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> ArcanaResult<()> {
+        debug!("Repository: log webhook delivery {} attempt {}", delivery.event_id, delivery.attempt);
+        self.webhook_dao.save_delivery(delivery).await
+    }
+
+    async fn find_deliveries(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        page: PageRequest,
+    ) -> ArcanaResult<Page<WebhookDelivery>> {
+        debug!("Repository: find deliveries of webhook subscription {}", subscription_id);
+        self.webhook_dao.find_deliveries(subscription_id, page).await
+    }
+}
+
+impl std::fmt::Debug for WebhookRepositoryImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookRepositoryImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockWebhookDao {
+        subscriptions: Mutex<Vec<WebhookSubscription>>,
+    }
+
+    impl std::fmt::Debug for MockWebhookDao {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockWebhookDao").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl WebhookDao for MockWebhookDao {
+        async fn find_by_id(&self, id: WebhookSubscriptionId) -> ArcanaResult<Option<WebhookSubscription>> {
+            Ok(self.subscriptions.lock().unwrap().iter().find(|s| s.id == id).cloned())
+        }
+
+        async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<WebhookSubscription>> {
+            let subscriptions = self.subscriptions.lock().unwrap().clone();
+            let total = subscriptions.len() as u64;
+            Ok(Page::new(subscriptions, page.page, page.size, total))
+        }
+
+        async fn find_active(&self) -> ArcanaResult<Vec<WebhookSubscription>> {
+            Ok(self.subscriptions.lock().unwrap().iter().filter(|s| s.active).cloned().collect())
+        }
+
+        async fn save(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription> {
+            self.subscriptions.lock().unwrap().push(subscription.clone());
+            Ok(subscription.clone())
+        }
+
+        async fn update(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription> {
+            Ok(subscription.clone())
+        }
+
+        async fn delete(&self, id: WebhookSubscriptionId) -> ArcanaResult<bool> {
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            let before = subscriptions.len();
+            subscriptions.retain(|s| s.id != id);
+            Ok(subscriptions.len() != before)
+        }
+
+        async fn save_delivery(&self, _delivery: &WebhookDelivery) -> ArcanaResult<()> {
+            Ok(())
+        }
+
+        async fn find_deliveries(
+            &self,
+            _subscription_id: WebhookSubscriptionId,
+            page: PageRequest,
+        ) -> ArcanaResult<Page<WebhookDelivery>> {
+            Ok(Page::empty(page.page, page.size))
+        }
+    }
+
+    fn subscription(event_types: &[&str], active: bool) -> WebhookSubscription {
+        let mut subscription = WebhookSubscription::new(
+            "https://example.com/hooks".to_string(),
+            event_types.iter().map(|t| t.to_string()).collect(),
+            "secret".to_string(),
+            None,
+        );
+        subscription.active = active;
+        subscription
+    }
+
+    #[tokio::test]
+    async fn test_find_subscribed_filters_by_event_type() {
+        let created = subscription(&["user.created"], true);
+        let everything = subscription(&["*"], true);
+        let paused = subscription(&["user.created"], false);
+        let repo = WebhookRepositoryImpl::new(Arc::new(MockWebhookDao::default()));
+        for s in [&created, &everything, &paused] {
+            repo.save(s).await.unwrap();
+        }
+
+        let ids = |subscriptions: Vec<WebhookSubscription>| subscriptions.into_iter().map(|s| s.id).collect::<Vec<_>>();
+        assert_eq!(ids(repo.find_subscribed("user.created").await.unwrap()), vec![created.id, everything.id]);
+        assert_eq!(ids(repo.find_subscribed("user.deleted").await.unwrap()), vec![everything.id]);
+    }
+
+    #[test]
+    fn test_repository_debug() {
+        let repo = WebhookRepositoryImpl::new(Arc::new(MockWebhookDao::default()));
+        assert!(format!("{:?}", repo).contains("WebhookRepositoryImpl"));
+    }
+}
@@ -16,16 +16,44 @@
 //!
 //! ```text
 //! src/
-//!   traits.rs                    ← UserRepository trait
+//!   traits.rs                         ← UserRepository, OAuthTokenRepository,
+//!                                       PasswordResetTokenRepository, MfaRepository,
+//!                                       AuditLogRepository, OutboxRepository,
+//!                                       WebhookRepository, RoleRepository,
+//!                                       TenantRepository traits
 //!   impl/
 //!     mod.rs
-//!     user_repository_impl.rs    ← UserRepositoryImpl
+//!     user_repository_impl.rs         ← UserRepositoryImpl
+//!     oauth_token_repository_impl.rs  ← OAuthTokenRepositoryImpl
+//!     password_reset_token_repository_impl.rs ← PasswordResetTokenRepositoryImpl
+//!     mfa_repository_impl.rs          ← MfaRepositoryImpl
+//!     audit_log_repository_impl.rs    ← AuditLogRepositoryImpl
+//!     outbox_repository_impl.rs       ← OutboxRepositoryImpl
+//!     webhook_repository_impl.rs      ← WebhookRepositoryImpl
+//!     role_repository_impl.rs         ← RoleRepositoryImpl
+//!     tenant_repository_impl.rs       ← TenantRepositoryImpl
 //!   dao/
-//!     user_dao.rs                ← UserDao trait
+//!     user_dao.rs                     ← UserDao trait
+//!     oauth_token_dao.rs              ← OAuthTokenDao trait
+//!     password_reset_token_dao.rs     ← PasswordResetTokenDao trait
+//!     mfa_dao.rs                      ← MfaDao trait
+//!     audit_log_dao.rs                ← AuditLogDao trait
+//!     outbox_dao.rs                   ← OutboxDao trait
+//!     webhook_dao.rs                  ← WebhookDao trait
+//!     role_dao.rs                     ← RoleDao trait
+//!     tenant_dao.rs                   ← TenantDao trait
 //!     impl/
 //!       mod.rs
 //!       mysql/
-//!         user_dao_impl.rs       ← MySqlUserDaoImpl
+//!         user_dao_impl.rs            ← MySqlUserDaoImpl
+//!         oauth_token_dao_impl.rs     ← MySqlOAuthTokenDaoImpl
+//!         password_reset_token_dao_impl.rs ← MySqlPasswordResetTokenDaoImpl
+//!         mfa_dao_impl.rs             ← MySqlMfaDaoImpl
+//!         audit_log_dao_impl.rs       ← MySqlAuditLogDaoImpl
+//!         outbox_dao_impl.rs          ← MySqlOutboxDaoImpl
+//!         webhook_dao_impl.rs         ← MySqlWebhookDaoImpl
+//!         role_dao_impl.rs            ← MySqlRoleDaoImpl
+//!         tenant_dao_impl.rs          ← MySqlTenantDaoImpl
 //! ```
 //!
 //! The existing [`MySqlUserRepository`] is retained for backward
@@ -37,19 +65,28 @@ pub mod mysql;
 pub mod traits;
 pub mod r#impl;
 
-pub use dao::UserDao;
+pub use dao::{
+    AuditLogDao, MfaDao, OAuthTokenDao, OutboxDao, PasswordResetTokenDao, RoleDao, TenantDao, UserDao, WebhookDao,
+};
 pub use pool::*;
 pub use traits::*;
-pub use r#impl::UserRepositoryImpl;
+pub use r#impl::{
+    AuditLogRepositoryImpl, MfaRepositoryImpl, OAuthTokenRepositoryImpl, OutboxRepositoryImpl,
+    PasswordResetTokenRepositoryImpl, RoleRepositoryImpl, TenantRepositoryImpl, UserRepositoryImpl,
+    WebhookRepositoryImpl,
+};
 
 // Re-export DAO and MySQL implementations for convenience
-pub use dao::MySqlUserDaoImpl;
+pub use dao::{
+    MySqlAuditLogDaoImpl, MySqlMfaDaoImpl, MySqlOAuthTokenDaoImpl, MySqlOutboxDaoImpl, MySqlPasswordResetTokenDaoImpl,
+    MySqlRoleDaoImpl, MySqlTenantDaoImpl, MySqlUserDaoImpl, MySqlWebhookDaoImpl,
+};
 pub use mysql::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arcana_core::{ArcanaResult, Page, PageRequest, UserId};
+    use arcana_core::{ArcanaResult, Page, PageRequest, TenantId, UserId};
     use arcana_core::{Email, User, UserRole, UserStatus};
     use async_trait::async_trait;
     use std::collections::HashMap;
@@ -82,35 +119,38 @@ mod tests {
             Ok(self.users.lock().unwrap().get(&id).cloned())
         }
 
-        async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.username == username)
+                .find(|u| u.tenant_id == tenant_id && u.username == username)
                 .cloned())
         }
 
-        async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.email.as_str() == email)
+                .find(|u| u.tenant_id == tenant_id && u.email.as_str() == email)
                 .cloned())
         }
 
-        async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.username == identifier || u.email.as_str() == identifier)
+                .find(|u| u.tenant_id == tenant_id && (u.username == identifier || u.email.as_str() == identifier))
                 .cloned())
         }
 
-        async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool> {
-            Ok(self.users.lock().unwrap().values().any(|u| u.username == username))
+        async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool> {
+            Ok(self.users.lock().unwrap().values().any(|u| u.tenant_id == tenant_id && u.username == username))
         }
 
-        async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool> {
+        async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool> {
             Ok(self.users.lock().unwrap().values()
-                .any(|u| u.email.as_str().to_lowercase() == email.to_lowercase()))
+                .any(|u| u.tenant_id == tenant_id && u.email.as_str().to_lowercase() == email.to_lowercase()))
         }
 
-        async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<User>> {
-            let users: Vec<User> = self.users.lock().unwrap().values().cloned().collect();
+        async fn find_all(&self, tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>> {
+            let users: Vec<User> = self.users.lock().unwrap().values()
+                .filter(|u| u.tenant_id == tenant_id)
+                .cloned()
+                .collect();
             let total = users.len() as u64;
             let start = page.offset();
             let end = std::cmp::min(start + page.limit(), users.len());
@@ -118,9 +158,9 @@ mod tests {
             Ok(Page::new(items, page.page, page.size, total))
         }
 
-        async fn find_by_role(&self, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
+        async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
             let users: Vec<User> = self.users.lock().unwrap().values()
-                .filter(|u| u.role == role)
+                .filter(|u| u.tenant_id == tenant_id && u.role == role)
                 .cloned()
                 .collect();
             let total = users.len() as u64;
@@ -144,13 +184,15 @@ mod tests {
             Ok(self.users.lock().unwrap().remove(&id).is_some())
         }
 
-        async fn count(&self) -> ArcanaResult<u64> {
-            Ok(self.users.lock().unwrap().len() as u64)
+        async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64> {
+            Ok(self.users.lock().unwrap().values()
+                .filter(|u| u.tenant_id == tenant_id)
+                .count() as u64)
         }
 
-        async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64> {
+        async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64> {
             Ok(self.users.lock().unwrap().values()
-                .filter(|u| u.role == role)
+                .filter(|u| u.tenant_id == tenant_id && u.role == role)
                 .count() as u64)
         }
     }
@@ -196,7 +238,7 @@ mod tests {
         let user = create_test_user("testuser", "test@example.com");
         let repo = InMemoryUserRepository::with_users(vec![user]);
 
-        let found = repo.find_by_username("testuser").await.unwrap();
+        let found = repo.find_by_username(TenantId::DEFAULT, "testuser").await.unwrap();
         assert!(found.is_some());
         assert_eq!(found.unwrap().email.as_str(), "test@example.com");
     }
@@ -204,7 +246,7 @@ mod tests {
     #[tokio::test]
     async fn test_find_by_username_not_found() {
         let repo = InMemoryUserRepository::new();
-        let result = repo.find_by_username("nonexistent").await.unwrap();
+        let result = repo.find_by_username(TenantId::DEFAULT, "nonexistent").await.unwrap();
         assert!(result.is_none());
     }
 
@@ -213,7 +255,7 @@ mod tests {
         let user = create_test_user("testuser", "test@example.com");
         let repo = InMemoryUserRepository::with_users(vec![user]);
 
-        let found = repo.find_by_email("test@example.com").await.unwrap();
+        let found = repo.find_by_email(TenantId::DEFAULT, "test@example.com").await.unwrap();
         assert!(found.is_some());
         assert_eq!(found.unwrap().username, "testuser");
     }
@@ -223,7 +265,7 @@ mod tests {
         let user = create_test_user("testuser", "test@example.com");
         let repo = InMemoryUserRepository::with_users(vec![user]);
 
-        let found = repo.find_by_username_or_email("testuser").await.unwrap();
+        let found = repo.find_by_username_or_email(TenantId::DEFAULT, "testuser").await.unwrap();
         assert!(found.is_some());
     }
 
@@ -232,7 +274,7 @@ mod tests {
         let user = create_test_user("testuser", "test@example.com");
         let repo = InMemoryUserRepository::with_users(vec![user]);
 
-        let found = repo.find_by_username_or_email("test@example.com").await.unwrap();
+        let found = repo.find_by_username_or_email(TenantId::DEFAULT, "test@example.com").await.unwrap();
         assert!(found.is_some());
     }
 
@@ -241,8 +283,8 @@ mod tests {
         let user = create_test_user("testuser", "test@example.com");
         let repo = InMemoryUserRepository::with_users(vec![user]);
 
-        assert!(repo.exists_by_username("testuser").await.unwrap());
-        assert!(!repo.exists_by_username("nonexistent").await.unwrap());
+        assert!(repo.exists_by_username(TenantId::DEFAULT, "testuser").await.unwrap());
+        assert!(!repo.exists_by_username(TenantId::DEFAULT, "nonexistent").await.unwrap());
     }
 
     #[tokio::test]
@@ -250,8 +292,8 @@ mod tests {
         let user = create_test_user("testuser", "test@example.com");
         let repo = InMemoryUserRepository::with_users(vec![user]);
 
-        assert!(repo.exists_by_email("test@example.com").await.unwrap());
-        assert!(!repo.exists_by_email("nonexistent@example.com").await.unwrap());
+        assert!(repo.exists_by_email(TenantId::DEFAULT, "test@example.com").await.unwrap());
+        assert!(!repo.exists_by_email(TenantId::DEFAULT, "nonexistent@example.com").await.unwrap());
     }
 
     #[tokio::test]
@@ -259,13 +301,13 @@ mod tests {
         let user = create_test_user("testuser", "test@example.com");
         let repo = InMemoryUserRepository::with_users(vec![user]);
 
-        assert!(repo.exists_by_email("TEST@EXAMPLE.COM").await.unwrap());
+        assert!(repo.exists_by_email(TenantId::DEFAULT, "TEST@EXAMPLE.COM").await.unwrap());
     }
 
     #[tokio::test]
     async fn test_find_all_empty() {
         let repo = InMemoryUserRepository::new();
-        let page = repo.find_all(PageRequest::new(0, 10)).await.unwrap();
+        let page = repo.find_all(TenantId::DEFAULT, PageRequest::new(0, 10)).await.unwrap();
         assert_eq!(page.content.len(), 0);
         assert_eq!(page.info.total_elements, 0);
     }
@@ -279,7 +321,7 @@ mod tests {
         ];
         let repo = InMemoryUserRepository::with_users(users);
 
-        let page = repo.find_all(PageRequest::new(0, 10)).await.unwrap();
+        let page = repo.find_all(TenantId::DEFAULT, PageRequest::new(0, 10)).await.unwrap();
         assert_eq!(page.content.len(), 3);
         assert_eq!(page.info.total_elements, 3);
     }
@@ -293,11 +335,11 @@ mod tests {
         ];
         let repo = InMemoryUserRepository::with_users(users);
 
-        let page = repo.find_all(PageRequest::new(0, 2)).await.unwrap();
+        let page = repo.find_all(TenantId::DEFAULT, PageRequest::new(0, 2)).await.unwrap();
         assert_eq!(page.content.len(), 2);
         assert_eq!(page.info.total_elements, 3);
 
-        let page2 = repo.find_all(PageRequest::new(1, 2)).await.unwrap();
+        let page2 = repo.find_all(TenantId::DEFAULT, PageRequest::new(1, 2)).await.unwrap();
         assert_eq!(page2.content.len(), 1);
     }
 
@@ -308,11 +350,11 @@ mod tests {
         let user = create_test_user("user", "user@example.com");
         let repo = InMemoryUserRepository::with_users(vec![admin, user]);
 
-        let admins = repo.find_by_role(UserRole::Admin, PageRequest::new(0, 10)).await.unwrap();
+        let admins = repo.find_by_role(TenantId::DEFAULT, UserRole::Admin, PageRequest::new(0, 10)).await.unwrap();
         assert_eq!(admins.content.len(), 1);
         assert_eq!(admins.content[0].username, "admin");
 
-        let users = repo.find_by_role(UserRole::User, PageRequest::new(0, 10)).await.unwrap();
+        let users = repo.find_by_role(TenantId::DEFAULT, UserRole::User, PageRequest::new(0, 10)).await.unwrap();
         assert_eq!(users.content.len(), 1);
         assert_eq!(users.content[0].username, "user");
     }
@@ -360,7 +402,7 @@ mod tests {
         ];
         let repo = InMemoryUserRepository::with_users(users);
 
-        assert_eq!(repo.count().await.unwrap(), 2);
+        assert_eq!(repo.count(TenantId::DEFAULT).await.unwrap(), 2);
     }
 
     #[tokio::test]
@@ -372,9 +414,9 @@ mod tests {
         let user = create_test_user("user", "user@example.com");
         let repo = InMemoryUserRepository::with_users(vec![admin1, admin2, user]);
 
-        assert_eq!(repo.count_by_role(UserRole::Admin).await.unwrap(), 2);
-        assert_eq!(repo.count_by_role(UserRole::User).await.unwrap(), 1);
-        assert_eq!(repo.count_by_role(UserRole::SuperAdmin).await.unwrap(), 0);
+        assert_eq!(repo.count_by_role(TenantId::DEFAULT, UserRole::Admin).await.unwrap(), 2);
+        assert_eq!(repo.count_by_role(TenantId::DEFAULT, UserRole::User).await.unwrap(), 1);
+        assert_eq!(repo.count_by_role(TenantId::DEFAULT, UserRole::SuperAdmin).await.unwrap(), 0);
     }
 
     #[tokio::test]
@@ -1,7 +1,7 @@
 //! MySQL user repository implementation.
 
 use crate::{traits::UserRepository, DatabasePoolInterface};
-use arcana_core::{ArcanaError, ArcanaResult, Page, PageRequest, UserId};
+use arcana_core::{ArcanaError, ArcanaResult, Page, PageRequest, TenantId, UserId};
 use arcana_core::{Email, User, UserRole, UserStatus};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -31,6 +31,7 @@ impl MySqlUserRepository {
 #[derive(Debug, FromRow)]
 struct UserRow {
     id: String,  // MySQL stores UUID as CHAR(36)
+    tenant_id: String,
     username: String,
     email: String,
     password_hash: String,
@@ -41,6 +42,7 @@ struct UserRow {
     email_verified: bool,
     avatar_url: Option<String>,
     last_login_at: Option<DateTime<Utc>>,
+    locked_until: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -51,9 +53,12 @@ impl TryFrom<UserRow> for User {
     fn try_from(row: UserRow) -> Result<Self, Self::Error> {
         let id = Uuid::parse_str(&row.id)
             .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {}", e)))?;
+        let tenant_id = Uuid::parse_str(&row.tenant_id)
+            .map_err(|e| ArcanaError::Internal(format!("Invalid UUID in database: {}", e)))?;
 
         Ok(User {
             id: UserId::from_uuid(id),
+            tenant_id: TenantId::from_uuid(tenant_id),
             username: row.username,
             email: Email::new_unchecked(row.email),
             password_hash: row.password_hash,
@@ -64,6 +69,7 @@ impl TryFrom<UserRow> for User {
             email_verified: row.email_verified,
             avatar_url: row.avatar_url,
             last_login_at: row.last_login_at,
+            locked_until: row.locked_until,
             created_at: row.created_at,
             updated_at: row.updated_at,
         })
@@ -96,8 +102,8 @@ impl UserRepository for MySqlUserRepository {
 
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
             FROM users
             WHERE id = ? AND status != 'deleted'
@@ -110,18 +116,19 @@ impl UserRepository for MySqlUserRepository {
         row.map(User::try_from).transpose()
     }
 
-    async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>> {
+    async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>> {
         debug!("Finding user by username: {}", username);
 
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
             FROM users
-            WHERE username = ? AND status != 'deleted'
+            WHERE tenant_id = ? AND username = ? AND status != 'deleted'
             "#,
         )
+        .bind(tenant_id.into_inner().to_string())
         .bind(username)
         .fetch_optional(self.pool.inner())
         .await?;
@@ -129,18 +136,19 @@ impl UserRepository for MySqlUserRepository {
         row.map(User::try_from).transpose()
     }
 
-    async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>> {
+    async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>> {
         debug!("Finding user by email: {}", email);
 
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
             FROM users
-            WHERE LOWER(email) = LOWER(?) AND status != 'deleted'
+            WHERE tenant_id = ? AND LOWER(email) = LOWER(?) AND status != 'deleted'
             "#,
         )
+        .bind(tenant_id.into_inner().to_string())
         .bind(email)
         .fetch_optional(self.pool.inner())
         .await?;
@@ -148,18 +156,19 @@ impl UserRepository for MySqlUserRepository {
         row.map(User::try_from).transpose()
     }
 
-    async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>> {
+    async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>> {
         debug!("Finding user by username or email: {}", identifier);
 
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
             FROM users
-            WHERE (username = ? OR LOWER(email) = LOWER(?)) AND status != 'deleted'
+            WHERE tenant_id = ? AND (username = ? OR LOWER(email) = LOWER(?)) AND status != 'deleted'
             "#,
         )
+        .bind(tenant_id.into_inner().to_string())
         .bind(identifier)
         .bind(identifier)
         .fetch_optional(self.pool.inner())
@@ -168,10 +177,11 @@ impl UserRepository for MySqlUserRepository {
         row.map(User::try_from).transpose()
     }
 
-    async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool> {
+    async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool> {
         let result: Option<i32> = sqlx::query_scalar(
-            "SELECT 1 FROM users WHERE username = ? LIMIT 1",
+            "SELECT 1 FROM users WHERE tenant_id = ? AND username = ? LIMIT 1",
         )
+        .bind(tenant_id.into_inner().to_string())
         .bind(username)
         .fetch_optional(self.pool.inner())
         .await?;
@@ -179,10 +189,11 @@ impl UserRepository for MySqlUserRepository {
         Ok(result.is_some())
     }
 
-    async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool> {
+    async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool> {
         let result: Option<i32> = sqlx::query_scalar(
-            "SELECT 1 FROM users WHERE LOWER(email) = LOWER(?) LIMIT 1",
+            "SELECT 1 FROM users WHERE tenant_id = ? AND LOWER(email) = LOWER(?) LIMIT 1",
         )
+        .bind(tenant_id.into_inner().to_string())
         .bind(email)
         .fetch_optional(self.pool.inner())
         .await?;
@@ -190,24 +201,26 @@ impl UserRepository for MySqlUserRepository {
         Ok(result.is_some())
     }
 
-    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<User>> {
-        debug!("Finding all users, page: {}, size: {}", page.page, page.size);
+    async fn find_all(&self, tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>> {
+        debug!("Finding all users in tenant {}, page: {}, size: {}", tenant_id, page.page, page.size);
 
-        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE status != 'deleted'")
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE tenant_id = ? AND status != 'deleted'")
+            .bind(tenant_id.into_inner().to_string())
             .fetch_one(self.pool.inner())
             .await?;
 
         let rows = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
             FROM users
-            WHERE status != 'deleted'
+            WHERE tenant_id = ? AND status != 'deleted'
             ORDER BY created_at DESC
             LIMIT ? OFFSET ?
             "#,
         )
+        .bind(tenant_id.into_inner().to_string())
         .bind(page.limit() as i64)
         .bind(page.offset() as i64)
         .fetch_all(self.pool.inner())
@@ -221,29 +234,31 @@ impl UserRepository for MySqlUserRepository {
         Ok(Page::new(users, page.page, page.size, total as u64))
     }
 
-    async fn find_by_role(&self, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
+    async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
         debug!("Finding users by role: {}", role);
 
         let role_str = role.to_string();
 
         let total: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM users WHERE role = ? AND status != 'deleted'",
+            "SELECT COUNT(*) FROM users WHERE tenant_id = ? AND role = ? AND status != 'deleted'",
         )
+        .bind(tenant_id.into_inner().to_string())
         .bind(&role_str)
         .fetch_one(self.pool.inner())
         .await?;
 
         let rows = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, first_name, last_name,
-                   role, status, email_verified, avatar_url, last_login_at,
+            SELECT id, tenant_id, username, email, password_hash, first_name, last_name,
+                   role, status, email_verified, avatar_url, last_login_at, locked_until,
                    created_at, updated_at
             FROM users
-            WHERE role = ? AND status != 'deleted'
+            WHERE tenant_id = ? AND role = ? AND status != 'deleted'
             ORDER BY created_at DESC
             LIMIT ? OFFSET ?
             "#,
         )
+        .bind(tenant_id.into_inner().to_string())
         .bind(&role_str)
         .bind(page.limit() as i64)
         .bind(page.offset() as i64)
@@ -266,12 +281,13 @@ impl UserRepository for MySqlUserRepository {
         // MySQL doesn't support RETURNING, so insert then select
         sqlx::query(
             r#"
-            INSERT INTO users (id, username, email, password_hash, first_name, last_name,
+            INSERT INTO users (id, tenant_id, username, email, password_hash, first_name, last_name,
                               role, status, email_verified, avatar_url, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&id_str)
+        .bind(user.tenant_id.into_inner().to_string())
         .bind(&user.username)
         .bind(user.email.as_str())
         .bind(&user.password_hash)
@@ -303,7 +319,7 @@ impl UserRepository for MySqlUserRepository {
             UPDATE users
             SET username = ?, email = ?, password_hash = ?, first_name = ?,
                 last_name = ?, role = ?, status = ?, email_verified = ?,
-                avatar_url = ?, last_login_at = ?, updated_at = ?
+                avatar_url = ?, last_login_at = ?, locked_until = ?, updated_at = ?
             WHERE id = ?
             "#,
         )
@@ -317,6 +333,7 @@ impl UserRepository for MySqlUserRepository {
         .bind(user.email_verified)
         .bind(&user.avatar_url)
         .bind(user.last_login_at)
+        .bind(user.locked_until)
         .bind(user.updated_at)
         .bind(&id_str)
         .execute(self.pool.inner())
@@ -341,18 +358,20 @@ impl UserRepository for MySqlUserRepository {
         Ok(result.rows_affected() > 0)
     }
 
-    async fn count(&self) -> ArcanaResult<u64> {
-        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE status != 'deleted'")
+    async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE tenant_id = ? AND status != 'deleted'")
+            .bind(tenant_id.into_inner().to_string())
             .fetch_one(self.pool.inner())
             .await?;
 
         Ok(count as u64)
     }
 
-    async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64> {
+    async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64> {
         let count: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM users WHERE role = ? AND status != 'deleted'",
+            "SELECT COUNT(*) FROM users WHERE tenant_id = ? AND role = ? AND status != 'deleted'",
         )
+        .bind(tenant_id.into_inner().to_string())
         .bind(role.to_string())
         .fetch_one(self.pool.inner())
         .await?;
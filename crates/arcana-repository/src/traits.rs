@@ -1,35 +1,45 @@
 //! Repository trait definitions.
 
-use arcana_core::{ArcanaResult, Interface, Page, PageRequest, UserId};
-use arcana_core::{User, UserRole};
+use arcana_core::{ArcanaResult, Interface, Page, PageRequest, TenantId, UserId};
+use arcana_core::{OAuthToken, OAuthTokenId, PasswordResetToken, PasswordResetTokenId, User, UserRole};
+use arcana_core::{AuditLog, AuditLogCursor, AuditLogFilter, AuditLogId, TotpCredential};
+use arcana_core::{DomainEvent, OutboxEvent, OutboxEventId};
+use arcana_core::{WebhookDelivery, WebhookSubscription, WebhookSubscriptionId};
+use arcana_core::{Permission, PermissionSet, Role, RoleId, Tenant};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 /// User repository trait.
+///
+/// Users belong to tenants: usernames and emails are unique within a
+/// tenant, so lookups by them, listings and counts are scoped to one. User
+/// IDs are unique across tenants; callers serving a tenant must check the
+/// `tenant_id` of users found by ID.
 #[async_trait]
 pub trait UserRepository: Interface + Send + Sync {
     /// Finds a user by ID.
     async fn find_by_id(&self, id: UserId) -> ArcanaResult<Option<User>>;
 
-    /// Finds a user by username.
-    async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>>;
+    /// Finds a user of a tenant by username.
+    async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>>;
 
-    /// Finds a user by email.
-    async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>>;
+    /// Finds a user of a tenant by email.
+    async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>>;
 
-    /// Finds a user by username or email.
-    async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>>;
+    /// Finds a user of a tenant by username or email.
+    async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>>;
 
-    /// Checks if a username exists.
-    async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool>;
+    /// Checks if a username exists in a tenant.
+    async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool>;
 
-    /// Checks if an email exists.
-    async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool>;
+    /// Checks if an email exists in a tenant.
+    async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool>;
 
-    /// Finds all users with pagination.
-    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<User>>;
+    /// Finds all users of a tenant with pagination.
+    async fn find_all(&self, tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>>;
 
-    /// Finds users by role.
-    async fn find_by_role(&self, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>>;
+    /// Finds users of a tenant by role.
+    async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>>;
 
     /// Saves a new user.
     async fn save(&self, user: &User) -> ArcanaResult<User>;
@@ -40,9 +50,282 @@ pub trait UserRepository: Interface + Send + Sync {
     /// Deletes a user by ID.
     async fn delete(&self, id: UserId) -> ArcanaResult<bool>;
 
-    /// Counts all users.
-    async fn count(&self) -> ArcanaResult<u64>;
+    /// Counts all users of a tenant.
+    async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64>;
 
-    /// Counts users by role.
-    async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64>;
+    /// Counts users of a tenant by role.
+    async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64>;
+
+    /// Returns `true` if the `*_with_events` methods store the events in a
+    /// transactional outbox, from which the outbox relay publishes them.
+    ///
+    /// Otherwise they only make the change and the caller publishes the events.
+    fn stores_events(&self) -> bool {
+        false
+    }
+
+    /// Saves a new user together with the events raised by the change.
+    async fn save_with_events(&self, user: &User, _events: &[Box<dyn DomainEvent>]) -> ArcanaResult<User> {
+        self.save(user).await
+    }
+
+    /// Updates an existing user together with the events raised by the change.
+    async fn update_with_events(&self, user: &User, _events: &[Box<dyn DomainEvent>]) -> ArcanaResult<User> {
+        self.update(user).await
+    }
+
+    /// Deletes a user by ID together with the events raised by the change.
+    ///
+    /// The events are discarded if there was no such user.
+    async fn delete_with_events(&self, id: UserId, _events: &[Box<dyn DomainEvent>]) -> ArcanaResult<bool> {
+        self.delete(id).await
+    }
+}
+
+/// OAuth (refresh) token repository trait.
+///
+/// Tokens are identified by the hash of the refresh token; callers are
+/// responsible for hashing before lookup or storage.
+#[async_trait]
+pub trait OAuthTokenRepository: Interface + Send + Sync {
+    /// Finds a token by ID.
+    async fn find_by_id(&self, id: OAuthTokenId) -> ArcanaResult<Option<OAuthToken>>;
+
+    /// Finds a token by its hash.
+    async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<OAuthToken>>;
+
+    /// Saves a new token.
+    async fn save(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken>;
+
+    /// Updates an existing token.
+    async fn update(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken>;
+
+    /// Finds every live token of a user, newest first.
+    ///
+    /// Each live token stands for one active session (token family).
+    async fn find_active_by_user(&self, user_id: UserId) -> ArcanaResult<Vec<OAuthToken>>;
+
+    /// Revokes every live token of a user. Returns the number revoked.
+    async fn revoke_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64>;
+
+    /// Atomically marks a live token as used by rotation.
+    ///
+    /// Returns `false` if the token had already been revoked or rotated.
+    async fn mark_rotated(&self, id: OAuthTokenId) -> ArcanaResult<bool>;
+
+    /// Revokes every live token in a token family. Returns the number revoked.
+    async fn revoke_family(&self, family_id: &str) -> ArcanaResult<u64>;
+}
+
+/// Password reset token repository trait.
+///
+/// Tokens are identified by the hash of the reset token; callers are
+/// responsible for hashing before lookup or storage.
+#[async_trait]
+pub trait PasswordResetTokenRepository: Interface + Send + Sync {
+    /// Finds a token by ID.
+    async fn find_by_id(&self, id: PasswordResetTokenId) -> ArcanaResult<Option<PasswordResetToken>>;
+
+    /// Finds a token by its hash.
+    async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<PasswordResetToken>>;
+
+    /// Saves a new token.
+    async fn save(&self, token: &PasswordResetToken) -> ArcanaResult<PasswordResetToken>;
+
+    /// Atomically marks an unused token as used.
+    ///
+    /// Returns `false` if the token had already been used.
+    async fn mark_used(&self, id: PasswordResetTokenId) -> ArcanaResult<bool>;
+
+    /// Invalidates every unused token of a user. Returns the number invalidated.
+    async fn invalidate_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64>;
+}
+
+/// Multi-factor authentication repository trait.
+///
+/// Holds each user's TOTP credential and recovery codes. Recovery codes are
+/// identified by their hash; callers are responsible for hashing.
+#[async_trait]
+pub trait MfaRepository: Interface + Send + Sync {
+    /// Finds the TOTP credential of a user.
+    async fn find_totp_by_user(&self, user_id: UserId) -> ArcanaResult<Option<TotpCredential>>;
+
+    /// Saves the TOTP credential of a user, replacing any previous one.
+    async fn save_totp(&self, credential: &TotpCredential) -> ArcanaResult<TotpCredential>;
+
+    /// Atomically records the time step of an accepted TOTP code.
+    ///
+    /// Returns `false` if a code from this or a later step was already used.
+    async fn record_totp_step(&self, user_id: UserId, step: i64) -> ArcanaResult<bool>;
+
+    /// Deletes the TOTP credential and recovery codes of a user.
+    ///
+    /// Returns `false` if the user had no TOTP credential.
+    async fn delete_by_user(&self, user_id: UserId) -> ArcanaResult<bool>;
+
+    /// Replaces every recovery code of a user.
+    async fn replace_recovery_codes(&self, user_id: UserId, code_hashes: &[String]) -> ArcanaResult<()>;
+
+    /// Atomically marks an unused recovery code as used.
+    ///
+    /// Returns `false` if the user has no unused code with that hash.
+    async fn consume_recovery_code(&self, user_id: UserId, code_hash: &str) -> ArcanaResult<bool>;
+}
+
+/// Audit log repository trait.
+///
+/// The audit trail is append-only: entries are saved once and never updated.
+#[async_trait]
+pub trait AuditLogRepository: Interface + Send + Sync {
+    /// Saves a new audit log entry, appending it to the hash chain.
+    async fn save(&self, entry: &AuditLog) -> ArcanaResult<AuditLog>;
+
+    /// Finds an audit log entry by ID.
+    async fn find_by_id(&self, id: AuditLogId) -> ArcanaResult<Option<AuditLog>>;
+
+    /// Finds the entries of actions performed by a user, newest first.
+    async fn find_by_user(&self, user_id: UserId, page: PageRequest) -> ArcanaResult<Page<AuditLog>>;
+
+    /// Finds up to `limit` entries matching the filter, newest first,
+    /// starting right after the cursor if one is given.
+    async fn search(
+        &self,
+        filter: &AuditLogFilter,
+        after: Option<AuditLogCursor>,
+        limit: usize,
+    ) -> ArcanaResult<Vec<AuditLog>>;
+    /// Returns the first and last sequence number of the chained entries
+    /// in the time range, or `None` if it has none.
+    ///
+    /// Without an end of the range, the last sequence number is that of the
+    /// head of the chain, so that entries removed from the end of the trail
+    /// are still accounted for.
+    async fn chain_bounds(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> ArcanaResult<Option<(u64, u64)>>;
+
+    /// Finds up to `limit` chained entries with a sequence number in the
+    /// inclusive range, in chain order.
+    async fn find_chain(&self, from_sequence: u64, to_sequence: u64, limit: usize) -> ArcanaResult<Vec<AuditLog>>;
+}
+
+/// Transactional outbox repository trait.
+///
+/// Events enter the outbox through the repositories of the changes that
+/// raised them; this repository serves the relay that delivers them.
+#[async_trait]
+pub trait OutboxRepository: Interface + Send + Sync {
+    /// Finds up to `limit` undelivered events with fewer than `max_attempts`
+    /// failed delivery attempts, oldest first.
+    async fn find_pending(&self, limit: usize, max_attempts: u32) -> ArcanaResult<Vec<OutboxEvent>>;
+
+    /// Marks an event as delivered. Returns `false` if it was already marked.
+    async fn mark_dispatched(&self, id: OutboxEventId) -> ArcanaResult<bool>;
+
+    /// Records a failed delivery attempt.
+    async fn record_failure(&self, id: OutboxEventId, error: &str) -> ArcanaResult<()>;
+}
+
+/// Webhook repository trait.
+///
+/// Holds the webhook subscriptions and the log of their delivery attempts.
+#[async_trait]
+pub trait WebhookRepository: Interface + Send + Sync {
+    /// Finds a subscription by ID.
+    async fn find_by_id(&self, id: WebhookSubscriptionId) -> ArcanaResult<Option<WebhookSubscription>>;
+
+    /// Finds a page of subscriptions, oldest first.
+    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<WebhookSubscription>>;
+
+    /// Finds the active subscriptions that receive events of this type.
+    async fn find_subscribed(&self, event_type: &str) -> ArcanaResult<Vec<WebhookSubscription>>;
+
+    /// Saves a new subscription.
+    async fn save(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription>;
+
+    /// Updates an existing subscription.
+    async fn update(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription>;
+
+    /// Deletes a subscription and its delivery log. Returns `true` if it existed.
+    async fn delete(&self, id: WebhookSubscriptionId) -> ArcanaResult<bool>;
+
+    /// Appends an attempt to the delivery log.
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> ArcanaResult<()>;
+
+    /// Finds a page of the delivery log of a subscription, newest first.
+    async fn find_deliveries(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        page: PageRequest,
+    ) -> ArcanaResult<Page<WebhookDelivery>>;
+}
+
+/// Role repository trait.
+///
+/// Holds the built-in and custom roles with their permission grants, the
+/// custom roles assigned to users and the permissions granted to users
+/// directly.
+#[async_trait]
+pub trait RoleRepository: Interface + Send + Sync {
+    /// Finds a role by ID.
+    async fn find_by_id(&self, id: RoleId) -> ArcanaResult<Option<Role>>;
+
+    /// Finds a role by name.
+    async fn find_by_name(&self, name: &str) -> ArcanaResult<Option<Role>>;
+
+    /// Finds all roles, built-in roles first.
+    async fn find_all(&self) -> ArcanaResult<Vec<Role>>;
+
+    /// Saves a new role.
+    async fn save(&self, role: &Role) -> ArcanaResult<Role>;
+
+    /// Updates an existing role.
+    async fn update(&self, role: &Role) -> ArcanaResult<Role>;
+
+    /// Deletes a custom role and its assignments. Returns `true` if it existed.
+    async fn delete(&self, id: RoleId) -> ArcanaResult<bool>;
+
+    /// Finds the custom roles assigned to a user.
+    async fn find_assigned(&self, user_id: UserId) -> ArcanaResult<Vec<Role>>;
+
+    /// Assigns a role to a user.
+    async fn assign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<()>;
+
+    /// Removes a role from a user. Returns `true` if it was assigned.
+    async fn unassign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<bool>;
+
+    /// Finds the permissions granted to a user directly.
+    async fn find_user_permissions(&self, user_id: UserId) -> ArcanaResult<PermissionSet>;
+
+    /// Grants a permission to a user directly.
+    async fn grant(&self, user_id: UserId, permission: Permission) -> ArcanaResult<()>;
+
+    /// Revokes a direct grant. Returns `true` if it was granted.
+    async fn revoke(&self, user_id: UserId, permission: Permission) -> ArcanaResult<bool>;
+
+    /// Finds the effective permissions of a user: the grants of the built-in
+    /// role of `role`, of the custom roles assigned to the user and the
+    /// direct grants.
+    async fn find_effective_permissions(&self, user_id: UserId, role: UserRole) -> ArcanaResult<PermissionSet>;
+}
+
+/// Tenant repository trait.
+#[async_trait]
+pub trait TenantRepository: Interface + Send + Sync {
+    /// Finds a tenant by ID.
+    async fn find_by_id(&self, id: TenantId) -> ArcanaResult<Option<Tenant>>;
+
+    /// Finds a tenant by slug.
+    async fn find_by_slug(&self, slug: &str) -> ArcanaResult<Option<Tenant>>;
+
+    /// Finds a page of tenants, oldest first.
+    async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<Tenant>>;
+
+    /// Saves a new tenant.
+    async fn save(&self, tenant: &Tenant) -> ArcanaResult<Tenant>;
+
+    /// Updates an existing tenant.
+    async fn update(&self, tenant: &Tenant) -> ArcanaResult<Tenant>;
 }
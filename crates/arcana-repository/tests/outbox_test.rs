@@ -0,0 +1,101 @@
+//! Integration tests for the transactional outbox.
+//!
+//! These tests run against a real MySQL database using testcontainers.
+//! Requires Docker to be available on the system.
+
+mod common;
+
+use arcana_core::{DomainEvent, Email, User, UserCreated, UserDeleted, UserId, UserRole, UserRoleChanged};
+use arcana_repository::{
+    MySqlOutboxDaoImpl, MySqlUserDaoImpl, OutboxRepository, OutboxRepositoryImpl, UserRepository, UserRepositoryImpl,
+};
+use common::TestDatabase;
+use std::sync::Arc;
+
+fn create_test_user(username: &str) -> User {
+    let mut user = User::new(
+        username.to_string(),
+        Email::new_unchecked(format!("{username}@example.com")),
+        "hashed_password_123".to_string(),
+        None,
+        None,
+    );
+    user.activate();
+    user
+}
+
+fn repositories(db: &TestDatabase) -> (UserRepositoryImpl, OutboxRepositoryImpl) {
+    let users = UserRepositoryImpl::new(Arc::new(MySqlUserDaoImpl::new(db.pool())));
+    let outbox = OutboxRepositoryImpl::new(Arc::new(MySqlOutboxDaoImpl::new(db.pool())));
+    (users, outbox)
+}
+
+#[tokio::test]
+async fn test_user_changes_store_their_events() {
+    let db = TestDatabase::new().await;
+    let (users, outbox) = repositories(&db);
+    let mut user = create_test_user("outboxuser");
+
+    let created: Vec<Box<dyn DomainEvent>> =
+        vec![Box::new(UserCreated::new(user.id, user.username.clone(), user.email.to_string()))];
+    users.save_with_events(&user, &created).await.expect("Failed to save user");
+
+    user.change_role(UserRole::Admin);
+    let changed: Vec<Box<dyn DomainEvent>> =
+        vec![Box::new(UserRoleChanged::new(user.id, UserRole::User, UserRole::Admin, UserId::new()))];
+    users.update_with_events(&user, &changed).await.expect("Failed to update user");
+
+    let pending = outbox.find_pending(10, 5).await.expect("Failed to find pending events");
+    let types: Vec<_> = pending.iter().map(|event| event.event_type.as_str()).collect();
+    assert_eq!(types, vec!["user.created", "user.role_changed"]);
+    assert!(pending.iter().all(|event| event.aggregate_id == user.id.to_string()));
+
+    let restored = pending[1].to_domain_event().expect("Failed to decode event");
+    assert_eq!(restored.downcast_ref::<UserRoleChanged>().unwrap().new_role, UserRole::Admin);
+}
+
+#[tokio::test]
+async fn test_failed_change_stores_no_events() {
+    let db = TestDatabase::new().await;
+    let (users, outbox) = repositories(&db);
+    let user = create_test_user("duplicate");
+    users.save(&user).await.expect("Failed to save user");
+
+    // Same username: the insert fails and the transaction is rolled back
+    let duplicate = create_test_user("duplicate");
+    let created: Vec<Box<dyn DomainEvent>> = vec![Box::new(UserCreated::new(
+        duplicate.id,
+        duplicate.username.clone(),
+        duplicate.email.to_string(),
+    ))];
+    assert!(users.save_with_events(&duplicate, &created).await.is_err());
+
+    let missing = UserId::new();
+    let deleted: Vec<Box<dyn DomainEvent>> = vec![Box::new(UserDeleted::new(missing, None))];
+    assert!(!users.delete_with_events(missing, &deleted).await.unwrap());
+
+    assert!(outbox.find_pending(10, 5).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_dispatched_and_failed_events_leave_the_queue() {
+    let db = TestDatabase::new().await;
+    let (users, outbox) = repositories(&db);
+    let user = create_test_user("relayed");
+    let events: Vec<Box<dyn DomainEvent>> = vec![
+        Box::new(UserCreated::new(user.id, user.username.clone(), user.email.to_string())),
+        Box::new(UserDeleted::new(user.id, None)),
+    ];
+    users.save_with_events(&user, &events).await.expect("Failed to save user");
+
+    let pending = outbox.find_pending(10, 1).await.unwrap();
+    assert!(outbox.mark_dispatched(pending[0].id).await.unwrap());
+    assert!(!outbox.mark_dispatched(pending[0].id).await.unwrap());
+    outbox.record_failure(pending[1].id, "handler unavailable").await.unwrap();
+
+    assert!(outbox.find_pending(10, 1).await.unwrap().is_empty());
+    let retried = outbox.find_pending(10, 2).await.unwrap();
+    assert_eq!(retried.len(), 1);
+    assert_eq!(retried[0].attempts, 1);
+    assert_eq!(retried[0].last_error.as_deref(), Some("handler unavailable"));
+}
@@ -5,8 +5,8 @@
 
 mod common;
 
-use arcana_core::{Email, PageRequest, User, UserId, UserRole, UserStatus};
-use arcana_repository::{MySqlUserRepository, UserRepository};
+use arcana_core::{Email, PageRequest, Tenant, TenantId, User, UserId, UserRole, UserStatus};
+use arcana_repository::{MySqlTenantDaoImpl, MySqlUserRepository, TenantRepository, TenantRepositoryImpl, UserRepository};
 use common::TestDatabase;
 use std::sync::Arc;
 
@@ -68,7 +68,7 @@ async fn test_find_by_username() {
     repo.save(&user).await.expect("Failed to save user");
 
     let found = repo
-        .find_by_username("findme")
+        .find_by_username(TenantId::DEFAULT, "findme")
         .await
         .expect("Query failed")
         .expect("User not found");
@@ -83,7 +83,7 @@ async fn test_find_by_username_not_found() {
     let repo = MySqlUserRepository::new(db.pool());
 
     let result = repo
-        .find_by_username("nonexistent")
+        .find_by_username(TenantId::DEFAULT, "nonexistent")
         .await
         .expect("Query failed");
 
@@ -99,7 +99,7 @@ async fn test_find_by_email() {
     repo.save(&user).await.expect("Failed to save user");
 
     let found = repo
-        .find_by_email("email@example.com")
+        .find_by_email(TenantId::DEFAULT, "email@example.com")
         .await
         .expect("Query failed")
         .expect("User not found");
@@ -116,7 +116,7 @@ async fn test_find_by_email_case_insensitive() {
     repo.save(&user).await.expect("Failed to save user");
 
     let found = repo
-        .find_by_email("casesensitive@example.com")
+        .find_by_email(TenantId::DEFAULT, "casesensitive@example.com")
         .await
         .expect("Query failed")
         .expect("User not found");
@@ -133,7 +133,7 @@ async fn test_find_by_username_or_email_with_username() {
     repo.save(&user).await.expect("Failed to save user");
 
     let found = repo
-        .find_by_username_or_email("dualuser")
+        .find_by_username_or_email(TenantId::DEFAULT, "dualuser")
         .await
         .expect("Query failed")
         .expect("User not found");
@@ -150,7 +150,7 @@ async fn test_find_by_username_or_email_with_email() {
     repo.save(&user).await.expect("Failed to save user");
 
     let found = repo
-        .find_by_username_or_email("dual2@example.com")
+        .find_by_username_or_email(TenantId::DEFAULT, "dual2@example.com")
         .await
         .expect("Query failed")
         .expect("User not found");
@@ -166,8 +166,8 @@ async fn test_exists_by_username() {
     let user = create_test_user("existsuser", "exists@example.com");
     repo.save(&user).await.expect("Failed to save user");
 
-    assert!(repo.exists_by_username("existsuser").await.expect("Query failed"));
-    assert!(!repo.exists_by_username("nonexistent").await.expect("Query failed"));
+    assert!(repo.exists_by_username(TenantId::DEFAULT, "existsuser").await.expect("Query failed"));
+    assert!(!repo.exists_by_username(TenantId::DEFAULT, "nonexistent").await.expect("Query failed"));
 }
 
 #[tokio::test]
@@ -178,9 +178,9 @@ async fn test_exists_by_email() {
     let user = create_test_user("existsemail", "existsemail@example.com");
     repo.save(&user).await.expect("Failed to save user");
 
-    assert!(repo.exists_by_email("existsemail@example.com").await.expect("Query failed"));
-    assert!(repo.exists_by_email("EXISTSEMAIL@EXAMPLE.COM").await.expect("Query failed"));
-    assert!(!repo.exists_by_email("nonexistent@example.com").await.expect("Query failed"));
+    assert!(repo.exists_by_email(TenantId::DEFAULT, "existsemail@example.com").await.expect("Query failed"));
+    assert!(repo.exists_by_email(TenantId::DEFAULT, "EXISTSEMAIL@EXAMPLE.COM").await.expect("Query failed"));
+    assert!(!repo.exists_by_email(TenantId::DEFAULT, "nonexistent@example.com").await.expect("Query failed"));
 }
 
 #[tokio::test]
@@ -189,7 +189,7 @@ async fn test_find_all_empty() {
     let repo = MySqlUserRepository::new(db.pool());
 
     let page = repo
-        .find_all(PageRequest::new(0, 10))
+        .find_all(TenantId::DEFAULT, PageRequest::new(0, 10))
         .await
         .expect("Query failed");
 
@@ -208,7 +208,7 @@ async fn test_find_all_with_users() {
     }
 
     let page = repo
-        .find_all(PageRequest::new(0, 10))
+        .find_all(TenantId::DEFAULT, PageRequest::new(0, 10))
         .await
         .expect("Query failed");
 
@@ -227,7 +227,7 @@ async fn test_find_all_with_pagination() {
     }
 
     let page1 = repo
-        .find_all(PageRequest::new(0, 3))
+        .find_all(TenantId::DEFAULT, PageRequest::new(0, 3))
         .await
         .expect("Query failed");
 
@@ -236,14 +236,14 @@ async fn test_find_all_with_pagination() {
     assert_eq!(page1.info.total_pages, 4);
 
     let page2 = repo
-        .find_all(PageRequest::new(1, 3))
+        .find_all(TenantId::DEFAULT, PageRequest::new(1, 3))
         .await
         .expect("Query failed");
 
     assert_eq!(page2.content.len(), 3);
 
     let page4 = repo
-        .find_all(PageRequest::new(3, 3))
+        .find_all(TenantId::DEFAULT, PageRequest::new(3, 3))
         .await
         .expect("Query failed");
 
@@ -267,7 +267,7 @@ async fn test_find_by_role() {
     repo.save(&user).await.expect("Failed to save user");
 
     let admins = repo
-        .find_by_role(UserRole::Admin, PageRequest::new(0, 10))
+        .find_by_role(TenantId::DEFAULT, UserRole::Admin, PageRequest::new(0, 10))
         .await
         .expect("Query failed");
 
@@ -275,7 +275,7 @@ async fn test_find_by_role() {
     assert!(admins.content.iter().all(|u| u.role == UserRole::Admin));
 
     let users = repo
-        .find_by_role(UserRole::User, PageRequest::new(0, 10))
+        .find_by_role(TenantId::DEFAULT, UserRole::User, PageRequest::new(0, 10))
         .await
         .expect("Query failed");
 
@@ -382,14 +382,14 @@ async fn test_count_users() {
     let db = TestDatabase::new().await;
     let repo = MySqlUserRepository::new(db.pool());
 
-    assert_eq!(repo.count().await.expect("Query failed"), 0);
+    assert_eq!(repo.count(TenantId::DEFAULT).await.expect("Query failed"), 0);
 
     for i in 1..=3 {
         let user = create_test_user(&format!("countuser{}", i), &format!("countuser{}@example.com", i));
         repo.save(&user).await.expect("Failed to save user");
     }
 
-    assert_eq!(repo.count().await.expect("Query failed"), 3);
+    assert_eq!(repo.count(TenantId::DEFAULT).await.expect("Query failed"), 3);
 }
 
 #[tokio::test]
@@ -407,9 +407,9 @@ async fn test_count_by_role() {
     let user2 = create_test_user("countuser2", "countuser2@example.com");
     repo.save(&user2).await.expect("Failed to save user2");
 
-    assert_eq!(repo.count_by_role(UserRole::Admin).await.expect("Query failed"), 1);
-    assert_eq!(repo.count_by_role(UserRole::User).await.expect("Query failed"), 2);
-    assert_eq!(repo.count_by_role(UserRole::Moderator).await.expect("Query failed"), 0);
+    assert_eq!(repo.count_by_role(TenantId::DEFAULT, UserRole::Admin).await.expect("Query failed"), 1);
+    assert_eq!(repo.count_by_role(TenantId::DEFAULT, UserRole::User).await.expect("Query failed"), 2);
+    assert_eq!(repo.count_by_role(TenantId::DEFAULT, UserRole::Moderator).await.expect("Query failed"), 0);
 }
 
 #[tokio::test]
@@ -421,17 +421,17 @@ async fn test_deleted_users_excluded_from_queries() {
     let user_id = user.id;
     repo.save(&user).await.expect("Failed to save user");
 
-    assert_eq!(repo.count().await.expect("Query failed"), 1);
+    assert_eq!(repo.count(TenantId::DEFAULT).await.expect("Query failed"), 1);
 
     repo.delete(user_id).await.expect("Failed to delete user");
 
     // All queries should exclude the deleted user
-    assert_eq!(repo.count().await.expect("Query failed"), 0);
+    assert_eq!(repo.count(TenantId::DEFAULT).await.expect("Query failed"), 0);
     assert!(repo.find_by_id(user_id).await.expect("Query failed").is_none());
-    assert!(repo.find_by_username("todelete").await.expect("Query failed").is_none());
-    assert!(repo.find_by_email("todelete@example.com").await.expect("Query failed").is_none());
+    assert!(repo.find_by_username(TenantId::DEFAULT, "todelete").await.expect("Query failed").is_none());
+    assert!(repo.find_by_email(TenantId::DEFAULT, "todelete@example.com").await.expect("Query failed").is_none());
 
-    let page = repo.find_all(PageRequest::new(0, 10)).await.expect("Query failed");
+    let page = repo.find_all(TenantId::DEFAULT, PageRequest::new(0, 10)).await.expect("Query failed");
     assert_eq!(page.content.len(), 0);
 }
 
@@ -459,5 +459,33 @@ async fn test_concurrent_saves() {
     }
 
     let repo = MySqlUserRepository::new(db.pool());
-    assert_eq!(repo.count().await.expect("Query failed"), 5);
+    assert_eq!(repo.count(TenantId::DEFAULT).await.expect("Query failed"), 5);
+}
+
+#[tokio::test]
+async fn test_same_username_in_different_tenants() {
+    let db = TestDatabase::new().await;
+    let tenants = TenantRepositoryImpl::new(Arc::new(MySqlTenantDaoImpl::new(db.pool())));
+    let repo = MySqlUserRepository::new(db.pool());
+
+    let tenant = tenants
+        .save(&Tenant::new("acme".to_string(), "Acme Corp".to_string()))
+        .await
+        .expect("Failed to save tenant");
+
+    repo.save(&create_test_user("shared", "shared@example.com"))
+        .await
+        .expect("Failed to save user");
+    let other = create_test_user("shared", "shared@example.com").in_tenant(tenant.id);
+    repo.save(&other).await.expect("Failed to save user in second tenant");
+
+    let found = repo
+        .find_by_username(tenant.id, "shared")
+        .await
+        .expect("Query failed")
+        .expect("User not found");
+    assert_eq!(found.id, other.id);
+    assert_eq!(found.tenant_id, tenant.id);
+    assert_eq!(repo.count(TenantId::DEFAULT).await.expect("Query failed"), 1);
+    assert_eq!(repo.count(tenant.id).await.expect("Query failed"), 1);
 }
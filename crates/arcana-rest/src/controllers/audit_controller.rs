@@ -0,0 +1,266 @@
+//! Audit trail controller.
+
+use crate::{
+    extractors::AuthenticatedUser,
+    responses::{ok, AppError, ApiResult},
+    state::AppState,
+};
+use arcana_core::{ArcanaError, ArcanaResult, ErrorResponse};
+use arcana_security::guards;
+use arcana_service::{AuditChainQuery, AuditChainReport, AuditLogListResponse, AuditLogQuery, AuditLogResponse};
+use axum::{
+    body::Body,
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use futures::{stream, StreamExt, TryStreamExt};
+use serde::Deserialize;
+use tracing::{debug, error};
+
+/// Columns of the CSV export, in order.
+const CSV_HEADER: &str =
+    "id,timestamp,user_id,action,resource_type,resource_id,success,ip_address,user_agent,error_message,details,sequence,prev_hash,hash\n";
+
+/// Creates the audit router.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_audit_logs))
+        .route("/export", get(export_audit_logs))
+        .route("/verify", get(verify_audit_chain))
+}
+
+/// Format of an audit trail export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row.
+    #[default]
+    Csv,
+    /// One JSON object per line.
+    Ndjson,
+}
+
+impl ExportFormat {
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            Self::Csv => "audit-log.csv",
+            Self::Ndjson => "audit-log.ndjson",
+        }
+    }
+
+    /// Encodes a page of entries.
+    fn encode(self, entries: &[AuditLogResponse]) -> ArcanaResult<String> {
+        let mut out = String::new();
+        for entry in entries {
+            match self {
+                Self::Csv => out.push_str(&csv_row(entry)?),
+                Self::Ndjson => out.push_str(&serde_json::to_string(entry)?),
+            }
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
+
+/// Query parameters selecting the export format.
+#[derive(Debug, Deserialize)]
+pub struct ExportParams {
+    /// `csv` (default) or `ndjson`.
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+/// Query the audit trail (moderator and above).
+#[utoipa::path(
+    get,
+    path = "/audit",
+    tag = "audit",
+    params(
+        ("user_id" = Option<String>, Query, description = "Only entries of this user (UUID)"),
+        ("action" = Option<String>, Query, description = "Only entries of this action, e.g. LOGIN"),
+        ("resource_type" = Option<String>, Query, description = "Only entries about this resource type"),
+        ("success" = Option<bool>, Query, description = "Only successful or failed operations"),
+        ("from" = Option<String>, Query, description = "Only entries at or after this time (RFC 3339)"),
+        ("to" = Option<String>, Query, description = "Only entries before this time (RFC 3339)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor of the previous page"),
+        ("limit" = Option<usize>, Query, description = "Page size (default 20, max 100)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Page of audit log entries, newest first", body = AuditLogListResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - system monitor permission required", body = ErrorResponse),
+        (status = 422, description = "Invalid filter or cursor", body = ErrorResponse)
+    )
+)]
+pub async fn list_audit_logs(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Query(query): Query<AuditLogQuery>,
+) -> ApiResult<AuditLogListResponse> {
+    debug!("List audit logs request: {:?}", query);
+
+    guards::system_monitor().check(&user, None)?;
+
+    let response = state.audit_service.query_audit_logs(query).await?;
+    ok(response)
+}
+
+/// Export the audit trail as CSV or NDJSON (moderator and above).
+///
+/// Streams every entry matching the filters, newest first.
+#[utoipa::path(
+    get,
+    path = "/audit/export",
+    tag = "audit",
+    params(
+        ("format" = Option<String>, Query, description = "csv (default) or ndjson"),
+        ("user_id" = Option<String>, Query, description = "Only entries of this user (UUID)"),
+        ("action" = Option<String>, Query, description = "Only entries of this action, e.g. LOGIN"),
+        ("resource_type" = Option<String>, Query, description = "Only entries about this resource type"),
+        ("success" = Option<bool>, Query, description = "Only successful or failed operations"),
+        ("from" = Option<String>, Query, description = "Only entries at or after this time (RFC 3339)"),
+        ("to" = Option<String>, Query, description = "Only entries before this time (RFC 3339)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Matching audit log entries", content(
+            (String = "text/csv"),
+            (String = "application/x-ndjson")
+        )),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - system monitor permission required", body = ErrorResponse),
+        (status = 422, description = "Invalid filter", body = ErrorResponse)
+    )
+)]
+pub async fn export_audit_logs(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Query(params): Query<ExportParams>,
+    Query(query): Query<AuditLogQuery>,
+) -> Result<Response, AppError> {
+    debug!("Export audit logs request: {:?} as {:?}", query, params.format);
+
+    guards::system_monitor().check(&user, None)?;
+
+    let format = params.format;
+    let query = AuditLogQuery { cursor: None, limit: Some(AuditLogQuery::MAX_LIMIT), ..query };
+
+    // Fetch the first page up front so that invalid filters are still
+    // reported as an error response rather than a truncated body.
+    let first = state.audit_service.query_audit_logs(query.clone()).await?;
+    let mut head = match format {
+        ExportFormat::Csv => CSV_HEADER.to_string(),
+        ExportFormat::Ndjson => String::new(),
+    };
+    head.push_str(&format.encode(&first.entries)?);
+
+    let audit_service = state.audit_service;
+    let rest = stream::try_unfold(first.next_cursor, move |cursor| {
+        let audit_service = audit_service.clone();
+        let query = query.clone();
+        async move {
+            let Some(cursor) = cursor else {
+                return Ok(None);
+            };
+            let page = audit_service
+                .query_audit_logs(AuditLogQuery { cursor: Some(cursor), ..query })
+                .await?;
+            Ok::<_, ArcanaError>(Some((format.encode(&page.entries)?, page.next_cursor)))
+        }
+    });
+    let body = stream::once(async { Ok(head) })
+        .chain(rest)
+        .inspect_err(|e| error!("Audit log export aborted: {}", e));
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", format.file_name())),
+        ],
+        Body::from_stream(body),
+    )
+        .into_response())
+}
+
+/// Verify the hash chain of the audit trail (admin only).
+///
+/// Walks the entries of the time range in chain order and reports the
+/// first entry that was edited, removed or inserted out of band.
+#[utoipa::path(
+    get,
+    path = "/audit/verify",
+    tag = "audit",
+    params(
+        ("from" = Option<String>, Query, description = "Only entries at or after this time (RFC 3339)"),
+        ("to" = Option<String>, Query, description = "Only entries before this time (RFC 3339)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Verification report", body = AuditChainReport),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse),
+        (status = 422, description = "Invalid time range", body = ErrorResponse)
+    )
+)]
+pub async fn verify_audit_chain(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Query(query): Query<AuditChainQuery>,
+) -> ApiResult<AuditChainReport> {
+    debug!("Verify audit chain request: {:?}", query);
+
+    guards::admin().default_tenant_only().check(&user, None)?;
+
+    let report = state.audit_service.verify_audit_chain(query).await?;
+    ok(report)
+}
+
+/// Formats an entry as a CSV row without the line terminator.
+fn csv_row(entry: &AuditLogResponse) -> ArcanaResult<String> {
+    let details = entry.details.as_ref().map(serde_json::to_string).transpose()?;
+    let fields = [
+        entry.id.to_string(),
+        entry.timestamp.to_rfc3339(),
+        entry.user_id.map(|id| id.to_string()).unwrap_or_default(),
+        entry.action.to_string(),
+        entry.resource_type.clone(),
+        entry.resource_id.clone().unwrap_or_default(),
+        entry.success.to_string(),
+        entry.ip_address.clone().unwrap_or_default(),
+        entry.user_agent.clone().unwrap_or_default(),
+        entry.error_message.clone().unwrap_or_default(),
+        details.unwrap_or_default(),
+        entry.sequence.map(|sequence| sequence.to_string()).unwrap_or_default(),
+        entry.prev_hash.clone().unwrap_or_default(),
+        entry.hash.clone().unwrap_or_default(),
+    ];
+
+    Ok(fields.iter().map(|field| csv_field(field)).collect::<Vec<_>>().join(","))
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
@@ -1,15 +1,23 @@
 //! Authentication controller.
 
 use crate::{
-    extractors::{AuthenticatedUser, ValidatedJson},
-    responses::{ok, AppError, ApiResult},
+    extractors::{AuthenticatedUser, ClientIp, ValidatedJson},
+    responses::{accepted, ok, ApiResponse, ApiResult, AppError},
     state::AppState,
 };
 use arcana_core::ErrorResponse;
 use arcana_service::{
-    AuthResponse, AuthUserInfo, LoginRequest, MessageResponse, RefreshTokenRequest, RegisterRequest,
+    AuthResponse, AuthUserInfo, LoginRequest, LoginResponse, MessageResponse, MfaLoginRequest,
+    PasswordResetConfirmRequest, PasswordResetRequest, RecoveryCodesResponse, RefreshTokenRequest,
+    RegisterRequest, ResendVerificationRequest, SessionInfo, TotpCodeRequest,
+    TotpEnrollmentResponse, VerifyEmailRequest,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get, post},
+    Json, Router,
 };
-use axum::{extract::State, routing::post, Router};
 use tracing::debug;
 
 /// Creates the auth router.
@@ -17,9 +25,21 @@ pub fn router() -> Router<AppState> {
     Router::new()
         .route("/register", post(register))
         .route("/login", post(login))
+        .route("/login/mfa", post(complete_mfa_login))
         .route("/refresh", post(refresh_token))
         .route("/logout", post(logout))
-        .route("/me", axum::routing::get(get_current_user))
+        .route("/verify-email", post(verify_email))
+        .route("/resend-verification", post(resend_verification))
+        .route("/forgot-password", post(forgot_password))
+        .route("/reset-password", post(reset_password))
+        .route("/me", get(get_current_user))
+        .route("/sessions", get(list_sessions))
+        .route("/sessions/{id}", delete(revoke_session))
+        .route("/sessions/revoke-others", post(revoke_other_sessions))
+        .route("/mfa/totp/enroll", post(enroll_totp))
+        .route("/mfa/totp/enable", post(enable_totp))
+        .route("/mfa/totp/disable", post(disable_totp))
+        .route("/mfa/recovery-codes", post(regenerate_recovery_codes))
 }
 
 /// Register a new user.
@@ -52,21 +72,48 @@ pub async fn register(
     tag = "auth",
     request_body = LoginRequest,
     responses(
-        (status = 200, description = "Login successful", body = AuthResponse),
+        (status = 200, description = "Login successful, or a second factor is required", body = LoginResponse),
         (status = 401, description = "Invalid credentials", body = ErrorResponse),
-        (status = 422, description = "Validation error", body = ErrorResponse)
+        (status = 403, description = "Account is locked", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse),
+        (status = 429, description = "Too many failed logins from this address", body = ErrorResponse)
     )
 )]
 pub async fn login(
     State(state): State<AppState>,
-    ValidatedJson(request): ValidatedJson<LoginRequest>,
-) -> ApiResult<AuthResponse> {
+    ClientIp(ip_address): ClientIp,
+    ValidatedJson(mut request): ValidatedJson<LoginRequest>,
+) -> ApiResult<LoginResponse> {
     debug!("Login request for: {}", request.username_or_email);
 
+    request.ip_address = ip_address;
+
     let response = state.auth_service.login(request).await?;
     ok(response)
 }
 
+/// Complete a two-factor login with a TOTP or recovery code.
+#[utoipa::path(
+    post,
+    path = "/auth/login/mfa",
+    tag = "auth",
+    request_body = MfaLoginRequest,
+    responses(
+        (status = 200, description = "Login successful", body = AuthResponse),
+        (status = 401, description = "Invalid code or expired challenge", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn complete_mfa_login(
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<MfaLoginRequest>,
+) -> ApiResult<AuthResponse> {
+    debug!("MFA login request");
+
+    let response = state.auth_service.complete_mfa_login(request).await?;
+    ok(response)
+}
+
 /// Refresh access token using refresh token.
 #[utoipa::path(
     post,
@@ -89,6 +136,96 @@ pub async fn refresh_token(
     ok(response)
 }
 
+/// Verify an email address using the token from the verification email.
+#[utoipa::path(
+    post,
+    path = "/auth/verify-email",
+    tag = "auth",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, description = "Email verified", body = MessageResponse),
+        (status = 401, description = "Invalid or expired verification token", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn verify_email(
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<VerifyEmailRequest>,
+) -> ApiResult<MessageResponse> {
+    debug!("Email verification request");
+
+    let response = state.auth_service.verify_email(request).await?;
+    ok(response)
+}
+
+/// Send a new verification email.
+///
+/// Responds the same way whether or not the address belongs to an unverified account.
+#[utoipa::path(
+    post,
+    path = "/auth/resend-verification",
+    tag = "auth",
+    request_body = ResendVerificationRequest,
+    responses(
+        (status = 200, description = "Verification email sent if the account is unverified", body = MessageResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn resend_verification(
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<ResendVerificationRequest>,
+) -> ApiResult<MessageResponse> {
+    debug!("Resend verification request");
+
+    let response = state.auth_service.resend_verification(request).await?;
+    ok(response)
+}
+
+/// Request a password reset email.
+///
+/// Responds the same way whether or not the address belongs to an account.
+#[utoipa::path(
+    post,
+    path = "/auth/forgot-password",
+    tag = "auth",
+    request_body = PasswordResetRequest,
+    responses(
+        (status = 202, description = "Reset email sent if the address belongs to an account", body = MessageResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn forgot_password(
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<PasswordResetRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<MessageResponse>>), AppError> {
+    debug!("Forgot password request");
+
+    let response = state.auth_service.forgot_password(request).await?;
+    Ok(accepted(response))
+}
+
+/// Set a new password using the token from the password reset email.
+#[utoipa::path(
+    post,
+    path = "/auth/reset-password",
+    tag = "auth",
+    request_body = PasswordResetConfirmRequest,
+    responses(
+        (status = 200, description = "Password reset; all sessions signed out", body = MessageResponse),
+        (status = 401, description = "Invalid, used or expired reset token", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn reset_password(
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<PasswordResetConfirmRequest>,
+) -> ApiResult<MessageResponse> {
+    debug!("Reset password request");
+
+    let response = state.auth_service.reset_password(request).await?;
+    ok(response)
+}
+
 /// Logout (invalidate tokens).
 #[utoipa::path(
     post,
@@ -108,11 +245,7 @@ pub async fn logout(
 ) -> ApiResult<MessageResponse> {
     debug!("Logout request for: {}", user.username);
 
-    let user_id = user.user_id().ok_or_else(|| {
-        AppError(arcana_core::ArcanaError::Internal("Missing user ID in token".to_string()))
-    })?;
-
-    let response = state.auth_service.logout(user_id).await?;
+    let response = state.auth_service.logout(&user).await?;
     ok(response)
 }
 
@@ -138,3 +271,180 @@ pub async fn get_current_user(
     let user_info = state.auth_service.get_current_user(&user.0).await?;
     ok(user_info)
 }
+
+/// List the active sessions of the current user.
+#[utoipa::path(
+    get,
+    path = "/auth/sessions",
+    tag = "auth",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Active sessions, newest first", body = Vec<SessionInfo>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    )
+)]
+pub async fn list_sessions(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> ApiResult<Vec<SessionInfo>> {
+    debug!("List sessions for: {}", user.username);
+
+    let sessions = state.auth_service.list_sessions(&user).await?;
+    ok(sessions)
+}
+
+/// Revoke one of the current user's sessions.
+#[utoipa::path(
+    delete,
+    path = "/auth/sessions/{id}",
+    tag = "auth",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = String, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 200, description = "Session revoked", body = MessageResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse)
+    )
+)]
+pub async fn revoke_session(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> ApiResult<MessageResponse> {
+    debug!("Revoke session {} for: {}", id, user.username);
+
+    let response = state.auth_service.revoke_session(&user, &id).await?;
+    ok(response)
+}
+
+/// Log out everywhere else (revoke every session except the current one).
+#[utoipa::path(
+    post,
+    path = "/auth/sessions/revoke-others",
+    tag = "auth",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Other sessions revoked", body = MessageResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    )
+)]
+pub async fn revoke_other_sessions(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> ApiResult<MessageResponse> {
+    debug!("Revoke other sessions for: {}", user.username);
+
+    let response = state.auth_service.revoke_other_sessions(&user).await?;
+    ok(response)
+}
+
+/// Start TOTP enrolment for the current user.
+#[utoipa::path(
+    post,
+    path = "/auth/mfa/totp/enroll",
+    tag = "auth",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Secret and otpauth URI for the authenticator app", body = TotpEnrollmentResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "Two-factor authentication already enabled", body = ErrorResponse)
+    )
+)]
+pub async fn enroll_totp(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> ApiResult<TotpEnrollmentResponse> {
+    debug!("TOTP enrolment for: {}", user.username);
+
+    let response = state.auth_service.enroll_totp(&user).await?;
+    ok(response)
+}
+
+/// Confirm TOTP enrolment and receive recovery codes.
+#[utoipa::path(
+    post,
+    path = "/auth/mfa/totp/enable",
+    tag = "auth",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, description = "Two-factor authentication enabled", body = RecoveryCodesResponse),
+        (status = 400, description = "Invalid code or no pending enrolment", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "Two-factor authentication already enabled", body = ErrorResponse)
+    )
+)]
+pub async fn enable_totp(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    ValidatedJson(request): ValidatedJson<TotpCodeRequest>,
+) -> ApiResult<RecoveryCodesResponse> {
+    debug!("Enable TOTP for: {}", user.username);
+
+    let response = state.auth_service.enable_totp(&user, request).await?;
+    ok(response)
+}
+
+/// Turn off two-factor authentication with a TOTP or recovery code.
+#[utoipa::path(
+    post,
+    path = "/auth/mfa/totp/disable",
+    tag = "auth",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, description = "Two-factor authentication disabled", body = MessageResponse),
+        (status = 400, description = "Invalid code or two-factor authentication not enabled", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    )
+)]
+pub async fn disable_totp(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    ValidatedJson(request): ValidatedJson<TotpCodeRequest>,
+) -> ApiResult<MessageResponse> {
+    debug!("Disable TOTP for: {}", user.username);
+
+    let response = state.auth_service.disable_totp(&user, request).await?;
+    ok(response)
+}
+
+/// Replace the current user's recovery codes.
+#[utoipa::path(
+    post,
+    path = "/auth/mfa/recovery-codes",
+    tag = "auth",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, description = "New recovery codes", body = RecoveryCodesResponse),
+        (status = 400, description = "Invalid code or two-factor authentication not enabled", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    )
+)]
+pub async fn regenerate_recovery_codes(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    ValidatedJson(request): ValidatedJson<TotpCodeRequest>,
+) -> ApiResult<RecoveryCodesResponse> {
+    debug!("Regenerate recovery codes for: {}", user.username);
+
+    let response = state.auth_service.regenerate_recovery_codes(&user, request).await?;
+    ok(response)
+}
@@ -1,34 +1,35 @@
 //! Job management REST API controller.
 
+use arcana_core::Permission;
 use arcana_jobs::{JobId, JobSearchQuery, JobStatus as JobStatusEnum, ThroughputPeriod};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
+    middleware,
     response::IntoResponse,
     routing::{delete, get, post},
     Json, Router,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::middleware::require_permission;
 use crate::state::AppState;
 
 /// Create the jobs router.
+///
+/// Inspecting queues and jobs requires `system:monitor`; changing them
+/// requires `system:admin`.
 pub fn router() -> Router<AppState> {
-    Router::new()
+    let monitoring = Router::new()
         // Queue operations
         .route("/queues", get(list_queues))
         .route("/queues/{queue}/stats", get(queue_stats))
         .route("/queues/{queue}/jobs", get(list_queue_jobs))
-        .route("/queues/{queue}/purge", post(purge_queue))
         // Job operations
         .route("/jobs", get(search_jobs))
         .route("/jobs/{job_id}", get(get_job))
-        .route("/jobs/{job_id}", delete(cancel_job))
-        .route("/jobs/{job_id}/retry", post(retry_job))
         // DLQ operations
         .route("/dlq", get(list_dlq))
-        .route("/dlq/{job_id}/retry", post(retry_dlq_job))
-        .route("/dlq/purge", post(purge_dlq))
         // Dashboard
         .route("/dashboard", get(dashboard_stats))
         .route("/dashboard/activity", get(recent_activity))
@@ -37,9 +38,24 @@ pub fn router() -> Router<AppState> {
         .route("/workers", get(list_workers))
         // Scheduled jobs
         .route("/scheduled", get(list_scheduled_jobs))
+        .route_layer(middleware::from_fn_with_state(Permission::SystemMonitor, require_permission));
+
+    let administration = Router::new()
+        // Queue operations
+        .route("/queues/{queue}/purge", post(purge_queue))
+        // Job operations
+        .route("/jobs/{job_id}", delete(cancel_job))
+        .route("/jobs/{job_id}/retry", post(retry_job))
+        // DLQ operations
+        .route("/dlq/{job_id}/retry", post(retry_dlq_job))
+        .route("/dlq/purge", post(purge_dlq))
+        // Scheduled jobs
         .route("/scheduled/{name}/trigger", post(trigger_scheduled_job))
         .route("/scheduled/{name}/enable", post(enable_scheduled_job))
         .route("/scheduled/{name}/disable", post(disable_scheduled_job))
+        .route_layer(middleware::from_fn_with_state(Permission::SystemAdmin, require_permission));
+
+    monitoring.merge(administration)
 }
 
 // ============================================================================
@@ -0,0 +1,40 @@
+//! JSON Web Key Set controller.
+//!
+//! Publishes the public keys access tokens are signed with so other services
+//! can verify them without sharing a secret.
+
+use arcana_security::{JwkSet, TokenProviderInterface};
+use axum::{
+    extract::State,
+    http::header,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use std::sync::Arc;
+
+/// How long clients may cache the key set.
+const JWKS_CACHE_CONTROL: &str = "public, max-age=300";
+
+/// Creates the JWKS router.
+pub fn router(token_provider: Arc<dyn TokenProviderInterface>) -> Router {
+    Router::new()
+        .route("/.well-known/jwks.json", get(jwks))
+        .with_state(token_provider)
+}
+
+/// JSON Web Key Set endpoint.
+///
+/// The set is empty when tokens are signed with a shared HS256 secret.
+#[utoipa::path(
+    get,
+    path = "/.well-known/jwks.json",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Public keys for verifying access tokens", content_type = "application/json")
+    )
+)]
+pub async fn jwks(State(token_provider): State<Arc<dyn TokenProviderInterface>>) -> impl IntoResponse {
+    let jwks: JwkSet = token_provider.jwks();
+    ([(header::CACHE_CONTROL, JWKS_CACHE_CONTROL)], Json(jwks))
+}
@@ -1,8 +1,13 @@
 //! REST API controllers.
 
+pub mod audit_controller;
 pub mod auth_controller;
 pub mod health_controller;
 pub mod jobs_controller;
+pub mod jwks_controller;
+pub mod role_controller;
+pub mod tenant_controller;
 pub mod user_controller;
+pub mod webhook_controller;
 
 pub use health_controller::*;
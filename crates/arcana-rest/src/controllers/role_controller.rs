@@ -0,0 +1,414 @@
+//! Role and permission grant controller.
+
+use crate::{
+    extractors::{AuthenticatedUser, ValidatedJson},
+    responses::{created, no_content, ok, AppError, ApiResponse, ApiResult},
+    state::AppState,
+};
+use arcana_core::{ArcanaError, ErrorResponse, Permission, PermissionSet, RoleId, UserId};
+use arcana_security::{guards, Claims, ClaimsExt};
+use arcana_service::{
+    AssignRoleRequest, CreateRoleRequest, GrantPermissionRequest, RoleListResponse, RoleResponse, RoleService,
+    UpdateRoleRequest, UserPermissionsResponse,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get, post},
+    Json, Router,
+};
+use std::sync::Arc;
+use tracing::debug;
+
+/// Creates the role router.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_roles).post(create_role))
+        .route("/{id}", get(get_role).put(update_role).delete(delete_role))
+}
+
+/// Creates the router for the grants of a user, merged into the user routes.
+pub fn user_router() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/permissions", get(get_user_permissions).post(grant_permission))
+        .route("/{id}/permissions/{permission}", delete(revoke_permission))
+        .route("/{id}/roles", post(assign_role))
+        .route("/{id}/roles/{role_id}", delete(unassign_role))
+}
+
+/// List all roles, built-in roles first (requires `user:manage_roles`).
+#[utoipa::path(
+    get,
+    path = "/roles",
+    tag = "roles",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "List of roles", body = RoleListResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles required", body = ErrorResponse)
+    )
+)]
+pub async fn list_roles(State(state): State<AppState>, user: AuthenticatedUser) -> ApiResult<RoleListResponse> {
+    debug!("List roles request");
+
+    guards::user_management().check(&user, None)?;
+
+    let response = role_service(&state)?.list_roles().await?;
+    ok(response)
+}
+
+/// Create a custom role (requires `user:manage_roles`).
+#[utoipa::path(
+    post,
+    path = "/roles",
+    tag = "roles",
+    request_body = CreateRoleRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 201, description = "Role created", body = RoleResponse),
+        (status = 400, description = "Invalid or reserved role name", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles required", body = ErrorResponse),
+        (status = 409, description = "Role name already taken", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn create_role(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    ValidatedJson(request): ValidatedJson<CreateRoleRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<RoleResponse>>), AppError> {
+    debug!("Create role request: {}", request.name);
+
+    guards::user_management().default_tenant_only().check(&user, None)?;
+
+    let response = role_service(&state)?.create_role(request).await?;
+    Ok(created(response))
+}
+
+/// Get a role (requires `user:manage_roles`).
+#[utoipa::path(
+    get,
+    path = "/roles/{id}",
+    tag = "roles",
+    params(
+        ("id" = String, Path, description = "Role ID (UUID)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Role", body = RoleResponse),
+        (status = 400, description = "Invalid role ID format", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles required", body = ErrorResponse),
+        (status = 404, description = "Role not found", body = ErrorResponse)
+    )
+)]
+pub async fn get_role(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> ApiResult<RoleResponse> {
+    debug!("Get role request: {}", id);
+
+    guards::user_management().check(&user, None)?;
+
+    let response = role_service(&state)?.get_role(parse_role_id(&id)?).await?;
+    ok(response)
+}
+
+/// Change a role (requires `user:manage_roles`).
+///
+/// Built-in roles cannot be renamed, but their permissions can be changed;
+/// this affects every user with that role.
+#[utoipa::path(
+    put,
+    path = "/roles/{id}",
+    tag = "roles",
+    params(
+        ("id" = String, Path, description = "Role ID (UUID)")
+    ),
+    request_body = UpdateRoleRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Role updated", body = RoleResponse),
+        (status = 400, description = "Invalid role ID or name, or renaming a built-in role", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles required", body = ErrorResponse),
+        (status = 404, description = "Role not found", body = ErrorResponse),
+        (status = 409, description = "Role name already taken", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn update_role(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateRoleRequest>,
+) -> ApiResult<RoleResponse> {
+    debug!("Update role request: {}", id);
+
+    guards::user_management().default_tenant_only().check(&user, None)?;
+
+    let response = role_service(&state)?.update_role(parse_role_id(&id)?, request).await?;
+    ok(response)
+}
+
+/// Delete a custom role and remove it from every user (requires `user:manage_roles`).
+#[utoipa::path(
+    delete,
+    path = "/roles/{id}",
+    tag = "roles",
+    params(
+        ("id" = String, Path, description = "Role ID (UUID)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 204, description = "Role deleted"),
+        (status = 400, description = "Invalid role ID or built-in role", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles required", body = ErrorResponse),
+        (status = 404, description = "Role not found", body = ErrorResponse)
+    )
+)]
+pub async fn delete_role(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    debug!("Delete role request: {}", id);
+
+    guards::user_management().default_tenant_only().check(&user, None)?;
+
+    role_service(&state)?.delete_role(parse_role_id(&id)?).await?;
+    Ok(no_content())
+}
+
+/// Get the roles and permission grants of a user (own or `user:manage_roles`).
+#[utoipa::path(
+    get,
+    path = "/users/{id}/permissions",
+    tag = "roles",
+    params(
+        ("id" = String, Path, description = "User ID (UUID)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Permission grants of the user", body = UserPermissionsResponse),
+        (status = 400, description = "Invalid user ID format", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles required", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse)
+    )
+)]
+pub async fn get_user_permissions(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> ApiResult<UserPermissionsResponse> {
+    debug!("Get user permissions request: {}", id);
+
+    let user_id = parse_user_id(&id)?;
+    guards::user_management().allow_owner().check(&user, Some(user_id))?;
+
+    let response = role_service(&state)?.get_user_permissions(user_id).await?;
+    ok(response)
+}
+
+/// Grant a permission to a user directly (requires `user:manage_roles` and the permission).
+///
+/// `system:*` permissions can only be granted from the default tenant.
+#[utoipa::path(
+    post,
+    path = "/users/{id}/permissions",
+    tag = "roles",
+    params(
+        ("id" = String, Path, description = "User ID (UUID)")
+    ),
+    request_body = GrantPermissionRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Permission granted", body = UserPermissionsResponse),
+        (status = 400, description = "Invalid user ID format", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles and the granted permission required", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 422, description = "Unknown permission", body = ErrorResponse)
+    )
+)]
+pub async fn grant_permission(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+    Json(request): Json<GrantPermissionRequest>,
+) -> ApiResult<UserPermissionsResponse> {
+    debug!("Grant permission request: {} to {}", request.permission, id);
+
+    check_grantable(&user, &[request.permission].into_iter().collect())?;
+
+    let response = role_service(&state)?
+        .grant_permission(parse_user_id(&id)?, request.permission)
+        .await?;
+    ok(response)
+}
+
+/// Revoke a permission granted to a user directly (requires `user:manage_roles`).
+#[utoipa::path(
+    delete,
+    path = "/users/{id}/permissions/{permission}",
+    tag = "roles",
+    params(
+        ("id" = String, Path, description = "User ID (UUID)"),
+        ("permission" = String, Path, description = "Permission, e.g. `user:read`")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Permission revoked", body = UserPermissionsResponse),
+        (status = 400, description = "Invalid user ID or unknown permission", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles required", body = ErrorResponse),
+        (status = 404, description = "User not found or permission not granted", body = ErrorResponse)
+    )
+)]
+pub async fn revoke_permission(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path((id, permission)): Path<(String, String)>,
+) -> ApiResult<UserPermissionsResponse> {
+    debug!("Revoke permission request: {} from {}", permission, id);
+
+    guards::user_management().check(&user, None)?;
+
+    let permission = permission.parse::<Permission>().map_err(AppError)?;
+    let response = role_service(&state)?
+        .revoke_permission(parse_user_id(&id)?, permission)
+        .await?;
+    ok(response)
+}
+
+/// Assign a custom role to a user (requires `user:manage_roles` and the permissions of the role).
+///
+/// Roles with `system:*` permissions can only be assigned from the default tenant.
+#[utoipa::path(
+    post,
+    path = "/users/{id}/roles",
+    tag = "roles",
+    params(
+        ("id" = String, Path, description = "User ID (UUID)")
+    ),
+    request_body = AssignRoleRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Role assigned", body = UserPermissionsResponse),
+        (status = 400, description = "Invalid user ID or built-in role", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles and the permissions of the role required", body = ErrorResponse),
+        (status = 404, description = "User or role not found", body = ErrorResponse)
+    )
+)]
+pub async fn assign_role(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+    Json(request): Json<AssignRoleRequest>,
+) -> ApiResult<UserPermissionsResponse> {
+    debug!("Assign role request: {} to {}", request.role_id, id);
+
+    guards::user_management().check(&user, None)?;
+    let role = role_service(&state)?.get_role(request.role_id).await?;
+    check_grantable(&user, &role.permissions)?;
+
+    let response = role_service(&state)?
+        .assign_role(parse_user_id(&id)?, request.role_id)
+        .await?;
+    ok(response)
+}
+
+/// Remove a custom role from a user (requires `user:manage_roles`).
+#[utoipa::path(
+    delete,
+    path = "/users/{id}/roles/{role_id}",
+    tag = "roles",
+    params(
+        ("id" = String, Path, description = "User ID (UUID)"),
+        ("role_id" = String, Path, description = "Role ID (UUID)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Role removed", body = UserPermissionsResponse),
+        (status = 400, description = "Invalid user or role ID format", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles required", body = ErrorResponse),
+        (status = 404, description = "User not found or role not assigned", body = ErrorResponse)
+    )
+)]
+pub async fn unassign_role(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path((id, role_id)): Path<(String, String)>,
+) -> ApiResult<UserPermissionsResponse> {
+    debug!("Unassign role request: {} from {}", role_id, id);
+
+    guards::user_management().check(&user, None)?;
+
+    let response = role_service(&state)?
+        .unassign_role(parse_user_id(&id)?, parse_role_id(&role_id)?)
+        .await?;
+    ok(response)
+}
+
+/// Checks that the caller may hand out `permissions`.
+///
+/// Nobody may hand out permissions they do not hold themselves, and
+/// `system:*` permissions, covering the whole deployment, are only handed
+/// out from the default tenant.
+fn check_grantable(user: &Claims, permissions: &PermissionSet) -> Result<(), AppError> {
+    let guard = if permissions.iter().any(|p| p.is_system()) {
+        guards::user_management().default_tenant_only()
+    } else {
+        guards::user_management()
+    };
+    guard.check(user, None)?;
+
+    for permission in permissions.iter() {
+        user.require_permission(permission)?;
+    }
+    Ok(())
+}
+
+/// Returns the role service, which only monolithic deployments have.
+fn role_service(state: &AppState) -> Result<&Arc<dyn RoleService>, AppError> {
+    state
+        .role_service
+        .as_ref()
+        .ok_or_else(|| AppError(ArcanaError::Configuration("Role management is not available in this deployment".to_string())))
+}
+
+/// Parse a role ID from a string.
+fn parse_role_id(id: &str) -> Result<RoleId, AppError> {
+    RoleId::parse(id).map_err(|_| AppError(ArcanaError::Validation(format!("Invalid role ID: {}", id))))
+}
+
+/// Parse a user ID from a string.
+fn parse_user_id(id: &str) -> Result<UserId, AppError> {
+    UserId::parse(id).map_err(|_| AppError(ArcanaError::Validation(format!("Invalid user ID: {}", id))))
+}
@@ -0,0 +1,163 @@
+//! Tenant management controller.
+
+use crate::{
+    extractors::{PaginationQuery, ValidatedJson},
+    middleware::require_permission,
+    responses::{created, ok, AppError, ApiResponse, ApiResult},
+    state::AppState,
+};
+use arcana_core::{ArcanaError, ErrorResponse, Permission, TenantId};
+use arcana_service::{CreateTenantRequest, TenantListResponse, TenantResponse, TenantService, UpdateTenantRequest};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    routing::get,
+    Json, Router,
+};
+use std::sync::Arc;
+use tracing::debug;
+
+/// Creates the tenant router.
+///
+/// Every route requires `system:admin`, which only users of the default
+/// tenant can hold.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_tenants).post(create_tenant))
+        .route("/{id}", get(get_tenant).put(update_tenant))
+        .route_layer(middleware::from_fn_with_state(Permission::SystemAdmin, require_permission))
+}
+
+/// List tenants, oldest first (requires `system:admin`).
+#[utoipa::path(
+    get,
+    path = "/tenants",
+    tag = "tenants",
+    params(
+        ("page" = Option<usize>, Query, description = "Page number (0-indexed)"),
+        ("size" = Option<usize>, Query, description = "Page size (max 100)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "List of tenants", body = TenantListResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - system:admin required", body = ErrorResponse)
+    )
+)]
+pub async fn list_tenants(
+    State(state): State<AppState>,
+    Query(pagination): Query<PaginationQuery>,
+) -> ApiResult<TenantListResponse> {
+    debug!("List tenants request");
+
+    let response = tenant_service(&state)?.list_tenants(pagination.into()).await?;
+    ok(response)
+}
+
+/// Create a tenant (requires `system:admin`).
+///
+/// Users sign up to the tenant by sending its ID in the `X-Tenant-ID` header.
+#[utoipa::path(
+    post,
+    path = "/tenants",
+    tag = "tenants",
+    request_body = CreateTenantRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 201, description = "Tenant created", body = TenantResponse),
+        (status = 400, description = "Malformed slug", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - system:admin required", body = ErrorResponse),
+        (status = 409, description = "Slug already taken", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn create_tenant(
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<CreateTenantRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<TenantResponse>>), AppError> {
+    debug!("Create tenant request: {}", request.slug);
+
+    let response = tenant_service(&state)?.create_tenant(request).await?;
+    Ok(created(response))
+}
+
+/// Get a tenant (requires `system:admin`).
+#[utoipa::path(
+    get,
+    path = "/tenants/{id}",
+    tag = "tenants",
+    params(
+        ("id" = String, Path, description = "Tenant ID (UUID)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Tenant", body = TenantResponse),
+        (status = 400, description = "Invalid tenant ID format", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - system:admin required", body = ErrorResponse),
+        (status = 404, description = "Tenant not found", body = ErrorResponse)
+    )
+)]
+pub async fn get_tenant(State(state): State<AppState>, Path(id): Path<String>) -> ApiResult<TenantResponse> {
+    debug!("Get tenant request: {}", id);
+
+    let response = tenant_service(&state)?.get_tenant(parse_tenant_id(&id)?).await?;
+    ok(response)
+}
+
+/// Rename, deactivate or reactivate a tenant (requires `system:admin`).
+///
+/// Users of a deactivated tenant can neither sign in nor refresh their tokens.
+#[utoipa::path(
+    put,
+    path = "/tenants/{id}",
+    tag = "tenants",
+    params(
+        ("id" = String, Path, description = "Tenant ID (UUID)")
+    ),
+    request_body = UpdateTenantRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Tenant updated", body = TenantResponse),
+        (status = 400, description = "Invalid tenant ID, or deactivating the default tenant", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - system:admin required", body = ErrorResponse),
+        (status = 404, description = "Tenant not found", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn update_tenant(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateTenantRequest>,
+) -> ApiResult<TenantResponse> {
+    debug!("Update tenant request: {}", id);
+
+    let response = tenant_service(&state)?
+        .update_tenant(parse_tenant_id(&id)?, request)
+        .await?;
+    ok(response)
+}
+
+/// Returns the tenant service, which only monolithic deployments have.
+fn tenant_service(state: &AppState) -> Result<&Arc<dyn TenantService>, AppError> {
+    state
+        .tenant_service
+        .as_ref()
+        .ok_or_else(|| AppError(ArcanaError::Configuration("Tenants are not available in this deployment".to_string())))
+}
+
+/// Parse a tenant ID from a string.
+fn parse_tenant_id(id: &str) -> Result<TenantId, AppError> {
+    TenantId::parse(id).map_err(|_| AppError(ArcanaError::Validation(format!("Invalid tenant ID: {}", id))))
+}
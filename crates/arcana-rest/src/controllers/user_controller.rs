@@ -1,13 +1,13 @@
 //! User management controller.
 
 use crate::{
-    extractors::{AuthenticatedUser, PaginationQuery, ValidatedJson},
+    extractors::{perm, AuthenticatedUser, PaginationQuery, RequirePermission, ValidatedJson},
     responses::{created, no_content, ok, AppError, ApiResult},
     state::AppState,
 };
-use arcana_core::{ArcanaError, ErrorResponse, UserId};
+use arcana_core::{ArcanaError, ErrorResponse, Permission, UserId};
 use arcana_core::UserRole;
-use arcana_security::ClaimsExt;
+use arcana_security::{ClaimsExt, PermissionGuard};
 use arcana_service::{
     ChangePasswordRequest, CreateUserRequest, UpdateUserRequest, UpdateUserRoleRequest,
     UpdateUserStatusRequest, UserListResponse, UserResponse,
@@ -33,7 +33,7 @@ pub fn router() -> Router<AppState> {
         .route("/{id}/password", put(change_password))
 }
 
-/// List all users (admin only).
+/// List all users (requires `user:read`).
 #[utoipa::path(
     get,
     path = "/users",
@@ -48,23 +48,21 @@ pub fn router() -> Router<AppState> {
     responses(
         (status = 200, description = "List of users", body = UserListResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse)
+        (status = 403, description = "Forbidden - user:read permission required", body = ErrorResponse)
     )
 )]
 pub async fn list_users(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    _user: RequirePermission<perm::UserRead>,
     Query(pagination): Query<PaginationQuery>,
 ) -> ApiResult<UserListResponse> {
     debug!("List users request");
 
-    user.require_role(UserRole::Admin)?;
-
     let response = state.user_service.list_users(pagination.into()).await?;
     ok(response)
 }
 
-/// Create a new user (admin only).
+/// Create a new user (requires `user:create`).
 #[utoipa::path(
     post,
     path = "/users",
@@ -76,20 +74,18 @@ pub async fn list_users(
     responses(
         (status = 201, description = "User created", body = UserResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:create permission required", body = ErrorResponse),
         (status = 409, description = "Username or email already exists", body = ErrorResponse),
         (status = 422, description = "Validation error", body = ErrorResponse)
     )
 )]
 pub async fn create_user(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    _user: RequirePermission<perm::UserCreate>,
     ValidatedJson(request): ValidatedJson<CreateUserRequest>,
 ) -> Result<(StatusCode, Json<crate::responses::ApiResponse<UserResponse>>), AppError> {
     debug!("Create user request: {}", request.username);
 
-    user.require_role(UserRole::Admin)?;
-
     let response = state.user_service.create_user(request).await?;
     Ok(created(response))
 }
@@ -109,7 +105,7 @@ pub async fn create_user(
         (status = 200, description = "User details", body = UserResponse),
         (status = 400, description = "Invalid user ID format", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - can only view own profile or need moderator role", body = ErrorResponse),
+        (status = 403, description = "Forbidden - can only view own profile or need user:update permission", body = ErrorResponse),
         (status = 404, description = "User not found", body = ErrorResponse)
     )
 )]
@@ -122,14 +118,11 @@ pub async fn get_user(
 
     let user_id = parse_user_id(&id)?;
 
-    // Users can view themselves, admins can view anyone
-    let current_user_id = user.user_id().ok_or_else(|| {
-        AppError(ArcanaError::Internal(MISSING_USER_ID_MSG.to_string()))
-    })?;
-
-    if current_user_id != user_id {
-        user.require_role(UserRole::Moderator)?;
-    }
+    // Users can view themselves, those who may edit users can view anyone
+    PermissionGuard::new()
+        .permission(Permission::UserUpdate)
+        .allow_owner()
+        .check(&user, Some(user_id))?;
 
     let response = state.user_service.get_user(user_id).await?;
     ok(response)
@@ -151,7 +144,7 @@ pub async fn get_user(
         (status = 200, description = "User updated", body = UserResponse),
         (status = 400, description = "Invalid user ID format", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - can only update own profile or need admin role", body = ErrorResponse),
+        (status = 403, description = "Forbidden - can only update own profile or need user:update permission", body = ErrorResponse),
         (status = 404, description = "User not found", body = ErrorResponse),
         (status = 422, description = "Validation error", body = ErrorResponse)
     )
@@ -166,20 +159,17 @@ pub async fn update_user(
 
     let user_id = parse_user_id(&id)?;
 
-    // Users can update themselves, admins can update anyone
-    let current_user_id = user.user_id().ok_or_else(|| {
-        AppError(ArcanaError::Internal(MISSING_USER_ID_MSG.to_string()))
-    })?;
-
-    if current_user_id != user_id {
-        user.require_role(UserRole::Admin)?;
-    }
+    // Users can update themselves, those who may edit users can update anyone
+    PermissionGuard::new()
+        .permission(Permission::UserUpdate)
+        .allow_owner()
+        .check(&user, Some(user_id))?;
 
     let response = state.user_service.update_user(user_id, request).await?;
     ok(response)
 }
 
-/// Delete a user (admin only).
+/// Delete a user (requires `user:delete`).
 #[utoipa::path(
     delete,
     path = "/users/{id}",
@@ -194,26 +184,24 @@ pub async fn update_user(
         (status = 204, description = "User deleted"),
         (status = 400, description = "Invalid user ID format", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:delete permission required", body = ErrorResponse),
         (status = 404, description = "User not found", body = ErrorResponse)
     )
 )]
 pub async fn delete_user(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    _user: RequirePermission<perm::UserDelete>,
     Path(id): Path<String>,
 ) -> Result<StatusCode, AppError> {
     debug!("Delete user request: {}", id);
 
-    user.require_role(UserRole::Admin)?;
-
     let user_id = parse_user_id(&id)?;
     state.user_service.delete_user(user_id).await?;
 
     Ok(no_content())
 }
 
-/// Update a user's role (admin only).
+/// Update a user's role (requires `user:manage_roles`).
 #[utoipa::path(
     patch,
     path = "/users/{id}/role",
@@ -229,21 +217,19 @@ pub async fn delete_user(
         (status = 200, description = "User role updated", body = UserResponse),
         (status = 400, description = "Invalid user ID format", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - admin role required, super_admin for elevating to super_admin", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:manage_roles permission required, super_admin for elevating to super_admin", body = ErrorResponse),
         (status = 404, description = "User not found", body = ErrorResponse),
         (status = 422, description = "Validation error", body = ErrorResponse)
     )
 )]
 pub async fn update_user_role(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    user: RequirePermission<perm::UserManageRoles>,
     Path(id): Path<String>,
     ValidatedJson(request): ValidatedJson<UpdateUserRoleRequest>,
 ) -> ApiResult<UserResponse> {
     debug!("Update user role request: {} -> {:?}", id, request.role);
 
-    user.require_role(UserRole::Admin)?;
-
     // Prevent changing to super admin unless you're a super admin
     if request.role == UserRole::SuperAdmin {
         user.require_super_admin()?;
@@ -254,7 +240,7 @@ pub async fn update_user_role(
     ok(response)
 }
 
-/// Update a user's status (admin only).
+/// Update a user's status (requires `user:update`).
 #[utoipa::path(
     patch,
     path = "/users/{id}/status",
@@ -270,21 +256,19 @@ pub async fn update_user_role(
         (status = 200, description = "User status updated", body = UserResponse),
         (status = 400, description = "Invalid user ID format", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - user:update permission required", body = ErrorResponse),
         (status = 404, description = "User not found", body = ErrorResponse),
         (status = 422, description = "Validation error", body = ErrorResponse)
     )
 )]
 pub async fn update_user_status(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    _user: RequirePermission<perm::UserUpdate>,
     Path(id): Path<String>,
     ValidatedJson(request): ValidatedJson<UpdateUserStatusRequest>,
 ) -> ApiResult<UserResponse> {
     debug!("Update user status request: {} -> {:?}", id, request.status);
 
-    user.require_role(UserRole::Admin)?;
-
     let user_id = parse_user_id(&id)?;
     let response = state.user_service.update_user_status(user_id, request).await?;
     ok(response)
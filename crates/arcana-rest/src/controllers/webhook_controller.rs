@@ -0,0 +1,249 @@
+//! Webhook subscription controller.
+
+use crate::{
+    extractors::{AuthenticatedUser, PaginationQuery, ValidatedJson},
+    responses::{created, no_content, ok, AppError, ApiResponse, ApiResult},
+    state::AppState,
+};
+use arcana_core::{ArcanaError, ErrorResponse, WebhookSubscriptionId};
+use arcana_security::guards;
+use arcana_service::{
+    CreateWebhookRequest, UpdateWebhookRequest, WebhookDeliveryListResponse, WebhookListResponse, WebhookResponse,
+    WebhookService,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use std::sync::Arc;
+use tracing::debug;
+
+/// Creates the webhook router.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_webhooks).post(create_webhook))
+        .route("/{id}", get(get_webhook).put(update_webhook).delete(delete_webhook))
+        .route("/{id}/deliveries", get(list_deliveries))
+}
+
+/// List webhook subscriptions (admin only).
+#[utoipa::path(
+    get,
+    path = "/webhooks",
+    tag = "webhooks",
+    params(
+        ("page" = Option<usize>, Query, description = "Page number (0-indexed)"),
+        ("size" = Option<usize>, Query, description = "Page size (max 100)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "List of webhook subscriptions", body = WebhookListResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse)
+    )
+)]
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Query(pagination): Query<PaginationQuery>,
+) -> ApiResult<WebhookListResponse> {
+    debug!("List webhooks request");
+
+    guards::admin().default_tenant_only().check(&user, None)?;
+
+    let response = webhook_service(&state)?.list_webhooks(pagination.into()).await?;
+    ok(response)
+}
+
+/// Subscribe an endpoint to domain events (admin only).
+///
+/// Events are posted as JSON with an `X-Arcana-Signature` header holding
+/// an HMAC-SHA256 signature made with the secret.
+#[utoipa::path(
+    post,
+    path = "/webhooks",
+    tag = "webhooks",
+    request_body = CreateWebhookRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 201, description = "Webhook subscription created", body = WebhookResponse),
+        (status = 400, description = "Unknown event type", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    ValidatedJson(request): ValidatedJson<CreateWebhookRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<WebhookResponse>>), AppError> {
+    debug!("Create webhook request: {}", request.url);
+
+    guards::admin().default_tenant_only().check(&user, None)?;
+
+    let response = webhook_service(&state)?.create_webhook(request).await?;
+    Ok(created(response))
+}
+
+/// Get a webhook subscription (admin only).
+#[utoipa::path(
+    get,
+    path = "/webhooks/{id}",
+    tag = "webhooks",
+    params(
+        ("id" = String, Path, description = "Webhook subscription ID (UUID)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Webhook subscription", body = WebhookResponse),
+        (status = 400, description = "Invalid webhook ID format", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse),
+        (status = 404, description = "Webhook subscription not found", body = ErrorResponse)
+    )
+)]
+pub async fn get_webhook(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> ApiResult<WebhookResponse> {
+    debug!("Get webhook request: {}", id);
+
+    guards::admin().default_tenant_only().check(&user, None)?;
+
+    let response = webhook_service(&state)?.get_webhook(parse_webhook_id(&id)?).await?;
+    ok(response)
+}
+
+/// Change a webhook subscription (admin only).
+#[utoipa::path(
+    put,
+    path = "/webhooks/{id}",
+    tag = "webhooks",
+    params(
+        ("id" = String, Path, description = "Webhook subscription ID (UUID)")
+    ),
+    request_body = UpdateWebhookRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Webhook subscription updated", body = WebhookResponse),
+        (status = 400, description = "Invalid webhook ID or unknown event type", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse),
+        (status = 404, description = "Webhook subscription not found", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    )
+)]
+pub async fn update_webhook(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateWebhookRequest>,
+) -> ApiResult<WebhookResponse> {
+    debug!("Update webhook request: {}", id);
+
+    guards::admin().default_tenant_only().check(&user, None)?;
+
+    let response = webhook_service(&state)?
+        .update_webhook(parse_webhook_id(&id)?, request)
+        .await?;
+    ok(response)
+}
+
+/// Delete a webhook subscription and its delivery log (admin only).
+#[utoipa::path(
+    delete,
+    path = "/webhooks/{id}",
+    tag = "webhooks",
+    params(
+        ("id" = String, Path, description = "Webhook subscription ID (UUID)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 204, description = "Webhook subscription deleted"),
+        (status = 400, description = "Invalid webhook ID format", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse),
+        (status = 404, description = "Webhook subscription not found", body = ErrorResponse)
+    )
+)]
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    debug!("Delete webhook request: {}", id);
+
+    guards::admin().default_tenant_only().check(&user, None)?;
+
+    webhook_service(&state)?.delete_webhook(parse_webhook_id(&id)?).await?;
+    Ok(no_content())
+}
+
+/// List the delivery attempts of a webhook subscription, newest first (admin only).
+#[utoipa::path(
+    get,
+    path = "/webhooks/{id}/deliveries",
+    tag = "webhooks",
+    params(
+        ("id" = String, Path, description = "Webhook subscription ID (UUID)"),
+        ("page" = Option<usize>, Query, description = "Page number (0-indexed)"),
+        ("size" = Option<usize>, Query, description = "Page size (max 100)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Delivery log", body = WebhookDeliveryListResponse),
+        (status = 400, description = "Invalid webhook ID format", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden - admin role required", body = ErrorResponse),
+        (status = 404, description = "Webhook subscription not found", body = ErrorResponse)
+    )
+)]
+pub async fn list_deliveries(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+    Query(pagination): Query<PaginationQuery>,
+) -> ApiResult<WebhookDeliveryListResponse> {
+    debug!("List webhook deliveries request: {}", id);
+
+    guards::admin().default_tenant_only().check(&user, None)?;
+
+    let response = webhook_service(&state)?
+        .list_deliveries(parse_webhook_id(&id)?, pagination.into())
+        .await?;
+    ok(response)
+}
+
+/// Returns the webhook service, which only monolithic deployments have.
+fn webhook_service(state: &AppState) -> Result<&Arc<dyn WebhookService>, AppError> {
+    state
+        .webhook_service
+        .as_ref()
+        .ok_or_else(|| {
+            AppError(ArcanaError::Configuration(
+                "Webhooks are only available in monolithic deployments, which deliver them".to_string(),
+            ))
+        })
+}
+
+/// Parse a webhook subscription ID from a string.
+fn parse_webhook_id(id: &str) -> Result<WebhookSubscriptionId, AppError> {
+    WebhookSubscriptionId::parse(id)
+        .map_err(|_| AppError(ArcanaError::Validation(format!("Invalid webhook ID: {}", id))))
+}
@@ -26,7 +26,7 @@ impl std::ops::Deref for AuthenticatedUser {
 }
 
 /// Error type for authentication extraction.
-pub struct AuthError(ArcanaError);
+pub struct AuthError(pub(crate) ArcanaError);
 
 impl IntoResponse for AuthError {
     fn into_response(self) -> Response {
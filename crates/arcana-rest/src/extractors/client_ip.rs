@@ -0,0 +1,83 @@
+//! Client IP extractor.
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::request::Parts,
+};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+
+/// Extractor for the client's IP address.
+///
+/// Uses the first `X-Forwarded-For` entry or `X-Real-IP` set by a reverse
+/// proxy, falling back to the peer address when the server was started with
+/// connect info. The headers are only trustworthy behind a proxy that sets them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientIp(pub Option<String>);
+
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let header = |name: &str| {
+            parts
+                .headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.split(',').next())
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+
+        let ip = header("x-forwarded-for")
+            .or_else(|| header("x-real-ip"))
+            .or_else(|| {
+                parts
+                    .extensions
+                    .get::<ConnectInfo<SocketAddr>>()
+                    .map(|ConnectInfo(addr)| addr.ip().to_string())
+            });
+
+        Ok(Self(ip))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Request;
+
+    async fn extract(request: Request<()>) -> ClientIp {
+        let (mut parts, _) = request.into_parts();
+        ClientIp::from_request_parts(&mut parts, &()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_forwarded_for_takes_first_entry() {
+        let request = Request::builder()
+            .header("x-forwarded-for", "203.0.113.7, 10.0.0.1")
+            .header("x-real-ip", "10.0.0.2")
+            .body(())
+            .unwrap();
+        assert_eq!(extract(request).await, ClientIp(Some("203.0.113.7".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_peer_address() {
+        let mut request = Request::builder().body(()).unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([192, 0, 2, 1], 4711))));
+        assert_eq!(extract(request).await, ClientIp(Some("192.0.2.1".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_client() {
+        let request = Request::builder().body(()).unwrap();
+        assert_eq!(extract(request).await, ClientIp(None));
+    }
+}
@@ -1,9 +1,13 @@
 //! Custom Axum extractors.
 
 mod claims;
+mod client_ip;
 mod pagination;
+mod permission;
 mod validated;
 
 pub use claims::*;
+pub use client_ip::*;
 pub use pagination::*;
+pub use permission::*;
 pub use validated::*;
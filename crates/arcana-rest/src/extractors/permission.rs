@@ -0,0 +1,84 @@
+//! Permission-checking extractor.
+
+use super::{AuthError, AuthenticatedUser};
+use arcana_core::Permission;
+use arcana_security::{Claims, ClaimsExt};
+use axum::{extract::FromRequestParts, http::request::Parts};
+use std::marker::PhantomData;
+
+/// Type-level name of a [`Permission`], used as the parameter of [`RequirePermission`].
+pub trait PermissionMarker: Send + Sync + 'static {
+    /// The required permission.
+    const PERMISSION: Permission;
+}
+
+macro_rules! permission_markers {
+    ($($name:ident),* $(,)?) => {
+        /// Marker types for every [`Permission`], named like its variants.
+        pub mod perm {
+            use super::{Permission, PermissionMarker};
+
+            $(
+                #[doc = concat!("Marker for [`Permission::", stringify!($name), "`].")]
+                #[derive(Debug, Clone, Copy)]
+                pub struct $name;
+
+                impl PermissionMarker for $name {
+                    const PERMISSION: Permission = Permission::$name;
+                }
+            )*
+        }
+    };
+}
+
+permission_markers!(
+    UserRead,
+    UserCreate,
+    UserUpdate,
+    UserDelete,
+    UserManageRoles,
+    PluginRead,
+    PluginInstall,
+    PluginUninstall,
+    PluginConfigure,
+    SystemConfig,
+    SystemMonitor,
+    SystemAdmin,
+    ContentRead,
+    ContentCreate,
+    ContentUpdate,
+    ContentDelete,
+    ContentModerate,
+);
+
+/// Extractor for authenticated users granted the permission `P`.
+///
+/// Rejects the request with 401 without valid credentials and with 403 if
+/// the user's effective permissions lack `P`:
+///
+/// ```ignore
+/// async fn delete_user(user: RequirePermission<perm::UserDelete>) { ... }
+/// ```
+pub struct RequirePermission<P: PermissionMarker>(pub Claims, PhantomData<P>);
+
+impl<P: PermissionMarker> std::ops::Deref for RequirePermission<P> {
+    type Target = Claims;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<S, P> FromRequestParts<S> for RequirePermission<P>
+where
+    S: Send + Sync,
+    P: PermissionMarker,
+{
+    type Rejection = AuthError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let AuthenticatedUser(claims) = AuthenticatedUser::from_request_parts(parts, state).await?;
+        claims.require_permission(P::PERMISSION).map_err(AuthError)?;
+        Ok(Self(claims, PhantomData))
+    }
+}
@@ -19,18 +19,34 @@ pub use state::*;
 mod tests {
     use super::*;
     use crate::{
-        controllers::{auth_controller, health_controller, user_controller},
+        controllers::{
+            audit_controller, auth_controller, health_controller, jobs_controller, jwks_controller, role_controller,
+            tenant_controller, user_controller, webhook_controller,
+        },
         middleware::{auth_middleware, AuthMiddlewareState},
     };
-    use arcana_config::SecurityConfig;
-    use arcana_core::{ArcanaError, ArcanaResult, Page, PageRequest, UserId};
+    use arcana_config::{JwtAlgorithm, SecurityConfig};
+    use arcana_core::{ArcanaError, ArcanaResult, AuditAction, AuditLogId, Page, PageRequest, TenantId, UserId};
+    use arcana_core::WebhookSubscriptionId;
+    use arcana_core::{Permission, PermissionSet, RoleId};
     use arcana_core::{Email, User, UserRole};
     use arcana_repository::UserRepository;
-    use arcana_security::{Claims, TokenProvider, TokenProviderInterface};
+    use arcana_security::{
+        Claims, PermissionResolverInterface, TokenProvider, TokenProviderInterface, TokenRevocationInterface,
+        TokenRevocationStore, TokenScope, DEFAULT_USER_REVOCATION_TTL,
+    };
     use arcana_service::{
+        AuditChainQuery, AuditChainReport, AuditLogListResponse, AuditLogQuery, AuditLogResponse, AuditService,
         AuthResponse, AuthService, AuthUserInfo, ChangePasswordRequest, CreateUserRequest,
-        LoginRequest, MessageResponse, RefreshTokenRequest, RegisterRequest, UpdateUserRequest,
-        UpdateUserRoleRequest, UpdateUserStatusRequest, UserListResponse, UserResponse, UserService,
+        LoginRequest, LoginResponse, MessageResponse, MfaChallengeResponse, MfaLoginRequest,
+        RecoveryCodesResponse, RefreshTokenRequest, RegisterRequest, TotpCodeRequest,
+        TotpEnrollmentResponse,
+        PasswordResetConfirmRequest, PasswordResetRequest, ResendVerificationRequest, SessionInfo, UpdateUserRequest, UpdateUserRoleRequest,
+        UpdateUserStatusRequest, UserListResponse, UserResponse, UserService, VerifyEmailRequest,
+        CreateWebhookRequest, UpdateWebhookRequest, WebhookDeliveryListResponse, WebhookListResponse,
+        WebhookResponse, WebhookService,
+        CreateRoleRequest, RoleListResponse, RoleResponse, RoleService, UpdateRoleRequest, UserPermissionsResponse,
+        CreateTenantRequest, TenantListResponse, TenantResponse, TenantService, UpdateTenantRequest,
     };
     use async_trait::async_trait;
     use axum::{
@@ -41,7 +57,7 @@ mod tests {
     };
     use http_body_util::BodyExt;
     use serde_json::{json, Value};
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     use std::sync::{Arc, Mutex};
     use tower::ServiceExt;
 
@@ -60,6 +76,7 @@ mod tests {
             tls_cert_path: None,
             tls_key_path: None,
             password_hash_cost: 4,
+            ..Default::default()
         })
     }
 
@@ -122,34 +139,37 @@ mod tests {
             Ok(self.users.lock().unwrap().get(&id).cloned())
         }
 
-        async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.username == username)
+                .find(|u| u.tenant_id == tenant_id && u.username == username)
                 .cloned())
         }
 
-        async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.email.as_str() == email)
+                .find(|u| u.tenant_id == tenant_id && u.email.as_str() == email)
                 .cloned())
         }
 
-        async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.username == identifier || u.email.as_str() == identifier)
+                .find(|u| u.tenant_id == tenant_id && (u.username == identifier || u.email.as_str() == identifier))
                 .cloned())
         }
 
-        async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool> {
-            Ok(self.users.lock().unwrap().values().any(|u| u.username == username))
+        async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool> {
+            Ok(self.users.lock().unwrap().values().any(|u| u.tenant_id == tenant_id && u.username == username))
         }
 
-        async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool> {
-            Ok(self.users.lock().unwrap().values().any(|u| u.email.as_str().to_lowercase() == email.to_lowercase()))
+        async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool> {
+            Ok(self.users.lock().unwrap().values().any(|u| u.tenant_id == tenant_id && u.email.as_str().to_lowercase() == email.to_lowercase()))
         }
 
-        async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<User>> {
-            let users: Vec<User> = self.users.lock().unwrap().values().cloned().collect();
+        async fn find_all(&self, tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>> {
+            let users: Vec<User> = self.users.lock().unwrap().values()
+                .filter(|u| u.tenant_id == tenant_id)
+                .cloned()
+                .collect();
             let total = users.len() as u64;
             let start = page.offset();
             let end = std::cmp::min(start + page.limit(), users.len());
@@ -157,9 +177,9 @@ mod tests {
             Ok(Page::new(items, page.page, page.size, total))
         }
 
-        async fn find_by_role(&self, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
+        async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
             let users: Vec<User> = self.users.lock().unwrap().values()
-                .filter(|u| u.role == role).cloned().collect();
+                .filter(|u| u.tenant_id == tenant_id && u.role == role).cloned().collect();
             let total = users.len() as u64;
             Ok(Page::new(users, page.page, page.size, total))
         }
@@ -178,12 +198,12 @@ mod tests {
             Ok(self.users.lock().unwrap().remove(&id).is_some())
         }
 
-        async fn count(&self) -> ArcanaResult<u64> {
-            Ok(self.users.lock().unwrap().len() as u64)
+        async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64> {
+            Ok(self.users.lock().unwrap().values().filter(|u| u.tenant_id == tenant_id).count() as u64)
         }
 
-        async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64> {
-            Ok(self.users.lock().unwrap().values().filter(|u| u.role == role).count() as u64)
+        async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64> {
+            Ok(self.users.lock().unwrap().values().filter(|u| u.tenant_id == tenant_id && u.role == role).count() as u64)
         }
     }
 
@@ -282,10 +302,14 @@ mod tests {
         }
     }
 
+    /// The only TOTP code the mock auth service accepts.
+    const MOCK_TOTP_CODE: &str = "123456";
+
     /// Mock auth service for controller tests.
     struct MockAuthService {
         token_provider: Arc<TokenProvider>,
         users: Arc<Mutex<HashMap<UserId, User>>>,
+        mfa_users: Arc<Mutex<HashSet<UserId>>>,
     }
 
     impl MockAuthService {
@@ -293,7 +317,36 @@ mod tests {
             Self {
                 token_provider: Arc::new(TokenProvider::new(config)),
                 users: Arc::new(Mutex::new(HashMap::new())),
+                mfa_users: Arc::new(Mutex::new(HashSet::new())),
+            }
+        }
+
+        fn auth_response(&self, user: User) -> ArcanaResult<AuthResponse> {
+            let tokens = self.token_provider.generate_tokens(
+                user.id, &user.username, user.email.as_str(), user.role,
+            )?;
+
+            Ok(AuthResponse {
+                access_token: tokens.access_token,
+                refresh_token: tokens.refresh_token,
+                token_type: tokens.token_type,
+                expires_in: tokens.access_expires_at - chrono::Utc::now().timestamp(),
+                user: AuthUserInfo {
+                    id: user.id,
+                    username: user.username,
+                    email: user.email.to_string(),
+                    role: user.role,
+                    first_name: user.first_name,
+                    last_name: user.last_name,
+                },
+            })
+        }
+
+        fn check_code(code: &str) -> ArcanaResult<()> {
+            if code != MOCK_TOTP_CODE {
+                return Err(ArcanaError::Validation("Invalid authentication code".to_string()));
             }
+            Ok(())
         }
 
         fn with_user(config: Arc<SecurityConfig>, user: User) -> Self {
@@ -337,30 +390,35 @@ mod tests {
             })
         }
 
-        async fn login(&self, request: LoginRequest) -> ArcanaResult<AuthResponse> {
+        async fn login(&self, request: LoginRequest) -> ArcanaResult<LoginResponse> {
             let user = self.users.lock().unwrap().values()
                 .find(|u| u.username == request.username_or_email || u.email.as_str() == request.username_or_email)
                 .cloned()
                 .ok_or(ArcanaError::InvalidCredentials)?;
 
-            let tokens = self.token_provider.generate_tokens(
-                user.id, &user.username, user.email.as_str(), user.role,
-            )?;
+            if self.mfa_users.lock().unwrap().contains(&user.id) {
+                let mfa_token = self.token_provider.generate_mfa_challenge_token(
+                    user.id, &user.username, user.email.as_str(), user.role,
+                )?;
+                return Ok(LoginResponse::MfaRequired(MfaChallengeResponse {
+                    mfa_required: true,
+                    mfa_token,
+                    expires_in: 300,
+                }));
+            }
 
-            Ok(AuthResponse {
-                access_token: tokens.access_token,
-                refresh_token: tokens.refresh_token,
-                token_type: tokens.token_type,
-                expires_in: tokens.access_expires_at - chrono::Utc::now().timestamp(),
-                user: AuthUserInfo {
-                    id: user.id,
-                    username: user.username,
-                    email: user.email.to_string(),
-                    role: user.role,
-                    first_name: user.first_name,
-                    last_name: user.last_name,
-                },
-            })
+            self.auth_response(user).map(LoginResponse::Authenticated)
+        }
+
+        async fn complete_mfa_login(&self, request: MfaLoginRequest) -> ArcanaResult<AuthResponse> {
+            let claims = self.token_provider.validate_mfa_challenge_token(&request.mfa_token)?;
+            if request.code != MOCK_TOTP_CODE {
+                return Err(ArcanaError::InvalidCredentials);
+            }
+            let user = claims.user_id()
+                .and_then(|id| self.users.lock().unwrap().get(&id).cloned())
+                .ok_or(ArcanaError::InvalidCredentials)?;
+            self.auth_response(user)
         }
 
         async fn refresh_token(&self, request: RefreshTokenRequest) -> ArcanaResult<AuthResponse> {
@@ -393,7 +451,7 @@ mod tests {
             self.token_provider.validate_access_token(token)
         }
 
-        async fn logout(&self, _user_id: UserId) -> ArcanaResult<MessageResponse> {
+        async fn logout(&self, _claims: &Claims) -> ArcanaResult<MessageResponse> {
             Ok(MessageResponse::new("Successfully logged out"))
         }
 
@@ -411,6 +469,360 @@ mod tests {
                 last_name: user.last_name,
             })
         }
+
+        async fn list_sessions(&self, claims: &Claims) -> ArcanaResult<Vec<SessionInfo>> {
+            let session_id = claims.session_id.clone().unwrap_or_default();
+            Ok(vec![SessionInfo {
+                id: session_id,
+                device_id: None,
+                user_agent: None,
+                ip_address: None,
+                last_active_at: chrono::Utc::now(),
+                expires_at: chrono::Utc::now() + chrono::Duration::days(7),
+                current: true,
+            }])
+        }
+
+        async fn revoke_session(&self, claims: &Claims, session_id: &str) -> ArcanaResult<MessageResponse> {
+            if claims.session_id.as_deref() != Some(session_id) {
+                return Err(ArcanaError::not_found("Session", session_id));
+            }
+            Ok(MessageResponse::new("Session revoked"))
+        }
+
+        async fn revoke_other_sessions(&self, _claims: &Claims) -> ArcanaResult<MessageResponse> {
+            Ok(MessageResponse::new("Revoked 0 other sessions"))
+        }
+
+        async fn verify_email(&self, request: VerifyEmailRequest) -> ArcanaResult<MessageResponse> {
+            let claims = self.token_provider.validate_email_verification_token(&request.token)?;
+            let user_id = claims.user_id().ok_or(ArcanaError::InvalidToken("No user ID".to_string()))?;
+            let mut users = self.users.lock().unwrap();
+            let user = users.get_mut(&user_id)
+                .ok_or(ArcanaError::InvalidToken("User not found".to_string()))?;
+            user.verify_email();
+            Ok(MessageResponse::new("Email verified"))
+        }
+
+        async fn resend_verification(
+            &self,
+            _request: ResendVerificationRequest,
+        ) -> ArcanaResult<MessageResponse> {
+            Ok(MessageResponse::new("Verification email sent"))
+        }
+
+        async fn forgot_password(&self, _request: PasswordResetRequest) -> ArcanaResult<MessageResponse> {
+            Ok(MessageResponse::new("Password reset email sent"))
+        }
+
+        async fn reset_password(
+            &self,
+            request: PasswordResetConfirmRequest,
+        ) -> ArcanaResult<MessageResponse> {
+            if request.token != "valid-reset-token" {
+                return Err(ArcanaError::InvalidToken("Invalid reset token".to_string()));
+            }
+            Ok(MessageResponse::new("Password has been reset"))
+        }
+
+        async fn enroll_totp(&self, claims: &Claims) -> ArcanaResult<TotpEnrollmentResponse> {
+            Ok(TotpEnrollmentResponse {
+                secret: "JBSWY3DPEHPK3PXP".to_string(),
+                otpauth_uri: format!("otpauth://totp/Arcana:{}?secret=JBSWY3DPEHPK3PXP", claims.username),
+            })
+        }
+
+        async fn enable_totp(
+            &self,
+            claims: &Claims,
+            request: TotpCodeRequest,
+        ) -> ArcanaResult<RecoveryCodesResponse> {
+            Self::check_code(&request.code)?;
+            let user_id = claims.user_id().ok_or(ArcanaError::InvalidToken("No user ID".to_string()))?;
+            self.mfa_users.lock().unwrap().insert(user_id);
+            Ok(RecoveryCodesResponse { recovery_codes: vec!["abcde-fghjk".to_string()] })
+        }
+
+        async fn disable_totp(&self, claims: &Claims, request: TotpCodeRequest) -> ArcanaResult<MessageResponse> {
+            Self::check_code(&request.code)?;
+            let user_id = claims.user_id().ok_or(ArcanaError::InvalidToken("No user ID".to_string()))?;
+            self.mfa_users.lock().unwrap().remove(&user_id);
+            Ok(MessageResponse::new("Two-factor authentication disabled"))
+        }
+
+        async fn regenerate_recovery_codes(
+            &self,
+            _claims: &Claims,
+            request: TotpCodeRequest,
+        ) -> ArcanaResult<RecoveryCodesResponse> {
+            Self::check_code(&request.code)?;
+            Ok(RecoveryCodesResponse { recovery_codes: vec!["mnpqr-stuvw".to_string()] })
+        }
+    }
+
+    /// Mock audit service serving a fixed trail in pages of at most two entries.
+    struct MockAuditService {
+        entries: Vec<AuditLogResponse>,
+    }
+
+    impl MockAuditService {
+        fn new() -> Self {
+            let entry = |action, details: Option<Value>| AuditLogResponse {
+                id: AuditLogId::new(),
+                user_id: Some(UserId::new()),
+                action,
+                resource_type: "user".to_string(),
+                resource_id: None,
+                details,
+                ip_address: Some("10.0.0.1".to_string()),
+                user_agent: Some("Mozilla/5.0 (X11, Linux)".to_string()),
+                success: true,
+                error_message: None,
+                timestamp: chrono::Utc::now(),
+                sequence: None,
+                prev_hash: None,
+                hash: None,
+            };
+            Self {
+                entries: vec![
+                    entry(AuditAction::Login, None),
+                    entry(AuditAction::UserUpdate, Some(json!({ "first_name": "Ada" }))),
+                    entry(AuditAction::Logout, None),
+                ],
+            }
+        }
+    }
+
+    #[async_trait]
+    impl AuditService for MockAuditService {
+        async fn query_audit_logs(&self, query: AuditLogQuery) -> ArcanaResult<AuditLogListResponse> {
+            let matching: Vec<_> = self.entries.iter()
+                .filter(|e| query.action.is_none_or(|action| e.action == action))
+                .cloned()
+                .collect();
+            let start = query.cursor.as_deref().map_or(Ok(0), str::parse)
+                .map_err(|_| ArcanaError::Validation("Invalid audit log cursor".to_string()))?;
+            let end = (start + query.limit().min(2)).min(matching.len());
+            Ok(AuditLogListResponse {
+                entries: matching[start..end].to_vec(),
+                next_cursor: (end < matching.len()).then(|| end.to_string()),
+            })
+        }
+
+        async fn verify_audit_chain(&self, _query: AuditChainQuery) -> ArcanaResult<AuditChainReport> {
+            Ok(AuditChainReport {
+                intact: true,
+                checked: self.entries.len() as u64,
+                first_sequence: Some(1),
+                last_sequence: Some(self.entries.len() as u64),
+                broken_link: None,
+            })
+        }
+    }
+
+    /// Mock webhook service keeping subscriptions in memory.
+    #[derive(Default)]
+    struct MockWebhookService {
+        webhooks: Mutex<Vec<WebhookResponse>>,
+    }
+
+    #[async_trait]
+    impl WebhookService for MockWebhookService {
+        async fn create_webhook(&self, request: CreateWebhookRequest) -> ArcanaResult<WebhookResponse> {
+            let now = chrono::Utc::now();
+            let webhook = WebhookResponse {
+                id: WebhookSubscriptionId::new(),
+                url: request.url,
+                event_types: request.event_types,
+                description: request.description,
+                active: true,
+                created_at: now,
+                updated_at: now,
+            };
+            self.webhooks.lock().unwrap().push(webhook.clone());
+            Ok(webhook)
+        }
+
+        async fn get_webhook(&self, id: WebhookSubscriptionId) -> ArcanaResult<WebhookResponse> {
+            self.webhooks.lock().unwrap().iter()
+                .find(|w| w.id == id)
+                .cloned()
+                .ok_or_else(|| ArcanaError::not_found("WebhookSubscription", id))
+        }
+
+        async fn list_webhooks(&self, page: PageRequest) -> ArcanaResult<WebhookListResponse> {
+            let webhooks = self.webhooks.lock().unwrap().clone();
+            let total_elements = webhooks.len() as u64;
+            Ok(WebhookListResponse { webhooks, page: page.page, size: page.size, total_elements, total_pages: 1 })
+        }
+
+        async fn update_webhook(&self, id: WebhookSubscriptionId, request: UpdateWebhookRequest) -> ArcanaResult<WebhookResponse> {
+            let mut webhook = self.get_webhook(id).await?;
+            if let Some(active) = request.active {
+                webhook.active = active;
+            }
+            Ok(webhook)
+        }
+
+        async fn delete_webhook(&self, id: WebhookSubscriptionId) -> ArcanaResult<()> {
+            self.get_webhook(id).await.map(|_| ())
+        }
+
+        async fn list_deliveries(&self, id: WebhookSubscriptionId, page: PageRequest) -> ArcanaResult<WebhookDeliveryListResponse> {
+            self.get_webhook(id).await?;
+            Ok(WebhookDeliveryListResponse {
+                deliveries: Vec::new(),
+                page: page.page,
+                size: page.size,
+                total_elements: 0,
+                total_pages: 0,
+            })
+        }
+    }
+
+    /// Mock role service keeping roles in memory; every user has the `user` role.
+    #[derive(Default)]
+    struct MockRoleService {
+        roles: Mutex<Vec<RoleResponse>>,
+    }
+
+    impl MockRoleService {
+        fn permissions(user_id: UserId, roles: Vec<RoleResponse>) -> UserPermissionsResponse {
+            let mut effective = UserRole::User.default_permissions();
+            effective.extend(roles.iter().flat_map(|r| r.permissions.iter()));
+            UserPermissionsResponse {
+                user_id,
+                role: UserRole::User,
+                roles,
+                granted: PermissionSet::new(),
+                effective,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl RoleService for MockRoleService {
+        async fn create_role(&self, request: CreateRoleRequest) -> ArcanaResult<RoleResponse> {
+            let now = chrono::Utc::now();
+            let role = RoleResponse {
+                id: RoleId::new(),
+                name: request.name,
+                description: request.description,
+                permissions: request.permissions.into_iter().collect(),
+                built_in: false,
+                created_at: now,
+                updated_at: now,
+            };
+            self.roles.lock().unwrap().push(role.clone());
+            Ok(role)
+        }
+
+        async fn get_role(&self, id: RoleId) -> ArcanaResult<RoleResponse> {
+            self.roles.lock().unwrap().iter()
+                .find(|r| r.id == id)
+                .cloned()
+                .ok_or_else(|| ArcanaError::not_found("Role", id))
+        }
+
+        async fn list_roles(&self) -> ArcanaResult<RoleListResponse> {
+            Ok(RoleListResponse { roles: self.roles.lock().unwrap().clone() })
+        }
+
+        async fn update_role(&self, id: RoleId, _request: UpdateRoleRequest) -> ArcanaResult<RoleResponse> {
+            self.get_role(id).await
+        }
+
+        async fn delete_role(&self, id: RoleId) -> ArcanaResult<()> {
+            self.get_role(id).await.map(|_| ())
+        }
+
+        async fn get_user_permissions(&self, user_id: UserId) -> ArcanaResult<UserPermissionsResponse> {
+            Ok(Self::permissions(user_id, Vec::new()))
+        }
+
+        async fn assign_role(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<UserPermissionsResponse> {
+            let role = self.get_role(role_id).await?;
+            Ok(Self::permissions(user_id, vec![role]))
+        }
+
+        async fn unassign_role(&self, user_id: UserId, _role_id: RoleId) -> ArcanaResult<UserPermissionsResponse> {
+            Ok(Self::permissions(user_id, Vec::new()))
+        }
+
+        async fn grant_permission(&self, user_id: UserId, permission: Permission) -> ArcanaResult<UserPermissionsResponse> {
+            let mut permissions = Self::permissions(user_id, Vec::new());
+            permissions.granted.insert(permission);
+            permissions.effective.insert(permission);
+            Ok(permissions)
+        }
+
+        async fn revoke_permission(&self, user_id: UserId, _permission: Permission) -> ArcanaResult<UserPermissionsResponse> {
+            Ok(Self::permissions(user_id, Vec::new()))
+        }
+    }
+
+    /// Mock tenant service keeping tenants in memory.
+    #[derive(Default)]
+    struct MockTenantService {
+        tenants: Mutex<Vec<TenantResponse>>,
+    }
+
+    #[async_trait]
+    impl TenantService for MockTenantService {
+        async fn create_tenant(&self, request: CreateTenantRequest) -> ArcanaResult<TenantResponse> {
+            let now = chrono::Utc::now();
+            let tenant = TenantResponse {
+                id: TenantId::new(),
+                slug: request.slug,
+                name: request.name,
+                active: true,
+                default: false,
+                created_at: now,
+                updated_at: now,
+            };
+            self.tenants.lock().unwrap().push(tenant.clone());
+            Ok(tenant)
+        }
+
+        async fn get_tenant(&self, id: TenantId) -> ArcanaResult<TenantResponse> {
+            self.tenants.lock().unwrap().iter()
+                .find(|t| t.id == id)
+                .cloned()
+                .ok_or_else(|| ArcanaError::not_found("Tenant", id))
+        }
+
+        async fn list_tenants(&self, page: PageRequest) -> ArcanaResult<TenantListResponse> {
+            let tenants = self.tenants.lock().unwrap().clone();
+            let total_elements = tenants.len() as u64;
+            Ok(TenantListResponse { tenants, page: page.page, size: page.size, total_elements, total_pages: 1 })
+        }
+
+        async fn update_tenant(&self, id: TenantId, request: UpdateTenantRequest) -> ArcanaResult<TenantResponse> {
+            let mut tenant = self.get_tenant(id).await?;
+            if let Some(active) = request.active {
+                tenant.active = active;
+            }
+            Ok(tenant)
+        }
+    }
+
+    /// Permission resolver granting fixed permissions on top of the role defaults.
+    #[derive(Default)]
+    struct MockPermissionResolver {
+        grants: PermissionSet,
+    }
+
+    #[async_trait]
+    impl PermissionResolverInterface for MockPermissionResolver {
+        async fn resolve(&self, _user_id: UserId, role: UserRole) -> ArcanaResult<PermissionSet> {
+            let mut permissions = role.default_permissions();
+            permissions.extend(self.grants.iter());
+            Ok(permissions)
+        }
+
+        async fn invalidate_user(&self, _user_id: UserId) {}
+
+        async fn invalidate_all(&self) {}
     }
 
     /// Creates a test router with mock services.
@@ -419,17 +831,69 @@ mod tests {
         auth_service: Arc<dyn AuthService>,
         token_provider: Arc<dyn TokenProviderInterface>,
     ) -> Router {
-        let state = AppState::new(user_service, auth_service);
-        let auth_state = AuthMiddlewareState::new(token_provider);
+        let token_revocation = Arc::new(TokenRevocationStore::in_memory(DEFAULT_USER_REVOCATION_TTL));
+        create_test_router_with_revocation(user_service, auth_service, token_provider, token_revocation)
+    }
+
+    /// Creates a test router sharing the given revocation list.
+    fn create_test_router_with_revocation(
+        user_service: Arc<dyn UserService>,
+        auth_service: Arc<dyn AuthService>,
+        token_provider: Arc<dyn TokenProviderInterface>,
+        token_revocation: Arc<dyn TokenRevocationInterface>,
+    ) -> Router {
+        let resolver = Arc::new(MockPermissionResolver::default());
+        create_test_router_with_resolver(user_service, auth_service, token_provider, token_revocation, resolver)
+    }
+
+    /// Creates a test router resolving permissions with the given resolver.
+    fn create_test_router_with_resolver(
+        user_service: Arc<dyn UserService>,
+        auth_service: Arc<dyn AuthService>,
+        token_provider: Arc<dyn TokenProviderInterface>,
+        token_revocation: Arc<dyn TokenRevocationInterface>,
+        permission_resolver: Arc<dyn PermissionResolverInterface>,
+    ) -> Router {
+        create_test_router_with_roles(
+            user_service,
+            auth_service,
+            token_provider,
+            token_revocation,
+            permission_resolver,
+            Arc::new(MockRoleService::default()),
+        )
+    }
+
+    /// Creates a test router with mock services, managing roles with `role_service`.
+    fn create_test_router_with_roles(
+        user_service: Arc<dyn UserService>,
+        auth_service: Arc<dyn AuthService>,
+        token_provider: Arc<dyn TokenProviderInterface>,
+        token_revocation: Arc<dyn TokenRevocationInterface>,
+        permission_resolver: Arc<dyn PermissionResolverInterface>,
+        role_service: Arc<dyn RoleService>,
+    ) -> Router {
+        let state = AppState::new(user_service, auth_service, Arc::new(MockAuditService::new()))
+            .with_webhooks(Arc::new(MockWebhookService::default()))
+            .with_roles(role_service)
+            .with_tenants(Arc::new(MockTenantService::default()));
+        let auth_state = AuthMiddlewareState::new(token_provider.clone(), token_revocation)
+            .with_permission_resolver(permission_resolver);
 
         let api_router = Router::new()
             .nest("/auth", auth_controller::router())
-            .nest("/users", user_controller::router())
+            .nest("/users", user_controller::router().merge(role_controller::user_router()))
+            .nest("/audit", audit_controller::router())
+            .nest("/webhooks", webhook_controller::router())
+            .nest("/roles", role_controller::router())
+            .nest("/tenants", tenant_controller::router())
+            .nest("/jobs", jobs_controller::router())
             .layer(axum_middleware::from_fn_with_state(auth_state.clone(), auth_middleware))
             .with_state(state);
 
         Router::new()
             .merge(health_controller::router())
+            .merge(jwks_controller::router(token_provider))
             .nest("/api/v1", api_router)
     }
 
@@ -502,6 +966,71 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    // =============================================================================
+    // JWKS Controller Tests
+    // =============================================================================
+
+    #[tokio::test]
+    async fn test_jwks_empty_for_hs256() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::new(config));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let request = Request::builder()
+            .uri("/.well-known/jwks.json")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["keys"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_jwks_publishes_public_key() {
+        let key_path = |name: &str| {
+            format!("{}/../arcana-security/testdata/jwt/{}", env!("CARGO_MANIFEST_DIR"), name)
+        };
+        let config = Arc::new(SecurityConfig {
+            jwt_issuer: "test-issuer".to_string(),
+            jwt_audience: "test-audience".to_string(),
+            jwt_algorithm: JwtAlgorithm::EdDSA,
+            jwt_private_key_path: Some(key_path("ed25519-private.pem")),
+            jwt_public_key_path: Some(key_path("ed25519-public.pem")),
+            ..Default::default()
+        });
+        let token_provider = Arc::new(TokenProvider::from_config(config.clone()).unwrap());
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::new(create_test_security_config()));
+        let router = create_test_router(user_service, auth_service, token_provider.clone());
+
+        let request = Request::builder()
+            .uri("/.well-known/jwks.json")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key(header::CACHE_CONTROL));
+
+        let body: Value = parse_body(response.into_body()).await;
+        let keys = body["keys"].as_array().unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0]["kty"], "OKP");
+        assert_eq!(keys[0]["crv"], "Ed25519");
+        assert_eq!(keys[0]["alg"], "EdDSA");
+        assert_eq!(keys[0]["use"], "sig");
+        assert!(keys[0].get("d").is_none());
+        assert_eq!(
+            keys[0]["kid"].as_str(),
+            token_provider.keys().current().signing_key().and_then(|key| key.kid())
+        );
+    }
+
     // =============================================================================
     // Auth Controller Tests
     // =============================================================================
@@ -702,45 +1231,43 @@ mod tests {
         assert!(body["data"]["message"].as_str().unwrap().contains("logged out"));
     }
 
-    // =============================================================================
-    // User Controller Tests
-    // =============================================================================
-
     #[tokio::test]
-    async fn test_list_users_as_admin() {
+    async fn test_revoked_token_rejected() {
         let config = create_test_security_config();
         let token_provider = Arc::new(TokenProvider::new(config.clone()));
-        let admin = create_admin_user();
-        let test_user = create_test_user();
+        let user = create_test_user();
 
         let tokens = token_provider.generate_tokens(
-            admin.id, &admin.username, admin.email.as_str(), admin.role,
+            user.id, &user.username, user.email.as_str(), user.role,
         ).unwrap();
+        let token_revocation = Arc::new(TokenRevocationStore::in_memory(DEFAULT_USER_REVOCATION_TTL));
+        token_revocation.revoke_user_tokens(user.id).await.unwrap();
 
-        let user_service = Arc::new(MockUserService::with_users(vec![admin.clone(), test_user]));
-        let auth_service = Arc::new(MockAuthService::with_user(config, admin));
-        let router = create_test_router(user_service, auth_service, token_provider);
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::with_user(config, user));
+        let router = create_test_router_with_revocation(
+            user_service,
+            auth_service,
+            token_provider,
+            token_revocation,
+        );
 
         let request = Request::builder()
             .method(Method::GET)
-            .uri("/api/v1/users")
+            .uri("/api/v1/auth/me")
             .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
             .body(Body::empty())
             .unwrap();
 
         let response = router.oneshot(request).await.unwrap();
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body: Value = parse_body(response.into_body()).await;
-        assert!(body["success"].as_bool().unwrap());
-        assert_eq!(body["data"]["total_elements"], 2);
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 
     #[tokio::test]
-    async fn test_list_users_forbidden_for_regular_user() {
+    async fn test_list_sessions_authenticated() {
         let config = create_test_security_config();
         let token_provider = Arc::new(TokenProvider::new(config.clone()));
-        let user = create_test_user(); // Regular user, not admin
+        let user = create_test_user();
 
         let tokens = token_provider.generate_tokens(
             user.id, &user.username, user.email.as_str(), user.role,
@@ -752,13 +1279,410 @@ mod tests {
 
         let request = Request::builder()
             .method(Method::GET)
-            .uri("/api/v1/users")
+            .uri("/api/v1/auth/sessions")
             .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
             .body(Body::empty())
             .unwrap();
 
         let response = router.oneshot(request).await.unwrap();
-        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"][0]["id"], tokens.session_id);
+        assert_eq!(body["data"][0]["current"], true);
+    }
+
+    #[tokio::test]
+    async fn test_list_sessions_unauthenticated() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::new(config));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri("/api/v1/auth/sessions")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_session() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user = create_test_user();
+
+        let tokens = token_provider.generate_tokens(
+            user.id, &user.username, user.email.as_str(), user.role,
+        ).unwrap();
+
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::with_user(config, user));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let request = Request::builder()
+            .method(Method::DELETE)
+            .uri(format!("/api/v1/auth/sessions/{}", tokens.session_id))
+            .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = Request::builder()
+            .method(Method::DELETE)
+            .uri("/api/v1/auth/sessions/unknown")
+            .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_other_sessions() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user = create_test_user();
+
+        let tokens = token_provider.generate_tokens(
+            user.id, &user.username, user.email.as_str(), user.role,
+        ).unwrap();
+
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::with_user(config, user));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/auth/sessions/revoke-others")
+            .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_verify_email() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user = create_test_user();
+
+        let token = token_provider
+            .generate_email_verification_token(user.id, &user.username, user.email.as_str(), user.role)
+            .unwrap();
+        let access = token_provider.generate_tokens(
+            user.id, &user.username, user.email.as_str(), user.role,
+        ).unwrap();
+
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::with_user(config, user));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/auth/verify-email")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json!({ "token": token }).to_string()))
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // An access token is not a verification token
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/auth/verify-email")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json!({ "token": access.access_token }).to_string()))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_resend_verification() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::new(config));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/auth/resend-verification")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json!({ "email": "user@example.com" }).to_string()))
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/auth/resend-verification")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json!({ "email": "not-an-email" }).to_string()))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_accepted() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::new(config));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/auth/forgot-password")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json!({ "email": "user@example.com" }).to_string()))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+    }
+
+    #[tokio::test]
+    async fn test_reset_password() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::new(config));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let reset = |token: &str| {
+            Request::builder()
+                .method(Method::POST)
+                .uri("/api/v1/auth/reset-password")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(
+                    json!({ "token": token, "new_password": "NewPassword456" }).to_string(),
+                ))
+                .unwrap()
+        };
+
+        let response = router.clone().oneshot(reset("valid-reset-token")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = router.oneshot(reset("bogus")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_two_factor_login_flow() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user = create_test_user();
+        let tokens = token_provider.generate_tokens(
+            user.id, &user.username, user.email.as_str(), user.role,
+        ).unwrap();
+
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::with_user(config, user));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let authed_post = |uri: &str, body: Value| {
+            Request::builder()
+                .method(Method::POST)
+                .uri(uri)
+                .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+        let login = || {
+            Request::builder()
+                .method(Method::POST)
+                .uri("/api/v1/auth/login")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(
+                    json!({ "username_or_email": "testuser", "password": "Password123" }).to_string(),
+                ))
+                .unwrap()
+        };
+
+        let response = router.clone()
+            .oneshot(authed_post("/api/v1/auth/mfa/totp/enroll", json!({})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = parse_body(response.into_body()).await;
+        assert!(body["data"]["otpauth_uri"].as_str().unwrap().starts_with("otpauth://totp/"));
+
+        let response = router.clone()
+            .oneshot(authed_post("/api/v1/auth/mfa/totp/enable", json!({ "code": "000000" })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = router.clone()
+            .oneshot(authed_post("/api/v1/auth/mfa/totp/enable", json!({ "code": MOCK_TOTP_CODE })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"]["recovery_codes"].as_array().unwrap().len(), 1);
+
+        // The password alone now only earns a challenge
+        let response = router.clone().oneshot(login()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"]["mfa_required"], true);
+        assert!(body["data"]["access_token"].is_null());
+        let mfa_token = body["data"]["mfa_token"].as_str().unwrap().to_string();
+
+        let complete = |code: &str| {
+            Request::builder()
+                .method(Method::POST)
+                .uri("/api/v1/auth/login/mfa")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(json!({ "mfa_token": mfa_token, "code": code }).to_string()))
+                .unwrap()
+        };
+
+        let response = router.clone().oneshot(complete("654321")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = router.clone().oneshot(complete(MOCK_TOTP_CODE)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = parse_body(response.into_body()).await;
+        assert!(body["data"]["access_token"].is_string());
+
+        let response = router.clone()
+            .oneshot(authed_post("/api/v1/auth/mfa/totp/disable", json!({ "code": MOCK_TOTP_CODE })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = router.oneshot(login()).await.unwrap();
+        let body: Value = parse_body(response.into_body()).await;
+        assert!(body["data"]["access_token"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_mfa_endpoints_require_auth() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::new(config));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/auth/mfa/recovery-codes")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json!({ "code": MOCK_TOTP_CODE }).to_string()))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    // =============================================================================
+    // User Controller Tests
+    // =============================================================================
+
+    #[tokio::test]
+    async fn test_list_users_as_admin() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let admin = create_admin_user();
+        let test_user = create_test_user();
+
+        let tokens = token_provider.generate_tokens(
+            admin.id, &admin.username, admin.email.as_str(), admin.role,
+        ).unwrap();
+
+        let user_service = Arc::new(MockUserService::with_users(vec![admin.clone(), test_user]));
+        let auth_service = Arc::new(MockAuthService::with_user(config, admin));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri("/api/v1/users")
+            .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = parse_body(response.into_body()).await;
+        assert!(body["success"].as_bool().unwrap());
+        assert_eq!(body["data"]["total_elements"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_user_forbidden_for_regular_user() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let user = create_test_user(); // Regular user, not admin
+
+        let tokens = token_provider.generate_tokens(
+            user.id, &user.username, user.email.as_str(), user.role,
+        ).unwrap();
+
+        let user_service = Arc::new(MockUserService::new());
+        let auth_service = Arc::new(MockAuthService::with_user(config, user));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let body = json!({
+            "username": "newuser",
+            "email": "new@example.com",
+            "password": "Password123"
+        });
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/users")
+            .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_user_routes_follow_granted_permissions() {
+        // A regular user may list users; granted user:create they may create them, but not delete them
+        let response = send_as(create_test_user(), Method::GET, "/api/v1/users", None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let grants: PermissionSet = [Permission::UserCreate].into_iter().collect();
+        let body = json!({ "username": "newuser", "email": "new@example.com", "password": "Password123" });
+        let response = send_as_granted(create_test_user(), grants.clone(), Method::POST, "/api/v1/users", Some(body)).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let uri = format!("/api/v1/users/{}", UserId::new());
+        let response = send_as_granted(create_test_user(), grants, Method::DELETE, &uri, None).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body: Value = parse_body(response.into_body()).await;
+        assert!(body["error"]["message"].as_str().unwrap().contains("user:delete"));
+    }
+
+    #[tokio::test]
+    async fn test_jobs_routes_require_system_permissions() {
+        let response = send_as(create_test_user(), Method::GET, "/api/v1/jobs/queues", None).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // Admins may inspect the queues (unavailable without Redis), but not change them
+        let response = send_as(create_admin_user(), Method::GET, "/api/v1/jobs/queues", None).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let response = send_as(create_admin_user(), Method::POST, "/api/v1/jobs/dlq/purge", None).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let grants: PermissionSet = [Permission::SystemAdmin].into_iter().collect();
+        let response = send_as_granted(create_admin_user(), grants, Method::POST, "/api/v1/jobs/dlq/purge", None).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
     }
 
     #[tokio::test]
@@ -1078,6 +2002,362 @@ mod tests {
         assert_eq!(body["data"]["size"], 10);
     }
 
+    // =============================================================================
+    // Audit Controller Tests
+    // =============================================================================
+
+    /// Sends an authenticated GET request as the given user.
+    async fn get_as(user: User, uri: &str) -> axum::response::Response {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let tokens = token_provider.generate_tokens(
+            user.id, &user.username, user.email.as_str(), user.role,
+        ).unwrap();
+
+        let user_service = Arc::new(MockUserService::with_users(vec![user.clone()]));
+        let auth_service = Arc::new(MockAuthService::with_user(config, user));
+        let router = create_test_router(user_service, auth_service, token_provider);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
+            .body(Body::empty())
+            .unwrap();
+        router.oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_list_audit_logs_as_admin() {
+        let response = get_as(create_admin_user(), "/api/v1/audit?limit=2").await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"]["entries"].as_array().unwrap().len(), 2);
+        assert_eq!(body["data"]["entries"][0]["action"], "LOGIN");
+        assert_eq!(body["data"]["next_cursor"], "2");
+    }
+
+    #[tokio::test]
+    async fn test_list_audit_logs_filters_by_action() {
+        let response = get_as(create_admin_user(), "/api/v1/audit?action=LOGOUT&success=true").await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"]["entries"].as_array().unwrap().len(), 1);
+        assert!(body["data"]["next_cursor"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_list_audit_logs_forbidden_for_regular_user() {
+        let response = get_as(create_test_user(), "/api/v1/audit").await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = get_as(create_test_user(), "/api/v1/audit/export").await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_export_audit_logs_as_csv() {
+        let response = get_as(create_admin_user(), "/api/v1/audit/export").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/csv; charset=utf-8");
+
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let csv = String::from_utf8(bytes.to_vec()).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 4, "header plus every entry across pages");
+        assert!(lines[0].starts_with("id,timestamp,user_id,action"));
+        assert!(lines[2].contains(",USER_UPDATE,user,,true,10.0.0.1,\"Mozilla/5.0 (X11, Linux)\",,"));
+        assert!(lines[2].ends_with("\"{\"\"first_name\"\":\"\"Ada\"\"}\",,,"));
+    }
+
+    #[tokio::test]
+    async fn test_verify_audit_chain_requires_admin() {
+        let mut moderator = create_test_user();
+        moderator.change_role(UserRole::Moderator);
+        let response = get_as(moderator, "/api/v1/audit/verify").await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = get_as(create_admin_user(), "/api/v1/audit/verify").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"]["intact"], true);
+        assert_eq!(body["data"]["checked"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_export_audit_logs_as_ndjson() {
+        let response = get_as(create_admin_user(), "/api/v1/audit/export?format=ndjson").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/x-ndjson");
+
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let actions: Vec<String> = String::from_utf8(bytes.to_vec()).unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap()["action"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(actions, vec!["LOGIN", "USER_UPDATE", "LOGOUT"]);
+    }
+
+    // =============================================================================
+    // Webhook Controller Tests
+    // =============================================================================
+
+    /// Sends an authenticated request with an optional JSON body as the given user.
+    async fn send_as(user: User, method: Method, uri: &str, body: Option<Value>) -> axum::response::Response {
+        send_as_granted(user, PermissionSet::new(), method, uri, body).await
+    }
+
+    /// Sends a request as `user`, who is granted `grants` on top of their role.
+    async fn send_as_granted(
+        user: User,
+        grants: PermissionSet,
+        method: Method,
+        uri: &str,
+        body: Option<Value>,
+    ) -> axum::response::Response {
+        send_with_roles(Arc::new(MockRoleService::default()), user, grants, method, uri, body).await
+    }
+
+    /// Sends a request as `user` to a router managing roles with `role_service`.
+    async fn send_with_roles(
+        role_service: Arc<MockRoleService>,
+        user: User,
+        grants: PermissionSet,
+        method: Method,
+        uri: &str,
+        body: Option<Value>,
+    ) -> axum::response::Response {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let scope = TokenScope::new(user.tenant_id, user.role.default_permissions());
+        let tokens = token_provider.generate_scoped_tokens(
+            user.id, &user.username, user.email.as_str(), user.role, &scope, None,
+        ).unwrap();
+
+        let user_service = Arc::new(MockUserService::with_users(vec![user.clone()]));
+        let auth_service = Arc::new(MockAuthService::with_user(config, user));
+        let router = create_test_router_with_roles(
+            user_service,
+            auth_service,
+            token_provider,
+            Arc::new(TokenRevocationStore::in_memory(DEFAULT_USER_REVOCATION_TTL)),
+            Arc::new(MockPermissionResolver { grants }),
+            role_service,
+        );
+
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+            .unwrap();
+        router.oneshot(request).await.unwrap()
+    }
+
+    fn webhook_body() -> Value {
+        json!({
+            "url": "https://hooks.example.com/arcana",
+            "event_types": ["user.created"],
+            "secret": "0123456789abcdef"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_as_admin() {
+        let response = send_as(create_admin_user(), Method::POST, "/api/v1/webhooks", Some(webhook_body())).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"]["url"], "https://hooks.example.com/arcana");
+        assert_eq!(body["data"]["event_types"], json!(["user.created"]));
+        assert!(body["data"].get("secret").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_webhooks_forbidden_for_regular_user() {
+        let response = send_as(create_test_user(), Method::POST, "/api/v1/webhooks", Some(webhook_body())).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = send_as(create_test_user(), Method::GET, "/api/v1/webhooks", None).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_rejects_short_secret() {
+        let mut body = webhook_body();
+        body["secret"] = json!("short");
+        let response = send_as(create_admin_user(), Method::POST, "/api/v1/webhooks", Some(body)).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_lookups_by_id() {
+        let response = send_as(create_admin_user(), Method::GET, "/api/v1/webhooks/not-a-uuid", None).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let uri = format!("/api/v1/webhooks/{}/deliveries", WebhookSubscriptionId::new());
+        let response = send_as(create_admin_user(), Method::GET, &uri, None).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_webhooks_rejected_without_webhook_service() {
+        let config = create_test_security_config();
+        let token_provider = Arc::new(TokenProvider::new(config.clone()));
+        let admin = create_admin_user();
+        let scope = TokenScope::new(admin.tenant_id, admin.role.default_permissions());
+        let tokens = token_provider.generate_scoped_tokens(
+            admin.id, &admin.username, admin.email.as_str(), admin.role, &scope, None,
+        ).unwrap();
+
+        // Layered deployments cannot deliver webhooks and leave the service out
+        let state = AppState::new(
+            Arc::new(MockUserService::with_users(vec![admin.clone()])),
+            Arc::new(MockAuthService::with_user(config, admin)),
+            Arc::new(MockAuditService::new()),
+        );
+        let auth_state = AuthMiddlewareState::new(
+            token_provider,
+            Arc::new(TokenRevocationStore::in_memory(DEFAULT_USER_REVOCATION_TTL)),
+        );
+        let router = Router::new().nest(
+            "/api/v1",
+            Router::new()
+                .nest("/webhooks", webhook_controller::router())
+                .layer(axum_middleware::from_fn_with_state(auth_state, auth_middleware))
+                .with_state(state),
+        );
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/webhooks")
+            .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(webhook_body().to_string()))
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body: Value = parse_body(response.into_body()).await;
+        assert!(body["error"]["message"].as_str().unwrap().contains("monolithic"));
+    }
+
+    #[tokio::test]
+    async fn test_create_role_as_admin() {
+        let body = json!({ "name": "support", "permissions": ["user:read", "user:update"] });
+        let response = send_as(create_admin_user(), Method::POST, "/api/v1/roles", Some(body)).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"]["name"], "support");
+        assert_eq!(body["data"]["permissions"], json!(["user:read", "user:update"]));
+        assert_eq!(body["data"]["built_in"], false);
+    }
+
+    #[tokio::test]
+    async fn test_role_management_follows_resolved_permissions() {
+        let response = send_as(create_test_user(), Method::GET, "/api/v1/roles", None).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // A regular user granted user:manage_roles may manage roles
+        let grants: PermissionSet = [Permission::UserManageRoles].into_iter().collect();
+        let response = send_as_granted(create_test_user(), grants, Method::GET, "/api/v1/roles", None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_user_permission_grants() {
+        let user = create_test_user();
+
+        // Users may look at their own permissions, but not grant any
+        let uri = format!("/api/v1/users/{}/permissions", user.id);
+        let response = send_as(user.clone(), Method::GET, &uri, None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"]["role"], "user");
+
+        let grant = json!({ "permission": "system:monitor" });
+        let response = send_as(user, Method::POST, &uri, Some(grant.clone())).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let uri = format!("/api/v1/users/{}/permissions", UserId::new());
+        let response = send_as(create_admin_user(), Method::POST, &uri, Some(grant)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"]["granted"], json!(["system:monitor"]));
+
+        let response = send_as(create_admin_user(), Method::DELETE, &format!("{}/user:fly", uri), None).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_grants_cannot_escalate_beyond_the_caller() {
+        let uri = format!("/api/v1/users/{}/permissions", UserId::new());
+        let tenant_admin = create_admin_user().in_tenant(TenantId::new());
+
+        // Tenant admins may grant what they hold, but no system permission
+        let response = send_as(tenant_admin.clone(), Method::POST, &uri, Some(json!({ "permission": "user:delete" }))).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        for permission in ["system:admin", "system:config", "system:monitor"] {
+            let grant = json!({ "permission": permission });
+            let response = send_as(tenant_admin.clone(), Method::POST, &uri, Some(grant)).await;
+            assert_eq!(response.status(), StatusCode::FORBIDDEN, "{}", permission);
+        }
+
+        // Nobody may grant a permission they lack
+        let grant = json!({ "permission": "system:admin" });
+        let response = send_as(create_admin_user(), Method::POST, &uri, Some(grant.clone())).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let grants: PermissionSet = [Permission::SystemAdmin].into_iter().collect();
+        let response = send_as_granted(create_admin_user(), grants, Method::POST, &uri, Some(grant)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_roles_cannot_escalate_beyond_the_caller() {
+        let roles = Arc::new(MockRoleService::default());
+        let operators = roles
+            .create_role(CreateRoleRequest {
+                name: "operators".to_string(),
+                description: None,
+                permissions: vec![Permission::UserRead, Permission::SystemConfig],
+            })
+            .await
+            .unwrap();
+        let uri = format!("/api/v1/users/{}/roles", UserId::new());
+        let assign = json!({ "role_id": operators.id });
+
+        let response = send_with_roles(roles.clone(), create_admin_user(), PermissionSet::new(), Method::POST, &uri, Some(assign.clone())).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let mut super_admin = create_admin_user();
+        super_admin.change_role(UserRole::SuperAdmin);
+        let response = send_with_roles(roles.clone(), super_admin.clone(), PermissionSet::new(), Method::POST, &uri, Some(assign.clone())).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = send_with_roles(roles, super_admin.in_tenant(TenantId::new()), PermissionSet::new(), Method::POST, &uri, Some(assign)).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_tenant_management_requires_system_admin() {
+        let body = json!({ "slug": "acme", "name": "Acme Corp" });
+        let response = send_as(create_admin_user(), Method::POST, "/api/v1/tenants", Some(body.clone())).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let grants: PermissionSet = [Permission::SystemAdmin].into_iter().collect();
+        let response = send_as_granted(create_admin_user(), grants.clone(), Method::POST, "/api/v1/tenants", Some(body)).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body: Value = parse_body(response.into_body()).await;
+        assert_eq!(body["data"]["slug"], "acme");
+        assert_eq!(body["data"]["default"], false);
+
+        let response = send_as_granted(create_admin_user(), grants, Method::GET, "/api/v1/tenants/not-a-uuid", None).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     // =============================================================================
     // Middleware Tests
     // =============================================================================
@@ -1180,6 +2460,7 @@ mod tests {
             tls_cert_path: None,
             tls_key_path: None,
             password_hash_cost: 4,
+            ..Default::default()
         });
         let wrong_token_provider = Arc::new(TokenProvider::new(wrong_config.clone()));
         let user = create_test_user();
@@ -1285,19 +2566,20 @@ mod tests {
         let config = create_test_security_config();
         let token_provider = Arc::new(TokenProvider::new(config.clone()));
         let user = create_test_user(); // Regular user, not admin
+        let user_id = user.id;
 
         let tokens = token_provider.generate_tokens(
             user.id, &user.username, user.email.as_str(), user.role,
         ).unwrap();
 
-        let user_service = Arc::new(MockUserService::new());
+        let user_service = Arc::new(MockUserService::with_users(vec![user.clone()]));
         let auth_service = Arc::new(MockAuthService::with_user(config, user));
         let router = create_test_router(user_service, auth_service, token_provider);
 
         // Try to access admin-only endpoint
         let request = Request::builder()
-            .method(Method::GET)
-            .uri("/api/v1/users")
+            .method(Method::DELETE)
+            .uri(format!("/api/v1/users/{}", user_id))
             .header(header::AUTHORIZATION, create_auth_header(&tokens.access_token))
             .body(Body::empty())
             .unwrap();
@@ -1,6 +1,11 @@
 //! Authentication middleware.
 
-use arcana_security::{Claims, TokenProvider, TokenProviderInterface};
+use crate::responses::AppError;
+use arcana_core::{ArcanaError, Permission, PermissionSet};
+use arcana_security::{
+    authenticate_access_token, Claims, ClaimsExt, PermissionResolverInterface, TokenProvider, TokenProviderInterface,
+    TokenRevocationInterface, TokenRevocationStore, DEFAULT_USER_REVOCATION_TTL,
+};
 use axum::{
     body::Body,
     extract::State,
@@ -9,24 +14,48 @@ use axum::{
     response::Response,
 };
 use std::sync::Arc;
-use tracing::debug;
+use tracing::{debug, warn};
 
 /// Authentication middleware state.
 #[derive(Clone)]
 pub struct AuthMiddlewareState {
     pub token_provider: Arc<dyn TokenProviderInterface>,
+    pub token_revocation: Arc<dyn TokenRevocationInterface>,
+    /// Resolves the effective permissions of authenticated users; without
+    /// it the permissions embedded in their token apply, or the built-in
+    /// grants of their role for tokens without them.
+    pub permission_resolver: Option<Arc<dyn PermissionResolverInterface>>,
 }
 
 impl AuthMiddlewareState {
-    /// Creates a new auth middleware state from a token provider.
-    pub fn new(token_provider: Arc<dyn TokenProviderInterface>) -> Self {
-        Self { token_provider }
+    /// Creates a new auth middleware state from a token provider and revocation list.
+    pub fn new(
+        token_provider: Arc<dyn TokenProviderInterface>,
+        token_revocation: Arc<dyn TokenRevocationInterface>,
+    ) -> Self {
+        Self {
+            token_provider,
+            token_revocation,
+            permission_resolver: None,
+        }
+    }
+
+    /// Resolves the effective permissions of authenticated users with `resolver`.
+    #[must_use]
+    pub fn with_permission_resolver(mut self, resolver: Arc<dyn PermissionResolverInterface>) -> Self {
+        self.permission_resolver = Some(resolver);
+        self
     }
 
     /// Creates from a concrete TokenProvider (for backward compatibility).
+    ///
+    /// Uses a private in-memory revocation list, so revocations made
+    /// elsewhere are not seen.
     pub fn from_provider(provider: Arc<TokenProvider>) -> Self {
         Self {
             token_provider: provider,
+            token_revocation: Arc::new(TokenRevocationStore::in_memory(DEFAULT_USER_REVOCATION_TTL)),
+            permission_resolver: None,
         }
     }
 }
@@ -34,7 +63,11 @@ impl AuthMiddlewareState {
 /// Authentication middleware that validates JWT tokens.
 ///
 /// This middleware extracts the token from the Authorization header,
-/// validates it, and adds the claims to the request extensions.
+/// validates it, checks it against the revocation list and adds the claims
+/// to the request extensions. With a permission resolver, the permissions
+/// embedded in the token are replaced by freshly resolved ones, so grant
+/// changes apply before the token expires; if they cannot be resolved, none
+/// are granted.
 pub async fn auth_middleware(
     State(state): State<AuthMiddlewareState>,
     mut request: Request<Body>,
@@ -48,10 +81,23 @@ pub async fn auth_middleware(
 
     if let Some(auth_header) = auth_header {
         if let Some(token) = auth_header.strip_prefix("Bearer ") {
-            // Validate token
-            match state.token_provider.validate_access_token(token) {
-                Ok(claims) => {
+            // Validate token and reject revoked ones
+            match authenticate_access_token(
+                state.token_provider.as_ref(),
+                state.token_revocation.as_ref(),
+                token,
+            )
+            .await
+            {
+                Ok(mut claims) => {
                     debug!("Authenticated user: {}", claims.username);
+                    if let (Some(resolver), Some(user_id)) = (&state.permission_resolver, claims.user_id()) {
+                        let permissions = resolver.resolve(user_id, claims.role).await.unwrap_or_else(|e| {
+                            warn!("Failed to resolve permissions of {}: {}", claims.username, e);
+                            PermissionSet::new()
+                        });
+                        claims.permissions = Some(permissions);
+                    }
                     request.extensions_mut().insert(claims);
                 }
                 Err(e) => {
@@ -81,6 +127,28 @@ pub async fn require_auth(
     Ok(next.run(request).await)
 }
 
+/// Middleware that requires a permission, for use as a route layer:
+///
+/// ```ignore
+/// router.route_layer(middleware::from_fn_with_state(Permission::SystemMonitor, require_permission))
+/// ```
+///
+/// Returns 401 if no valid token is present and 403 if the user's effective
+/// permissions lack the required one.
+pub async fn require_permission(
+    State(permission): State<Permission>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, AppError> {
+    let claims = request
+        .extensions()
+        .get::<Claims>()
+        .ok_or_else(|| ArcanaError::Unauthorized("Invalid or expired token".to_string()))?;
+    claims.require_permission(permission)?;
+
+    Ok(next.run(request).await)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,9 +167,13 @@ mod tests {
             tls_cert_path: None,
             tls_key_path: None,
             password_hash_cost: 4,
+            ..Default::default()
         });
         let provider = Arc::new(TokenProvider::new(config));
-        let state = AuthMiddlewareState::new(provider as Arc<dyn TokenProviderInterface>);
+        let state = AuthMiddlewareState::new(
+            provider as Arc<dyn TokenProviderInterface>,
+            Arc::new(TokenRevocationStore::in_memory(DEFAULT_USER_REVOCATION_TTL)),
+        );
         // Verify state was created without panicking
         let _ = state.token_provider.clone();
     }
@@ -118,6 +190,7 @@ mod tests {
             tls_cert_path: None,
             tls_key_path: None,
             password_hash_cost: 4,
+            ..Default::default()
         });
         let provider = Arc::new(TokenProvider::new(config));
         let state = AuthMiddlewareState::from_provider(provider);
@@ -136,9 +209,13 @@ mod tests {
             tls_cert_path: None,
             tls_key_path: None,
             password_hash_cost: 4,
+            ..Default::default()
         });
         let provider = Arc::new(TokenProvider::new(config));
-        let state = AuthMiddlewareState::new(provider as Arc<dyn TokenProviderInterface>);
+        let state = AuthMiddlewareState::new(
+            provider as Arc<dyn TokenProviderInterface>,
+            Arc::new(TokenRevocationStore::in_memory(DEFAULT_USER_REVOCATION_TTL)),
+        );
         let _cloned = state.clone();
     }
 }
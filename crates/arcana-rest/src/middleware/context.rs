@@ -0,0 +1,131 @@
+//! Request context middleware.
+
+use crate::extractors::ClientIp;
+use arcana_core::TenantId;
+use arcana_security::Claims;
+use arcana_service::RequestContext;
+use axum::{
+    body::Body,
+    http::{header::USER_AGENT, Request},
+    middleware::Next,
+    response::Response,
+};
+
+/// Header selecting the tenant a request acts on.
+///
+/// Honoured for anonymous requests (login, registration) and for holders of
+/// `system:admin`; everyone else acts on the tenant of their token.
+pub const TENANT_HEADER: &str = "x-tenant-id";
+
+/// Middleware that runs the request inside a [`RequestContext`].
+///
+/// Records the authenticated user, tenant, client IP and user agent so that
+/// the audit trail can attribute whatever the request changes. Must be
+/// layered inside [`auth_middleware`](super::auth_middleware) to see the
+/// claims. A malformed [`TENANT_HEADER`] is ignored.
+pub async fn request_context_middleware(
+    ClientIp(ip_address): ClientIp,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let user_agent = request
+        .headers()
+        .get(USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let requested_tenant = request
+        .headers()
+        .get(TENANT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| TenantId::parse(value).ok());
+    let claims = request.extensions().get::<Claims>();
+    let actor = claims.and_then(Claims::user_id);
+    let tenant = match claims {
+        Some(claims) => claims.acting_tenant(requested_tenant),
+        None => requested_tenant.unwrap_or_default(),
+    };
+
+    RequestContext::new(ip_address, user_agent)
+        .with_actor(actor)
+        .with_tenant(Some(tenant))
+        .scope(next.run(request))
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{middleware, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn current_context() -> String {
+        let context = RequestContext::current();
+        format!("{:?}|{:?}", context.ip_address, context.user_agent)
+    }
+
+    async fn current_tenant() -> String {
+        RequestContext::current().tenant_id().to_string()
+    }
+
+    async fn tenant_of(tenant_header: Option<String>, claims: Option<Claims>) -> String {
+        let app = Router::new()
+            .route("/", get(current_tenant))
+            .layer(middleware::from_fn(request_context_middleware));
+
+        let mut request = Request::builder().uri("/");
+        if let Some(tenant) = tenant_header {
+            request = request.header(TENANT_HEADER, tenant);
+        }
+        let mut request = request.body(Body::empty()).unwrap();
+        if let Some(claims) = claims {
+            request.extensions_mut().insert(claims);
+        }
+
+        let response = app.oneshot(request).await.unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_handler_sees_request_context() {
+        let app = Router::new()
+            .route("/", get(current_context))
+            .layer(middleware::from_fn(request_context_middleware));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header("x-forwarded-for", "203.0.113.7")
+                    .header(USER_AGENT, "curl/8.0")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"Some(\"203.0.113.7\")|Some(\"curl/8.0\")");
+    }
+
+    #[tokio::test]
+    async fn test_tenant_header_selects_tenant_of_anonymous_requests_only() {
+        let tenant_id = TenantId::new();
+
+        assert_eq!(tenant_of(None, None).await, TenantId::DEFAULT.to_string());
+        assert_eq!(tenant_of(Some(tenant_id.to_string()), None).await, tenant_id.to_string());
+        assert_eq!(tenant_of(Some("not-a-tenant".to_string()), None).await, TenantId::DEFAULT.to_string());
+
+        let claims = Claims::new_access(
+            arcana_core::UserId::new(),
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            arcana_core::UserRole::User,
+            "arcana".to_string(),
+            "arcana-api".to_string(),
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        );
+        assert_eq!(tenant_of(Some(tenant_id.to_string()), Some(claims)).await, TenantId::DEFAULT.to_string());
+    }
+}
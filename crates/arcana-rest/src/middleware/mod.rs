@@ -1,7 +1,9 @@
 //! Axum middleware.
 
 mod auth;
+mod context;
 mod logging;
 
 pub use auth::*;
+pub use context::*;
 pub use logging::*;
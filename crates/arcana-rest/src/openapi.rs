@@ -2,13 +2,26 @@
 //!
 //! This module provides OpenAPI/Swagger documentation generation for the REST API.
 
-use arcana_core::{ErrorResponse, FieldError, UserRole, UserStatus, UserId};
+use arcana_core::{AuditAction, AuditChainBreak, AuditChainBreakReason, AuditLogId, ErrorResponse, FieldError, UserRole, UserStatus, UserId};
+use arcana_core::{WebhookDeliveryId, WebhookDeliveryStatus, WebhookSubscriptionId};
+use arcana_core::{Permission, PermissionSet, RoleId, TenantId};
 use arcana_service::{
-    AuthResponse, AuthUserInfo, ChangePasswordRequest, CreateUserRequest, LoginRequest,
-    MessageResponse, PasswordResetConfirmRequest, PasswordResetRequest, RefreshTokenRequest,
-    RegisterRequest, UpdateUserRequest, UpdateUserRoleRequest, UpdateUserStatusRequest,
-    UserListResponse, UserResponse,
+    AuditChainReport, AuditLogListResponse, AuditLogResponse, AuthResponse, AuthUserInfo, ChangePasswordRequest, CreateUserRequest, LoginRequest,
+    LoginResponse, MessageResponse, MfaChallengeResponse, MfaLoginRequest,
+    PasswordResetConfirmRequest, PasswordResetRequest, RecoveryCodesResponse, RefreshTokenRequest,
+    RegisterRequest, ResendVerificationRequest, SessionInfo, TotpCodeRequest,
+    TotpEnrollmentResponse, UpdateUserRequest, UpdateUserRoleRequest, UpdateUserStatusRequest,
+    UserListResponse, UserResponse, VerifyEmailRequest,
 };
+use arcana_service::{
+    CreateWebhookRequest, UpdateWebhookRequest, WebhookDeliveryListResponse, WebhookDeliveryResponse, WebhookListResponse,
+    WebhookResponse,
+};
+use arcana_service::{
+    AssignRoleRequest, CreateRoleRequest, GrantPermissionRequest, RoleListResponse, RoleResponse, UpdateRoleRequest,
+    UserPermissionsResponse,
+};
+use arcana_service::{CreateTenantRequest, TenantListResponse, TenantResponse, UpdateTenantRequest};
 use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa::{Modify, OpenApi};
 
@@ -35,9 +48,22 @@ use utoipa::{Modify, OpenApi};
         // Auth endpoints
         crate::controllers::auth_controller::register,
         crate::controllers::auth_controller::login,
+        crate::controllers::auth_controller::complete_mfa_login,
         crate::controllers::auth_controller::refresh_token,
         crate::controllers::auth_controller::logout,
+        crate::controllers::auth_controller::verify_email,
+        crate::controllers::auth_controller::resend_verification,
+        crate::controllers::auth_controller::forgot_password,
+        crate::controllers::auth_controller::reset_password,
         crate::controllers::auth_controller::get_current_user,
+        crate::controllers::auth_controller::list_sessions,
+        crate::controllers::auth_controller::revoke_session,
+        crate::controllers::auth_controller::revoke_other_sessions,
+        crate::controllers::auth_controller::enroll_totp,
+        crate::controllers::auth_controller::enable_totp,
+        crate::controllers::auth_controller::disable_totp,
+        crate::controllers::auth_controller::regenerate_recovery_codes,
+        crate::controllers::jwks_controller::jwks,
         // User endpoints
         crate::controllers::user_controller::list_users,
         crate::controllers::user_controller::create_user,
@@ -47,6 +73,33 @@ use utoipa::{Modify, OpenApi};
         crate::controllers::user_controller::update_user_role,
         crate::controllers::user_controller::update_user_status,
         crate::controllers::user_controller::change_password,
+        // Audit endpoints
+        crate::controllers::audit_controller::list_audit_logs,
+        crate::controllers::audit_controller::export_audit_logs,
+        crate::controllers::audit_controller::verify_audit_chain,
+        // Webhook endpoints
+        crate::controllers::webhook_controller::list_webhooks,
+        crate::controllers::webhook_controller::create_webhook,
+        crate::controllers::webhook_controller::get_webhook,
+        crate::controllers::webhook_controller::update_webhook,
+        crate::controllers::webhook_controller::delete_webhook,
+        crate::controllers::webhook_controller::list_deliveries,
+        // Role endpoints
+        crate::controllers::role_controller::list_roles,
+        crate::controllers::role_controller::create_role,
+        crate::controllers::role_controller::get_role,
+        crate::controllers::role_controller::update_role,
+        crate::controllers::role_controller::delete_role,
+        crate::controllers::role_controller::get_user_permissions,
+        crate::controllers::role_controller::grant_permission,
+        crate::controllers::role_controller::revoke_permission,
+        crate::controllers::role_controller::assign_role,
+        crate::controllers::role_controller::unassign_role,
+        // Tenant endpoints
+        crate::controllers::tenant_controller::list_tenants,
+        crate::controllers::tenant_controller::create_tenant,
+        crate::controllers::tenant_controller::get_tenant,
+        crate::controllers::tenant_controller::update_tenant,
         // Health endpoints
         crate::controllers::health_controller::health_check,
         crate::controllers::health_controller::readiness_check,
@@ -66,8 +119,17 @@ use utoipa::{Modify, OpenApi};
             RefreshTokenRequest,
             AuthResponse,
             AuthUserInfo,
+            LoginResponse,
+            MfaChallengeResponse,
+            MfaLoginRequest,
+            TotpEnrollmentResponse,
+            TotpCodeRequest,
+            RecoveryCodesResponse,
+            SessionInfo,
             PasswordResetRequest,
             PasswordResetConfirmRequest,
+            VerifyEmailRequest,
+            ResendVerificationRequest,
             MessageResponse,
             // User DTOs
             CreateUserRequest,
@@ -77,12 +139,51 @@ use utoipa::{Modify, OpenApi};
             ChangePasswordRequest,
             UserResponse,
             UserListResponse,
+            // Audit DTOs
+            AuditLogId,
+            AuditAction,
+            AuditLogResponse,
+            AuditLogListResponse,
+            AuditChainReport,
+            AuditChainBreak,
+            AuditChainBreakReason,
+            // Webhook DTOs
+            WebhookSubscriptionId,
+            WebhookDeliveryId,
+            WebhookDeliveryStatus,
+            CreateWebhookRequest,
+            UpdateWebhookRequest,
+            WebhookResponse,
+            WebhookListResponse,
+            WebhookDeliveryResponse,
+            WebhookDeliveryListResponse,
+            // Role DTOs
+            RoleId,
+            Permission,
+            PermissionSet,
+            CreateRoleRequest,
+            UpdateRoleRequest,
+            AssignRoleRequest,
+            GrantPermissionRequest,
+            RoleResponse,
+            RoleListResponse,
+            UserPermissionsResponse,
+            // Tenant DTOs
+            TenantId,
+            CreateTenantRequest,
+            UpdateTenantRequest,
+            TenantResponse,
+            TenantListResponse,
         )
     ),
     modifiers(&SecurityAddon),
     tags(
         (name = "auth", description = "Authentication endpoints"),
         (name = "users", description = "User management endpoints"),
+        (name = "audit", description = "Audit trail endpoints"),
+        (name = "webhooks", description = "Outbound webhook endpoints"),
+        (name = "roles", description = "Role and permission grant endpoints"),
+        (name = "tenants", description = "Tenant management endpoints"),
         (name = "health", description = "Health check endpoints")
     )
 )]
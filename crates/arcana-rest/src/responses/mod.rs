@@ -73,6 +73,11 @@ pub fn created<T: Serialize>(data: T) -> (StatusCode, Json<ApiResponse<T>>) {
     (StatusCode::CREATED, Json(ApiResponse::success(data)))
 }
 
+/// Helper to create an accepted (202) response.
+pub fn accepted<T: Serialize>(data: T) -> (StatusCode, Json<ApiResponse<T>>) {
+    (StatusCode::ACCEPTED, Json(ApiResponse::success(data)))
+}
+
 /// Helper to create a no content (204) response.
 pub fn no_content() -> StatusCode {
     StatusCode::NO_CONTENT
@@ -1,14 +1,19 @@
 //! Main application router.
 
 use crate::{
-    controllers::{auth_controller, health_controller, jobs_controller, user_controller},
-    middleware::{auth_middleware, logging_middleware, AuthMiddlewareState},
+    controllers::{
+        audit_controller, auth_controller, health_controller, jobs_controller, jwks_controller, role_controller,
+        tenant_controller, user_controller, webhook_controller,
+    },
+    middleware::{auth_middleware, logging_middleware, request_context_middleware, AuthMiddlewareState},
     openapi::ApiDoc,
     state::AppState,
 };
 use arcana_config::ServerConfig;
-use arcana_security::TokenProviderInterface;
-use arcana_service::{AuthService, UserService};
+use arcana_security::{PermissionResolverInterface, TokenProviderInterface, TokenRevocationInterface};
+use arcana_service::{
+    AuditService, AuditTrailInterface, AuthService, RoleService, TenantService, UserService, WebhookService,
+};
 use axum::{
     middleware,
     routing::get,
@@ -28,20 +33,32 @@ use utoipa_swagger_ui::SwaggerUi;
 /// Creates the main application router from a Shaku module.
 ///
 /// This is the preferred way to create the router, using Shaku for dependency injection.
-/// The module must provide UserService, AuthService, and TokenProviderInterface components.
+/// The module must provide UserService, AuthService, AuditService,
+/// WebhookService, RoleService, TenantService, AuditTrailInterface, TokenProviderInterface,
+/// TokenRevocationInterface and PermissionResolverInterface components.
 pub fn create_router<M>(module: &M, server_config: &ServerConfig) -> Router
 where
     M: Module
         + HasComponent<dyn UserService>
         + HasComponent<dyn AuthService>
-        + HasComponent<dyn TokenProviderInterface>,
+        + HasComponent<dyn AuditService>
+        + HasComponent<dyn WebhookService>
+        + HasComponent<dyn RoleService>
+        + HasComponent<dyn TenantService>
+        + HasComponent<dyn AuditTrailInterface>
+        + HasComponent<dyn TokenProviderInterface>
+        + HasComponent<dyn TokenRevocationInterface>
+        + HasComponent<dyn PermissionResolverInterface>,
 {
     // Create CORS layer
     let cors = create_cors_layer(server_config);
 
-    // Get token provider from module for auth middleware
+    // Get token provider, revocation list and permission resolver from module for auth middleware
     let token_provider: Arc<dyn TokenProviderInterface> = module.resolve();
-    let auth_state = AuthMiddlewareState::new(token_provider);
+    let token_revocation: Arc<dyn TokenRevocationInterface> = module.resolve();
+    let permission_resolver: Arc<dyn PermissionResolverInterface> = module.resolve();
+    let auth_state =
+        AuthMiddlewareState::new(token_provider.clone(), token_revocation).with_permission_resolver(permission_resolver);
 
     // Create app state by resolving services from module
     let state = AppState::from_module(module);
@@ -49,14 +66,21 @@ where
     // Build the API router with authentication
     let api_router = Router::new()
         .nest("/auth", auth_controller::router())
-        .nest("/users", user_controller::router())
+        .nest("/users", user_controller::router().merge(role_controller::user_router()))
+        .nest("/audit", audit_controller::router())
         .nest("/jobs", jobs_controller::router())
+        .nest("/webhooks", webhook_controller::router())
+        .nest("/roles", role_controller::router())
+        .nest("/tenants", tenant_controller::router())
+        .layer(middleware::from_fn(request_context_middleware))
         .layer(middleware::from_fn_with_state(auth_state.clone(), auth_middleware))
         .with_state(state.clone());
 
     let router = Router::new()
         // Health endpoints (no auth required)
         .merge(health_controller::router())
+        // Token verification keys (no auth required)
+        .merge(jwks_controller::router(token_provider))
         // API v1
         .nest("/api/v1", api_router)
         // Swagger UI and OpenAPI spec
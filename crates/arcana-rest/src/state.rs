@@ -4,7 +4,10 @@
 //! Services are resolved from a Shaku module and stored in the state.
 
 use arcana_jobs::JobQueueInterface;
-use arcana_service::{AuthService, UserService};
+use arcana_service::{
+    AuditService, AuditTrailInterface, AuditedAuthService, AuditedRoleService, AuditedUserService,
+    AuditedTenantService, AuditedWebhookService, AuthService, RoleService, TenantService, UserService, WebhookService,
+};
 use shaku::{HasComponent, Module};
 use std::sync::Arc;
 
@@ -18,8 +21,17 @@ pub struct AppState {
     pub user_service: Arc<dyn UserService>,
     /// Authentication service.
     pub auth_service: Arc<dyn AuthService>,
+    /// Audit log query service.
+    pub audit_service: Arc<dyn AuditService>,
     /// Job queue interface (optional, only available when Redis is configured).
     pub job_queue: Option<Arc<dyn JobQueueInterface>>,
+    /// Webhook subscription service (optional, only available in monolithic deployments,
+    /// the only ones that deliver webhooks).
+    pub webhook_service: Option<Arc<dyn WebhookService>>,
+    /// Role and permission grant service (optional, only available in monolithic deployments).
+    pub role_service: Option<Arc<dyn RoleService>>,
+    /// Tenant management service (optional, only available in monolithic deployments).
+    pub tenant_service: Option<Arc<dyn TenantService>>,
 }
 
 impl AppState {
@@ -27,11 +39,16 @@ impl AppState {
     pub fn new(
         user_service: Arc<dyn UserService>,
         auth_service: Arc<dyn AuthService>,
+        audit_service: Arc<dyn AuditService>,
     ) -> Self {
         Self {
             user_service,
             auth_service,
+            audit_service,
             job_queue: None,
+            webhook_service: None,
+            role_service: None,
+            tenant_service: None,
         }
     }
 
@@ -39,39 +56,84 @@ impl AppState {
     pub fn with_jobs(
         user_service: Arc<dyn UserService>,
         auth_service: Arc<dyn AuthService>,
+        audit_service: Arc<dyn AuditService>,
         job_queue: Arc<dyn JobQueueInterface>,
     ) -> Self {
         Self {
             user_service,
             auth_service,
+            audit_service,
             job_queue: Some(job_queue),
+            webhook_service: None,
+            role_service: None,
+            tenant_service: None,
         }
     }
 
+    /// Adds the webhook subscription service.
+    #[must_use]
+    pub fn with_webhooks(mut self, webhook_service: Arc<dyn WebhookService>) -> Self {
+        self.webhook_service = Some(webhook_service);
+        self
+    }
+
+    /// Adds the role and permission grant service.
+    #[must_use]
+    pub fn with_roles(mut self, role_service: Arc<dyn RoleService>) -> Self {
+        self.role_service = Some(role_service);
+        self
+    }
+
+    /// Adds the tenant management service.
+    #[must_use]
+    pub fn with_tenants(mut self, tenant_service: Arc<dyn TenantService>) -> Self {
+        self.tenant_service = Some(tenant_service);
+        self
+    }
+
     /// Creates application state by resolving services from a Shaku module.
     ///
     /// This is the preferred way to create AppState, as it ensures
-    /// services are properly wired through dependency injection.
+    /// services are properly wired through dependency injection. The
+    /// services are wrapped so that every mutating call is audited.
     pub fn from_module<M>(module: &M) -> Self
     where
-        M: Module + HasComponent<dyn UserService> + HasComponent<dyn AuthService>,
+        M: Module
+            + HasComponent<dyn UserService>
+            + HasComponent<dyn AuthService>
+            + HasComponent<dyn AuditTrailInterface>
+            + HasComponent<dyn AuditService>
+            + HasComponent<dyn WebhookService>
+            + HasComponent<dyn RoleService>
+            + HasComponent<dyn TenantService>,
     {
+        let audit_trail: Arc<dyn AuditTrailInterface> = module.resolve();
         Self {
-            user_service: module.resolve(),
-            auth_service: module.resolve(),
+            user_service: Arc::new(AuditedUserService::new(module.resolve(), audit_trail.clone())),
+            auth_service: Arc::new(AuditedAuthService::new(module.resolve(), audit_trail.clone())),
+            audit_service: module.resolve(),
             job_queue: None,
+            webhook_service: Some(Arc::new(AuditedWebhookService::new(module.resolve(), audit_trail.clone()))),
+            role_service: Some(Arc::new(AuditedRoleService::new(module.resolve(), audit_trail.clone()))),
+            tenant_service: Some(Arc::new(AuditedTenantService::new(module.resolve(), audit_trail))),
         }
     }
 
     /// Creates application state with job queue from a Shaku module.
     pub fn from_module_with_jobs<M>(module: &M, job_queue: Arc<dyn JobQueueInterface>) -> Self
     where
-        M: Module + HasComponent<dyn UserService> + HasComponent<dyn AuthService>,
+        M: Module
+            + HasComponent<dyn UserService>
+            + HasComponent<dyn AuthService>
+            + HasComponent<dyn AuditTrailInterface>
+            + HasComponent<dyn AuditService>
+            + HasComponent<dyn WebhookService>
+            + HasComponent<dyn RoleService>
+            + HasComponent<dyn TenantService>,
     {
         Self {
-            user_service: module.resolve(),
-            auth_service: module.resolve(),
             job_queue: Some(job_queue),
+            ..Self::from_module(module)
         }
     }
 }
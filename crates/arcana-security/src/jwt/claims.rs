@@ -1,7 +1,7 @@
 //! JWT claims structure.
 
-use arcana_core::UserId;
-use arcana_core::UserRole;
+use arcana_core::{TenantId, UserId};
+use arcana_core::{Permission, PermissionSet, UserRole};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -31,6 +31,11 @@ pub struct Claims {
     /// Issued at timestamp.
     pub iat: i64,
 
+    /// Issued at timestamp in microseconds, so revocation cutoffs can tell
+    /// apart tokens issued within the same second. `None` in older tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iat_micros: Option<i64>,
+
     /// Expiration timestamp.
     pub exp: i64,
 
@@ -50,6 +55,19 @@ pub struct Claims {
     /// Session ID for refresh token invalidation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub session_id: Option<String>,
+
+    /// Effective permissions of the user.
+    ///
+    /// Embedded in access tokens at issuance and replaced by freshly resolved
+    /// ones where the role tables are reachable. `None` in tokens issued
+    /// without them; the built-in grants of `role` apply then.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<PermissionSet>,
+
+    /// Tenant of the user. `None` in tokens issued before multi-tenancy,
+    /// which belong to the default tenant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<TenantId>,
 }
 
 impl Claims {
@@ -73,12 +91,15 @@ impl Claims {
             role,
             token_type: TokenType::Access,
             iat: now.timestamp(),
+            iat_micros: Some(now.timestamp_micros()),
             exp: expires_at.timestamp(),
             nbf: Some(now.timestamp()),
             iss: issuer,
             aud: audience,
             jti: Uuid::now_v7().to_string(),
             session_id: None,
+            permissions: None,
+            tenant_id: None,
         }
     }
 
@@ -103,21 +124,78 @@ impl Claims {
             role,
             token_type: TokenType::Refresh,
             iat: now.timestamp(),
+            iat_micros: Some(now.timestamp_micros()),
             exp: expires_at.timestamp(),
             nbf: Some(now.timestamp()),
             iss: issuer,
             aud: audience,
             jti: Uuid::now_v7().to_string(),
             session_id: Some(session_id),
+            permissions: None,
+            tenant_id: None,
+        }
+    }
+
+    /// Creates new email verification token claims.
+    ///
+    /// The token proves control of `email` and cannot be used as an access token.
+    #[must_use]
+    pub fn new_email_verification(
+        user_id: UserId,
+        username: String,
+        email: String,
+        role: UserRole,
+        issuer: String,
+        audience: String,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            token_type: TokenType::EmailVerification,
+            ..Self::new_access(user_id, username, email, role, issuer, audience, expires_at)
         }
     }
 
+    /// Creates new MFA challenge token claims.
+    ///
+    /// Issued after a correct password when the account has two-factor
+    /// authentication enabled; it can only be exchanged for a token pair
+    /// together with a valid second factor.
+    #[must_use]
+    pub fn new_mfa_challenge(
+        user_id: UserId,
+        username: String,
+        email: String,
+        role: UserRole,
+        issuer: String,
+        audience: String,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            token_type: TokenType::MfaChallenge,
+            ..Self::new_access(user_id, username, email, role, issuer, audience, expires_at)
+        }
+    }
+
+    /// Returns when the token was issued, in microseconds.
+    ///
+    /// Tokens without `iat_micros` count as issued at the start of their second.
+    #[must_use]
+    pub fn issued_at_micros(&self) -> i64 {
+        self.iat_micros.unwrap_or_else(|| self.iat.saturating_mul(1_000_000))
+    }
+
     /// Returns the user ID.
     #[must_use]
     pub fn user_id(&self) -> Option<UserId> {
         self.user_id.map(UserId::from_uuid)
     }
 
+    /// Returns the tenant of the user.
+    #[must_use]
+    pub fn tenant_id(&self) -> TenantId {
+        self.tenant_id.unwrap_or_default()
+    }
+
     /// Checks if the token is expired.
     #[must_use]
     pub fn is_expired(&self) -> bool {
@@ -130,12 +208,54 @@ impl Claims {
         DateTime::from_timestamp(self.exp, 0).unwrap_or_else(Utc::now)
     }
 
+    /// Sets the effective permissions carried by the claims.
+    #[must_use]
+    pub fn with_permissions(mut self, permissions: PermissionSet) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    /// Sets the tenant of the user.
+    #[must_use]
+    pub fn with_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = Some(tenant_id);
+        self
+    }
+
     /// Checks if the user has the required role.
     #[must_use]
     pub const fn has_role(&self, required: UserRole) -> bool {
         self.role.has_permission(required)
     }
 
+    /// Checks if the user is granted a permission.
+    ///
+    /// Uses the resolved effective permissions, or the built-in grants of
+    /// the role if they have not been resolved. System permissions cover the
+    /// whole deployment and are only granted within the default tenant.
+    #[must_use]
+    pub fn is_granted(&self, permission: Permission) -> bool {
+        if permission.is_system() && !self.tenant_id().is_default() {
+            return false;
+        }
+        match &self.permissions {
+            Some(permissions) => permissions.contains(permission),
+            None => permission.is_allowed_for(self.role),
+        }
+    }
+
+    /// Returns the tenant a request of the user acts on.
+    ///
+    /// Holders of `system:admin` may act on any tenant they ask for; everyone
+    /// else always acts on their own tenant.
+    #[must_use]
+    pub fn acting_tenant(&self, requested: Option<TenantId>) -> TenantId {
+        match requested {
+            Some(tenant_id) if self.is_granted(Permission::SystemAdmin) => tenant_id,
+            _ => self.tenant_id(),
+        }
+    }
+
     /// Checks if this is an access token.
     #[must_use]
     pub const fn is_access_token(&self) -> bool {
@@ -147,6 +267,18 @@ impl Claims {
     pub const fn is_refresh_token(&self) -> bool {
         matches!(self.token_type, TokenType::Refresh)
     }
+
+    /// Checks if this is an email verification token.
+    #[must_use]
+    pub const fn is_email_verification_token(&self) -> bool {
+        matches!(self.token_type, TokenType::EmailVerification)
+    }
+
+    /// Checks if this is an MFA challenge token.
+    #[must_use]
+    pub const fn is_mfa_challenge_token(&self) -> bool {
+        matches!(self.token_type, TokenType::MfaChallenge)
+    }
 }
 
 /// Token type enumeration.
@@ -157,6 +289,10 @@ pub enum TokenType {
     Access,
     /// Refresh token (long-lived, used to obtain new access tokens).
     Refresh,
+    /// Email verification token (sent by mail to confirm an address).
+    EmailVerification,
+    /// MFA challenge token (short-lived, exchanged for tokens with a second factor).
+    MfaChallenge,
 }
 
 impl std::fmt::Display for TokenType {
@@ -164,6 +300,8 @@ impl std::fmt::Display for TokenType {
         match self {
             Self::Access => write!(f, "access"),
             Self::Refresh => write!(f, "refresh"),
+            Self::EmailVerification => write!(f, "email_verification"),
+            Self::MfaChallenge => write!(f, "mfa_challenge"),
         }
     }
 }
@@ -337,6 +475,43 @@ mod tests {
     fn test_token_type_display() {
         assert_eq!(TokenType::Access.to_string(), "access");
         assert_eq!(TokenType::Refresh.to_string(), "refresh");
+        assert_eq!(TokenType::EmailVerification.to_string(), "email_verification");
+        assert_eq!(TokenType::MfaChallenge.to_string(), "mfa_challenge");
+    }
+
+    #[test]
+    fn test_email_verification_claims() {
+        let claims = Claims::new_email_verification(
+            UserId::new(),
+            "testuser".to_string(),
+            "test@example.com".to_string(),
+            UserRole::User,
+            "issuer".to_string(),
+            "audience".to_string(),
+            Utc::now() + Duration::hours(24),
+        );
+
+        assert!(claims.is_email_verification_token());
+        assert!(!claims.is_access_token());
+        assert!(!claims.is_refresh_token());
+        assert!(claims.session_id.is_none());
+    }
+
+    #[test]
+    fn test_mfa_challenge_claims() {
+        let claims = Claims::new_mfa_challenge(
+            UserId::new(),
+            "testuser".to_string(),
+            "test@example.com".to_string(),
+            UserRole::User,
+            "issuer".to_string(),
+            "audience".to_string(),
+            Utc::now() + Duration::minutes(5),
+        );
+
+        assert!(claims.is_mfa_challenge_token());
+        assert!(!claims.is_access_token());
+        assert!(!claims.is_email_verification_token());
     }
 
     #[test]
@@ -356,6 +531,69 @@ mod tests {
         assert_eq!(claims.aud, "my-audience");
     }
 
+    #[test]
+    fn test_resolved_permissions_replace_role_grants() {
+        let mut claims = make_access_claims(UserRole::User);
+        assert!(claims.is_granted(Permission::UserRead));
+        assert!(!claims.is_granted(Permission::UserUpdate));
+
+        claims.permissions = Some([Permission::UserUpdate].into_iter().collect());
+        assert!(claims.is_granted(Permission::UserUpdate));
+        assert!(!claims.is_granted(Permission::UserRead));
+    }
+
+    #[test]
+    fn test_permissions_claim_round_trip() {
+        let claims = make_access_claims(UserRole::User)
+            .with_permissions([Permission::UserRead, Permission::SystemMonitor].into_iter().collect());
+
+        let json = serde_json::to_value(&claims).unwrap();
+        assert_eq!(json["permissions"], serde_json::json!(["user:read", "system:monitor"]));
+
+        let decoded: Claims = serde_json::from_value(json).unwrap();
+        assert!(decoded.is_granted(Permission::SystemMonitor));
+        assert!(!decoded.is_granted(Permission::ContentRead));
+
+        // Tokens without the claim fall back to the role's grants
+        let json = serde_json::to_value(make_access_claims(UserRole::User)).unwrap();
+        assert!(json.get("permissions").is_none());
+        let decoded: Claims = serde_json::from_value(json).unwrap();
+        assert!(decoded.permissions.is_none());
+        assert!(decoded.is_granted(Permission::ContentRead));
+    }
+
+    #[test]
+    fn test_system_permissions_stay_in_default_tenant() {
+        let claims = make_access_claims(UserRole::SuperAdmin);
+        assert!(claims.tenant_id().is_default());
+        assert!(claims.is_granted(Permission::SystemAdmin));
+
+        let tenant_id = TenantId::new();
+        let claims = claims.with_tenant(tenant_id);
+        assert_eq!(claims.tenant_id(), tenant_id);
+        assert!(!claims.is_granted(Permission::SystemAdmin));
+        assert!(!claims.is_granted(Permission::SystemMonitor));
+        assert!(claims.is_granted(Permission::UserManageRoles));
+
+        let json = serde_json::to_value(&claims).unwrap();
+        assert_eq!(json["tenant_id"], serde_json::json!(tenant_id.to_string()));
+    }
+
+    #[test]
+    fn test_only_system_admins_act_on_other_tenants() {
+        let other = TenantId::new();
+
+        let admin = make_access_claims(UserRole::SuperAdmin);
+        assert_eq!(admin.acting_tenant(Some(other)), other);
+        assert_eq!(admin.acting_tenant(None), TenantId::DEFAULT);
+
+        let user = make_access_claims(UserRole::User);
+        assert_eq!(user.acting_tenant(Some(other)), TenantId::DEFAULT);
+
+        let tenant_admin = make_access_claims(UserRole::SuperAdmin).with_tenant(other);
+        assert_eq!(tenant_admin.acting_tenant(Some(TenantId::DEFAULT)), other);
+    }
+
     #[test]
     fn test_superadmin_has_all_roles() {
         let claims = make_access_claims(UserRole::SuperAdmin);
@@ -0,0 +1,488 @@
+//! JWT key ring for signing-key rotation.
+//!
+//! A ring holds every key tokens may be verified with, identified by `kid`.
+//! One of them signs new tokens; the others keep validating tokens they
+//! signed earlier until they are removed, so rotating in a new key does not
+//! invalidate outstanding tokens.
+
+use arcana_config::{JwtAlgorithm, JwtKeyConfig, SecurityConfig};
+use arcana_core::{ArcanaError, ArcanaResult};
+use jsonwebtoken::jwk::{Jwk, JwkSet, PublicKeyUse, ThumbprintHash};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use std::sync::{Arc, RwLock};
+
+/// A single key of the ring.
+#[derive(Clone)]
+pub struct JwtKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    /// Signing half; `None` for keys that only verify tokens.
+    encoding_key: Option<EncodingKey>,
+    decoding_key: DecodingKey,
+    validation: Validation,
+    /// Published public key; `None` for shared secrets.
+    jwk: Option<Jwk>,
+}
+
+impl JwtKey {
+    /// Creates an HS256 key from a shared secret.
+    fn from_secret(kid: Option<String>, secret: &str, config: &SecurityConfig) -> Self {
+        Self {
+            kid,
+            algorithm: Algorithm::HS256,
+            encoding_key: Some(EncodingKey::from_secret(secret.as_bytes())),
+            decoding_key: DecodingKey::from_secret(secret.as_bytes()),
+            validation: build_validation(config, Algorithm::HS256),
+            jwk: None,
+        }
+    }
+
+    /// Loads an asymmetric key from PEM files.
+    ///
+    /// Without an explicit `kid` the RFC 7638 thumbprint of the public key is used.
+    fn from_pem_files(
+        kid: Option<String>,
+        algorithm: Algorithm,
+        private_key_path: Option<&str>,
+        public_key_path: &str,
+        config: &SecurityConfig,
+    ) -> ArcanaResult<Self> {
+        let public_pem = read_key_file(public_key_path)?;
+        let decoding_key = match algorithm {
+            Algorithm::RS256 => DecodingKey::from_rsa_pem(&public_pem),
+            Algorithm::ES256 => DecodingKey::from_ec_pem(&public_pem),
+            _ => DecodingKey::from_ed_pem(&public_pem),
+        }
+        .map_err(|e| invalid_key(public_key_path, e))?;
+
+        let encoding_key = match private_key_path {
+            Some(path) => {
+                let private_pem = read_key_file(path)?;
+                let key = match algorithm {
+                    Algorithm::RS256 => EncodingKey::from_rsa_pem(&private_pem),
+                    Algorithm::ES256 => EncodingKey::from_ec_pem(&private_pem),
+                    _ => EncodingKey::from_ed_pem(&private_pem),
+                }
+                .map_err(|e| invalid_key(path, e))?;
+                ensure_key_pair_matches(&key, &decoding_key, algorithm)?;
+                Some(key)
+            }
+            None => None,
+        };
+
+        let mut jwk = Jwk::from_decoding_key(&decoding_key, Some(algorithm))
+            .map_err(|e| invalid_key(public_key_path, e))?;
+        let kid = match kid {
+            Some(kid) => kid,
+            None => jwk
+                .thumbprint(ThumbprintHash::SHA256)
+                .map_err(|e| invalid_key(public_key_path, e))?,
+        };
+        jwk.common.key_id = Some(kid.clone());
+        jwk.common.public_key_use = Some(PublicKeyUse::Signature);
+
+        Ok(Self {
+            kid: Some(kid),
+            algorithm,
+            encoding_key,
+            decoding_key,
+            validation: build_validation(config, algorithm),
+            jwk: Some(jwk),
+        })
+    }
+
+    /// Loads a key ring entry.
+    fn from_entry(entry: &JwtKeyConfig, config: &SecurityConfig) -> ArcanaResult<Self> {
+        let kid = Some(entry.kid.clone());
+        match to_algorithm(entry.algorithm) {
+            Algorithm::HS256 => {
+                let secret = entry.secret.as_deref().ok_or_else(|| {
+                    ArcanaError::Configuration(format!("JWT key '{}' requires a secret", entry.kid))
+                })?;
+                Ok(Self::from_secret(kid, secret, config))
+            }
+            algorithm => {
+                let public_key_path = entry.public_key_path.as_deref().ok_or_else(|| {
+                    ArcanaError::Configuration(format!(
+                        "JWT key '{}' requires public_key_path",
+                        entry.kid
+                    ))
+                })?;
+                Self::from_pem_files(
+                    kid,
+                    algorithm,
+                    entry.private_key_path.as_deref(),
+                    public_key_path,
+                    config,
+                )
+            }
+        }
+    }
+
+    /// Returns the key identifier.
+    #[must_use]
+    pub fn kid(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+
+    /// Returns the signing algorithm.
+    #[must_use]
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Returns the header tokens signed with this key carry.
+    #[must_use]
+    pub fn header(&self) -> Header {
+        let mut header = Header::new(self.algorithm);
+        header.kid = self.kid.clone();
+        header
+    }
+
+    /// Returns the signing key, if this key can sign.
+    #[must_use]
+    pub fn encoding_key(&self) -> Option<&EncodingKey> {
+        self.encoding_key.as_ref()
+    }
+
+    /// Returns the verification key.
+    #[must_use]
+    pub fn decoding_key(&self) -> &DecodingKey {
+        &self.decoding_key
+    }
+
+    /// Returns the validation rules for tokens signed with this key.
+    #[must_use]
+    pub fn validation(&self) -> &Validation {
+        &self.validation
+    }
+}
+
+/// The keys a token provider signs and verifies with.
+#[derive(Clone)]
+pub struct KeyRing {
+    keys: Vec<JwtKey>,
+    /// Index of the key that signs new tokens; `None` on verify-only instances.
+    signing: Option<usize>,
+}
+
+impl KeyRing {
+    /// Creates a ring holding only the shared `jwt_secret` (HS256, no `kid`).
+    #[must_use]
+    pub fn from_secret(config: &SecurityConfig) -> Self {
+        Self {
+            keys: vec![JwtKey::from_secret(None, &config.jwt_secret, config)],
+            signing: Some(0),
+        }
+    }
+
+    /// Builds the ring described by the configuration.
+    ///
+    /// `jwt_keys` takes precedence; without it the ring holds the single key
+    /// configured by `jwt_algorithm`, `jwt_secret` and the PEM key paths.
+    pub fn from_config(config: &SecurityConfig) -> ArcanaResult<Self> {
+        if config.jwt_keys.is_empty() {
+            return Self::from_single_key(config);
+        }
+
+        let keys = config
+            .jwt_keys
+            .iter()
+            .map(|entry| JwtKey::from_entry(entry, config))
+            .collect::<ArcanaResult<Vec<_>>>()?;
+
+        let signing_index = match config.jwt_signing_kid.as_deref() {
+            Some(kid) => keys
+                .iter()
+                .position(|key| key.kid() == Some(kid))
+                .ok_or_else(|| {
+                    ArcanaError::Configuration(format!("JWT signing kid '{}' is not in the key ring", kid))
+                })?,
+            None => 0,
+        };
+        let signing = keys[signing_index].encoding_key.is_some().then_some(signing_index);
+
+        Ok(Self { keys, signing })
+    }
+
+    fn from_single_key(config: &SecurityConfig) -> ArcanaResult<Self> {
+        let algorithm = to_algorithm(config.jwt_algorithm);
+        if algorithm == Algorithm::HS256 {
+            return Ok(Self::from_secret(config));
+        }
+
+        let public_key_path = config.jwt_public_key_path.as_deref().ok_or_else(|| {
+            ArcanaError::Configuration(format!("{:?} requires jwt_public_key_path", algorithm))
+        })?;
+        let key = JwtKey::from_pem_files(
+            None,
+            algorithm,
+            config.jwt_private_key_path.as_deref(),
+            public_key_path,
+            config,
+        )?;
+        let signing = key.encoding_key.is_some().then_some(0);
+
+        Ok(Self {
+            keys: vec![key],
+            signing,
+        })
+    }
+
+    /// Returns the key new tokens are signed with.
+    #[must_use]
+    pub fn signing_key(&self) -> Option<&JwtKey> {
+        self.signing.map(|index| &self.keys[index])
+    }
+
+    /// Returns the keys a token with the given header may have been signed with.
+    ///
+    /// Tokens carrying a `kid` match that key only. Tokens without one, issued
+    /// before keys had identifiers, are tried against every key of their algorithm.
+    #[must_use]
+    pub fn verification_keys(&self, header: &Header) -> Vec<&JwtKey> {
+        match header.kid.as_deref() {
+            Some(kid) => self.keys.iter().filter(|key| key.kid() == Some(kid)).collect(),
+            None => self
+                .keys
+                .iter()
+                .filter(|key| key.algorithm == header.alg)
+                .collect(),
+        }
+    }
+
+    /// Returns the identifiers of all keys in the ring.
+    #[must_use]
+    pub fn kids(&self) -> Vec<&str> {
+        self.keys.iter().filter_map(JwtKey::kid).collect()
+    }
+
+    /// Returns the public keys of the ring; shared secrets are never published.
+    #[must_use]
+    pub fn jwks(&self) -> JwkSet {
+        JwkSet {
+            keys: self.keys.iter().filter_map(|key| key.jwk.clone()).collect(),
+        }
+    }
+}
+
+/// A key ring that can be replaced at runtime; clones share the same keys.
+#[derive(Clone)]
+pub struct SharedKeyRing {
+    current: Arc<RwLock<Arc<KeyRing>>>,
+}
+
+impl SharedKeyRing {
+    /// Creates a shared ring starting with the given keys.
+    #[must_use]
+    pub fn new(ring: KeyRing) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(Arc::new(ring))),
+        }
+    }
+
+    /// Returns the current keys.
+    #[must_use]
+    pub fn current(&self) -> Arc<KeyRing> {
+        self.current.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replaces the keys; tokens already being validated finish with the old ring.
+    pub fn replace(&self, ring: KeyRing) {
+        *self.current.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(ring);
+    }
+}
+
+fn to_algorithm(algorithm: JwtAlgorithm) -> Algorithm {
+    match algorithm {
+        JwtAlgorithm::HS256 => Algorithm::HS256,
+        JwtAlgorithm::RS256 => Algorithm::RS256,
+        JwtAlgorithm::ES256 => Algorithm::ES256,
+        JwtAlgorithm::EdDSA => Algorithm::EdDSA,
+    }
+}
+
+fn build_validation(config: &SecurityConfig, algorithm: Algorithm) -> Validation {
+    let mut validation = Validation::new(algorithm);
+    validation.set_issuer(&[&config.jwt_issuer]);
+    validation.set_audience(&[&config.jwt_audience]);
+    validation.validate_exp = true;
+    validation.validate_nbf = true;
+    validation
+}
+
+fn read_key_file(path: &str) -> ArcanaResult<Vec<u8>> {
+    std::fs::read(path).map_err(|e| {
+        ArcanaError::Configuration(format!("Failed to read JWT key '{}': {}", path, e))
+    })
+}
+
+fn invalid_key(path: &str, error: jsonwebtoken::errors::Error) -> ArcanaError {
+    ArcanaError::Configuration(format!("Invalid JWT key '{}': {}", path, error))
+}
+
+/// Fails fast when the configured private key does not belong to the public key,
+/// which would otherwise only surface as every issued token being rejected.
+fn ensure_key_pair_matches(
+    encoding_key: &EncodingKey,
+    decoding_key: &DecodingKey,
+    algorithm: Algorithm,
+) -> ArcanaResult<()> {
+    const PROBE: &[u8] = b"arcana-jwt-key-pair-check";
+
+    let signature = jsonwebtoken::crypto::sign(PROBE, encoding_key, algorithm)
+        .map_err(|e| ArcanaError::Configuration(format!("Failed to sign with JWT private key: {}", e)))?;
+    let matches = jsonwebtoken::crypto::verify(&signature, PROBE, decoding_key, algorithm)
+        .map_err(|e| ArcanaError::Configuration(format!("Failed to verify with JWT public key: {}", e)))?;
+
+    if matches {
+        Ok(())
+    } else {
+        Err(ArcanaError::Configuration(
+            "JWT private key does not match the configured public key".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_path(name: &str) -> String {
+        format!("{}/testdata/jwt/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    fn secret_entry(kid: &str, secret: &str) -> JwtKeyConfig {
+        JwtKeyConfig {
+            kid: kid.to_string(),
+            algorithm: JwtAlgorithm::HS256,
+            secret: Some(secret.to_string()),
+            private_key_path: None,
+            public_key_path: None,
+        }
+    }
+
+    fn ed25519_entry(kid: &str, with_private_key: bool) -> JwtKeyConfig {
+        JwtKeyConfig {
+            kid: kid.to_string(),
+            algorithm: JwtAlgorithm::EdDSA,
+            secret: None,
+            private_key_path: with_private_key.then(|| key_path("ed25519-private.pem")),
+            public_key_path: Some(key_path("ed25519-public.pem")),
+        }
+    }
+
+    #[test]
+    fn test_signing_kid_selects_signing_key() {
+        let config = SecurityConfig {
+            jwt_keys: vec![
+                secret_entry("old", "old-secret-key-for-testing-only-32"),
+                ed25519_entry("new", true),
+            ],
+            jwt_signing_kid: Some("new".to_string()),
+            ..Default::default()
+        };
+        let ring = KeyRing::from_config(&config).unwrap();
+
+        let signing = ring.signing_key().unwrap();
+        assert_eq!(signing.kid(), Some("new"));
+        assert_eq!(signing.header().kid.as_deref(), Some("new"));
+        assert_eq!(ring.kids(), vec!["old", "new"]);
+    }
+
+    #[test]
+    fn test_signing_key_defaults_to_first_entry() {
+        let config = SecurityConfig {
+            jwt_keys: vec![
+                secret_entry("first", "first-secret-key-for-testing-only"),
+                secret_entry("second", "second-secret-key-for-testing-only"),
+            ],
+            ..Default::default()
+        };
+        let ring = KeyRing::from_config(&config).unwrap();
+        assert_eq!(ring.signing_key().unwrap().kid(), Some("first"));
+    }
+
+    #[test]
+    fn test_unknown_signing_kid_rejected() {
+        let config = SecurityConfig {
+            jwt_keys: vec![secret_entry("current", "current-secret-key-for-testing-only")],
+            jwt_signing_kid: Some("missing".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            KeyRing::from_config(&config),
+            Err(ArcanaError::Configuration(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_only_signing_entry_cannot_sign() {
+        let config = SecurityConfig {
+            jwt_keys: vec![ed25519_entry("public-only", false)],
+            ..Default::default()
+        };
+        let ring = KeyRing::from_config(&config).unwrap();
+        assert!(ring.signing_key().is_none());
+        assert_eq!(ring.verification_keys(&Header::new(Algorithm::EdDSA)).len(), 1);
+    }
+
+    #[test]
+    fn test_verification_keys_by_kid_and_algorithm() {
+        let config = SecurityConfig {
+            jwt_keys: vec![
+                secret_entry("a", "secret-a-key-for-testing-only-32c"),
+                secret_entry("b", "secret-b-key-for-testing-only-32c"),
+                ed25519_entry("c", true),
+            ],
+            ..Default::default()
+        };
+        let ring = KeyRing::from_config(&config).unwrap();
+
+        let mut header = Header::new(Algorithm::HS256);
+        assert_eq!(ring.verification_keys(&header).len(), 2);
+
+        header.kid = Some("b".to_string());
+        let keys = ring.verification_keys(&header);
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].kid(), Some("b"));
+
+        header.kid = Some("retired".to_string());
+        assert!(ring.verification_keys(&header).is_empty());
+    }
+
+    #[test]
+    fn test_jwks_skips_shared_secrets() {
+        let config = SecurityConfig {
+            jwt_keys: vec![
+                secret_entry("hmac", "hmac-secret-key-for-testing-only-32"),
+                ed25519_entry("ed", false),
+            ],
+            ..Default::default()
+        };
+        let jwks = KeyRing::from_config(&config).unwrap().jwks();
+        assert_eq!(jwks.keys.len(), 1);
+        assert_eq!(jwks.keys[0].common.key_id.as_deref(), Some("ed"));
+    }
+
+    #[test]
+    fn test_shared_key_ring_replace_is_visible_to_clones() {
+        let config = SecurityConfig {
+            jwt_keys: vec![secret_entry("v1", "v1-secret-key-for-testing-only-32c")],
+            ..Default::default()
+        };
+        let shared = SharedKeyRing::new(KeyRing::from_config(&config).unwrap());
+        let clone = shared.clone();
+
+        let rotated = SecurityConfig {
+            jwt_keys: vec![
+                secret_entry("v2", "v2-secret-key-for-testing-only-32c"),
+                secret_entry("v1", "v1-secret-key-for-testing-only-32c"),
+            ],
+            ..Default::default()
+        };
+        shared.replace(KeyRing::from_config(&rotated).unwrap());
+
+        assert_eq!(clone.current().signing_key().unwrap().kid(), Some("v2"));
+    }
+}
@@ -1,7 +1,13 @@
 //! JWT token management.
 
 mod claims;
+mod key_ring;
+mod revocation;
+mod token_hash;
 mod token_provider;
 
 pub use claims::*;
+pub use key_ring::*;
+pub use revocation::*;
+pub use token_hash::*;
 pub use token_provider::*;
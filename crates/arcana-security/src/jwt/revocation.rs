@@ -0,0 +1,479 @@
+//! Access-token revocation list.
+//!
+//! Access tokens are stateless, so revoking one before it expires needs a
+//! denylist consulted on every authenticated request. Three kinds of entries
+//! are kept, each only for as long as the tokens they cover can still be valid:
+//!
+//! - a single token, keyed by its `jti`;
+//! - every token of a session, keyed by session ID;
+//! - every token of a user issued up to a cutoff time in microseconds (role,
+//!   status and password changes), keyed by user ID.
+
+use super::{Claims, TokenProviderInterface};
+use arcana_core::{ArcanaError, ArcanaResult, Interface, UserId};
+use async_trait::async_trait;
+use chrono::Utc;
+use deadpool_redis::{redis::AsyncCommands, Pool};
+use shaku::Component;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::debug;
+
+/// Default lifetime of session and user-wide revocations (matches the default access token lifetime).
+pub const DEFAULT_USER_REVOCATION_TTL: Duration = Duration::from_secs(3600);
+
+/// Prefix for all revocation keys.
+const REVOCATION_PREFIX: &str = "arcana:revoked";
+
+/// Interface for the access-token revocation list.
+#[async_trait]
+pub trait TokenRevocationInterface: Interface + Send + Sync {
+    /// Revokes a single access token until it expires.
+    async fn revoke_token(&self, jti: &str, expires_at: i64) -> ArcanaResult<()>;
+
+    /// Revokes a single-use token, returning `false` if it was already revoked.
+    ///
+    /// Only one of several concurrent calls for the same `jti` returns `true`,
+    /// so the caller that gets it is the one allowed to redeem the token.
+    async fn consume_token(&self, jti: &str, expires_at: i64) -> ArcanaResult<bool>;
+
+    /// Revokes every access token of a session.
+    async fn revoke_session(&self, session_id: &str) -> ArcanaResult<()>;
+
+    /// Revokes every access token issued to a user up to now.
+    async fn revoke_user_tokens(&self, user_id: UserId) -> ArcanaResult<()>;
+
+    /// Returns whether the token described by `claims` has been revoked.
+    async fn is_revoked(&self, claims: &Claims) -> ArcanaResult<bool>;
+}
+
+/// Revocation list stored in Redis, with an in-memory fallback.
+///
+/// Revocations are always recorded locally as well, so they keep working on
+/// this instance when Redis is disabled or unreachable. Redis makes them
+/// visible to every instance, so a failing Redis write or lookup is reported
+/// as an error: a revocation is not confirmed unless every instance will see
+/// it, and a token is not accepted unless it is known not to be revoked.
+#[derive(Component)]
+#[shaku(interface = TokenRevocationInterface)]
+pub struct TokenRevocationStore {
+    /// Redis connection pool (`None` keeps revocations in memory only).
+    pool: Option<Arc<Pool>>,
+    /// How long session and user-wide revocations are kept (the access token lifetime).
+    #[shaku(default = DEFAULT_USER_REVOCATION_TTL)]
+    user_revocation_ttl: Duration,
+    /// Local entries: key -> (value, expiry timestamp).
+    #[shaku(default)]
+    local: Mutex<HashMap<String, (i64, i64)>>,
+}
+
+impl TokenRevocationStore {
+    /// Creates a Redis-backed revocation store.
+    #[must_use]
+    pub fn new(pool: Arc<Pool>, user_revocation_ttl: Duration) -> Self {
+        Self {
+            pool: Some(pool),
+            user_revocation_ttl,
+            local: Mutex::default(),
+        }
+    }
+
+    /// Creates a revocation store that only keeps entries in memory.
+    #[must_use]
+    pub fn in_memory(user_revocation_ttl: Duration) -> Self {
+        Self {
+            pool: None,
+            user_revocation_ttl,
+            local: Mutex::default(),
+        }
+    }
+
+    /// Records an entry locally, dropping entries that have expired.
+    fn store_locally(&self, key: String, value: i64, expires_at: i64) {
+        let now = Utc::now().timestamp();
+        let mut local = self.local.lock().unwrap_or_else(|e| e.into_inner());
+        local.retain(|_, (_, expiry)| *expiry > now);
+        local.insert(key, (value, expires_at));
+    }
+
+    /// Records an entry locally unless a live one exists, returning whether it was recorded.
+    fn store_locally_if_absent(&self, key: String, value: i64, expires_at: i64) -> bool {
+        let now = Utc::now().timestamp();
+        let mut local = self.local.lock().unwrap_or_else(|e| e.into_inner());
+        local.retain(|_, (_, expiry)| *expiry > now);
+        if local.contains_key(&key) {
+            return false;
+        }
+        local.insert(key, (value, expires_at));
+        true
+    }
+
+    /// Reads a live local entry.
+    fn local_value(&self, key: &str) -> Option<i64> {
+        let now = Utc::now().timestamp();
+        let local = self.local.lock().unwrap_or_else(|e| e.into_inner());
+        local
+            .get(key)
+            .filter(|(_, expiry)| *expiry > now)
+            .map(|(value, _)| *value)
+    }
+
+    /// Writes an entry to Redis, if enabled.
+    async fn store_in_redis(&self, key: &str, value: i64, ttl_secs: u64) -> ArcanaResult<()> {
+        let Some(pool) = &self.pool else {
+            return Ok(());
+        };
+
+        let mut conn = pool
+            .get()
+            .await
+            .map_err(|e| ArcanaError::Cache(format!("Failed to get Redis connection: {}", e)))?;
+
+        conn.set_ex::<_, _, ()>(key, value, ttl_secs.max(1))
+            .await
+            .map_err(|e| ArcanaError::Cache(format!("Failed to set key '{}': {}", key, e)))
+    }
+
+    /// Writes an entry to Redis unless it exists, returning whether it was written.
+    ///
+    /// Always `true` when Redis is disabled.
+    async fn store_in_redis_if_absent(&self, key: &str, value: i64, ttl_secs: u64) -> ArcanaResult<bool> {
+        let Some(pool) = &self.pool else {
+            return Ok(true);
+        };
+
+        let mut conn = pool
+            .get()
+            .await
+            .map_err(|e| ArcanaError::Cache(format!("Failed to get Redis connection: {}", e)))?;
+
+        let stored: Option<String> = deadpool_redis::redis::cmd("SET")
+            .arg(key)
+            .arg(value)
+            .arg("NX")
+            .arg("EX")
+            .arg(ttl_secs.max(1))
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| ArcanaError::Cache(format!("Failed to set key '{}': {}", key, e)))?;
+
+        Ok(stored.is_some())
+    }
+
+    /// Reads entries from Redis, if enabled, in the order of `keys`.
+    async fn redis_values(&self, keys: &[&str]) -> ArcanaResult<Vec<Option<i64>>> {
+        let Some(pool) = &self.pool else {
+            return Ok(vec![None; keys.len()]);
+        };
+
+        let mut conn = pool
+            .get()
+            .await
+            .map_err(|e| ArcanaError::Cache(format!("Failed to get Redis connection: {}", e)))?;
+
+        let mut pipe = deadpool_redis::redis::pipe();
+        for key in keys {
+            pipe.get(*key);
+        }
+
+        pipe.query_async(&mut conn)
+            .await
+            .map_err(|e| ArcanaError::Cache(format!("Failed to read revocation entries: {}", e)))
+    }
+
+    /// Records an entry that lives as long as the access tokens it covers.
+    async fn store_for_token_lifetime(&self, key: String, value: i64) -> ArcanaResult<()> {
+        let ttl = i64::try_from(self.user_revocation_ttl.as_secs()).unwrap_or(i64::MAX);
+        self.store(key, value, Utc::now().timestamp().saturating_add(ttl)).await
+    }
+
+    /// Records an entry locally and in Redis.
+    async fn store(&self, key: String, value: i64, expires_at: i64) -> ArcanaResult<()> {
+        let ttl_secs = u64::try_from(expires_at - Utc::now().timestamp()).unwrap_or(0);
+        if ttl_secs == 0 {
+            return Ok(());
+        }
+
+        // Applied on this instance even if Redis fails, but the caller learns the others may not see it
+        self.store_locally(key.clone(), value, expires_at);
+        self.store_in_redis(&key, value, ttl_secs).await
+    }
+}
+
+#[async_trait]
+impl TokenRevocationInterface for TokenRevocationStore {
+    async fn revoke_token(&self, jti: &str, expires_at: i64) -> ArcanaResult<()> {
+        debug!("Revoking access token {}", jti);
+        self.store(token_key(jti), 1, expires_at).await
+    }
+
+    async fn consume_token(&self, jti: &str, expires_at: i64) -> ArcanaResult<bool> {
+        debug!("Consuming single-use token {}", jti);
+        let key = token_key(jti);
+        let ttl_secs = u64::try_from(expires_at - Utc::now().timestamp()).unwrap_or(0);
+
+        // Claimed locally first so concurrent calls on this instance never both reach Redis
+        if ttl_secs == 0 || !self.store_locally_if_absent(key.clone(), 1, expires_at) {
+            return Ok(false);
+        }
+
+        // Another instance may have consumed it; unlike revocations, this cannot fall back to memory
+        self.store_in_redis_if_absent(&key, 1, ttl_secs).await
+    }
+
+    async fn revoke_session(&self, session_id: &str) -> ArcanaResult<()> {
+        debug!("Revoking access tokens of session {}", session_id);
+        self.store_for_token_lifetime(session_key(session_id), Utc::now().timestamp())
+            .await
+    }
+
+    async fn revoke_user_tokens(&self, user_id: UserId) -> ArcanaResult<()> {
+        debug!("Revoking access tokens of user {}", user_id);
+        // Microseconds, so a token issued right after the change in the same second stays valid
+        self.store_for_token_lifetime(user_key(&user_id.to_string()), Utc::now().timestamp_micros())
+            .await
+    }
+
+    async fn is_revoked(&self, claims: &Claims) -> ArcanaResult<bool> {
+        let token_key = token_key(&claims.jti);
+        let user_key = user_key(&claims.sub);
+        // Tokens issued before session binding carry no session ID
+        let session_key = claims.session_id.as_deref().map(session_key);
+
+        let mut keys = vec![token_key.as_str(), user_key.as_str()];
+        keys.extend(session_key.as_deref());
+
+        // Values are in the order of `keys`: token, user cutoff, session
+        let revoked = |values: &[Option<i64>]| {
+            values.first().is_some_and(Option::is_some)
+                || values.get(1).copied().flatten().is_some_and(|cutoff| claims.issued_at_micros() <= cutoff)
+                || values.get(2).is_some_and(Option::is_some)
+        };
+
+        let local: Vec<Option<i64>> = keys.iter().map(|key| self.local_value(key)).collect();
+        if revoked(&local) {
+            return Ok(true);
+        }
+
+        // Another instance may have revoked the token, so an unreachable Redis cannot clear it
+        let values = self.redis_values(&keys).await?;
+        Ok(revoked(&values))
+    }
+}
+
+impl std::fmt::Debug for TokenRevocationStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenRevocationStore")
+            .field("redis_enabled", &self.pool.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Revocation key for a single token.
+fn token_key(jti: &str) -> String {
+    format!("{}:jti:{}", REVOCATION_PREFIX, jti)
+}
+
+/// Revocation key for every token of a session.
+fn session_key(session_id: &str) -> String {
+    format!("{}:session:{}", REVOCATION_PREFIX, session_id)
+}
+
+/// Revocation key for every token of a user.
+fn user_key(user_id: &str) -> String {
+    format!("{}:user:{}", REVOCATION_PREFIX, user_id)
+}
+
+/// Validates an access token and rejects it if it has been revoked.
+///
+/// This is the check both transports run on every authenticated request.
+/// A failing revocation lookup rejects the token.
+pub async fn authenticate_access_token(
+    token_provider: &dyn TokenProviderInterface,
+    token_revocation: &dyn TokenRevocationInterface,
+    token: &str,
+) -> ArcanaResult<Claims> {
+    let claims = token_provider.validate_access_token(token)?;
+
+    if token_revocation.is_revoked(&claims).await.unwrap_or(true) {
+        return Err(ArcanaError::InvalidToken("Token has been revoked".to_string()));
+    }
+
+    Ok(claims)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TokenProvider;
+    use arcana_config::SecurityConfig;
+    use arcana_core::UserRole;
+
+    fn create_provider() -> TokenProvider {
+        TokenProvider::new(Arc::new(SecurityConfig {
+            jwt_secret: "test-secret-key-for-testing-only".to_string(),
+            ..SecurityConfig::default()
+        }))
+    }
+
+    fn create_claims(provider: &TokenProvider, user_id: UserId) -> (String, Claims) {
+        let token = provider
+            .generate_access_token(user_id, "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+        let claims = provider.validate_access_token(&token).unwrap();
+        (token, claims)
+    }
+
+    fn create_store() -> TokenRevocationStore {
+        TokenRevocationStore::in_memory(DEFAULT_USER_REVOCATION_TTL)
+    }
+
+    #[tokio::test]
+    async fn test_revoke_token_by_jti() {
+        let store = create_store();
+        let provider = create_provider();
+        let (_, claims) = create_claims(&provider, UserId::new());
+        let (_, other) = create_claims(&provider, UserId::new());
+
+        assert!(!store.is_revoked(&claims).await.unwrap());
+        store.revoke_token(&claims.jti, claims.exp).await.unwrap();
+
+        assert!(store.is_revoked(&claims).await.unwrap());
+        assert!(!store.is_revoked(&other).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_consume_token_succeeds_once() {
+        let store = Arc::new(create_store());
+        let provider = create_provider();
+        let (_, claims) = create_claims(&provider, UserId::new());
+
+        let attempts = (0..8).map(|_| {
+            let store = store.clone();
+            let (jti, exp) = (claims.jti.clone(), claims.exp);
+            tokio::spawn(async move { store.consume_token(&jti, exp).await.unwrap() })
+        });
+        let mut consumed = 0;
+        for attempt in attempts.collect::<Vec<_>>() {
+            consumed += usize::from(attempt.await.unwrap());
+        }
+
+        assert_eq!(consumed, 1);
+        assert!(store.is_revoked(&claims).await.unwrap());
+        assert!(!store.consume_token("expired", Utc::now().timestamp() - 10).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_expired_token_is_not_stored() {
+        let store = create_store();
+        store.revoke_token("expired", Utc::now().timestamp() - 10).await.unwrap();
+        assert!(store.local_value(&token_key("expired")).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_user_tokens_covers_earlier_tokens() {
+        let store = create_store();
+        let provider = create_provider();
+        let user_id = UserId::new();
+        let (_, claims) = create_claims(&provider, user_id);
+        let (_, other_user) = create_claims(&provider, UserId::new());
+
+        store.revoke_user_tokens(user_id).await.unwrap();
+
+        assert!(store.is_revoked(&claims).await.unwrap());
+        assert!(!store.is_revoked(&other_user).await.unwrap());
+
+        let mut later = claims.clone();
+        later.iat += 5;
+        later.iat_micros = later.iat_micros.map(|micros| micros + 5_000_000);
+        assert!(!store.is_revoked(&later).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_user_tokens_spares_tokens_issued_in_the_same_second() {
+        let store = create_store();
+        let provider = create_provider();
+        let user_id = UserId::new();
+        let (_, claims) = create_claims(&provider, user_id);
+
+        store.revoke_user_tokens(user_id).await.unwrap();
+        let cutoff = store.local_value(&user_key(&user_id.to_string())).unwrap();
+
+        let mut fresh = claims.clone();
+        fresh.iat = cutoff / 1_000_000;
+        fresh.iat_micros = Some(cutoff + 1);
+        assert!(!store.is_revoked(&fresh).await.unwrap());
+
+        // Tokens without microseconds are revoked for the whole second of the cutoff
+        fresh.iat_micros = None;
+        assert!(store.is_revoked(&fresh).await.unwrap());
+
+        let (_, reissued) = create_claims(&provider, user_id);
+        assert!(!store.is_revoked(&reissued).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_session_covers_only_that_session() {
+        let store = create_store();
+        let provider = create_provider();
+        let (_, mut claims) = create_claims(&provider, UserId::new());
+        claims.session_id = Some("session-1".to_string());
+        let mut other_session = claims.clone();
+        other_session.session_id = Some("session-2".to_string());
+        let mut unbound = claims.clone();
+        unbound.session_id = None;
+
+        store.revoke_session("session-1").await.unwrap();
+
+        assert!(store.is_revoked(&claims).await.unwrap());
+        assert!(!store.is_revoked(&other_session).await.unwrap());
+        assert!(!store.is_revoked(&unbound).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_access_token_rejects_revoked() {
+        let store = create_store();
+        let provider = create_provider();
+        let (token, claims) = create_claims(&provider, UserId::new());
+
+        assert!(authenticate_access_token(&provider, &store, &token).await.is_ok());
+
+        store.revoke_token(&claims.jti, claims.exp).await.unwrap();
+        let result = authenticate_access_token(&provider, &store, &token).await;
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_redis_fails_closed() {
+        let pool = deadpool_redis::Config::from_url("redis://127.0.0.1:1")
+            .create_pool(Some(deadpool_redis::Runtime::Tokio1))
+            .unwrap();
+        let store = TokenRevocationStore::new(Arc::new(pool), DEFAULT_USER_REVOCATION_TTL);
+        let provider = create_provider();
+        let (token, claims) = create_claims(&provider, UserId::new());
+
+        assert!(matches!(store.is_revoked(&claims).await, Err(ArcanaError::Cache(_))));
+        let result = authenticate_access_token(&provider, &store, &token).await;
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+
+        // Single-use tokens cannot be redeemed without Redis
+        assert!(store.consume_token("challenge", claims.exp).await.is_err());
+
+        // Revocations fail while Redis is down, but still apply on this instance
+        assert!(matches!(store.revoke_token(&claims.jti, claims.exp).await, Err(ArcanaError::Cache(_))));
+        assert!(store.is_revoked(&claims).await.unwrap());
+    }
+
+    #[test]
+    fn test_revocation_keys() {
+        assert_eq!(token_key("abc"), "arcana:revoked:jti:abc");
+        assert_eq!(session_key("s1"), "arcana:revoked:session:s1");
+        assert_eq!(user_key("u1"), "arcana:revoked:user:u1");
+    }
+
+    #[test]
+    fn test_store_debug() {
+        assert!(format!("{:?}", create_store()).contains("redis_enabled: false"));
+    }
+}
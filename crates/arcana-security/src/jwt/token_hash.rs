@@ -0,0 +1,58 @@
+//! Generation and hashing of opaque tokens for server-side storage.
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+/// Number of random bytes in an opaque token.
+const OPAQUE_TOKEN_BYTES: usize = 32;
+
+/// Generates a random opaque token (256 bits, hex-encoded).
+///
+/// Used for single-use secrets such as password reset tokens; only
+/// [`hash_token`] of the value is stored.
+#[must_use]
+pub fn generate_opaque_token() -> String {
+    let mut bytes = [0u8; OPAQUE_TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Hashes a token for storage (hex-encoded SHA-256).
+///
+/// Refresh tokens are high-entropy signed JWTs, so a fast unsalted digest is
+/// sufficient; it lets the stored record be looked up by hash directly.
+#[must_use]
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_token_is_deterministic() {
+        assert_eq!(hash_token("some.refresh.token"), hash_token("some.refresh.token"));
+    }
+
+    #[test]
+    fn test_hash_token_differs_per_token() {
+        assert_ne!(hash_token("token-a"), hash_token("token-b"));
+    }
+
+    #[test]
+    fn test_generate_opaque_token() {
+        let token = generate_opaque_token();
+        assert_eq!(token.len(), OPAQUE_TOKEN_BYTES * 2);
+        assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(token, generate_opaque_token());
+    }
+
+    #[test]
+    fn test_hash_token_format() {
+        let hash = hash_token("token");
+        assert_eq!(hash.len(), 64);
+        assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(hash, "token");
+    }
+}
@@ -1,14 +1,17 @@
 //! JWT token provider for creating and validating tokens.
 
-use super::Claims;
+use super::{Claims, JwtKey, KeyRing, SharedKeyRing};
 use arcana_config::SecurityConfig;
 use arcana_core::{ArcanaError, ArcanaResult, Interface, UserId};
-use arcana_core::UserRole;
+use arcana_core::{PermissionSet, TenantId, UserRole};
 use chrono::{Duration, Utc};
-use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::{decode, decode_header, encode};
 use shaku::Component;
 use std::sync::Arc;
-use tracing::{debug, warn};
+use tracing::{debug, info, warn};
+
+pub use jsonwebtoken::jwk::JwkSet;
 
 /// Token pair containing access and refresh tokens.
 #[derive(Debug, Clone)]
@@ -21,10 +24,29 @@ pub struct TokenPair {
     pub access_expires_at: i64,
     /// Refresh token expiration timestamp.
     pub refresh_expires_at: i64,
+    /// Session both tokens belong to (the `session_id` claim).
+    pub session_id: String,
     /// Token type (always "Bearer").
     pub token_type: String,
 }
 
+/// What an access token is scoped to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenScope {
+    /// Tenant of the user (the `tenant_id` claim).
+    pub tenant_id: TenantId,
+    /// Effective permissions of the user (the `permissions` claim).
+    pub permissions: PermissionSet,
+}
+
+impl TokenScope {
+    /// Creates a scope.
+    #[must_use]
+    pub fn new(tenant_id: TenantId, permissions: PermissionSet) -> Self {
+        Self { tenant_id, permissions }
+    }
+}
+
 /// Interface for JWT token operations.
 ///
 /// This trait abstracts token generation and validation for dependency injection.
@@ -38,6 +60,34 @@ pub trait TokenProviderInterface: Interface + Send + Sync {
         role: UserRole,
     ) -> ArcanaResult<TokenPair>;
 
+    /// Generates a token pair whose refresh token belongs to an existing session.
+    ///
+    /// Used for refresh-token rotation, where the new refresh token stays in
+    /// the same token family as the one it replaces.
+    fn generate_tokens_for_session(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+        session_id: &str,
+    ) -> ArcanaResult<TokenPair>;
+
+    /// Generates a token pair whose access token carries the user's tenant
+    /// and effective permissions.
+    ///
+    /// A `session_id` keeps the refresh token in an existing session;
+    /// otherwise a new one is started.
+    fn generate_scoped_tokens(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+        scope: &TokenScope,
+        session_id: Option<&str>,
+    ) -> ArcanaResult<TokenPair>;
+
     /// Validates a token and returns the claims.
     fn validate_token(&self, token: &str) -> ArcanaResult<Claims>;
 
@@ -50,63 +100,117 @@ pub trait TokenProviderInterface: Interface + Send + Sync {
     /// Refreshes a token pair using a refresh token.
     fn refresh_tokens(&self, refresh_token: &str) -> ArcanaResult<TokenPair>;
 
+    /// Generates a token confirming that the user controls `email`.
+    fn generate_email_verification_token(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+    ) -> ArcanaResult<String>;
+
+    /// Validates an email verification token specifically.
+    fn validate_email_verification_token(&self, token: &str) -> ArcanaResult<Claims>;
+
+    /// Generates a short-lived token standing in for a half-completed MFA login.
+    fn generate_mfa_challenge_token(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+    ) -> ArcanaResult<String>;
+
+    /// Validates an MFA challenge token specifically.
+    fn validate_mfa_challenge_token(&self, token: &str) -> ArcanaResult<Claims>;
+
     /// Decodes a token without validation (for inspection).
     fn decode_without_validation(&self, token: &str) -> ArcanaResult<Claims>;
+
+    /// Returns the public keys tokens can be verified with.
+    ///
+    /// Empty for HS256, whose shared secret must never be published.
+    fn jwks(&self) -> JwkSet;
+
+    /// Reloads the signing and verification keys, e.g. after a key rotation.
+    fn reload_keys(&self, config: &SecurityConfig) -> ArcanaResult<()>;
 }
 
 /// JWT token provider service.
 #[derive(Component, Clone)]
 #[shaku(interface = TokenProviderInterface)]
 pub struct TokenProvider {
-    encoding_key: EncodingKey,
-    decoding_key: DecodingKey,
+    keys: SharedKeyRing,
     config: Arc<SecurityConfig>,
-    validation: Validation,
 }
 
 impl TokenProvider {
-    /// Creates a new token provider.
+    /// Creates a new token provider signing with the shared `jwt_secret` (HS256).
+    ///
+    /// Use [`TokenProvider::from_config`] to honor the configured keys.
     #[must_use]
     pub fn new(config: Arc<SecurityConfig>) -> Self {
-        let encoding_key = EncodingKey::from_secret(config.jwt_secret.as_bytes());
-        let decoding_key = DecodingKey::from_secret(config.jwt_secret.as_bytes());
-
-        let mut validation = Validation::new(Algorithm::HS256);
-        validation.set_issuer(&[&config.jwt_issuer]);
-        validation.set_audience(&[&config.jwt_audience]);
-        validation.validate_exp = true;
-        validation.validate_nbf = true;
-
         Self {
-            encoding_key,
-            decoding_key,
+            keys: SharedKeyRing::new(KeyRing::from_secret(&config)),
             config,
-            validation,
         }
     }
 
-    /// Returns the encoding key.
+    /// Creates a token provider for the configured keys.
     ///
-    /// This is used for Shaku component parameter extraction.
-    #[must_use]
-    pub fn encoding_key(&self) -> &EncodingKey {
-        &self.encoding_key
+    /// Loads the key ring from `jwt_keys`, or the single key configured by
+    /// `jwt_algorithm`. Without a private key the provider can only verify
+    /// tokens, which is what layers that never issue tokens need.
+    pub fn from_config(config: Arc<SecurityConfig>) -> ArcanaResult<Self> {
+        Ok(Self {
+            keys: SharedKeyRing::new(KeyRing::from_config(&config)?),
+            config,
+        })
     }
 
-    /// Returns the decoding key.
+    /// Returns the key ring.
     ///
     /// This is used for Shaku component parameter extraction.
     #[must_use]
-    pub fn decoding_key(&self) -> &DecodingKey {
-        &self.decoding_key
+    pub fn keys(&self) -> &SharedKeyRing {
+        &self.keys
     }
 
-    /// Returns the validation configuration.
-    ///
-    /// This is used for Shaku component parameter extraction.
+    /// Returns the public keys tokens can be verified with.
     #[must_use]
-    pub fn validation(&self) -> &Validation {
-        &self.validation
+    pub fn jwks(&self) -> JwkSet {
+        self.keys.current().jwks()
+    }
+
+    /// Reloads the signing and verification keys from configuration.
+    ///
+    /// The current keys stay in place if the new ones fail to load.
+    pub fn reload_keys(&self, config: &SecurityConfig) -> ArcanaResult<()> {
+        let ring = KeyRing::from_config(config)?;
+        info!(
+            "Reloaded JWT keys (signing: {:?}, accepted: {:?})",
+            ring.signing_key().and_then(JwtKey::kid),
+            ring.kids()
+        );
+        self.keys.replace(ring);
+        Ok(())
+    }
+
+    /// Signs claims with the active signing key.
+    fn sign(&self, claims: &Claims, token_kind: &str) -> ArcanaResult<String> {
+        let keys = self.keys.current();
+        let (key, encoding_key) = keys
+            .signing_key()
+            .and_then(|key| key.encoding_key().map(|encoding_key| (key, encoding_key)))
+            .ok_or_else(|| {
+                ArcanaError::Configuration(
+                    "JWT private key not configured; this instance can only verify tokens".to_string(),
+                )
+            })?;
+
+        encode(&key.header(), claims, encoding_key).map_err(|e| {
+            ArcanaError::Internal(format!("Failed to generate {} token: {}", token_kind, e))
+        })
     }
 
     /// Generates a token pair for a user.
@@ -118,9 +222,49 @@ impl TokenProvider {
         role: UserRole,
     ) -> ArcanaResult<TokenPair> {
         let session_id = uuid::Uuid::now_v7().to_string();
+        self.generate_tokens_for_session(user_id, username, email, role, &session_id)
+    }
+
+    /// Generates a token pair whose refresh token belongs to an existing session.
+    pub fn generate_tokens_for_session(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+        session_id: &str,
+    ) -> ArcanaResult<TokenPair> {
+        self.encode_tokens(user_id, username, email, role, None, session_id)
+    }
+
+    /// Generates a token pair whose access token carries the `scope`.
+    ///
+    /// Without a `session_id` a new session is started.
+    pub fn generate_scoped_tokens(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+        scope: &TokenScope,
+        session_id: Option<&str>,
+    ) -> ArcanaResult<TokenPair> {
+        let session_id = session_id.map_or_else(|| uuid::Uuid::now_v7().to_string(), str::to_string);
+        self.encode_tokens(user_id, username, email, role, Some(scope), &session_id)
+    }
 
-        let access_token = self.generate_access_token(user_id, username, email, role)?;
-        let refresh_token = self.generate_refresh_token(user_id, username, email, role, &session_id)?;
+    /// Signs a token pair for a session.
+    fn encode_tokens(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+        scope: Option<&TokenScope>,
+        session_id: &str,
+    ) -> ArcanaResult<TokenPair> {
+        let access_token = self.encode_access_token(user_id, username, email, role, scope, Some(session_id))?;
+        let refresh_token = self.generate_refresh_token(user_id, username, email, role, session_id)?;
 
         let access_expires_at = (Utc::now() + Duration::seconds(self.config.jwt_access_expiration_secs as i64)).timestamp();
         let refresh_expires_at = (Utc::now() + Duration::seconds(self.config.jwt_refresh_expiration_secs as i64)).timestamp();
@@ -130,6 +274,7 @@ impl TokenProvider {
             refresh_token,
             access_expires_at,
             refresh_expires_at,
+            session_id: session_id.to_string(),
             token_type: "Bearer".to_string(),
         })
     }
@@ -141,10 +286,23 @@ impl TokenProvider {
         username: &str,
         email: &str,
         role: UserRole,
+    ) -> ArcanaResult<String> {
+        self.encode_access_token(user_id, username, email, role, None, None)
+    }
+
+    /// Signs an access token, optionally scoped and bound to a session.
+    fn encode_access_token(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+        scope: Option<&TokenScope>,
+        session_id: Option<&str>,
     ) -> ArcanaResult<String> {
         let expires_at = Utc::now() + Duration::seconds(self.config.jwt_access_expiration_secs as i64);
 
-        let claims = Claims::new_access(
+        let mut claims = Claims::new_access(
             user_id,
             username.to_string(),
             email.to_string(),
@@ -153,9 +311,13 @@ impl TokenProvider {
             self.config.jwt_audience.clone(),
             expires_at,
         );
+        claims.session_id = session_id.map(str::to_string);
+        if let Some(scope) = scope {
+            claims.tenant_id = Some(scope.tenant_id);
+            claims.permissions = Some(scope.permissions.clone());
+        }
 
-        let token = encode(&Header::default(), &claims, &self.encoding_key)
-            .map_err(|e| ArcanaError::Internal(format!("Failed to generate access token: {}", e)))?;
+        let token = self.sign(&claims, "access")?;
 
         debug!("Generated access token for user {}", user_id);
         Ok(token)
@@ -183,35 +345,108 @@ impl TokenProvider {
             session_id.to_string(),
         );
 
-        let token = encode(&Header::default(), &claims, &self.encoding_key)
-            .map_err(|e| ArcanaError::Internal(format!("Failed to generate refresh token: {}", e)))?;
+        let token = self.sign(&claims, "refresh")?;
 
         debug!("Generated refresh token for user {}", user_id);
         Ok(token)
     }
 
+    /// Generates an email verification token.
+    ///
+    /// It expires after `email_verification_expiration_secs`.
+    pub fn generate_email_verification_token(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+    ) -> ArcanaResult<String> {
+        let expires_at =
+            Utc::now() + Duration::seconds(self.config.email_verification_expiration_secs as i64);
+
+        let claims = Claims::new_email_verification(
+            user_id,
+            username.to_string(),
+            email.to_string(),
+            role,
+            self.config.jwt_issuer.clone(),
+            self.config.jwt_audience.clone(),
+            expires_at,
+        );
+
+        let token = self.sign(&claims, "email verification")?;
+
+        debug!("Generated email verification token for user {}", user_id);
+        Ok(token)
+    }
+
+    /// Generates an MFA challenge token.
+    ///
+    /// It expires after `mfa_challenge_expiration_secs`.
+    pub fn generate_mfa_challenge_token(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+    ) -> ArcanaResult<String> {
+        let expires_at =
+            Utc::now() + Duration::seconds(self.config.mfa_challenge_expiration_secs as i64);
+
+        let claims = Claims::new_mfa_challenge(
+            user_id,
+            username.to_string(),
+            email.to_string(),
+            role,
+            self.config.jwt_issuer.clone(),
+            self.config.jwt_audience.clone(),
+            expires_at,
+        );
+
+        let token = self.sign(&claims, "MFA challenge")?;
+
+        debug!("Generated MFA challenge token for user {}", user_id);
+        Ok(token)
+    }
+
     /// Validates a token and returns the claims.
     pub fn validate_token(&self, token: &str) -> ArcanaResult<Claims> {
-        let token_data = decode::<Claims>(token, &self.decoding_key, &self.validation)
-            .map_err(|e| {
-                warn!("Token validation failed: {}", e);
-                match e.kind() {
-                    jsonwebtoken::errors::ErrorKind::ExpiredSignature => ArcanaError::TokenExpired,
-                    jsonwebtoken::errors::ErrorKind::InvalidToken
-                    | jsonwebtoken::errors::ErrorKind::InvalidSignature => {
-                        ArcanaError::InvalidToken("Invalid token signature".to_string())
-                    }
-                    jsonwebtoken::errors::ErrorKind::InvalidIssuer => {
-                        ArcanaError::InvalidToken("Invalid token issuer".to_string())
-                    }
-                    jsonwebtoken::errors::ErrorKind::InvalidAudience => {
-                        ArcanaError::InvalidToken("Invalid token audience".to_string())
+        let header = decode_header(token).map_err(|e| {
+            warn!("Token validation failed: {}", e);
+            ArcanaError::InvalidToken("Invalid token signature".to_string())
+        })?;
+
+        // Without a kid several keys may match; only a bad signature moves on to the next one
+        let keys = self.keys.current();
+        let mut last_error = None;
+        for key in keys.verification_keys(&header) {
+            match decode::<Claims>(token, key.decoding_key(), key.validation()) {
+                Ok(token_data) => return Ok(token_data.claims),
+                Err(e) => {
+                    let try_next = matches!(e.kind(), ErrorKind::InvalidSignature);
+                    last_error = Some(e);
+                    if !try_next {
+                        break;
                     }
-                    _ => ArcanaError::InvalidToken(e.to_string()),
                 }
-            })?;
+            }
+        }
 
-        Ok(token_data.claims)
+        let Some(e) = last_error else {
+            warn!("Token validation failed: unknown signing key {:?}", header.kid);
+            return Err(ArcanaError::InvalidToken("Unknown token signing key".to_string()));
+        };
+
+        warn!("Token validation failed: {}", e);
+        Err(match e.kind() {
+            ErrorKind::ExpiredSignature => ArcanaError::TokenExpired,
+            ErrorKind::InvalidToken | ErrorKind::InvalidSignature => {
+                ArcanaError::InvalidToken("Invalid token signature".to_string())
+            }
+            ErrorKind::InvalidIssuer => ArcanaError::InvalidToken("Invalid token issuer".to_string()),
+            ErrorKind::InvalidAudience => ArcanaError::InvalidToken("Invalid token audience".to_string()),
+            _ => ArcanaError::InvalidToken(e.to_string()),
+        })
     }
 
     /// Validates an access token specifically.
@@ -236,6 +471,28 @@ impl TokenProvider {
         Ok(claims)
     }
 
+    /// Validates an email verification token specifically.
+    pub fn validate_email_verification_token(&self, token: &str) -> ArcanaResult<Claims> {
+        let claims = self.validate_token(token)?;
+
+        if !claims.is_email_verification_token() {
+            return Err(ArcanaError::InvalidToken("Expected email verification token".to_string()));
+        }
+
+        Ok(claims)
+    }
+
+    /// Validates an MFA challenge token specifically.
+    pub fn validate_mfa_challenge_token(&self, token: &str) -> ArcanaResult<Claims> {
+        let claims = self.validate_token(token)?;
+
+        if !claims.is_mfa_challenge_token() {
+            return Err(ArcanaError::InvalidToken("Expected MFA challenge token".to_string()));
+        }
+
+        Ok(claims)
+    }
+
     /// Refreshes a token pair using a refresh token.
     pub fn refresh_tokens(&self, refresh_token: &str) -> ArcanaResult<TokenPair> {
         let claims = self.validate_refresh_token(refresh_token)?;
@@ -249,13 +506,7 @@ impl TokenProvider {
 
     /// Decodes a token without validation (for inspection).
     pub fn decode_without_validation(&self, token: &str) -> ArcanaResult<Claims> {
-        let mut validation = Validation::new(Algorithm::HS256);
-        validation.insecure_disable_signature_validation();
-        validation.validate_exp = false;
-        validation.validate_nbf = false;
-        validation.validate_aud = false;
-
-        let token_data = decode::<Claims>(token, &self.decoding_key, &validation)
+        let token_data = jsonwebtoken::dangerous::insecure_decode::<Claims>(token)
             .map_err(|e| ArcanaError::InvalidToken(e.to_string()))?;
 
         Ok(token_data.claims)
@@ -265,6 +516,10 @@ impl TokenProvider {
 impl std::fmt::Debug for TokenProvider {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TokenProvider")
+            .field(
+                "signing_kid",
+                &self.keys.current().signing_key().and_then(JwtKey::kid),
+            )
             .field("issuer", &self.config.jwt_issuer)
             .field("audience", &self.config.jwt_audience)
             .finish_non_exhaustive()
@@ -279,44 +534,34 @@ impl TokenProviderInterface for TokenProvider {
         email: &str,
         role: UserRole,
     ) -> ArcanaResult<TokenPair> {
-        let session_id = uuid::Uuid::now_v7().to_string();
-
-        let access_token = self.generate_access_token(user_id, username, email, role)?;
-        let refresh_token = self.generate_refresh_token(user_id, username, email, role, &session_id)?;
+        TokenProvider::generate_tokens(self, user_id, username, email, role)
+    }
 
-        let access_expires_at = (Utc::now() + Duration::seconds(self.config.jwt_access_expiration_secs as i64)).timestamp();
-        let refresh_expires_at = (Utc::now() + Duration::seconds(self.config.jwt_refresh_expiration_secs as i64)).timestamp();
+    fn generate_tokens_for_session(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+        session_id: &str,
+    ) -> ArcanaResult<TokenPair> {
+        TokenProvider::generate_tokens_for_session(self, user_id, username, email, role, session_id)
+    }
 
-        Ok(TokenPair {
-            access_token,
-            refresh_token,
-            access_expires_at,
-            refresh_expires_at,
-            token_type: "Bearer".to_string(),
-        })
+    fn generate_scoped_tokens(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+        scope: &TokenScope,
+        session_id: Option<&str>,
+    ) -> ArcanaResult<TokenPair> {
+        TokenProvider::generate_scoped_tokens(self, user_id, username, email, role, scope, session_id)
     }
 
     fn validate_token(&self, token: &str) -> ArcanaResult<Claims> {
-        let token_data = decode::<Claims>(token, &self.decoding_key, &self.validation)
-            .map_err(|e| {
-                warn!("Token validation failed: {}", e);
-                match e.kind() {
-                    jsonwebtoken::errors::ErrorKind::ExpiredSignature => ArcanaError::TokenExpired,
-                    jsonwebtoken::errors::ErrorKind::InvalidToken
-                    | jsonwebtoken::errors::ErrorKind::InvalidSignature => {
-                        ArcanaError::InvalidToken("Invalid token signature".to_string())
-                    }
-                    jsonwebtoken::errors::ErrorKind::InvalidIssuer => {
-                        ArcanaError::InvalidToken("Invalid token issuer".to_string())
-                    }
-                    jsonwebtoken::errors::ErrorKind::InvalidAudience => {
-                        ArcanaError::InvalidToken("Invalid token audience".to_string())
-                    }
-                    _ => ArcanaError::InvalidToken(e.to_string()),
-                }
-            })?;
-
-        Ok(token_data.claims)
+        TokenProvider::validate_token(self, token)
     }
 
     fn validate_access_token(&self, token: &str) -> ArcanaResult<Claims> {
@@ -349,23 +594,56 @@ impl TokenProviderInterface for TokenProvider {
         self.generate_tokens(user_id, &claims.username, &claims.email, claims.role)
     }
 
-    fn decode_without_validation(&self, token: &str) -> ArcanaResult<Claims> {
-        let mut validation = Validation::new(Algorithm::HS256);
-        validation.insecure_disable_signature_validation();
-        validation.validate_exp = false;
-        validation.validate_nbf = false;
-        validation.validate_aud = false;
+    fn generate_email_verification_token(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+    ) -> ArcanaResult<String> {
+        TokenProvider::generate_email_verification_token(self, user_id, username, email, role)
+    }
+
+    fn validate_email_verification_token(&self, token: &str) -> ArcanaResult<Claims> {
+        TokenProvider::validate_email_verification_token(self, token)
+    }
+
+    fn generate_mfa_challenge_token(
+        &self,
+        user_id: UserId,
+        username: &str,
+        email: &str,
+        role: UserRole,
+    ) -> ArcanaResult<String> {
+        TokenProvider::generate_mfa_challenge_token(self, user_id, username, email, role)
+    }
 
-        let token_data = decode::<Claims>(token, &self.decoding_key, &validation)
+    fn validate_mfa_challenge_token(&self, token: &str) -> ArcanaResult<Claims> {
+        TokenProvider::validate_mfa_challenge_token(self, token)
+    }
+
+    fn decode_without_validation(&self, token: &str) -> ArcanaResult<Claims> {
+        let token_data = jsonwebtoken::dangerous::insecure_decode::<Claims>(token)
             .map_err(|e| ArcanaError::InvalidToken(e.to_string()))?;
 
         Ok(token_data.claims)
     }
+
+    fn jwks(&self) -> JwkSet {
+        TokenProvider::jwks(self)
+    }
+
+    fn reload_keys(&self, config: &SecurityConfig) -> ArcanaResult<()> {
+        TokenProvider::reload_keys(self, config)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arcana_config::{JwtAlgorithm, JwtKeyConfig};
+    use jsonwebtoken::jwk::PublicKeyUse;
+    use jsonwebtoken::Algorithm;
 
     fn create_test_provider() -> TokenProvider {
         let config = SecurityConfig {
@@ -417,6 +695,59 @@ mod tests {
         assert!(tokens.refresh_expires_at > tokens.access_expires_at);
     }
 
+    #[test]
+    fn test_token_pair_session_id_matches_refresh_claims() {
+        let provider = create_test_provider();
+        let user_id = UserId::new();
+        let tokens = provider
+            .generate_tokens(user_id, "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+
+        let refresh_claims = provider.validate_refresh_token(&tokens.refresh_token).unwrap();
+        assert_eq!(refresh_claims.session_id, Some(tokens.session_id.clone()));
+
+        let access_claims = provider.validate_access_token(&tokens.access_token).unwrap();
+        assert_eq!(access_claims.session_id, Some(tokens.session_id));
+    }
+
+    #[test]
+    fn test_generate_tokens_for_session_keeps_session() {
+        let provider = create_test_provider();
+        let user_id = UserId::new();
+        let tokens = provider
+            .generate_tokens_for_session(user_id, "testuser", "test@example.com", UserRole::User, "family-1")
+            .unwrap();
+
+        assert_eq!(tokens.session_id, "family-1");
+        let refresh_claims = provider.validate_refresh_token(&tokens.refresh_token).unwrap();
+        assert_eq!(refresh_claims.session_id, Some("family-1".to_string()));
+    }
+
+    #[test]
+    fn test_access_token_carries_scope() {
+        let provider = create_test_provider();
+        let permissions: PermissionSet = [arcana_core::Permission::SystemMonitor].into_iter().collect();
+        let scope = TokenScope::new(TenantId::new(), permissions.clone());
+
+        let tokens = provider
+            .generate_scoped_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User, &scope, None)
+            .unwrap();
+        let claims = provider.validate_access_token(&tokens.access_token).unwrap();
+        assert_eq!(claims.permissions, Some(permissions));
+        assert_eq!(claims.tenant_id, Some(scope.tenant_id));
+        assert_eq!(claims.session_id.as_deref(), Some(tokens.session_id.as_str()));
+
+        let tokens = provider
+            .generate_scoped_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User, &scope, Some("family-1"))
+            .unwrap();
+        assert_eq!(tokens.session_id, "family-1");
+
+        // Refresh tokens only identify the session
+        let refresh_claims = provider.validate_refresh_token(&tokens.refresh_token).unwrap();
+        assert!(refresh_claims.permissions.is_none());
+        assert!(refresh_claims.tenant_id.is_none());
+    }
+
     #[test]
     fn test_refresh_tokens() {
         let provider = create_test_provider();
@@ -463,6 +794,47 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_email_verification_token_round_trip() {
+        let provider = create_test_provider();
+        let user_id = UserId::new();
+        let token = provider
+            .generate_email_verification_token(user_id, "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+
+        let claims = provider.validate_email_verification_token(&token).unwrap();
+        assert_eq!(claims.user_id(), Some(user_id));
+        assert_eq!(claims.email, "test@example.com");
+        assert!(claims.exp - claims.iat >= 86400);
+
+        // A verification token is not an access token, nor the other way around
+        assert!(provider.validate_access_token(&token).is_err());
+        let tokens = provider
+            .generate_tokens(user_id, "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+        assert!(provider.validate_email_verification_token(&tokens.access_token).is_err());
+    }
+
+    #[test]
+    fn test_mfa_challenge_token_round_trip() {
+        let provider = create_test_provider();
+        let user_id = UserId::new();
+        let token = provider
+            .generate_mfa_challenge_token(user_id, "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+
+        let claims = provider.validate_mfa_challenge_token(&token).unwrap();
+        assert_eq!(claims.user_id(), Some(user_id));
+        assert!(claims.exp - claims.iat <= 300);
+
+        // A challenge token must never pass as an access token
+        assert!(provider.validate_access_token(&token).is_err());
+        let tokens = provider
+            .generate_tokens(user_id, "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+        assert!(provider.validate_mfa_challenge_token(&tokens.access_token).is_err());
+    }
+
     #[test]
     fn test_decode_without_validation() {
         let provider = create_test_provider();
@@ -555,6 +927,258 @@ mod tests {
         assert_eq!(claims.username, "u");
     }
 
+    fn test_key_path(name: &str) -> String {
+        format!("{}/testdata/jwt/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    fn create_key_pair_config(
+        algorithm: JwtAlgorithm,
+        private_key: Option<&str>,
+        public_key: &str,
+    ) -> Arc<SecurityConfig> {
+        Arc::new(SecurityConfig {
+            jwt_issuer: "test-issuer".to_string(),
+            jwt_audience: "test-audience".to_string(),
+            jwt_algorithm: algorithm,
+            jwt_private_key_path: private_key.map(test_key_path),
+            jwt_public_key_path: Some(test_key_path(public_key)),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_asymmetric_algorithms_round_trip() {
+        let cases = [
+            (JwtAlgorithm::RS256, Algorithm::RS256, "rsa"),
+            (JwtAlgorithm::ES256, Algorithm::ES256, "ec"),
+            (JwtAlgorithm::EdDSA, Algorithm::EdDSA, "ed25519"),
+        ];
+
+        for (algorithm, expected, key) in cases {
+            let config = create_key_pair_config(
+                algorithm,
+                Some(&format!("{}-private.pem", key)),
+                &format!("{}-public.pem", key),
+            );
+            let provider = TokenProvider::from_config(config).unwrap();
+
+            let tokens = provider
+                .generate_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User)
+                .unwrap();
+            let claims = provider.validate_access_token(&tokens.access_token).unwrap();
+            assert_eq!(claims.username, "testuser");
+            assert!(provider.validate_refresh_token(&tokens.refresh_token).is_ok());
+
+            let header = jsonwebtoken::decode_header(&tokens.access_token).unwrap();
+            assert_eq!(header.alg, expected);
+
+            let jwks = TokenProviderInterface::jwks(&provider);
+            assert_eq!(jwks.keys.len(), 1);
+            assert_eq!(header.kid, jwks.keys[0].common.key_id);
+            assert_eq!(jwks.keys[0].common.public_key_use, Some(PublicKeyUse::Signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_only_provider() {
+        let signer = TokenProvider::from_config(create_key_pair_config(
+            JwtAlgorithm::EdDSA,
+            Some("ed25519-private.pem"),
+            "ed25519-public.pem",
+        ))
+        .unwrap();
+        let verifier = TokenProvider::from_config(create_key_pair_config(
+            JwtAlgorithm::EdDSA,
+            None,
+            "ed25519-public.pem",
+        ))
+        .unwrap();
+
+        let tokens = signer
+            .generate_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+        assert!(verifier.validate_access_token(&tokens.access_token).is_ok());
+        assert_eq!(signer.jwks(), verifier.jwks());
+
+        let result = verifier.generate_tokens(UserId::new(), "u", "u@x.com", UserRole::User);
+        assert!(matches!(result, Err(ArcanaError::Configuration(_))));
+    }
+
+    #[test]
+    fn test_mismatched_key_pair_rejected() {
+        let result = TokenProvider::from_config(create_key_pair_config(
+            JwtAlgorithm::EdDSA,
+            Some("ed25519-other-private.pem"),
+            "ed25519-public.pem",
+        ));
+        assert!(matches!(result, Err(ArcanaError::Configuration(_))));
+    }
+
+    #[test]
+    fn test_missing_public_key_rejected() {
+        let result = TokenProvider::from_config(create_key_pair_config(
+            JwtAlgorithm::RS256,
+            Some("rsa-private.pem"),
+            "missing-public.pem",
+        ));
+        assert!(matches!(result, Err(ArcanaError::Configuration(_))));
+    }
+
+    #[test]
+    fn test_hs256_tokens_rejected_by_asymmetric_provider() {
+        let hs256 = create_test_provider();
+        let rs256 = TokenProvider::from_config(create_key_pair_config(
+            JwtAlgorithm::RS256,
+            Some("rsa-private.pem"),
+            "rsa-public.pem",
+        ))
+        .unwrap();
+
+        let tokens = hs256
+            .generate_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+        assert!(rs256.validate_access_token(&tokens.access_token).is_err());
+    }
+
+    #[test]
+    fn test_hs256_publishes_no_keys() {
+        let provider = TokenProvider::from_config(Arc::new(SecurityConfig {
+            jwt_secret: "test-secret-key-for-testing-only".to_string(),
+            ..Default::default()
+        }))
+        .unwrap();
+        assert!(TokenProviderInterface::jwks(&provider).keys.is_empty());
+    }
+
+    fn secret_key(kid: &str, secret: &str) -> JwtKeyConfig {
+        JwtKeyConfig {
+            kid: kid.to_string(),
+            algorithm: JwtAlgorithm::HS256,
+            secret: Some(secret.to_string()),
+            private_key_path: None,
+            public_key_path: None,
+        }
+    }
+
+    fn create_key_ring_config(keys: Vec<JwtKeyConfig>, signing_kid: &str) -> SecurityConfig {
+        SecurityConfig {
+            jwt_issuer: "test-issuer".to_string(),
+            jwt_audience: "test-audience".to_string(),
+            jwt_keys: keys,
+            jwt_signing_kid: Some(signing_kid.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rotation_keeps_outstanding_tokens_valid() {
+        let old = secret_key("2026-07", "old-secret-key-for-testing-only-32");
+        let new = secret_key("2026-10", "new-secret-key-for-testing-only-32");
+        let provider = TokenProvider::from_config(Arc::new(create_key_ring_config(
+            vec![old.clone()],
+            "2026-07",
+        )))
+        .unwrap();
+
+        let old_tokens = provider
+            .generate_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+
+        provider
+            .reload_keys(&create_key_ring_config(vec![new, old], "2026-10"))
+            .unwrap();
+
+        let new_tokens = provider
+            .generate_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+        let header = jsonwebtoken::decode_header(&new_tokens.access_token).unwrap();
+        assert_eq!(header.kid.as_deref(), Some("2026-10"));
+
+        assert!(provider.validate_access_token(&old_tokens.access_token).is_ok());
+        assert!(provider.validate_access_token(&new_tokens.access_token).is_ok());
+    }
+
+    #[test]
+    fn test_retired_key_rejected_after_reload() {
+        let old = secret_key("2026-07", "old-secret-key-for-testing-only-32");
+        let new = secret_key("2026-10", "new-secret-key-for-testing-only-32");
+        let provider = TokenProvider::from_config(Arc::new(create_key_ring_config(
+            vec![old.clone(), new.clone()],
+            "2026-07",
+        )))
+        .unwrap();
+
+        let old_tokens = provider
+            .generate_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+
+        provider
+            .reload_keys(&create_key_ring_config(vec![new], "2026-10"))
+            .unwrap();
+
+        let result = provider.validate_access_token(&old_tokens.access_token);
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+    }
+
+    #[test]
+    fn test_tokens_without_kid_validate_against_key_ring() {
+        // Tokens issued from the single jwt_secret carry no kid
+        let legacy = create_test_provider();
+        let tokens = legacy
+            .generate_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+
+        let provider = TokenProvider::from_config(Arc::new(create_key_ring_config(
+            vec![
+                secret_key("2026-10", "new-secret-key-for-testing-only-32"),
+                secret_key("legacy", "test-secret-key-for-testing-only"),
+            ],
+            "2026-10",
+        )))
+        .unwrap();
+
+        let claims = provider.validate_access_token(&tokens.access_token).unwrap();
+        assert_eq!(claims.username, "testuser");
+    }
+
+    #[test]
+    fn test_failed_reload_keeps_current_keys() {
+        let provider = TokenProvider::from_config(Arc::new(create_key_ring_config(
+            vec![secret_key("current", "current-secret-key-for-testing-only")],
+            "current",
+        )))
+        .unwrap();
+        let tokens = provider
+            .generate_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+
+        let result = provider.reload_keys(&create_key_ring_config(
+            vec![secret_key("current", "current-secret-key-for-testing-only")],
+            "missing",
+        ));
+        assert!(matches!(result, Err(ArcanaError::Configuration(_))));
+        assert!(provider.validate_access_token(&tokens.access_token).is_ok());
+    }
+
+    #[test]
+    fn test_reload_is_visible_to_clones() {
+        let provider = create_test_provider();
+        let clone = provider.clone();
+
+        provider
+            .reload_keys(&create_key_ring_config(
+                vec![secret_key("rotated", "rotated-secret-key-for-testing-only")],
+                "rotated",
+            ))
+            .unwrap();
+
+        let tokens = clone
+            .generate_tokens(UserId::new(), "testuser", "test@example.com", UserRole::User)
+            .unwrap();
+        let header = jsonwebtoken::decode_header(&tokens.access_token).unwrap();
+        assert_eq!(header.kid.as_deref(), Some("rotated"));
+    }
+
     #[test]
     fn test_provider_debug() {
         let provider = create_test_provider();
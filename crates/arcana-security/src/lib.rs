@@ -1,12 +1,19 @@
 //! # Arcana Security
 //!
 //! Security module for Arcana Cloud Rust providing JWT authentication,
-//! password hashing, and RBAC authorization.
+//! password hashing, multi-factor authentication, brute-force protection,
+//! RBAC authorization and webhook payload signing.
 
 pub mod jwt;
+pub mod lockout;
+pub mod mfa;
 pub mod password;
 pub mod rbac;
+pub mod webhook;
 
 pub use jwt::*;
+pub use lockout::*;
+pub use mfa::*;
 pub use password::*;
 pub use rbac::*;
+pub use webhook::*;
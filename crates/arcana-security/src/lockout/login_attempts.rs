@@ -0,0 +1,306 @@
+//! Failed-login counters.
+//!
+//! Every failed login is counted against the account and the client IP. A
+//! counter starts with the first failure and is dropped once the failure
+//! window has passed, so the counts cover a window of recent attempts.
+
+use arcana_core::{ArcanaError, ArcanaResult, Interface, UserId};
+use async_trait::async_trait;
+use chrono::Utc;
+use deadpool_redis::{redis::AsyncCommands, Pool};
+use shaku::Component;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Default window in which failed logins are counted.
+pub const DEFAULT_FAILURE_WINDOW: Duration = Duration::from_secs(900);
+
+/// Prefix for all failed-login counter keys.
+const LOGIN_FAILURES_PREFIX: &str = "arcana:login_failures";
+
+/// What a failed-login counter is kept for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginAttemptKey<'a> {
+    /// Failures against one account.
+    Account(UserId),
+    /// Failures from one client IP, whatever account they targeted.
+    Ip(&'a str),
+}
+
+impl LoginAttemptKey<'_> {
+    /// Returns the storage key of the counter.
+    fn storage_key(&self) -> String {
+        match self {
+            Self::Account(user_id) => format!("{}:account:{}", LOGIN_FAILURES_PREFIX, user_id),
+            Self::Ip(ip) => format!("{}:ip:{}", LOGIN_FAILURES_PREFIX, ip),
+        }
+    }
+}
+
+/// Interface for failed-login counters.
+#[async_trait]
+pub trait LoginAttemptInterface: Interface + Send + Sync {
+    /// Records a failed login and returns the failures in the current window.
+    async fn record_failure(&self, key: LoginAttemptKey<'_>) -> ArcanaResult<u32>;
+
+    /// Returns the failures in the current window.
+    async fn failures(&self, key: LoginAttemptKey<'_>) -> ArcanaResult<u32>;
+
+    /// Forgets the failures, e.g. after a successful login.
+    async fn clear(&self, key: LoginAttemptKey<'_>) -> ArcanaResult<()>;
+}
+
+/// Failed-login counters stored in Redis, with an in-memory fallback.
+///
+/// Failures are always counted locally as well, so protection keeps working
+/// on this instance when Redis is disabled or unreachable. Redis shares the
+/// counts between instances; the higher of both counts is used.
+#[derive(Component)]
+#[shaku(interface = LoginAttemptInterface)]
+pub struct LoginAttemptStore {
+    /// Redis connection pool (`None` keeps counters in memory only).
+    pool: Option<Arc<Pool>>,
+    /// How long failures are counted.
+    #[shaku(default = DEFAULT_FAILURE_WINDOW)]
+    window: Duration,
+    /// Local counters: key -> (failures, expiry timestamp).
+    #[shaku(default)]
+    local: Mutex<HashMap<String, (u32, i64)>>,
+}
+
+impl LoginAttemptStore {
+    /// Creates a Redis-backed counter store.
+    #[must_use]
+    pub fn new(pool: Arc<Pool>, window: Duration) -> Self {
+        Self {
+            pool: Some(pool),
+            window,
+            local: Mutex::default(),
+        }
+    }
+
+    /// Creates a counter store that only keeps counts in memory.
+    #[must_use]
+    pub fn in_memory(window: Duration) -> Self {
+        Self {
+            pool: None,
+            window,
+            local: Mutex::default(),
+        }
+    }
+
+    fn window_secs(&self) -> u64 {
+        self.window.as_secs().max(1)
+    }
+
+    /// Increments a local counter, dropping counters that have expired.
+    fn increment_locally(&self, key: &str) -> u32 {
+        let now = Utc::now().timestamp();
+        let expires_at = now.saturating_add(i64::try_from(self.window_secs()).unwrap_or(i64::MAX));
+        let mut local = self.local.lock().unwrap_or_else(|e| e.into_inner());
+        local.retain(|_, (_, expiry)| *expiry > now);
+        let entry = local.entry(key.to_string()).or_insert((0, expires_at));
+        entry.0 = entry.0.saturating_add(1);
+        entry.0
+    }
+
+    /// Reads a live local counter.
+    fn local_count(&self, key: &str) -> u32 {
+        let now = Utc::now().timestamp();
+        let local = self.local.lock().unwrap_or_else(|e| e.into_inner());
+        local
+            .get(key)
+            .filter(|(_, expiry)| *expiry > now)
+            .map_or(0, |(count, _)| *count)
+    }
+
+    async fn connection(pool: &Pool) -> ArcanaResult<deadpool_redis::Connection> {
+        pool.get()
+            .await
+            .map_err(|e| ArcanaError::Cache(format!("Failed to get Redis connection: {}", e)))
+    }
+
+    /// Increments a counter in Redis, if enabled, starting its window on the first failure.
+    async fn increment_in_redis(&self, key: &str) -> ArcanaResult<Option<u32>> {
+        let Some(pool) = &self.pool else {
+            return Ok(None);
+        };
+
+        let mut conn = Self::connection(pool).await?;
+        let count: u32 = conn
+            .incr(key, 1)
+            .await
+            .map_err(|e| ArcanaError::Cache(format!("Failed to increment '{}': {}", key, e)))?;
+
+        if count == 1 {
+            let ttl = i64::try_from(self.window_secs()).unwrap_or(i64::MAX);
+            conn.expire::<_, ()>(key, ttl)
+                .await
+                .map_err(|e| ArcanaError::Cache(format!("Failed to expire '{}': {}", key, e)))?;
+        }
+
+        Ok(Some(count))
+    }
+
+    /// Reads a counter from Redis, if enabled.
+    async fn redis_count(&self, key: &str) -> ArcanaResult<u32> {
+        let Some(pool) = &self.pool else {
+            return Ok(0);
+        };
+
+        let mut conn = Self::connection(pool).await?;
+        let count: Option<u32> = conn
+            .get(key)
+            .await
+            .map_err(|e| ArcanaError::Cache(format!("Failed to get key '{}': {}", key, e)))?;
+        Ok(count.unwrap_or(0))
+    }
+
+    /// Deletes a counter from Redis, if enabled.
+    async fn delete_in_redis(&self, key: &str) -> ArcanaResult<()> {
+        let Some(pool) = &self.pool else {
+            return Ok(());
+        };
+
+        let mut conn = Self::connection(pool).await?;
+        conn.del::<_, ()>(key)
+            .await
+            .map_err(|e| ArcanaError::Cache(format!("Failed to delete key '{}': {}", key, e)))
+    }
+}
+
+#[async_trait]
+impl LoginAttemptInterface for LoginAttemptStore {
+    async fn record_failure(&self, key: LoginAttemptKey<'_>) -> ArcanaResult<u32> {
+        let key = key.storage_key();
+        let local = self.increment_locally(&key);
+
+        let shared = match self.increment_in_redis(&key).await {
+            Ok(count) => count.unwrap_or(0),
+            Err(e) => {
+                warn!("Failed login counted in memory only: {}", e);
+                0
+            }
+        };
+
+        let failures = local.max(shared);
+        debug!("Failed login {} for {}", failures, key);
+        Ok(failures)
+    }
+
+    async fn failures(&self, key: LoginAttemptKey<'_>) -> ArcanaResult<u32> {
+        let key = key.storage_key();
+        let local = self.local_count(&key);
+
+        match self.redis_count(&key).await {
+            Ok(shared) => Ok(local.max(shared)),
+            Err(e) => {
+                warn!("Failed login count fell back to memory: {}", e);
+                Ok(local)
+            }
+        }
+    }
+
+    async fn clear(&self, key: LoginAttemptKey<'_>) -> ArcanaResult<()> {
+        let key = key.storage_key();
+        self.local
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&key);
+
+        if let Err(e) = self.delete_in_redis(&key).await {
+            warn!("Failed login counter cleared in memory only: {}", e);
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for LoginAttemptStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginAttemptStore")
+            .field("redis_enabled", &self.pool.is_some())
+            .field("window", &self.window)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_store() -> LoginAttemptStore {
+        LoginAttemptStore::in_memory(DEFAULT_FAILURE_WINDOW)
+    }
+
+    #[tokio::test]
+    async fn test_record_failure_counts_up() {
+        let store = create_store();
+        let user_id = UserId::new();
+
+        assert_eq!(store.failures(LoginAttemptKey::Account(user_id)).await.unwrap(), 0);
+        assert_eq!(store.record_failure(LoginAttemptKey::Account(user_id)).await.unwrap(), 1);
+        assert_eq!(store.record_failure(LoginAttemptKey::Account(user_id)).await.unwrap(), 2);
+        assert_eq!(store.failures(LoginAttemptKey::Account(user_id)).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_counters_are_separate() {
+        let store = create_store();
+        let user_id = UserId::new();
+
+        store.record_failure(LoginAttemptKey::Account(user_id)).await.unwrap();
+        store.record_failure(LoginAttemptKey::Ip("10.0.0.1")).await.unwrap();
+        store.record_failure(LoginAttemptKey::Ip("10.0.0.1")).await.unwrap();
+
+        assert_eq!(store.failures(LoginAttemptKey::Account(user_id)).await.unwrap(), 1);
+        assert_eq!(store.failures(LoginAttemptKey::Ip("10.0.0.1")).await.unwrap(), 2);
+        assert_eq!(store.failures(LoginAttemptKey::Ip("10.0.0.2")).await.unwrap(), 0);
+        assert_eq!(store.failures(LoginAttemptKey::Account(UserId::new())).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_clear_resets_counter() {
+        let store = create_store();
+        let key = LoginAttemptKey::Ip("10.0.0.1");
+
+        store.record_failure(key).await.unwrap();
+        store.clear(key).await.unwrap();
+
+        assert_eq!(store.failures(key).await.unwrap(), 0);
+        assert_eq!(store.record_failure(key).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_counter_starts_over() {
+        let store = create_store();
+        let key = LoginAttemptKey::Ip("10.0.0.1");
+        store.record_failure(key).await.unwrap();
+
+        store
+            .local
+            .lock()
+            .unwrap()
+            .values_mut()
+            .for_each(|(_, expiry)| *expiry = Utc::now().timestamp() - 1);
+
+        assert_eq!(store.failures(key).await.unwrap(), 0);
+        assert_eq!(store.record_failure(key).await.unwrap(), 1);
+    }
+
+    #[test]
+    fn test_storage_keys() {
+        let user_id = UserId::new();
+        assert_eq!(
+            LoginAttemptKey::Account(user_id).storage_key(),
+            format!("arcana:login_failures:account:{}", user_id)
+        );
+        assert_eq!(LoginAttemptKey::Ip("::1").storage_key(), "arcana:login_failures:ip:::1");
+    }
+
+    #[test]
+    fn test_store_debug() {
+        assert!(format!("{:?}", create_store()).contains("redis_enabled: false"));
+    }
+}
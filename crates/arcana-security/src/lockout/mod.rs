@@ -0,0 +1,5 @@
+//! Brute-force protection for logins.
+
+mod login_attempts;
+
+pub use login_attempts::*;
@@ -0,0 +1,7 @@
+//! Multi-factor authentication: TOTP and one-time recovery codes.
+
+mod recovery_codes;
+mod totp;
+
+pub use recovery_codes::*;
+pub use totp::*;
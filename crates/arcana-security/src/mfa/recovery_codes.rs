@@ -0,0 +1,79 @@
+//! One-time recovery codes for accounts with two-factor authentication.
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+
+/// Number of recovery codes issued at a time.
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Characters a recovery code is drawn from (no `0`/`o` or `1`/`l` lookalikes).
+const RECOVERY_CODE_ALPHABET: &[u8] = b"23456789abcdefghjkmnpqrstuvwxyz";
+
+/// Length of each of the two dash-separated groups of a recovery code.
+const RECOVERY_CODE_GROUP_LEN: usize = 5;
+
+/// Generates `count` random recovery codes formatted as `xxxxx-xxxxx`.
+///
+/// Only [`normalize_recovery_code`] followed by `hash_token` of each code is
+/// stored; the plain codes are shown to the user once.
+#[must_use]
+pub fn generate_recovery_codes(count: usize) -> Vec<String> {
+    (0..count).map(|_| generate_recovery_code()).collect()
+}
+
+/// Normalizes user input so codes match regardless of case, dashes or spaces.
+#[must_use]
+pub fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn generate_recovery_code() -> String {
+    let mut code = String::with_capacity(RECOVERY_CODE_GROUP_LEN * 2 + 1);
+    for i in 0..RECOVERY_CODE_GROUP_LEN * 2 {
+        if i == RECOVERY_CODE_GROUP_LEN {
+            code.push('-');
+        }
+        // Rejection sampling keeps every character equally likely
+        let limit = u8::MAX - u8::MAX % RECOVERY_CODE_ALPHABET.len() as u8;
+        let index = loop {
+            let mut byte = [0u8; 1];
+            OsRng.fill_bytes(&mut byte);
+            if byte[0] < limit {
+                break byte[0] as usize % RECOVERY_CODE_ALPHABET.len();
+            }
+        };
+        code.push(RECOVERY_CODE_ALPHABET[index] as char);
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_recovery_codes() {
+        let codes = generate_recovery_codes(RECOVERY_CODE_COUNT);
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+
+        for code in &codes {
+            assert_eq!(code.len(), 11);
+            assert_eq!(code.chars().nth(5), Some('-'));
+            assert!(code.bytes().filter(|b| *b != b'-').all(|b| RECOVERY_CODE_ALPHABET.contains(&b)));
+        }
+
+        let mut unique = codes.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), codes.len());
+    }
+
+    #[test]
+    fn test_normalize_recovery_code() {
+        assert_eq!(normalize_recovery_code("ABCDE-FGHJK"), "abcdefghjk");
+        assert_eq!(normalize_recovery_code(" abcde fghjk "), "abcdefghjk");
+        assert_eq!(normalize_recovery_code("abcdefghjk"), "abcdefghjk");
+    }
+}
@@ -0,0 +1,131 @@
+//! Time-based one-time passwords (RFC 6238).
+
+use arcana_core::{ArcanaError, ArcanaResult};
+use totp_rs::{Algorithm, Secret, TOTP};
+
+/// Number of digits in a TOTP code.
+const TOTP_DIGITS: usize = 6;
+
+/// Length of a TOTP time step in seconds.
+pub const TOTP_STEP_SECS: u64 = 30;
+
+/// Number of neighbouring time steps accepted to absorb clock drift.
+const TOTP_ALLOWED_DRIFT_STEPS: i64 = 1;
+
+/// Generates a new random TOTP secret (160 bits, base32-encoded).
+#[must_use]
+pub fn generate_totp_secret() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+}
+
+/// Builds the `otpauth://` provisioning URI for an authenticator app.
+///
+/// # Errors
+///
+/// Returns an error if the secret is not valid base32 or the account name
+/// contains a colon.
+pub fn totp_provisioning_uri(secret: &str, issuer: &str, account: &str) -> ArcanaResult<String> {
+    Ok(build_totp(secret, Some(issuer.to_string()), account.to_string())?.get_url())
+}
+
+/// Verifies a TOTP code at `unix_time`, allowing one step of drift either way.
+///
+/// Returns the time step the code matched so callers can reject a replay of
+/// the same code, or `None` if the code is wrong.
+///
+/// # Errors
+///
+/// Returns an error if the stored secret is not valid base32.
+pub fn verify_totp(secret: &str, code: &str, unix_time: u64) -> ArcanaResult<Option<i64>> {
+    let totp = build_totp(secret, None, String::new())?;
+    let code = code.trim();
+    let current_step = (unix_time / TOTP_STEP_SECS) as i64;
+
+    for step in current_step - TOTP_ALLOWED_DRIFT_STEPS..=current_step + TOTP_ALLOWED_DRIFT_STEPS {
+        if step < 0 {
+            continue;
+        }
+        // `check` compares in constant time; a zero skew pins it to this step
+        if totp.check(code, step as u64 * TOTP_STEP_SECS) {
+            return Ok(Some(step));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Generates the TOTP code for `unix_time`, as an authenticator app would.
+///
+/// # Errors
+///
+/// Returns an error if the secret is not valid base32.
+pub fn generate_totp_code(secret: &str, unix_time: u64) -> ArcanaResult<String> {
+    Ok(build_totp(secret, None, String::new())?.generate(unix_time))
+}
+
+fn build_totp(secret: &str, issuer: Option<String>, account: String) -> ArcanaResult<TOTP> {
+    let bytes = Secret::Encoded(secret.to_string())
+        .to_bytes()
+        .map_err(|e| ArcanaError::Internal(format!("Invalid TOTP secret: {e}")))?;
+
+    TOTP::new(Algorithm::SHA1, TOTP_DIGITS, 0, TOTP_STEP_SECS, bytes, issuer, account)
+        .map_err(|e| ArcanaError::Internal(format!("Invalid TOTP parameters: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code_at(secret: &str, unix_time: u64) -> String {
+        generate_totp_code(secret, unix_time).unwrap()
+    }
+
+    #[test]
+    fn test_generate_totp_secret() {
+        let secret = generate_totp_secret();
+        assert_eq!(secret.len(), 32);
+        assert_ne!(secret, generate_totp_secret());
+    }
+
+    #[test]
+    fn test_provisioning_uri() {
+        let secret = generate_totp_secret();
+        let uri = totp_provisioning_uri(&secret, "Arcana Cloud", "alice@example.com").unwrap();
+
+        assert!(uri.starts_with("otpauth://totp/Arcana%20Cloud:alice%40example.com?"));
+        assert!(uri.contains(&format!("secret={secret}")));
+        assert!(uri.contains("issuer=Arcana%20Cloud"));
+    }
+
+    #[test]
+    fn test_verify_totp_returns_matched_step() {
+        let secret = generate_totp_secret();
+        let now = 1_700_000_000;
+        let code = code_at(&secret, now);
+
+        assert_eq!(verify_totp(&secret, &code, now).unwrap(), Some((now / TOTP_STEP_SECS) as i64));
+    }
+
+    #[test]
+    fn test_verify_totp_allows_one_step_of_drift() {
+        let secret = generate_totp_secret();
+        let now = 1_700_000_000;
+
+        let previous = code_at(&secret, now - TOTP_STEP_SECS);
+        assert!(verify_totp(&secret, &previous, now).unwrap().is_some());
+
+        let stale = code_at(&secret, now - 3 * TOTP_STEP_SECS);
+        assert!(verify_totp(&secret, &stale, now).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_verify_totp_rejects_wrong_code() {
+        let secret = generate_totp_secret();
+        assert!(verify_totp(&secret, "not-a-code", 1_700_000_000).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_invalid_secret() {
+        assert!(verify_totp("not base32!", "123456", 1_700_000_000).is_err());
+    }
+}
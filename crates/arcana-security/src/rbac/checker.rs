@@ -38,14 +38,10 @@ impl ClaimsExt for Claims {
     }
 
     fn require_permission(&self, permission: Permission) -> ArcanaResult<()> {
-        if permission.is_allowed_for(self.role) {
+        if self.is_granted(permission) {
             Ok(())
         } else {
-            Err(ArcanaError::Forbidden(format!(
-                "Permission denied: {} requires at least {} role",
-                permission,
-                permission.minimum_role()
-            )))
+            Err(ArcanaError::Forbidden(format!("Permission denied: {} is not granted", permission)))
         }
     }
 
@@ -80,6 +76,7 @@ pub struct PermissionGuard {
     required_role: Option<UserRole>,
     required_permission: Option<Permission>,
     allow_owner: bool,
+    default_tenant_only: bool,
 }
 
 impl PermissionGuard {
@@ -90,6 +87,7 @@ impl PermissionGuard {
             required_role: None,
             required_permission: None,
             allow_owner: false,
+            default_tenant_only: false,
         }
     }
 
@@ -114,8 +112,22 @@ impl PermissionGuard {
         self
     }
 
+    /// Restricts access to users of the default tenant, for resources
+    /// shared by the whole deployment.
+    #[must_use]
+    pub fn default_tenant_only(mut self) -> Self {
+        self.default_tenant_only = true;
+        self
+    }
+
     /// Checks if the claims satisfy the guard requirements.
     pub fn check(&self, claims: &Claims, resource_owner_id: Option<UserId>) -> ArcanaResult<()> {
+        if self.default_tenant_only && !claims.tenant_id().is_default() {
+            return Err(ArcanaError::Forbidden(
+                "Only available to users of the default tenant".to_string(),
+            ));
+        }
+
         // Check if owner access is allowed and user is owner
         if self.allow_owner {
             if let Some(owner_id) = resource_owner_id {
@@ -196,12 +208,18 @@ pub mod guards {
     pub fn system_config() -> PermissionGuard {
         PermissionGuard::new().permission(Permission::SystemConfig)
     }
+
+    /// Guard for system monitoring, including reading the audit trail.
+    #[must_use]
+    pub fn system_monitor() -> PermissionGuard {
+        PermissionGuard::new().permission(Permission::SystemMonitor)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arcana_core::Permission;
+    use arcana_core::{Permission, PermissionSet};
     use chrono::{Duration, Utc};
 
     fn create_claims(role: UserRole) -> Claims {
@@ -301,6 +319,17 @@ mod tests {
         assert!(guard.check(&user_claims, None).is_err());
     }
 
+    #[test]
+    fn test_default_tenant_only_guard() {
+        let admin_claims = create_claims(UserRole::Admin);
+        let guard = guards::admin().default_tenant_only();
+
+        assert!(guard.check(&admin_claims, None).is_ok());
+        let tenant_admin = admin_claims.with_tenant(arcana_core::TenantId::new());
+        assert!(guards::admin().check(&tenant_admin, None).is_ok());
+        assert!(guard.check(&tenant_admin, None).is_err());
+    }
+
     #[test]
     fn test_owner_or_admin_guard() {
         let user_claims = create_claims(UserRole::User);
@@ -329,6 +358,28 @@ mod tests {
         assert!(guard.check(&admin_claims, None).is_ok());
     }
 
+    #[test]
+    fn test_system_monitor_guard() {
+        let guard = guards::system_monitor();
+
+        assert!(guard.check(&create_claims(UserRole::User), None).is_err());
+        assert!(guard.check(&create_claims(UserRole::Moderator), None).is_ok());
+        assert!(guard.check(&create_claims(UserRole::SuperAdmin), None).is_ok());
+    }
+
+    #[test]
+    fn test_permission_guard_uses_resolved_permissions() {
+        let mut support_claims = create_claims(UserRole::User);
+        support_claims.permissions = Some([Permission::UserRead, Permission::UserUpdate].into_iter().collect());
+
+        assert!(PermissionGuard::new().permission(Permission::UserUpdate).check(&support_claims, None).is_ok());
+        assert!(guards::user_management().check(&support_claims, None).is_err());
+
+        let mut restricted_admin = create_claims(UserRole::Admin);
+        restricted_admin.permissions = Some(PermissionSet::new());
+        assert!(restricted_admin.require_permission(Permission::UserDelete).is_err());
+    }
+
     #[test]
     fn test_permission_guard_with_specific_permission() {
         let user_claims = create_claims(UserRole::User);
@@ -1,5 +1,7 @@
 //! Role-based access control module.
 
 mod checker;
+mod resolver;
 
 pub use checker::*;
+pub use resolver::*;
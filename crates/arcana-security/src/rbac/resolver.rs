@@ -0,0 +1,42 @@
+//! Effective permission resolution.
+//!
+//! A user's effective permissions are the grants of their built-in role, of
+//! the custom roles assigned to them and the permissions granted to them
+//! directly. Resolving them needs the database, so the resolver lives behind
+//! an interface implemented by the service layer and is expected to cache.
+
+use arcana_core::{ArcanaResult, Interface, PermissionSet, UserId, UserRole};
+use async_trait::async_trait;
+use shaku::Component;
+
+/// Interface for resolving the effective permissions of users.
+#[async_trait]
+pub trait PermissionResolverInterface: Interface + Send + Sync {
+    /// Resolves the effective permissions of a user holding `role`.
+    async fn resolve(&self, user_id: UserId, role: UserRole) -> ArcanaResult<PermissionSet>;
+
+    /// Drops the cached permissions of a user.
+    async fn invalidate_user(&self, user_id: UserId);
+
+    /// Drops every cached permission set, e.g. after a role's grants changed.
+    async fn invalidate_all(&self);
+}
+
+/// Resolver granting the built-in permissions of the user's role only.
+///
+/// Used by deployments that cannot reach the role tables, such as the
+/// distributed service layer.
+#[derive(Component, Debug, Default)]
+#[shaku(interface = PermissionResolverInterface)]
+pub struct RolePermissionResolver;
+
+#[async_trait]
+impl PermissionResolverInterface for RolePermissionResolver {
+    async fn resolve(&self, _user_id: UserId, role: UserRole) -> ArcanaResult<PermissionSet> {
+        Ok(role.default_permissions())
+    }
+
+    async fn invalidate_user(&self, _user_id: UserId) {}
+
+    async fn invalidate_all(&self) {}
+}
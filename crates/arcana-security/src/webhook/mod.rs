@@ -0,0 +1,5 @@
+//! Outbound webhook payload signing.
+
+mod signature;
+
+pub use signature::*;
@@ -0,0 +1,102 @@
+//! HMAC-SHA256 signatures for webhook payloads.
+//!
+//! The signature header has the form `t=<unix seconds>,v1=<hex digest>`, where
+//! the digest is computed over `"<t>.<body>"` with the subscription secret.
+//! Binding the timestamp into the digest lets receivers reject replayed
+//! requests.
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Header carrying the payload signature.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Arcana-Signature";
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn mac(secret: &str, timestamp: i64, body: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac
+}
+
+/// Signs a payload, returning the value of the [`WEBHOOK_SIGNATURE_HEADER`].
+#[must_use]
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let digest = mac(secret, timestamp, body).finalize().into_bytes();
+    format!("t={},v1={}", timestamp, hex::encode(digest))
+}
+
+/// Verifies a signature header produced by [`sign_webhook_payload`].
+///
+/// Fails if the header is malformed, the digest does not match or the
+/// timestamp is more than `tolerance_secs` away from the current time. The
+/// digest is compared in constant time.
+#[must_use]
+pub fn verify_webhook_signature(secret: &str, header: &str, body: &[u8], tolerance_secs: i64) -> bool {
+    let mut timestamp = None;
+    let mut digest = None;
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = value.parse::<i64>().ok(),
+            Some(("v1", value)) => digest = hex::decode(value).ok(),
+            _ => {}
+        }
+    }
+    let (Some(timestamp), Some(digest)) = (timestamp, digest) else {
+        return false;
+    };
+
+    if (Utc::now().timestamp() - timestamp).abs() > tolerance_secs {
+        return false;
+    }
+    mac(secret, timestamp, body).verify_slice(&digest).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_0123456789abcdef";
+
+    #[test]
+    fn test_sign_and_verify() {
+        let body = br#"{"type":"user.created"}"#;
+        let header = sign_webhook_payload(SECRET, Utc::now().timestamp(), body);
+        assert!(header.starts_with("t="));
+        assert!(header.contains(",v1="));
+        assert!(verify_webhook_signature(SECRET, &header, body, 300));
+    }
+
+    #[test]
+    fn test_signature_is_deterministic() {
+        assert_eq!(
+            sign_webhook_payload(SECRET, 1_700_000_000, b"{}"),
+            sign_webhook_payload(SECRET, 1_700_000_000, b"{}")
+        );
+        assert_ne!(
+            sign_webhook_payload(SECRET, 1_700_000_000, b"{}"),
+            sign_webhook_payload(SECRET, 1_700_000_001, b"{}")
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_tampering() {
+        let body = br#"{"type":"user.created"}"#;
+        let header = sign_webhook_payload(SECRET, Utc::now().timestamp(), body);
+        assert!(!verify_webhook_signature(SECRET, &header, br#"{"type":"user.deleted"}"#, 300));
+        assert!(!verify_webhook_signature("another-secret", &header, body, 300));
+    }
+
+    #[test]
+    fn test_verify_rejects_stale_and_malformed_headers() {
+        let body = b"{}";
+        let stale = sign_webhook_payload(SECRET, Utc::now().timestamp() - 600, body);
+        assert!(!verify_webhook_signature(SECRET, &stale, body, 300));
+        assert!(!verify_webhook_signature(SECRET, "v1=abcd", body, 300));
+        assert!(!verify_webhook_signature(SECRET, "t=1,v1=not-hex", body, 300));
+        assert!(!verify_webhook_signature(SECRET, "", body, 300));
+    }
+}
@@ -23,8 +23,9 @@
 //! 3. **Connection**: Connection establishment overhead
 //! 4. **Throughput**: Requests per second under load
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput, BenchmarkId};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput, BenchmarkId};
 use serde::{Deserialize, Serialize};
+use std::hint::black_box;
 use std::time::Duration;
 
 // ============================================================================
@@ -5,17 +5,43 @@
 //! - `DistributedServiceModule`: Service layer with remote repository via gRPC
 //! - `RepositoryModule`: Repository layer only (for distributed deployments)
 
-use arcana_config::{DatabaseConfig, RedisConfig, SecurityConfig, SecurityConfigInterface};
+use arcana_config::{
+    DatabaseConfig, EventsConfig, MailConfig, PluginConfig, RedisConfig, SecurityConfig, SecurityConfigInterface,
+};
 use arcana_core::{module, ArcanaResult, HasComponent};
-use arcana_grpc::RemoteUserRepository;
+use arcana_grpc::{
+    RemoteAuditLogRepository, RemoteMfaRepository, RemoteOAuthTokenRepository, RemotePasswordResetTokenRepository,
+    RemoteTenantRepository, RemoteUserRepository,
+};
 use arcana_repository::{
+    AuditLogRepository, AuditLogRepositoryImpl,
     DatabasePool, DatabasePoolInterface,
-    MySqlUserDaoImpl,
+    MfaRepository, MfaRepositoryImpl,
+    MySqlAuditLogDaoImpl, MySqlMfaDaoImpl, MySqlOAuthTokenDaoImpl, MySqlOutboxDaoImpl, MySqlPasswordResetTokenDaoImpl,
+    MySqlRoleDaoImpl, MySqlTenantDaoImpl, MySqlUserDaoImpl, MySqlWebhookDaoImpl,
+    OAuthTokenRepository, OAuthTokenRepositoryImpl,
+    OutboxRepository, OutboxRepositoryImpl,
+    PasswordResetTokenRepository, PasswordResetTokenRepositoryImpl,
+    RoleRepositoryImpl,
+    TenantRepository, TenantRepositoryImpl,
     UserRepository, UserRepositoryImpl,
+    WebhookRepository, WebhookRepositoryImpl,
+};
+use arcana_security::{
+    LoginAttemptInterface, LoginAttemptStore, PasswordHasher, PasswordHasherInterface, PermissionResolverInterface,
+    RolePermissionResolver, TokenProvider, TokenProviderInterface, TokenRevocationInterface, TokenRevocationStore,
 };
-use arcana_security::{PasswordHasher, PasswordHasherInterface, TokenProvider, TokenProviderInterface};
+use arcana_jobs::redis::RedisJobQueue;
+use arcana_jobs::JobsConfig;
+use arcana_plugin_runtime::{PluginEventBridge, PluginManager};
+use arcana_service::{AuditService, AuditServiceImpl, AuditTrail, AuditedAuthService, AuditedUserService};
 use arcana_service::{AuthService, AuthServiceComponent, CacheInterface, RedisCacheService, RedisCacheServiceParameters, UserService, UserServiceComponent};
+use arcana_service::{mailer_from_config, spawn_mail_worker, MailQueue, MailQueueParameters};
+use arcana_service::{spawn_outbox_relay, EventBus, EventBusExt, EventBusInterface, OutboxRelay};
+use arcana_service::{spawn_webhook_worker, WebhookDeliverer, WebhookDispatcher, WebhookServiceImpl};
+use arcana_service::{PermissionResolver, RoleServiceImpl, TenantServiceImpl};
 use std::sync::Arc;
+use std::time::Duration;
 
 // ============================================================================
 // Shaku Module Definitions
@@ -24,9 +50,21 @@ use std::sync::Arc;
 // Monolithic deployment module with local MySQL database.
 // Contains all components for a single-process deployment:
 // - Database pool → MySqlUserDaoImpl (DAO) → UserRepositoryImpl (Repository)
-// - Security components (password hashing, JWT tokens)
+// - Database pool → MySqlOAuthTokenDaoImpl (DAO) → OAuthTokenRepositoryImpl (Repository)
+// - Database pool → MySqlPasswordResetTokenDaoImpl (DAO) → PasswordResetTokenRepositoryImpl (Repository)
+// - Database pool → MySqlMfaDaoImpl (DAO) → MfaRepositoryImpl (Repository)
+// - Database pool → MySqlAuditLogDaoImpl (DAO) → AuditLogRepositoryImpl (Repository)
+// - Database pool → MySqlOutboxDaoImpl (DAO) → OutboxRepositoryImpl (Repository)
+// - Database pool → MySqlWebhookDaoImpl (DAO) → WebhookRepositoryImpl (Repository)
+// - Database pool → MySqlRoleDaoImpl (DAO) → RoleRepositoryImpl (Repository)
+// - Database pool → MySqlTenantDaoImpl (DAO) → TenantRepositoryImpl (Repository)
+// - Security components (password hashing, JWT tokens, token revocation, failed-login counters)
 // - Caching (Redis)
-// - Business services (user, auth)
+// - Mail queue (Redis job queue, inline delivery without Redis)
+// - Audit trail
+// - Domain event bus
+// - Permission resolution (cached role and grant lookups)
+// - Business services (user, auth, audit, webhooks, roles, tenants)
 //
 // 4-layer hierarchy within this module:
 //   Service → UserRepository (trait) → UserRepositoryImpl → UserDao → MySqlUserDaoImpl → MySQL
@@ -36,12 +74,38 @@ module! {
             DatabasePool,
             PasswordHasher,
             TokenProvider,
+            TokenRevocationStore,
+            LoginAttemptStore,
             SecurityConfig,
             MySqlUserDaoImpl,      // DAO layer (implements UserDao)
             UserRepositoryImpl,    // Repository layer (implements UserRepository, injects UserDao)
+            MySqlOAuthTokenDaoImpl,
+            OAuthTokenRepositoryImpl,
+            MySqlPasswordResetTokenDaoImpl,
+            PasswordResetTokenRepositoryImpl,
+            MySqlMfaDaoImpl,
+            MfaRepositoryImpl,
+            MySqlAuditLogDaoImpl,
+            AuditLogRepositoryImpl,
+            MySqlOutboxDaoImpl,
+            OutboxRepositoryImpl,
+            MySqlWebhookDaoImpl,
+            WebhookRepositoryImpl,
+            MySqlRoleDaoImpl,
+            RoleRepositoryImpl,
+            MySqlTenantDaoImpl,
+            TenantRepositoryImpl,
             RedisCacheService,
+            MailQueue,
+            AuditTrail,
+            EventBus,
             UserServiceComponent,
             AuthServiceComponent,
+            AuditServiceImpl,
+            WebhookServiceImpl,
+            PermissionResolver,
+            RoleServiceImpl,
+            TenantServiceImpl,
         ],
         providers = [],
     }
@@ -49,20 +113,36 @@ module! {
 
 // Distributed service layer module with remote repository.
 // Contains components for the service layer in a distributed deployment:
-// - Security components (password hashing, JWT tokens)
+// - Security components (password hashing, JWT tokens, token revocation, failed-login counters)
 // - Caching (Redis)
-// - Business services (user, auth)
-// - Remote repository client (connects to repository layer via gRPC)
+// - Mail queue (Redis job queue, inline delivery without Redis)
+// - Audit trail
+// - Domain event bus
+// - Permissions of the built-in roles (the role tables are not reachable)
+// - Business services (user, auth, audit); webhooks are not delivered in this layer
+// - Remote repository clients (connect to repository layer via gRPC)
 module! {
     pub DistributedServiceModule {
         components = [
             PasswordHasher,
             TokenProvider,
+            TokenRevocationStore,
+            LoginAttemptStore,
             SecurityConfig,
             RemoteUserRepository,
+            RemoteOAuthTokenRepository,
+            RemotePasswordResetTokenRepository,
+            RemoteMfaRepository,
+            RemoteAuditLogRepository,
+            RemoteTenantRepository,
             RedisCacheService,
+            MailQueue,
+            AuditTrail,
+            EventBus,
+            RolePermissionResolver,
             UserServiceComponent,
             AuthServiceComponent,
+            AuditServiceImpl,
         ],
         providers = [],
     }
@@ -77,6 +157,16 @@ module! {
             DatabasePool,
             MySqlUserDaoImpl,   // DAO layer
             UserRepositoryImpl, // Repository layer
+            MySqlOAuthTokenDaoImpl,
+            OAuthTokenRepositoryImpl,
+            MySqlPasswordResetTokenDaoImpl,
+            PasswordResetTokenRepositoryImpl,
+            MySqlMfaDaoImpl,
+            MfaRepositoryImpl,
+            MySqlAuditLogDaoImpl,
+            AuditLogRepositoryImpl,
+            MySqlTenantDaoImpl,
+            TenantRepositoryImpl,
         ],
         providers = [],
     }
@@ -93,26 +183,22 @@ pub async fn build_monolithic_module(
     db_config: &DatabaseConfig,
     redis_config: &RedisConfig,
     security_config: SecurityConfig,
+    mail_config: &MailConfig,
 ) -> ArcanaResult<Arc<MonolithicModule>> {
     // Create database pool (async operation)
     let db_pool = DatabasePool::connect(db_config).await?;
 
     // Create Redis cache pool (if enabled)
-    let cache_pool = if redis_config.enabled {
-        let redis_cfg = deadpool_redis::Config::from_url(&redis_config.url);
-        let pool = redis_cfg
-            .create_pool(Some(deadpool_redis::Runtime::Tokio1))
-            .map_err(|e| arcana_core::ArcanaError::Cache(format!("Failed to create Redis pool: {}", e)))?;
-        Some(Arc::new(pool))
-    } else {
-        None
-    };
+    let cache_pool = create_redis_pool(redis_config)?.map(Arc::new);
+
+    // Create mail queue (queued on Redis when enabled)
+    let mail_queue = build_mail_queue_parameters(cache_pool.as_deref(), mail_config)?;
 
     // Create password hasher with configured cost
     let password_hasher = PasswordHasher::with_cost(security_config.password_hash_cost());
 
-    // Create token provider with security config
-    let token_provider = TokenProvider::new(Arc::new(security_config.clone()));
+    // Create token provider for the configured signing algorithm
+    let token_provider = TokenProvider::from_config(Arc::new(security_config.clone()))?;
 
     // Build the module with parameters
     let module = MonolithicModule::builder()
@@ -120,17 +206,30 @@ pub async fn build_monolithic_module(
             pool: db_pool.inner().clone(),
         })
         .with_component_parameters::<RedisCacheService>(RedisCacheServiceParameters {
-            pool: cache_pool,
+            pool: cache_pool.clone(),
             default_ttl: arcana_service::DEFAULT_TTL,
         })
+        .with_component_parameters::<MailQueue>(mail_queue)
+        .with_component_parameters::<PermissionResolver>(arcana_service::PermissionResolverParameters {
+            ttl: security_config.permission_cache_ttl(),
+            local: Default::default(),
+        })
+        .with_component_parameters::<TokenRevocationStore>(arcana_security::TokenRevocationStoreParameters {
+            pool: cache_pool.clone(),
+            user_revocation_ttl: Duration::from_secs(security_config.jwt_access_expiration_secs),
+            local: Default::default(),
+        })
+        .with_component_parameters::<LoginAttemptStore>(arcana_security::LoginAttemptStoreParameters {
+            pool: cache_pool,
+            window: security_config.lockout.failure_window(),
+            local: Default::default(),
+        })
         .with_component_parameters::<PasswordHasher>(arcana_security::PasswordHasherParameters {
             argon2: password_hasher.argon2_arc(),
         })
         .with_component_parameters::<TokenProvider>(arcana_security::TokenProviderParameters {
-            encoding_key: token_provider.encoding_key().clone(),
-            decoding_key: token_provider.decoding_key().clone(),
+            keys: token_provider.keys().clone(),
             config: Arc::new(security_config.clone()),
-            validation: token_provider.validation().clone(),
         })
         .with_component_parameters::<SecurityConfig>(arcana_config::SecurityConfigParameters {
             jwt_secret: security_config.jwt_secret.clone(),
@@ -142,6 +241,17 @@ pub async fn build_monolithic_module(
             tls_cert_path: security_config.tls_cert_path.clone(),
             tls_key_path: security_config.tls_key_path.clone(),
             password_hash_cost: security_config.password_hash_cost,
+            jwt_algorithm: security_config.jwt_algorithm,
+            jwt_private_key_path: security_config.jwt_private_key_path.clone(),
+            jwt_public_key_path: security_config.jwt_public_key_path.clone(),
+            jwt_keys: security_config.jwt_keys.clone(),
+            jwt_signing_kid: security_config.jwt_signing_kid.clone(),
+            email_verification_expiration_secs: security_config.email_verification_expiration_secs,
+            password_reset_expiration_secs: security_config.password_reset_expiration_secs,
+            mfa_issuer: security_config.mfa_issuer.clone(),
+            mfa_challenge_expiration_secs: security_config.mfa_challenge_expiration_secs,
+            permission_cache_ttl_secs: security_config.permission_cache_ttl_secs,
+            lockout: security_config.lockout.clone(),
         })
         .build();
 
@@ -155,41 +265,53 @@ pub async fn build_distributed_service_module(
     repository_url: &str,
     redis_config: &RedisConfig,
     security_config: SecurityConfig,
+    mail_config: &MailConfig,
 ) -> ArcanaResult<Arc<DistributedServiceModule>> {
     // Create remote repository client (async operation)
     let remote_repo = RemoteUserRepository::connect(repository_url).await?;
 
+    // No outbox relay runs in this layer, so events stored in the outbox would never be delivered
+    if remote_repo.stores_events() {
+        return Err(arcana_core::ArcanaError::Configuration(
+            "The transactional outbox is only supported in monolithic deployments".to_string(),
+        ));
+    }
+
     // Create Redis cache pool (if enabled)
-    let cache_pool = if redis_config.enabled {
-        let redis_cfg = deadpool_redis::Config::from_url(&redis_config.url);
-        let pool = redis_cfg
-            .create_pool(Some(deadpool_redis::Runtime::Tokio1))
-            .map_err(|e| arcana_core::ArcanaError::Cache(format!("Failed to create Redis pool: {}", e)))?;
-        Some(Arc::new(pool))
-    } else {
-        None
-    };
+    let cache_pool = create_redis_pool(redis_config)?.map(Arc::new);
+
+    // Create mail queue (queued on Redis when enabled)
+    let mail_queue = build_mail_queue_parameters(cache_pool.as_deref(), mail_config)?;
 
     // Create password hasher with configured cost
     let password_hasher = PasswordHasher::with_cost(security_config.password_hash_cost());
 
-    // Create token provider with security config
-    let token_provider = TokenProvider::new(Arc::new(security_config.clone()));
+    // Create token provider for the configured signing algorithm
+    let token_provider = TokenProvider::from_config(Arc::new(security_config.clone()))?;
 
     // Build the module with parameters
     let module = DistributedServiceModule::builder()
         .with_component_parameters::<RedisCacheService>(RedisCacheServiceParameters {
-            pool: cache_pool,
+            pool: cache_pool.clone(),
             default_ttl: arcana_service::DEFAULT_TTL,
         })
+        .with_component_parameters::<MailQueue>(mail_queue)
+        .with_component_parameters::<TokenRevocationStore>(arcana_security::TokenRevocationStoreParameters {
+            pool: cache_pool.clone(),
+            user_revocation_ttl: Duration::from_secs(security_config.jwt_access_expiration_secs),
+            local: Default::default(),
+        })
+        .with_component_parameters::<LoginAttemptStore>(arcana_security::LoginAttemptStoreParameters {
+            pool: cache_pool,
+            window: security_config.lockout.failure_window(),
+            local: Default::default(),
+        })
         .with_component_parameters::<PasswordHasher>(arcana_security::PasswordHasherParameters {
             argon2: password_hasher.argon2_arc(),
         })
         .with_component_parameters::<TokenProvider>(arcana_security::TokenProviderParameters {
-            encoding_key: token_provider.encoding_key().clone(),
-            decoding_key: token_provider.decoding_key().clone(),
+            keys: token_provider.keys().clone(),
             config: Arc::new(security_config.clone()),
-            validation: token_provider.validation().clone(),
         })
         .with_component_parameters::<SecurityConfig>(arcana_config::SecurityConfigParameters {
             jwt_secret: security_config.jwt_secret.clone(),
@@ -201,12 +323,48 @@ pub async fn build_distributed_service_module(
             tls_cert_path: security_config.tls_cert_path.clone(),
             tls_key_path: security_config.tls_key_path.clone(),
             password_hash_cost: security_config.password_hash_cost,
+            jwt_algorithm: security_config.jwt_algorithm,
+            jwt_private_key_path: security_config.jwt_private_key_path.clone(),
+            jwt_public_key_path: security_config.jwt_public_key_path.clone(),
+            jwt_keys: security_config.jwt_keys.clone(),
+            jwt_signing_kid: security_config.jwt_signing_kid.clone(),
+            email_verification_expiration_secs: security_config.email_verification_expiration_secs,
+            password_reset_expiration_secs: security_config.password_reset_expiration_secs,
+            mfa_issuer: security_config.mfa_issuer.clone(),
+            mfa_challenge_expiration_secs: security_config.mfa_challenge_expiration_secs,
+            permission_cache_ttl_secs: security_config.permission_cache_ttl_secs,
+            lockout: security_config.lockout.clone(),
         })
         .with_component_parameters::<RemoteUserRepository>(
             arcana_grpc::RemoteUserRepositoryParameters {
                 client: remote_repo.client().clone(),
             },
         )
+        .with_component_parameters::<RemoteOAuthTokenRepository>(
+            arcana_grpc::RemoteOAuthTokenRepositoryParameters {
+                client: remote_repo.client().clone(),
+            },
+        )
+        .with_component_parameters::<RemotePasswordResetTokenRepository>(
+            arcana_grpc::RemotePasswordResetTokenRepositoryParameters {
+                client: remote_repo.client().clone(),
+            },
+        )
+        .with_component_parameters::<RemoteMfaRepository>(
+            arcana_grpc::RemoteMfaRepositoryParameters {
+                client: remote_repo.client().clone(),
+            },
+        )
+        .with_component_parameters::<RemoteAuditLogRepository>(
+            arcana_grpc::RemoteAuditLogRepositoryParameters {
+                client: remote_repo.client().clone(),
+            },
+        )
+        .with_component_parameters::<RemoteTenantRepository>(
+            arcana_grpc::RemoteTenantRepositoryParameters {
+                client: remote_repo.client().clone(),
+            },
+        )
         .build();
 
     Ok(Arc::new(module))
@@ -231,35 +389,143 @@ pub async fn build_repository_module(
     Ok(Arc::new(module))
 }
 
+/// Starts the plugin manager, watching the plugin directory if hot reload
+/// is enabled, and routes the domain events to the plugins' event listeners.
+pub async fn start_plugins<M: EventResolver>(module: &M, config: &PluginConfig) -> ArcanaResult<Arc<PluginManager>> {
+    let plugins = Arc::new(PluginManager::new(config.clone())?);
+    plugins.initialize().await?;
+    plugins.watch();
+
+    if config.enabled {
+        module.event_bus().subscribe_all(Arc::new(PluginEventBridge::new(plugins.clone())));
+    }
+    Ok(plugins)
+}
+
+/// Starts relaying the events of the transactional outbox to the event bus.
+///
+/// Runs on the configured schedule, through the Redis job scheduler when
+/// Redis is enabled. The outbox is monolith-only: in layered deployments the
+/// service layer publishes events itself right after each change.
+pub fn start_outbox_relay(
+    module: &MonolithicModule,
+    redis_config: &RedisConfig,
+    events_config: &EventsConfig,
+) -> ArcanaResult<()> {
+    let outbox: Arc<dyn OutboxRepository> = module.resolve();
+    let relay = OutboxRelay::new(
+        outbox,
+        module.event_bus(),
+        events_config.outbox_batch_size,
+        events_config.outbox_max_attempts,
+    );
+
+    spawn_outbox_relay(
+        Arc::new(relay),
+        create_redis_pool(redis_config)?,
+        &events_config.outbox_relay_schedule,
+    )
+}
+
+/// Starts delivering domain events to the webhook subscriptions.
+///
+/// Deliveries are queued on the Redis job queue and made by a webhook
+/// worker when Redis is enabled, and by background tasks otherwise.
+pub fn start_webhook_delivery(module: &MonolithicModule, redis_config: &RedisConfig) -> ArcanaResult<()> {
+    let repository: Arc<dyn WebhookRepository> = module.resolve();
+    let deliverer = Arc::new(WebhookDeliverer::new(repository.clone()));
+
+    let dispatcher = match create_redis_pool(redis_config)? {
+        Some(pool) => {
+            let jobs = Arc::new(RedisJobQueue::new(pool, JobsConfig::default()));
+            spawn_webhook_worker(jobs.clone(), deliverer);
+            WebhookDispatcher::queued(repository, jobs)
+        }
+        None => WebhookDispatcher::inline(repository, deliverer),
+    };
+
+    module.event_bus().subscribe_all(Arc::new(dispatcher));
+    Ok(())
+}
+
+/// Creates a Redis connection pool if Redis is enabled.
+fn create_redis_pool(redis_config: &RedisConfig) -> ArcanaResult<Option<deadpool_redis::Pool>> {
+    if !redis_config.enabled {
+        return Ok(None);
+    }
+
+    deadpool_redis::Config::from_url(&redis_config.url)
+        .create_pool(Some(deadpool_redis::Runtime::Tokio1))
+        .map(Some)
+        .map_err(|e| arcana_core::ArcanaError::Cache(format!("Failed to create Redis pool: {}", e)))
+}
+
+/// Builds the mail queue parameters.
+///
+/// Messages are delivered by the mailer selected by `mail.transport`. With
+/// Redis, mail is enqueued as jobs and a mail worker is started to deliver
+/// them; without it, mail is delivered inline.
+fn build_mail_queue_parameters(
+    cache_pool: Option<&deadpool_redis::Pool>,
+    mail_config: &MailConfig,
+) -> ArcanaResult<MailQueueParameters> {
+    let mailer = mailer_from_config(mail_config)?;
+
+    let jobs = cache_pool.map(|pool| {
+        let jobs = Arc::new(RedisJobQueue::new(pool.clone(), JobsConfig::default()));
+        spawn_mail_worker(jobs.clone(), mailer.clone());
+        jobs
+    });
+
+    Ok(MailQueueParameters {
+        jobs,
+        mailer,
+        config: mail_config.clone(),
+    })
+}
+
 // ============================================================================
 // Module Resolution Helpers
 // ============================================================================
 
 /// Trait for resolving common services from any module.
+///
+/// The resolved services record every mutating call in the audit trail.
 pub trait ServiceResolver {
     /// Resolves the user service from the module.
     fn user_service(&self) -> Arc<dyn UserService>;
 
     /// Resolves the auth service from the module.
     fn auth_service(&self) -> Arc<dyn AuthService>;
+
+    /// Resolves the audit log query service from the module.
+    fn audit_service(&self) -> Arc<dyn AuditService>;
 }
 
 impl ServiceResolver for MonolithicModule {
     fn user_service(&self) -> Arc<dyn UserService> {
-        self.resolve()
+        Arc::new(AuditedUserService::new(self.resolve(), self.resolve()))
     }
 
     fn auth_service(&self) -> Arc<dyn AuthService> {
+        Arc::new(AuditedAuthService::new(self.resolve(), self.resolve()))
+    }
+
+    fn audit_service(&self) -> Arc<dyn AuditService> {
         self.resolve()
     }
 }
 
 impl ServiceResolver for DistributedServiceModule {
     fn user_service(&self) -> Arc<dyn UserService> {
-        self.resolve()
+        Arc::new(AuditedUserService::new(self.resolve(), self.resolve()))
     }
 
     fn auth_service(&self) -> Arc<dyn AuthService> {
+        Arc::new(AuditedAuthService::new(self.resolve(), self.resolve()))
+    }
+
+    fn audit_service(&self) -> Arc<dyn AuditService> {
         self.resolve()
     }
 }
@@ -286,24 +552,99 @@ impl DatabaseResolver for RepositoryModule {
 pub trait RepositoryResolver {
     /// Resolves the user repository from the module.
     fn user_repository(&self) -> Arc<dyn UserRepository>;
+
+    /// Resolves the OAuth token repository from the module.
+    fn oauth_token_repository(&self) -> Arc<dyn OAuthTokenRepository>;
+
+    /// Resolves the password reset token repository from the module.
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository>;
+
+    /// Resolves the MFA repository from the module.
+    fn mfa_repository(&self) -> Arc<dyn MfaRepository>;
+
+    /// Resolves the audit log repository from the module.
+    fn audit_log_repository(&self) -> Arc<dyn AuditLogRepository>;
+
+    /// Resolves the tenant repository from the module.
+    fn tenant_repository(&self) -> Arc<dyn TenantRepository>;
 }
 
 impl RepositoryResolver for MonolithicModule {
     fn user_repository(&self) -> Arc<dyn UserRepository> {
         self.resolve()
     }
+
+    fn oauth_token_repository(&self) -> Arc<dyn OAuthTokenRepository> {
+        self.resolve()
+    }
+
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository> {
+        self.resolve()
+    }
+
+    fn mfa_repository(&self) -> Arc<dyn MfaRepository> {
+        self.resolve()
+    }
+
+    fn audit_log_repository(&self) -> Arc<dyn AuditLogRepository> {
+        self.resolve()
+    }
+
+    fn tenant_repository(&self) -> Arc<dyn TenantRepository> {
+        self.resolve()
+    }
 }
 
 impl RepositoryResolver for DistributedServiceModule {
     fn user_repository(&self) -> Arc<dyn UserRepository> {
         self.resolve()
     }
+
+    fn oauth_token_repository(&self) -> Arc<dyn OAuthTokenRepository> {
+        self.resolve()
+    }
+
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository> {
+        self.resolve()
+    }
+
+    fn mfa_repository(&self) -> Arc<dyn MfaRepository> {
+        self.resolve()
+    }
+
+    fn audit_log_repository(&self) -> Arc<dyn AuditLogRepository> {
+        self.resolve()
+    }
+
+    fn tenant_repository(&self) -> Arc<dyn TenantRepository> {
+        self.resolve()
+    }
 }
 
 impl RepositoryResolver for RepositoryModule {
     fn user_repository(&self) -> Arc<dyn UserRepository> {
         self.resolve()
     }
+
+    fn oauth_token_repository(&self) -> Arc<dyn OAuthTokenRepository> {
+        self.resolve()
+    }
+
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository> {
+        self.resolve()
+    }
+
+    fn mfa_repository(&self) -> Arc<dyn MfaRepository> {
+        self.resolve()
+    }
+
+    fn audit_log_repository(&self) -> Arc<dyn AuditLogRepository> {
+        self.resolve()
+    }
+
+    fn tenant_repository(&self) -> Arc<dyn TenantRepository> {
+        self.resolve()
+    }
 }
 
 /// Trait for resolving cache components.
@@ -324,6 +665,24 @@ impl CacheResolver for DistributedServiceModule {
     }
 }
 
+/// Trait for resolving the domain event bus.
+pub trait EventResolver {
+    /// Resolves the event bus the services publish on.
+    fn event_bus(&self) -> Arc<dyn EventBusInterface>;
+}
+
+impl EventResolver for MonolithicModule {
+    fn event_bus(&self) -> Arc<dyn EventBusInterface> {
+        self.resolve()
+    }
+}
+
+impl EventResolver for DistributedServiceModule {
+    fn event_bus(&self) -> Arc<dyn EventBusInterface> {
+        self.resolve()
+    }
+}
+
 /// Trait for resolving security components.
 pub trait SecurityResolver {
     /// Resolves the password hasher from the module.
@@ -332,6 +691,15 @@ pub trait SecurityResolver {
     /// Resolves the token provider from the module.
     fn token_provider(&self) -> Arc<dyn TokenProviderInterface>;
 
+    /// Resolves the access-token revocation list from the module.
+    fn token_revocation(&self) -> Arc<dyn TokenRevocationInterface>;
+
+    /// Resolves the failed-login counters from the module.
+    fn login_attempts(&self) -> Arc<dyn LoginAttemptInterface>;
+
+    /// Resolves the effective-permission resolver from the module.
+    fn permission_resolver(&self) -> Arc<dyn PermissionResolverInterface>;
+
     /// Resolves the security config from the module.
     fn security_config(&self) -> Arc<dyn SecurityConfigInterface>;
 }
@@ -345,6 +713,18 @@ impl SecurityResolver for MonolithicModule {
         self.resolve()
     }
 
+    fn token_revocation(&self) -> Arc<dyn TokenRevocationInterface> {
+        self.resolve()
+    }
+
+    fn login_attempts(&self) -> Arc<dyn LoginAttemptInterface> {
+        self.resolve()
+    }
+
+    fn permission_resolver(&self) -> Arc<dyn PermissionResolverInterface> {
+        self.resolve()
+    }
+
     fn security_config(&self) -> Arc<dyn SecurityConfigInterface> {
         self.resolve()
     }
@@ -359,6 +739,18 @@ impl SecurityResolver for DistributedServiceModule {
         self.resolve()
     }
 
+    fn token_revocation(&self) -> Arc<dyn TokenRevocationInterface> {
+        self.resolve()
+    }
+
+    fn login_attempts(&self) -> Arc<dyn LoginAttemptInterface> {
+        self.resolve()
+    }
+
+    fn permission_resolver(&self) -> Arc<dyn PermissionResolverInterface> {
+        self.resolve()
+    }
+
     fn security_config(&self) -> Arc<dyn SecurityConfigInterface> {
         self.resolve()
     }
@@ -375,7 +767,10 @@ pub type AppModule = MonolithicModule;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arcana_config::MailTransport;
     use arcana_core::UserRole;
+    use arcana_repository::RoleRepository;
+    use arcana_service::{AuditTrailInterface, RoleService, TenantService, WebhookService};
 
     // =========================================================================
     // Compile-Time Trait Verification Tests
@@ -389,6 +784,7 @@ mod tests {
         fn _assert_repository_resolver<T: RepositoryResolver>() {}
         fn _assert_security_resolver<T: SecurityResolver>() {}
         fn _assert_cache_resolver<T: CacheResolver>() {}
+        fn _assert_event_resolver<T: EventResolver>() {}
 
         _assert_service_resolver::<MonolithicModule>();
         _assert_service_resolver::<DistributedServiceModule>();
@@ -401,6 +797,8 @@ mod tests {
         _assert_security_resolver::<DistributedServiceModule>();
         _assert_cache_resolver::<MonolithicModule>();
         _assert_cache_resolver::<DistributedServiceModule>();
+        _assert_event_resolver::<MonolithicModule>();
+        _assert_event_resolver::<DistributedServiceModule>();
     }
 
     #[test]
@@ -409,33 +807,83 @@ mod tests {
         fn _assert_has_user_service<T: HasComponent<dyn UserService>>() {}
         fn _assert_has_auth_service<T: HasComponent<dyn AuthService>>() {}
         fn _assert_has_user_repository<T: HasComponent<dyn UserRepository>>() {}
+        fn _assert_has_oauth_token_repository<T: HasComponent<dyn OAuthTokenRepository>>() {}
+        fn _assert_has_password_reset_token_repository<T: HasComponent<dyn PasswordResetTokenRepository>>() {}
+        fn _assert_has_mfa_repository<T: HasComponent<dyn MfaRepository>>() {}
+        fn _assert_has_audit_log_repository<T: HasComponent<dyn AuditLogRepository>>() {}
+        fn _assert_has_outbox_repository<T: HasComponent<dyn OutboxRepository>>() {}
+        fn _assert_has_webhook_repository<T: HasComponent<dyn WebhookRepository>>() {}
+        fn _assert_has_webhook_service<T: HasComponent<dyn WebhookService>>() {}
+        fn _assert_has_role_repository<T: HasComponent<dyn RoleRepository>>() {}
+        fn _assert_has_role_service<T: HasComponent<dyn RoleService>>() {}
+        fn _assert_has_tenant_repository<T: HasComponent<dyn TenantRepository>>() {}
+        fn _assert_has_tenant_service<T: HasComponent<dyn TenantService>>() {}
+        fn _assert_has_permission_resolver<T: HasComponent<dyn PermissionResolverInterface>>() {}
+        fn _assert_has_audit_trail<T: HasComponent<dyn AuditTrailInterface>>() {}
+        fn _assert_has_audit_service<T: HasComponent<dyn AuditService>>() {}
         fn _assert_has_password_hasher<T: HasComponent<dyn PasswordHasherInterface>>() {}
         fn _assert_has_token_provider<T: HasComponent<dyn TokenProviderInterface>>() {}
+        fn _assert_has_token_revocation<T: HasComponent<dyn TokenRevocationInterface>>() {}
+        fn _assert_has_login_attempts<T: HasComponent<dyn LoginAttemptInterface>>() {}
         fn _assert_has_security_config<T: HasComponent<dyn SecurityConfigInterface>>() {}
         fn _assert_has_database_pool<T: HasComponent<dyn DatabasePoolInterface>>() {}
         fn _assert_has_cache<T: HasComponent<dyn CacheInterface>>() {}
+        fn _assert_has_event_bus<T: HasComponent<dyn EventBusInterface>>() {}
 
         // MonolithicModule should have all components
         _assert_has_user_service::<MonolithicModule>();
         _assert_has_auth_service::<MonolithicModule>();
         _assert_has_user_repository::<MonolithicModule>();
+        _assert_has_oauth_token_repository::<MonolithicModule>();
+        _assert_has_password_reset_token_repository::<MonolithicModule>();
+        _assert_has_mfa_repository::<MonolithicModule>();
+        _assert_has_audit_log_repository::<MonolithicModule>();
+        _assert_has_outbox_repository::<MonolithicModule>();
+        _assert_has_webhook_repository::<MonolithicModule>();
+        _assert_has_webhook_service::<MonolithicModule>();
+        _assert_has_role_repository::<MonolithicModule>();
+        _assert_has_role_service::<MonolithicModule>();
+        _assert_has_tenant_repository::<MonolithicModule>();
+        _assert_has_tenant_service::<MonolithicModule>();
+        _assert_has_permission_resolver::<MonolithicModule>();
+        _assert_has_audit_trail::<MonolithicModule>();
+        _assert_has_audit_service::<MonolithicModule>();
         _assert_has_password_hasher::<MonolithicModule>();
         _assert_has_token_provider::<MonolithicModule>();
+        _assert_has_token_revocation::<MonolithicModule>();
+        _assert_has_login_attempts::<MonolithicModule>();
         _assert_has_security_config::<MonolithicModule>();
         _assert_has_database_pool::<MonolithicModule>();
         _assert_has_cache::<MonolithicModule>();
+        _assert_has_event_bus::<MonolithicModule>();
 
         // DistributedServiceModule should have service, security, and cache components
         _assert_has_user_service::<DistributedServiceModule>();
         _assert_has_auth_service::<DistributedServiceModule>();
         _assert_has_user_repository::<DistributedServiceModule>();
+        _assert_has_oauth_token_repository::<DistributedServiceModule>();
+        _assert_has_password_reset_token_repository::<DistributedServiceModule>();
+        _assert_has_mfa_repository::<DistributedServiceModule>();
+        _assert_has_audit_log_repository::<DistributedServiceModule>();
+        _assert_has_tenant_repository::<DistributedServiceModule>();
+        _assert_has_audit_trail::<DistributedServiceModule>();
+        _assert_has_audit_service::<DistributedServiceModule>();
         _assert_has_password_hasher::<DistributedServiceModule>();
         _assert_has_token_provider::<DistributedServiceModule>();
+        _assert_has_token_revocation::<DistributedServiceModule>();
+        _assert_has_login_attempts::<DistributedServiceModule>();
         _assert_has_security_config::<DistributedServiceModule>();
         _assert_has_cache::<DistributedServiceModule>();
+        _assert_has_event_bus::<DistributedServiceModule>();
+        _assert_has_permission_resolver::<DistributedServiceModule>();
 
         // RepositoryModule should have database and repository components
         _assert_has_user_repository::<RepositoryModule>();
+        _assert_has_oauth_token_repository::<RepositoryModule>();
+        _assert_has_password_reset_token_repository::<RepositoryModule>();
+        _assert_has_mfa_repository::<RepositoryModule>();
+        _assert_has_audit_log_repository::<RepositoryModule>();
+        _assert_has_tenant_repository::<RepositoryModule>();
         _assert_has_database_pool::<RepositoryModule>();
     }
 
@@ -454,6 +902,17 @@ mod tests {
             tls_cert_path: None,
             tls_key_path: None,
             password_hash_cost: 4,
+            jwt_algorithm: arcana_config::JwtAlgorithm::HS256,
+            jwt_private_key_path: None,
+            jwt_public_key_path: None,
+            jwt_keys: Vec::new(),
+            jwt_signing_kid: None,
+            email_verification_expiration_secs: 86400,
+            password_reset_expiration_secs: 1800,
+            mfa_issuer: "Arcana Cloud".to_string(),
+            mfa_challenge_expiration_secs: 300,
+            permission_cache_ttl_secs: 60,
+            lockout: arcana_config::LockoutConfig::default(),
         }
     }
 
@@ -632,6 +1091,17 @@ mod tests {
             tls_cert_path: config.tls_cert_path.clone(),
             tls_key_path: config.tls_key_path.clone(),
             password_hash_cost: config.password_hash_cost,
+            jwt_algorithm: config.jwt_algorithm,
+            jwt_private_key_path: config.jwt_private_key_path.clone(),
+            jwt_public_key_path: config.jwt_public_key_path.clone(),
+            jwt_keys: config.jwt_keys.clone(),
+            jwt_signing_kid: config.jwt_signing_kid.clone(),
+            email_verification_expiration_secs: config.email_verification_expiration_secs,
+            password_reset_expiration_secs: config.password_reset_expiration_secs,
+            mfa_issuer: config.mfa_issuer.clone(),
+            mfa_challenge_expiration_secs: config.mfa_challenge_expiration_secs,
+            permission_cache_ttl_secs: config.permission_cache_ttl_secs,
+            lockout: config.lockout.clone(),
         };
 
         assert_eq!(params.jwt_secret, config.jwt_secret);
@@ -658,15 +1128,37 @@ mod tests {
 
         // Verify parameters can be created
         let params = arcana_security::TokenProviderParameters {
-            encoding_key: provider.encoding_key().clone(),
-            decoding_key: provider.decoding_key().clone(),
+            keys: provider.keys().clone(),
             config: config.clone(),
-            validation: provider.validation().clone(),
         };
 
         // Params should contain valid keys
-        let _ = params.encoding_key;
-        let _ = params.decoding_key;
+        assert!(params.keys.current().signing_key().is_some());
+    }
+
+    #[test]
+    fn test_mail_queue_parameters_without_redis() {
+        let mail_config = MailConfig {
+            from_address: "mailer@example.com".to_string(),
+            ..MailConfig::default()
+        };
+
+        let params = build_mail_queue_parameters(None, &mail_config).unwrap();
+
+        // Without Redis, mail is delivered inline
+        assert!(params.jobs.is_none());
+        assert_eq!(params.config.from_address, "mailer@example.com");
+    }
+
+    #[test]
+    fn test_mail_queue_parameters_reject_invalid_smtp_sender() {
+        let mail_config = MailConfig {
+            transport: MailTransport::Smtp,
+            from_address: "not an address".to_string(),
+            ..MailConfig::default()
+        };
+
+        assert!(build_mail_queue_parameters(None, &mail_config).is_err());
     }
 
     // =========================================================================
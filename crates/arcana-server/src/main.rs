@@ -10,12 +10,13 @@
 use arcana_config::{AppConfig, ConfigLoader, DeploymentLayer, DeploymentMode};
 use arcana_core::ArcanaResult;
 use arcana_rest::create_router;
+use std::net::SocketAddr;
 use tokio::signal;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use arcana_server::di::{
-    build_distributed_service_module, build_monolithic_module, build_repository_module,
-    DatabaseResolver, RepositoryResolver, ServiceResolver,
+    build_distributed_service_module, build_monolithic_module, build_repository_module, start_outbox_relay,
+    start_plugins, start_webhook_delivery, DatabaseResolver, RepositoryResolver, SecurityResolver, ServiceResolver,
 };
 
 #[tokio::main]
@@ -132,17 +133,30 @@ async fn run_layered_http(config: AppConfig) -> ArcanaResult<()> {
 
 async fn run_monolithic(config: AppConfig) -> ArcanaResult<()> {
     // Build Shaku DI module with all components
-    let module = build_monolithic_module(&config.database, &config.redis, config.security.clone()).await?;
+    let module = build_monolithic_module(&config.database, &config.redis, config.security.clone(), &config.mail).await?;
 
     // Run migrations using the resolved database pool
     module.database_pool().run_migrations().await?;
 
+    // Route domain events to the plugins' event listeners
+    start_plugins(module.as_ref(), &config.plugins).await?;
+
+    // Post domain events to the webhook subscriptions
+    start_webhook_delivery(&module, &config.redis)?;
+
+    // Deliver the events stored with user changes
+    start_outbox_relay(&module, &config.redis, &config.events)?;
+
     // Create REST router from module
     let router = create_router(module.as_ref(), &config.server);
 
     // Resolve services for gRPC server
     let user_service = module.user_service();
     let auth_service = module.auth_service();
+    let audit_service = module.audit_service();
+    let token_provider = module.token_provider();
+    let token_revocation = module.token_revocation();
+    spawn_jwt_key_reload(token_provider.clone());
 
     // Start REST server
     let rest_addr = config.server.rest_addr();
@@ -153,11 +167,19 @@ async fn run_monolithic(config: AppConfig) -> ArcanaResult<()> {
         .map_err(|e| arcana_core::ArcanaError::Internal(format!("Failed to bind REST: {}", e)))?;
 
     // Create gRPC server
-    let grpc_server = arcana_grpc::GrpcServer::new(&config.server, user_service, auth_service)?;
+    let grpc_server = arcana_grpc::GrpcServer::new(
+        &config.server,
+        user_service,
+        auth_service,
+        audit_service,
+        token_provider,
+        token_revocation,
+    )?
+    .with_permission_resolver(module.permission_resolver());
 
     // Run both servers concurrently
     tokio::select! {
-        result = axum::serve(listener, router).with_graceful_shutdown(shutdown_signal()) => {
+        result = axum::serve(listener, router.into_make_service_with_connect_info::<SocketAddr>()).with_graceful_shutdown(shutdown_signal()) => {
             result.map_err(|e| arcana_core::ArcanaError::Internal(format!("REST server error: {}", e)))?;
         }
         result = grpc_server.serve() => {
@@ -184,19 +206,25 @@ async fn run_controller_layer_grpc(config: AppConfig) -> ArcanaResult<()> {
     // Create remote service clients via gRPC
     let user_service = arcana_grpc::create_remote_user_service(service_url).await?;
     let auth_service = arcana_grpc::create_remote_auth_service(service_url).await?;
+    let audit_service = arcana_grpc::create_remote_audit_service(service_url).await?;
 
-    // Create token provider for JWT validation (still local)
-    let token_provider = arcana_security::TokenProvider::new(std::sync::Arc::new(config.security.clone()));
+    // Create token provider for JWT validation (still local; a public key suffices)
+    let token_provider =
+        arcana_security::TokenProvider::from_config(std::sync::Arc::new(config.security.clone()))?;
     let token_provider: std::sync::Arc<dyn arcana_security::TokenProviderInterface> =
         std::sync::Arc::new(token_provider);
+    spawn_jwt_key_reload(token_provider.clone());
+
+    // Revocations are written by the service layer; sharing its Redis makes them visible here
+    let token_revocation = create_token_revocation(&config)?;
 
     // Create application state for REST
-    let app_state = arcana_rest::AppState::new(user_service, auth_service);
+    let app_state = arcana_rest::AppState::new(user_service, auth_service, audit_service);
 
     // Create REST router with state and token provider
     // Note: For controller layer, we use the legacy AppState approach
     // since we're using remote services rather than a Shaku module
-    let router = create_router_legacy(app_state, token_provider, &config.server);
+    let router = create_router_legacy(app_state, token_provider, token_revocation, &config.server);
 
     // Start REST server only (controller doesn't expose gRPC)
     let rest_addr = config.server.rest_addr();
@@ -206,7 +234,7 @@ async fn run_controller_layer_grpc(config: AppConfig) -> ArcanaResult<()> {
         .await
         .map_err(|e| arcana_core::ArcanaError::Internal(format!("Failed to bind REST: {}", e)))?;
 
-    axum::serve(listener, router)
+    axum::serve(listener, router.into_make_service_with_connect_info::<SocketAddr>())
         .with_graceful_shutdown(shutdown_signal())
         .await
         .map_err(|e| arcana_core::ArcanaError::Internal(format!("REST server error: {}", e)))?;
@@ -238,15 +266,28 @@ async fn run_service_layer_grpc(config: AppConfig) -> ArcanaResult<()> {
     info!("Connecting to repository layer at: {}", repository_url);
 
     // Build Shaku distributed service module
-    let module = build_distributed_service_module(repository_url, &config.redis, config.security.clone()).await?;
+    let module = build_distributed_service_module(repository_url, &config.redis, config.security.clone(), &config.mail).await?;
+
+    // Route domain events to the plugins' event listeners
+    start_plugins(module.as_ref(), &config.plugins).await?;
 
     // Resolve services from module
     let user_service = module.user_service();
     let auth_service = module.auth_service();
+    let audit_service = module.audit_service();
+
+    spawn_jwt_key_reload(module.token_provider());
 
     // Create gRPC server to expose services
-    let grpc_server =
-        arcana_grpc::GrpcServer::new(&config.server, user_service.clone(), auth_service.clone())?;
+    let grpc_server = arcana_grpc::GrpcServer::new(
+        &config.server,
+        user_service,
+        auth_service,
+        audit_service,
+        module.token_provider(),
+        module.token_revocation(),
+    )?
+    .with_permission_resolver(module.permission_resolver());
 
     info!(
         "Starting gRPC server on {}",
@@ -281,9 +322,23 @@ async fn run_repository_layer(config: AppConfig) -> ArcanaResult<()> {
 
     // Resolve repository from module
     let user_repository = RepositoryResolver::user_repository(module.as_ref());
+    let oauth_token_repository = RepositoryResolver::oauth_token_repository(module.as_ref());
+    let password_reset_token_repository =
+        RepositoryResolver::password_reset_token_repository(module.as_ref());
+    let mfa_repository = RepositoryResolver::mfa_repository(module.as_ref());
+    let audit_log_repository = RepositoryResolver::audit_log_repository(module.as_ref());
+    let tenant_repository = RepositoryResolver::tenant_repository(module.as_ref());
 
     // Create gRPC server to expose repository
-    let grpc_server = arcana_grpc::RepositoryGrpcServer::new(&config.server, user_repository)?;
+    let grpc_server = arcana_grpc::RepositoryGrpcServer::new(
+        &config.server,
+        user_repository,
+        oauth_token_repository,
+        password_reset_token_repository,
+        mfa_repository,
+        audit_log_repository,
+        tenant_repository,
+    )?;
 
     info!(
         "Starting Repository gRPC server on {}",
@@ -296,13 +351,36 @@ async fn run_repository_layer(config: AppConfig) -> ArcanaResult<()> {
     Ok(())
 }
 
+/// Creates the access-token revocation list for the controller layer.
+fn create_token_revocation(
+    config: &AppConfig,
+) -> ArcanaResult<std::sync::Arc<dyn arcana_security::TokenRevocationInterface>> {
+    let ttl = std::time::Duration::from_secs(config.security.jwt_access_expiration_secs);
+
+    if !config.redis.enabled {
+        return Ok(std::sync::Arc::new(arcana_security::TokenRevocationStore::in_memory(ttl)));
+    }
+
+    let pool = deadpool_redis::Config::from_url(&config.redis.url)
+        .create_pool(Some(deadpool_redis::Runtime::Tokio1))
+        .map_err(|e| arcana_core::ArcanaError::Cache(format!("Failed to create Redis pool: {}", e)))?;
+
+    Ok(std::sync::Arc::new(arcana_security::TokenRevocationStore::new(
+        std::sync::Arc::new(pool),
+        ttl,
+    )))
+}
+
 /// Creates a REST router using legacy AppState (for controller layer with remote services).
 fn create_router_legacy(
     state: arcana_rest::AppState,
     token_provider: std::sync::Arc<dyn arcana_security::TokenProviderInterface>,
+    token_revocation: std::sync::Arc<dyn arcana_security::TokenRevocationInterface>,
     server_config: &arcana_config::ServerConfig,
 ) -> axum::Router {
-    use arcana_rest::middleware::{auth_middleware, logging_middleware, AuthMiddlewareState};
+    use arcana_rest::middleware::{
+        auth_middleware, logging_middleware, request_context_middleware, AuthMiddlewareState,
+    };
     use axum::{middleware, routing::get, Router};
     use tower_http::{
         compression::CompressionLayer,
@@ -325,18 +403,24 @@ fn create_router_legacy(
     };
 
     // Create auth middleware state
-    let auth_state = AuthMiddlewareState::new(token_provider);
+    let auth_state = AuthMiddlewareState::new(token_provider.clone(), token_revocation);
 
     // Build the API router with authentication
     let api_router = Router::new()
         .nest("/auth", arcana_rest::controllers::auth_controller::router())
         .nest("/users", arcana_rest::controllers::user_controller::router())
+        .nest("/audit", arcana_rest::controllers::audit_controller::router())
+        // Webhooks are delivered only in monolithic mode; here every request is refused
+        .nest("/webhooks", arcana_rest::controllers::webhook_controller::router())
+        .layer(middleware::from_fn(request_context_middleware))
         .layer(middleware::from_fn_with_state(auth_state.clone(), auth_middleware))
         .with_state(state.clone());
 
     Router::new()
         // Health endpoints (no auth required)
         .merge(arcana_rest::controllers::health_controller::router())
+        // Token verification keys (no auth required)
+        .merge(arcana_rest::controllers::jwks_controller::router(token_provider))
         // API v1
         .nest("/api/v1", api_router)
         // Root endpoint
@@ -348,6 +432,35 @@ fn create_router_legacy(
         .layer(middleware::from_fn(logging_middleware))
 }
 
+/// Reloads the JWT keys from configuration on SIGHUP, so signing keys can be
+/// rotated without a restart. A configuration that fails to load keeps the current keys.
+fn spawn_jwt_key_reload(token_provider: std::sync::Arc<dyn arcana_security::TokenProviderInterface>) {
+    #[cfg(unix)]
+    tokio::spawn(async move {
+        let mut hangup = match signal::unix::signal(signal::unix::SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(e) => {
+                warn!("Failed to install SIGHUP handler, JWT keys cannot be reloaded: {}", e);
+                return;
+            }
+        };
+
+        while hangup.recv().await.is_some() {
+            info!("Received SIGHUP, reloading JWT keys...");
+            let result = match load_config().await {
+                Ok(config) => token_provider.reload_keys(&config.security),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                warn!("Failed to reload JWT keys, keeping the current ones: {}", e);
+            }
+        }
+    });
+
+    #[cfg(not(unix))]
+    let _ = token_provider;
+}
+
 async fn shutdown_signal() {
     let ctrl_c = async {
         signal::ctrl_c()
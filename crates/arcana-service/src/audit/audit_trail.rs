@@ -0,0 +1,193 @@
+//! Writing audit log entries.
+
+use super::RequestContext;
+use arcana_core::{ArcanaResult, AuditAction, AuditLog, Interface, UserId};
+use arcana_repository::AuditLogRepository;
+use async_trait::async_trait;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::error;
+
+/// Interface for recording audit log entries.
+#[async_trait]
+pub trait AuditTrailInterface: Interface + Send + Sync {
+    /// Records an entry on behalf of the current request.
+    ///
+    /// Fills in the actor, IP address and user agent from the
+    /// [`RequestContext`] where the entry leaves them empty. Never fails:
+    /// the audited operation has already happened, so a lost entry is
+    /// logged instead of being reported to the caller.
+    async fn record(&self, entry: AuditLog);
+}
+
+/// Audit trail that appends entries to the audit log repository.
+#[derive(Component)]
+#[shaku(interface = AuditTrailInterface)]
+pub struct AuditTrail {
+    #[shaku(inject)]
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl AuditTrail {
+    /// Creates an audit trail writing to the given repository.
+    #[must_use]
+    pub fn new(audit_log_repository: Arc<dyn AuditLogRepository>) -> Self {
+        Self { audit_log_repository }
+    }
+}
+
+#[async_trait]
+impl AuditTrailInterface for AuditTrail {
+    async fn record(&self, mut entry: AuditLog) {
+        let context = RequestContext::current();
+        entry.user_id = entry.user_id.or(context.actor);
+        entry.ip_address = entry.ip_address.or(context.ip_address);
+        entry.user_agent = entry.user_agent.or(context.user_agent);
+
+        if let Err(e) = self.audit_log_repository.save(&entry).await {
+            error!(
+                target: "arcana::audit",
+                action = %entry.action,
+                resource_type = %entry.resource_type,
+                resource_id = ?entry.resource_id,
+                "Failed to record audit log entry: {}",
+                e
+            );
+        }
+    }
+}
+
+impl std::fmt::Debug for AuditTrail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditTrail").finish_non_exhaustive()
+    }
+}
+
+/// Builds the entry for the outcome of an operation.
+pub(crate) fn outcome_entry<T>(
+    result: &ArcanaResult<T>,
+    user_id: Option<UserId>,
+    action: AuditAction,
+    resource_type: &str,
+    resource_id: Option<String>,
+) -> AuditLog {
+    match result {
+        Ok(_) => AuditLog::success(user_id, action, resource_type, resource_id),
+        Err(e) => AuditLog::failure(user_id, action, resource_type, resource_id, e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{ArcanaError, AuditLogCursor, AuditLogFilter, AuditLogId, Page, PageRequest};
+    use chrono::{DateTime, Utc};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct InMemoryAuditLogRepository {
+        entries: Mutex<Vec<AuditLog>>,
+        fail: bool,
+    }
+
+    impl std::fmt::Debug for InMemoryAuditLogRepository {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("InMemoryAuditLogRepository").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl AuditLogRepository for InMemoryAuditLogRepository {
+        async fn save(&self, entry: &AuditLog) -> ArcanaResult<AuditLog> {
+            if self.fail {
+                return Err(ArcanaError::Database("unavailable".to_string()));
+            }
+            self.entries.lock().unwrap().push(entry.clone());
+            Ok(entry.clone())
+        }
+
+        async fn find_by_id(&self, id: AuditLogId) -> ArcanaResult<Option<AuditLog>> {
+            Ok(self.entries.lock().unwrap().iter().find(|e| e.id == id).cloned())
+        }
+
+        async fn find_by_user(&self, _user_id: UserId, page: PageRequest) -> ArcanaResult<Page<AuditLog>> {
+            Ok(Page::empty(page.page, page.size))
+        }
+
+        async fn search(
+            &self,
+            _filter: &AuditLogFilter,
+            _after: Option<AuditLogCursor>,
+            _limit: usize,
+        ) -> ArcanaResult<Vec<AuditLog>> {
+            Ok(Vec::new())
+        }
+
+        async fn chain_bounds(
+            &self,
+            _from: Option<DateTime<Utc>>,
+            _to: Option<DateTime<Utc>>,
+        ) -> ArcanaResult<Option<(u64, u64)>> {
+            Ok(None)
+        }
+
+        async fn find_chain(&self, _from_sequence: u64, _to_sequence: u64, _limit: usize) -> ArcanaResult<Vec<AuditLog>> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_fills_in_request_context() {
+        let repository = Arc::new(InMemoryAuditLogRepository::default());
+        let trail = AuditTrail::new(repository.clone());
+        let actor = UserId::new();
+        let context = RequestContext::new(Some("10.0.0.1".to_string()), Some("curl/8.0".to_string()))
+            .with_actor(Some(actor));
+
+        context
+            .scope(trail.record(AuditLog::success(None, AuditAction::UserDelete, "user", None)))
+            .await;
+
+        let entries = repository.entries.lock().unwrap();
+        assert_eq!(entries[0].user_id, Some(actor));
+        assert_eq!(entries[0].ip_address.as_deref(), Some("10.0.0.1"));
+        assert_eq!(entries[0].user_agent.as_deref(), Some("curl/8.0"));
+    }
+
+    #[tokio::test]
+    async fn test_record_keeps_explicit_user() {
+        let repository = Arc::new(InMemoryAuditLogRepository::default());
+        let trail = AuditTrail::new(repository.clone());
+        let user_id = UserId::new();
+
+        RequestContext::default()
+            .with_actor(Some(UserId::new()))
+            .scope(trail.record(AuditLog::success(Some(user_id), AuditAction::Login, "user", None)))
+            .await;
+
+        assert_eq!(repository.entries.lock().unwrap()[0].user_id, Some(user_id));
+    }
+
+    #[tokio::test]
+    async fn test_record_swallows_repository_errors() {
+        let repository = Arc::new(InMemoryAuditLogRepository {
+            fail: true,
+            ..Default::default()
+        });
+        let trail = AuditTrail::new(repository.clone());
+
+        trail.record(AuditLog::success(None, AuditAction::Logout, "user", None)).await;
+        assert!(repository.entries.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_outcome_entry() {
+        let ok: ArcanaResult<()> = Ok(());
+        assert!(outcome_entry(&ok, None, AuditAction::UserCreate, "user", None).success);
+
+        let err: ArcanaResult<()> = Err(ArcanaError::Forbidden("nope".to_string()));
+        let entry = outcome_entry(&err, None, AuditAction::UserCreate, "user", None);
+        assert!(!entry.success);
+        assert!(entry.error_message.unwrap().contains("nope"));
+    }
+}
@@ -0,0 +1,492 @@
+//! Audit decorator for the authentication service.
+
+use super::audit_trail::outcome_entry;
+use super::{AuditTrailInterface, SESSION_RESOURCE, USER_RESOURCE};
+use crate::dto::{
+    AuthResponse, AuthUserInfo, LoginRequest, LoginResponse, MessageResponse, MfaLoginRequest,
+    PasswordResetConfirmRequest, PasswordResetRequest, RecoveryCodesResponse, RefreshTokenRequest,
+    RegisterRequest, ResendVerificationRequest, SessionInfo, TotpCodeRequest,
+    TotpEnrollmentResponse, VerifyEmailRequest,
+};
+use crate::AuthService;
+use arcana_core::{ArcanaResult, AuditAction, AuditLog};
+use arcana_security::Claims;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// [`AuthService`] that records sign-ins, sign-outs and every account change
+/// in the audit trail.
+///
+/// A password login that still needs a second factor is not recorded; the
+/// login is recorded once [`AuthService::complete_mfa_login`] finishes it.
+/// Reads and token validation pass straight through to the wrapped service.
+pub struct AuditedAuthService {
+    inner: Arc<dyn AuthService>,
+    audit_trail: Arc<dyn AuditTrailInterface>,
+}
+
+impl AuditedAuthService {
+    /// Wraps an authentication service.
+    #[must_use]
+    pub fn new(inner: Arc<dyn AuthService>, audit_trail: Arc<dyn AuditTrailInterface>) -> Self {
+        Self { inner, audit_trail }
+    }
+
+    /// Records the outcome of an operation the signed-in user performed on their own account.
+    async fn record_own(
+        &self,
+        result: &ArcanaResult<impl Send + Sync>,
+        claims: &Claims,
+        action: AuditAction,
+        details: Option<serde_json::Value>,
+    ) {
+        let user_id = claims.user_id();
+        let mut entry = outcome_entry(result, user_id, action, USER_RESOURCE, user_id.map(|id| id.to_string()));
+        entry.details = details;
+        self.audit_trail.record(entry).await;
+    }
+}
+
+/// Builds the entry for a sign-in that issued (or failed to issue) tokens.
+fn sign_in_entry(result: &ArcanaResult<AuthResponse>, action: AuditAction) -> AuditLog {
+    let user_id = result.as_ref().ok().map(|response| response.user.id);
+    outcome_entry(result, user_id, action, USER_RESOURCE, user_id.map(|id| id.to_string()))
+}
+
+#[async_trait]
+impl AuthService for AuditedAuthService {
+    async fn register(&self, request: RegisterRequest) -> ArcanaResult<AuthResponse> {
+        let details = json!({ "username": request.username, "email": request.email, "self_registration": true });
+        let result = self.inner.register(request).await;
+
+        let entry = sign_in_entry(&result, AuditAction::UserCreate);
+        self.audit_trail.record(entry.with_details(details)).await;
+        result
+    }
+
+    async fn login(&self, request: LoginRequest) -> ArcanaResult<LoginResponse> {
+        let details = json!({ "username_or_email": request.username_or_email, "device_id": request.device_id });
+        let ip_address = request.ip_address.clone();
+        let result = self.inner.login(request).await;
+
+        let mut entry = match &result {
+            Ok(LoginResponse::Authenticated(response)) => {
+                let user_id = response.user.id;
+                AuditLog::success(Some(user_id), AuditAction::Login, USER_RESOURCE, Some(user_id.to_string()))
+            }
+            Ok(LoginResponse::MfaRequired(_)) => return result,
+            Err(e) => AuditLog::failure(None, AuditAction::Login, USER_RESOURCE, None, e.to_string()),
+        };
+        entry.ip_address = ip_address;
+        self.audit_trail.record(entry.with_details(details)).await;
+        result
+    }
+
+    async fn complete_mfa_login(&self, request: MfaLoginRequest) -> ArcanaResult<AuthResponse> {
+        let result = self.inner.complete_mfa_login(request).await;
+
+        let entry = sign_in_entry(&result, AuditAction::Login);
+        self.audit_trail.record(entry.with_details(json!({ "mfa": true }))).await;
+        result
+    }
+
+    async fn refresh_token(&self, request: RefreshTokenRequest) -> ArcanaResult<AuthResponse> {
+        let result = self.inner.refresh_token(request).await;
+
+        self.audit_trail
+            .record(sign_in_entry(&result, AuditAction::TokenRefresh))
+            .await;
+        result
+    }
+
+    async fn validate_token(&self, token: &str) -> ArcanaResult<Claims> {
+        self.inner.validate_token(token).await
+    }
+
+    async fn logout(&self, claims: &Claims) -> ArcanaResult<MessageResponse> {
+        let result = self.inner.logout(claims).await;
+        self.record_own(&result, claims, AuditAction::Logout, None).await;
+        result
+    }
+
+    async fn get_current_user(&self, claims: &Claims) -> ArcanaResult<AuthUserInfo> {
+        self.inner.get_current_user(claims).await
+    }
+
+    async fn list_sessions(&self, claims: &Claims) -> ArcanaResult<Vec<SessionInfo>> {
+        self.inner.list_sessions(claims).await
+    }
+
+    async fn revoke_session(&self, claims: &Claims, session_id: &str) -> ArcanaResult<MessageResponse> {
+        let result = self.inner.revoke_session(claims, session_id).await;
+
+        let entry = outcome_entry(
+            &result,
+            claims.user_id(),
+            AuditAction::Logout,
+            SESSION_RESOURCE,
+            Some(session_id.to_string()),
+        );
+        self.audit_trail.record(entry).await;
+        result
+    }
+
+    async fn revoke_other_sessions(&self, claims: &Claims) -> ArcanaResult<MessageResponse> {
+        let result = self.inner.revoke_other_sessions(claims).await;
+        self.record_own(&result, claims, AuditAction::Logout, Some(json!({ "other_sessions": true })))
+            .await;
+        result
+    }
+
+    async fn verify_email(&self, request: VerifyEmailRequest) -> ArcanaResult<MessageResponse> {
+        let result = self.inner.verify_email(request).await;
+
+        let entry = outcome_entry(&result, None, AuditAction::UserUpdate, USER_RESOURCE, None);
+        self.audit_trail.record(entry.with_details(json!({ "email_verified": true }))).await;
+        result
+    }
+
+    async fn resend_verification(
+        &self,
+        request: ResendVerificationRequest,
+    ) -> ArcanaResult<MessageResponse> {
+        self.inner.resend_verification(request).await
+    }
+
+    async fn forgot_password(&self, request: PasswordResetRequest) -> ArcanaResult<MessageResponse> {
+        let details = json!({ "email": request.email });
+        let result = self.inner.forgot_password(request).await;
+
+        let entry = outcome_entry(&result, None, AuditAction::PasswordResetRequest, USER_RESOURCE, None);
+        self.audit_trail.record(entry.with_details(details)).await;
+        result
+    }
+
+    async fn reset_password(
+        &self,
+        request: PasswordResetConfirmRequest,
+    ) -> ArcanaResult<MessageResponse> {
+        let result = self.inner.reset_password(request).await;
+
+        let entry = outcome_entry(&result, None, AuditAction::PasswordResetComplete, USER_RESOURCE, None);
+        self.audit_trail.record(entry).await;
+        result
+    }
+
+    async fn enroll_totp(&self, claims: &Claims) -> ArcanaResult<TotpEnrollmentResponse> {
+        self.inner.enroll_totp(claims).await
+    }
+
+    async fn enable_totp(
+        &self,
+        claims: &Claims,
+        request: TotpCodeRequest,
+    ) -> ArcanaResult<RecoveryCodesResponse> {
+        let result = self.inner.enable_totp(claims, request).await;
+        self.record_own(&result, claims, AuditAction::UserUpdate, Some(json!({ "mfa": "enabled" })))
+            .await;
+        result
+    }
+
+    async fn disable_totp(&self, claims: &Claims, request: TotpCodeRequest) -> ArcanaResult<MessageResponse> {
+        let result = self.inner.disable_totp(claims, request).await;
+        self.record_own(&result, claims, AuditAction::UserUpdate, Some(json!({ "mfa": "disabled" })))
+            .await;
+        result
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        claims: &Claims,
+        request: TotpCodeRequest,
+    ) -> ArcanaResult<RecoveryCodesResponse> {
+        let result = self.inner.regenerate_recovery_codes(claims, request).await;
+        self.record_own(
+            &result,
+            claims,
+            AuditAction::UserUpdate,
+            Some(json!({ "mfa": "recovery_codes_regenerated" })),
+        )
+        .await;
+        result
+    }
+}
+
+impl std::fmt::Debug for AuditedAuthService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditedAuthService").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::RequestContext;
+    use crate::dto::MfaChallengeResponse;
+    use arcana_core::{ArcanaError, UserId, UserRole};
+    use chrono::{Duration, Utc};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingAuditTrail {
+        entries: Mutex<Vec<AuditLog>>,
+    }
+
+    impl std::fmt::Debug for RecordingAuditTrail {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("RecordingAuditTrail").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl AuditTrailInterface for RecordingAuditTrail {
+        async fn record(&self, entry: AuditLog) {
+            self.entries.lock().unwrap().push(entry);
+        }
+    }
+
+    /// Auth service where "alice" signs in directly and "bob" needs a second factor.
+    struct StubAuthService {
+        user_id: UserId,
+    }
+
+    impl std::fmt::Debug for StubAuthService {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("StubAuthService").finish_non_exhaustive()
+        }
+    }
+
+    impl StubAuthService {
+        fn auth_response(&self) -> AuthResponse {
+            AuthResponse {
+                access_token: "access".to_string(),
+                refresh_token: "refresh".to_string(),
+                token_type: "Bearer".to_string(),
+                expires_in: 900,
+                user: AuthUserInfo {
+                    id: self.user_id,
+                    username: "alice".to_string(),
+                    email: "alice@example.com".to_string(),
+                    role: UserRole::User,
+                    first_name: None,
+                    last_name: None,
+                },
+            }
+        }
+    }
+
+    fn unused<T>() -> ArcanaResult<T> {
+        Err(ArcanaError::Internal("not used in tests".to_string()))
+    }
+
+    #[async_trait]
+    impl AuthService for StubAuthService {
+        async fn register(&self, _request: RegisterRequest) -> ArcanaResult<AuthResponse> {
+            Ok(self.auth_response())
+        }
+
+        async fn login(&self, request: LoginRequest) -> ArcanaResult<LoginResponse> {
+            match request.username_or_email.as_str() {
+                "alice" => Ok(LoginResponse::Authenticated(self.auth_response())),
+                "bob" => Ok(LoginResponse::MfaRequired(MfaChallengeResponse {
+                    mfa_required: true,
+                    mfa_token: "challenge".to_string(),
+                    expires_in: 300,
+                })),
+                _ => Err(ArcanaError::InvalidCredentials),
+            }
+        }
+
+        async fn complete_mfa_login(&self, _request: MfaLoginRequest) -> ArcanaResult<AuthResponse> {
+            Ok(self.auth_response())
+        }
+
+        async fn refresh_token(&self, _request: RefreshTokenRequest) -> ArcanaResult<AuthResponse> {
+            Ok(self.auth_response())
+        }
+
+        async fn validate_token(&self, _token: &str) -> ArcanaResult<Claims> {
+            unused()
+        }
+
+        async fn logout(&self, _claims: &Claims) -> ArcanaResult<MessageResponse> {
+            Ok(MessageResponse::new("Successfully logged out"))
+        }
+
+        async fn get_current_user(&self, _claims: &Claims) -> ArcanaResult<AuthUserInfo> {
+            Ok(self.auth_response().user)
+        }
+
+        async fn list_sessions(&self, _claims: &Claims) -> ArcanaResult<Vec<SessionInfo>> {
+            Ok(Vec::new())
+        }
+
+        async fn revoke_session(&self, _claims: &Claims, _session_id: &str) -> ArcanaResult<MessageResponse> {
+            Ok(MessageResponse::new("Session revoked"))
+        }
+
+        async fn revoke_other_sessions(&self, _claims: &Claims) -> ArcanaResult<MessageResponse> {
+            unused()
+        }
+
+        async fn verify_email(&self, _request: VerifyEmailRequest) -> ArcanaResult<MessageResponse> {
+            unused()
+        }
+
+        async fn resend_verification(
+            &self,
+            _request: ResendVerificationRequest,
+        ) -> ArcanaResult<MessageResponse> {
+            unused()
+        }
+
+        async fn forgot_password(&self, _request: PasswordResetRequest) -> ArcanaResult<MessageResponse> {
+            unused()
+        }
+
+        async fn reset_password(
+            &self,
+            _request: PasswordResetConfirmRequest,
+        ) -> ArcanaResult<MessageResponse> {
+            unused()
+        }
+
+        async fn enroll_totp(&self, _claims: &Claims) -> ArcanaResult<TotpEnrollmentResponse> {
+            unused()
+        }
+
+        async fn enable_totp(
+            &self,
+            _claims: &Claims,
+            _request: TotpCodeRequest,
+        ) -> ArcanaResult<RecoveryCodesResponse> {
+            unused()
+        }
+
+        async fn disable_totp(&self, _claims: &Claims, _request: TotpCodeRequest) -> ArcanaResult<MessageResponse> {
+            Ok(MessageResponse::new("Two-factor authentication disabled"))
+        }
+
+        async fn regenerate_recovery_codes(
+            &self,
+            _claims: &Claims,
+            _request: TotpCodeRequest,
+        ) -> ArcanaResult<RecoveryCodesResponse> {
+            unused()
+        }
+    }
+
+    fn create_service() -> (AuditedAuthService, Arc<RecordingAuditTrail>, UserId) {
+        let user_id = UserId::new();
+        let trail = Arc::new(RecordingAuditTrail::default());
+        let service = AuditedAuthService::new(Arc::new(StubAuthService { user_id }), trail.clone());
+        (service, trail, user_id)
+    }
+
+    fn login_request(username_or_email: &str) -> LoginRequest {
+        LoginRequest {
+            username_or_email: username_or_email.to_string(),
+            password: "password123".to_string(),
+            device_id: None,
+            ip_address: Some("203.0.113.7".to_string()),
+        }
+    }
+
+    fn claims_for(user_id: UserId) -> Claims {
+        Claims::new_access(
+            user_id,
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            UserRole::User,
+            "arcana".to_string(),
+            "arcana".to_string(),
+            Utc::now() + Duration::minutes(15),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_login_success_is_recorded_for_the_user() {
+        let (service, trail, user_id) = create_service();
+
+        service.login(login_request("alice")).await.unwrap();
+
+        let entries = trail.entries.lock().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, AuditAction::Login);
+        assert_eq!(entries[0].user_id, Some(user_id));
+        assert_eq!(entries[0].ip_address.as_deref(), Some("203.0.113.7"));
+        assert!(entries[0].details.as_ref().unwrap().get("password").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_login_failure_is_recorded() {
+        let (service, trail, _) = create_service();
+
+        assert!(service.login(login_request("mallory")).await.is_err());
+
+        let entries = trail.entries.lock().unwrap();
+        assert!(!entries[0].success);
+        assert_eq!(entries[0].user_id, None);
+        assert_eq!(entries[0].details.as_ref().unwrap()["username_or_email"], "mallory");
+    }
+
+    #[tokio::test]
+    async fn test_login_is_recorded_once_second_factor_completes() {
+        let (service, trail, user_id) = create_service();
+
+        service.login(login_request("bob")).await.unwrap();
+        assert!(trail.entries.lock().unwrap().is_empty());
+
+        service
+            .complete_mfa_login(MfaLoginRequest {
+                mfa_token: "challenge".to_string(),
+                code: "123456".to_string(),
+                device_id: None,
+            })
+            .await
+            .unwrap();
+
+        let entries = trail.entries.lock().unwrap();
+        assert_eq!(entries[0].action, AuditAction::Login);
+        assert_eq!(entries[0].user_id, Some(user_id));
+        assert_eq!(entries[0].details.as_ref().unwrap()["mfa"], true);
+    }
+
+    #[tokio::test]
+    async fn test_account_changes_are_recorded_for_the_signed_in_user() {
+        let (service, trail, user_id) = create_service();
+        let claims = claims_for(user_id);
+
+        RequestContext::new(Some("10.0.0.1".to_string()), None)
+            .with_actor(Some(user_id))
+            .scope(async {
+                service
+                    .disable_totp(&claims, TotpCodeRequest { code: "123456".to_string() })
+                    .await
+                    .unwrap();
+                service.revoke_session(&claims, "session-1").await.unwrap();
+                service.logout(&claims).await.unwrap();
+            })
+            .await;
+
+        let entries = trail.entries.lock().unwrap();
+        let actions: Vec<_> = entries.iter().map(|e| e.action).collect();
+        assert_eq!(actions, vec![AuditAction::UserUpdate, AuditAction::Logout, AuditAction::Logout]);
+        assert!(entries.iter().all(|e| e.user_id == Some(user_id)));
+        assert_eq!(entries[0].details.as_ref().unwrap()["mfa"], "disabled");
+        assert_eq!(entries[1].resource_type, SESSION_RESOURCE);
+        assert_eq!(entries[1].resource_id.as_deref(), Some("session-1"));
+    }
+
+    #[tokio::test]
+    async fn test_reads_are_not_recorded() {
+        let (service, trail, user_id) = create_service();
+        let claims = claims_for(user_id);
+
+        service.get_current_user(&claims).await.unwrap();
+        service.list_sessions(&claims).await.unwrap();
+
+        assert!(trail.entries.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,272 @@
+//! Audit decorator for the role service.
+
+use super::audit_trail::outcome_entry;
+use super::{AuditTrailInterface, ROLE_RESOURCE, USER_RESOURCE};
+use crate::dto::{CreateRoleRequest, RoleListResponse, RoleResponse, UpdateRoleRequest, UserPermissionsResponse};
+use crate::RoleService;
+use arcana_core::{ArcanaResult, AuditAction, Permission, RoleId, UserId};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+/// [`RoleService`] that records every mutating call in the audit trail.
+///
+/// Changes to roles are recorded against the role; assignments and direct
+/// grants are recorded as role changes of the user concerned.
+pub struct AuditedRoleService {
+    inner: Arc<dyn RoleService>,
+    audit_trail: Arc<dyn AuditTrailInterface>,
+}
+
+impl AuditedRoleService {
+    /// Wraps a role service.
+    #[must_use]
+    pub fn new(inner: Arc<dyn RoleService>, audit_trail: Arc<dyn AuditTrailInterface>) -> Self {
+        Self { inner, audit_trail }
+    }
+
+    async fn record_grant_change(&self, result: &ArcanaResult<UserPermissionsResponse>, user_id: UserId, details: Value) {
+        self.audit_trail
+            .record(
+                outcome_entry(result, None, AuditAction::UserRoleChange, USER_RESOURCE, Some(user_id.to_string()))
+                    .with_details(details),
+            )
+            .await;
+    }
+}
+
+#[async_trait]
+impl RoleService for AuditedRoleService {
+    async fn create_role(&self, request: CreateRoleRequest) -> ArcanaResult<RoleResponse> {
+        let details = json!({ "name": request.name, "permissions": request.permissions });
+        let result = self.inner.create_role(request).await;
+
+        let resource_id = result.as_ref().ok().map(|role| role.id.to_string());
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::Create, ROLE_RESOURCE, resource_id).with_details(details))
+            .await;
+        result
+    }
+
+    async fn get_role(&self, id: RoleId) -> ArcanaResult<RoleResponse> {
+        self.inner.get_role(id).await
+    }
+
+    async fn list_roles(&self) -> ArcanaResult<RoleListResponse> {
+        self.inner.list_roles().await
+    }
+
+    async fn update_role(&self, id: RoleId, request: UpdateRoleRequest) -> ArcanaResult<RoleResponse> {
+        let details = json!({ "name": request.name, "permissions": request.permissions });
+        let result = self.inner.update_role(id, request).await;
+
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::Update, ROLE_RESOURCE, Some(id.to_string())).with_details(details))
+            .await;
+        result
+    }
+
+    async fn delete_role(&self, id: RoleId) -> ArcanaResult<()> {
+        let result = self.inner.delete_role(id).await;
+
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::Delete, ROLE_RESOURCE, Some(id.to_string())))
+            .await;
+        result
+    }
+
+    async fn get_user_permissions(&self, user_id: UserId) -> ArcanaResult<UserPermissionsResponse> {
+        self.inner.get_user_permissions(user_id).await
+    }
+
+    async fn assign_role(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<UserPermissionsResponse> {
+        let result = self.inner.assign_role(user_id, role_id).await;
+        self.record_grant_change(&result, user_id, json!({ "assigned_role": role_id })).await;
+        result
+    }
+
+    async fn unassign_role(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<UserPermissionsResponse> {
+        let result = self.inner.unassign_role(user_id, role_id).await;
+        self.record_grant_change(&result, user_id, json!({ "unassigned_role": role_id })).await;
+        result
+    }
+
+    async fn grant_permission(&self, user_id: UserId, permission: Permission) -> ArcanaResult<UserPermissionsResponse> {
+        let result = self.inner.grant_permission(user_id, permission).await;
+        self.record_grant_change(&result, user_id, json!({ "granted_permission": permission })).await;
+        result
+    }
+
+    async fn revoke_permission(&self, user_id: UserId, permission: Permission) -> ArcanaResult<UserPermissionsResponse> {
+        let result = self.inner.revoke_permission(user_id, permission).await;
+        self.record_grant_change(&result, user_id, json!({ "revoked_permission": permission })).await;
+        result
+    }
+}
+
+impl std::fmt::Debug for AuditedRoleService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditedRoleService").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{ArcanaError, AuditLog, PermissionSet, UserRole};
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingAuditTrail {
+        entries: Mutex<Vec<AuditLog>>,
+    }
+
+    impl std::fmt::Debug for RecordingAuditTrail {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("RecordingAuditTrail").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl AuditTrailInterface for RecordingAuditTrail {
+        async fn record(&self, entry: AuditLog) {
+            self.entries.lock().unwrap().push(entry);
+        }
+    }
+
+    /// Role service that knows a single role and a single user.
+    struct StubRoleService {
+        role_id: RoleId,
+        user_id: UserId,
+    }
+
+    impl std::fmt::Debug for StubRoleService {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("StubRoleService").finish_non_exhaustive()
+        }
+    }
+
+    impl StubRoleService {
+        fn find(&self, id: RoleId) -> ArcanaResult<RoleResponse> {
+            if id != self.role_id {
+                return Err(ArcanaError::not_found("Role", id));
+            }
+            Ok(RoleResponse {
+                id,
+                name: "support".to_string(),
+                description: None,
+                permissions: PermissionSet::new(),
+                built_in: false,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            })
+        }
+
+        fn permissions(&self, user_id: UserId) -> ArcanaResult<UserPermissionsResponse> {
+            if user_id != self.user_id {
+                return Err(ArcanaError::not_found("User", user_id));
+            }
+            Ok(UserPermissionsResponse {
+                user_id,
+                role: UserRole::User,
+                roles: Vec::new(),
+                granted: PermissionSet::new(),
+                effective: UserRole::User.default_permissions(),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl RoleService for StubRoleService {
+        async fn create_role(&self, _request: CreateRoleRequest) -> ArcanaResult<RoleResponse> {
+            self.find(self.role_id)
+        }
+
+        async fn get_role(&self, id: RoleId) -> ArcanaResult<RoleResponse> {
+            self.find(id)
+        }
+
+        async fn list_roles(&self) -> ArcanaResult<RoleListResponse> {
+            Ok(RoleListResponse {
+                roles: vec![self.find(self.role_id)?],
+            })
+        }
+
+        async fn update_role(&self, id: RoleId, _request: UpdateRoleRequest) -> ArcanaResult<RoleResponse> {
+            self.find(id)
+        }
+
+        async fn delete_role(&self, id: RoleId) -> ArcanaResult<()> {
+            self.find(id).map(|_| ())
+        }
+
+        async fn get_user_permissions(&self, user_id: UserId) -> ArcanaResult<UserPermissionsResponse> {
+            self.permissions(user_id)
+        }
+
+        async fn assign_role(&self, user_id: UserId, _role_id: RoleId) -> ArcanaResult<UserPermissionsResponse> {
+            self.permissions(user_id)
+        }
+
+        async fn unassign_role(&self, user_id: UserId, _role_id: RoleId) -> ArcanaResult<UserPermissionsResponse> {
+            self.permissions(user_id)
+        }
+
+        async fn grant_permission(&self, user_id: UserId, _permission: Permission) -> ArcanaResult<UserPermissionsResponse> {
+            self.permissions(user_id)
+        }
+
+        async fn revoke_permission(&self, user_id: UserId, _permission: Permission) -> ArcanaResult<UserPermissionsResponse> {
+            self.permissions(user_id)
+        }
+    }
+
+    fn create_service() -> (AuditedRoleService, Arc<RecordingAuditTrail>, RoleId, UserId) {
+        let role_id = RoleId::new();
+        let user_id = UserId::new();
+        let trail = Arc::new(RecordingAuditTrail::default());
+        let service = AuditedRoleService::new(Arc::new(StubRoleService { role_id, user_id }), trail.clone());
+        (service, trail, role_id, user_id)
+    }
+
+    #[tokio::test]
+    async fn test_role_changes_are_recorded_against_the_role() {
+        let (service, trail, role_id, _) = create_service();
+
+        service
+            .create_role(CreateRoleRequest {
+                name: "support".to_string(),
+                description: None,
+                permissions: vec![Permission::UserRead],
+            })
+            .await
+            .unwrap();
+        service.update_role(role_id, UpdateRoleRequest::default()).await.unwrap();
+        service.delete_role(role_id).await.unwrap();
+        service.get_role(role_id).await.unwrap();
+        service.list_roles().await.unwrap();
+
+        let entries = trail.entries.lock().unwrap();
+        let actions: Vec<_> = entries.iter().map(|e| e.action).collect();
+        assert_eq!(actions, vec![AuditAction::Create, AuditAction::Update, AuditAction::Delete]);
+        assert!(entries.iter().all(|e| e.resource_type == ROLE_RESOURCE && e.resource_id == Some(role_id.to_string())));
+        assert_eq!(entries[0].details.as_ref().unwrap()["permissions"][0], "user:read");
+    }
+
+    #[tokio::test]
+    async fn test_grant_changes_are_recorded_against_the_user() {
+        let (service, trail, role_id, user_id) = create_service();
+
+        service.assign_role(user_id, role_id).await.unwrap();
+        service.grant_permission(user_id, Permission::SystemMonitor).await.unwrap();
+        assert!(service.revoke_permission(UserId::new(), Permission::SystemMonitor).await.is_err());
+        service.get_user_permissions(user_id).await.unwrap();
+
+        let entries = trail.entries.lock().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert!(entries.iter().all(|e| e.action == AuditAction::UserRoleChange && e.resource_type == USER_RESOURCE));
+        assert_eq!(entries[1].details.as_ref().unwrap()["granted_permission"], "system:monitor");
+        assert!(!entries[2].success);
+    }
+}
@@ -0,0 +1,166 @@
+//! Audit decorator for the tenant service.
+
+use super::audit_trail::outcome_entry;
+use super::{AuditTrailInterface, TENANT_RESOURCE};
+use crate::dto::{CreateTenantRequest, TenantListResponse, TenantResponse, UpdateTenantRequest};
+use crate::TenantService;
+use arcana_core::{ArcanaResult, AuditAction, PageRequest, TenantId};
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// [`TenantService`] that records every mutating call in the audit trail.
+pub struct AuditedTenantService {
+    inner: Arc<dyn TenantService>,
+    audit_trail: Arc<dyn AuditTrailInterface>,
+}
+
+impl AuditedTenantService {
+    /// Wraps a tenant service.
+    #[must_use]
+    pub fn new(inner: Arc<dyn TenantService>, audit_trail: Arc<dyn AuditTrailInterface>) -> Self {
+        Self { inner, audit_trail }
+    }
+}
+
+#[async_trait]
+impl TenantService for AuditedTenantService {
+    async fn create_tenant(&self, request: CreateTenantRequest) -> ArcanaResult<TenantResponse> {
+        let details = json!({ "slug": request.slug, "name": request.name });
+        let result = self.inner.create_tenant(request).await;
+
+        let resource_id = result.as_ref().ok().map(|tenant| tenant.id.to_string());
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::Create, TENANT_RESOURCE, resource_id).with_details(details))
+            .await;
+        result
+    }
+
+    async fn get_tenant(&self, id: TenantId) -> ArcanaResult<TenantResponse> {
+        self.inner.get_tenant(id).await
+    }
+
+    async fn list_tenants(&self, page: PageRequest) -> ArcanaResult<TenantListResponse> {
+        self.inner.list_tenants(page).await
+    }
+
+    async fn update_tenant(&self, id: TenantId, request: UpdateTenantRequest) -> ArcanaResult<TenantResponse> {
+        let details = json!({ "name": request.name, "active": request.active });
+        let result = self.inner.update_tenant(id, request).await;
+
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::Update, TENANT_RESOURCE, Some(id.to_string())).with_details(details))
+            .await;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{ArcanaError, AuditLog};
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingAuditTrail {
+        entries: Mutex<Vec<AuditLog>>,
+    }
+
+    impl std::fmt::Debug for RecordingAuditTrail {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("RecordingAuditTrail").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl AuditTrailInterface for RecordingAuditTrail {
+        async fn record(&self, entry: AuditLog) {
+            self.entries.lock().unwrap().push(entry);
+        }
+    }
+
+    /// Tenant service that knows a single tenant.
+    struct StubTenantService {
+        id: TenantId,
+    }
+
+    impl std::fmt::Debug for StubTenantService {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("StubTenantService").finish_non_exhaustive()
+        }
+    }
+
+    impl StubTenantService {
+        fn find(&self, id: TenantId) -> ArcanaResult<TenantResponse> {
+            if id != self.id {
+                return Err(ArcanaError::not_found("Tenant", id));
+            }
+            Ok(TenantResponse {
+                id,
+                slug: "acme".to_string(),
+                name: "Acme Corp".to_string(),
+                active: true,
+                default: false,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl TenantService for StubTenantService {
+        async fn create_tenant(&self, _request: CreateTenantRequest) -> ArcanaResult<TenantResponse> {
+            self.find(self.id)
+        }
+
+        async fn get_tenant(&self, id: TenantId) -> ArcanaResult<TenantResponse> {
+            self.find(id)
+        }
+
+        async fn list_tenants(&self, page: PageRequest) -> ArcanaResult<TenantListResponse> {
+            Ok(TenantListResponse {
+                tenants: vec![self.find(self.id)?],
+                page: page.page,
+                size: page.size,
+                total_elements: 1,
+                total_pages: 1,
+            })
+        }
+
+        async fn update_tenant(&self, id: TenantId, _request: UpdateTenantRequest) -> ArcanaResult<TenantResponse> {
+            self.find(id)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mutations_are_recorded() {
+        let id = TenantId::new();
+        let trail = Arc::new(RecordingAuditTrail::default());
+        let service = AuditedTenantService::new(Arc::new(StubTenantService { id }), trail.clone());
+
+        service
+            .create_tenant(CreateTenantRequest {
+                slug: "acme".to_string(),
+                name: "Acme Corp".to_string(),
+            })
+            .await
+            .unwrap();
+        service
+            .update_tenant(id, UpdateTenantRequest {
+                active: Some(false),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        service.get_tenant(id).await.unwrap();
+        assert!(service.update_tenant(TenantId::new(), UpdateTenantRequest::default()).await.is_err());
+
+        let entries = trail.entries.lock().unwrap();
+        let actions: Vec<_> = entries.iter().map(|e| e.action).collect();
+        assert_eq!(actions, vec![AuditAction::Create, AuditAction::Update, AuditAction::Update]);
+        assert!(entries.iter().all(|e| e.resource_type == TENANT_RESOURCE));
+        assert_eq!(entries[1].details.as_ref().unwrap()["active"], false);
+        assert!(!entries[2].success);
+    }
+}
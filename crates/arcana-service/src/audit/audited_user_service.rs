@@ -0,0 +1,338 @@
+//! Audit decorator for the user service.
+
+use super::audit_trail::outcome_entry;
+use super::{AuditTrailInterface, USER_RESOURCE};
+use crate::dto::{
+    ChangePasswordRequest, CreateUserRequest, UpdateUserRequest, UpdateUserRoleRequest,
+    UpdateUserStatusRequest, UserListResponse, UserResponse,
+};
+use crate::UserService;
+use arcana_core::{ArcanaResult, AuditAction, PageRequest, UserId};
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// [`UserService`] that records every mutating call in the audit trail.
+///
+/// Reads pass straight through to the wrapped service.
+pub struct AuditedUserService {
+    inner: Arc<dyn UserService>,
+    audit_trail: Arc<dyn AuditTrailInterface>,
+}
+
+impl AuditedUserService {
+    /// Wraps a user service.
+    #[must_use]
+    pub fn new(inner: Arc<dyn UserService>, audit_trail: Arc<dyn AuditTrailInterface>) -> Self {
+        Self { inner, audit_trail }
+    }
+}
+
+#[async_trait]
+impl UserService for AuditedUserService {
+    async fn create_user(&self, request: CreateUserRequest) -> ArcanaResult<UserResponse> {
+        let details = json!({ "username": request.username, "email": request.email });
+        let result = self.inner.create_user(request).await;
+
+        let resource_id = result.as_ref().ok().map(|user| user.id.to_string());
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::UserCreate, USER_RESOURCE, resource_id).with_details(details))
+            .await;
+        result
+    }
+
+    async fn get_user(&self, id: UserId) -> ArcanaResult<UserResponse> {
+        self.inner.get_user(id).await
+    }
+
+    async fn get_user_by_username(&self, username: &str) -> ArcanaResult<UserResponse> {
+        self.inner.get_user_by_username(username).await
+    }
+
+    async fn list_users(&self, page: PageRequest) -> ArcanaResult<UserListResponse> {
+        self.inner.list_users(page).await
+    }
+
+    async fn update_user(&self, id: UserId, request: UpdateUserRequest) -> ArcanaResult<UserResponse> {
+        let details = json!(request);
+        let result = self.inner.update_user(id, request).await;
+
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::UserUpdate, USER_RESOURCE, Some(id.to_string())).with_details(details))
+            .await;
+        result
+    }
+
+    async fn update_user_role(&self, id: UserId, request: UpdateUserRoleRequest) -> ArcanaResult<UserResponse> {
+        let details = json!({ "role": request.role });
+        let result = self.inner.update_user_role(id, request).await;
+
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::UserRoleChange, USER_RESOURCE, Some(id.to_string())).with_details(details))
+            .await;
+        result
+    }
+
+    async fn update_user_status(&self, id: UserId, request: UpdateUserStatusRequest) -> ArcanaResult<UserResponse> {
+        let details = json!({ "status": request.status, "reason": request.reason });
+        let result = self.inner.update_user_status(id, request).await;
+
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::UserStatusChange, USER_RESOURCE, Some(id.to_string())).with_details(details))
+            .await;
+        result
+    }
+
+    async fn change_password(&self, id: UserId, request: ChangePasswordRequest) -> ArcanaResult<()> {
+        let result = self.inner.change_password(id, request).await;
+
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::PasswordChange, USER_RESOURCE, Some(id.to_string())))
+            .await;
+        result
+    }
+
+    async fn delete_user(&self, id: UserId) -> ArcanaResult<()> {
+        let result = self.inner.delete_user(id).await;
+
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::UserDelete, USER_RESOURCE, Some(id.to_string())))
+            .await;
+        result
+    }
+
+    async fn username_exists(&self, username: &str) -> ArcanaResult<bool> {
+        self.inner.username_exists(username).await
+    }
+
+    async fn email_exists(&self, email: &str) -> ArcanaResult<bool> {
+        self.inner.email_exists(email).await
+    }
+}
+
+impl std::fmt::Debug for AuditedUserService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditedUserService").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::RequestContext;
+    use arcana_core::{ArcanaError, AuditLog, TenantId, UserRole, UserStatus};
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingAuditTrail {
+        entries: Mutex<Vec<AuditLog>>,
+    }
+
+    impl std::fmt::Debug for RecordingAuditTrail {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("RecordingAuditTrail").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl AuditTrailInterface for RecordingAuditTrail {
+        async fn record(&self, entry: AuditLog) {
+            self.entries.lock().unwrap().push(entry);
+        }
+    }
+
+    /// User service that knows a single user.
+    struct StubUserService {
+        user_id: UserId,
+    }
+
+    impl std::fmt::Debug for StubUserService {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("StubUserService").finish_non_exhaustive()
+        }
+    }
+
+    impl StubUserService {
+        fn user(&self) -> UserResponse {
+            UserResponse {
+                id: self.user_id,
+                tenant_id: TenantId::DEFAULT,
+                username: "alice".to_string(),
+                email: "alice@example.com".to_string(),
+                first_name: None,
+                last_name: None,
+                role: UserRole::User,
+                status: UserStatus::Active,
+                email_verified: true,
+                avatar_url: None,
+                last_login_at: None,
+                created_at: Utc::now(),
+            }
+        }
+
+        fn find(&self, id: UserId) -> ArcanaResult<UserResponse> {
+            if id == self.user_id {
+                Ok(self.user())
+            } else {
+                Err(ArcanaError::not_found("User", id))
+            }
+        }
+    }
+
+    #[async_trait]
+    impl UserService for StubUserService {
+        async fn create_user(&self, _request: CreateUserRequest) -> ArcanaResult<UserResponse> {
+            Ok(self.user())
+        }
+
+        async fn get_user(&self, id: UserId) -> ArcanaResult<UserResponse> {
+            self.find(id)
+        }
+
+        async fn get_user_by_username(&self, _username: &str) -> ArcanaResult<UserResponse> {
+            Ok(self.user())
+        }
+
+        async fn list_users(&self, page: PageRequest) -> ArcanaResult<UserListResponse> {
+            Ok(UserListResponse {
+                users: vec![self.user()],
+                page: page.page,
+                size: page.size,
+                total_elements: 1,
+                total_pages: 1,
+            })
+        }
+
+        async fn update_user(&self, id: UserId, _request: UpdateUserRequest) -> ArcanaResult<UserResponse> {
+            self.find(id)
+        }
+
+        async fn update_user_role(&self, id: UserId, _request: UpdateUserRoleRequest) -> ArcanaResult<UserResponse> {
+            self.find(id)
+        }
+
+        async fn update_user_status(&self, id: UserId, _request: UpdateUserStatusRequest) -> ArcanaResult<UserResponse> {
+            self.find(id)
+        }
+
+        async fn change_password(&self, id: UserId, _request: ChangePasswordRequest) -> ArcanaResult<()> {
+            self.find(id).map(|_| ())
+        }
+
+        async fn delete_user(&self, id: UserId) -> ArcanaResult<()> {
+            self.find(id).map(|_| ())
+        }
+
+        async fn username_exists(&self, _username: &str) -> ArcanaResult<bool> {
+            Ok(true)
+        }
+
+        async fn email_exists(&self, _email: &str) -> ArcanaResult<bool> {
+            Ok(true)
+        }
+    }
+
+    fn create_service() -> (AuditedUserService, Arc<RecordingAuditTrail>, UserId) {
+        let user_id = UserId::new();
+        let trail = Arc::new(RecordingAuditTrail::default());
+        let service = AuditedUserService::new(Arc::new(StubUserService { user_id }), trail.clone());
+        (service, trail, user_id)
+    }
+
+    #[tokio::test]
+    async fn test_create_user_is_recorded() {
+        let (service, trail, user_id) = create_service();
+
+        service
+            .create_user(CreateUserRequest {
+                username: "alice".to_string(),
+                email: "alice@example.com".to_string(),
+                password: "password123".to_string(),
+                first_name: None,
+                last_name: None,
+            })
+            .await
+            .unwrap();
+
+        let entries = trail.entries.lock().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, AuditAction::UserCreate);
+        assert_eq!(entries[0].resource_id, Some(user_id.to_string()));
+        let details = entries[0].details.as_ref().unwrap();
+        assert_eq!(details["username"], "alice");
+        assert!(details.get("password").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_role_change_records_new_role() {
+        let (service, trail, user_id) = create_service();
+
+        service
+            .update_user_role(user_id, UpdateUserRoleRequest { role: UserRole::Admin })
+            .await
+            .unwrap();
+
+        let entries = trail.entries.lock().unwrap();
+        assert_eq!(entries[0].action, AuditAction::UserRoleChange);
+        assert_eq!(entries[0].details.as_ref().unwrap()["role"], "admin");
+        assert!(entries[0].success);
+    }
+
+    #[tokio::test]
+    async fn test_failed_status_change_is_recorded() {
+        let (service, trail, _) = create_service();
+        let other = UserId::new();
+
+        let result = service
+            .update_user_status(other, UpdateUserStatusRequest { status: UserStatus::Suspended, reason: None })
+            .await;
+        assert!(result.is_err());
+
+        let entries = trail.entries.lock().unwrap();
+        assert_eq!(entries[0].action, AuditAction::UserStatusChange);
+        assert_eq!(entries[0].resource_id, Some(other.to_string()));
+        assert!(!entries[0].success);
+        assert!(entries[0].error_message.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_password_change_and_delete_are_recorded() {
+        let (service, trail, user_id) = create_service();
+
+        RequestContext::default()
+            .with_actor(Some(user_id))
+            .scope(async {
+                service
+                    .change_password(
+                        user_id,
+                        ChangePasswordRequest {
+                            current_password: "old-password".to_string(),
+                            new_password: "new-password".to_string(),
+                        },
+                    )
+                    .await
+                    .unwrap();
+                service.delete_user(user_id).await.unwrap();
+            })
+            .await;
+
+        let entries = trail.entries.lock().unwrap();
+        let actions: Vec<_> = entries.iter().map(|e| e.action).collect();
+        assert_eq!(actions, vec![AuditAction::PasswordChange, AuditAction::UserDelete]);
+        assert!(entries[0].details.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reads_are_not_recorded() {
+        let (service, trail, user_id) = create_service();
+
+        service.get_user(user_id).await.unwrap();
+        service.get_user_by_username("alice").await.unwrap();
+        service.list_users(PageRequest::default()).await.unwrap();
+        service.username_exists("alice").await.unwrap();
+        service.email_exists("alice@example.com").await.unwrap();
+
+        assert!(trail.entries.lock().unwrap().is_empty());
+    }
+}
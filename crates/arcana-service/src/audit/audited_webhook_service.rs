@@ -0,0 +1,241 @@
+//! Audit decorator for the webhook service.
+
+use super::audit_trail::outcome_entry;
+use super::{AuditTrailInterface, WEBHOOK_RESOURCE};
+use crate::dto::{
+    CreateWebhookRequest, UpdateWebhookRequest, WebhookDeliveryListResponse, WebhookListResponse, WebhookResponse,
+};
+use crate::WebhookService;
+use arcana_core::{ArcanaResult, AuditAction, PageRequest, WebhookSubscriptionId};
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// [`WebhookService`] that records every mutating call in the audit trail.
+///
+/// Secrets are never written to the trail; a changed secret is recorded as
+/// `secret_rotated`.
+pub struct AuditedWebhookService {
+    inner: Arc<dyn WebhookService>,
+    audit_trail: Arc<dyn AuditTrailInterface>,
+}
+
+impl AuditedWebhookService {
+    /// Wraps a webhook service.
+    #[must_use]
+    pub fn new(inner: Arc<dyn WebhookService>, audit_trail: Arc<dyn AuditTrailInterface>) -> Self {
+        Self { inner, audit_trail }
+    }
+}
+
+#[async_trait]
+impl WebhookService for AuditedWebhookService {
+    async fn create_webhook(&self, request: CreateWebhookRequest) -> ArcanaResult<WebhookResponse> {
+        let details = json!({ "url": request.url, "event_types": request.event_types });
+        let result = self.inner.create_webhook(request).await;
+
+        let resource_id = result.as_ref().ok().map(|webhook| webhook.id.to_string());
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::Create, WEBHOOK_RESOURCE, resource_id).with_details(details))
+            .await;
+        result
+    }
+
+    async fn get_webhook(&self, id: WebhookSubscriptionId) -> ArcanaResult<WebhookResponse> {
+        self.inner.get_webhook(id).await
+    }
+
+    async fn list_webhooks(&self, page: PageRequest) -> ArcanaResult<WebhookListResponse> {
+        self.inner.list_webhooks(page).await
+    }
+
+    async fn update_webhook(&self, id: WebhookSubscriptionId, request: UpdateWebhookRequest) -> ArcanaResult<WebhookResponse> {
+        let details = json!({
+            "url": request.url,
+            "event_types": request.event_types,
+            "active": request.active,
+            "secret_rotated": request.secret.is_some(),
+        });
+        let result = self.inner.update_webhook(id, request).await;
+
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::Update, WEBHOOK_RESOURCE, Some(id.to_string())).with_details(details))
+            .await;
+        result
+    }
+
+    async fn delete_webhook(&self, id: WebhookSubscriptionId) -> ArcanaResult<()> {
+        let result = self.inner.delete_webhook(id).await;
+
+        self.audit_trail
+            .record(outcome_entry(&result, None, AuditAction::Delete, WEBHOOK_RESOURCE, Some(id.to_string())))
+            .await;
+        result
+    }
+
+    async fn list_deliveries(&self, id: WebhookSubscriptionId, page: PageRequest) -> ArcanaResult<WebhookDeliveryListResponse> {
+        self.inner.list_deliveries(id, page).await
+    }
+}
+
+impl std::fmt::Debug for AuditedWebhookService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditedWebhookService").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{ArcanaError, AuditLog};
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingAuditTrail {
+        entries: Mutex<Vec<AuditLog>>,
+    }
+
+    impl std::fmt::Debug for RecordingAuditTrail {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("RecordingAuditTrail").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl AuditTrailInterface for RecordingAuditTrail {
+        async fn record(&self, entry: AuditLog) {
+            self.entries.lock().unwrap().push(entry);
+        }
+    }
+
+    /// Webhook service that knows a single subscription.
+    struct StubWebhookService {
+        id: WebhookSubscriptionId,
+    }
+
+    impl std::fmt::Debug for StubWebhookService {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("StubWebhookService").finish_non_exhaustive()
+        }
+    }
+
+    impl StubWebhookService {
+        fn find(&self, id: WebhookSubscriptionId) -> ArcanaResult<WebhookResponse> {
+            if id != self.id {
+                return Err(ArcanaError::not_found("Webhook", id));
+            }
+            Ok(WebhookResponse {
+                id,
+                url: "https://example.com/hooks".to_string(),
+                event_types: vec!["*".to_string()],
+                description: None,
+                active: true,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl WebhookService for StubWebhookService {
+        async fn create_webhook(&self, _request: CreateWebhookRequest) -> ArcanaResult<WebhookResponse> {
+            self.find(self.id)
+        }
+
+        async fn get_webhook(&self, id: WebhookSubscriptionId) -> ArcanaResult<WebhookResponse> {
+            self.find(id)
+        }
+
+        async fn list_webhooks(&self, page: PageRequest) -> ArcanaResult<WebhookListResponse> {
+            Ok(WebhookListResponse {
+                webhooks: vec![self.find(self.id)?],
+                page: page.page,
+                size: page.size,
+                total_elements: 1,
+                total_pages: 1,
+            })
+        }
+
+        async fn update_webhook(&self, id: WebhookSubscriptionId, _request: UpdateWebhookRequest) -> ArcanaResult<WebhookResponse> {
+            self.find(id)
+        }
+
+        async fn delete_webhook(&self, id: WebhookSubscriptionId) -> ArcanaResult<()> {
+            self.find(id).map(|_| ())
+        }
+
+        async fn list_deliveries(&self, id: WebhookSubscriptionId, page: PageRequest) -> ArcanaResult<WebhookDeliveryListResponse> {
+            self.find(id)?;
+            Ok(WebhookDeliveryListResponse {
+                deliveries: Vec::new(),
+                page: page.page,
+                size: page.size,
+                total_elements: 0,
+                total_pages: 0,
+            })
+        }
+    }
+
+    fn create_service() -> (AuditedWebhookService, Arc<RecordingAuditTrail>, WebhookSubscriptionId) {
+        let id = WebhookSubscriptionId::new();
+        let trail = Arc::new(RecordingAuditTrail::default());
+        let service = AuditedWebhookService::new(Arc::new(StubWebhookService { id }), trail.clone());
+        (service, trail, id)
+    }
+
+    #[tokio::test]
+    async fn test_mutations_are_recorded_without_secrets() {
+        let (service, trail, id) = create_service();
+
+        service
+            .create_webhook(CreateWebhookRequest {
+                url: "https://example.com/hooks".to_string(),
+                event_types: vec!["*".to_string()],
+                secret: "0123456789abcdef".to_string(),
+                description: None,
+            })
+            .await
+            .unwrap();
+        service
+            .update_webhook(id, UpdateWebhookRequest {
+                secret: Some("fedcba9876543210".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        service.delete_webhook(id).await.unwrap();
+
+        let entries = trail.entries.lock().unwrap();
+        let actions: Vec<_> = entries.iter().map(|e| e.action).collect();
+        assert_eq!(actions, vec![AuditAction::Create, AuditAction::Update, AuditAction::Delete]);
+        assert!(entries.iter().all(|e| e.resource_type == WEBHOOK_RESOURCE && e.resource_id == Some(id.to_string())));
+
+        let details = serde_json::to_string(&entries.iter().map(|e| e.details.clone()).collect::<Vec<_>>()).unwrap();
+        assert!(!details.contains("0123456789abcdef"));
+        assert!(!details.contains("fedcba9876543210"));
+        assert_eq!(entries[1].details.as_ref().unwrap()["secret_rotated"], true);
+    }
+
+    #[tokio::test]
+    async fn test_failed_delete_is_recorded() {
+        let (service, trail, _) = create_service();
+
+        assert!(service.delete_webhook(WebhookSubscriptionId::new()).await.is_err());
+
+        let entries = trail.entries.lock().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(!entries[0].success);
+    }
+
+    #[tokio::test]
+    async fn test_reads_are_not_recorded() {
+        let (service, trail, id) = create_service();
+
+        service.get_webhook(id).await.unwrap();
+        service.list_webhooks(PageRequest::first()).await.unwrap();
+        service.list_deliveries(id, PageRequest::first()).await.unwrap();
+
+        assert!(trail.entries.lock().unwrap().is_empty());
+    }
+}
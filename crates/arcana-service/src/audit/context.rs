@@ -0,0 +1,96 @@
+//! Per-request context for the audit trail and tenant scoping.
+
+use arcana_core::{TenantId, UserId};
+use std::future::Future;
+
+tokio::task_local! {
+    static REQUEST_CONTEXT: RequestContext;
+}
+
+/// Who made the current request, for which tenant and from where.
+///
+/// Entry points (REST middleware, gRPC layer) run each request inside
+/// [`RequestContext::scope`], so services can attribute their actions
+/// without every method taking the caller as an argument.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestContext {
+    /// Authenticated user making the request, if any.
+    pub actor: Option<UserId>,
+    /// Tenant the request acts on, if the entry point resolved one.
+    pub tenant: Option<TenantId>,
+    /// IP address of the client.
+    pub ip_address: Option<String>,
+    /// User agent of the client.
+    pub user_agent: Option<String>,
+}
+
+impl RequestContext {
+    /// Creates a context for an anonymous client.
+    #[must_use]
+    pub fn new(ip_address: Option<String>, user_agent: Option<String>) -> Self {
+        Self {
+            actor: None,
+            tenant: None,
+            ip_address,
+            user_agent,
+        }
+    }
+
+    /// Sets the authenticated user making the request.
+    #[must_use]
+    pub fn with_actor(mut self, actor: Option<UserId>) -> Self {
+        self.actor = actor;
+        self
+    }
+
+    /// Sets the tenant the request acts on.
+    #[must_use]
+    pub fn with_tenant(mut self, tenant: Option<TenantId>) -> Self {
+        self.tenant = tenant;
+        self
+    }
+
+    /// Returns the tenant the request acts on.
+    ///
+    /// Requests without a tenant, and work outside of a request, act on
+    /// the [default tenant](TenantId::DEFAULT).
+    #[must_use]
+    pub fn tenant_id(&self) -> TenantId {
+        self.tenant.unwrap_or_default()
+    }
+
+    /// Runs a future with this context as the current one.
+    pub async fn scope<F: Future>(self, f: F) -> F::Output {
+        REQUEST_CONTEXT.scope(self, f).await
+    }
+
+    /// Returns the context of the current request.
+    ///
+    /// Outside of a request (e.g. background jobs) this is an empty context.
+    #[must_use]
+    pub fn current() -> Self {
+        REQUEST_CONTEXT.try_with(Clone::clone).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_current_inside_scope() {
+        let actor = UserId::new();
+        let context = RequestContext::new(Some("10.0.0.1".to_string()), Some("curl/8.0".to_string()))
+            .with_actor(Some(actor))
+            .with_tenant(Some(TenantId::new()));
+
+        let current = context.clone().scope(async { RequestContext::current() }).await;
+        assert_eq!(current, context);
+    }
+
+    #[tokio::test]
+    async fn test_current_outside_scope_is_empty() {
+        assert_eq!(RequestContext::current(), RequestContext::default());
+        assert!(RequestContext::current().tenant_id().is_default());
+    }
+}
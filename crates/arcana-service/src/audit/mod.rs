@@ -0,0 +1,39 @@
+//! Audit trail for the service layer.
+//!
+//! [`AuditedUserService`], [`AuditedAuthService`], [`AuditedWebhookService`],
+//! [`AuditedRoleService`] and [`AuditedTenantService`] wrap the real services and record every
+//! mutating call through the [`AuditTrailInterface`]. The actor, IP address
+//! and user agent come from the [`RequestContext`] that the REST middleware
+//! and the gRPC layer establish for each request, together with the tenant
+//! the request acts on.
+
+mod audit_trail;
+mod audited_auth_service;
+mod audited_role_service;
+mod audited_tenant_service;
+mod audited_user_service;
+mod audited_webhook_service;
+mod context;
+
+pub use audit_trail::{AuditTrail, AuditTrailInterface, AuditTrailParameters};
+pub use audited_auth_service::AuditedAuthService;
+pub use audited_role_service::AuditedRoleService;
+pub use audited_tenant_service::AuditedTenantService;
+pub use audited_user_service::AuditedUserService;
+pub use audited_webhook_service::AuditedWebhookService;
+pub use context::RequestContext;
+
+/// Resource type of entries about user accounts.
+pub(crate) const USER_RESOURCE: &str = "user";
+
+/// Resource type of entries about sessions.
+pub(crate) const SESSION_RESOURCE: &str = "session";
+
+/// Resource type of entries about webhook subscriptions.
+pub(crate) const WEBHOOK_RESOURCE: &str = "webhook";
+
+/// Resource type of entries about roles.
+pub(crate) const ROLE_RESOURCE: &str = "role";
+
+/// Resource type of entries about tenants.
+pub(crate) const TENANT_RESOURCE: &str = "tenant";
@@ -0,0 +1,16 @@
+//! Audit service trait definition.
+
+use crate::dto::{AuditChainQuery, AuditChainReport, AuditLogListResponse, AuditLogQuery};
+use arcana_core::{ArcanaResult, Interface};
+use async_trait::async_trait;
+
+/// Audit service trait for reading the audit trail.
+#[async_trait]
+pub trait AuditService: Interface + Send + Sync {
+    /// Finds a page of audit trail entries, newest first.
+    async fn query_audit_logs(&self, query: AuditLogQuery) -> ArcanaResult<AuditLogListResponse>;
+
+    /// Verifies the hash chain of the entries in a time range and reports
+    /// the first broken link.
+    async fn verify_audit_chain(&self, query: AuditChainQuery) -> ArcanaResult<AuditChainReport>;
+}
@@ -1,10 +1,12 @@
 //! Authentication service trait.
 
 use crate::dto::{
-    AuthResponse, AuthUserInfo, LoginRequest, MessageResponse, RefreshTokenRequest, RegisterRequest,
+    AuthResponse, AuthUserInfo, LoginRequest, LoginResponse, MessageResponse, MfaLoginRequest,
+    PasswordResetConfirmRequest, PasswordResetRequest, RecoveryCodesResponse, RefreshTokenRequest,
+    RegisterRequest, ResendVerificationRequest, SessionInfo, TotpCodeRequest,
+    TotpEnrollmentResponse, VerifyEmailRequest,
 };
-use arcana_core::{ArcanaResult, Interface, UserId};
-use arcana_repository::UserRepository;
+use arcana_core::{ArcanaResult, Interface};
 use arcana_security::Claims;
 use async_trait::async_trait;
 
@@ -34,20 +36,92 @@ fn conflict_email_msg(email: &str) -> String {
 #[async_trait]
 pub trait AuthService: Interface + Send + Sync {
     /// Registers a new user.
+    ///
+    /// The account starts pending verification and a verification email is sent.
     async fn register(&self, request: RegisterRequest) -> ArcanaResult<AuthResponse>;
 
     /// Logs in a user.
-    async fn login(&self, request: LoginRequest) -> ArcanaResult<AuthResponse>;
+    ///
+    /// When the user has two-factor authentication enabled, returns a
+    /// short-lived challenge instead of tokens; see [`Self::complete_mfa_login`].
+    async fn login(&self, request: LoginRequest) -> ArcanaResult<LoginResponse>;
+
+    /// Completes a two-factor login with a TOTP or recovery code.
+    ///
+    /// The challenge token can only be exchanged once.
+    async fn complete_mfa_login(&self, request: MfaLoginRequest) -> ArcanaResult<AuthResponse>;
 
     /// Refreshes an access token.
     async fn refresh_token(&self, request: RefreshTokenRequest) -> ArcanaResult<AuthResponse>;
 
-    /// Validates an access token and returns claims.
+    /// Validates an access token (including revocation) and returns claims.
     async fn validate_token(&self, token: &str) -> ArcanaResult<Claims>;
 
-    /// Logs out a user (invalidates refresh token).
-    async fn logout(&self, user_id: UserId) -> ArcanaResult<MessageResponse>;
+    /// Logs out the user the claims belong to.
+    ///
+    /// Ends every session of the user: all refresh tokens and every access
+    /// token issued so far are revoked.
+    async fn logout(&self, claims: &Claims) -> ArcanaResult<MessageResponse>;
 
     /// Gets the current user from claims.
     async fn get_current_user(&self, claims: &Claims) -> ArcanaResult<AuthUserInfo>;
+
+    /// Lists the active sessions of the user the claims belong to, newest first.
+    async fn list_sessions(&self, claims: &Claims) -> ArcanaResult<Vec<SessionInfo>>;
+
+    /// Revokes one of the user's sessions, including its access tokens.
+    async fn revoke_session(&self, claims: &Claims, session_id: &str) -> ArcanaResult<MessageResponse>;
+
+    /// Revokes every session of the user except the one making the request.
+    async fn revoke_other_sessions(&self, claims: &Claims) -> ArcanaResult<MessageResponse>;
+
+    /// Verifies a user's email address with a token from the verification email.
+    async fn verify_email(&self, request: VerifyEmailRequest) -> ArcanaResult<MessageResponse>;
+
+    /// Sends the verification email again.
+    ///
+    /// Responds the same whether or not the address belongs to an unverified
+    /// account, so it cannot be used to probe for registered emails.
+    async fn resend_verification(
+        &self,
+        request: ResendVerificationRequest,
+    ) -> ArcanaResult<MessageResponse>;
+
+    /// Mails a single-use password reset link.
+    ///
+    /// Responds the same whether or not the address belongs to an account,
+    /// so it cannot be used to probe for registered emails.
+    async fn forgot_password(&self, request: PasswordResetRequest) -> ArcanaResult<MessageResponse>;
+
+    /// Sets a new password with a token from the password reset email.
+    ///
+    /// Signs the user out everywhere by revoking every refresh and access token.
+    async fn reset_password(
+        &self,
+        request: PasswordResetConfirmRequest,
+    ) -> ArcanaResult<MessageResponse>;
+
+    /// Starts TOTP enrolment with a new secret for the authenticator app.
+    ///
+    /// The secret has no effect until confirmed with [`Self::enable_totp`].
+    async fn enroll_totp(&self, claims: &Claims) -> ArcanaResult<TotpEnrollmentResponse>;
+
+    /// Confirms TOTP enrolment with a code from the authenticator app.
+    ///
+    /// Returns one-time recovery codes; they are not retrievable later.
+    async fn enable_totp(
+        &self,
+        claims: &Claims,
+        request: TotpCodeRequest,
+    ) -> ArcanaResult<RecoveryCodesResponse>;
+
+    /// Turns two-factor authentication off, given a TOTP or recovery code.
+    async fn disable_totp(&self, claims: &Claims, request: TotpCodeRequest) -> ArcanaResult<MessageResponse>;
+
+    /// Replaces the user's recovery codes, given a TOTP code.
+    async fn regenerate_recovery_codes(
+        &self,
+        claims: &Claims,
+        request: TotpCodeRequest,
+    ) -> ArcanaResult<RecoveryCodesResponse>;
 }
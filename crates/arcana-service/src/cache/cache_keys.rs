@@ -1,50 +1,82 @@
 //! Cache key generators for consistent key naming.
+//!
+//! User entries live under their tenant's namespace, so lookups by username
+//! or email never see another tenant's users and a tenant's entries can be
+//! dropped together.
 
-use arcana_core::UserId;
+use arcana_core::{TenantId, UserId, UserRole};
 
 /// Prefix for all cache keys to namespace them.
 const CACHE_PREFIX: &str = "arcana:cache";
 
+/// Prefix of the cache keys of a tenant.
+fn tenant_prefix(tenant_id: TenantId) -> String {
+    format!("{}:tenant:{}", CACHE_PREFIX, tenant_id)
+}
+
 /// Generate a cache key for a user by ID.
 #[must_use]
-pub fn user_by_id(id: UserId) -> String {
-    format!("{}:user:id:{}", CACHE_PREFIX, id)
+pub fn user_by_id(tenant_id: TenantId, id: UserId) -> String {
+    format!("{}:user:id:{}", tenant_prefix(tenant_id), id)
 }
 
 /// Generate a cache key for a user by username.
 #[must_use]
-pub fn user_by_username(username: &str) -> String {
-    format!("{}:user:username:{}", CACHE_PREFIX, username.to_lowercase())
+pub fn user_by_username(tenant_id: TenantId, username: &str) -> String {
+    format!("{}:user:username:{}", tenant_prefix(tenant_id), username.to_lowercase())
 }
 
 /// Generate a cache key for checking if a username exists.
 #[must_use]
-pub fn username_exists(username: &str) -> String {
-    format!("{}:exists:username:{}", CACHE_PREFIX, username.to_lowercase())
+pub fn username_exists(tenant_id: TenantId, username: &str) -> String {
+    format!("{}:exists:username:{}", tenant_prefix(tenant_id), username.to_lowercase())
 }
 
 /// Generate a cache key for checking if an email exists.
 #[must_use]
-pub fn email_exists(email: &str) -> String {
-    format!("{}:exists:email:{}", CACHE_PREFIX, email.to_lowercase())
+pub fn email_exists(tenant_id: TenantId, email: &str) -> String {
+    format!("{}:exists:email:{}", tenant_prefix(tenant_id), email.to_lowercase())
 }
 
 /// Pattern to invalidate all user-related cache entries for a specific user ID.
 #[must_use]
-pub fn user_invalidation_pattern(id: UserId) -> String {
-    format!("{}:user:*:{}*", CACHE_PREFIX, id)
+pub fn user_invalidation_pattern(tenant_id: TenantId, id: UserId) -> String {
+    format!("{}:user:*:{}*", tenant_prefix(tenant_id), id)
 }
 
-/// Pattern to invalidate all username existence checks.
+/// Pattern to invalidate all username existence checks of a tenant.
 #[must_use]
-pub fn username_exists_pattern() -> String {
-    format!("{}:exists:username:*", CACHE_PREFIX)
+pub fn username_exists_pattern(tenant_id: TenantId) -> String {
+    format!("{}:exists:username:*", tenant_prefix(tenant_id))
 }
 
-/// Pattern to invalidate all email existence checks.
+/// Pattern to invalidate all email existence checks of a tenant.
 #[must_use]
-pub fn email_exists_pattern() -> String {
-    format!("{}:exists:email:*", CACHE_PREFIX)
+pub fn email_exists_pattern(tenant_id: TenantId) -> String {
+    format!("{}:exists:email:*", tenant_prefix(tenant_id))
+}
+
+/// Pattern to invalidate all cache entries of a tenant.
+#[must_use]
+pub fn tenant_pattern(tenant_id: TenantId) -> String {
+    format!("{}:*", tenant_prefix(tenant_id))
+}
+/// Generate a cache key for the effective permissions of a user with a role.
+#[must_use]
+pub fn user_permissions(id: UserId, role: UserRole) -> String {
+    format!("{}:permissions:{}:{}", CACHE_PREFIX, id, role)
+}
+
+/// Pattern to invalidate the cached permissions of a user.
+#[must_use]
+pub fn user_permissions_pattern(id: UserId) -> String {
+    format!("{}:permissions:{}:*", CACHE_PREFIX, id)
+}
+
+/// Pattern to invalidate the cached permissions of all users.
+#[must_use]
+pub fn permissions_pattern() -> String {
+    format!("{}:permissions:*", CACHE_PREFIX)
 }
 
 #[cfg(test)]
@@ -54,46 +86,65 @@ mod tests {
     #[test]
     fn test_user_by_id_key() {
         let id = UserId::new();
-        let key = user_by_id(id);
-        assert!(key.starts_with("arcana:cache:user:id:"));
+        let key = user_by_id(TenantId::DEFAULT, id);
+        assert!(key.starts_with(&format!("arcana:cache:tenant:{}:user:id:", TenantId::DEFAULT)));
         assert!(key.contains(&id.to_string()));
     }
 
     #[test]
     fn test_user_by_username_key() {
-        let key = user_by_username("TestUser");
-        assert_eq!(key, "arcana:cache:user:username:testuser");
+        let tenant_id = TenantId::new();
+        let key = user_by_username(tenant_id, "TestUser");
+        assert_eq!(key, format!("arcana:cache:tenant:{}:user:username:testuser", tenant_id));
     }
 
     #[test]
     fn test_username_exists_key() {
-        let key = username_exists("Admin");
-        assert_eq!(key, "arcana:cache:exists:username:admin");
+        let key = username_exists(TenantId::DEFAULT, "Admin");
+        assert_eq!(key, format!("arcana:cache:tenant:{}:exists:username:admin", TenantId::DEFAULT));
     }
 
     #[test]
     fn test_email_exists_key() {
-        let key = email_exists("Test@Example.COM");
-        assert_eq!(key, "arcana:cache:exists:email:test@example.com");
+        let key = email_exists(TenantId::DEFAULT, "Test@Example.COM");
+        assert_eq!(key, format!("arcana:cache:tenant:{}:exists:email:test@example.com", TenantId::DEFAULT));
+    }
+
+    #[test]
+    fn test_user_keys_differ_between_tenants() {
+        let id = UserId::new();
+        let other = TenantId::new();
+        assert_ne!(user_by_id(TenantId::DEFAULT, id), user_by_id(other, id));
+        assert_ne!(username_exists(TenantId::DEFAULT, "alice"), username_exists(other, "alice"));
+        assert!(user_by_username(other, "alice").starts_with(tenant_pattern(other).trim_end_matches('*')));
     }
 
     #[test]
     fn test_user_invalidation_pattern() {
         let id = UserId::new();
-        let pattern = user_invalidation_pattern(id);
-        assert!(pattern.starts_with("arcana:cache:user:*:"));
+        let pattern = user_invalidation_pattern(TenantId::DEFAULT, id);
+        assert!(pattern.starts_with(&format!("arcana:cache:tenant:{}:user:*:", TenantId::DEFAULT)));
         assert!(pattern.contains(&id.to_string()));
     }
 
     #[test]
     fn test_username_exists_pattern() {
-        let pattern = username_exists_pattern();
-        assert_eq!(pattern, "arcana:cache:exists:username:*");
+        let pattern = username_exists_pattern(TenantId::DEFAULT);
+        assert_eq!(pattern, format!("arcana:cache:tenant:{}:exists:username:*", TenantId::DEFAULT));
     }
 
     #[test]
     fn test_email_exists_pattern() {
-        let pattern = email_exists_pattern();
-        assert_eq!(pattern, "arcana:cache:exists:email:*");
+        let pattern = email_exists_pattern(TenantId::DEFAULT);
+        assert_eq!(pattern, format!("arcana:cache:tenant:{}:exists:email:*", TenantId::DEFAULT));
+    }
+
+    #[test]
+    fn test_user_permissions_key() {
+        let id = UserId::new();
+        let key = user_permissions(id, UserRole::Moderator);
+        assert_eq!(key, format!("arcana:cache:permissions:{}:moderator", id));
+        assert!(key.starts_with(user_permissions_pattern(id).trim_end_matches('*')));
+        assert!(key.starts_with(permissions_pattern().trim_end_matches('*')));
     }
 }
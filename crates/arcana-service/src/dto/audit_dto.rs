@@ -0,0 +1,177 @@
+//! Audit trail DTOs.
+
+use arcana_core::{AuditAction, AuditChainBreak, AuditLog, AuditLogFilter, AuditLogId, UserId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Query for a page of the audit trail.
+///
+/// Every filter that is set must match. Pages are read newest first; pass
+/// the `next_cursor` of a page as `cursor` to get the following one.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AuditLogQuery {
+    /// Only entries of this user.
+    pub user_id: Option<UserId>,
+    /// Only entries of this action.
+    pub action: Option<AuditAction>,
+    /// Only entries about this resource type.
+    #[validate(length(max = 64))]
+    pub resource_type: Option<String>,
+    /// Only successful (`true`) or failed (`false`) operations.
+    pub success: Option<bool>,
+    /// Only entries at or after this time.
+    pub from: Option<DateTime<Utc>>,
+    /// Only entries before this time.
+    pub to: Option<DateTime<Utc>>,
+    /// Cursor returned with the previous page.
+    pub cursor: Option<String>,
+    /// Maximum number of entries in the page.
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100"))]
+    pub limit: Option<usize>,
+}
+
+impl AuditLogQuery {
+    /// The default page size.
+    pub const DEFAULT_LIMIT: usize = 20;
+    /// The maximum allowed page size.
+    pub const MAX_LIMIT: usize = 100;
+
+    /// Returns the filter part of the query.
+    #[must_use]
+    pub fn filter(&self) -> AuditLogFilter {
+        AuditLogFilter {
+            user_id: self.user_id,
+            action: self.action,
+            resource_type: self.resource_type.clone(),
+            success: self.success,
+            from: self.from,
+            to: self.to,
+        }
+    }
+
+    /// Returns the requested page size.
+    #[must_use]
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(Self::DEFAULT_LIMIT).min(Self::MAX_LIMIT)
+    }
+}
+
+/// An audit trail entry.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogResponse {
+    pub id: AuditLogId,
+    pub user_id: Option<UserId>,
+    pub action: AuditAction,
+    pub resource_type: String,
+    pub resource_id: Option<String>,
+    #[schema(value_type = Option<Object>)]
+    pub details: Option<JsonValue>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub success: bool,
+    pub error_message: Option<String>,
+    pub timestamp: DateTime<Utc>,
+    /// Position in the hash chain.
+    pub sequence: Option<u64>,
+    pub prev_hash: Option<String>,
+    pub hash: Option<String>,
+}
+
+impl From<AuditLog> for AuditLogResponse {
+    fn from(entry: AuditLog) -> Self {
+        Self {
+            id: entry.id,
+            user_id: entry.user_id,
+            action: entry.action,
+            resource_type: entry.resource_type,
+            resource_id: entry.resource_id,
+            details: entry.details,
+            ip_address: entry.ip_address,
+            user_agent: entry.user_agent,
+            success: entry.success,
+            error_message: entry.error_message,
+            timestamp: entry.timestamp,
+            sequence: entry.sequence,
+            prev_hash: entry.prev_hash,
+            hash: entry.hash,
+        }
+    }
+}
+
+/// A page of the audit trail.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogListResponse {
+    pub entries: Vec<AuditLogResponse>,
+    /// Cursor for the next page, absent on the last page.
+    pub next_cursor: Option<String>,
+}
+
+/// Time range of the audit trail whose hash chain to verify.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct AuditChainQuery {
+    /// Only entries at or after this time.
+    pub from: Option<DateTime<Utc>>,
+    /// Only entries before this time.
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Outcome of verifying the audit hash chain.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditChainReport {
+    /// Whether every link walked is intact.
+    pub intact: bool,
+    /// Number of entries verified.
+    pub checked: u64,
+    /// Sequence number the walk started at, absent if there was nothing to walk.
+    pub first_sequence: Option<u64>,
+    /// Sequence number the walk should have reached.
+    pub last_sequence: Option<u64>,
+    /// The first broken link.
+    pub broken_link: Option<AuditChainBreak>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_limit() {
+        assert_eq!(AuditLogQuery::default().limit(), AuditLogQuery::DEFAULT_LIMIT);
+
+        let query = AuditLogQuery { limit: Some(1000), ..Default::default() };
+        assert_eq!(query.limit(), AuditLogQuery::MAX_LIMIT);
+        assert!(query.validate().is_err());
+    }
+
+    #[test]
+    fn test_query_filter() {
+        let user_id = UserId::new();
+        let query = AuditLogQuery {
+            user_id: Some(user_id),
+            action: Some(AuditAction::Login),
+            success: Some(false),
+            cursor: Some("ignored".to_string()),
+            ..Default::default()
+        };
+
+        let filter = query.filter();
+        assert_eq!(filter.user_id, Some(user_id));
+        assert_eq!(filter.action, Some(AuditAction::Login));
+        assert_eq!(filter.success, Some(false));
+        assert_eq!(filter.resource_type, None);
+    }
+
+    #[test]
+    fn test_response_from_entry() {
+        let entry = AuditLog::failure(None, AuditAction::Login, "user", None, "Invalid credentials")
+            .with_ip_address("10.0.0.1");
+        let response = AuditLogResponse::from(entry.clone());
+
+        assert_eq!(response.id, entry.id);
+        assert!(!response.success);
+        assert_eq!(response.ip_address.as_deref(), Some("10.0.0.1"));
+    }
+}
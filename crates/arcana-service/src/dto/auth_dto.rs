@@ -1,7 +1,8 @@
 //! Authentication-related DTOs.
 
 use arcana_core::UserId;
-use arcana_core::UserRole;
+use arcana_core::{OAuthToken, UserRole};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
@@ -17,6 +18,11 @@ pub struct LoginRequest {
 
     /// Optional device identifier for token tracking.
     pub device_id: Option<String>,
+
+    /// Client IP address, filled in by the transport for brute-force protection.
+    #[serde(skip)]
+    #[schema(ignore)]
+    pub ip_address: Option<String>,
 }
 
 /// Registration request.
@@ -55,6 +61,78 @@ pub struct AuthResponse {
     pub user: AuthUserInfo,
 }
 
+/// Outcome of a password login.
+///
+/// Accounts with two-factor authentication get a challenge to complete with
+/// a second factor instead of tokens.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum LoginResponse {
+    /// The user is signed in.
+    Authenticated(AuthResponse),
+    /// A second factor is required; see [`MfaLoginRequest`].
+    MfaRequired(MfaChallengeResponse),
+}
+
+impl LoginResponse {
+    /// Returns the auth response if no second factor is required.
+    #[must_use]
+    pub fn into_authenticated(self) -> Option<AuthResponse> {
+        match self {
+            Self::Authenticated(response) => Some(response),
+            Self::MfaRequired(_) => None,
+        }
+    }
+}
+
+/// Challenge returned by a password login when a second factor is required.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MfaChallengeResponse {
+    /// Always true; lets clients tell the challenge from a token pair.
+    pub mfa_required: bool,
+    /// Short-lived token to present with the second factor.
+    pub mfa_token: String,
+    /// Seconds until the challenge expires.
+    pub expires_in: i64,
+}
+
+/// Second step of a two-factor login.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct MfaLoginRequest {
+    /// Challenge token from the password login.
+    #[validate(length(min = 1, message = "MFA token is required"))]
+    pub mfa_token: String,
+
+    /// TOTP code from the authenticator app, or an unused recovery code.
+    #[validate(length(min = 1, message = "Code is required"))]
+    pub code: String,
+
+    /// Optional device identifier for token tracking.
+    pub device_id: Option<String>,
+}
+
+/// A TOTP secret awaiting confirmation.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TotpEnrollmentResponse {
+    /// Base32 secret for manual entry.
+    pub secret: String,
+    /// `otpauth://` URI to render as a QR code.
+    pub otpauth_uri: String,
+}
+
+/// Request carrying a TOTP code (or, where accepted, a recovery code).
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct TotpCodeRequest {
+    #[validate(length(min = 1, message = "Code is required"))]
+    pub code: String,
+}
+
+/// Freshly issued recovery codes, shown to the user only once.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
 /// User info included in auth response.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct AuthUserInfo {
@@ -66,6 +144,56 @@ pub struct AuthUserInfo {
     pub last_name: Option<String>,
 }
 
+/// An active session (refresh token family) of the current user.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SessionInfo {
+    /// Session ID.
+    pub id: String,
+    /// Device identifier given at login.
+    pub device_id: Option<String>,
+    /// User agent of the client.
+    pub user_agent: Option<String>,
+    /// IP address of the client.
+    pub ip_address: Option<String>,
+    /// When the session last signed in or refreshed its tokens.
+    pub last_active_at: DateTime<Utc>,
+    /// When the session expires unless refreshed.
+    pub expires_at: DateTime<Utc>,
+    /// Whether this is the session making the request.
+    pub current: bool,
+}
+
+impl SessionInfo {
+    /// Creates session info from the live refresh token of a session.
+    #[must_use]
+    pub fn from_token(token: OAuthToken, current_session_id: Option<&str>) -> Self {
+        Self {
+            current: current_session_id == Some(token.family_id.as_str()),
+            last_active_at: token.last_used_at.unwrap_or(token.created_at),
+            id: token.family_id,
+            device_id: token.device_id,
+            user_agent: token.user_agent,
+            ip_address: token.ip_address,
+            expires_at: token.expires_at,
+        }
+    }
+}
+
+/// Email verification request.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct VerifyEmailRequest {
+    /// Token from the verification email.
+    #[validate(length(min = 1, message = "Verification token is required"))]
+    pub token: String,
+}
+
+/// Request to send the verification email again.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ResendVerificationRequest {
+    #[validate(email(message = "Invalid email address"))]
+    pub email: String,
+}
+
 /// Password reset request.
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct PasswordResetRequest {
@@ -76,6 +204,8 @@ pub struct PasswordResetRequest {
 /// Password reset confirmation.
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct PasswordResetConfirmRequest {
+    /// Token from the password reset email.
+    #[validate(length(min = 1, message = "Reset token is required"))]
     pub token: String,
 
     #[validate(length(min = 8, message = "New password must be at least 8 characters"))]
@@ -116,6 +246,7 @@ mod tests {
             username_or_email: "testuser".to_string(),
             password: "password123".to_string(),
             device_id: Some("device-123".to_string()),
+            ip_address: None,
         };
 
         assert!(request.validate().is_ok());
@@ -127,6 +258,7 @@ mod tests {
             username_or_email: "".to_string(),
             password: "password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         assert!(request.validate().is_err());
@@ -138,6 +270,7 @@ mod tests {
             username_or_email: "testuser".to_string(),
             password: "".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         assert!(request.validate().is_err());
@@ -233,6 +366,33 @@ mod tests {
         assert!(request.validate().is_err());
     }
 
+    #[test]
+    fn test_verify_email_request_requires_token() {
+        let request = VerifyEmailRequest { token: String::new() };
+        assert!(request.validate().is_err());
+
+        let request = VerifyEmailRequest { token: "token".to_string() };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_resend_verification_request_invalid_email() {
+        let request = ResendVerificationRequest { email: "not-an-email".to_string() };
+        assert!(request.validate().is_err());
+
+        let request = ResendVerificationRequest { email: "user@example.com".to_string() };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_password_reset_confirm_requires_token() {
+        let request = PasswordResetConfirmRequest {
+            token: String::new(),
+            new_password: "NewPassword123".to_string(),
+        };
+        assert!(request.validate().is_err());
+    }
+
     #[test]
     fn test_refresh_token_request() {
         let request = RefreshTokenRequest {
@@ -295,12 +455,58 @@ mod tests {
         assert_eq!(response.user.username, "testuser");
     }
 
+    #[test]
+    fn test_login_response_serialization() {
+        let challenge = LoginResponse::MfaRequired(MfaChallengeResponse {
+            mfa_required: true,
+            mfa_token: "challenge".to_string(),
+            expires_in: 300,
+        });
+
+        let json = serde_json::to_value(&challenge).unwrap();
+        assert_eq!(json["mfa_required"], true);
+        assert_eq!(json["mfa_token"], "challenge");
+        assert!(challenge.into_authenticated().is_none());
+    }
+
+    #[test]
+    fn test_mfa_login_request_requires_code() {
+        let request = MfaLoginRequest {
+            mfa_token: "challenge".to_string(),
+            code: String::new(),
+            device_id: None,
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn test_session_info_from_token() {
+        let token = OAuthToken::new(
+            UserId::new(),
+            "hash".to_string(),
+            "family-1".to_string(),
+            Utc::now() + chrono::Duration::days(7),
+            Some("device-1".to_string()),
+            None,
+            None,
+        );
+
+        let current = SessionInfo::from_token(token.clone(), Some("family-1"));
+        assert_eq!(current.id, "family-1");
+        assert_eq!(current.device_id.as_deref(), Some("device-1"));
+        assert_eq!(current.last_active_at, token.created_at);
+        assert!(current.current);
+
+        assert!(!SessionInfo::from_token(token, Some("family-2")).current);
+    }
+
     #[test]
     fn test_auth_dto_serialization() {
         let request = LoginRequest {
             username_or_email: "testuser".to_string(),
             password: "password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
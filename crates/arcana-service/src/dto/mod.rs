@@ -2,6 +2,14 @@
 
 mod user_dto;
 mod auth_dto;
+mod audit_dto;
+mod webhook_dto;
+mod role_dto;
+mod tenant_dto;
 
 pub use user_dto::*;
 pub use auth_dto::*;
+pub use audit_dto::*;
+pub use webhook_dto::*;
+pub use role_dto::*;
+pub use tenant_dto::*;
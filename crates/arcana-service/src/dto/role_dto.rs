@@ -0,0 +1,120 @@
+//! Role and permission grant DTOs.
+
+use arcana_core::{Permission, PermissionSet, Role, RoleId, UserId, UserRole};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Request to create a custom role.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateRoleRequest {
+    /// Unique name: lowercase letters, digits, `-` and `_`.
+    #[validate(length(min = 2, max = 64, message = "Role name must be 2-64 characters"))]
+    pub name: String,
+
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+
+    /// Permissions granted by the role (e.g. `user:read`).
+    pub permissions: Vec<Permission>,
+}
+
+/// Request to change a role; unset fields are kept.
+///
+/// Built-in roles can only have their description and permissions changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateRoleRequest {
+    #[validate(length(min = 2, max = 64, message = "Role name must be 2-64 characters"))]
+    pub name: Option<String>,
+
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+
+    /// Replaces the permissions granted by the role.
+    pub permissions: Option<Vec<Permission>>,
+}
+
+/// Request to assign a custom role to a user.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssignRoleRequest {
+    pub role_id: RoleId,
+}
+
+/// Request to grant a permission to a user directly.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GrantPermissionRequest {
+    pub permission: Permission,
+}
+
+/// Role response DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RoleResponse {
+    pub id: RoleId,
+    pub name: String,
+    pub description: Option<String>,
+    pub permissions: PermissionSet,
+    /// Whether this is the role of a built-in user role.
+    pub built_in: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Role> for RoleResponse {
+    fn from(role: Role) -> Self {
+        Self {
+            id: role.id,
+            name: role.name,
+            description: role.description,
+            permissions: role.permissions,
+            built_in: role.built_in,
+            created_at: role.created_at,
+            updated_at: role.updated_at,
+        }
+    }
+}
+
+/// Role list response, built-in roles first.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RoleListResponse {
+    pub roles: Vec<RoleResponse>,
+}
+
+impl From<Vec<Role>> for RoleListResponse {
+    fn from(roles: Vec<Role>) -> Self {
+        Self {
+            roles: roles.into_iter().map(RoleResponse::from).collect(),
+        }
+    }
+}
+
+/// Permission grants of a user.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserPermissionsResponse {
+    pub user_id: UserId,
+    /// Built-in role of the user.
+    pub role: UserRole,
+    /// Custom roles assigned to the user.
+    pub roles: Vec<RoleResponse>,
+    /// Permissions granted to the user directly.
+    pub granted: PermissionSet,
+    /// Everything the user is allowed to do.
+    pub effective: PermissionSet,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_request_deserializes_permission_names() {
+        let request: CreateRoleRequest = serde_json::from_str(
+            r#"{"name": "support", "description": null, "permissions": ["user:read", "user:update"]}"#,
+        )
+        .unwrap();
+        assert_eq!(request.permissions, vec![Permission::UserRead, Permission::UserUpdate]);
+        assert!(request.validate().is_ok());
+
+        assert!(serde_json::from_str::<CreateRoleRequest>(r#"{"name": "support", "permissions": ["user:fly"]}"#).is_err());
+    }
+}
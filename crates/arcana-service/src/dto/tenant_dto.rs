@@ -0,0 +1,77 @@
+//! Tenant DTOs.
+
+use arcana_core::{Page, Tenant, TenantId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Request to create a tenant.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateTenantRequest {
+    /// Unique URL-safe name: lowercase letters, digits and `-`.
+    #[validate(length(min = 2, max = 63, message = "Tenant slug must be 2-63 characters"))]
+    pub slug: String,
+
+    #[validate(length(min = 1, max = 255, message = "Tenant name must be 1-255 characters"))]
+    pub name: String,
+}
+
+/// Request to change a tenant; unset fields are kept.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateTenantRequest {
+    #[validate(length(min = 1, max = 255, message = "Tenant name must be 1-255 characters"))]
+    pub name: Option<String>,
+
+    /// Stops (`false`) or allows (`true`) sign-ins of the tenant's users.
+    pub active: Option<bool>,
+}
+
+/// Tenant response DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TenantResponse {
+    pub id: TenantId,
+    pub slug: String,
+    pub name: String,
+    pub active: bool,
+    /// Whether this is the default tenant.
+    pub default: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Tenant> for TenantResponse {
+    fn from(tenant: Tenant) -> Self {
+        Self {
+            default: tenant.is_default(),
+            id: tenant.id,
+            slug: tenant.slug,
+            name: tenant.name,
+            active: tenant.active,
+            created_at: tenant.created_at,
+            updated_at: tenant.updated_at,
+        }
+    }
+}
+
+/// Tenant list response with pagination.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TenantListResponse {
+    pub tenants: Vec<TenantResponse>,
+    pub page: usize,
+    pub size: usize,
+    pub total_elements: u64,
+    pub total_pages: u64,
+}
+
+impl From<Page<Tenant>> for TenantListResponse {
+    fn from(page: Page<Tenant>) -> Self {
+        Self {
+            tenants: page.content.into_iter().map(TenantResponse::from).collect(),
+            page: page.info.page,
+            size: page.info.size,
+            total_elements: page.info.total_elements,
+            total_pages: page.info.total_pages,
+        }
+    }
+}
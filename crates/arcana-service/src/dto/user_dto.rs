@@ -1,6 +1,6 @@
 //! User-related DTOs.
 
-use arcana_core::UserId;
+use arcana_core::{TenantId, UserId};
 use arcana_core::{User, UserRole, UserStatus};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -66,6 +66,7 @@ pub struct ChangePasswordRequest {
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UserResponse {
     pub id: UserId,
+    pub tenant_id: TenantId,
     pub username: String,
     pub email: String,
     pub first_name: Option<String>,
@@ -82,6 +83,7 @@ impl From<User> for UserResponse {
     fn from(user: User) -> Self {
         Self {
             id: user.id,
+            tenant_id: user.tenant_id,
             username: user.username,
             email: user.email.to_string(),
             first_name: user.first_name,
@@ -100,6 +102,7 @@ impl From<&User> for UserResponse {
     fn from(user: &User) -> Self {
         Self {
             id: user.id,
+            tenant_id: user.tenant_id,
             username: user.username.clone(),
             email: user.email.to_string(),
             first_name: user.first_name.clone(),
@@ -0,0 +1,192 @@
+//! Webhook DTOs.
+
+use arcana_core::{Page, WebhookDelivery, WebhookDeliveryId, WebhookDeliveryStatus, WebhookSubscription, WebhookSubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Request to subscribe an endpoint to domain events.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateWebhookRequest {
+    /// URL the events are posted to.
+    #[validate(url(message = "Invalid webhook URL"), length(max = 2048))]
+    pub url: String,
+
+    /// Event types to deliver (e.g. `user.created`), or `*` for all of them.
+    #[validate(length(min = 1, message = "At least one event type is required"))]
+    pub event_types: Vec<String>,
+
+    /// Shared secret the payloads are signed with.
+    #[validate(length(min = 16, max = 255, message = "Secret must be 16-255 characters"))]
+    pub secret: String,
+
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+}
+
+/// Request to change a webhook subscription; unset fields are kept.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateWebhookRequest {
+    #[validate(url(message = "Invalid webhook URL"), length(max = 2048))]
+    pub url: Option<String>,
+
+    #[validate(length(min = 1, message = "At least one event type is required"))]
+    pub event_types: Option<Vec<String>>,
+
+    #[validate(length(min = 16, max = 255, message = "Secret must be 16-255 characters"))]
+    pub secret: Option<String>,
+
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+
+    /// Pauses (`false`) or resumes (`true`) deliveries.
+    pub active: Option<bool>,
+}
+
+/// Webhook subscription response DTO. The secret is never returned.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookResponse {
+    pub id: WebhookSubscriptionId,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub description: Option<String>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<WebhookSubscription> for WebhookResponse {
+    fn from(subscription: WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id,
+            url: subscription.url,
+            event_types: subscription.event_types,
+            description: subscription.description,
+            active: subscription.active,
+            created_at: subscription.created_at,
+            updated_at: subscription.updated_at,
+        }
+    }
+}
+
+/// Webhook subscription list response with pagination.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookListResponse {
+    pub webhooks: Vec<WebhookResponse>,
+    pub page: usize,
+    pub size: usize,
+    pub total_elements: u64,
+    pub total_pages: u64,
+}
+
+impl From<Page<WebhookSubscription>> for WebhookListResponse {
+    fn from(page: Page<WebhookSubscription>) -> Self {
+        Self {
+            webhooks: page.content.into_iter().map(WebhookResponse::from).collect(),
+            page: page.info.page,
+            size: page.info.size,
+            total_elements: page.info.total_elements,
+            total_pages: page.info.total_pages,
+        }
+    }
+}
+
+/// One delivery attempt of the delivery log.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryResponse {
+    pub id: WebhookDeliveryId,
+    /// ID of the event, also sent in the `X-Arcana-Delivery` header.
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub attempt: u32,
+    pub status: WebhookDeliveryStatus,
+    /// HTTP status the endpoint answered with, if it answered.
+    pub response_status: Option<u16>,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+    pub attempted_at: DateTime<Utc>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type,
+            attempt: delivery.attempt,
+            status: delivery.status,
+            response_status: delivery.response_status,
+            error: delivery.error,
+            duration_ms: delivery.duration_ms,
+            attempted_at: delivery.attempted_at,
+        }
+    }
+}
+
+/// Delivery log page, newest attempt first.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryListResponse {
+    pub deliveries: Vec<WebhookDeliveryResponse>,
+    pub page: usize,
+    pub size: usize,
+    pub total_elements: u64,
+    pub total_pages: u64,
+}
+
+impl From<Page<WebhookDelivery>> for WebhookDeliveryListResponse {
+    fn from(page: Page<WebhookDelivery>) -> Self {
+        Self {
+            deliveries: page.content.into_iter().map(WebhookDeliveryResponse::from).collect(),
+            page: page.info.page,
+            size: page.info.size,
+            total_elements: page.info.total_elements,
+            total_pages: page.info.total_pages,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_request() -> CreateWebhookRequest {
+        CreateWebhookRequest {
+            url: "https://example.com/hooks".to_string(),
+            event_types: vec!["user.created".to_string()],
+            secret: "0123456789abcdef".to_string(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_create_request_validation() {
+        assert!(create_request().validate().is_ok());
+
+        let mut request = create_request();
+        request.url = "not a url".to_string();
+        assert!(request.validate().is_err());
+
+        let mut request = create_request();
+        request.event_types.clear();
+        assert!(request.validate().is_err());
+
+        let mut request = create_request();
+        request.secret = "short".to_string();
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn test_response_omits_secret() {
+        let subscription = WebhookSubscription::new(
+            "https://example.com/hooks".to_string(),
+            vec!["*".to_string()],
+            "0123456789abcdef".to_string(),
+            None,
+        );
+        let json = serde_json::to_string(&WebhookResponse::from(subscription)).unwrap();
+        assert!(!json.contains("secret"));
+        assert!(!json.contains("0123456789abcdef"));
+    }
+}
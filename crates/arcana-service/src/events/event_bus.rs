@@ -0,0 +1,321 @@
+//! In-process domain event bus.
+
+use arcana_core::{ArcanaResult, DomainEvent, EventHandler, EventPublisher, Interface};
+use async_trait::async_trait;
+use futures::FutureExt;
+use shaku::Component;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use tracing::{debug, error, warn};
+
+/// Handle of a subscription, used to remove it again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+/// Handler run inline while an event is published.
+pub type SyncEventHandler = dyn Fn(&dyn DomainEvent) -> ArcanaResult<()> + Send + Sync;
+
+/// A handler subscribed to the bus.
+///
+/// Handlers see every published event; the typed helpers of [`EventBusExt`]
+/// wrap handlers so that they skip events of other types.
+#[derive(Clone)]
+pub enum EventSubscriber {
+    /// Called inline, without yielding to the runtime.
+    Sync(Arc<SyncEventHandler>),
+    /// Awaited before the next handler runs.
+    Async(Arc<dyn EventHandler<dyn DomainEvent>>),
+}
+
+impl std::fmt::Debug for EventSubscriber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sync(_) => f.write_str("EventSubscriber::Sync"),
+            Self::Async(_) => f.write_str("EventSubscriber::Async"),
+        }
+    }
+}
+
+/// Interface of the domain event bus.
+///
+/// Publishing goes through [`EventPublisher`]; the generic subscription
+/// helpers live in [`EventBusExt`].
+pub trait EventBusInterface: EventPublisher + Interface + Send + Sync {
+    /// Adds a handler that runs after all handlers subscribed before it.
+    fn subscribe_handler(&self, subscriber: EventSubscriber) -> SubscriptionId;
+
+    /// Removes a handler. Returns `false` if it was not subscribed.
+    fn unsubscribe(&self, id: SubscriptionId) -> bool;
+}
+
+/// Typed subscription helpers and fire-and-forget publishing.
+#[async_trait]
+pub trait EventBusExt: EventBusInterface {
+    /// Subscribes an async handler to events of type `E`.
+    fn subscribe<E: DomainEvent + 'static>(&self, handler: Arc<dyn EventHandler<E>>) -> SubscriptionId {
+        self.subscribe_handler(EventSubscriber::Async(Arc::new(TypedHandler { handler, _event: PhantomData })))
+    }
+
+    /// Subscribes a synchronous function to events of type `E`.
+    fn subscribe_fn<E, F>(&self, handler: F) -> SubscriptionId
+    where
+        E: DomainEvent + 'static,
+        F: Fn(&E) -> ArcanaResult<()> + Send + Sync + 'static,
+    {
+        self.subscribe_handler(EventSubscriber::Sync(Arc::new(move |event: &dyn DomainEvent| {
+            event.downcast_ref::<E>().map_or(Ok(()), &handler)
+        })))
+    }
+
+    /// Subscribes an async handler to every event.
+    fn subscribe_all(&self, handler: Arc<dyn EventHandler<dyn DomainEvent>>) -> SubscriptionId {
+        self.subscribe_handler(EventSubscriber::Async(handler))
+    }
+
+    /// Publishes an event, logging instead of returning a failure.
+    ///
+    /// For services that emit events after the fact: the operation has
+    /// already succeeded and must not be reported as failed.
+    async fn emit<E: DomainEvent + 'static>(&self, event: E) {
+        let event_type = event.event_type();
+        if let Err(e) = self.publish(Box::new(event)).await {
+            warn!(target: "arcana::events", event_type, "Failed to publish event: {}", e);
+        }
+    }
+}
+
+impl<T: EventBusInterface + ?Sized> EventBusExt for T {}
+
+/// Adapts a handler of one event type to the untyped bus.
+struct TypedHandler<E: DomainEvent> {
+    handler: Arc<dyn EventHandler<E>>,
+    _event: PhantomData<fn(&E)>,
+}
+
+#[async_trait]
+impl<E: DomainEvent + 'static> EventHandler<dyn DomainEvent> for TypedHandler<E> {
+    async fn handle(&self, event: &dyn DomainEvent) -> ArcanaResult<()> {
+        match event.downcast_ref::<E>() {
+            Some(event) => self.handler.handle(event).await,
+            None => Ok(()),
+        }
+    }
+}
+
+/// Event bus delivering domain events to in-process handlers.
+///
+/// Each event is handed to every handler in subscription order, one handler
+/// at a time, and [`publish`](EventPublisher::publish) returns once all of
+/// them are done; [`publish_all`](EventPublisher::publish_all) delivers its
+/// events in order. A handler that fails or panics is logged and skipped, so
+/// it neither stops the other handlers nor fails the publisher.
+#[derive(Component, Default)]
+#[shaku(interface = EventBusInterface)]
+pub struct EventBus {
+    #[shaku(default)]
+    subscribers: RwLock<Vec<(SubscriptionId, EventSubscriber)>>,
+    #[shaku(default)]
+    next_id: AtomicU64,
+}
+
+impl EventBus {
+    /// Creates a bus without subscribers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of subscribed handlers.
+    #[must_use]
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Runs one handler, containing its failures.
+    async fn dispatch(id: SubscriptionId, subscriber: &EventSubscriber, event: &(dyn DomainEvent + 'static)) {
+        let result = match subscriber {
+            EventSubscriber::Sync(handler) => panic::catch_unwind(AssertUnwindSafe(|| handler(event))),
+            EventSubscriber::Async(handler) => AssertUnwindSafe(handler.handle(event)).catch_unwind().await,
+        };
+
+        match result {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => error!(
+                target: "arcana::events",
+                event_type = event.event_type(),
+                subscription = id.0,
+                "Event handler failed: {}",
+                e
+            ),
+            Err(_) => error!(
+                target: "arcana::events",
+                event_type = event.event_type(),
+                subscription = id.0,
+                "Event handler panicked"
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl EventPublisher for EventBus {
+    async fn publish(&self, event: Box<dyn DomainEvent>) -> ArcanaResult<()> {
+        // Handlers may subscribe or unsubscribe while the event is delivered
+        let subscribers = self.subscribers.read().unwrap_or_else(|e| e.into_inner()).clone();
+        debug!(
+            "Publishing {} for {} to {} handlers",
+            event.event_type(),
+            event.aggregate_id(),
+            subscribers.len()
+        );
+
+        for (id, subscriber) in &subscribers {
+            Self::dispatch(*id, subscriber, event.as_ref()).await;
+        }
+        Ok(())
+    }
+
+    async fn publish_all(&self, events: Vec<Box<dyn DomainEvent>>) -> ArcanaResult<()> {
+        for event in events {
+            self.publish(event).await?;
+        }
+        Ok(())
+    }
+}
+
+impl EventBusInterface for EventBus {
+    fn subscribe_handler(&self, subscriber: EventSubscriber) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        self.subscribers
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((id, subscriber));
+        id
+    }
+
+    fn unsubscribe(&self, id: SubscriptionId) -> bool {
+        let mut subscribers = self.subscribers.write().unwrap_or_else(|e| e.into_inner());
+        let before = subscribers.len();
+        subscribers.retain(|(subscribed, _)| *subscribed != id);
+        subscribers.len() != before
+    }
+}
+
+impl std::fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBus")
+            .field("subscribers", &self.subscriber_count())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{ArcanaError, UserCreated, UserDeleted, UserId};
+    use std::sync::Mutex;
+
+    /// Handler that records what it saw in a shared log.
+    struct Recorder {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler<UserCreated> for Recorder {
+        async fn handle(&self, event: &UserCreated) -> ArcanaResult<()> {
+            tokio::task::yield_now().await;
+            self.log.lock().unwrap().push(format!("{}:{}", self.name, event.username));
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl EventHandler<dyn DomainEvent> for Recorder {
+        async fn handle(&self, event: &dyn DomainEvent) -> ArcanaResult<()> {
+            self.log.lock().unwrap().push(format!("{}:{}", self.name, event.event_type()));
+            Ok(())
+        }
+    }
+
+    fn recorder(name: &'static str, log: &Arc<Mutex<Vec<String>>>) -> Arc<Recorder> {
+        Arc::new(Recorder { name, log: log.clone() })
+    }
+
+    fn created(username: &str) -> UserCreated {
+        UserCreated::new(UserId::new(), username.to_string(), format!("{}@example.com", username))
+    }
+
+    #[tokio::test]
+    async fn test_handlers_run_in_subscription_order() {
+        let bus = EventBus::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        bus.subscribe::<UserCreated>(recorder("first", &log));
+        let sync_log = log.clone();
+        bus.subscribe_fn(move |event: &UserCreated| {
+            sync_log.lock().unwrap().push(format!("sync:{}", event.username));
+            Ok(())
+        });
+        bus.subscribe_all(recorder("all", &log));
+
+        bus.publish_all(vec![Box::new(created("alice")), Box::new(created("bob"))])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "first:alice",
+                "sync:alice",
+                "all:user.created",
+                "first:bob",
+                "sync:bob",
+                "all:user.created"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_typed_handlers_skip_other_events() {
+        let bus = EventBus::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        bus.subscribe::<UserCreated>(recorder("typed", &log));
+        bus.subscribe_all(recorder("all", &log));
+
+        bus.emit(UserDeleted::new(UserId::new(), None)).await;
+
+        assert_eq!(*log.lock().unwrap(), vec!["all:user.deleted"]);
+    }
+
+    #[tokio::test]
+    async fn test_failing_handlers_are_isolated() {
+        let bus = EventBus::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        bus.subscribe_fn(|_: &UserCreated| Err(ArcanaError::Internal("boom".to_string())));
+        bus.subscribe_fn(|_: &UserCreated| -> ArcanaResult<()> { panic!("handler bug") });
+        bus.subscribe::<UserCreated>(recorder("last", &log));
+
+        bus.publish(Box::new(created("alice"))).await.unwrap();
+
+        assert_eq!(*log.lock().unwrap(), vec!["last:alice"]);
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe() {
+        let bus = EventBus::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let id = bus.subscribe::<UserCreated>(recorder("gone", &log));
+        assert_eq!(bus.subscriber_count(), 1);
+
+        assert!(bus.unsubscribe(id));
+        assert!(!bus.unsubscribe(id));
+        bus.emit(created("alice")).await;
+
+        assert!(log.lock().unwrap().is_empty());
+        assert_eq!(bus.subscriber_count(), 0);
+    }
+}
@@ -0,0 +1,20 @@
+//! Domain events for the service layer.
+//!
+//! Services publish the domain events of [`arcana_core`] (`UserCreated`,
+//! `LoginFailed`, ...) on the [`EventBusInterface`] after the change they
+//! describe has been made. The [`EventBus`] delivers them to in-process
+//! handlers subscribed through [`EventBusExt`].
+//!
+//! Changes to users are stored together with their events in a
+//! transactional outbox where the repository supports it; the
+//! [`OutboxRelay`] publishes them from there.
+
+mod event_bus;
+mod outbox;
+
+pub use event_bus::{
+    EventBus, EventBusExt, EventBusInterface, EventBusParameters, EventSubscriber, SubscriptionId,
+    SyncEventHandler,
+};
+pub use outbox::{spawn_outbox_relay, OutboxRelay, RelayOutboxJob, OUTBOX_QUEUE};
+pub(crate) use outbox::publish_unless_stored;
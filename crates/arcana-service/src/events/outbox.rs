@@ -0,0 +1,349 @@
+//! Relaying events from the transactional outbox.
+
+use super::EventBusInterface;
+use arcana_core::{ArcanaError, ArcanaResult, DomainEvent};
+use arcana_jobs::redis::RedisJobQueue;
+use arcana_jobs::{Job, JobContext, JobError, JobsConfig, ScheduledJob, Scheduler, WorkerPool, WorkerPoolConfig};
+use arcana_repository::{OutboxRepository, UserRepository};
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{debug, error, info, warn};
+
+/// Job queue that carries outbox relay runs.
+pub const OUTBOX_QUEUE: &str = "outbox";
+
+/// Job that delivers the pending events of the outbox.
+///
+/// Enqueued by the scheduler on the configured schedule.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RelayOutboxJob;
+
+#[async_trait]
+impl Job for RelayOutboxJob {
+    const NAME: &'static str = "relay_outbox";
+    const QUEUE: &'static str = OUTBOX_QUEUE;
+    // The next scheduled run picks up whatever this one left behind
+    const MAX_RETRIES: u32 = 0;
+
+    /// Fails: delivery needs the handler that [`spawn_outbox_relay`]
+    /// registers with the relay.
+    async fn execute(&self, _ctx: JobContext) -> Result<(), JobError> {
+        Err(JobError::ExecutionFailed("No outbox relay registered".to_string()))
+    }
+}
+
+/// Delivers events stored in the outbox to the event bus.
+///
+/// Delivery is at least once: an event is marked dispatched only after it
+/// was published, so an event whose mark is lost is published again on the
+/// next run. Events that cannot be delivered are retried on later runs
+/// until they have failed `max_attempts` times, after which they stay in
+/// the outbox for inspection.
+pub struct OutboxRelay {
+    outbox: Arc<dyn OutboxRepository>,
+    publisher: Arc<dyn EventBusInterface>,
+    batch_size: usize,
+    max_attempts: u32,
+    /// Held during a run so that runs do not overlap.
+    running: Mutex<()>,
+}
+
+impl OutboxRelay {
+    /// Creates a relay fetching `batch_size` events at a time.
+    #[must_use]
+    pub fn new(
+        outbox: Arc<dyn OutboxRepository>,
+        publisher: Arc<dyn EventBusInterface>,
+        batch_size: usize,
+        max_attempts: u32,
+    ) -> Self {
+        Self {
+            outbox,
+            publisher,
+            batch_size: batch_size.max(1),
+            max_attempts,
+            running: Mutex::new(()),
+        }
+    }
+
+    /// Publishes the pending events, oldest first, and marks them dispatched.
+    ///
+    /// Returns the number of events delivered.
+    pub async fn relay_pending(&self) -> ArcanaResult<usize> {
+        let _running = self.running.lock().await;
+        let mut delivered = 0;
+
+        loop {
+            let batch = self.outbox.find_pending(self.batch_size, self.max_attempts).await?;
+            let fetched = batch.len();
+            let mut delivered_in_batch = 0;
+
+            for entry in batch {
+                let published = match entry.to_domain_event() {
+                    Ok(event) => self.publisher.publish(event).await,
+                    Err(e) => Err(e),
+                };
+
+                if let Err(e) = published {
+                    warn!(
+                        target: "arcana::events",
+                        event_id = %entry.id,
+                        event_type = %entry.event_type,
+                        attempt = entry.attempts + 1,
+                        "Failed to relay outbox event: {}",
+                        e
+                    );
+                    self.outbox.record_failure(entry.id, &e.to_string()).await?;
+                    continue;
+                }
+
+                self.outbox.mark_dispatched(entry.id).await?;
+                delivered_in_batch += 1;
+            }
+
+            delivered += delivered_in_batch;
+
+            // Failed events come back in the next batch; leave them to the next run
+            if fetched < self.batch_size || delivered_in_batch == 0 {
+                break;
+            }
+        }
+
+        if delivered > 0 {
+            debug!("Relayed {} outbox events", delivered);
+        }
+        Ok(delivered)
+    }
+}
+
+impl std::fmt::Debug for OutboxRelay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OutboxRelay")
+            .field("batch_size", &self.batch_size)
+            .field("max_attempts", &self.max_attempts)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Runs the relay on a cron schedule (with seconds).
+///
+/// With Redis, a [`Scheduler`] enqueues a [`RelayOutboxJob`] per tick and
+/// a worker runs it; scheduler leader election keeps instances sharing the
+/// database from enqueuing a run each. Without Redis, the relay runs on a
+/// local timer.
+pub fn spawn_outbox_relay(
+    relay: Arc<OutboxRelay>,
+    redis: Option<deadpool_redis::Pool>,
+    schedule: &str,
+) -> ArcanaResult<()> {
+    let job = ScheduledJob::new(RelayOutboxJob::NAME, schedule, || RelayOutboxJob)
+        .map_err(|e| ArcanaError::Configuration(format!("Invalid outbox relay schedule: {}", e)))?;
+
+    let Some(pool) = redis else {
+        tokio::spawn(async move {
+            while let Some(next) = job.next_run_from(Utc::now()) {
+                tokio::time::sleep((next - Utc::now()).to_std().unwrap_or_default()).await;
+                if let Err(e) = relay.relay_pending().await {
+                    error!("Outbox relay failed: {}", e);
+                }
+            }
+        });
+        info!("Outbox relay scheduled locally: {}", schedule);
+        return Ok(());
+    };
+
+    let jobs = Arc::new(RedisJobQueue::new(pool.clone(), JobsConfig::default()));
+
+    let scheduler = Arc::new(Scheduler::new(pool, jobs.clone(), JobsConfig::default().scheduler));
+    scheduler.register(job);
+    tokio::spawn(async move {
+        if let Err(e) = scheduler.start().await {
+            error!("Outbox scheduler stopped: {}", e);
+        }
+    });
+
+    let workers = Arc::new(WorkerPool::new(
+        jobs,
+        WorkerPoolConfig {
+            concurrency: 1,
+            queues: vec![OUTBOX_QUEUE.to_string()],
+            ..WorkerPoolConfig::default()
+        },
+    ));
+    workers.register::<RelayOutboxJob>(move |_job, _ctx| {
+        let relay = relay.clone();
+        Box::pin(async move {
+            relay
+                .relay_pending()
+                .await
+                .map(|_| ())
+                .map_err(|e| JobError::ExecutionFailed(e.to_string()))
+        })
+    });
+    tokio::spawn(async move {
+        if let Err(e) = workers.start().await {
+            error!("Outbox worker stopped: {}", e);
+        }
+    });
+
+    info!("Outbox relay scheduled on the job queue: {}", schedule);
+    Ok(())
+}
+
+/// Publishes the events of a change unless the repository stored them in
+/// the outbox, logging instead of returning a failure.
+pub(crate) async fn publish_unless_stored(
+    repository: &dyn UserRepository,
+    events: &dyn EventBusInterface,
+    batch: Vec<Box<dyn DomainEvent>>,
+) {
+    if repository.stores_events() {
+        return;
+    }
+    if let Err(e) = events.publish_all(batch).await {
+        warn!(target: "arcana::events", "Failed to publish events: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{EventBus, EventSubscriber};
+    use arcana_core::{LogoutOccurred, OutboxEvent, OutboxEventId, UserDeleted, UserId};
+    use std::sync::Mutex as StdMutex;
+
+    /// Outbox kept in memory, optionally failing to mark events dispatched.
+    #[derive(Default)]
+    struct InMemoryOutbox {
+        events: StdMutex<Vec<OutboxEvent>>,
+        fail_marking: bool,
+    }
+
+    impl InMemoryOutbox {
+        fn push(&self, event: &dyn DomainEvent) -> OutboxEventId {
+            let entry = OutboxEvent::from_event(event).unwrap();
+            let id = entry.id;
+            self.events.lock().unwrap().push(entry);
+            id
+        }
+
+        fn get(&self, id: OutboxEventId) -> OutboxEvent {
+            self.events.lock().unwrap().iter().find(|e| e.id == id).cloned().unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl OutboxRepository for InMemoryOutbox {
+        async fn find_pending(&self, limit: usize, max_attempts: u32) -> ArcanaResult<Vec<OutboxEvent>> {
+            Ok(self
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|e| !e.is_dispatched() && e.attempts < max_attempts)
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+
+        async fn mark_dispatched(&self, id: OutboxEventId) -> ArcanaResult<bool> {
+            if self.fail_marking {
+                return Err(ArcanaError::Database("unavailable".to_string()));
+            }
+            let mut events = self.events.lock().unwrap();
+            let entry = events.iter_mut().find(|e| e.id == id && !e.is_dispatched());
+            Ok(entry.map(|e| e.dispatched_at = Some(Utc::now())).is_some())
+        }
+
+        async fn record_failure(&self, id: OutboxEventId, error: &str) -> ArcanaResult<()> {
+            let mut events = self.events.lock().unwrap();
+            if let Some(entry) = events.iter_mut().find(|e| e.id == id) {
+                entry.attempts += 1;
+                entry.last_error = Some(error.to_string());
+            }
+            Ok(())
+        }
+    }
+
+    fn recording_bus() -> (Arc<EventBus>, Arc<StdMutex<Vec<String>>>) {
+        let bus = Arc::new(EventBus::new());
+        let log = Arc::new(StdMutex::new(Vec::new()));
+        let seen = log.clone();
+        bus.subscribe_handler(EventSubscriber::Sync(Arc::new(move |event: &dyn DomainEvent| {
+            seen.lock().unwrap().push(event.event_type().to_string());
+            Ok(())
+        })));
+        (bus, log)
+    }
+
+    #[tokio::test]
+    async fn test_relay_delivers_in_order_and_marks_dispatched() {
+        let outbox = Arc::new(InMemoryOutbox::default());
+        let first = outbox.push(&LogoutOccurred::new(UserId::new(), None));
+        let second = outbox.push(&UserDeleted::new(UserId::new(), None));
+        let third = outbox.push(&LogoutOccurred::new(UserId::new(), None));
+        let (bus, log) = recording_bus();
+
+        let relay = OutboxRelay::new(outbox.clone(), bus, 2, 5);
+        assert_eq!(relay.relay_pending().await.unwrap(), 3);
+
+        assert_eq!(*log.lock().unwrap(), vec!["auth.logout", "user.deleted", "auth.logout"]);
+        for id in [first, second, third] {
+            assert!(outbox.get(id).is_dispatched());
+        }
+        assert_eq!(relay.relay_pending().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_relay_records_undeliverable_events() {
+        let outbox = Arc::new(InMemoryOutbox::default());
+        let broken = outbox.push(&LogoutOccurred::new(UserId::new(), None));
+        outbox.events.lock().unwrap()[0].payload = "not json".to_string();
+        let fine = outbox.push(&UserDeleted::new(UserId::new(), None));
+        let (bus, log) = recording_bus();
+
+        let relay = OutboxRelay::new(outbox.clone(), bus, 10, 2);
+        assert_eq!(relay.relay_pending().await.unwrap(), 1);
+        assert_eq!(*log.lock().unwrap(), vec!["user.deleted"]);
+
+        let failed = outbox.get(broken);
+        assert!(!failed.is_dispatched());
+        assert_eq!(failed.attempts, 1);
+        assert!(failed.last_error.is_some());
+        assert!(outbox.get(fine).is_dispatched());
+
+        // Given up on after max_attempts
+        relay.relay_pending().await.unwrap();
+        assert_eq!(outbox.get(broken).attempts, 2);
+        relay.relay_pending().await.unwrap();
+        assert_eq!(outbox.get(broken).attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_relay_redelivers_events_not_marked_dispatched() {
+        let outbox = Arc::new(InMemoryOutbox {
+            fail_marking: true,
+            ..Default::default()
+        });
+        let id = outbox.push(&UserDeleted::new(UserId::new(), None));
+        let (bus, log) = recording_bus();
+
+        let relay = OutboxRelay::new(outbox.clone(), bus, 10, 5);
+        assert!(relay.relay_pending().await.is_err());
+        assert!(relay.relay_pending().await.is_err());
+
+        assert_eq!(*log.lock().unwrap(), vec!["user.deleted", "user.deleted"]);
+        assert!(!outbox.get(id).is_dispatched());
+    }
+
+    #[test]
+    fn test_spawn_rejects_invalid_schedule() {
+        let outbox = Arc::new(InMemoryOutbox::default());
+        let relay = Arc::new(OutboxRelay::new(outbox, Arc::new(EventBus::new()), 10, 5));
+
+        assert!(spawn_outbox_relay(relay, None, "every now and then").is_err());
+    }
+}
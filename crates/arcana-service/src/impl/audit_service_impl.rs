@@ -0,0 +1,359 @@
+//! Audit service implementation.
+
+use crate::audit_service::AuditService;
+use crate::dto::{AuditChainQuery, AuditChainReport, AuditLogListResponse, AuditLogQuery, AuditLogResponse};
+use arcana_core::{ArcanaError, ArcanaResult, AuditChainVerifier, AuditLogCursor, ValidateExt};
+use arcana_repository::AuditLogRepository;
+use async_trait::async_trait;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Number of entries read at a time while walking the hash chain.
+const CHAIN_BATCH_SIZE: usize = 500;
+
+/// Audit service reading the audit trail from the audit log repository.
+#[derive(Component)]
+#[shaku(interface = AuditService)]
+pub struct AuditServiceImpl {
+    #[shaku(inject)]
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl AuditServiceImpl {
+    /// Creates a new audit service.
+    #[must_use]
+    pub fn new(audit_log_repository: Arc<dyn AuditLogRepository>) -> Self {
+        Self { audit_log_repository }
+    }
+}
+
+#[async_trait]
+impl AuditService for AuditServiceImpl {
+    async fn query_audit_logs(&self, query: AuditLogQuery) -> ArcanaResult<AuditLogListResponse> {
+        debug!("Querying audit logs: {:?}", query);
+
+        query.validate_request()?;
+        let after = query
+            .cursor
+            .as_deref()
+            .map(str::parse::<AuditLogCursor>)
+            .transpose()?;
+        let limit = query.limit();
+
+        // Fetch one extra entry to learn whether another page follows
+        let mut entries = self
+            .audit_log_repository
+            .search(&query.filter(), after, limit + 1)
+            .await?;
+        let next_cursor = if entries.len() > limit {
+            entries.truncate(limit);
+            entries.last().map(|entry| AuditLogCursor::after(entry).to_string())
+        } else {
+            None
+        };
+
+        Ok(AuditLogListResponse {
+            entries: entries.into_iter().map(AuditLogResponse::from).collect(),
+            next_cursor,
+        })
+    }
+
+    async fn verify_audit_chain(&self, query: AuditChainQuery) -> ArcanaResult<AuditChainReport> {
+        debug!("Verifying audit chain: {:?}", query);
+
+        if let (Some(from), Some(to)) = (query.from, query.to) {
+            if from >= to {
+                return Err(ArcanaError::Validation("'from' must be before 'to'".to_string()));
+            }
+        }
+
+        let Some((first, last)) = self.audit_log_repository.chain_bounds(query.from, query.to).await? else {
+            return Ok(AuditChainReport {
+                intact: true,
+                checked: 0,
+                first_sequence: None,
+                last_sequence: None,
+                broken_link: None,
+            });
+        };
+
+        // Start at the entry before the range, or at the very beginning of
+        // the chain, so that entries removed at the start are noticed too.
+        let start = match query.from {
+            Some(_) => first.saturating_sub(1).max(1),
+            None => 1,
+        };
+        let mut verifier = AuditChainVerifier::new(start);
+        let mut broken_link = None;
+
+        'walk: while verifier.next_sequence() <= last {
+            let batch = self
+                .audit_log_repository
+                .find_chain(verifier.next_sequence(), last, CHAIN_BATCH_SIZE)
+                .await?;
+            if batch.is_empty() {
+                break;
+            }
+            for entry in &batch {
+                if let Err(broken) = verifier.check(entry) {
+                    broken_link = Some(broken);
+                    break 'walk;
+                }
+            }
+        }
+        let broken_link = broken_link.or_else(|| verifier.finish(last).err());
+
+        if let Some(broken) = &broken_link {
+            warn!(
+                target: "arcana::audit",
+                sequence = broken.sequence,
+                reason = %broken.reason,
+                "Audit hash chain is broken"
+            );
+        }
+
+        Ok(AuditChainReport {
+            intact: broken_link.is_none(),
+            checked: verifier.checked(),
+            first_sequence: Some(start),
+            last_sequence: Some(last),
+            broken_link,
+        })
+    }
+}
+
+impl std::fmt::Debug for AuditServiceImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditServiceImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{
+        AuditAction, AuditChainBreakReason, AuditLog, AuditLogFilter, AuditLogId, Page, PageRequest, UserId,
+    };
+    use chrono::{DateTime, Duration, Utc};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct InMemoryAuditLogRepository {
+        entries: Mutex<Vec<AuditLog>>,
+    }
+
+    impl std::fmt::Debug for InMemoryAuditLogRepository {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("InMemoryAuditLogRepository").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl AuditLogRepository for InMemoryAuditLogRepository {
+        async fn save(&self, entry: &AuditLog) -> ArcanaResult<AuditLog> {
+            let mut entries = self.entries.lock().unwrap();
+            let mut entry = entry.clone();
+            entry.chain(entries.len() as u64 + 1, entries.last().and_then(|e| e.hash.clone()));
+            entries.push(entry.clone());
+            Ok(entry)
+        }
+
+        async fn find_by_id(&self, id: AuditLogId) -> ArcanaResult<Option<AuditLog>> {
+            Ok(self.entries.lock().unwrap().iter().find(|e| e.id == id).cloned())
+        }
+
+        async fn find_by_user(&self, _user_id: UserId, page: PageRequest) -> ArcanaResult<Page<AuditLog>> {
+            Ok(Page::empty(page.page, page.size))
+        }
+
+        async fn search(
+            &self,
+            filter: &AuditLogFilter,
+            after: Option<AuditLogCursor>,
+            limit: usize,
+        ) -> ArcanaResult<Vec<AuditLog>> {
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|e| filter.matches(e) && after.is_none_or(|cursor| cursor.precedes(e)))
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+
+        async fn chain_bounds(
+            &self,
+            from: Option<DateTime<Utc>>,
+            to: Option<DateTime<Utc>>,
+        ) -> ArcanaResult<Option<(u64, u64)>> {
+            let filter = AuditLogFilter { from, to, ..Default::default() };
+            let entries = self.entries.lock().unwrap();
+            let sequences = || entries.iter().filter(|e| filter.matches(e)).filter_map(|e| e.sequence);
+            Ok(sequences().min().zip(sequences().max()))
+        }
+
+        async fn find_chain(&self, from_sequence: u64, to_sequence: u64, limit: usize) -> ArcanaResult<Vec<AuditLog>> {
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|e| e.sequence.is_some_and(|s| (from_sequence..=to_sequence).contains(&s)))
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+    }
+
+    async fn create_repository(entries: Vec<AuditLog>) -> Arc<InMemoryAuditLogRepository> {
+        let repository = Arc::new(InMemoryAuditLogRepository::default());
+        for entry in &entries {
+            repository.save(entry).await.unwrap();
+        }
+        repository
+    }
+
+    async fn create_service(entries: Vec<AuditLog>) -> AuditServiceImpl {
+        AuditServiceImpl::new(create_repository(entries).await)
+    }
+
+    /// Entries one minute apart, the first one `count` minutes ago.
+    fn entries_by_minute(count: i64) -> Vec<AuditLog> {
+        (0..count)
+            .map(|i| {
+                let mut entry = AuditLog::success(None, AuditAction::Login, "user", None);
+                entry.timestamp = Utc::now() - Duration::minutes(count - i);
+                entry
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_query_pages_through_matching_entries() {
+        let user_id = UserId::new();
+        let service = create_service(vec![
+            AuditLog::success(Some(user_id), AuditAction::Login, "user", None),
+            AuditLog::failure(Some(user_id), AuditAction::Login, "user", None, "Invalid credentials"),
+            AuditLog::success(None, AuditAction::Login, "user", None),
+            AuditLog::success(Some(user_id), AuditAction::Logout, "user", None),
+            AuditLog::success(Some(user_id), AuditAction::Login, "user", None),
+        ])
+        .await;
+        let query = AuditLogQuery {
+            user_id: Some(user_id),
+            action: Some(AuditAction::Login),
+            limit: Some(2),
+            ..Default::default()
+        };
+
+        let first = service.query_audit_logs(query.clone()).await.unwrap();
+        assert_eq!(first.entries.len(), 2);
+        assert!(first.next_cursor.is_some());
+
+        let second = service
+            .query_audit_logs(AuditLogQuery { cursor: first.next_cursor, ..query })
+            .await
+            .unwrap();
+        assert_eq!(second.entries.len(), 1);
+        assert!(second.next_cursor.is_none());
+        assert!(second.entries[0].success);
+    }
+
+    #[tokio::test]
+    async fn test_query_filters_on_success() {
+        let service = create_service(vec![
+            AuditLog::success(None, AuditAction::Login, "user", None),
+            AuditLog::failure(None, AuditAction::Login, "user", None, "Invalid credentials"),
+        ])
+        .await;
+
+        let response = service
+            .query_audit_logs(AuditLogQuery { success: Some(false), ..Default::default() })
+            .await
+            .unwrap();
+        assert_eq!(response.entries.len(), 1);
+        assert!(!response.entries[0].success);
+        assert!(response.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_query_rejects_invalid_cursor() {
+        let service = create_service(Vec::new()).await;
+
+        let result = service
+            .query_audit_logs(AuditLogQuery { cursor: Some("garbage".to_string()), ..Default::default() })
+            .await;
+        assert!(matches!(result, Err(ArcanaError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_verify_intact_chain() {
+        let service = create_service(entries_by_minute(5)).await;
+
+        let report = service.verify_audit_chain(AuditChainQuery::default()).await.unwrap();
+        assert!(report.intact);
+        assert_eq!(report.checked, 5);
+        assert_eq!((report.first_sequence, report.last_sequence), (Some(1), Some(5)));
+    }
+
+    #[tokio::test]
+    async fn test_verify_reports_edited_entry() {
+        let repository = create_repository(entries_by_minute(5)).await;
+        repository.entries.lock().unwrap()[2].success = false;
+        let service = AuditServiceImpl::new(repository);
+
+        let report = service.verify_audit_chain(AuditChainQuery::default()).await.unwrap();
+        assert!(!report.intact);
+        assert_eq!(report.checked, 2);
+        let broken = report.broken_link.unwrap();
+        assert_eq!(broken.sequence, 3);
+        assert_eq!(broken.reason, AuditChainBreakReason::HashMismatch);
+    }
+
+    #[tokio::test]
+    async fn test_verify_range_notices_entry_deleted_at_its_start() {
+        let entries = entries_by_minute(5);
+        let from = entries[2].timestamp;
+        let repository = create_repository(entries).await;
+        repository.entries.lock().unwrap().remove(2);
+        let service = AuditServiceImpl::new(repository);
+
+        let report = service
+            .verify_audit_chain(AuditChainQuery { from: Some(from), to: None })
+            .await
+            .unwrap();
+        let broken = report.broken_link.unwrap();
+        assert_eq!(broken.sequence, 3);
+        assert_eq!(broken.reason, AuditChainBreakReason::MissingEntries);
+    }
+
+    #[tokio::test]
+    async fn test_verify_range_starts_before_it() {
+        let entries = entries_by_minute(5);
+        let (from, to) = (entries[2].timestamp, entries[4].timestamp);
+        let service = create_service(entries).await;
+
+        let report = service
+            .verify_audit_chain(AuditChainQuery { from: Some(from), to: Some(to) })
+            .await
+            .unwrap();
+        assert!(report.intact);
+        assert_eq!((report.first_sequence, report.last_sequence), (Some(2), Some(4)));
+        assert_eq!(report.checked, 3);
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_inverted_range() {
+        let service = create_service(Vec::new()).await;
+        let now: DateTime<Utc> = Utc::now();
+
+        let result = service
+            .verify_audit_chain(AuditChainQuery { from: Some(now), to: Some(now - Duration::hours(1)) })
+            .await;
+        assert!(matches!(result, Err(ArcanaError::Validation(_))));
+    }
+}
@@ -2,270 +2,949 @@
 
 use crate::auth_service::AuthService;
 use crate::dto::{
-    AuthResponse, AuthUserInfo, LoginRequest, MessageResponse, RefreshTokenRequest, RegisterRequest,
+    AuthResponse, AuthUserInfo, LoginRequest, LoginResponse, MessageResponse,
+    MfaChallengeResponse, MfaLoginRequest, PasswordResetConfirmRequest, PasswordResetRequest,
+    RecoveryCodesResponse, RefreshTokenRequest, RegisterRequest, ResendVerificationRequest,
+    SessionInfo, TotpCodeRequest, TotpEnrollmentResponse, VerifyEmailRequest,
+};
+use crate::audit::RequestContext;
+use crate::events::{publish_unless_stored, EventBusExt, EventBusInterface};
+use crate::mail::{EmailMessage, MailQueueInterface};
+use arcana_config::{LockoutConfig, SecurityConfigInterface};
+use arcana_core::{ArcanaError, ArcanaResult, TenantId, UserId, ValidateExt};
+use arcana_core::{DomainEvent, Email, OAuthToken, RefreshTokenReuseDetected, User, UserStatus};
+use arcana_core::{AccountLocked, AccountUnlocked, LoginFailed, LoginFailureReason};
+use arcana_core::{LoginSucceeded, LogoutOccurred, PasswordChanged, PasswordResetRequested, TokenRefreshed};
+use arcana_core::{UserCreated, UserEmailVerified};
+use arcana_core::{PasswordResetToken, TotpCredential};
+use arcana_repository::{
+    MfaRepository, OAuthTokenRepository, PasswordResetTokenRepository, TenantRepository, UserRepository,
+};
+use arcana_security::{
+    authenticate_access_token, generate_opaque_token, generate_recovery_codes,
+    generate_totp_secret, hash_token, normalize_recovery_code, totp_provisioning_uri, verify_totp,
+    Claims, LoginAttemptInterface, LoginAttemptKey, PasswordHasherInterface, PermissionResolverInterface,
+    TokenPair, TokenProviderInterface, TokenScope, TokenRevocationInterface, RECOVERY_CODE_COUNT,
 };
-use arcana_config::SecurityConfig;
-use arcana_core::{ArcanaError, ArcanaResult, Interface, UserId, ValidateExt};
-use arcana_core::{Email, User, UserStatus};
-use arcana_repository::UserRepository;
-use arcana_security::{Claims, PasswordHasher, PasswordHasherInterface, TokenProvider, TokenProviderInterface};
 use async_trait::async_trait;
 use shaku::Component;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
-/// Authentication service implementation.
-pub struct AuthServiceImpl<R: UserRepository> {
-    user_repository: Arc<R>,
-    password_hasher: Arc<PasswordHasher>,
-    token_provider: Arc<TokenProvider>,
-}
-
-impl<R: UserRepository> AuthServiceImpl<R> {
-    /// Creates a new authentication service.
-    pub fn new(
-        user_repository: Arc<R>,
-        password_hasher: Arc<PasswordHasher>,
-        security_config: Arc<SecurityConfig>,
-    ) -> Self {
-        let token_provider = Arc::new(TokenProvider::new(security_config));
-        Self {
-            user_repository,
-            password_hasher,
-            token_provider,
+/// Builds the `oauth_tokens` record for a freshly issued refresh token.
+///
+/// The client's user agent and IP address are taken from the current request
+/// so sessions can be told apart when listed.
+fn new_refresh_token_record(
+    user_id: UserId,
+    tokens: &TokenPair,
+    device_id: Option<String>,
+) -> OAuthToken {
+    let expires_at = chrono::DateTime::from_timestamp(tokens.refresh_expires_at, 0)
+        .unwrap_or_else(chrono::Utc::now);
+    let context = RequestContext::current();
+
+    OAuthToken::new(
+        user_id,
+        hash_token(&tokens.refresh_token),
+        tokens.session_id.clone(),
+        expires_at,
+        device_id,
+        context.user_agent,
+        context.ip_address,
+    )
+}
+
+/// Fails unless the tenant exists and its users may sign in.
+///
+/// Access tokens already issued stay valid until they expire; deactivating a
+/// tenant stops new sign-ins and token refreshes.
+async fn require_active_tenant(tenant_repository: &dyn TenantRepository, tenant_id: TenantId) -> ArcanaResult<()> {
+    match tenant_repository.find_by_id(tenant_id).await? {
+        Some(tenant) if tenant.active => Ok(()),
+        Some(_) => Err(ArcanaError::Forbidden("Tenant is not active".to_string())),
+        None => Err(ArcanaError::not_found("Tenant", tenant_id)),
+    }
+}
+
+/// Consumes a presented refresh token so it can be exchanged exactly once.
+///
+/// Returns the stored record of the now-rotated token. If the token had
+/// already been rotated, it is being replayed: the whole token family is
+/// revoked and a [`RefreshTokenReuseDetected`] security event is emitted.
+async fn rotate_refresh_token(
+    repository: &dyn OAuthTokenRepository,
+    events: &dyn EventBusInterface,
+    refresh_token: &str,
+    user_id: UserId,
+) -> ArcanaResult<OAuthToken> {
+    let stored = repository.find_by_token_hash(&hash_token(refresh_token)).await?;
+
+    match stored {
+        Some(token) if token.user_id == user_id && token.is_valid() => {
+            // A concurrent refresh may have rotated the token since the lookup
+            if repository.mark_rotated(token.id).await? {
+                Ok(token)
+            } else {
+                Err(revoke_reused_family(repository, events, token).await)
+            }
+        }
+        Some(token) if token.user_id == user_id && is_rotated(&token) => {
+            Err(revoke_reused_family(repository, events, token).await)
+        }
+        Some(token) if token.revoked => {
+            warn!("Refresh rejected: token {} has been revoked", token.id);
+            Err(ArcanaError::InvalidToken("Refresh token has been revoked".to_string()))
+        }
+        _ => {
+            warn!("Refresh rejected: unknown refresh token for user {}", user_id);
+            Err(ArcanaError::InvalidToken("Refresh token is not recognized".to_string()))
         }
     }
+}
 
-    /// Creates an auth response for a user.
-    fn create_auth_response(&self, user: &User) -> ArcanaResult<AuthResponse> {
-        let tokens = self.token_provider.generate_tokens(
-            user.id,
-            &user.username,
-            user.email.as_str(),
-            user.role,
-        )?;
+/// Returns whether a token was revoked by rotation rather than by logout.
+fn is_rotated(token: &OAuthToken) -> bool {
+    token.revoked && token.last_used_at.is_some()
+}
 
-        Ok(AuthResponse {
-            access_token: tokens.access_token,
-            refresh_token: tokens.refresh_token,
-            token_type: tokens.token_type,
-            expires_in: tokens.access_expires_at - chrono::Utc::now().timestamp(),
-            user: AuthUserInfo {
-                id: user.id,
-                username: user.username.clone(),
-                email: user.email.to_string(),
-                role: user.role,
-                first_name: user.first_name.clone(),
-                last_name: user.last_name.clone(),
-            },
-        })
+/// Revokes the family of a replayed refresh token and reports the reuse.
+async fn revoke_reused_family(
+    repository: &dyn OAuthTokenRepository,
+    events: &dyn EventBusInterface,
+    token: OAuthToken,
+) -> ArcanaError {
+    let revoked = match repository.revoke_family(&token.family_id).await {
+        Ok(revoked) => revoked,
+        Err(e) => return e,
+    };
+
+    report_security_event(events, RefreshTokenReuseDetected::new(token.user_id, token.family_id, revoked)).await;
+    ArcanaError::InvalidToken("Refresh token reuse detected".to_string())
+}
+
+/// Lists the active sessions of the claims' user, marking the caller's own.
+async fn list_user_sessions(
+    repository: &dyn OAuthTokenRepository,
+    claims: &Claims,
+) -> ArcanaResult<Vec<SessionInfo>> {
+    let user_id = claims.user_id().ok_or_else(|| {
+        ArcanaError::InvalidToken("Invalid token: missing user ID".to_string())
+    })?;
+
+    let tokens = repository.find_active_by_user(user_id).await?;
+    Ok(tokens
+        .into_iter()
+        .map(|token| SessionInfo::from_token(token, claims.session_id.as_deref()))
+        .collect())
+}
+
+/// Revokes one of the claims' user's sessions.
+async fn revoke_user_session(
+    repository: &dyn OAuthTokenRepository,
+    token_revocation: &dyn TokenRevocationInterface,
+    claims: &Claims,
+    session_id: &str,
+) -> ArcanaResult<MessageResponse> {
+    let user_id = claims.user_id().ok_or_else(|| {
+        ArcanaError::InvalidToken("Invalid token: missing user ID".to_string())
+    })?;
+
+    // Only sessions of the caller can be revoked
+    let sessions = repository.find_active_by_user(user_id).await?;
+    if !sessions.iter().any(|token| token.family_id == session_id) {
+        return Err(ArcanaError::not_found("Session", session_id));
     }
+
+    end_session(repository, token_revocation, session_id).await?;
+
+    info!("Session {} of user {} revoked", session_id, user_id);
+    Ok(MessageResponse::new("Session revoked"))
 }
 
-#[async_trait]
-impl<R: UserRepository + 'static> AuthService for AuthServiceImpl<R> {
-    async fn register(&self, request: RegisterRequest) -> ArcanaResult<AuthResponse> {
-        debug!("Registering user: {}", request.username);
+/// Revokes every session of the claims' user except the caller's own.
+async fn revoke_other_user_sessions(
+    repository: &dyn OAuthTokenRepository,
+    token_revocation: &dyn TokenRevocationInterface,
+    claims: &Claims,
+) -> ArcanaResult<MessageResponse> {
+    let user_id = claims.user_id().ok_or_else(|| {
+        ArcanaError::InvalidToken("Invalid token: missing user ID".to_string())
+    })?;
+    let current_session = claims.session_id.as_deref().ok_or_else(|| {
+        ArcanaError::InvalidToken("Token is not bound to a session".to_string())
+    })?;
+
+    let mut revoked = 0;
+    for token in repository.find_active_by_user(user_id).await? {
+        if token.family_id != current_session {
+            end_session(repository, token_revocation, &token.family_id).await?;
+            revoked += 1;
+        }
+    }
 
-        // Validate request
-        request.validate_request()?;
+    info!("Revoked {} other sessions of user {}", revoked, user_id);
+    Ok(MessageResponse::new(format!("Revoked {} other sessions", revoked)))
+}
 
-        // Check for existing username
-        if self.user_repository.exists_by_username(&request.username).await? {
-            return Err(ArcanaError::Conflict(format!(
-                "Username '{}' already exists",
-                request.username
-            )));
+/// Revokes the refresh tokens of a session and every access token issued in it.
+async fn end_session(
+    repository: &dyn OAuthTokenRepository,
+    token_revocation: &dyn TokenRevocationInterface,
+    session_id: &str,
+) -> ArcanaResult<()> {
+    repository.revoke_family(session_id).await?;
+    token_revocation.revoke_session(session_id).await
+}
+
+/// Mails a user a link that verifies their email address.
+async fn send_verification_email(
+    token_provider: &dyn TokenProviderInterface,
+    mail_queue: &dyn MailQueueInterface,
+    user: &User,
+) -> ArcanaResult<()> {
+    let token = token_provider.generate_email_verification_token(
+        user.id,
+        &user.username,
+        user.email.as_str(),
+        user.role,
+    )?;
+    let link = mail_queue.config().verification_link(&token);
+
+    mail_queue
+        .enqueue(EmailMessage::email_verification(user.email.as_str(), &user.username, &link))
+        .await
+}
+
+/// Sends the verification email to a freshly registered user.
+///
+/// Registration still succeeds when the email cannot be queued; the user can
+/// ask for it again.
+async fn send_registration_verification(
+    token_provider: &dyn TokenProviderInterface,
+    mail_queue: &dyn MailQueueInterface,
+    user: &User,
+) {
+    if let Err(e) = send_verification_email(token_provider, mail_queue, user).await {
+        warn!("Failed to send verification email to user {}: {}", user.id, e);
+    }
+}
+
+/// Marks the email address a verification token was issued for as verified.
+async fn verify_user_email(
+    repository: &dyn UserRepository,
+    token_provider: &dyn TokenProviderInterface,
+    events: &dyn EventBusInterface,
+    request: VerifyEmailRequest,
+) -> ArcanaResult<MessageResponse> {
+    request.validate_request()?;
+
+    let claims = token_provider.validate_email_verification_token(&request.token)?;
+    let user_id = claims.user_id().ok_or_else(|| {
+        ArcanaError::InvalidToken("Invalid token: missing user ID".to_string())
+    })?;
+
+    let mut user = repository
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(|| ArcanaError::InvalidToken("User no longer exists".to_string()))?;
+
+    // A token sent to a previous address must not verify the current one
+    if !user.email.as_str().eq_ignore_ascii_case(&claims.email) {
+        warn!("Email verification rejected: address changed for user {}", user.id);
+        return Err(ArcanaError::InvalidToken(
+            "Verification token was issued for a different email address".to_string(),
+        ));
+    }
+
+    if user.email_verified {
+        return Ok(MessageResponse::new("Email already verified"));
+    }
+
+    user.verify_email();
+    let verified: Vec<Box<dyn DomainEvent>> = vec![Box::new(UserEmailVerified::new(user.id, user.email.to_string()))];
+    repository.update_with_events(&user, &verified).await?;
+
+    publish_unless_stored(repository, events, verified).await;
+
+    info!("Email verified for user {}", user.id);
+    Ok(MessageResponse::new("Email verified"))
+}
+
+/// Sends the verification email again to an unverified account.
+async fn resend_user_verification(
+    repository: &dyn UserRepository,
+    token_provider: &dyn TokenProviderInterface,
+    mail_queue: &dyn MailQueueInterface,
+    request: ResendVerificationRequest,
+) -> ArcanaResult<MessageResponse> {
+    request.validate_request()?;
+
+    match repository.find_by_email(RequestContext::current().tenant_id(), &request.email).await? {
+        Some(user) if !user.email_verified && user.status.can_login() => {
+            if let Err(e) = send_verification_email(token_provider, mail_queue, &user).await {
+                warn!("Failed to resend verification email to user {}: {}", user.id, e);
+            }
         }
+        _ => debug!("Verification resend skipped: no unverified account for the address"),
+    }
 
-        // Check for existing email
-        if self.user_repository.exists_by_email(&request.email).await? {
-            return Err(ArcanaError::Conflict(format!(
-                "Email '{}' already exists",
-                request.email
-            )));
+    Ok(MessageResponse::new(
+        "If the address belongs to an unverified account, a verification email has been sent",
+    ))
+}
+
+/// Mails a user a single-use link that resets their password.
+///
+/// Only the hash of the reset token is stored, and any reset link sent
+/// earlier stops working.
+async fn send_password_reset_email(
+    repository: &dyn PasswordResetTokenRepository,
+    mail_queue: &dyn MailQueueInterface,
+    user: &User,
+    expiration: Duration,
+) -> ArcanaResult<()> {
+    let ttl = chrono::Duration::from_std(expiration).map_err(|_| {
+        ArcanaError::Configuration("Password reset expiration is out of range".to_string())
+    })?;
+
+    repository.invalidate_all_by_user(user.id).await?;
+
+    let token = generate_opaque_token();
+    repository
+        .save(&PasswordResetToken::new(user.id, hash_token(&token), chrono::Utc::now() + ttl))
+        .await?;
+
+    let link = mail_queue.config().password_reset_link(&token);
+    mail_queue
+        .enqueue(EmailMessage::password_reset(user.email.as_str(), &user.username, &link))
+        .await
+}
+
+/// Starts a password reset for the account an email address belongs to.
+async fn request_password_reset(
+    user_repository: &dyn UserRepository,
+    reset_token_repository: &dyn PasswordResetTokenRepository,
+    mail_queue: &dyn MailQueueInterface,
+    events: &dyn EventBusInterface,
+    expiration: Duration,
+    request: PasswordResetRequest,
+) -> ArcanaResult<MessageResponse> {
+    request.validate_request()?;
+
+    match user_repository.find_by_email(RequestContext::current().tenant_id(), &request.email).await? {
+        Some(user) if user.status.can_login() => {
+            info!("Password reset requested for user {}", user.id);
+            if let Err(e) =
+                send_password_reset_email(reset_token_repository, mail_queue, &user, expiration).await
+            {
+                warn!("Failed to send password reset email to user {}: {}", user.id, e);
+            }
+            events
+                .emit(PasswordResetRequested::new(
+                    user.id,
+                    user.email.to_string(),
+                    RequestContext::current().ip_address,
+                ))
+                .await;
         }
+        _ => debug!("Password reset skipped: no active account for the address"),
+    }
 
-        // Parse email
-        let email = Email::new(&request.email)
-            .map_err(|e| ArcanaError::Validation(e.to_string()))?;
+    Ok(MessageResponse::new(
+        "If the address belongs to an account, a password reset email has been sent",
+    ))
+}
 
-        // Hash password
-        let password_hash = self.password_hasher.hash(&request.password)?;
+/// Sets a new password with a reset token and signs the user out everywhere.
+async fn reset_user_password(
+    user_repository: &dyn UserRepository,
+    oauth_token_repository: &dyn OAuthTokenRepository,
+    reset_token_repository: &dyn PasswordResetTokenRepository,
+    token_revocation: &dyn TokenRevocationInterface,
+    password_hasher: &dyn PasswordHasherInterface,
+    events: &dyn EventBusInterface,
+    request: PasswordResetConfirmRequest,
+) -> ArcanaResult<MessageResponse> {
+    request.validate_request()?;
+
+    let invalid_token =
+        || ArcanaError::InvalidToken("Password reset token is invalid or has expired".to_string());
+
+    let token = reset_token_repository
+        .find_by_token_hash(&hash_token(&request.token))
+        .await?
+        .filter(PasswordResetToken::is_valid)
+        .ok_or_else(invalid_token)?;
+
+    // A concurrent reset may have used the token since the lookup
+    if !reset_token_repository.mark_used(token.id).await? {
+        warn!("Password reset rejected: token {} already used", token.id);
+        return Err(invalid_token());
+    }
 
-        // Create user
-        let mut user = User::new(
-            request.username,
-            email,
-            password_hash,
-            request.first_name,
-            request.last_name,
-        );
+    let mut user = user_repository
+        .find_by_id(token.user_id)
+        .await?
+        .filter(|user| user.status.can_login())
+        .ok_or_else(invalid_token)?;
 
-        // For now, auto-activate users (in production, you'd send verification email)
-        user.activate();
+    user.update_password(password_hasher.hash(&request.new_password)?);
+    let changed: Vec<Box<dyn DomainEvent>> = vec![Box::new(PasswordChanged::new(user.id, user.id))];
+    user_repository.update_with_events(&user, &changed).await?;
 
-        // Save user
-        let saved_user = self.user_repository.save(&user).await?;
+    reset_token_repository.invalidate_all_by_user(user.id).await?;
+    let revoked = oauth_token_repository.revoke_all_by_user(user.id).await?;
+    token_revocation.revoke_user_tokens(user.id).await?;
 
-        info!("User registered: {}", saved_user.id);
+    publish_unless_stored(user_repository, events, changed).await;
+
+    info!("Password reset for user {} ({} refresh tokens revoked)", user.id, revoked);
+    Ok(MessageResponse::new("Password has been reset"))
+}
 
-        // Generate tokens
-        self.create_auth_response(&saved_user)
+/// Checks a login's password, with brute-force protection.
+///
+/// Clients that have failed too often are refused outright, an expired
+/// automatic lock is lifted first, and every failure is counted and answered
+/// with a growing delay until the account is locked.
+async fn authenticate_credentials(
+    user_repository: &dyn UserRepository,
+    password_hasher: &dyn PasswordHasherInterface,
+    login_attempts: &dyn LoginAttemptInterface,
+    events: &dyn EventBusInterface,
+    lockout: &LockoutConfig,
+    request: &LoginRequest,
+) -> ArcanaResult<User> {
+    let ip_address = request.ip_address.as_deref();
+
+    if let Some(ip) = ip_address {
+        let failures = login_attempts.failures(LoginAttemptKey::Ip(ip)).await?;
+        if lockout.max_failed_attempts_per_ip > 0 && failures >= lockout.max_failed_attempts_per_ip {
+            report_login_failure(events, request, LoginFailureReason::TooManyAttempts).await;
+            return Err(ArcanaError::RateLimitExceeded);
+        }
     }
 
-    async fn login(&self, request: LoginRequest) -> ArcanaResult<AuthResponse> {
-        debug!("Login attempt for: {}", request.username_or_email);
+    let Some(mut user) = user_repository
+        .find_by_username_or_email(RequestContext::current().tenant_id(), &request.username_or_email)
+        .await?
+    else {
+        return Err(reject_login(
+            user_repository,
+            login_attempts,
+            events,
+            lockout,
+            None,
+            request,
+            LoginFailureReason::UserNotFound,
+        )
+        .await);
+    };
+
+    if user.lock_expired(chrono::Utc::now()) {
+        user.unlock();
+        user = user_repository.update(&user).await?;
+        login_attempts.clear(LoginAttemptKey::Account(user.id)).await?;
+        info!("Account lock expired: {}", user.id);
+        report_security_event(events, AccountUnlocked::new(user.id)).await;
+    }
 
-        // Validate request
-        request.validate_request()?;
+    if !user.status.can_login() {
+        let (reason, error) = match user.status {
+            UserStatus::Suspended => (
+                LoginFailureReason::AccountSuspended,
+                ArcanaError::Forbidden("Account is suspended".to_string()),
+            ),
+            UserStatus::Locked => (
+                LoginFailureReason::AccountLocked,
+                ArcanaError::Forbidden("Account is locked".to_string()),
+            ),
+            UserStatus::Deleted => (LoginFailureReason::UserNotFound, ArcanaError::InvalidCredentials),
+            _ => (
+                LoginFailureReason::AccountNotVerified,
+                ArcanaError::Forbidden("Account is not active".to_string()),
+            ),
+        };
+        report_login_failure(events, request, reason).await;
+        return Err(error);
+    }
 
-        // Find user
-        let user = self
-            .user_repository
-            .find_by_username_or_email(&request.username_or_email)
-            .await?
-            .ok_or_else(|| {
-                warn!("Login failed: user not found - {}", request.username_or_email);
-                ArcanaError::InvalidCredentials
-            })?;
+    if !password_hasher.verify(&request.password, &user.password_hash)? {
+        return Err(reject_login(
+            user_repository,
+            login_attempts,
+            events,
+            lockout,
+            Some(&user),
+            request,
+            LoginFailureReason::InvalidPassword,
+        )
+        .await);
+    }
 
-        // Check user status
-        if !user.status.can_login() {
-            warn!("Login failed: user status {:?} - {}", user.status, user.id);
-            return Err(match user.status {
-                UserStatus::Suspended => ArcanaError::Forbidden("Account is suspended".to_string()),
-                UserStatus::Locked => ArcanaError::Forbidden("Account is locked".to_string()),
-                UserStatus::Deleted => ArcanaError::InvalidCredentials,
-                _ => ArcanaError::Forbidden("Account is not active".to_string()),
-            });
-        }
-
-        // Verify password
-        if !self.password_hasher.verify(&request.password, &user.password_hash)? {
-            warn!("Login failed: invalid password - {}", user.id);
-            return Err(ArcanaError::InvalidCredentials);
+    Ok(user)
+}
+
+/// Counts a failed login against the client IP and the account.
+///
+/// The account is locked once it reaches the configured number of failures;
+/// otherwise the response is delayed by the failure delay before the error
+/// to return is handed back.
+async fn reject_login(
+    user_repository: &dyn UserRepository,
+    login_attempts: &dyn LoginAttemptInterface,
+    events: &dyn EventBusInterface,
+    lockout: &LockoutConfig,
+    user: Option<&User>,
+    request: &LoginRequest,
+    reason: LoginFailureReason,
+) -> ArcanaError {
+    report_login_failure(events, request, reason).await;
+
+    let ip_failures = match request.ip_address.as_deref() {
+        Some(ip) => login_attempts.record_failure(LoginAttemptKey::Ip(ip)).await.unwrap_or(0),
+        None => 0,
+    };
+
+    let account_failures = match user {
+        Some(user) => record_account_failure(
+            user_repository,
+            login_attempts,
+            events,
+            lockout,
+            user,
+            request.ip_address.clone(),
+        )
+        .await,
+        None => Ok(0),
+    };
+
+    match account_failures {
+        Ok(failures) => {
+            tokio::time::sleep(lockout.failure_delay(failures.max(ip_failures))).await;
+            ArcanaError::InvalidCredentials
         }
+        Err(locked) => locked,
+    }
+}
 
-        // Update last login
-        let mut updated_user = user.clone();
-        updated_user.record_login();
-        let _ = self.user_repository.update(&updated_user).await;
+/// Counts a failed password or second factor against an account.
+///
+/// Returns the failures so far, or the error to report once the failure has
+/// locked the account.
+async fn record_account_failure(
+    user_repository: &dyn UserRepository,
+    login_attempts: &dyn LoginAttemptInterface,
+    events: &dyn EventBusInterface,
+    lockout: &LockoutConfig,
+    user: &User,
+    ip_address: Option<String>,
+) -> Result<u32, ArcanaError> {
+    let failures = login_attempts
+        .record_failure(LoginAttemptKey::Account(user.id))
+        .await
+        .unwrap_or(0);
+    if lockout.max_failed_attempts == 0 || failures < lockout.max_failed_attempts {
+        return Ok(failures);
+    }
 
-        info!("User logged in: {}", user.id);
+    let locked_until = chrono::Utc::now()
+        + chrono::Duration::from_std(lockout.lockout_duration()).unwrap_or(chrono::Duration::zero());
+    let mut locked = user.clone();
+    locked.lock_until(locked_until);
+    if let Err(e) = user_repository.update(&locked).await {
+        warn!("Failed to lock account {}: {}", user.id, e);
+        return Ok(failures);
+    }
+    // The next window starts once the lock expires
+    let _ = login_attempts.clear(LoginAttemptKey::Account(user.id)).await;
+
+    report_security_event(events, AccountLocked::new(user.id, failures, locked_until, ip_address)).await;
+    Err(ArcanaError::Forbidden("Account is locked".to_string()))
+}
 
-        // Generate tokens
-        self.create_auth_response(&user)
+/// Reports a failed login as a security event.
+async fn report_login_failure(events: &dyn EventBusInterface, request: &LoginRequest, reason: LoginFailureReason) {
+    report_security_event(
+        events,
+        LoginFailed::new(
+            request.username_or_email.clone(),
+            reason,
+            request.ip_address.clone(),
+            RequestContext::current().user_agent,
+        ),
+    )
+    .await;
+}
+
+/// Issues an MFA challenge in place of tokens if the user has TOTP enabled.
+async fn issue_mfa_challenge(
+    mfa_repository: &dyn MfaRepository,
+    token_provider: &dyn TokenProviderInterface,
+    user: &User,
+    expiration_secs: u64,
+) -> ArcanaResult<Option<MfaChallengeResponse>> {
+    let enabled = mfa_repository
+        .find_totp_by_user(user.id)
+        .await?
+        .is_some_and(|credential| credential.is_enabled());
+    if !enabled {
+        return Ok(None);
     }
 
-    async fn refresh_token(&self, request: RefreshTokenRequest) -> ArcanaResult<AuthResponse> {
-        debug!("Refreshing token");
+    let mfa_token = token_provider.generate_mfa_challenge_token(
+        user.id,
+        &user.username,
+        user.email.as_str(),
+        user.role,
+    )?;
+
+    Ok(Some(MfaChallengeResponse {
+        mfa_required: true,
+        mfa_token,
+        expires_in: expiration_secs as i64,
+    }))
+}
 
-        // Validate refresh token
-        let claims = self.token_provider.validate_refresh_token(&request.refresh_token)?;
+/// Checks a TOTP code or, failing that, consumes a matching recovery code.
+async fn verify_second_factor(
+    mfa_repository: &dyn MfaRepository,
+    user_id: UserId,
+    code: &str,
+) -> ArcanaResult<bool> {
+    let Some(credential) = find_enabled_totp(mfa_repository, user_id).await? else {
+        return Ok(false);
+    };
+
+    if accept_totp_code(mfa_repository, &credential, code).await? {
+        return Ok(true);
+    }
 
-        // Get user to ensure they still exist and are active
-        let user_id = claims.user_id().ok_or_else(|| {
-            ArcanaError::InvalidToken("Invalid refresh token: missing user ID".to_string())
-        })?;
+    mfa_repository
+        .consume_recovery_code(user_id, &hash_token(&normalize_recovery_code(code)))
+        .await
+}
 
-        let user = self
-            .user_repository
-            .find_by_id(user_id)
-            .await?
-            .ok_or_else(|| ArcanaError::InvalidToken("User no longer exists".to_string()))?;
+/// Verifies a TOTP code and records its time step so it cannot be reused.
+async fn accept_totp_code(
+    mfa_repository: &dyn MfaRepository,
+    credential: &TotpCredential,
+    code: &str,
+) -> ArcanaResult<bool> {
+    match verify_totp(&credential.secret, code, unix_now())? {
+        Some(step) => mfa_repository.record_totp_step(credential.user_id, step).await,
+        None => Ok(false),
+    }
+}
 
-        if !user.status.can_login() {
-            return Err(ArcanaError::Forbidden("Account is not active".to_string()));
-        }
+/// Returns the user's TOTP credential if two-factor authentication is on.
+async fn find_enabled_totp(
+    mfa_repository: &dyn MfaRepository,
+    user_id: UserId,
+) -> ArcanaResult<Option<TotpCredential>> {
+    Ok(mfa_repository
+        .find_totp_by_user(user_id)
+        .await?
+        .filter(TotpCredential::is_enabled))
+}
 
-        info!("Token refreshed for user: {}", user.id);
+/// Starts (or restarts) TOTP enrolment with a fresh secret.
+async fn enroll_user_totp(
+    mfa_repository: &dyn MfaRepository,
+    issuer: &str,
+    claims: &Claims,
+) -> ArcanaResult<TotpEnrollmentResponse> {
+    let user_id = claims_user_id(claims)?;
+
+    if find_enabled_totp(mfa_repository, user_id).await?.is_some() {
+        return Err(ArcanaError::Conflict(
+            "Two-factor authentication is already enabled".to_string(),
+        ));
+    }
+
+    let secret = generate_totp_secret();
+    let otpauth_uri = totp_provisioning_uri(&secret, issuer, &claims.email)?;
+    mfa_repository
+        .save_totp(&TotpCredential::new(user_id, secret.clone()))
+        .await?;
+
+    info!("TOTP enrolment started for user {}", user_id);
+    Ok(TotpEnrollmentResponse { secret, otpauth_uri })
+}
 
-        // Generate new tokens
-        self.create_auth_response(&user)
+/// Confirms a pending TOTP enrolment and issues recovery codes.
+async fn enable_user_totp(
+    mfa_repository: &dyn MfaRepository,
+    claims: &Claims,
+    request: TotpCodeRequest,
+) -> ArcanaResult<RecoveryCodesResponse> {
+    request.validate_request()?;
+    let user_id = claims_user_id(claims)?;
+
+    let mut credential = mfa_repository.find_totp_by_user(user_id).await?.ok_or_else(|| {
+        ArcanaError::BusinessRule("TOTP enrolment has not been started".to_string())
+    })?;
+    if credential.is_enabled() {
+        return Err(ArcanaError::Conflict(
+            "Two-factor authentication is already enabled".to_string(),
+        ));
     }
 
-    async fn validate_token(&self, token: &str) -> ArcanaResult<Claims> {
-        self.token_provider.validate_access_token(token)
+    let step = verify_totp(&credential.secret, &request.code, unix_now())?
+        .ok_or_else(invalid_mfa_code)?;
+    credential.enable(step);
+    mfa_repository.save_totp(&credential).await?;
+
+    let recovery_codes = issue_recovery_codes(mfa_repository, user_id).await?;
+
+    info!("Two-factor authentication enabled for user {}", user_id);
+    Ok(recovery_codes)
+}
+
+/// Turns two-factor authentication off after checking a second factor.
+async fn disable_user_totp(
+    mfa_repository: &dyn MfaRepository,
+    claims: &Claims,
+    request: TotpCodeRequest,
+) -> ArcanaResult<MessageResponse> {
+    request.validate_request()?;
+    let user_id = claims_user_id(claims)?;
+
+    require_enabled_totp(mfa_repository, user_id).await?;
+    if !verify_second_factor(mfa_repository, user_id, &request.code).await? {
+        return Err(invalid_mfa_code());
     }
 
-    async fn logout(&self, user_id: UserId) -> ArcanaResult<MessageResponse> {
-        debug!("Logging out user: {}", user_id);
+    mfa_repository.delete_by_user(user_id).await?;
 
-        // In a full implementation, you would invalidate refresh tokens in the database
-        // For now, we just return success (tokens will expire naturally)
+    info!("Two-factor authentication disabled for user {}", user_id);
+    Ok(MessageResponse::new("Two-factor authentication disabled"))
+}
 
-        info!("User logged out: {}", user_id);
-        Ok(MessageResponse::new("Successfully logged out"))
+/// Replaces the user's recovery codes after checking a TOTP code.
+async fn regenerate_user_recovery_codes(
+    mfa_repository: &dyn MfaRepository,
+    claims: &Claims,
+    request: TotpCodeRequest,
+) -> ArcanaResult<RecoveryCodesResponse> {
+    request.validate_request()?;
+    let user_id = claims_user_id(claims)?;
+
+    let credential = require_enabled_totp(mfa_repository, user_id).await?;
+    if !accept_totp_code(mfa_repository, &credential, &request.code).await? {
+        return Err(invalid_mfa_code());
     }
 
-    async fn get_current_user(&self, claims: &Claims) -> ArcanaResult<AuthUserInfo> {
-        let user_id = claims.user_id().ok_or_else(|| {
-            ArcanaError::InvalidToken("Invalid token: missing user ID".to_string())
-        })?;
+    let recovery_codes = issue_recovery_codes(mfa_repository, user_id).await?;
 
-        let user = self
-            .user_repository
-            .find_by_id(user_id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", user_id))?;
+    info!("Recovery codes regenerated for user {}", user_id);
+    Ok(recovery_codes)
+}
 
-        Ok(AuthUserInfo {
+async fn require_enabled_totp(
+    mfa_repository: &dyn MfaRepository,
+    user_id: UserId,
+) -> ArcanaResult<TotpCredential> {
+    find_enabled_totp(mfa_repository, user_id).await?.ok_or_else(|| {
+        ArcanaError::BusinessRule("Two-factor authentication is not enabled".to_string())
+    })
+}
+
+/// Generates a new set of recovery codes, storing only their hashes.
+async fn issue_recovery_codes(
+    mfa_repository: &dyn MfaRepository,
+    user_id: UserId,
+) -> ArcanaResult<RecoveryCodesResponse> {
+    let recovery_codes = generate_recovery_codes(RECOVERY_CODE_COUNT);
+    let code_hashes: Vec<String> = recovery_codes
+        .iter()
+        .map(|code| hash_token(&normalize_recovery_code(code)))
+        .collect();
+    mfa_repository.replace_recovery_codes(user_id, &code_hashes).await?;
+
+    Ok(RecoveryCodesResponse { recovery_codes })
+}
+
+fn claims_user_id(claims: &Claims) -> ArcanaResult<UserId> {
+    claims.user_id().ok_or_else(|| {
+        ArcanaError::InvalidToken("Invalid token: missing user ID".to_string())
+    })
+}
+
+fn invalid_mfa_code() -> ArcanaError {
+    ArcanaError::Validation("Invalid authentication code".to_string())
+}
+
+fn unix_now() -> u64 {
+    chrono::Utc::now().timestamp().max(0) as u64
+}
+
+/// Writes an auth security event to the security log and publishes it.
+async fn report_security_event<E: DomainEvent + 'static>(events: &dyn EventBusInterface, event: E) {
+    let payload = event.to_json().unwrap_or_default();
+    warn!(
+        target: "arcana::security",
+        event_type = event.event_type(),
+        aggregate_id = %event.aggregate_id(),
+        %payload,
+        "Security event"
+    );
+    events.emit(event).await;
+}
+
+/// Publishes a successful login.
+async fn report_login(events: &dyn EventBusInterface, user_id: UserId) {
+    let context = RequestContext::current();
+    events
+        .emit(LoginSucceeded::new(user_id, context.ip_address, context.user_agent))
+        .await;
+}
+
+/// Maps a user and token pair to the auth response DTO.
+fn to_auth_response(user: &User, tokens: TokenPair) -> AuthResponse {
+    AuthResponse {
+        access_token: tokens.access_token,
+        refresh_token: tokens.refresh_token,
+        token_type: tokens.token_type,
+        expires_in: tokens.access_expires_at - chrono::Utc::now().timestamp(),
+        user: AuthUserInfo {
             id: user.id,
-            username: user.username,
+            username: user.username.clone(),
             email: user.email.to_string(),
             role: user.role,
-            first_name: user.first_name,
-            last_name: user.last_name,
-        })
-    }
-}
-
-impl<R: UserRepository> std::fmt::Debug for AuthServiceImpl<R> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("AuthServiceImpl").finish_non_exhaustive()
+            first_name: user.first_name.clone(),
+            last_name: user.last_name.clone(),
+        },
     }
 }
 
 /// Concrete authentication service component for Shaku DI.
 ///
 /// This component uses dependency injection to receive its dependencies,
-/// providing compile-time verified DI through Shaku.
+/// providing compile-time verified DI through Shaku. It is the only
+/// [`AuthService`] implementation, so tokens are always issued by the injected
+/// provider with the configured algorithm and key ring.
 #[derive(Component)]
 #[shaku(interface = AuthService)]
 pub struct AuthServiceComponent {
     #[shaku(inject)]
     user_repository: Arc<dyn UserRepository>,
     #[shaku(inject)]
+    oauth_token_repository: Arc<dyn OAuthTokenRepository>,
+    #[shaku(inject)]
+    password_reset_token_repository: Arc<dyn PasswordResetTokenRepository>,
+    #[shaku(inject)]
+    mfa_repository: Arc<dyn MfaRepository>,
+    #[shaku(inject)]
     password_hasher: Arc<dyn PasswordHasherInterface>,
     #[shaku(inject)]
     token_provider: Arc<dyn TokenProviderInterface>,
+    #[shaku(inject)]
+    token_revocation: Arc<dyn TokenRevocationInterface>,
+    #[shaku(inject)]
+    mail_queue: Arc<dyn MailQueueInterface>,
+    #[shaku(inject)]
+    login_attempts: Arc<dyn LoginAttemptInterface>,
+    #[shaku(inject)]
+    events: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    security_config: Arc<dyn SecurityConfigInterface>,
+    #[shaku(inject)]
+    permission_resolver: Arc<dyn PermissionResolverInterface>,
+    #[shaku(inject)]
+    tenant_repository: Arc<dyn TenantRepository>,
 }
 
 impl AuthServiceComponent {
-    /// Creates an auth response for a user.
-    fn create_auth_response(&self, user: &User) -> ArcanaResult<AuthResponse> {
-        let tokens = self.token_provider.generate_tokens(
+    /// Creates an auth response for a user and stores its refresh token.
+    ///
+    /// The access token carries the user's tenant and effective permissions. A
+    /// `session_id` keeps the new refresh token in an existing token family;
+    /// otherwise a new family is started.
+    async fn create_auth_response(
+        &self,
+        user: &User,
+        session_id: Option<&str>,
+        device_id: Option<String>,
+    ) -> ArcanaResult<AuthResponse> {
+        let permissions = self.permission_resolver.resolve(user.id, user.role).await?;
+        let tokens = self.token_provider.generate_scoped_tokens(
             user.id,
             &user.username,
             user.email.as_str(),
             user.role,
+            &TokenScope::new(user.tenant_id, permissions),
+            session_id,
         )?;
 
-        Ok(AuthResponse {
-            access_token: tokens.access_token,
-            refresh_token: tokens.refresh_token,
-            token_type: tokens.token_type,
-            expires_in: tokens.access_expires_at - chrono::Utc::now().timestamp(),
-            user: AuthUserInfo {
-                id: user.id,
-                username: user.username.clone(),
-                email: user.email.to_string(),
-                role: user.role,
-                first_name: user.first_name.clone(),
-                last_name: user.last_name.clone(),
-            },
-        })
+        self.oauth_token_repository
+            .save(&new_refresh_token_record(user.id, &tokens, device_id))
+            .await?;
+
+        Ok(to_auth_response(user, tokens))
+    }
+
+    /// Exchanges an MFA challenge and a second factor for the signed-in user.
+    ///
+    /// The challenge is consumed atomically on success so it cannot be replayed.
+    async fn complete_mfa_challenge(&self, request: &MfaLoginRequest) -> ArcanaResult<User> {
+        request.validate_request()?;
+
+        let claims = self.token_provider.validate_mfa_challenge_token(&request.mfa_token)?;
+        if self.token_revocation.is_revoked(&claims).await? {
+            return Err(ArcanaError::InvalidToken("MFA challenge has already been used".to_string()));
+        }
+
+        let user_id = claims.user_id().ok_or_else(|| {
+            ArcanaError::InvalidToken("Invalid token: missing user ID".to_string())
+        })?;
+
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| user.status.can_login())
+            .ok_or(ArcanaError::InvalidCredentials)?;
+
+        if !verify_second_factor(self.mfa_repository.as_ref(), user_id, &request.code).await? {
+            let context = RequestContext::current();
+            report_security_event(
+                self.events.as_ref(),
+                LoginFailed::new(
+                    user.username.clone(),
+                    LoginFailureReason::InvalidMfaCode,
+                    context.ip_address.clone(),
+                    context.user_agent,
+                ),
+            )
+            .await;
+            let failures = record_account_failure(
+                self.user_repository.as_ref(),
+                self.login_attempts.as_ref(),
+                self.events.as_ref(),
+                self.security_config.lockout(),
+                &user,
+                context.ip_address,
+            )
+            .await?;
+            tokio::time::sleep(self.security_config.lockout().failure_delay(failures)).await;
+            return Err(ArcanaError::InvalidCredentials);
+        }
+
+        // Concurrent requests may all pass the checks above; only one redeems the challenge
+        if !self.token_revocation.consume_token(&claims.jti, claims.exp).await? {
+            return Err(ArcanaError::InvalidToken("MFA challenge has already been used".to_string()));
+        }
+        self.login_attempts.clear(LoginAttemptKey::Account(user.id)).await?;
+
+        let mut updated_user = user.clone();
+        updated_user.record_login();
+        let _ = self.user_repository.update(&updated_user).await;
+
+        info!("User logged in with MFA: {}", user.id);
+        Ok(user)
     }
 }
 
@@ -276,14 +955,17 @@ impl AuthService for AuthServiceComponent {
 
         request.validate_request()?;
 
-        if self.user_repository.exists_by_username(&request.username).await? {
+        let tenant_id = RequestContext::current().tenant_id();
+        require_active_tenant(self.tenant_repository.as_ref(), tenant_id).await?;
+
+        if self.user_repository.exists_by_username(tenant_id, &request.username).await? {
             return Err(ArcanaError::Conflict(format!(
                 "Username '{}' already exists",
                 request.username
             )));
         }
 
-        if self.user_repository.exists_by_email(&request.email).await? {
+        if self.user_repository.exists_by_email(tenant_id, &request.email).await? {
             return Err(ArcanaError::Conflict(format!(
                 "Email '{}' already exists",
                 request.email
@@ -295,59 +977,82 @@ impl AuthService for AuthServiceComponent {
 
         let password_hash = self.password_hasher.hash(&request.password)?;
 
-        let mut user = User::new(
+        let user = User::new(
             request.username,
             email,
             password_hash,
             request.first_name,
             request.last_name,
-        );
-
-        user.activate();
+        )
+        .in_tenant(tenant_id);
 
-        let saved_user = self.user_repository.save(&user).await?;
+        let events: Vec<Box<dyn DomainEvent>> =
+            vec![Box::new(UserCreated::new(user.id, user.username.clone(), user.email.to_string()))];
+        let saved_user = self.user_repository.save_with_events(&user, &events).await?;
 
         info!("User registered: {}", saved_user.id);
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
+
+        send_registration_verification(
+            self.token_provider.as_ref(),
+            self.mail_queue.as_ref(),
+            &saved_user,
+        )
+        .await;
 
-        self.create_auth_response(&saved_user)
+        self.create_auth_response(&saved_user, None, None).await
     }
 
-    async fn login(&self, request: LoginRequest) -> ArcanaResult<AuthResponse> {
+    async fn login(&self, request: LoginRequest) -> ArcanaResult<LoginResponse> {
         debug!("Login attempt for: {}", request.username_or_email);
 
         request.validate_request()?;
 
-        let user = self
-            .user_repository
-            .find_by_username_or_email(&request.username_or_email)
-            .await?
-            .ok_or_else(|| {
-                warn!("Login failed: user not found - {}", request.username_or_email);
-                ArcanaError::InvalidCredentials
-            })?;
+        require_active_tenant(self.tenant_repository.as_ref(), RequestContext::current().tenant_id()).await?;
 
-        if !user.status.can_login() {
-            warn!("Login failed: user status {:?} - {}", user.status, user.id);
-            return Err(match user.status {
-                UserStatus::Suspended => ArcanaError::Forbidden("Account is suspended".to_string()),
-                UserStatus::Locked => ArcanaError::Forbidden("Account is locked".to_string()),
-                UserStatus::Deleted => ArcanaError::InvalidCredentials,
-                _ => ArcanaError::Forbidden("Account is not active".to_string()),
-            });
-        }
+        let user = authenticate_credentials(
+            self.user_repository.as_ref(),
+            self.password_hasher.as_ref(),
+            self.login_attempts.as_ref(),
+            self.events.as_ref(),
+            self.security_config.lockout(),
+            &request,
+        )
+        .await?;
 
-        if !self.password_hasher.verify(&request.password, &user.password_hash)? {
-            warn!("Login failed: invalid password - {}", user.id);
-            return Err(ArcanaError::InvalidCredentials);
+        if let Some(challenge) = issue_mfa_challenge(
+            self.mfa_repository.as_ref(),
+            self.token_provider.as_ref(),
+            &user,
+            self.security_config.mfa_challenge_expiration_secs(),
+        )
+        .await?
+        {
+            info!("Password accepted, second factor required: {}", user.id);
+            return Ok(LoginResponse::MfaRequired(challenge));
         }
 
+        self.login_attempts
+            .clear(LoginAttemptKey::Account(user.id))
+            .await?;
+
         let mut updated_user = user.clone();
         updated_user.record_login();
         let _ = self.user_repository.update(&updated_user).await;
 
         info!("User logged in: {}", user.id);
+        report_login(self.events.as_ref(), user.id).await;
 
-        self.create_auth_response(&user)
+        self.create_auth_response(&user, None, request.device_id)
+            .await
+            .map(LoginResponse::Authenticated)
+    }
+
+    async fn complete_mfa_login(&self, request: MfaLoginRequest) -> ArcanaResult<AuthResponse> {
+        let user = self.complete_mfa_challenge(&request).await?;
+        report_login(self.events.as_ref(), user.id).await;
+
+        self.create_auth_response(&user, None, request.device_id).await
     }
 
     async fn refresh_token(&self, request: RefreshTokenRequest) -> ArcanaResult<AuthResponse> {
@@ -359,6 +1064,14 @@ impl AuthService for AuthServiceComponent {
             ArcanaError::InvalidToken("Invalid refresh token: missing user ID".to_string())
         })?;
 
+        let stored = rotate_refresh_token(
+            self.oauth_token_repository.as_ref(),
+            self.events.as_ref(),
+            &request.refresh_token,
+            user_id,
+        )
+        .await?;
+
         let user = self
             .user_repository
             .find_by_id(user_id)
@@ -368,20 +1081,37 @@ impl AuthService for AuthServiceComponent {
         if !user.status.can_login() {
             return Err(ArcanaError::Forbidden("Account is not active".to_string()));
         }
+        require_active_tenant(self.tenant_repository.as_ref(), user.tenant_id).await?;
 
         info!("Token refreshed for user: {}", user.id);
+        // The new pair stays in the family of the rotated token
+        self.events
+            .emit(TokenRefreshed::new(user.id, stored.family_id.clone(), stored.family_id.clone()))
+            .await;
 
-        self.create_auth_response(&user)
+        self.create_auth_response(&user, Some(&stored.family_id), stored.device_id)
+            .await
     }
 
     async fn validate_token(&self, token: &str) -> ArcanaResult<Claims> {
-        self.token_provider.validate_access_token(token)
+        authenticate_access_token(self.token_provider.as_ref(), self.token_revocation.as_ref(), token)
+            .await
     }
 
-    async fn logout(&self, user_id: UserId) -> ArcanaResult<MessageResponse> {
+    async fn logout(&self, claims: &Claims) -> ArcanaResult<MessageResponse> {
+        let user_id = claims.user_id().ok_or_else(|| {
+            ArcanaError::InvalidToken("Invalid token: missing user ID".to_string())
+        })?;
         debug!("Logging out user: {}", user_id);
 
-        info!("User logged out: {}", user_id);
+        // Every session ends: no refresh token can be exchanged and no access token is accepted
+        let revoked = self.oauth_token_repository.revoke_all_by_user(user_id).await?;
+        self.token_revocation.revoke_user_tokens(user_id).await?;
+
+        info!("User logged out: {} ({} refresh tokens revoked)", user_id, revoked);
+        self.events
+            .emit(LogoutOccurred::new(user_id, claims.session_id.clone()))
+            .await;
         Ok(MessageResponse::new("Successfully logged out"))
     }
 
@@ -405,6 +1135,104 @@ impl AuthService for AuthServiceComponent {
             last_name: user.last_name,
         })
     }
+
+    async fn list_sessions(&self, claims: &Claims) -> ArcanaResult<Vec<SessionInfo>> {
+        list_user_sessions(self.oauth_token_repository.as_ref(), claims).await
+    }
+
+    async fn revoke_session(&self, claims: &Claims, session_id: &str) -> ArcanaResult<MessageResponse> {
+        revoke_user_session(
+            self.oauth_token_repository.as_ref(),
+            self.token_revocation.as_ref(),
+            claims,
+            session_id,
+        )
+        .await
+    }
+
+    async fn revoke_other_sessions(&self, claims: &Claims) -> ArcanaResult<MessageResponse> {
+        revoke_other_user_sessions(
+            self.oauth_token_repository.as_ref(),
+            self.token_revocation.as_ref(),
+            claims,
+        )
+        .await
+    }
+
+    async fn verify_email(&self, request: VerifyEmailRequest) -> ArcanaResult<MessageResponse> {
+        verify_user_email(
+            self.user_repository.as_ref(),
+            self.token_provider.as_ref(),
+            self.events.as_ref(),
+            request,
+        )
+        .await
+    }
+
+    async fn resend_verification(
+        &self,
+        request: ResendVerificationRequest,
+    ) -> ArcanaResult<MessageResponse> {
+        resend_user_verification(
+            self.user_repository.as_ref(),
+            self.token_provider.as_ref(),
+            self.mail_queue.as_ref(),
+            request,
+        )
+        .await
+    }
+
+    async fn forgot_password(&self, request: PasswordResetRequest) -> ArcanaResult<MessageResponse> {
+        request_password_reset(
+            self.user_repository.as_ref(),
+            self.password_reset_token_repository.as_ref(),
+            self.mail_queue.as_ref(),
+            self.events.as_ref(),
+            Duration::from_secs(self.security_config.password_reset_expiration_secs()),
+            request,
+        )
+        .await
+    }
+
+    async fn reset_password(
+        &self,
+        request: PasswordResetConfirmRequest,
+    ) -> ArcanaResult<MessageResponse> {
+        reset_user_password(
+            self.user_repository.as_ref(),
+            self.oauth_token_repository.as_ref(),
+            self.password_reset_token_repository.as_ref(),
+            self.token_revocation.as_ref(),
+            self.password_hasher.as_ref(),
+            self.events.as_ref(),
+            request,
+        )
+        .await
+    }
+
+    async fn enroll_totp(&self, claims: &Claims) -> ArcanaResult<TotpEnrollmentResponse> {
+        enroll_user_totp(self.mfa_repository.as_ref(), self.security_config.mfa_issuer(), claims).await
+    }
+
+    async fn enable_totp(
+        &self,
+        claims: &Claims,
+        request: TotpCodeRequest,
+    ) -> ArcanaResult<RecoveryCodesResponse> {
+        enable_user_totp(self.mfa_repository.as_ref(), claims, request).await
+    }
+
+    async fn disable_totp(&self, claims: &Claims, request: TotpCodeRequest) -> ArcanaResult<MessageResponse> {
+        disable_user_totp(self.mfa_repository.as_ref(), claims, request).await
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        claims: &Claims,
+        request: TotpCodeRequest,
+    ) -> ArcanaResult<RecoveryCodesResponse> {
+        regenerate_user_recovery_codes(self.mfa_repository.as_ref(), claims, request).await
+    }
 }
 
 impl std::fmt::Debug for AuthServiceComponent {
@@ -416,11 +1244,17 @@ impl std::fmt::Debug for AuthServiceComponent {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::events::{EventBus, EventSubscriber};
+    use crate::mail::{MailQueue, Mailer};
+    use arcana_config::{LockoutConfig, MailConfig, SecurityConfig};
     use arcana_core::Page;
-    use arcana_core::{Email, User, UserRole, UserStatus};
-    use arcana_security::Claims;
+    use arcana_core::{Email, Permission, PermissionSet, Tenant, User, UserRole, UserStatus};
+    use arcana_security::{
+        generate_totp_code, Claims, LoginAttemptStore, PasswordHasher, TokenProvider, TokenRevocationStore,
+        TOTP_STEP_SECS,
+    };
     use async_trait::async_trait;
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     use std::sync::Mutex;
 
     /// Mock user repository for testing.
@@ -448,43 +1282,46 @@ mod tests {
             Ok(self.users.lock().unwrap().get(&id).cloned())
         }
 
-        async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.username == username)
+                .find(|u| u.tenant_id == tenant_id && u.username == username)
                 .cloned())
         }
 
-        async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.email.as_str() == email)
+                .find(|u| u.tenant_id == tenant_id && u.email.as_str() == email)
                 .cloned())
         }
 
-        async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.username == identifier || u.email.as_str() == identifier)
+                .find(|u| u.tenant_id == tenant_id && (u.username == identifier || u.email.as_str() == identifier))
                 .cloned())
         }
 
-        async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool> {
+        async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool> {
             Ok(self.users.lock().unwrap().values()
-                .any(|u| u.username == username))
+                .any(|u| u.tenant_id == tenant_id && u.username == username))
         }
 
-        async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool> {
+        async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool> {
             Ok(self.users.lock().unwrap().values()
-                .any(|u| u.email.as_str().to_lowercase() == email.to_lowercase()))
+                .any(|u| u.tenant_id == tenant_id && u.email.as_str().to_lowercase() == email.to_lowercase()))
         }
 
-        async fn find_all(&self, page: arcana_core::PageRequest) -> ArcanaResult<Page<User>> {
-            let users: Vec<User> = self.users.lock().unwrap().values().cloned().collect();
+        async fn find_all(&self, tenant_id: TenantId, page: arcana_core::PageRequest) -> ArcanaResult<Page<User>> {
+            let users: Vec<User> = self.users.lock().unwrap().values()
+                .filter(|u| u.tenant_id == tenant_id)
+                .cloned()
+                .collect();
             let total = users.len() as u64;
             Ok(Page::new(users, page.page, page.size, total))
         }
 
-        async fn find_by_role(&self, role: UserRole, page: arcana_core::PageRequest) -> ArcanaResult<Page<User>> {
+        async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: arcana_core::PageRequest) -> ArcanaResult<Page<User>> {
             let users: Vec<User> = self.users.lock().unwrap().values()
-                .filter(|u| u.role == role)
+                .filter(|u| u.tenant_id == tenant_id && u.role == role)
                 .cloned()
                 .collect();
             let total = users.len() as u64;
@@ -508,17 +1345,292 @@ mod tests {
             Ok(users.remove(&id).is_some())
         }
 
-        async fn count(&self) -> ArcanaResult<u64> {
-            Ok(self.users.lock().unwrap().len() as u64)
+        async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64> {
+            Ok(self.users.lock().unwrap().values().filter(|u| u.tenant_id == tenant_id).count() as u64)
         }
 
-        async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64> {
+        async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64> {
             Ok(self.users.lock().unwrap().values()
-                .filter(|u| u.role == role)
+                .filter(|u| u.tenant_id == tenant_id && u.role == role)
                 .count() as u64)
         }
     }
 
+    /// In-memory OAuth token repository for testing.
+    struct MockOAuthTokenRepository {
+        tokens: Mutex<HashMap<arcana_core::OAuthTokenId, OAuthToken>>,
+    }
+
+    impl MockOAuthTokenRepository {
+        fn new() -> Self {
+            Self {
+                tokens: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn all(&self) -> Vec<OAuthToken> {
+            self.tokens.lock().unwrap().values().cloned().collect()
+        }
+    }
+
+    #[async_trait]
+    impl OAuthTokenRepository for MockOAuthTokenRepository {
+        async fn find_by_id(&self, id: arcana_core::OAuthTokenId) -> ArcanaResult<Option<OAuthToken>> {
+            Ok(self.tokens.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<OAuthToken>> {
+            Ok(self.tokens.lock().unwrap().values()
+                .find(|t| t.token_hash == token_hash)
+                .cloned())
+        }
+
+        async fn save(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken> {
+            self.tokens.lock().unwrap().insert(token.id, token.clone());
+            Ok(token.clone())
+        }
+
+        async fn update(&self, token: &OAuthToken) -> ArcanaResult<OAuthToken> {
+            self.tokens.lock().unwrap().insert(token.id, token.clone());
+            Ok(token.clone())
+        }
+
+        async fn find_active_by_user(&self, user_id: UserId) -> ArcanaResult<Vec<OAuthToken>> {
+            let mut tokens: Vec<OAuthToken> = self.tokens.lock().unwrap().values()
+                .filter(|t| t.user_id == user_id && t.is_valid())
+                .cloned()
+                .collect();
+            tokens.sort_by_key(|t| std::cmp::Reverse(t.created_at));
+            Ok(tokens)
+        }
+
+        async fn revoke_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64> {
+            let mut revoked = 0;
+            for token in self.tokens.lock().unwrap().values_mut() {
+                if token.user_id == user_id && !token.revoked {
+                    token.revoke();
+                    revoked += 1;
+                }
+            }
+            Ok(revoked)
+        }
+
+        async fn mark_rotated(&self, id: arcana_core::OAuthTokenId) -> ArcanaResult<bool> {
+            match self.tokens.lock().unwrap().get_mut(&id) {
+                Some(token) if !token.revoked => {
+                    token.record_usage();
+                    token.revoke();
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn revoke_family(&self, family_id: &str) -> ArcanaResult<u64> {
+            let mut revoked = 0;
+            for token in self.tokens.lock().unwrap().values_mut() {
+                if token.family_id == family_id && !token.revoked {
+                    token.revoke();
+                    revoked += 1;
+                }
+            }
+            Ok(revoked)
+        }
+    }
+
+    /// In-memory password reset token repository for testing.
+    #[derive(Default)]
+    struct MockPasswordResetTokenRepository {
+        tokens: Mutex<HashMap<arcana_core::PasswordResetTokenId, PasswordResetToken>>,
+    }
+
+    impl MockPasswordResetTokenRepository {
+        fn expire_all(&self) {
+            for token in self.tokens.lock().unwrap().values_mut() {
+                token.expires_at = chrono::Utc::now() - chrono::Duration::seconds(1);
+            }
+        }
+    }
+
+    #[async_trait]
+    impl PasswordResetTokenRepository for MockPasswordResetTokenRepository {
+        async fn find_by_id(
+            &self,
+            id: arcana_core::PasswordResetTokenId,
+        ) -> ArcanaResult<Option<PasswordResetToken>> {
+            Ok(self.tokens.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn find_by_token_hash(&self, token_hash: &str) -> ArcanaResult<Option<PasswordResetToken>> {
+            Ok(self.tokens.lock().unwrap().values()
+                .find(|t| t.token_hash == token_hash)
+                .cloned())
+        }
+
+        async fn save(&self, token: &PasswordResetToken) -> ArcanaResult<PasswordResetToken> {
+            self.tokens.lock().unwrap().insert(token.id, token.clone());
+            Ok(token.clone())
+        }
+
+        async fn mark_used(&self, id: arcana_core::PasswordResetTokenId) -> ArcanaResult<bool> {
+            match self.tokens.lock().unwrap().get_mut(&id) {
+                Some(token) if !token.is_used() => {
+                    token.mark_used();
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn invalidate_all_by_user(&self, user_id: UserId) -> ArcanaResult<u64> {
+            let mut invalidated = 0;
+            for token in self.tokens.lock().unwrap().values_mut() {
+                if token.user_id == user_id && !token.is_used() {
+                    token.mark_used();
+                    invalidated += 1;
+                }
+            }
+            Ok(invalidated)
+        }
+    }
+
+    /// In-memory MFA repository for testing.
+    #[derive(Default)]
+    struct MockMfaRepository {
+        credentials: Mutex<HashMap<UserId, TotpCredential>>,
+        recovery_codes: Mutex<Vec<(UserId, String, bool)>>,
+    }
+
+    impl MockMfaRepository {
+        fn unused_recovery_codes(&self, user_id: UserId) -> usize {
+            self.recovery_codes.lock().unwrap().iter()
+                .filter(|(owner, _, used)| *owner == user_id && !used)
+                .count()
+        }
+    }
+
+    #[async_trait]
+    impl MfaRepository for MockMfaRepository {
+        async fn find_totp_by_user(&self, user_id: UserId) -> ArcanaResult<Option<TotpCredential>> {
+            Ok(self.credentials.lock().unwrap().get(&user_id).cloned())
+        }
+
+        async fn save_totp(&self, credential: &TotpCredential) -> ArcanaResult<TotpCredential> {
+            self.credentials.lock().unwrap().insert(credential.user_id, credential.clone());
+            Ok(credential.clone())
+        }
+
+        async fn record_totp_step(&self, user_id: UserId, step: i64) -> ArcanaResult<bool> {
+            match self.credentials.lock().unwrap().get_mut(&user_id) {
+                Some(credential) if credential.last_used_step.is_none_or(|last| last < step) => {
+                    credential.last_used_step = Some(step);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn delete_by_user(&self, user_id: UserId) -> ArcanaResult<bool> {
+            self.recovery_codes.lock().unwrap().retain(|(owner, _, _)| *owner != user_id);
+            Ok(self.credentials.lock().unwrap().remove(&user_id).is_some())
+        }
+
+        async fn replace_recovery_codes(&self, user_id: UserId, code_hashes: &[String]) -> ArcanaResult<()> {
+            let mut codes = self.recovery_codes.lock().unwrap();
+            codes.retain(|(owner, _, _)| *owner != user_id);
+            codes.extend(code_hashes.iter().map(|hash| (user_id, hash.clone(), false)));
+            Ok(())
+        }
+
+        async fn consume_recovery_code(&self, user_id: UserId, code_hash: &str) -> ArcanaResult<bool> {
+            let mut codes = self.recovery_codes.lock().unwrap();
+            match codes.iter_mut().find(|(owner, hash, used)| *owner == user_id && hash == code_hash && !used) {
+                Some(code) => {
+                    code.2 = true;
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+    }
+
+    /// Mailer that records sent messages.
+    #[derive(Default)]
+    struct RecordingMailer {
+        sent: Mutex<Vec<EmailMessage>>,
+    }
+
+    impl RecordingMailer {
+        fn sent(&self) -> Vec<EmailMessage> {
+            self.sent.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl Mailer for RecordingMailer {
+        async fn send(&self, message: &EmailMessage) -> ArcanaResult<()> {
+            self.sent.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    /// Tenant repository knowing every tenant; those deactivated refuse sign-ins.
+    #[derive(Debug, Default)]
+    struct MockTenantRepository {
+        inactive: Mutex<HashSet<TenantId>>,
+    }
+
+    impl MockTenantRepository {
+        fn deactivate(&self, tenant_id: TenantId) {
+            self.inactive.lock().unwrap().insert(tenant_id);
+        }
+    }
+
+    #[async_trait]
+    impl TenantRepository for MockTenantRepository {
+        async fn find_by_id(&self, id: TenantId) -> ArcanaResult<Option<Tenant>> {
+            let mut tenant = Tenant::new(id.to_string(), "Tenant".to_string());
+            tenant.id = id;
+            tenant.active = !self.inactive.lock().unwrap().contains(&id);
+            Ok(Some(tenant))
+        }
+
+        async fn find_by_slug(&self, _slug: &str) -> ArcanaResult<Option<Tenant>> {
+            Ok(None)
+        }
+
+        async fn find_all(&self, page: arcana_core::PageRequest) -> ArcanaResult<Page<Tenant>> {
+            Ok(Page::new(Vec::new(), page.page, page.size, 0))
+        }
+
+        async fn save(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+            Ok(tenant.clone())
+        }
+
+        async fn update(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+            Ok(tenant.clone())
+        }
+    }
+
+    /// Resolver granting `grants` on top of the built-in permissions of the role.
+    #[derive(Debug, Default)]
+    struct GrantingResolver {
+        grants: PermissionSet,
+    }
+
+    #[async_trait]
+    impl PermissionResolverInterface for GrantingResolver {
+        async fn resolve(&self, _user_id: UserId, role: UserRole) -> ArcanaResult<PermissionSet> {
+            let mut permissions = role.default_permissions();
+            permissions.extend(self.grants.iter());
+            Ok(permissions)
+        }
+
+        async fn invalidate_user(&self, _user_id: UserId) {}
+
+        async fn invalidate_all(&self) {}
+    }
+
     fn create_test_config() -> Arc<SecurityConfig> {
         Arc::new(SecurityConfig {
             jwt_secret: "test-secret-key-for-testing-only".to_string(),
@@ -530,6 +1642,11 @@ mod tests {
             tls_cert_path: None,
             tls_key_path: None,
             password_hash_cost: 4,
+            lockout: LockoutConfig {
+                delay_base_ms: 0,
+                ..LockoutConfig::default()
+            },
+            ..Default::default()
         })
     }
 
@@ -546,14 +1663,114 @@ mod tests {
         user
     }
 
-    fn create_auth_service(repo: MockUserRepository) -> AuthServiceImpl<MockUserRepository> {
-        AuthServiceImpl::new(
-            Arc::new(repo),
-            Arc::new(PasswordHasher::new()),
-            create_test_config(),
+    type TestAuthService = AuthServiceComponent;
+
+    fn create_auth_service(repo: MockUserRepository) -> TestAuthService {
+        create_auth_service_with_tokens(repo, Arc::new(MockOAuthTokenRepository::new()))
+    }
+
+    fn create_auth_service_with_tokens(
+        repo: MockUserRepository,
+        tokens: Arc<MockOAuthTokenRepository>,
+    ) -> TestAuthService {
+        create_auth_service_with_mailer(repo, tokens, Arc::new(RecordingMailer::default()))
+    }
+
+    fn create_auth_service_with_mailer(
+        repo: MockUserRepository,
+        tokens: Arc<MockOAuthTokenRepository>,
+        mailer: Arc<RecordingMailer>,
+    ) -> TestAuthService {
+        create_auth_service_with_dependencies(repo, tokens, mailer, Arc::new(MockMfaRepository::default()))
+    }
+
+    fn create_auth_service_with_mfa(repo: MockUserRepository, mfa: Arc<MockMfaRepository>) -> TestAuthService {
+        create_auth_service_with_dependencies(
+            repo,
+            Arc::new(MockOAuthTokenRepository::new()),
+            Arc::new(RecordingMailer::default()),
+            mfa,
         )
     }
 
+    fn create_auth_service_with_dependencies(
+        repo: MockUserRepository,
+        tokens: Arc<MockOAuthTokenRepository>,
+        mailer: Arc<RecordingMailer>,
+        mfa: Arc<MockMfaRepository>,
+    ) -> TestAuthService {
+        AuthServiceComponent {
+            oauth_token_repository: tokens,
+            mfa_repository: mfa,
+            mail_queue: Arc::new(MailQueue::inline(mailer, MailConfig::default())),
+            ..create_auth_component(
+                repo,
+                Arc::new(GrantingResolver::default()),
+                Arc::new(MockTenantRepository::default()),
+            )
+        }
+    }
+
+    /// Builds the DI component the way the module wires it.
+    fn create_auth_component(
+        repo: MockUserRepository,
+        resolver: Arc<GrantingResolver>,
+        tenants: Arc<MockTenantRepository>,
+    ) -> AuthServiceComponent {
+        let config = create_test_config();
+        AuthServiceComponent {
+            user_repository: Arc::new(repo),
+            oauth_token_repository: Arc::new(MockOAuthTokenRepository::new()),
+            password_reset_token_repository: Arc::new(MockPasswordResetTokenRepository::default()),
+            mfa_repository: Arc::new(MockMfaRepository::default()),
+            password_hasher: Arc::new(PasswordHasher::new()),
+            token_provider: Arc::new(TokenProvider::from_config(config.clone()).unwrap()),
+            token_revocation: Arc::new(TokenRevocationStore::in_memory(std::time::Duration::from_secs(3600))),
+            mail_queue: Arc::new(MailQueue::inline(Arc::new(RecordingMailer::default()), MailConfig::default())),
+            login_attempts: Arc::new(LoginAttemptStore::in_memory(std::time::Duration::from_secs(900))),
+            events: Arc::new(EventBus::new()),
+            security_config: config,
+            permission_resolver: resolver,
+            tenant_repository: tenants,
+        }
+    }
+
+    /// Collects the types of the events published on a bus.
+    fn record_event_types(events: &dyn EventBusInterface) -> Arc<Mutex<Vec<String>>> {
+        let published = Arc::new(Mutex::new(Vec::new()));
+        let log = published.clone();
+        events.subscribe_handler(EventSubscriber::Sync(Arc::new(move |event: &dyn DomainEvent| {
+            log.lock().unwrap().push(event.event_type().to_string());
+            Ok(())
+        })));
+        published
+    }
+
+    fn register_request() -> RegisterRequest {
+        RegisterRequest {
+            username: "newuser".to_string(),
+            email: "new@example.com".to_string(),
+            password: "Password123".to_string(),
+            first_name: None,
+            last_name: None,
+        }
+    }
+
+    /// Extracts the token from the link in a verification email.
+    fn verification_token(message: &EmailMessage) -> String {
+        let start = message.body.find("token=").expect("verification link") + "token=".len();
+        message.body[start..].split_whitespace().next().unwrap().to_string()
+    }
+
+    fn login_request() -> LoginRequest {
+        LoginRequest {
+            username_or_email: "testuser".to_string(),
+            password: "Password123".to_string(),
+            device_id: Some("device-1".to_string()),
+            ip_address: None,
+        }
+    }
+
     #[tokio::test]
     async fn test_register_success() {
         let repo = MockUserRepository::new();
@@ -629,11 +1846,12 @@ mod tests {
             username_or_email: "testuser".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         let result = service.login(request).await;
         assert!(result.is_ok());
-        let response = result.unwrap();
+        let response = result.unwrap().into_authenticated().unwrap();
         assert!(!response.access_token.is_empty());
         assert_eq!(response.user.username, "testuser");
     }
@@ -648,6 +1866,7 @@ mod tests {
             username_or_email: "test@example.com".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         let result = service.login(request).await;
@@ -664,6 +1883,7 @@ mod tests {
             username_or_email: "testuser".to_string(),
             password: "WrongPassword".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         let result = service.login(request).await;
@@ -674,6 +1894,32 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_login_is_scoped_to_request_tenant() {
+        let tenant_id = TenantId::new();
+        let user = create_active_user_with_password("Password123").in_tenant(tenant_id);
+        let service = create_auth_service(MockUserRepository::with_user(user));
+        let request = || LoginRequest {
+            username_or_email: "testuser".to_string(),
+            password: "Password123".to_string(),
+            device_id: None,
+            ip_address: None,
+        };
+
+        let result = service.login(request()).await;
+        assert!(matches!(result, Err(ArcanaError::InvalidCredentials)));
+
+        let response = RequestContext::default()
+            .with_tenant(Some(tenant_id))
+            .scope(service.login(request()))
+            .await
+            .unwrap()
+            .into_authenticated()
+            .unwrap();
+        let claims = service.validate_token(&response.access_token).await.unwrap();
+        assert_eq!(claims.tenant_id(), tenant_id);
+    }
+
     #[tokio::test]
     async fn test_login_user_not_found() {
         let repo = MockUserRepository::new();
@@ -683,6 +1929,7 @@ mod tests {
             username_or_email: "nonexistent".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         let result = service.login(request).await;
@@ -704,6 +1951,7 @@ mod tests {
             username_or_email: "testuser".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         let result = service.login(request).await;
@@ -721,42 +1969,272 @@ mod tests {
         let repo = MockUserRepository::with_user(user);
         let service = create_auth_service(repo);
 
-        let request = LoginRequest {
-            username_or_email: "testuser".to_string(),
-            password: "Password123".to_string(),
-            device_id: None,
-        };
+        let request = LoginRequest {
+            username_or_email: "testuser".to_string(),
+            password: "Password123".to_string(),
+            device_id: None,
+            ip_address: None,
+        };
+
+        let result = service.login(request).await;
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            ArcanaError::Forbidden(msg) => assert!(msg.contains("locked")),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_success() {
+        let user = create_active_user_with_password("Password123");
+        let repo = MockUserRepository::with_user(user.clone());
+        let service = create_auth_service(repo);
+
+        // First login to get tokens
+        let login_request = LoginRequest {
+            username_or_email: "testuser".to_string(),
+            password: "Password123".to_string(),
+            device_id: None,
+            ip_address: None,
+        };
+        let login_response = service.login(login_request).await.unwrap().into_authenticated().unwrap();
+
+        // Now refresh
+        let refresh_request = RefreshTokenRequest {
+            refresh_token: login_response.refresh_token,
+        };
+        let result = service.refresh_token(refresh_request).await;
+        assert!(result.is_ok());
+        let response = result.unwrap();
+        assert!(!response.access_token.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_login_stores_hashed_refresh_token() {
+        let user = create_active_user_with_password("Password123");
+        let user_id = user.id;
+        let tokens = Arc::new(MockOAuthTokenRepository::new());
+        let service = create_auth_service_with_tokens(MockUserRepository::with_user(user), tokens.clone());
+
+        let response = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+
+        let stored = tokens.all();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].user_id, user_id);
+        assert_eq!(stored[0].token_hash, hash_token(&response.refresh_token));
+        assert_ne!(stored[0].token_hash, response.refresh_token);
+        assert_eq!(stored[0].device_id.as_deref(), Some("device-1"));
+        assert!(stored[0].is_valid());
+    }
+
+    #[tokio::test]
+    async fn test_register_stores_refresh_token() {
+        let tokens = Arc::new(MockOAuthTokenRepository::new());
+        let service = create_auth_service_with_tokens(MockUserRepository::new(), tokens.clone());
+
+        let request = RegisterRequest {
+            username: "newuser".to_string(),
+            email: "new@example.com".to_string(),
+            password: "Password123".to_string(),
+            first_name: None,
+            last_name: None,
+        };
+        let response = service.register(request).await.unwrap();
+
+        let stored = tokens.all();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].token_hash, hash_token(&response.refresh_token));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_rotates_within_family() {
+        let user = create_active_user_with_password("Password123");
+        let tokens = Arc::new(MockOAuthTokenRepository::new());
+        let service = create_auth_service_with_tokens(MockUserRepository::with_user(user), tokens.clone());
+
+        let login_response = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let refreshed = service
+            .refresh_token(RefreshTokenRequest {
+                refresh_token: login_response.refresh_token.clone(),
+            })
+            .await
+            .unwrap();
+        assert_ne!(refreshed.refresh_token, login_response.refresh_token);
+
+        let original = tokens
+            .find_by_token_hash(&hash_token(&login_response.refresh_token))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(original.last_used_at.is_some());
+        assert!(original.revoked);
+
+        let rotated = tokens
+            .find_by_token_hash(&hash_token(&refreshed.refresh_token))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(rotated.is_valid());
+        assert_eq!(rotated.family_id, original.family_id);
+        assert_eq!(rotated.device_id.as_deref(), Some("device-1"));
+
+        // The rotated token can itself be exchanged
+        service
+            .refresh_token(RefreshTokenRequest {
+                refresh_token: refreshed.refresh_token,
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_reuse_revokes_family() {
+        let user = create_active_user_with_password("Password123");
+        let tokens = Arc::new(MockOAuthTokenRepository::new());
+        let service = create_auth_service_with_tokens(MockUserRepository::with_user(user), tokens.clone());
+
+        let session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let other_session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let refreshed = service
+            .refresh_token(RefreshTokenRequest {
+                refresh_token: session.refresh_token.clone(),
+            })
+            .await
+            .unwrap();
+
+        // Replaying the already-rotated token is treated as theft
+        let result = service
+            .refresh_token(RefreshTokenRequest {
+                refresh_token: session.refresh_token,
+            })
+            .await;
+        match result {
+            Err(ArcanaError::InvalidToken(msg)) => assert!(msg.contains("reuse")),
+            other => panic!("Expected reuse detection, got {:?}", other.map(|_| ())),
+        }
+
+        // The newest token of the family is revoked too
+        let result = service
+            .refresh_token(RefreshTokenRequest {
+                refresh_token: refreshed.refresh_token,
+            })
+            .await;
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+
+        // Other sessions are unaffected
+        service
+            .refresh_token(RefreshTokenRequest {
+                refresh_token: other_session.refresh_token,
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_unknown_to_server_rejected() {
+        let user = create_active_user_with_password("Password123");
+        let service = create_auth_service(MockUserRepository::with_user(user.clone()));
+
+        // Validly signed, but never issued through the service
+        let provider = TokenProvider::new(create_test_config());
+        let pair = provider
+            .generate_tokens(user.id, &user.username, user.email.as_str(), user.role)
+            .unwrap();
+
+        let result = service
+            .refresh_token(RefreshTokenRequest {
+                refresh_token: pair.refresh_token,
+            })
+            .await;
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+    }
+
+    #[tokio::test]
+    async fn test_logout_revokes_refresh_tokens() {
+        let user = create_active_user_with_password("Password123");
+        let tokens = Arc::new(MockOAuthTokenRepository::new());
+        let service = create_auth_service_with_tokens(MockUserRepository::with_user(user), tokens.clone());
+
+        let first = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let second = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+
+        let claims = service.validate_token(&first.access_token).await.unwrap();
+        service.logout(&claims).await.unwrap();
+        assert!(tokens.all().iter().all(|t| t.revoked));
 
-        let result = service.login(request).await;
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            ArcanaError::Forbidden(msg) => assert!(msg.contains("locked")),
-            _ => panic!("Expected Forbidden error"),
+        for refresh_token in [first.refresh_token, second.refresh_token] {
+            let result = service.refresh_token(RefreshTokenRequest { refresh_token }).await;
+            match result {
+                Err(ArcanaError::InvalidToken(msg)) => assert!(msg.contains("revoked")),
+                other => panic!("Expected revoked refresh token, got {:?}", other.map(|_| ())),
+            }
         }
     }
 
     #[tokio::test]
-    async fn test_refresh_token_success() {
-        let user = create_active_user_with_password("Password123");
-        let repo = MockUserRepository::with_user(user.clone());
-        let service = create_auth_service(repo);
+    async fn test_session_lifecycle_publishes_events() {
+        let service = create_auth_service(MockUserRepository::new());
+        let published = record_event_types(service.events.as_ref());
+        let logins = Arc::new(Mutex::new(Vec::new()));
+        let seen = logins.clone();
+        service.events.subscribe_fn(move |event: &LoginSucceeded| {
+            seen.lock().unwrap().push((event.ip_address.clone(), event.user_agent.clone()));
+            Ok(())
+        });
+
+        let registered = service.register(register_request()).await.unwrap();
+        let mut user = service.user_repository.find_by_id(registered.user.id).await.unwrap().unwrap();
+        user.status = UserStatus::Active;
+        service.user_repository.update(&user).await.unwrap();
+
+        let context = RequestContext::new(Some("203.0.113.7".to_string()), Some("curl/8.0".to_string()));
+        let session = context
+            .scope(service.login(LoginRequest {
+                username_or_email: "newuser".to_string(),
+                ..login_request()
+            }))
+            .await
+            .unwrap()
+            .into_authenticated()
+            .unwrap();
+        let refreshed = service
+            .refresh_token(RefreshTokenRequest {
+                refresh_token: session.refresh_token,
+            })
+            .await
+            .unwrap();
+        let claims = service.validate_token(&refreshed.access_token).await.unwrap();
+        service.logout(&claims).await.unwrap();
+
+        assert_eq!(
+            *published.lock().unwrap(),
+            vec!["user.created", "auth.login_succeeded", "auth.token_refreshed", "auth.logout"]
+        );
+        assert_eq!(
+            *logins.lock().unwrap(),
+            vec![(Some("203.0.113.7".to_string()), Some("curl/8.0".to_string()))]
+        );
+    }
 
-        // First login to get tokens
-        let login_request = LoginRequest {
-            username_or_email: "testuser".to_string(),
-            password: "Password123".to_string(),
-            device_id: None,
-        };
-        let login_response = service.login(login_request).await.unwrap();
+    #[tokio::test]
+    async fn test_rotate_refresh_token_rejects_other_user() {
+        let tokens = MockOAuthTokenRepository::new();
+        let token = OAuthToken::new(
+            UserId::new(),
+            hash_token("refresh"),
+            "family".to_string(),
+            chrono::Utc::now() + chrono::Duration::days(1),
+            None,
+            None,
+            None,
+        );
+        tokens.save(&token).await.unwrap();
 
-        // Now refresh
-        let refresh_request = RefreshTokenRequest {
-            refresh_token: login_response.refresh_token,
-        };
-        let result = service.refresh_token(refresh_request).await;
-        assert!(result.is_ok());
-        let response = result.unwrap();
-        assert!(!response.access_token.is_empty());
+        let events = EventBus::new();
+        assert!(rotate_refresh_token(&tokens, &events, "refresh", UserId::new()).await.is_err());
+        assert!(rotate_refresh_token(&tokens, &events, "unknown", token.user_id).await.is_err());
+        assert!(tokens.all()[0].is_valid());
+        assert!(rotate_refresh_token(&tokens, &events, "refresh", token.user_id).await.is_ok());
     }
 
     #[tokio::test]
@@ -782,8 +2260,9 @@ mod tests {
             username_or_email: "testuser".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
-        let login_response = service.login(login_request).await.unwrap();
+        let login_response = service.login(login_request).await.unwrap().into_authenticated().unwrap();
 
         let result = service.validate_token(&login_response.access_token).await;
         assert!(result.is_ok());
@@ -803,15 +2282,152 @@ mod tests {
     #[tokio::test]
     async fn test_logout() {
         let user = create_active_user_with_password("Password123");
-        let user_id = user.id;
         let repo = MockUserRepository::with_user(user);
         let service = create_auth_service(repo);
 
-        let result = service.logout(user_id).await;
+        let login_response = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let claims = service.validate_token(&login_response.access_token).await.unwrap();
+
+        let result = service.logout(&claims).await;
         assert!(result.is_ok());
         assert!(result.unwrap().message.contains("logged out"));
     }
 
+    #[tokio::test]
+    async fn test_logout_revokes_access_token() {
+        let user = create_active_user_with_password("Password123");
+        let service = create_auth_service(MockUserRepository::with_user(user));
+
+        let session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let other_session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let claims = service.validate_token(&session.access_token).await.unwrap();
+
+        service.logout(&claims).await.unwrap();
+
+        for access_token in [session.access_token, other_session.access_token] {
+            let result = service.validate_token(&access_token).await;
+            assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+        }
+        let next_session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        assert!(service.validate_token(&next_session.access_token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_list_sessions_marks_current() {
+        let user = create_active_user_with_password("Password123");
+        let service = create_auth_service(MockUserRepository::with_user(user));
+
+        let current = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let claims = service.validate_token(&current.access_token).await.unwrap();
+
+        let sessions = service.list_sessions(&claims).await.unwrap();
+        assert_eq!(sessions.len(), 2);
+
+        let current_sessions: Vec<_> = sessions.iter().filter(|s| s.current).collect();
+        assert_eq!(current_sessions.len(), 1);
+        assert_eq!(Some(&current_sessions[0].id), claims.session_id.as_ref());
+        assert_eq!(current_sessions[0].device_id.as_deref(), Some("device-1"));
+    }
+
+    #[tokio::test]
+    async fn test_list_sessions_shows_client_of_each_session() {
+        let user = create_active_user_with_password("Password123");
+        let service = create_auth_service(MockUserRepository::with_user(user));
+        let client = |ip: &str, agent: &str| RequestContext::new(Some(ip.to_string()), Some(agent.to_string()));
+
+        let laptop = client("203.0.113.7", "Firefox/130.0")
+            .scope(service.login(login_request()))
+            .await
+            .unwrap()
+            .into_authenticated()
+            .unwrap();
+        let phone = client("198.51.100.4", "ArcanaApp/2.1")
+            .scope(service.login(login_request()))
+            .await
+            .unwrap()
+            .into_authenticated()
+            .unwrap();
+        client("198.51.100.9", "ArcanaApp/2.1")
+            .scope(service.refresh_token(RefreshTokenRequest { refresh_token: phone.refresh_token }))
+            .await
+            .unwrap();
+
+        let claims = service.validate_token(&laptop.access_token).await.unwrap();
+        let sessions = service.list_sessions(&claims).await.unwrap();
+        let clients: HashSet<_> = sessions
+            .iter()
+            .map(|s| (s.ip_address.as_deref().unwrap(), s.user_agent.as_deref().unwrap()))
+            .collect();
+        assert_eq!(clients, HashSet::from([("203.0.113.7", "Firefox/130.0"), ("198.51.100.9", "ArcanaApp/2.1")]));
+    }
+
+    #[tokio::test]
+    async fn test_revoke_session_ends_that_session_only() {
+        let user = create_active_user_with_password("Password123");
+        let service = create_auth_service(MockUserRepository::with_user(user));
+
+        let current = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let other = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let claims = service.validate_token(&current.access_token).await.unwrap();
+        let other_claims = service.validate_token(&other.access_token).await.unwrap();
+        let other_session = other_claims.session_id.clone().unwrap();
+
+        service.revoke_session(&claims, &other_session).await.unwrap();
+
+        let result = service.validate_token(&other.access_token).await;
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+        let result = service
+            .refresh_token(RefreshTokenRequest { refresh_token: other.refresh_token })
+            .await;
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+
+        assert!(service.validate_token(&current.access_token).await.is_ok());
+        assert_eq!(service.list_sessions(&claims).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_session_of_other_user_not_found() {
+        let user = create_active_user_with_password("Password123");
+        let service = create_auth_service(MockUserRepository::with_user(user));
+
+        let session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let claims = service.validate_token(&session.access_token).await.unwrap();
+        let stranger_id = UserId::new();
+        let mut stranger = claims.clone();
+        stranger.sub = stranger_id.to_string();
+        stranger.user_id = Some(stranger_id.into_inner());
+
+        let result = service
+            .revoke_session(&stranger, claims.session_id.as_deref().unwrap())
+            .await;
+        assert!(matches!(result, Err(ArcanaError::NotFound { .. })));
+        assert!(service.validate_token(&session.access_token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_other_sessions_keeps_current() {
+        let user = create_active_user_with_password("Password123");
+        let service = create_auth_service(MockUserRepository::with_user(user));
+
+        let current = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let others = [
+            service.login(login_request()).await.unwrap().into_authenticated().unwrap(),
+            service.login(login_request()).await.unwrap().into_authenticated().unwrap(),
+        ];
+        let claims = service.validate_token(&current.access_token).await.unwrap();
+
+        let response = service.revoke_other_sessions(&claims).await.unwrap();
+        assert!(response.message.contains('2'));
+
+        for other in others {
+            assert!(service.validate_token(&other.access_token).await.is_err());
+        }
+        let sessions = service.list_sessions(&claims).await.unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert!(sessions[0].current);
+    }
+
     #[tokio::test]
     async fn test_get_current_user_success() {
         let user = create_active_user_with_password("Password123");
@@ -822,8 +2438,9 @@ mod tests {
             username_or_email: "testuser".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
-        let login_response = service.login(login_request).await.unwrap();
+        let login_response = service.login(login_request).await.unwrap().into_authenticated().unwrap();
         let claims = service.validate_token(&login_response.access_token).await.unwrap();
 
         let result = service.get_current_user(&claims).await;
@@ -839,7 +2456,7 @@ mod tests {
         let repo = MockUserRepository::with_user(user.clone());
         let service = create_auth_service(repo);
 
-        let response = service.create_auth_response(&user);
+        let response = service.create_auth_response(&user, None, None).await;
         assert!(response.is_ok());
         let auth = response.unwrap();
         assert!(!auth.access_token.is_empty());
@@ -848,6 +2465,68 @@ mod tests {
         assert!(auth.expires_in > 0);
     }
 
+    /// Signs in through `service`, then deactivates the tenant and checks
+    /// that registering, signing in and refreshing are refused.
+    async fn assert_inactive_tenant_refuses_sign_in(service: &dyn AuthService, tenants: &MockTenantRepository) {
+        let tokens = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        tenants.deactivate(TenantId::DEFAULT);
+
+        assert!(matches!(service.register(register_request()).await, Err(ArcanaError::Forbidden(_))));
+        assert!(matches!(service.login(login_request()).await, Err(ArcanaError::Forbidden(_))));
+        let refresh = RefreshTokenRequest { refresh_token: tokens.refresh_token };
+        assert!(matches!(service.refresh_token(refresh).await, Err(ArcanaError::Forbidden(_))));
+    }
+
+    /// Signs in and refreshes through `service`, checking that both access
+    /// tokens carry the permissions the resolver grants.
+    async fn assert_tokens_carry_resolved_permissions(service: &dyn AuthService) {
+        let login = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let claims = service.validate_token(&login.access_token).await.unwrap();
+        assert!(claims.is_granted(Permission::SystemMonitor));
+        assert!(!claims.is_granted(Permission::UserDelete));
+
+        let refresh = RefreshTokenRequest { refresh_token: login.refresh_token };
+        let refreshed = service.refresh_token(refresh).await.unwrap();
+        let claims = service.validate_token(&refreshed.access_token).await.unwrap();
+        assert!(claims.is_granted(Permission::SystemMonitor));
+    }
+
+    fn monitor_resolver() -> Arc<GrantingResolver> {
+        Arc::new(GrantingResolver { grants: [Permission::SystemMonitor].into_iter().collect() })
+    }
+
+    #[tokio::test]
+    async fn test_tokens_carry_resolved_permissions() {
+        let user = MockUserRepository::with_user(create_active_user_with_password("Password123"));
+        let component = create_auth_component(user, monitor_resolver(), Arc::default());
+        assert_tokens_carry_resolved_permissions(&component).await;
+    }
+
+    #[tokio::test]
+    async fn test_inactive_tenant_refuses_sign_in() {
+        let user = MockUserRepository::with_user(create_active_user_with_password("Password123"));
+        let tenants = Arc::new(MockTenantRepository::default());
+        let component = create_auth_component(user, Arc::default(), tenants.clone());
+        assert_inactive_tenant_refuses_sign_in(&component, &tenants).await;
+    }
+
+    #[tokio::test]
+    async fn test_component_registers_and_signs_in_within_the_request_tenant() {
+        let tenant_id = TenantId::new();
+        let component = create_auth_component(MockUserRepository::new(), Arc::default(), Arc::default());
+        let tenant = RequestContext::default().with_tenant(Some(tenant_id));
+
+        let registered = tenant.clone().scope(component.register(register_request())).await.unwrap();
+        let claims = component.validate_token(&registered.access_token).await.unwrap();
+        assert_eq!(claims.tenant_id(), tenant_id);
+
+        let login = || LoginRequest { username_or_email: "newuser".to_string(), ..login_request() };
+        assert!(matches!(component.login(login()).await, Err(ArcanaError::InvalidCredentials)));
+        let response = tenant.scope(component.login(login())).await.unwrap().into_authenticated().unwrap();
+        let claims = component.validate_token(&response.access_token).await.unwrap();
+        assert_eq!(claims.tenant_id(), tenant_id);
+    }
+
     // =========================================================================
     // Additional Edge Case Tests
     // =========================================================================
@@ -872,6 +2551,7 @@ mod tests {
             username_or_email: "testuser".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         let result = service.login(request).await;
@@ -900,6 +2580,7 @@ mod tests {
             username_or_email: "testuser".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         let result = service.login(request).await;
@@ -982,6 +2663,7 @@ mod tests {
             username_or_email: "TEST@EXAMPLE.COM".to_string(),
             password: "Password123".to_string(),
             device_id: None,
+            ip_address: None,
         };
 
         // This test documents behavior - whether case-insensitive or not
@@ -991,4 +2673,616 @@ mod tests {
         // If it succeeds, that means case-insensitive lookup is supported
         let _ = result; // Either is acceptable
     }
+
+    #[tokio::test]
+    async fn test_register_sends_verification_email() {
+        let mailer = Arc::new(RecordingMailer::default());
+        let service = create_auth_service_with_mailer(
+            MockUserRepository::new(),
+            Arc::new(MockOAuthTokenRepository::new()),
+            mailer.clone(),
+        );
+
+        let response = service.register(register_request()).await.unwrap();
+
+        let user = service.user_repository.find_by_id(response.user.id).await.unwrap().unwrap();
+        assert_eq!(user.status, UserStatus::PendingVerification);
+        assert!(!user.email_verified);
+
+        let sent = mailer.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "new@example.com");
+        assert!(sent[0].body.contains("http://localhost:8080/verify-email?token="));
+    }
+
+    #[tokio::test]
+    async fn test_verify_email_activates_account() {
+        let mailer = Arc::new(RecordingMailer::default());
+        let service = create_auth_service_with_mailer(
+            MockUserRepository::new(),
+            Arc::new(MockOAuthTokenRepository::new()),
+            mailer.clone(),
+        );
+        let response = service.register(register_request()).await.unwrap();
+        let token = verification_token(&mailer.sent()[0]);
+
+        let result = service.verify_email(VerifyEmailRequest { token: token.clone() }).await.unwrap();
+        assert_eq!(result.message, "Email verified");
+
+        let user = service.user_repository.find_by_id(response.user.id).await.unwrap().unwrap();
+        assert_eq!(user.status, UserStatus::Active);
+        assert!(user.email_verified);
+
+        // Following the link again is harmless
+        let again = service.verify_email(VerifyEmailRequest { token }).await.unwrap();
+        assert_eq!(again.message, "Email already verified");
+    }
+
+    #[tokio::test]
+    async fn test_verify_email_rejects_other_token_types() {
+        let service = create_auth_service(MockUserRepository::new());
+        let response = service.register(register_request()).await.unwrap();
+
+        let result = service
+            .verify_email(VerifyEmailRequest { token: response.access_token })
+            .await;
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+    }
+
+    #[tokio::test]
+    async fn test_verify_email_rejects_token_for_previous_address() {
+        let mailer = Arc::new(RecordingMailer::default());
+        let service = create_auth_service_with_mailer(
+            MockUserRepository::new(),
+            Arc::new(MockOAuthTokenRepository::new()),
+            mailer.clone(),
+        );
+        let response = service.register(register_request()).await.unwrap();
+        let token = verification_token(&mailer.sent()[0]);
+
+        let mut user = service.user_repository.find_by_id(response.user.id).await.unwrap().unwrap();
+        user.email = Email::new_unchecked("changed@example.com".to_string());
+        service.user_repository.update(&user).await.unwrap();
+
+        let result = service.verify_email(VerifyEmailRequest { token }).await;
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+    }
+
+    #[tokio::test]
+    async fn test_resend_verification_only_for_unverified_accounts() {
+        let mailer = Arc::new(RecordingMailer::default());
+        let service = create_auth_service_with_mailer(
+            MockUserRepository::with_user(create_active_user_with_password("Password123")),
+            Arc::new(MockOAuthTokenRepository::new()),
+            mailer.clone(),
+        );
+        service.register(register_request()).await.unwrap();
+        assert_eq!(mailer.sent().len(), 1);
+
+        let pending = service
+            .resend_verification(ResendVerificationRequest { email: "new@example.com".to_string() })
+            .await
+            .unwrap();
+        assert_eq!(mailer.sent().len(), 2);
+
+        // Verified and unknown addresses get the same response but no email
+        let verified = service
+            .resend_verification(ResendVerificationRequest { email: "test@example.com".to_string() })
+            .await
+            .unwrap();
+        let unknown = service
+            .resend_verification(ResendVerificationRequest { email: "nobody@example.com".to_string() })
+            .await
+            .unwrap();
+        assert_eq!(mailer.sent().len(), 2);
+        assert_eq!(pending.message, verified.message);
+        assert_eq!(pending.message, unknown.message);
+    }
+
+    fn reset_request(token: String) -> PasswordResetConfirmRequest {
+        PasswordResetConfirmRequest {
+            token,
+            new_password: "NewPassword456".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_stores_hashed_token_and_sends_link() {
+        let mailer = Arc::new(RecordingMailer::default());
+        let service = create_auth_service_with_mailer(
+            MockUserRepository::with_user(create_active_user_with_password("Password123")),
+            Arc::new(MockOAuthTokenRepository::new()),
+            mailer.clone(),
+        );
+
+        service
+            .forgot_password(PasswordResetRequest { email: "test@example.com".to_string() })
+            .await
+            .unwrap();
+
+        let sent = mailer.sent();
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].body.contains("http://localhost:8080/reset-password?token="));
+
+        let token = verification_token(&sent[0]);
+        let stored = service
+            .password_reset_token_repository
+            .find_by_token_hash(&hash_token(&token))
+            .await
+            .unwrap()
+            .expect("reset token stored by hash");
+        assert!(stored.is_valid());
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_unknown_email_does_not_enumerate() {
+        let mailer = Arc::new(RecordingMailer::default());
+        let service = create_auth_service_with_mailer(
+            MockUserRepository::with_user(create_active_user_with_password("Password123")),
+            Arc::new(MockOAuthTokenRepository::new()),
+            mailer.clone(),
+        );
+
+        let known = service
+            .forgot_password(PasswordResetRequest { email: "test@example.com".to_string() })
+            .await
+            .unwrap();
+        let unknown = service
+            .forgot_password(PasswordResetRequest { email: "nobody@example.com".to_string() })
+            .await
+            .unwrap();
+
+        assert_eq!(known.message, unknown.message);
+        assert_eq!(mailer.sent().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_changes_password_and_revokes_tokens() {
+        let mailer = Arc::new(RecordingMailer::default());
+        let tokens = Arc::new(MockOAuthTokenRepository::new());
+        let service = create_auth_service_with_mailer(
+            MockUserRepository::with_user(create_active_user_with_password("Password123")),
+            tokens.clone(),
+            mailer.clone(),
+        );
+        let session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+
+        service
+            .forgot_password(PasswordResetRequest { email: "test@example.com".to_string() })
+            .await
+            .unwrap();
+        let token = verification_token(&mailer.sent()[0]);
+        service.reset_password(reset_request(token.clone())).await.unwrap();
+
+        assert!(tokens.all().iter().all(|t| t.revoked));
+        assert!(service.validate_token(&session.access_token).await.is_err());
+        assert!(service.login(login_request()).await.is_err());
+
+        let login = service
+            .login(LoginRequest {
+                password: "NewPassword456".to_string(),
+                ..login_request()
+            })
+            .await;
+        assert!(login.is_ok());
+
+        // Reset tokens are single-use
+        let again = service.reset_password(reset_request(token)).await;
+        assert!(matches!(again, Err(ArcanaError::InvalidToken(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_rejects_expired_token() {
+        let mailer = Arc::new(RecordingMailer::default());
+        let resets = Arc::new(MockPasswordResetTokenRepository::default());
+        let service = AuthServiceComponent {
+            password_reset_token_repository: resets.clone(),
+            ..create_auth_service_with_mailer(
+                MockUserRepository::with_user(create_active_user_with_password("Password123")),
+                Arc::new(MockOAuthTokenRepository::new()),
+                mailer.clone(),
+            )
+        };
+
+        service
+            .forgot_password(PasswordResetRequest { email: "test@example.com".to_string() })
+            .await
+            .unwrap();
+        resets.expire_all();
+
+        let token = verification_token(&mailer.sent()[0]);
+        let result = service.reset_password(reset_request(token)).await;
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_invalidates_earlier_links() {
+        let mailer = Arc::new(RecordingMailer::default());
+        let service = create_auth_service_with_mailer(
+            MockUserRepository::with_user(create_active_user_with_password("Password123")),
+            Arc::new(MockOAuthTokenRepository::new()),
+            mailer.clone(),
+        );
+        let request = || PasswordResetRequest { email: "test@example.com".to_string() };
+
+        service.forgot_password(request()).await.unwrap();
+        service.forgot_password(request()).await.unwrap();
+
+        let sent = mailer.sent();
+        let first = service.reset_password(reset_request(verification_token(&sent[0]))).await;
+        assert!(matches!(first, Err(ArcanaError::InvalidToken(_))));
+        assert!(service.reset_password(reset_request(verification_token(&sent[1]))).await.is_ok());
+    }
+
+    /// Enrols and enables TOTP for the user.
+    ///
+    /// Returns claims of a session started before MFA was enabled, the TOTP
+    /// secret and the recovery codes.
+    async fn enable_mfa(service: &TestAuthService) -> (Claims, String, Vec<String>) {
+        let session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let claims = service.validate_token(&session.access_token).await.unwrap();
+
+        let enrollment = service.enroll_totp(&claims).await.unwrap();
+        let code = generate_totp_code(&enrollment.secret, unix_now()).unwrap();
+        let recovery = service.enable_totp(&claims, TotpCodeRequest { code }).await.unwrap();
+
+        (claims, enrollment.secret, recovery.recovery_codes)
+    }
+
+    /// Returns the TOTP code of the step after the last one used.
+    async fn next_totp_code(mfa: &MockMfaRepository, user_id: UserId, secret: &str) -> String {
+        let credential = mfa.find_totp_by_user(user_id).await.unwrap().unwrap();
+        let step = credential.last_used_step.unwrap() + 1;
+        generate_totp_code(secret, step as u64 * TOTP_STEP_SECS).unwrap()
+    }
+
+    fn mfa_challenge(response: LoginResponse) -> MfaChallengeResponse {
+        match response {
+            LoginResponse::MfaRequired(challenge) => challenge,
+            LoginResponse::Authenticated(_) => panic!("expected an MFA challenge"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enroll_totp_returns_provisioning_uri() {
+        let mfa = Arc::new(MockMfaRepository::default());
+        let service = create_auth_service_with_mfa(
+            MockUserRepository::with_user(create_active_user_with_password("Password123")),
+            mfa.clone(),
+        );
+        let session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let claims = service.validate_token(&session.access_token).await.unwrap();
+
+        let enrollment = service.enroll_totp(&claims).await.unwrap();
+        assert!(enrollment.otpauth_uri.starts_with("otpauth://totp/"));
+        assert!(enrollment.otpauth_uri.contains(&enrollment.secret));
+
+        // Pending enrolment does not change how the user logs in
+        let stored = mfa.find_totp_by_user(claims.user_id().unwrap()).await.unwrap().unwrap();
+        assert!(!stored.is_enabled());
+        assert!(service.login(login_request()).await.unwrap().into_authenticated().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_enable_totp_rejects_wrong_code() {
+        let service = create_auth_service(MockUserRepository::with_user(
+            create_active_user_with_password("Password123"),
+        ));
+        let session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+        let claims = service.validate_token(&session.access_token).await.unwrap();
+        service.enroll_totp(&claims).await.unwrap();
+
+        let result = service
+            .enable_totp(&claims, TotpCodeRequest { code: "not-a-code".to_string() })
+            .await;
+        assert!(matches!(result, Err(ArcanaError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_enable_totp_issues_recovery_codes() {
+        let user = create_active_user_with_password("Password123");
+        let user_id = user.id;
+        let mfa = Arc::new(MockMfaRepository::default());
+        let service = create_auth_service_with_mfa(MockUserRepository::with_user(user), mfa.clone());
+
+        let (claims, _, recovery_codes) = enable_mfa(&service).await;
+
+        assert_eq!(recovery_codes.len(), RECOVERY_CODE_COUNT);
+        assert_eq!(mfa.unused_recovery_codes(user_id), RECOVERY_CODE_COUNT);
+        // Only hashes are stored
+        assert!(mfa.recovery_codes.lock().unwrap().iter().all(|(_, hash, _)| !recovery_codes.contains(hash)));
+
+        let again = service.enroll_totp(&claims).await;
+        assert!(matches!(again, Err(ArcanaError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_login_with_mfa_returns_challenge() {
+        let service = create_auth_service(MockUserRepository::with_user(
+            create_active_user_with_password("Password123"),
+        ));
+        enable_mfa(&service).await;
+
+        let challenge = mfa_challenge(service.login(login_request()).await.unwrap());
+        assert!(challenge.mfa_required);
+        assert_eq!(challenge.expires_in, 300);
+
+        // The challenge is not an access token
+        assert!(service.validate_token(&challenge.mfa_token).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_complete_mfa_login_with_totp() {
+        let user = create_active_user_with_password("Password123");
+        let user_id = user.id;
+        let mfa = Arc::new(MockMfaRepository::default());
+        let service = create_auth_service_with_mfa(MockUserRepository::with_user(user), mfa.clone());
+        let (_, secret, _) = enable_mfa(&service).await;
+        let challenge = mfa_challenge(service.login(login_request()).await.unwrap());
+
+        // The code used to enable TOTP cannot be replayed
+        let used_step = mfa.find_totp_by_user(user_id).await.unwrap().unwrap().last_used_step.unwrap();
+        let replay = service
+            .complete_mfa_login(MfaLoginRequest {
+                mfa_token: challenge.mfa_token.clone(),
+                code: generate_totp_code(&secret, used_step as u64 * TOTP_STEP_SECS).unwrap(),
+                device_id: None,
+            })
+            .await;
+        assert!(matches!(replay, Err(ArcanaError::InvalidCredentials)));
+
+        let response = service
+            .complete_mfa_login(MfaLoginRequest {
+                mfa_token: challenge.mfa_token,
+                code: next_totp_code(&mfa, user_id, &secret).await,
+                device_id: Some("device-1".to_string()),
+            })
+            .await
+            .unwrap();
+        assert!(service.validate_token(&response.access_token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_complete_mfa_login_with_recovery_code_is_single_use() {
+        let user = create_active_user_with_password("Password123");
+        let user_id = user.id;
+        let mfa = Arc::new(MockMfaRepository::default());
+        let service = create_auth_service_with_mfa(MockUserRepository::with_user(user), mfa.clone());
+        let (_, _, recovery_codes) = enable_mfa(&service).await;
+
+        let challenge = mfa_challenge(service.login(login_request()).await.unwrap());
+        let request = MfaLoginRequest {
+            mfa_token: challenge.mfa_token,
+            code: recovery_codes[0].to_uppercase(),
+            device_id: None,
+        };
+        assert!(service.complete_mfa_login(request.clone()).await.is_ok());
+        assert_eq!(mfa.unused_recovery_codes(user_id), RECOVERY_CODE_COUNT - 1);
+
+        // Neither the challenge nor the recovery code can be used again
+        let again = service.complete_mfa_login(request).await;
+        assert!(matches!(again, Err(ArcanaError::InvalidToken(_))));
+
+        let challenge = mfa_challenge(service.login(login_request()).await.unwrap());
+        let reused = service
+            .complete_mfa_login(MfaLoginRequest {
+                mfa_token: challenge.mfa_token,
+                code: recovery_codes[0].clone(),
+                device_id: None,
+            })
+            .await;
+        assert!(matches!(reused, Err(ArcanaError::InvalidCredentials)));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_mfa_logins_redeem_the_challenge_once() {
+        let user = create_active_user_with_password("Password123");
+        let mfa = Arc::new(MockMfaRepository::default());
+        let service = create_auth_service_with_mfa(MockUserRepository::with_user(user), mfa);
+        let (_, _, recovery_codes) = enable_mfa(&service).await;
+        let challenge = mfa_challenge(service.login(login_request()).await.unwrap());
+
+        let complete = |code: &str| {
+            service.complete_mfa_login(MfaLoginRequest {
+                mfa_token: challenge.mfa_token.clone(),
+                code: code.to_string(),
+                device_id: None,
+            })
+        };
+        let (first, second) = tokio::join!(complete(&recovery_codes[0]), complete(&recovery_codes[1]));
+
+        assert_eq!(usize::from(first.is_ok()) + usize::from(second.is_ok()), 1);
+    }
+
+    #[tokio::test]
+    async fn test_complete_mfa_login_rejects_access_token() {
+        let service = create_auth_service(MockUserRepository::with_user(
+            create_active_user_with_password("Password123"),
+        ));
+        let session = service.login(login_request()).await.unwrap().into_authenticated().unwrap();
+
+        let result = service
+            .complete_mfa_login(MfaLoginRequest {
+                mfa_token: session.access_token,
+                code: "123456".to_string(),
+                device_id: None,
+            })
+            .await;
+        assert!(matches!(result, Err(ArcanaError::InvalidToken(_))));
+    }
+
+    #[tokio::test]
+    async fn test_disable_totp_with_recovery_code() {
+        let service = create_auth_service(MockUserRepository::with_user(
+            create_active_user_with_password("Password123"),
+        ));
+        let (claims, _, recovery_codes) = enable_mfa(&service).await;
+
+        service
+            .disable_totp(&claims, TotpCodeRequest { code: recovery_codes[1].clone() })
+            .await
+            .unwrap();
+
+        assert!(service.login(login_request()).await.unwrap().into_authenticated().is_some());
+        let again = service
+            .disable_totp(&claims, TotpCodeRequest { code: recovery_codes[2].clone() })
+            .await;
+        assert!(matches!(again, Err(ArcanaError::BusinessRule(_))));
+    }
+
+    #[tokio::test]
+    async fn test_regenerate_recovery_codes_replaces_old_codes() {
+        let user = create_active_user_with_password("Password123");
+        let user_id = user.id;
+        let mfa = Arc::new(MockMfaRepository::default());
+        let service = create_auth_service_with_mfa(MockUserRepository::with_user(user), mfa.clone());
+        let (claims, secret, old_codes) = enable_mfa(&service).await;
+
+        // A recovery code is not accepted here
+        let with_recovery_code = service
+            .regenerate_recovery_codes(&claims, TotpCodeRequest { code: old_codes[0].clone() })
+            .await;
+        assert!(matches!(with_recovery_code, Err(ArcanaError::Validation(_))));
+
+        let new_codes = service
+            .regenerate_recovery_codes(
+                &claims,
+                TotpCodeRequest { code: next_totp_code(&mfa, user_id, &secret).await },
+            )
+            .await
+            .unwrap()
+            .recovery_codes;
+
+        assert_eq!(mfa.unused_recovery_codes(user_id), RECOVERY_CODE_COUNT);
+        assert!(new_codes.iter().all(|code| !old_codes.contains(code)));
+        let old_hash = hash_token(&normalize_recovery_code(&old_codes[0]));
+        assert!(!mfa.consume_recovery_code(user_id, &old_hash).await.unwrap());
+    }
+
+    fn wrong_password_request(ip_address: Option<&str>) -> LoginRequest {
+        LoginRequest {
+            password: "WrongPassword".to_string(),
+            ip_address: ip_address.map(str::to_string),
+            ..login_request()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_locks_account_after_max_failures() {
+        let user = create_active_user_with_password("Password123");
+        let user_id = user.id;
+        let service = create_auth_service(MockUserRepository::with_user(user));
+
+        for _ in 1..LockoutConfig::default().max_failed_attempts {
+            let result = service.login(wrong_password_request(None)).await;
+            assert!(matches!(result, Err(ArcanaError::InvalidCredentials)));
+        }
+
+        let published = record_event_types(service.events.as_ref());
+        let result = service.login(wrong_password_request(None)).await;
+        assert!(matches!(result, Err(ArcanaError::Forbidden(msg)) if msg == "Account is locked"));
+        assert_eq!(*published.lock().unwrap(), vec!["auth.login_failed", "auth.account_locked"]);
+
+        let locked = service.user_repository.find_by_id(user_id).await.unwrap().unwrap();
+        assert_eq!(locked.status, UserStatus::Locked);
+        assert!(locked.locked_until.unwrap() > chrono::Utc::now());
+
+        // The right password does not get past the lock
+        let result = service.login(login_request()).await;
+        assert!(matches!(result, Err(ArcanaError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_expired_lock_is_lifted_on_login() {
+        let mut user = create_active_user_with_password("Password123");
+        user.lock_until(chrono::Utc::now() - chrono::Duration::seconds(1));
+        let user_id = user.id;
+        let service = create_auth_service(MockUserRepository::with_user(user));
+
+        let response = service.login(login_request()).await.unwrap();
+        assert!(response.into_authenticated().is_some());
+
+        let unlocked = service.user_repository.find_by_id(user_id).await.unwrap().unwrap();
+        assert_eq!(unlocked.status, UserStatus::Active);
+        assert!(unlocked.locked_until.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_manual_lock_is_not_lifted() {
+        let mut user = create_active_user_with_password("Password123");
+        user.status = UserStatus::Locked;
+        let service = create_auth_service(MockUserRepository::with_user(user));
+
+        let result = service.login(login_request()).await;
+        assert!(matches!(result, Err(ArcanaError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_successful_login_resets_failure_count() {
+        let user = create_active_user_with_password("Password123");
+        let user_id = user.id;
+        let service = create_auth_service(MockUserRepository::with_user(user));
+        let max = LockoutConfig::default().max_failed_attempts;
+
+        for _ in 1..max {
+            let _ = service.login(wrong_password_request(None)).await;
+        }
+        service.login(login_request()).await.unwrap();
+        for _ in 1..max {
+            let result = service.login(wrong_password_request(None)).await;
+            assert!(matches!(result, Err(ArcanaError::InvalidCredentials)));
+        }
+
+        let user = service.user_repository.find_by_id(user_id).await.unwrap().unwrap();
+        assert_eq!(user.status, UserStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn test_login_refuses_ip_after_too_many_failures() {
+        let service = create_auth_service(MockUserRepository::with_user(
+            create_active_user_with_password("Password123"),
+        ));
+        let ip = "203.0.113.7";
+        for _ in 0..LockoutConfig::default().max_failed_attempts_per_ip {
+            let _ = service
+                .login(LoginRequest {
+                    username_or_email: "nobody".to_string(),
+                    ..wrong_password_request(Some(ip))
+                })
+                .await;
+        }
+
+        let with_ip = LoginRequest { ip_address: Some(ip.to_string()), ..login_request() };
+        let result = service.login(with_ip).await;
+        assert!(matches!(result, Err(ArcanaError::RateLimitExceeded)));
+
+        // Other clients are unaffected
+        let other_ip = LoginRequest { ip_address: Some("198.51.100.1".to_string()), ..login_request() };
+        assert!(service.login(other_ip).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_failed_second_factor_counts_towards_lock() {
+        let user = create_active_user_with_password("Password123");
+        let user_id = user.id;
+        let mfa = Arc::new(MockMfaRepository::default());
+        let service = create_auth_service_with_mfa(MockUserRepository::with_user(user), mfa.clone());
+        enable_mfa(&service).await;
+
+        let challenge = mfa_challenge(service.login(login_request()).await.unwrap());
+        let max = LockoutConfig::default().max_failed_attempts;
+        for attempt in 1..=max {
+            let result = service
+                .complete_mfa_login(MfaLoginRequest {
+                    mfa_token: challenge.mfa_token.clone(),
+                    code: "000000".to_string(),
+                    device_id: None,
+                })
+                .await;
+            assert!(result.is_err(), "attempt {} should fail", attempt);
+        }
+
+        let locked = service.user_repository.find_by_id(user_id).await.unwrap().unwrap();
+        assert_eq!(locked.status, UserStatus::Locked);
+    }
 }
@@ -5,6 +5,14 @@
 
 pub mod user_service_impl;
 pub mod auth_service_impl;
+pub mod audit_service_impl;
+pub mod webhook_service_impl;
+pub mod role_service_impl;
+pub mod tenant_service_impl;
 
 pub use user_service_impl::{UserServiceComponent, UserServiceImpl};
-pub use auth_service_impl::{AuthServiceComponent, AuthServiceImpl};
+pub use auth_service_impl::AuthServiceComponent;
+pub use audit_service_impl::AuditServiceImpl;
+pub use webhook_service_impl::WebhookServiceImpl;
+pub use role_service_impl::RoleServiceImpl;
+pub use tenant_service_impl::TenantServiceImpl;
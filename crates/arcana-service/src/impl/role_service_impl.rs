@@ -0,0 +1,620 @@
+//! Role service implementation.
+
+use crate::audit::RequestContext;
+use crate::dto::{CreateRoleRequest, RoleListResponse, RoleResponse, UpdateRoleRequest, UserPermissionsResponse};
+use crate::role_service::RoleService;
+use arcana_core::{ArcanaError, ArcanaResult, Permission, PermissionSet, Role, RoleId, User, UserId, UserRole, ValidateExt};
+use arcana_repository::{RoleRepository, UserRepository};
+use arcana_security::PermissionResolverInterface;
+use async_trait::async_trait;
+use chrono::Utc;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Checks that a custom role name is well-formed and does not shadow a built-in role.
+fn validate_role_name(name: &str) -> ArcanaResult<()> {
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    {
+        return Err(ArcanaError::Validation(
+            "Role name may only contain lowercase letters, digits, '-' and '_'".to_string(),
+        ));
+    }
+    if UserRole::from_str(name).is_some() {
+        return Err(ArcanaError::Validation(format!("Role name '{}' is reserved", name)));
+    }
+    Ok(())
+}
+
+/// Role service storing roles and grants in the role repository.
+///
+/// Every change drops the cached permissions it affects, so it takes effect
+/// on the next request of the users concerned.
+#[derive(Component)]
+#[shaku(interface = RoleService)]
+pub struct RoleServiceImpl {
+    #[shaku(inject)]
+    role_repository: Arc<dyn RoleRepository>,
+    #[shaku(inject)]
+    user_repository: Arc<dyn UserRepository>,
+    #[shaku(inject)]
+    permission_resolver: Arc<dyn PermissionResolverInterface>,
+}
+
+impl RoleServiceImpl {
+    /// Creates a new role service.
+    #[must_use]
+    pub fn new(
+        role_repository: Arc<dyn RoleRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        permission_resolver: Arc<dyn PermissionResolverInterface>,
+    ) -> Self {
+        Self {
+            role_repository,
+            user_repository,
+            permission_resolver,
+        }
+    }
+
+    async fn find(&self, id: RoleId) -> ArcanaResult<Role> {
+        self.role_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ArcanaError::not_found("Role", id))
+    }
+
+    /// Finds a user of the tenant the current request acts on.
+    async fn find_user(&self, user_id: UserId) -> ArcanaResult<User> {
+        let tenant_id = RequestContext::current().tenant_id();
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| user.tenant_id == tenant_id)
+            .ok_or_else(|| ArcanaError::not_found("User", user_id))
+    }
+
+    /// Refuses `system:*` permissions to users outside the default tenant,
+    /// since they cover the whole deployment.
+    fn ensure_grantable(user: &User, permissions: &PermissionSet) -> ArcanaResult<()> {
+        if !user.tenant_id.is_default() {
+            if let Some(permission) = permissions.iter().find(Permission::is_system) {
+                return Err(ArcanaError::Forbidden(format!(
+                    "{} can only be granted to users of the default tenant",
+                    permission
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    async fn ensure_name_available(&self, name: &str) -> ArcanaResult<()> {
+        if self.role_repository.find_by_name(name).await?.is_some() {
+            return Err(ArcanaError::conflict(format!("Role '{}' already exists", name)));
+        }
+        Ok(())
+    }
+
+    async fn user_permissions(&self, user: &User) -> ArcanaResult<UserPermissionsResponse> {
+        let roles = self.role_repository.find_assigned(user.id).await?;
+        let granted = self.role_repository.find_user_permissions(user.id).await?;
+        let effective = self.role_repository.find_effective_permissions(user.id, user.role).await?;
+        Ok(UserPermissionsResponse {
+            user_id: user.id,
+            role: user.role,
+            roles: roles.into_iter().map(RoleResponse::from).collect(),
+            granted,
+            effective,
+        })
+    }
+}
+
+#[async_trait]
+impl RoleService for RoleServiceImpl {
+    async fn create_role(&self, request: CreateRoleRequest) -> ArcanaResult<RoleResponse> {
+        debug!("Creating role {}", request.name);
+
+        request.validate_request()?;
+        validate_role_name(&request.name)?;
+        self.ensure_name_available(&request.name).await?;
+
+        let role = Role::new(request.name, request.description, request.permissions.into_iter().collect());
+        let saved = self.role_repository.save(&role).await?;
+
+        info!("Created role {} ({})", saved.name, saved.id);
+        Ok(RoleResponse::from(saved))
+    }
+
+    async fn get_role(&self, id: RoleId) -> ArcanaResult<RoleResponse> {
+        debug!("Getting role: {}", id);
+        self.find(id).await.map(RoleResponse::from)
+    }
+
+    async fn list_roles(&self) -> ArcanaResult<RoleListResponse> {
+        debug!("Listing roles");
+        self.role_repository.find_all().await.map(RoleListResponse::from)
+    }
+
+    async fn update_role(&self, id: RoleId, request: UpdateRoleRequest) -> ArcanaResult<RoleResponse> {
+        debug!("Updating role: {}", id);
+
+        request.validate_request()?;
+        let mut role = self.find(id).await?;
+
+        if let Some(name) = request.name.filter(|name| *name != role.name) {
+            if role.built_in {
+                return Err(ArcanaError::BusinessRule("Built-in roles cannot be renamed".to_string()));
+            }
+            validate_role_name(&name)?;
+            self.ensure_name_available(&name).await?;
+            role.name = name;
+        }
+        if let Some(description) = request.description {
+            role.description = Some(description);
+        }
+        let permissions_changed = request.permissions.is_some();
+        if let Some(permissions) = request.permissions {
+            role.permissions = permissions.into_iter().collect::<PermissionSet>();
+        }
+        role.updated_at = Utc::now();
+
+        let updated = self.role_repository.update(&role).await?;
+        if permissions_changed {
+            self.permission_resolver.invalidate_all().await;
+        }
+
+        info!("Updated role {} ({})", updated.name, id);
+        Ok(RoleResponse::from(updated))
+    }
+
+    async fn delete_role(&self, id: RoleId) -> ArcanaResult<()> {
+        debug!("Deleting role: {}", id);
+
+        if self.find(id).await?.built_in {
+            return Err(ArcanaError::BusinessRule("Built-in roles cannot be deleted".to_string()));
+        }
+        if !self.role_repository.delete(id).await? {
+            return Err(ArcanaError::not_found("Role", id));
+        }
+        self.permission_resolver.invalidate_all().await;
+
+        info!("Deleted role {}", id);
+        Ok(())
+    }
+
+    async fn get_user_permissions(&self, user_id: UserId) -> ArcanaResult<UserPermissionsResponse> {
+        debug!("Getting permissions of user {}", user_id);
+        let user = self.find_user(user_id).await?;
+        self.user_permissions(&user).await
+    }
+
+    async fn assign_role(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<UserPermissionsResponse> {
+        debug!("Assigning role {} to user {}", role_id, user_id);
+
+        let user = self.find_user(user_id).await?;
+        let role = self.find(role_id).await?;
+        if role.built_in {
+            return Err(ArcanaError::BusinessRule(
+                "Built-in roles follow the user's role and cannot be assigned".to_string(),
+            ));
+        }
+        Self::ensure_grantable(&user, &role.permissions)?;
+        self.role_repository.assign(user_id, role_id).await?;
+        self.permission_resolver.invalidate_user(user_id).await;
+
+        info!("Assigned role {} to user {}", role_id, user_id);
+        self.user_permissions(&user).await
+    }
+
+    async fn unassign_role(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<UserPermissionsResponse> {
+        debug!("Removing role {} from user {}", role_id, user_id);
+
+        let user = self.find_user(user_id).await?;
+        if !self.role_repository.unassign(user_id, role_id).await? {
+            return Err(ArcanaError::not_found("Role assignment", role_id));
+        }
+        self.permission_resolver.invalidate_user(user_id).await;
+
+        info!("Removed role {} from user {}", role_id, user_id);
+        self.user_permissions(&user).await
+    }
+
+    async fn grant_permission(&self, user_id: UserId, permission: Permission) -> ArcanaResult<UserPermissionsResponse> {
+        debug!("Granting {} to user {}", permission, user_id);
+
+        let user = self.find_user(user_id).await?;
+        Self::ensure_grantable(&user, &[permission].into_iter().collect())?;
+        self.role_repository.grant(user_id, permission).await?;
+        self.permission_resolver.invalidate_user(user_id).await;
+
+        info!("Granted {} to user {}", permission, user_id);
+        self.user_permissions(&user).await
+    }
+
+    async fn revoke_permission(&self, user_id: UserId, permission: Permission) -> ArcanaResult<UserPermissionsResponse> {
+        debug!("Revoking {} from user {}", permission, user_id);
+
+        let user = self.find_user(user_id).await?;
+        if !self.role_repository.revoke(user_id, permission).await? {
+            return Err(ArcanaError::not_found("Permission grant", permission));
+        }
+        self.permission_resolver.invalidate_user(user_id).await;
+
+        info!("Revoked {} from user {}", permission, user_id);
+        self.user_permissions(&user).await
+    }
+}
+
+impl std::fmt::Debug for RoleServiceImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RoleServiceImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{Email, Page, PageRequest, TenantId};
+    use std::sync::Mutex;
+
+    /// User repository that knows a single user.
+    struct SingleUserRepository {
+        user: User,
+    }
+
+    impl std::fmt::Debug for SingleUserRepository {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("SingleUserRepository").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl UserRepository for SingleUserRepository {
+        async fn find_by_id(&self, id: UserId) -> ArcanaResult<Option<User>> {
+            Ok((id == self.user.id).then(|| self.user.clone()))
+        }
+
+        async fn find_by_username(&self, _tenant_id: TenantId, _username: &str) -> ArcanaResult<Option<User>> {
+            Ok(None)
+        }
+
+        async fn find_by_email(&self, _tenant_id: TenantId, _email: &str) -> ArcanaResult<Option<User>> {
+            Ok(None)
+        }
+
+        async fn find_by_username_or_email(&self, _tenant_id: TenantId, _identifier: &str) -> ArcanaResult<Option<User>> {
+            Ok(None)
+        }
+
+        async fn exists_by_username(&self, _tenant_id: TenantId, _username: &str) -> ArcanaResult<bool> {
+            Ok(false)
+        }
+
+        async fn exists_by_email(&self, _tenant_id: TenantId, _email: &str) -> ArcanaResult<bool> {
+            Ok(false)
+        }
+
+        async fn find_all(&self, _tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>> {
+            Ok(Page::empty(page.page, page.size))
+        }
+
+        async fn find_by_role(&self, _tenant_id: TenantId, _role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
+            Ok(Page::empty(page.page, page.size))
+        }
+
+        async fn save(&self, user: &User) -> ArcanaResult<User> {
+            Ok(user.clone())
+        }
+
+        async fn update(&self, user: &User) -> ArcanaResult<User> {
+            Ok(user.clone())
+        }
+
+        async fn delete(&self, _id: UserId) -> ArcanaResult<bool> {
+            Ok(false)
+        }
+
+        async fn count(&self, _tenant_id: TenantId) -> ArcanaResult<u64> {
+            Ok(1)
+        }
+
+        async fn count_by_role(&self, _tenant_id: TenantId, _role: UserRole) -> ArcanaResult<u64> {
+            Ok(0)
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemoryRoleRepository {
+        roles: Mutex<Vec<Role>>,
+        assignments: Mutex<Vec<(UserId, RoleId)>>,
+        grants: Mutex<Vec<(UserId, Permission)>>,
+    }
+
+    impl std::fmt::Debug for InMemoryRoleRepository {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("InMemoryRoleRepository").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl RoleRepository for InMemoryRoleRepository {
+        async fn find_by_id(&self, id: RoleId) -> ArcanaResult<Option<Role>> {
+            Ok(self.roles.lock().unwrap().iter().find(|r| r.id == id).cloned())
+        }
+
+        async fn find_by_name(&self, name: &str) -> ArcanaResult<Option<Role>> {
+            Ok(self.roles.lock().unwrap().iter().find(|r| r.name == name).cloned())
+        }
+
+        async fn find_all(&self) -> ArcanaResult<Vec<Role>> {
+            Ok(self.roles.lock().unwrap().clone())
+        }
+
+        async fn save(&self, role: &Role) -> ArcanaResult<Role> {
+            self.roles.lock().unwrap().push(role.clone());
+            Ok(role.clone())
+        }
+
+        async fn update(&self, role: &Role) -> ArcanaResult<Role> {
+            let mut roles = self.roles.lock().unwrap();
+            let stored = roles
+                .iter_mut()
+                .find(|r| r.id == role.id)
+                .ok_or_else(|| ArcanaError::not_found("Role", role.id))?;
+            *stored = role.clone();
+            Ok(role.clone())
+        }
+
+        async fn delete(&self, id: RoleId) -> ArcanaResult<bool> {
+            let mut roles = self.roles.lock().unwrap();
+            let before = roles.len();
+            roles.retain(|r| r.id != id || r.built_in);
+            self.assignments.lock().unwrap().retain(|(_, role_id)| *role_id != id);
+            Ok(roles.len() != before)
+        }
+
+        async fn find_assigned(&self, user_id: UserId) -> ArcanaResult<Vec<Role>> {
+            let assignments = self.assignments.lock().unwrap();
+            Ok(self
+                .roles
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| assignments.contains(&(user_id, r.id)))
+                .cloned()
+                .collect())
+        }
+
+        async fn assign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<()> {
+            let mut assignments = self.assignments.lock().unwrap();
+            if !assignments.contains(&(user_id, role_id)) {
+                assignments.push((user_id, role_id));
+            }
+            Ok(())
+        }
+
+        async fn unassign(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<bool> {
+            let mut assignments = self.assignments.lock().unwrap();
+            let before = assignments.len();
+            assignments.retain(|a| *a != (user_id, role_id));
+            Ok(assignments.len() != before)
+        }
+
+        async fn find_user_permissions(&self, user_id: UserId) -> ArcanaResult<PermissionSet> {
+            Ok(self
+                .grants
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(id, _)| *id == user_id)
+                .map(|(_, permission)| *permission)
+                .collect())
+        }
+
+        async fn grant(&self, user_id: UserId, permission: Permission) -> ArcanaResult<()> {
+            let mut grants = self.grants.lock().unwrap();
+            if !grants.contains(&(user_id, permission)) {
+                grants.push((user_id, permission));
+            }
+            Ok(())
+        }
+
+        async fn revoke(&self, user_id: UserId, permission: Permission) -> ArcanaResult<bool> {
+            let mut grants = self.grants.lock().unwrap();
+            let before = grants.len();
+            grants.retain(|g| *g != (user_id, permission));
+            Ok(grants.len() != before)
+        }
+
+        async fn find_effective_permissions(&self, user_id: UserId, role: UserRole) -> ArcanaResult<PermissionSet> {
+            let mut permissions = role.default_permissions();
+            for assigned in self.find_assigned(user_id).await? {
+                permissions.extend(assigned.permissions);
+            }
+            permissions.extend(self.find_user_permissions(user_id).await?);
+            Ok(permissions)
+        }
+    }
+
+    /// Resolver that records which invalidations were requested.
+    #[derive(Default)]
+    struct RecordingResolver {
+        invalidations: Mutex<Vec<Option<UserId>>>,
+    }
+
+    impl std::fmt::Debug for RecordingResolver {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("RecordingResolver").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl PermissionResolverInterface for RecordingResolver {
+        async fn resolve(&self, _user_id: UserId, role: UserRole) -> ArcanaResult<PermissionSet> {
+            Ok(role.default_permissions())
+        }
+
+        async fn invalidate_user(&self, user_id: UserId) {
+            self.invalidations.lock().unwrap().push(Some(user_id));
+        }
+
+        async fn invalidate_all(&self) {
+            self.invalidations.lock().unwrap().push(None);
+        }
+    }
+
+    struct Fixture {
+        service: RoleServiceImpl,
+        roles: Arc<InMemoryRoleRepository>,
+        resolver: Arc<RecordingResolver>,
+        user_id: UserId,
+    }
+
+    fn fixture() -> Fixture {
+        fixture_in(TenantId::DEFAULT)
+    }
+
+    fn fixture_in(tenant_id: TenantId) -> Fixture {
+        let user = User::new(
+            "agent".to_string(),
+            Email::new_unchecked("agent@example.com".to_string()),
+            "hashed_password".to_string(),
+            None,
+            None,
+        )
+        .in_tenant(tenant_id);
+        let user_id = user.id;
+        let roles = Arc::new(InMemoryRoleRepository::default());
+        let mut built_in = Role::new("user".to_string(), None, UserRole::User.default_permissions());
+        built_in.built_in = true;
+        roles.roles.lock().unwrap().push(built_in);
+
+        let resolver = Arc::new(RecordingResolver::default());
+        let service = RoleServiceImpl::new(roles.clone(), Arc::new(SingleUserRepository { user }), resolver.clone());
+        Fixture {
+            service,
+            roles,
+            resolver,
+            user_id,
+        }
+    }
+
+    fn support_request() -> CreateRoleRequest {
+        CreateRoleRequest {
+            name: "support".to_string(),
+            description: Some("Support agents".to_string()),
+            permissions: vec![Permission::UserRead, Permission::UserUpdate],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_assigned_role_and_grant_extend_effective_permissions() {
+        let f = fixture();
+        let support = f.service.create_role(support_request()).await.unwrap();
+
+        let permissions = f.service.assign_role(f.user_id, support.id).await.unwrap();
+        assert_eq!(permissions.roles.len(), 1);
+        assert!(permissions.effective.contains(Permission::UserUpdate));
+        assert!(!permissions.effective.contains(Permission::UserDelete));
+
+        let permissions = f.service.grant_permission(f.user_id, Permission::SystemMonitor).await.unwrap();
+        assert_eq!(permissions.granted, [Permission::SystemMonitor].into_iter().collect());
+        assert!(permissions.effective.contains(Permission::SystemMonitor));
+
+        let permissions = f.service.unassign_role(f.user_id, support.id).await.unwrap();
+        assert!(!permissions.effective.contains(Permission::UserUpdate));
+
+        assert_eq!(*f.resolver.invalidations.lock().unwrap(), vec![Some(f.user_id); 3]);
+    }
+
+    #[tokio::test]
+    async fn test_role_names_are_validated() {
+        let f = fixture();
+        f.service.create_role(support_request()).await.unwrap();
+
+        let duplicate = f.service.create_role(support_request()).await;
+        assert!(matches!(duplicate, Err(ArcanaError::Conflict(_))));
+
+        let reserved = CreateRoleRequest {
+            name: "admin".to_string(),
+            ..support_request()
+        };
+        assert!(matches!(f.service.create_role(reserved).await, Err(ArcanaError::Validation(_))));
+
+        let malformed = CreateRoleRequest {
+            name: "Support Team".to_string(),
+            ..support_request()
+        };
+        assert!(matches!(f.service.create_role(malformed).await, Err(ArcanaError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_built_in_roles_are_protected() {
+        let f = fixture();
+        let built_in = f.roles.find_by_name("user").await.unwrap().unwrap();
+
+        let rename = UpdateRoleRequest {
+            name: Some("member".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(f.service.update_role(built_in.id, rename).await, Err(ArcanaError::BusinessRule(_))));
+        assert!(matches!(f.service.delete_role(built_in.id).await, Err(ArcanaError::BusinessRule(_))));
+        assert!(matches!(f.service.assign_role(f.user_id, built_in.id).await, Err(ArcanaError::BusinessRule(_))));
+
+        // Their grants can still be changed, which affects every user
+        let regrant = UpdateRoleRequest {
+            permissions: Some(vec![Permission::UserRead]),
+            ..Default::default()
+        };
+        let updated = f.service.update_role(built_in.id, regrant).await.unwrap();
+        assert_eq!(updated.permissions, [Permission::UserRead].into_iter().collect());
+        assert_eq!(*f.resolver.invalidations.lock().unwrap(), vec![None]);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_user_and_missing_grant_are_not_found() {
+        let f = fixture();
+
+        let unknown_user = f.service.get_user_permissions(UserId::new()).await;
+        assert!(matches!(unknown_user, Err(ArcanaError::NotFound { .. })));
+
+        let missing = f.service.revoke_permission(f.user_id, Permission::SystemAdmin).await;
+        assert!(matches!(missing, Err(ArcanaError::NotFound { .. })));
+        assert!(f.resolver.invalidations.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_users_of_other_tenants_are_not_found() {
+        let f = fixture();
+        let other_tenant = RequestContext::new(None, None).with_tenant(Some(TenantId::new()));
+
+        let result = other_tenant.scope(f.service.grant_permission(f.user_id, Permission::ContentRead)).await;
+        assert!(matches!(result, Err(ArcanaError::NotFound { .. })));
+        assert!(f.resolver.invalidations.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_system_permissions_stay_in_the_default_tenant() {
+        let tenant_id = TenantId::new();
+        let f = fixture_in(tenant_id);
+        let tenant = RequestContext::new(None, None).with_tenant(Some(tenant_id));
+
+        let result = tenant.clone().scope(f.service.grant_permission(f.user_id, Permission::SystemAdmin)).await;
+        assert!(matches!(result, Err(ArcanaError::Forbidden(_))));
+
+        let operators = CreateRoleRequest {
+            name: "operators".to_string(),
+            description: None,
+            permissions: vec![Permission::UserRead, Permission::SystemConfig],
+        };
+        let operators = f.service.create_role(operators).await.unwrap();
+        let result = tenant.clone().scope(f.service.assign_role(f.user_id, operators.id)).await;
+        assert!(matches!(result, Err(ArcanaError::Forbidden(_))));
+        assert!(f.roles.grants.lock().unwrap().is_empty());
+
+        // Tenant permissions can still be granted
+        let permissions = tenant.scope(f.service.grant_permission(f.user_id, Permission::ContentRead)).await.unwrap();
+        assert!(permissions.effective.contains(Permission::ContentRead));
+    }
+}
@@ -0,0 +1,220 @@
+//! Tenant service implementation.
+
+use crate::dto::{CreateTenantRequest, TenantListResponse, TenantResponse, UpdateTenantRequest};
+use crate::tenant_service::TenantService;
+use arcana_core::{ArcanaError, ArcanaResult, PageRequest, Tenant, TenantId, ValidateExt};
+use arcana_repository::TenantRepository;
+use async_trait::async_trait;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Checks that a slug is lowercase and URL-safe.
+fn validate_slug(slug: &str) -> ArcanaResult<()> {
+    let well_formed = slug.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !slug.starts_with('-')
+        && !slug.ends_with('-');
+    if !well_formed {
+        return Err(ArcanaError::Validation(
+            "Tenant slug may only contain lowercase letters, digits and inner '-'".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Tenant service storing tenants in the tenant repository.
+#[derive(Component)]
+#[shaku(interface = TenantService)]
+pub struct TenantServiceImpl {
+    #[shaku(inject)]
+    tenant_repository: Arc<dyn TenantRepository>,
+}
+
+impl TenantServiceImpl {
+    /// Creates a new tenant service.
+    #[must_use]
+    pub fn new(tenant_repository: Arc<dyn TenantRepository>) -> Self {
+        Self { tenant_repository }
+    }
+
+    async fn find(&self, id: TenantId) -> ArcanaResult<Tenant> {
+        self.tenant_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ArcanaError::not_found("Tenant", id))
+    }
+}
+
+#[async_trait]
+impl TenantService for TenantServiceImpl {
+    async fn create_tenant(&self, request: CreateTenantRequest) -> ArcanaResult<TenantResponse> {
+        debug!("Creating tenant {}", request.slug);
+
+        request.validate_request()?;
+        validate_slug(&request.slug)?;
+        if self.tenant_repository.find_by_slug(&request.slug).await?.is_some() {
+            return Err(ArcanaError::conflict(format!("Tenant '{}' already exists", request.slug)));
+        }
+
+        let saved = self.tenant_repository.save(&Tenant::new(request.slug, request.name)).await?;
+
+        info!("Created tenant {} ({})", saved.slug, saved.id);
+        Ok(TenantResponse::from(saved))
+    }
+
+    async fn get_tenant(&self, id: TenantId) -> ArcanaResult<TenantResponse> {
+        debug!("Getting tenant: {}", id);
+        self.find(id).await.map(TenantResponse::from)
+    }
+
+    async fn list_tenants(&self, page: PageRequest) -> ArcanaResult<TenantListResponse> {
+        debug!("Listing tenants, page: {}, size: {}", page.page, page.size);
+
+        let tenants = self.tenant_repository.find_all(page).await?;
+        Ok(TenantListResponse::from(tenants))
+    }
+
+    async fn update_tenant(&self, id: TenantId, request: UpdateTenantRequest) -> ArcanaResult<TenantResponse> {
+        debug!("Updating tenant: {}", id);
+
+        request.validate_request()?;
+        let mut tenant = self.find(id).await?;
+
+        if let Some(name) = request.name {
+            tenant.rename(name);
+        }
+        if let Some(active) = request.active {
+            // The operators of the deployment sign in to the default tenant
+            if !active && tenant.is_default() {
+                return Err(ArcanaError::BusinessRule("The default tenant cannot be deactivated".to_string()));
+            }
+            tenant.set_active(active);
+        }
+
+        let updated = self.tenant_repository.update(&tenant).await?;
+        info!("Updated tenant {}", id);
+        Ok(TenantResponse::from(updated))
+    }
+}
+
+impl std::fmt::Debug for TenantServiceImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TenantServiceImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::Page;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct InMemoryTenantRepository {
+        tenants: Mutex<Vec<Tenant>>,
+    }
+
+    impl std::fmt::Debug for InMemoryTenantRepository {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("InMemoryTenantRepository").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl TenantRepository for InMemoryTenantRepository {
+        async fn find_by_id(&self, id: TenantId) -> ArcanaResult<Option<Tenant>> {
+            Ok(self.tenants.lock().unwrap().iter().find(|t| t.id == id).cloned())
+        }
+
+        async fn find_by_slug(&self, slug: &str) -> ArcanaResult<Option<Tenant>> {
+            Ok(self.tenants.lock().unwrap().iter().find(|t| t.slug == slug).cloned())
+        }
+
+        async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<Tenant>> {
+            let tenants = self.tenants.lock().unwrap().clone();
+            let total = tenants.len() as u64;
+            Ok(Page::new(tenants, page.page, page.size, total))
+        }
+
+        async fn save(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+            self.tenants.lock().unwrap().push(tenant.clone());
+            Ok(tenant.clone())
+        }
+
+        async fn update(&self, tenant: &Tenant) -> ArcanaResult<Tenant> {
+            let mut tenants = self.tenants.lock().unwrap();
+            let stored = tenants
+                .iter_mut()
+                .find(|t| t.id == tenant.id)
+                .ok_or_else(|| ArcanaError::not_found("Tenant", tenant.id))?;
+            *stored = tenant.clone();
+            Ok(tenant.clone())
+        }
+    }
+
+    fn default_tenant() -> Tenant {
+        Tenant {
+            id: TenantId::DEFAULT,
+            slug: "default".to_string(),
+            name: "Default".to_string(),
+            active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn create_service() -> TenantServiceImpl {
+        let repository = InMemoryTenantRepository::default();
+        repository.tenants.lock().unwrap().push(default_tenant());
+        TenantServiceImpl::new(Arc::new(repository))
+    }
+
+    fn create_request(slug: &str) -> CreateTenantRequest {
+        CreateTenantRequest {
+            slug: slug.to_string(),
+            name: "Acme Corp".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_tenant() {
+        let service = create_service();
+
+        let tenant = service.create_tenant(create_request("acme")).await.unwrap();
+        assert!(tenant.active);
+        assert!(!tenant.default);
+
+        let duplicate = service.create_tenant(create_request("acme")).await;
+        assert!(matches!(duplicate, Err(ArcanaError::Conflict(_))));
+        assert_eq!(service.list_tenants(PageRequest::first()).await.unwrap().total_elements, 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_tenant_rejects_malformed_slug() {
+        let service = create_service();
+
+        for slug in ["Acme", "acme corp", "-acme", "acme_corp"] {
+            let result = service.create_tenant(create_request(slug)).await;
+            assert!(matches!(result, Err(ArcanaError::Validation(_))), "{slug} was accepted");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deactivate_tenant() {
+        let service = create_service();
+        let tenant = service.create_tenant(create_request("acme")).await.unwrap();
+
+        let request = UpdateTenantRequest {
+            active: Some(false),
+            ..Default::default()
+        };
+        let updated = service.update_tenant(tenant.id, request.clone()).await.unwrap();
+        assert!(!updated.active);
+        assert_eq!(updated.name, "Acme Corp");
+
+        let result = service.update_tenant(TenantId::DEFAULT, request).await;
+        assert!(matches!(result, Err(ArcanaError::BusinessRule(_))));
+        assert!(service.get_tenant(TenantId::DEFAULT).await.unwrap().active);
+    }
+}
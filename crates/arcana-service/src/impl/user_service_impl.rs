@@ -5,11 +5,16 @@ use crate::dto::{
     ChangePasswordRequest, CreateUserRequest, UpdateUserRequest, UpdateUserRoleRequest,
     UpdateUserStatusRequest, UserListResponse, UserResponse,
 };
+use crate::audit::RequestContext;
+use crate::events::{publish_unless_stored, EventBusInterface};
 use crate::user_service::UserService;
-use arcana_core::{ArcanaError, ArcanaResult, PageRequest, UserId, ValidateExt};
+use arcana_core::{ArcanaError, ArcanaResult, DomainEvent, PageRequest, TenantId, UserId, ValidateExt};
 use arcana_core::{Email, User};
+use arcana_core::{PasswordChanged, UserCreated, UserDeleted, UserRoleChanged, UserStatusChanged, UserUpdated};
 use arcana_repository::UserRepository;
-use arcana_security::{PasswordHasher, PasswordHasherInterface};
+use arcana_security::{
+    PasswordHasher, PasswordHasherInterface, TokenRevocationInterface, TokenRevocationStore,
+};
 use async_trait::async_trait;
 use shaku::Component;
 use std::sync::Arc;
@@ -25,18 +30,63 @@ fn conflict_email_msg(email: &str) -> String {
     format!("Email '{}' already exists", email)
 }
 
+/// Names the profile fields that an update request changes.
+fn changed_profile_fields(user: &User, request: &UpdateUserRequest) -> Vec<String> {
+    [
+        ("first_name", user.first_name != request.first_name),
+        ("last_name", user.last_name != request.last_name),
+        ("avatar_url", user.avatar_url != request.avatar_url),
+    ]
+    .into_iter()
+    .filter(|(_, changed)| *changed)
+    .map(|(field, _)| field.to_string())
+    .collect()
+}
+
+/// Returns the user making the current request.
+fn current_actor() -> Option<UserId> {
+    RequestContext::current().actor
+}
+
+/// Returns the tenant the current request acts on.
+fn current_tenant() -> TenantId {
+    RequestContext::current().tenant_id()
+}
+
+/// Finds a user of the given tenant; users of other tenants are not found.
+async fn find_tenant_user<R: UserRepository + ?Sized>(
+    user_repository: &R,
+    tenant_id: TenantId,
+    id: UserId,
+) -> ArcanaResult<User> {
+    user_repository
+        .find_by_id(id)
+        .await?
+        .filter(|user| user.tenant_id == tenant_id)
+        .ok_or_else(|| ArcanaError::not_found("User", id))
+}
+
 /// Generic user service implementation (non-DI).
 pub struct UserServiceImpl<R: UserRepository> {
     user_repository: Arc<R>,
     password_hasher: Arc<PasswordHasher>,
+    token_revocation: Arc<TokenRevocationStore>,
+    events: Arc<dyn EventBusInterface>,
 }
 
 impl<R: UserRepository> UserServiceImpl<R> {
     /// Creates a new user service.
-    pub fn new(user_repository: Arc<R>, password_hasher: Arc<PasswordHasher>) -> Self {
+    pub fn new(
+        user_repository: Arc<R>,
+        password_hasher: Arc<PasswordHasher>,
+        token_revocation: Arc<TokenRevocationStore>,
+        events: Arc<dyn EventBusInterface>,
+    ) -> Self {
         Self {
             user_repository,
             password_hasher,
+            token_revocation,
+            events,
         }
     }
 }
@@ -49,15 +99,17 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
         // Validate request
         request.validate_request()?;
 
+        let tenant_id = current_tenant();
+
         // Check for existing username
-        if self.user_repository.exists_by_username(&request.username).await? {
+        if self.user_repository.exists_by_username(tenant_id, &request.username).await? {
             return Err(ArcanaError::conflict(
                 conflict_username_msg(&request.username),
             ));
         }
 
         // Check for existing email
-        if self.user_repository.exists_by_email(&request.email).await? {
+        if self.user_repository.exists_by_email(tenant_id, &request.email).await? {
             return Err(ArcanaError::conflict(
                 conflict_email_msg(&request.email),
             ));
@@ -77,10 +129,15 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
             password_hash,
             request.first_name,
             request.last_name,
-        );
+        )
+        .in_tenant(tenant_id);
 
         // Save user
-        let saved_user = self.user_repository.save(&user).await?;
+        let events: Vec<Box<dyn DomainEvent>> =
+            vec![Box::new(UserCreated::new(user.id, user.username.clone(), user.email.to_string()))];
+        let saved_user = self.user_repository.save_with_events(&user, &events).await?;
+
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("User created: {}", saved_user.id);
         Ok(UserResponse::from(saved_user))
@@ -89,11 +146,8 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
     async fn get_user(&self, id: UserId) -> ArcanaResult<UserResponse> {
         debug!("Getting user: {}", id);
 
-        let user = self
-            .user_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", id))?;
+        let tenant_id = current_tenant();
+        let user = find_tenant_user(self.user_repository.as_ref(), tenant_id, id).await?;
 
         Ok(UserResponse::from(user))
     }
@@ -103,7 +157,7 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
 
         let user = self
             .user_repository
-            .find_by_username(username)
+            .find_by_username(current_tenant(), username)
             .await?
             .ok_or_else(|| ArcanaError::not_found("User", username))?;
 
@@ -113,7 +167,7 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
     async fn list_users(&self, page: PageRequest) -> ArcanaResult<UserListResponse> {
         debug!("Listing users, page: {}, size: {}", page.page, page.size);
 
-        let users = self.user_repository.find_all(page).await?;
+        let users = self.user_repository.find_all(current_tenant(), page).await?;
         Ok(UserListResponse::from(users))
     }
 
@@ -122,15 +176,15 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
 
         request.validate_request()?;
 
-        let mut user = self
-            .user_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", id))?;
+        let mut user = find_tenant_user(self.user_repository.as_ref(), current_tenant(), id).await?;
 
+        let updated_fields = changed_profile_fields(&user, &request);
         user.update_profile(request.first_name, request.last_name, request.avatar_url);
 
-        let updated_user = self.user_repository.update(&user).await?;
+        let events: Vec<Box<dyn DomainEvent>> = vec![Box::new(UserUpdated::new(id, updated_fields))];
+        let updated_user = self.user_repository.update_with_events(&user, &events).await?;
+
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("User updated: {}", id);
         Ok(UserResponse::from(updated_user))
@@ -139,15 +193,19 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
     async fn update_user_role(&self, id: UserId, request: UpdateUserRoleRequest) -> ArcanaResult<UserResponse> {
         debug!("Updating user role: {} -> {:?}", id, request.role);
 
-        let mut user = self
-            .user_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", id))?;
+        let mut user = find_tenant_user(self.user_repository.as_ref(), current_tenant(), id).await?;
 
+        let old_role = user.role;
         user.change_role(request.role);
 
-        let updated_user = self.user_repository.update(&user).await?;
+        let events: Vec<Box<dyn DomainEvent>> =
+            vec![Box::new(UserRoleChanged::new(id, old_role, request.role, current_actor().unwrap_or(id)))];
+        let updated_user = self.user_repository.update_with_events(&user, &events).await?;
+
+        // Tokens issued before the change carry the old role
+        self.token_revocation.revoke_user_tokens(id).await?;
+
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("User role updated: {} -> {:?}", id, request.role);
         Ok(UserResponse::from(updated_user))
@@ -156,16 +214,25 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
     async fn update_user_status(&self, id: UserId, request: UpdateUserStatusRequest) -> ArcanaResult<UserResponse> {
         debug!("Updating user status: {} -> {:?}", id, request.status);
 
-        let mut user = self
-            .user_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", id))?;
+        let mut user = find_tenant_user(self.user_repository.as_ref(), current_tenant(), id).await?;
 
+        let old_status = user.status;
         user.status = request.status;
         user.updated_at = chrono::Utc::now();
 
-        let updated_user = self.user_repository.update(&user).await?;
+        let events: Vec<Box<dyn DomainEvent>> = vec![Box::new(UserStatusChanged::new(
+            id,
+            old_status,
+            request.status,
+            request.reason,
+            current_actor(),
+        ))];
+        let updated_user = self.user_repository.update_with_events(&user, &events).await?;
+
+        // Access tokens must not outlive a suspension or lock
+        self.token_revocation.revoke_user_tokens(id).await?;
+
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("User status updated: {} -> {:?}", id, request.status);
         Ok(UserResponse::from(updated_user))
@@ -176,11 +243,7 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
 
         request.validate_request()?;
 
-        let mut user = self
-            .user_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", id))?;
+        let mut user = find_tenant_user(self.user_repository.as_ref(), current_tenant(), id).await?;
 
         // Verify current password
         if !self.password_hasher.verify(&request.current_password, &user.password_hash)? {
@@ -191,7 +254,14 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
         let new_hash = self.password_hasher.hash(&request.new_password)?;
         user.update_password(new_hash);
 
-        self.user_repository.update(&user).await?;
+        let events: Vec<Box<dyn DomainEvent>> =
+            vec![Box::new(PasswordChanged::new(id, current_actor().unwrap_or(id)))];
+        self.user_repository.update_with_events(&user, &events).await?;
+
+        // Sessions opened with the old password are ended
+        self.token_revocation.revoke_user_tokens(id).await?;
+
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("Password changed for user: {}", id);
         Ok(())
@@ -200,22 +270,27 @@ impl<R: UserRepository + 'static> UserService for UserServiceImpl<R> {
     async fn delete_user(&self, id: UserId) -> ArcanaResult<()> {
         debug!("Deleting user: {}", id);
 
-        let deleted = self.user_repository.delete(id).await?;
+        find_tenant_user(self.user_repository.as_ref(), current_tenant(), id).await?;
+
+        let events: Vec<Box<dyn DomainEvent>> = vec![Box::new(UserDeleted::new(id, current_actor()))];
+        let deleted = self.user_repository.delete_with_events(id, &events).await?;
 
         if !deleted {
             return Err(ArcanaError::not_found("User", id));
         }
 
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
+
         info!("User deleted: {}", id);
         Ok(())
     }
 
     async fn username_exists(&self, username: &str) -> ArcanaResult<bool> {
-        self.user_repository.exists_by_username(username).await
+        self.user_repository.exists_by_username(current_tenant(), username).await
     }
 
     async fn email_exists(&self, email: &str) -> ArcanaResult<bool> {
-        self.user_repository.exists_by_email(email).await
+        self.user_repository.exists_by_email(current_tenant(), email).await
     }
 }
 
@@ -238,6 +313,10 @@ pub struct UserServiceComponent {
     password_hasher: Arc<dyn PasswordHasherInterface>,
     #[shaku(inject)]
     cache: Arc<dyn CacheInterface>,
+    #[shaku(inject)]
+    token_revocation: Arc<dyn TokenRevocationInterface>,
+    #[shaku(inject)]
+    events: Arc<dyn EventBusInterface>,
 }
 
 #[async_trait]
@@ -247,13 +326,14 @@ impl UserService for UserServiceComponent {
 
         request.validate_request()?;
 
-        if self.user_repository.exists_by_username(&request.username).await? {
+        let tenant_id = current_tenant();
+        if self.user_repository.exists_by_username(tenant_id, &request.username).await? {
             return Err(ArcanaError::conflict(
                 conflict_username_msg(&request.username),
             ));
         }
 
-        if self.user_repository.exists_by_email(&request.email).await? {
+        if self.user_repository.exists_by_email(tenant_id, &request.email).await? {
             return Err(ArcanaError::conflict(
                 conflict_email_msg(&request.email),
             ));
@@ -270,9 +350,14 @@ impl UserService for UserServiceComponent {
             password_hash,
             request.first_name,
             request.last_name,
-        );
+        )
+        .in_tenant(tenant_id);
+
+        let events: Vec<Box<dyn DomainEvent>> =
+            vec![Box::new(UserCreated::new(user.id, user.username.clone(), user.email.to_string()))];
+        let saved_user = self.user_repository.save_with_events(&user, &events).await?;
 
-        let saved_user = self.user_repository.save(&user).await?;
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("User created: {}", saved_user.id);
         Ok(UserResponse::from(saved_user))
@@ -281,7 +366,8 @@ impl UserService for UserServiceComponent {
     async fn get_user(&self, id: UserId) -> ArcanaResult<UserResponse> {
         debug!("Getting user: {}", id);
 
-        let cache_key = cache_keys::user_by_id(id);
+        let tenant_id = current_tenant();
+        let cache_key = cache_keys::user_by_id(tenant_id, id);
 
         // Try cache first
         if let Some(cached) = self.cache.get::<UserResponse>(&cache_key).await? {
@@ -289,11 +375,7 @@ impl UserService for UserServiceComponent {
             return Ok(cached);
         }
 
-        let user = self
-            .user_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", id))?;
+        let user = find_tenant_user(self.user_repository.as_ref(), tenant_id, id).await?;
 
         let response = UserResponse::from(user);
 
@@ -306,7 +388,8 @@ impl UserService for UserServiceComponent {
     async fn get_user_by_username(&self, username: &str) -> ArcanaResult<UserResponse> {
         debug!("Getting user by username: {}", username);
 
-        let cache_key = cache_keys::user_by_username(username);
+        let tenant_id = current_tenant();
+        let cache_key = cache_keys::user_by_username(tenant_id, username);
 
         // Try cache first
         if let Some(cached) = self.cache.get::<UserResponse>(&cache_key).await? {
@@ -316,7 +399,7 @@ impl UserService for UserServiceComponent {
 
         let user = self
             .user_repository
-            .find_by_username(username)
+            .find_by_username(tenant_id, username)
             .await?
             .ok_or_else(|| ArcanaError::not_found("User", username))?;
 
@@ -326,7 +409,7 @@ impl UserService for UserServiceComponent {
         let _ = self.cache.set(&cache_key, &response, DEFAULT_TTL).await;
         let _ = self
             .cache
-            .set(&cache_keys::user_by_id(response.id), &response, DEFAULT_TTL)
+            .set(&cache_keys::user_by_id(tenant_id, response.id), &response, DEFAULT_TTL)
             .await;
 
         Ok(response)
@@ -335,7 +418,7 @@ impl UserService for UserServiceComponent {
     async fn list_users(&self, page: PageRequest) -> ArcanaResult<UserListResponse> {
         debug!("Listing users, page: {}, size: {}", page.page, page.size);
 
-        let users = self.user_repository.find_all(page).await?;
+        let users = self.user_repository.find_all(current_tenant(), page).await?;
         Ok(UserListResponse::from(users))
     }
 
@@ -344,22 +427,22 @@ impl UserService for UserServiceComponent {
 
         request.validate_request()?;
 
-        let mut user = self
-            .user_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", id))?;
+        let mut user = find_tenant_user(self.user_repository.as_ref(), current_tenant(), id).await?;
 
         // Capture username before update for cache invalidation
         let username = user.username.clone();
 
+        let updated_fields = changed_profile_fields(&user, &request);
         user.update_profile(request.first_name, request.last_name, request.avatar_url);
 
-        let updated_user = self.user_repository.update(&user).await?;
+        let events: Vec<Box<dyn DomainEvent>> = vec![Box::new(UserUpdated::new(id, updated_fields))];
+        let updated_user = self.user_repository.update_with_events(&user, &events).await?;
 
         // Invalidate cache entries
-        let _ = self.cache.delete(&cache_keys::user_by_id(id)).await;
-        let _ = self.cache.delete(&cache_keys::user_by_username(&username)).await;
+        let _ = self.cache.delete(&cache_keys::user_by_id(user.tenant_id, id)).await;
+        let _ = self.cache.delete(&cache_keys::user_by_username(user.tenant_id, &username)).await;
+
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("User updated: {}", id);
         Ok(UserResponse::from(updated_user))
@@ -368,20 +451,24 @@ impl UserService for UserServiceComponent {
     async fn update_user_role(&self, id: UserId, request: UpdateUserRoleRequest) -> ArcanaResult<UserResponse> {
         debug!("Updating user role: {} -> {:?}", id, request.role);
 
-        let mut user = self
-            .user_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", id))?;
+        let mut user = find_tenant_user(self.user_repository.as_ref(), current_tenant(), id).await?;
 
         let username = user.username.clone();
+        let old_role = user.role;
         user.change_role(request.role);
 
-        let updated_user = self.user_repository.update(&user).await?;
+        let events: Vec<Box<dyn DomainEvent>> =
+            vec![Box::new(UserRoleChanged::new(id, old_role, request.role, current_actor().unwrap_or(id)))];
+        let updated_user = self.user_repository.update_with_events(&user, &events).await?;
 
         // Invalidate cache entries
-        let _ = self.cache.delete(&cache_keys::user_by_id(id)).await;
-        let _ = self.cache.delete(&cache_keys::user_by_username(&username)).await;
+        let _ = self.cache.delete(&cache_keys::user_by_id(user.tenant_id, id)).await;
+        let _ = self.cache.delete(&cache_keys::user_by_username(user.tenant_id, &username)).await;
+
+        // Tokens issued before the change carry the old role
+        self.token_revocation.revoke_user_tokens(id).await?;
+
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("User role updated: {} -> {:?}", id, request.role);
         Ok(UserResponse::from(updated_user))
@@ -390,21 +477,30 @@ impl UserService for UserServiceComponent {
     async fn update_user_status(&self, id: UserId, request: UpdateUserStatusRequest) -> ArcanaResult<UserResponse> {
         debug!("Updating user status: {} -> {:?}", id, request.status);
 
-        let mut user = self
-            .user_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", id))?;
+        let mut user = find_tenant_user(self.user_repository.as_ref(), current_tenant(), id).await?;
 
         let username = user.username.clone();
+        let old_status = user.status;
         user.status = request.status;
         user.updated_at = chrono::Utc::now();
 
-        let updated_user = self.user_repository.update(&user).await?;
+        let events: Vec<Box<dyn DomainEvent>> = vec![Box::new(UserStatusChanged::new(
+            id,
+            old_status,
+            request.status,
+            request.reason,
+            current_actor(),
+        ))];
+        let updated_user = self.user_repository.update_with_events(&user, &events).await?;
 
         // Invalidate cache entries
-        let _ = self.cache.delete(&cache_keys::user_by_id(id)).await;
-        let _ = self.cache.delete(&cache_keys::user_by_username(&username)).await;
+        let _ = self.cache.delete(&cache_keys::user_by_id(user.tenant_id, id)).await;
+        let _ = self.cache.delete(&cache_keys::user_by_username(user.tenant_id, &username)).await;
+
+        // Access tokens must not outlive a suspension or lock
+        self.token_revocation.revoke_user_tokens(id).await?;
+
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("User status updated: {} -> {:?}", id, request.status);
         Ok(UserResponse::from(updated_user))
@@ -415,11 +511,7 @@ impl UserService for UserServiceComponent {
 
         request.validate_request()?;
 
-        let mut user = self
-            .user_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| ArcanaError::not_found("User", id))?;
+        let mut user = find_tenant_user(self.user_repository.as_ref(), current_tenant(), id).await?;
 
         if !self.password_hasher.verify(&request.current_password, &user.password_hash)? {
             return Err(ArcanaError::InvalidCredentials);
@@ -428,7 +520,14 @@ impl UserService for UserServiceComponent {
         let new_hash = self.password_hasher.hash(&request.new_password)?;
         user.update_password(new_hash);
 
-        self.user_repository.update(&user).await?;
+        let events: Vec<Box<dyn DomainEvent>> =
+            vec![Box::new(PasswordChanged::new(id, current_actor().unwrap_or(id)))];
+        self.user_repository.update_with_events(&user, &events).await?;
+
+        // Sessions opened with the old password are ended
+        self.token_revocation.revoke_user_tokens(id).await?;
+
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("Password changed for user: {}", id);
         Ok(())
@@ -438,33 +537,35 @@ impl UserService for UserServiceComponent {
         debug!("Deleting user: {}", id);
 
         // Get user info before deletion for cache invalidation
-        let user = self.user_repository.find_by_id(id).await?;
+        let user = find_tenant_user(self.user_repository.as_ref(), current_tenant(), id).await?;
 
-        let deleted = self.user_repository.delete(id).await?;
+        let events: Vec<Box<dyn DomainEvent>> = vec![Box::new(UserDeleted::new(id, current_actor()))];
+        let deleted = self.user_repository.delete_with_events(id, &events).await?;
 
         if !deleted {
             return Err(ArcanaError::not_found("User", id));
         }
 
         // Invalidate cache entries
-        let _ = self.cache.delete(&cache_keys::user_by_id(id)).await;
-        if let Some(user) = user {
-            let _ = self.cache.delete(&cache_keys::user_by_username(&user.username)).await;
-        }
+        let _ = self.cache.delete(&cache_keys::user_by_id(user.tenant_id, id)).await;
+        let _ = self.cache.delete(&cache_keys::user_by_username(user.tenant_id, &user.username)).await;
+
+        publish_unless_stored(self.user_repository.as_ref(), self.events.as_ref(), events).await;
 
         info!("User deleted: {}", id);
         Ok(())
     }
 
     async fn username_exists(&self, username: &str) -> ArcanaResult<bool> {
-        let cache_key = cache_keys::username_exists(username);
+        let tenant_id = current_tenant();
+        let cache_key = cache_keys::username_exists(tenant_id, username);
 
         // Try cache first
         if let Some(cached) = self.cache.get::<bool>(&cache_key).await? {
             return Ok(cached);
         }
 
-        let exists = self.user_repository.exists_by_username(username).await?;
+        let exists = self.user_repository.exists_by_username(tenant_id, username).await?;
 
         // Cache the result with short TTL
         let _ = self.cache.set(&cache_key, &exists, SHORT_TTL).await;
@@ -473,14 +574,15 @@ impl UserService for UserServiceComponent {
     }
 
     async fn email_exists(&self, email: &str) -> ArcanaResult<bool> {
-        let cache_key = cache_keys::email_exists(email);
+        let tenant_id = current_tenant();
+        let cache_key = cache_keys::email_exists(tenant_id, email);
 
         // Try cache first
         if let Some(cached) = self.cache.get::<bool>(&cache_key).await? {
             return Ok(cached);
         }
 
-        let exists = self.user_repository.exists_by_email(email).await?;
+        let exists = self.user_repository.exists_by_email(tenant_id, email).await?;
 
         // Cache the result with short TTL
         let _ = self.cache.set(&cache_key, &exists, SHORT_TTL).await;
@@ -498,7 +600,8 @@ impl std::fmt::Debug for UserServiceComponent {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arcana_core::Page;
+    use crate::events::{EventBus, EventBusExt, EventSubscriber};
+    use arcana_core::{DomainEvent, Page};
     use arcana_core::{Email, User, UserRole, UserStatus};
     use async_trait::async_trait;
     use std::collections::HashMap;
@@ -533,36 +636,39 @@ mod tests {
             Ok(self.users.lock().unwrap().get(&id).cloned())
         }
 
-        async fn find_by_username(&self, username: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.username == username)
+                .find(|u| u.tenant_id == tenant_id && u.username == username)
                 .cloned())
         }
 
-        async fn find_by_email(&self, email: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.email.as_str() == email)
+                .find(|u| u.tenant_id == tenant_id && u.email.as_str() == email)
                 .cloned())
         }
 
-        async fn find_by_username_or_email(&self, identifier: &str) -> ArcanaResult<Option<User>> {
+        async fn find_by_username_or_email(&self, tenant_id: TenantId, identifier: &str) -> ArcanaResult<Option<User>> {
             Ok(self.users.lock().unwrap().values()
-                .find(|u| u.username == identifier || u.email.as_str() == identifier)
+                .find(|u| u.tenant_id == tenant_id && (u.username == identifier || u.email.as_str() == identifier))
                 .cloned())
         }
 
-        async fn exists_by_username(&self, username: &str) -> ArcanaResult<bool> {
+        async fn exists_by_username(&self, tenant_id: TenantId, username: &str) -> ArcanaResult<bool> {
             Ok(self.users.lock().unwrap().values()
-                .any(|u| u.username == username))
+                .any(|u| u.tenant_id == tenant_id && u.username == username))
         }
 
-        async fn exists_by_email(&self, email: &str) -> ArcanaResult<bool> {
+        async fn exists_by_email(&self, tenant_id: TenantId, email: &str) -> ArcanaResult<bool> {
             Ok(self.users.lock().unwrap().values()
-                .any(|u| u.email.as_str().to_lowercase() == email.to_lowercase()))
+                .any(|u| u.tenant_id == tenant_id && u.email.as_str().to_lowercase() == email.to_lowercase()))
         }
 
-        async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<User>> {
-            let users: Vec<User> = self.users.lock().unwrap().values().cloned().collect();
+        async fn find_all(&self, tenant_id: TenantId, page: PageRequest) -> ArcanaResult<Page<User>> {
+            let users: Vec<User> = self.users.lock().unwrap().values()
+                .filter(|u| u.tenant_id == tenant_id)
+                .cloned()
+                .collect();
             let total = users.len() as u64;
             let start = page.offset();
             let end = std::cmp::min(start + page.limit(), users.len());
@@ -574,9 +680,9 @@ mod tests {
             Ok(Page::new(items, page.page, page.size, total))
         }
 
-        async fn find_by_role(&self, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
+        async fn find_by_role(&self, tenant_id: TenantId, role: UserRole, page: PageRequest) -> ArcanaResult<Page<User>> {
             let users: Vec<User> = self.users.lock().unwrap().values()
-                .filter(|u| u.role == role)
+                .filter(|u| u.tenant_id == tenant_id && u.role == role)
                 .cloned()
                 .collect();
             let total = users.len() as u64;
@@ -607,13 +713,13 @@ mod tests {
             Ok(users.remove(&id).is_some())
         }
 
-        async fn count(&self) -> ArcanaResult<u64> {
-            Ok(self.users.lock().unwrap().len() as u64)
+        async fn count(&self, tenant_id: TenantId) -> ArcanaResult<u64> {
+            Ok(self.users.lock().unwrap().values().filter(|u| u.tenant_id == tenant_id).count() as u64)
         }
 
-        async fn count_by_role(&self, role: UserRole) -> ArcanaResult<u64> {
+        async fn count_by_role(&self, tenant_id: TenantId, role: UserRole) -> ArcanaResult<u64> {
             Ok(self.users.lock().unwrap().values()
-                .filter(|u| u.role == role)
+                .filter(|u| u.tenant_id == tenant_id && u.role == role)
                 .count() as u64)
         }
     }
@@ -629,12 +735,54 @@ mod tests {
     }
 
     fn create_user_service(repo: MockUserRepository) -> UserServiceImpl<MockUserRepository> {
+        create_user_service_with_revocation(
+            repo,
+            Arc::new(TokenRevocationStore::in_memory(std::time::Duration::from_secs(3600))),
+        )
+    }
+
+    fn create_user_service_with_revocation(
+        repo: MockUserRepository,
+        token_revocation: Arc<TokenRevocationStore>,
+    ) -> UserServiceImpl<MockUserRepository> {
         UserServiceImpl::new(
             Arc::new(repo),
             Arc::new(PasswordHasher::new()),
+            token_revocation,
+            Arc::new(EventBus::new()),
         )
     }
 
+    /// Creates a service whose events are collected as event types.
+    fn create_user_service_with_events(
+        repo: MockUserRepository,
+    ) -> (UserServiceImpl<MockUserRepository>, Arc<Mutex<Vec<String>>>, Arc<EventBus>) {
+        let events = Arc::new(EventBus::new());
+        let published = Arc::new(Mutex::new(Vec::new()));
+        let log = published.clone();
+        events.subscribe_handler(EventSubscriber::Sync(Arc::new(move |event: &dyn DomainEvent| {
+            log.lock().unwrap().push(event.event_type().to_string());
+            Ok(())
+        })));
+
+        let service = UserServiceImpl::new(
+            Arc::new(repo),
+            Arc::new(PasswordHasher::new()),
+            Arc::new(TokenRevocationStore::in_memory(std::time::Duration::from_secs(3600))),
+            events.clone(),
+        );
+        (service, published, events)
+    }
+
+    /// Issues access token claims for a user, as a login would.
+    fn issue_claims(user: &User) -> arcana_security::Claims {
+        let provider = arcana_security::TokenProvider::new(Arc::new(arcana_config::SecurityConfig::default()));
+        let token = provider
+            .generate_access_token(user.id, &user.username, user.email.as_str(), user.role)
+            .unwrap();
+        provider.validate_access_token(&token).unwrap()
+    }
+
     #[tokio::test]
     async fn test_create_user_success() {
         let repo = MockUserRepository::new();
@@ -822,6 +970,42 @@ mod tests {
         assert_eq!(result.unwrap().role, UserRole::Admin);
     }
 
+    #[tokio::test]
+    async fn test_update_user_role_publishes_event() {
+        let user = create_test_user();
+        let user_id = user.id;
+        let admin_id = UserId::new();
+        let (service, published, events) = create_user_service_with_events(MockUserRepository::with_user(user));
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let seen = changes.clone();
+        events.subscribe_fn(move |event: &UserRoleChanged| {
+            seen.lock().unwrap().push((event.old_role, event.new_role, event.changed_by));
+            Ok(())
+        });
+
+        RequestContext::default()
+            .with_actor(Some(admin_id))
+            .scope(service.update_user_role(user_id, UpdateUserRoleRequest { role: UserRole::Admin }))
+            .await
+            .unwrap();
+
+        assert_eq!(*published.lock().unwrap(), vec!["user.role_changed"]);
+        assert_eq!(*changes.lock().unwrap(), vec![(UserRole::User, UserRole::Admin, admin_id)]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_operations_publish_nothing() {
+        let (service, published, _) = create_user_service_with_events(MockUserRepository::new());
+
+        assert!(service.delete_user(UserId::new()).await.is_err());
+        assert!(service
+            .update_user_role(UserId::new(), UpdateUserRoleRequest { role: UserRole::Admin })
+            .await
+            .is_err());
+
+        assert!(published.lock().unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_update_user_status() {
         let user = create_test_user();
@@ -839,6 +1023,43 @@ mod tests {
         assert_eq!(result.unwrap().status, UserStatus::Suspended);
     }
 
+    #[tokio::test]
+    async fn test_update_user_status_revokes_access_tokens() {
+        let user = create_test_user();
+        let claims = issue_claims(&user);
+        let token_revocation = Arc::new(TokenRevocationStore::in_memory(std::time::Duration::from_secs(3600)));
+        let service = create_user_service_with_revocation(
+            MockUserRepository::with_user(user.clone()),
+            token_revocation.clone(),
+        );
+
+        let request = UpdateUserStatusRequest {
+            status: UserStatus::Suspended,
+            reason: None,
+        };
+        service.update_user_status(user.id, request).await.unwrap();
+
+        assert!(token_revocation.is_revoked(&claims).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_update_user_role_revokes_access_tokens() {
+        let user = create_test_user();
+        let claims = issue_claims(&user);
+        let token_revocation = Arc::new(TokenRevocationStore::in_memory(std::time::Duration::from_secs(3600)));
+        let service = create_user_service_with_revocation(
+            MockUserRepository::with_user(user.clone()),
+            token_revocation.clone(),
+        );
+
+        let request = UpdateUserRoleRequest { role: UserRole::Admin };
+        service.update_user_role(user.id, request).await.unwrap();
+
+        assert!(token_revocation.is_revoked(&claims).await.unwrap());
+        // A token issued right after the change, even within the same second, is not affected
+        assert!(!token_revocation.is_revoked(&issue_claims(&user)).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_delete_user_success() {
         let user = create_test_user();
@@ -850,6 +1071,43 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_create_update_and_delete_publish_events() {
+        let (service, published, events) = create_user_service_with_events(MockUserRepository::new());
+        let updated_fields = Arc::new(Mutex::new(Vec::new()));
+        let seen = updated_fields.clone();
+        events.subscribe_fn(move |event: &UserUpdated| {
+            seen.lock().unwrap().extend(event.updated_fields.clone());
+            Ok(())
+        });
+
+        let user = service
+            .create_user(CreateUserRequest {
+                username: "eventuser".to_string(),
+                email: "event@example.com".to_string(),
+                password: "password123".to_string(),
+                first_name: None,
+                last_name: None,
+            })
+            .await
+            .unwrap();
+        service
+            .update_user(
+                user.id,
+                UpdateUserRequest {
+                    first_name: Some("Ada".to_string()),
+                    last_name: None,
+                    avatar_url: None,
+                },
+            )
+            .await
+            .unwrap();
+        service.delete_user(user.id).await.unwrap();
+
+        assert_eq!(*published.lock().unwrap(), vec!["user.created", "user.updated", "user.deleted"]);
+        assert_eq!(*updated_fields.lock().unwrap(), vec!["first_name"]);
+    }
+
     #[tokio::test]
     async fn test_delete_user_not_found() {
         let repo = MockUserRepository::new();
@@ -859,6 +1117,57 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_users_of_other_tenants_are_not_found() {
+        let user = create_test_user();
+        let user_id = user.id;
+        let service = create_user_service(MockUserRepository::with_user(user));
+        let other_tenant = RequestContext::default().with_tenant(Some(TenantId::new()));
+
+        let (lookup, exists, deleted) = other_tenant
+            .scope(async {
+                (
+                    service.get_user(user_id).await,
+                    service.username_exists("testuser").await.unwrap(),
+                    service.delete_user(user_id).await,
+                )
+            })
+            .await;
+        assert!(matches!(lookup, Err(ArcanaError::NotFound { .. })));
+        assert!(!exists);
+        assert!(matches!(deleted, Err(ArcanaError::NotFound { .. })));
+        assert!(service.get_user(user_id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_user_joins_request_tenant() {
+        let tenant_id = TenantId::new();
+        let repo = Arc::new(MockUserRepository::with_user(create_test_user()));
+        let service = UserServiceImpl::new(
+            repo.clone(),
+            Arc::new(PasswordHasher::new()),
+            Arc::new(TokenRevocationStore::in_memory(std::time::Duration::from_secs(3600))),
+            Arc::new(EventBus::new()),
+        );
+
+        // The username is taken in the default tenant only
+        let request = CreateUserRequest {
+            username: "testuser".to_string(),
+            email: "test@example.com".to_string(),
+            password: "Password123".to_string(),
+            first_name: None,
+            last_name: None,
+        };
+        let created = RequestContext::default()
+            .with_tenant(Some(tenant_id))
+            .scope(service.create_user(request))
+            .await
+            .unwrap();
+
+        let stored = repo.find_by_id(created.id).await.unwrap().unwrap();
+        assert_eq!(stored.tenant_id, tenant_id);
+    }
+
     #[tokio::test]
     async fn test_username_exists() {
         let user = create_test_user();
@@ -898,6 +1207,26 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_change_password_revokes_access_tokens() {
+        let mut user = create_test_user();
+        user.password_hash = PasswordHasher::new().hash("OldPassword123").unwrap();
+        let claims = issue_claims(&user);
+        let token_revocation = Arc::new(TokenRevocationStore::in_memory(std::time::Duration::from_secs(3600)));
+        let service = create_user_service_with_revocation(
+            MockUserRepository::with_user(user.clone()),
+            token_revocation.clone(),
+        );
+
+        let request = ChangePasswordRequest {
+            current_password: "OldPassword123".to_string(),
+            new_password: "NewPassword456".to_string(),
+        };
+        service.change_password(user.id, request).await.unwrap();
+
+        assert!(token_revocation.is_revoked(&claims).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_change_password_wrong_current() {
         let mut user = create_test_user();
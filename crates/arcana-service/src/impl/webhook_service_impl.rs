@@ -0,0 +1,275 @@
+//! Webhook service implementation.
+
+use crate::dto::{
+    CreateWebhookRequest, UpdateWebhookRequest, WebhookDeliveryListResponse, WebhookListResponse, WebhookResponse,
+};
+use crate::webhook_service::WebhookService;
+use arcana_core::{
+    ArcanaError, ArcanaResult, PageRequest, ValidateExt, WebhookSubscription, WebhookSubscriptionId, EVENT_TYPES,
+    WEBHOOK_ALL_EVENTS,
+};
+use arcana_repository::WebhookRepository;
+use async_trait::async_trait;
+use chrono::Utc;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Checks that every event type names a domain event or is `*`.
+fn validate_event_types(event_types: &[String]) -> ArcanaResult<()> {
+    match event_types
+        .iter()
+        .find(|t| t.as_str() != WEBHOOK_ALL_EVENTS && !EVENT_TYPES.contains(&t.as_str()))
+    {
+        Some(unknown) => Err(ArcanaError::Validation(format!("Unknown event type: {}", unknown))),
+        None => Ok(()),
+    }
+}
+
+/// Webhook service storing subscriptions in the webhook repository.
+#[derive(Component)]
+#[shaku(interface = WebhookService)]
+pub struct WebhookServiceImpl {
+    #[shaku(inject)]
+    webhook_repository: Arc<dyn WebhookRepository>,
+}
+
+impl WebhookServiceImpl {
+    /// Creates a new webhook service.
+    #[must_use]
+    pub fn new(webhook_repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { webhook_repository }
+    }
+
+    async fn find(&self, id: WebhookSubscriptionId) -> ArcanaResult<WebhookSubscription> {
+        self.webhook_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| ArcanaError::not_found("Webhook", id))
+    }
+}
+
+#[async_trait]
+impl WebhookService for WebhookServiceImpl {
+    async fn create_webhook(&self, request: CreateWebhookRequest) -> ArcanaResult<WebhookResponse> {
+        debug!("Creating webhook for {}", request.url);
+
+        request.validate_request()?;
+        validate_event_types(&request.event_types)?;
+
+        let subscription = WebhookSubscription::new(request.url, request.event_types, request.secret, request.description);
+        let saved = self.webhook_repository.save(&subscription).await?;
+
+        info!("Created webhook {} for {}", saved.id, saved.url);
+        Ok(WebhookResponse::from(saved))
+    }
+
+    async fn get_webhook(&self, id: WebhookSubscriptionId) -> ArcanaResult<WebhookResponse> {
+        debug!("Getting webhook: {}", id);
+        self.find(id).await.map(WebhookResponse::from)
+    }
+
+    async fn list_webhooks(&self, page: PageRequest) -> ArcanaResult<WebhookListResponse> {
+        debug!("Listing webhooks, page: {}, size: {}", page.page, page.size);
+
+        let subscriptions = self.webhook_repository.find_all(page).await?;
+        Ok(WebhookListResponse::from(subscriptions))
+    }
+
+    async fn update_webhook(&self, id: WebhookSubscriptionId, request: UpdateWebhookRequest) -> ArcanaResult<WebhookResponse> {
+        debug!("Updating webhook: {}", id);
+
+        request.validate_request()?;
+        if let Some(event_types) = &request.event_types {
+            validate_event_types(event_types)?;
+        }
+
+        let mut subscription = self.find(id).await?;
+        if let Some(url) = request.url {
+            subscription.url = url;
+        }
+        if let Some(event_types) = request.event_types {
+            subscription.event_types = event_types;
+        }
+        if let Some(secret) = request.secret {
+            subscription.secret = secret;
+        }
+        if let Some(description) = request.description {
+            subscription.description = Some(description);
+        }
+        if let Some(active) = request.active {
+            subscription.active = active;
+        }
+        subscription.updated_at = Utc::now();
+
+        let updated = self.webhook_repository.update(&subscription).await?;
+        info!("Updated webhook {}", id);
+        Ok(WebhookResponse::from(updated))
+    }
+
+    async fn delete_webhook(&self, id: WebhookSubscriptionId) -> ArcanaResult<()> {
+        debug!("Deleting webhook: {}", id);
+
+        if !self.webhook_repository.delete(id).await? {
+            return Err(ArcanaError::not_found("Webhook", id));
+        }
+
+        info!("Deleted webhook {}", id);
+        Ok(())
+    }
+
+    async fn list_deliveries(&self, id: WebhookSubscriptionId, page: PageRequest) -> ArcanaResult<WebhookDeliveryListResponse> {
+        debug!("Listing deliveries of webhook {}, page: {}, size: {}", id, page.page, page.size);
+
+        // Distinguish an unknown subscription from one without deliveries
+        self.find(id).await?;
+        let deliveries = self.webhook_repository.find_deliveries(id, page).await?;
+        Ok(WebhookDeliveryListResponse::from(deliveries))
+    }
+}
+
+impl std::fmt::Debug for WebhookServiceImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookServiceImpl").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{Page, WebhookDelivery};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct InMemoryWebhookRepository {
+        subscriptions: Mutex<Vec<WebhookSubscription>>,
+    }
+
+    impl std::fmt::Debug for InMemoryWebhookRepository {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("InMemoryWebhookRepository").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl WebhookRepository for InMemoryWebhookRepository {
+        async fn find_by_id(&self, id: WebhookSubscriptionId) -> ArcanaResult<Option<WebhookSubscription>> {
+            Ok(self.subscriptions.lock().unwrap().iter().find(|s| s.id == id).cloned())
+        }
+
+        async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<WebhookSubscription>> {
+            let subscriptions = self.subscriptions.lock().unwrap().clone();
+            let total = subscriptions.len() as u64;
+            Ok(Page::new(subscriptions, page.page, page.size, total))
+        }
+
+        async fn find_subscribed(&self, event_type: &str) -> ArcanaResult<Vec<WebhookSubscription>> {
+            Ok(self.subscriptions.lock().unwrap().iter().filter(|s| s.matches(event_type)).cloned().collect())
+        }
+
+        async fn save(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription> {
+            self.subscriptions.lock().unwrap().push(subscription.clone());
+            Ok(subscription.clone())
+        }
+
+        async fn update(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription> {
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            let stored = subscriptions
+                .iter_mut()
+                .find(|s| s.id == subscription.id)
+                .ok_or_else(|| ArcanaError::not_found("Webhook", subscription.id))?;
+            *stored = subscription.clone();
+            Ok(subscription.clone())
+        }
+
+        async fn delete(&self, id: WebhookSubscriptionId) -> ArcanaResult<bool> {
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            let before = subscriptions.len();
+            subscriptions.retain(|s| s.id != id);
+            Ok(subscriptions.len() != before)
+        }
+
+        async fn save_delivery(&self, _delivery: &WebhookDelivery) -> ArcanaResult<()> {
+            Ok(())
+        }
+
+        async fn find_deliveries(
+            &self,
+            _subscription_id: WebhookSubscriptionId,
+            page: PageRequest,
+        ) -> ArcanaResult<Page<WebhookDelivery>> {
+            Ok(Page::empty(page.page, page.size))
+        }
+    }
+
+    fn create_service() -> (WebhookServiceImpl, Arc<InMemoryWebhookRepository>) {
+        let repository = Arc::new(InMemoryWebhookRepository::default());
+        (WebhookServiceImpl::new(repository.clone()), repository)
+    }
+
+    fn create_request(event_types: &[&str]) -> CreateWebhookRequest {
+        CreateWebhookRequest {
+            url: "https://example.com/hooks".to_string(),
+            event_types: event_types.iter().map(|t| t.to_string()).collect(),
+            secret: "0123456789abcdef".to_string(),
+            description: Some("CRM sync".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook() {
+        let (service, repository) = create_service();
+
+        let webhook = service.create_webhook(create_request(&["user.created", "*"])).await.unwrap();
+        assert!(webhook.active);
+        assert_eq!(webhook.event_types, vec!["user.created", "*"]);
+
+        let stored = repository.find_by_id(webhook.id).await.unwrap().unwrap();
+        assert_eq!(stored.secret, "0123456789abcdef");
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_rejects_unknown_event_type() {
+        let (service, repository) = create_service();
+
+        let result = service.create_webhook(create_request(&["user.created", "user.exploded"])).await;
+        assert!(matches!(result, Err(ArcanaError::Validation(message)) if message.contains("user.exploded")));
+        assert!(repository.subscriptions.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_webhook_keeps_unset_fields() {
+        let (service, repository) = create_service();
+        let webhook = service.create_webhook(create_request(&["user.created"])).await.unwrap();
+
+        let request = UpdateWebhookRequest {
+            event_types: Some(vec!["user.deleted".to_string()]),
+            active: Some(false),
+            ..Default::default()
+        };
+        let updated = service.update_webhook(webhook.id, request).await.unwrap();
+
+        assert_eq!(updated.url, webhook.url);
+        assert_eq!(updated.event_types, vec!["user.deleted"]);
+        assert!(!updated.active);
+        assert_eq!(repository.find_by_id(webhook.id).await.unwrap().unwrap().secret, "0123456789abcdef");
+    }
+
+    #[tokio::test]
+    async fn test_delete_webhook() {
+        let (service, _) = create_service();
+        let webhook = service.create_webhook(create_request(&["*"])).await.unwrap();
+
+        service.delete_webhook(webhook.id).await.unwrap();
+        assert!(matches!(service.get_webhook(webhook.id).await, Err(ArcanaError::NotFound { .. })));
+        assert!(matches!(service.delete_webhook(webhook.id).await, Err(ArcanaError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_list_deliveries_of_unknown_webhook() {
+        let (service, _) = create_service();
+
+        let result = service.list_deliveries(WebhookSubscriptionId::new(), PageRequest::first()).await;
+        assert!(matches!(result, Err(ArcanaError::NotFound { .. })));
+    }
+}
@@ -9,21 +9,52 @@
 //! src/
 //!   user_service.rs          ← UserService trait
 //!   auth_service.rs          ← AuthService trait
+//!   audit_service.rs         ← AuditService trait
+//!   webhook_service.rs       ← WebhookService trait
+//!   role_service.rs          ← RoleService trait
+//!   tenant_service.rs        ← TenantService trait
 //!   impl/
 //!     mod.rs                 ← pub use declarations
 //!     user_service_impl.rs   ← UserServiceImpl + UserServiceComponent
-//!     auth_service_impl.rs   ← AuthServiceImpl + AuthServiceComponent
+//!     auth_service_impl.rs   ← AuthServiceComponent
+//!     audit_service_impl.rs  ← AuditServiceImpl
+//!     webhook_service_impl.rs ← WebhookServiceImpl
+//!     role_service_impl.rs   ← RoleServiceImpl
+//!     tenant_service_impl.rs ← TenantServiceImpl
+//!   mail/                    ← Mailer trait, SMTP and log mailers, mail queue and job
+//!   audit/                   ← request context, audit trail, audited service decorators
+//!   events/                  ← in-process domain event bus, outbox relay
+//!   webhooks/                ← signed webhook delivery job, worker and event dispatcher
+//!   rbac/                    ← cached effective permission resolver
 //! ```
 
+pub mod audit;
 pub mod cache;
 pub mod dto;
+pub mod events;
+pub mod mail;
 pub mod mappers;
 pub mod user_service;
 pub mod auth_service;
+pub mod audit_service;
+pub mod webhook_service;
+pub mod webhooks;
+pub mod rbac;
+pub mod role_service;
+pub mod tenant_service;
 pub mod r#impl;
 
+pub use audit::*;
 pub use cache::*;
 pub use dto::*;
+pub use events::*;
+pub use mail::*;
 pub use user_service::*;
 pub use auth_service::*;
-pub use r#impl::{UserServiceComponent, UserServiceImpl, AuthServiceComponent, AuthServiceImpl};
+pub use audit_service::*;
+pub use webhook_service::*;
+pub use webhooks::*;
+pub use rbac::*;
+pub use role_service::*;
+pub use tenant_service::*;
+pub use r#impl::{UserServiceComponent, UserServiceImpl, AuthServiceComponent, AuditServiceImpl, WebhookServiceImpl, RoleServiceImpl, TenantServiceImpl};
@@ -0,0 +1,225 @@
+//! Mail queue and the job that delivers queued mail.
+
+use super::{EmailMessage, LogMailer, Mailer};
+use crate::audit::RequestContext;
+use arcana_config::MailConfig;
+use arcana_core::{ArcanaError, ArcanaResult, Interface};
+use arcana_jobs::redis::RedisJobQueue;
+use arcana_jobs::{Job, JobContext, JobError, JobQueue, QueuedJob, WorkerPool, WorkerPoolConfig};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shaku::Component;
+use std::sync::Arc;
+use tracing::{debug, error};
+
+/// Job queue that carries outgoing mail.
+pub const MAIL_QUEUE: &str = "mail";
+
+/// Number of messages the mail worker delivers concurrently.
+const MAIL_WORKER_CONCURRENCY: usize = 2;
+
+/// Job that delivers one email.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SendEmailJob {
+    /// Message to deliver.
+    pub message: EmailMessage,
+}
+
+#[async_trait]
+impl Job for SendEmailJob {
+    const NAME: &'static str = "send_email";
+    const QUEUE: &'static str = MAIL_QUEUE;
+
+    /// Fails: delivery needs the handler that [`spawn_mail_worker`]
+    /// registers with the configured mailer.
+    async fn execute(&self, _ctx: JobContext) -> Result<(), JobError> {
+        Err(JobError::ExecutionFailed("No mailer registered".to_string()))
+    }
+}
+
+/// Sends a queued message, reporting failures as job errors so they are retried.
+async fn deliver(mailer: &dyn Mailer, message: &EmailMessage) -> Result<(), JobError> {
+    mailer
+        .send(message)
+        .await
+        .map_err(|e| JobError::ExecutionFailed(e.to_string()))
+}
+
+/// Interface for queueing outgoing mail.
+#[async_trait]
+pub trait MailQueueInterface: Interface + Send + Sync {
+    /// Queues a message for delivery.
+    async fn enqueue(&self, message: EmailMessage) -> ArcanaResult<()>;
+
+    /// Returns the mail configuration (sender and link targets).
+    fn config(&self) -> &MailConfig;
+}
+
+/// Default mailer of the DI component; the server injects the configured one.
+fn default_mailer() -> Arc<dyn Mailer> {
+    Arc::new(LogMailer::default())
+}
+
+/// Mail queue backed by the Redis job queue.
+///
+/// Without a job queue (Redis disabled), messages are delivered inline.
+#[derive(Component)]
+#[shaku(interface = MailQueueInterface)]
+pub struct MailQueue {
+    /// Job queue messages are enqueued on (`None` delivers inline).
+    jobs: Option<Arc<RedisJobQueue>>,
+    /// Mailer used for inline delivery.
+    #[shaku(default = default_mailer())]
+    mailer: Arc<dyn Mailer>,
+    /// Mail configuration.
+    #[shaku(default)]
+    config: MailConfig,
+}
+
+impl MailQueue {
+    /// Creates a mail queue that enqueues a [`SendEmailJob`] per message.
+    #[must_use]
+    pub fn new(jobs: Arc<RedisJobQueue>, mailer: Arc<dyn Mailer>, config: MailConfig) -> Self {
+        Self {
+            jobs: Some(jobs),
+            mailer,
+            config,
+        }
+    }
+
+    /// Creates a mail queue that delivers every message immediately.
+    #[must_use]
+    pub fn inline(mailer: Arc<dyn Mailer>, config: MailConfig) -> Self {
+        Self {
+            jobs: None,
+            mailer,
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl MailQueueInterface for MailQueue {
+    async fn enqueue(&self, message: EmailMessage) -> ArcanaResult<()> {
+        let Some(jobs) = &self.jobs else {
+            return self.mailer.send(&message).await;
+        };
+
+        let queued = QueuedJob::new(SendEmailJob { message }).tenant(RequestContext::current().tenant_id());
+        let job_id = jobs
+            .enqueue_with(queued)
+            .await
+            .map_err(|e| ArcanaError::Internal(format!("Failed to queue email: {}", e)))?;
+
+        debug!("Queued email as job {}", job_id);
+        Ok(())
+    }
+
+    fn config(&self) -> &MailConfig {
+        &self.config
+    }
+}
+
+impl std::fmt::Debug for MailQueue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MailQueue")
+            .field("queued", &self.jobs.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Starts a worker that delivers queued mail through `mailer`.
+///
+/// Returns the worker pool so it can be stopped on shutdown.
+pub fn spawn_mail_worker(
+    jobs: Arc<RedisJobQueue>,
+    mailer: Arc<dyn Mailer>,
+) -> Arc<WorkerPool<RedisJobQueue>> {
+    let pool = Arc::new(WorkerPool::new(
+        jobs,
+        WorkerPoolConfig {
+            concurrency: MAIL_WORKER_CONCURRENCY,
+            queues: vec![MAIL_QUEUE.to_string()],
+            ..WorkerPoolConfig::default()
+        },
+    ));
+
+    pool.register::<SendEmailJob>(move |job, _ctx| {
+        let mailer = mailer.clone();
+        Box::pin(async move { deliver(mailer.as_ref(), &job.message).await })
+    });
+
+    let worker = pool.clone();
+    tokio::spawn(async move {
+        if let Err(e) = worker.start().await {
+            error!("Mail worker stopped: {}", e);
+        }
+    });
+
+    pool
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Mailer that records sent messages.
+    #[derive(Default)]
+    struct RecordingMailer {
+        sent: Mutex<Vec<EmailMessage>>,
+    }
+
+    #[async_trait]
+    impl Mailer for RecordingMailer {
+        async fn send(&self, message: &EmailMessage) -> ArcanaResult<()> {
+            self.sent.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inline_queue_delivers_immediately() {
+        let mailer = Arc::new(RecordingMailer::default());
+        let queue = MailQueue::inline(mailer.clone(), MailConfig::default());
+
+        let message = EmailMessage::new("user@example.com", "Hello", "Body");
+        queue.enqueue(message.clone()).await.unwrap();
+
+        assert_eq!(*mailer.sent.lock().unwrap(), vec![message]);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_maps_mailer_errors() {
+        struct FailingMailer;
+
+        #[async_trait]
+        impl Mailer for FailingMailer {
+            async fn send(&self, _message: &EmailMessage) -> ArcanaResult<()> {
+                Err(ArcanaError::Internal("smtp down".to_string()))
+            }
+        }
+
+        let result = deliver(&FailingMailer, &EmailMessage::new("a@b.c", "s", "b")).await;
+        assert!(matches!(result, Err(JobError::ExecutionFailed(msg)) if msg.contains("smtp down")));
+    }
+
+    #[test]
+    fn test_send_email_job_payload_round_trip() {
+        let job = SendEmailJob {
+            message: EmailMessage::new("user@example.com", "Hello", "Body"),
+        };
+        let data = arcana_jobs::JobData::new(&job).unwrap();
+
+        assert_eq!(data.name, "send_email");
+        assert_eq!(data.queue, MAIL_QUEUE);
+        let decoded: SendEmailJob = data.deserialize().unwrap();
+        assert_eq!(decoded.message, job.message);
+    }
+
+    #[test]
+    fn test_mail_queue_debug() {
+        let queue = MailQueue::inline(default_mailer(), MailConfig::default());
+        assert!(format!("{:?}", queue).contains("queued: false"));
+    }
+}
@@ -0,0 +1,277 @@
+//! Mail messages and delivery.
+
+use super::SmtpMailer;
+use arcana_config::{MailConfig, MailTransport};
+use arcana_core::{ArcanaError, ArcanaResult};
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// An outgoing email.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmailMessage {
+    /// Recipient address.
+    pub to: String,
+    /// Subject line.
+    pub subject: String,
+    /// Plain-text body.
+    pub body: String,
+}
+
+impl EmailMessage {
+    /// Creates a new message.
+    #[must_use]
+    pub fn new(to: impl Into<String>, subject: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            to: to.into(),
+            subject: subject.into(),
+            body: body.into(),
+        }
+    }
+
+    /// Creates the message asking a new user to confirm their email address.
+    #[must_use]
+    pub fn email_verification(to: &str, username: &str, link: &str) -> Self {
+        Self::new(
+            to,
+            "Verify your email address",
+            format!(
+                "Hi {},\n\n\
+                 Please confirm your email address by opening the link below:\n\n\
+                 {}\n\n\
+                 If you did not create an account, you can ignore this email.\n",
+                username, link
+            ),
+        )
+    }
+
+    /// Creates the message that lets a user choose a new password.
+    #[must_use]
+    pub fn password_reset(to: &str, username: &str, link: &str) -> Self {
+        Self::new(
+            to,
+            "Reset your password",
+            format!(
+                "Hi {},\n\n\
+                 We received a request to reset your password. Open the link below to choose a new one:\n\n\
+                 {}\n\n\
+                 The link can be used once. If you did not ask for a reset, you can ignore this email.\n",
+                username, link
+            ),
+        )
+    }
+}
+
+/// Delivers email.
+///
+/// [`SmtpMailer`] sends through an SMTP server and [`LogMailer`] only logs;
+/// implement this trait to plug in another transport (an email API, ...).
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    /// Sends a message.
+    async fn send(&self, message: &EmailMessage) -> ArcanaResult<()>;
+}
+
+/// Creates the mailer selected by `config.transport`.
+///
+/// # Errors
+///
+/// Returns a configuration error if the SMTP mailer cannot be built.
+pub fn mailer_from_config(config: &MailConfig) -> ArcanaResult<Arc<dyn Mailer>> {
+    Ok(match config.transport {
+        MailTransport::Log => Arc::new(LogMailer::from_config(config)),
+        MailTransport::Smtp => Arc::new(SmtpMailer::from_config(config)?),
+    })
+}
+
+/// Mailer that logs messages instead of sending them, for development and tests.
+///
+/// Only the sender, recipient and subject are logged: bodies carry
+/// verification and reset links, which must not end up in the logs. The
+/// body is kept only when an outbox directory is configured explicitly, in
+/// which case every message is written there as an `.eml` file for local
+/// development and tests.
+#[derive(Debug, Clone)]
+pub struct LogMailer {
+    /// Sender address.
+    from_address: String,
+    /// Directory messages are written to.
+    outbox_dir: Option<PathBuf>,
+}
+
+impl LogMailer {
+    /// Creates a mailer that only logs messages.
+    #[must_use]
+    pub fn new(from_address: impl Into<String>) -> Self {
+        Self {
+            from_address: from_address.into(),
+            outbox_dir: None,
+        }
+    }
+
+    /// Creates a mailer that also writes messages to `outbox_dir`.
+    #[must_use]
+    pub fn with_outbox(from_address: impl Into<String>, outbox_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            from_address: from_address.into(),
+            outbox_dir: Some(outbox_dir.into()),
+        }
+    }
+
+    /// Creates a mailer from the mail configuration.
+    #[must_use]
+    pub fn from_config(config: &MailConfig) -> Self {
+        Self {
+            from_address: config.from_address.clone(),
+            outbox_dir: config.outbox_dir.as_ref().map(PathBuf::from),
+        }
+    }
+
+    /// Renders a message in RFC 5322 form.
+    fn render(&self, message: &EmailMessage) -> String {
+        format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\n\r\n{}",
+            self.from_address,
+            message.to,
+            message.subject,
+            Utc::now().to_rfc2822(),
+            message.body
+        )
+    }
+
+    /// Writes a message to the outbox directory.
+    async fn write_to_outbox(&self, dir: &Path, message: &EmailMessage) -> ArcanaResult<()> {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| ArcanaError::Internal(format!("Failed to create mail outbox: {}", e)))?;
+
+        let path = dir.join(format!(
+            "{}-{}.eml",
+            Utc::now().format("%Y%m%dT%H%M%S%.3f"),
+            uuid::Uuid::now_v7()
+        ));
+        tokio::fs::write(&path, self.render(message))
+            .await
+            .map_err(|e| ArcanaError::Internal(format!("Failed to write {}: {}", path.display(), e)))?;
+
+        debug!("Wrote email to {}", path.display());
+        Ok(())
+    }
+}
+
+impl Default for LogMailer {
+    fn default() -> Self {
+        Self::from_config(&MailConfig::default())
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, message: &EmailMessage) -> ArcanaResult<()> {
+        info!(
+            target: "arcana::mail",
+            from = %self.from_address,
+            to = %message.to,
+            subject = %message.subject,
+            "Email sent"
+        );
+
+        if let Some(dir) = &self.outbox_dir {
+            self.write_to_outbox(dir, message).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_email_verification_message() {
+        let message = EmailMessage::email_verification(
+            "user@example.com",
+            "alice",
+            "https://app.example.com/verify-email?token=abc",
+        );
+
+        assert_eq!(message.to, "user@example.com");
+        assert_eq!(message.subject, "Verify your email address");
+        assert!(message.body.contains("Hi alice"));
+        assert!(message.body.contains("https://app.example.com/verify-email?token=abc"));
+    }
+
+    #[test]
+    fn test_password_reset_message() {
+        let message = EmailMessage::password_reset(
+            "user@example.com",
+            "alice",
+            "https://app.example.com/reset-password?token=abc",
+        );
+
+        assert_eq!(message.to, "user@example.com");
+        assert_eq!(message.subject, "Reset your password");
+        assert!(message.body.contains("Hi alice"));
+        assert!(message.body.contains("https://app.example.com/reset-password?token=abc"));
+    }
+
+    #[tokio::test]
+    async fn test_log_mailer_without_outbox() {
+        let mailer = LogMailer::new("no-reply@example.com");
+        let message = EmailMessage::new("user@example.com", "Hello", "Body");
+        assert!(mailer.send(&message).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_log_mailer_writes_outbox() {
+        let dir = tempfile::tempdir().unwrap();
+        let outbox = dir.path().join("outbox");
+        let mailer = LogMailer::with_outbox("no-reply@example.com", &outbox);
+
+        mailer
+            .send(&EmailMessage::new("user@example.com", "Hello", "Body text"))
+            .await
+            .unwrap();
+
+        let files: Vec<_> = std::fs::read_dir(&outbox).unwrap().collect();
+        assert_eq!(files.len(), 1);
+
+        let content = std::fs::read_to_string(files[0].as_ref().unwrap().path()).unwrap();
+        assert!(content.contains("From: no-reply@example.com"));
+        assert!(content.contains("To: user@example.com"));
+        assert!(content.contains("Subject: Hello"));
+        assert!(content.ends_with("Body text"));
+    }
+
+    #[tokio::test]
+    async fn test_mailer_from_config_selects_transport() {
+        let log = MailConfig::default();
+        assert!(mailer_from_config(&log).is_ok());
+
+        let smtp = MailConfig {
+            transport: MailTransport::Smtp,
+            from_address: "not an address".to_string(),
+            ..MailConfig::default()
+        };
+        assert!(matches!(mailer_from_config(&smtp), Err(ArcanaError::Configuration(_))));
+    }
+
+    #[test]
+    fn test_log_mailer_default_has_no_outbox() {
+        assert_eq!(LogMailer::default().outbox_dir, None);
+    }
+
+    #[test]
+    fn test_log_mailer_from_config() {
+        let config = MailConfig {
+            outbox_dir: Some("/tmp/outbox".to_string()),
+            ..MailConfig::default()
+        };
+        let mailer = LogMailer::from_config(&config);
+        assert_eq!(mailer.from_address, "no-reply@arcana.local");
+        assert_eq!(mailer.outbox_dir, Some(PathBuf::from("/tmp/outbox")));
+    }
+}
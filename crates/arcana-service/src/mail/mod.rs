@@ -0,0 +1,17 @@
+//! Outgoing mail for the service layer.
+//!
+//! Services queue [`EmailMessage`]s through the [`MailQueueInterface`]. With
+//! Redis enabled each message becomes a [`SendEmailJob`] handled by a mail
+//! worker; otherwise it is delivered inline. Delivery itself goes through the
+//! [`Mailer`] selected by `mail.transport`: [`SmtpMailer`] in deployments and
+//! [`LogMailer`] for development and tests.
+
+mod mail_queue;
+mod mailer;
+mod smtp;
+
+pub use mail_queue::{
+    spawn_mail_worker, MailQueue, MailQueueInterface, MailQueueParameters, SendEmailJob, MAIL_QUEUE,
+};
+pub use mailer::{mailer_from_config, EmailMessage, LogMailer, Mailer};
+pub use smtp::SmtpMailer;
@@ -0,0 +1,160 @@
+//! SMTP delivery.
+
+use super::{EmailMessage, Mailer};
+use arcana_config::{MailConfig, SmtpTls};
+use arcana_core::{ArcanaError, ArcanaResult};
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::time::Duration;
+use tracing::info;
+
+/// Mailer that sends messages through an SMTP server.
+///
+/// Connections are pooled and reused between messages.
+pub struct SmtpMailer {
+    /// Sender of every message.
+    from: Mailbox,
+    /// Pooled connection to the server.
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    /// Creates a mailer for the server in `config.smtp`.
+    ///
+    /// No connection is opened until the first message is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the sender address is invalid or
+    /// the TLS parameters cannot be built for the host.
+    pub fn from_config(config: &MailConfig) -> ArcanaResult<Self> {
+        let from = config
+            .from_address
+            .parse::<Mailbox>()
+            .map_err(|e| ArcanaError::Configuration(format!("Invalid mail.from_address: {}", e)))?;
+
+        let smtp = &config.smtp;
+        let builder = match smtp.tls {
+            SmtpTls::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&smtp.host),
+            SmtpTls::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp.host)
+                .map_err(|e| ArcanaError::Configuration(format!("Invalid SMTP host {}: {}", smtp.host, e)))?,
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp.host)
+                .map_err(|e| ArcanaError::Configuration(format!("Invalid SMTP host {}: {}", smtp.host, e)))?,
+        };
+        let mut builder = builder
+            .port(smtp.port)
+            .timeout(Some(Duration::from_secs(smtp.timeout_secs)));
+        if let (Some(username), Some(password)) = (&smtp.username, &smtp.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            from,
+            transport: builder.build(),
+        })
+    }
+
+    /// Builds the MIME message for `message`.
+    fn build_message(&self, message: &EmailMessage) -> ArcanaResult<Message> {
+        let to = message
+            .to
+            .parse::<Mailbox>()
+            .map_err(|e| ArcanaError::Validation(format!("Invalid recipient address: {}", e)))?;
+
+        Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(message.subject.clone())
+            .header(ContentType::TEXT_PLAIN)
+            .body(message.body.clone())
+            .map_err(|e| ArcanaError::Internal(format!("Failed to build email: {}", e)))
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: &EmailMessage) -> ArcanaResult<()> {
+        let email = self.build_message(message)?;
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| ArcanaError::ExternalService {
+                service: "smtp".to_string(),
+                message: e.to_string(),
+            })?;
+
+        info!(
+            target: "arcana::mail",
+            from = %self.from,
+            to = %message.to,
+            subject = %message.subject,
+            "Email sent"
+        );
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for SmtpMailer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmtpMailer")
+            .field("from", &self.from.to_string())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn smtp_config() -> MailConfig {
+        let mut config = MailConfig::default();
+        config.smtp.host = "smtp.example.com".to_string();
+        config
+    }
+
+    #[tokio::test]
+    async fn test_from_config_rejects_invalid_sender() {
+        let config = MailConfig {
+            from_address: "not an address".to_string(),
+            ..smtp_config()
+        };
+        assert!(matches!(SmtpMailer::from_config(&config), Err(ArcanaError::Configuration(_))));
+    }
+
+    #[tokio::test]
+    async fn test_build_message() {
+        let mailer = SmtpMailer::from_config(&smtp_config()).unwrap();
+        let email = mailer
+            .build_message(&EmailMessage::new("user@example.com", "Hello", "Body text"))
+            .unwrap();
+
+        let formatted = String::from_utf8(email.formatted()).unwrap();
+        assert!(formatted.contains("From: no-reply@arcana.local"));
+        assert!(formatted.contains("To: user@example.com"));
+        assert!(formatted.contains("Subject: Hello"));
+        assert!(formatted.contains("Body text"));
+    }
+
+    #[tokio::test]
+    async fn test_send_rejects_invalid_recipient() {
+        let mailer = SmtpMailer::from_config(&smtp_config()).unwrap();
+        let result = mailer.send(&EmailMessage::new("nobody", "Hello", "Body")).await;
+        assert!(matches!(result, Err(ArcanaError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_send_reports_unreachable_server() {
+        let mut config = smtp_config();
+        config.smtp.host = "127.0.0.1".to_string();
+        config.smtp.port = 1;
+        config.smtp.tls = SmtpTls::None;
+        config.smtp.timeout_secs = 1;
+        let mailer = SmtpMailer::from_config(&config).unwrap();
+
+        let result = mailer.send(&EmailMessage::new("user@example.com", "Hello", "Body")).await;
+        assert!(matches!(result, Err(ArcanaError::ExternalService { service, .. }) if service == "smtp"));
+    }
+}
@@ -0,0 +1,9 @@
+//! Data-driven RBAC for the service layer.
+//!
+//! [`PermissionResolver`] resolves the effective permissions of
+//! authenticated users from the role repository and caches them, so the
+//! auth middleware can attach them to the request claims.
+
+mod permission_resolver;
+
+pub use permission_resolver::{PermissionResolver, PermissionResolverParameters, DEFAULT_PERMISSION_CACHE_TTL};
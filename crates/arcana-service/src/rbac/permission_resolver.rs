@@ -0,0 +1,230 @@
+//! Cached resolution of effective user permissions.
+
+use crate::cache::{cache_keys, CacheExt, CacheInterface};
+use arcana_core::{ArcanaResult, PermissionSet, UserId, UserRole};
+use arcana_repository::RoleRepository;
+use arcana_security::PermissionResolverInterface;
+use async_trait::async_trait;
+use shaku::Component;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// Default time resolved permissions are cached (1 minute).
+pub const DEFAULT_PERMISSION_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Permission resolver reading grants from the role repository.
+///
+/// Resolved permissions are cached in Redis when the cache is enabled, so
+/// invalidations reach every instance; otherwise they are cached in memory.
+/// Either way a change takes effect after at most `ttl` on instances that
+/// did not make it.
+#[derive(Component)]
+#[shaku(interface = PermissionResolverInterface)]
+pub struct PermissionResolver {
+    #[shaku(inject)]
+    role_repository: Arc<dyn RoleRepository>,
+    #[shaku(inject)]
+    cache: Arc<dyn CacheInterface>,
+    /// How long resolved permissions are cached.
+    #[shaku(default = DEFAULT_PERMISSION_CACHE_TTL)]
+    ttl: Duration,
+    /// In-memory cache used when the shared cache is disabled: key -> (permissions, expiry).
+    #[shaku(default)]
+    local: Mutex<HashMap<String, (PermissionSet, Instant)>>,
+}
+
+impl PermissionResolver {
+    /// Creates a resolver caching permissions for `ttl`.
+    #[must_use]
+    pub fn new(role_repository: Arc<dyn RoleRepository>, cache: Arc<dyn CacheInterface>, ttl: Duration) -> Self {
+        Self {
+            role_repository,
+            cache,
+            ttl,
+            local: Mutex::default(),
+        }
+    }
+
+    async fn cached(&self, key: &str) -> Option<PermissionSet> {
+        if self.cache.is_enabled() {
+            return self.cache.get::<PermissionSet>(key).await.ok().flatten();
+        }
+        let local = self.local.lock().unwrap_or_else(|e| e.into_inner());
+        local
+            .get(key)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(permissions, _)| permissions.clone())
+    }
+
+    async fn store(&self, key: String, permissions: &PermissionSet) {
+        if self.cache.is_enabled() {
+            let _ = self.cache.set(&key, permissions, self.ttl).await;
+            return;
+        }
+        let now = Instant::now();
+        let mut local = self.local.lock().unwrap_or_else(|e| e.into_inner());
+        local.retain(|_, (_, expires_at)| *expires_at > now);
+        local.insert(key, (permissions.clone(), now + self.ttl));
+    }
+
+    async fn invalidate(&self, pattern: &str) {
+        if self.cache.is_enabled() {
+            if let Err(e) = self.cache.delete_pattern(pattern).await {
+                warn!("Failed to invalidate cached permissions {}: {}", pattern, e);
+            }
+        }
+        let prefix = pattern.trim_end_matches('*');
+        self.local
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|key, _| !key.starts_with(prefix));
+    }
+}
+
+#[async_trait]
+impl PermissionResolverInterface for PermissionResolver {
+    async fn resolve(&self, user_id: UserId, role: UserRole) -> ArcanaResult<PermissionSet> {
+        let key = cache_keys::user_permissions(user_id, role);
+        if let Some(permissions) = self.cached(&key).await {
+            return Ok(permissions);
+        }
+
+        debug!("Resolving permissions of user {}", user_id);
+        let permissions = self
+            .role_repository
+            .find_effective_permissions(user_id, role)
+            .await?;
+        self.store(key, &permissions).await;
+        Ok(permissions)
+    }
+
+    async fn invalidate_user(&self, user_id: UserId) {
+        self.invalidate(&cache_keys::user_permissions_pattern(user_id)).await;
+    }
+
+    async fn invalidate_all(&self) {
+        self.invalidate(&cache_keys::permissions_pattern()).await;
+    }
+}
+
+impl std::fmt::Debug for PermissionResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PermissionResolver")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::RedisCacheService;
+    use arcana_core::{Permission, Role, RoleId};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Role repository that grants `UserUpdate` on top of the role defaults
+    /// and counts lookups.
+    #[derive(Default)]
+    struct CountingRoleRepository {
+        lookups: AtomicUsize,
+    }
+
+    impl std::fmt::Debug for CountingRoleRepository {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("CountingRoleRepository").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl RoleRepository for CountingRoleRepository {
+        async fn find_by_id(&self, _id: RoleId) -> ArcanaResult<Option<Role>> {
+            Ok(None)
+        }
+
+        async fn find_by_name(&self, _name: &str) -> ArcanaResult<Option<Role>> {
+            Ok(None)
+        }
+
+        async fn find_all(&self) -> ArcanaResult<Vec<Role>> {
+            Ok(Vec::new())
+        }
+
+        async fn save(&self, role: &Role) -> ArcanaResult<Role> {
+            Ok(role.clone())
+        }
+
+        async fn update(&self, role: &Role) -> ArcanaResult<Role> {
+            Ok(role.clone())
+        }
+
+        async fn delete(&self, _id: RoleId) -> ArcanaResult<bool> {
+            Ok(false)
+        }
+
+        async fn find_assigned(&self, _user_id: UserId) -> ArcanaResult<Vec<Role>> {
+            Ok(Vec::new())
+        }
+
+        async fn assign(&self, _user_id: UserId, _role_id: RoleId) -> ArcanaResult<()> {
+            Ok(())
+        }
+
+        async fn unassign(&self, _user_id: UserId, _role_id: RoleId) -> ArcanaResult<bool> {
+            Ok(false)
+        }
+
+        async fn find_user_permissions(&self, _user_id: UserId) -> ArcanaResult<PermissionSet> {
+            Ok(PermissionSet::new())
+        }
+
+        async fn grant(&self, _user_id: UserId, _permission: Permission) -> ArcanaResult<()> {
+            Ok(())
+        }
+
+        async fn revoke(&self, _user_id: UserId, _permission: Permission) -> ArcanaResult<bool> {
+            Ok(false)
+        }
+
+        async fn find_effective_permissions(&self, _user_id: UserId, role: UserRole) -> ArcanaResult<PermissionSet> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            let mut permissions = role.default_permissions();
+            permissions.insert(Permission::UserUpdate);
+            Ok(permissions)
+        }
+    }
+
+    fn create_resolver() -> (PermissionResolver, Arc<CountingRoleRepository>) {
+        let repository = Arc::new(CountingRoleRepository::default());
+        let resolver = PermissionResolver::new(
+            repository.clone(),
+            Arc::new(RedisCacheService::disabled()),
+            DEFAULT_PERMISSION_CACHE_TTL,
+        );
+        (resolver, repository)
+    }
+
+    #[tokio::test]
+    async fn test_resolved_permissions_are_cached_until_invalidated() {
+        let (resolver, repository) = create_resolver();
+        let agent = UserId::new();
+
+        let permissions = resolver.resolve(agent, UserRole::User).await.unwrap();
+        assert!(permissions.contains(Permission::UserUpdate));
+        resolver.resolve(agent, UserRole::User).await.unwrap();
+        assert_eq!(repository.lookups.load(Ordering::SeqCst), 1);
+
+        resolver.invalidate_user(UserId::new()).await;
+        resolver.resolve(agent, UserRole::User).await.unwrap();
+        assert_eq!(repository.lookups.load(Ordering::SeqCst), 1);
+
+        resolver.invalidate_user(agent).await;
+        resolver.resolve(agent, UserRole::User).await.unwrap();
+        assert_eq!(repository.lookups.load(Ordering::SeqCst), 2);
+
+        resolver.invalidate_all().await;
+        resolver.resolve(agent, UserRole::User).await.unwrap();
+        assert_eq!(repository.lookups.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,39 @@
+//! Role service trait definition.
+
+use crate::dto::{CreateRoleRequest, RoleListResponse, RoleResponse, UpdateRoleRequest, UserPermissionsResponse};
+use arcana_core::{ArcanaResult, Interface, Permission, RoleId, UserId};
+use async_trait::async_trait;
+
+/// Role service trait for managing roles and permission grants.
+#[async_trait]
+pub trait RoleService: Interface + Send + Sync {
+    /// Creates a custom role.
+    async fn create_role(&self, request: CreateRoleRequest) -> ArcanaResult<RoleResponse>;
+
+    /// Gets a role by ID.
+    async fn get_role(&self, id: RoleId) -> ArcanaResult<RoleResponse>;
+
+    /// Lists all roles, built-in roles first.
+    async fn list_roles(&self) -> ArcanaResult<RoleListResponse>;
+
+    /// Changes a role.
+    async fn update_role(&self, id: RoleId, request: UpdateRoleRequest) -> ArcanaResult<RoleResponse>;
+
+    /// Deletes a custom role and removes it from every user.
+    async fn delete_role(&self, id: RoleId) -> ArcanaResult<()>;
+
+    /// Gets the roles and permission grants of a user.
+    async fn get_user_permissions(&self, user_id: UserId) -> ArcanaResult<UserPermissionsResponse>;
+
+    /// Assigns a custom role to a user.
+    async fn assign_role(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<UserPermissionsResponse>;
+
+    /// Removes a custom role from a user.
+    async fn unassign_role(&self, user_id: UserId, role_id: RoleId) -> ArcanaResult<UserPermissionsResponse>;
+
+    /// Grants a permission to a user directly.
+    async fn grant_permission(&self, user_id: UserId, permission: Permission) -> ArcanaResult<UserPermissionsResponse>;
+
+    /// Revokes a permission granted to a user directly.
+    async fn revoke_permission(&self, user_id: UserId, permission: Permission) -> ArcanaResult<UserPermissionsResponse>;
+}
@@ -0,0 +1,21 @@
+//! Tenant service trait definition.
+
+use crate::dto::{CreateTenantRequest, TenantListResponse, TenantResponse, UpdateTenantRequest};
+use arcana_core::{ArcanaResult, Interface, PageRequest, TenantId};
+use async_trait::async_trait;
+
+/// Tenant service trait for managing the tenants of the deployment.
+#[async_trait]
+pub trait TenantService: Interface + Send + Sync {
+    /// Creates a tenant.
+    async fn create_tenant(&self, request: CreateTenantRequest) -> ArcanaResult<TenantResponse>;
+
+    /// Gets a tenant by ID.
+    async fn get_tenant(&self, id: TenantId) -> ArcanaResult<TenantResponse>;
+
+    /// Lists tenants with pagination, oldest first.
+    async fn list_tenants(&self, page: PageRequest) -> ArcanaResult<TenantListResponse>;
+
+    /// Renames, deactivates or reactivates a tenant.
+    async fn update_tenant(&self, id: TenantId, request: UpdateTenantRequest) -> ArcanaResult<TenantResponse>;
+}
@@ -0,0 +1,29 @@
+//! Webhook service trait definition.
+
+use crate::dto::{
+    CreateWebhookRequest, UpdateWebhookRequest, WebhookDeliveryListResponse, WebhookListResponse, WebhookResponse,
+};
+use arcana_core::{ArcanaResult, Interface, PageRequest, WebhookSubscriptionId};
+use async_trait::async_trait;
+
+/// Webhook service trait for managing webhook subscriptions.
+#[async_trait]
+pub trait WebhookService: Interface + Send + Sync {
+    /// Subscribes an endpoint to domain events.
+    async fn create_webhook(&self, request: CreateWebhookRequest) -> ArcanaResult<WebhookResponse>;
+
+    /// Gets a subscription by ID.
+    async fn get_webhook(&self, id: WebhookSubscriptionId) -> ArcanaResult<WebhookResponse>;
+
+    /// Lists subscriptions with pagination.
+    async fn list_webhooks(&self, page: PageRequest) -> ArcanaResult<WebhookListResponse>;
+
+    /// Changes a subscription.
+    async fn update_webhook(&self, id: WebhookSubscriptionId, request: UpdateWebhookRequest) -> ArcanaResult<WebhookResponse>;
+
+    /// Deletes a subscription together with its delivery log.
+    async fn delete_webhook(&self, id: WebhookSubscriptionId) -> ArcanaResult<()>;
+
+    /// Lists the delivery attempts of a subscription, newest first.
+    async fn list_deliveries(&self, id: WebhookSubscriptionId, page: PageRequest) -> ArcanaResult<WebhookDeliveryListResponse>;
+}
@@ -0,0 +1,194 @@
+//! Delivering webhook payloads.
+
+use arcana_core::{WebhookDelivery, WebhookDeliveryId, WebhookDeliveryStatus, WebhookSubscriptionId};
+use arcana_jobs::redis::RedisJobQueue;
+use arcana_jobs::{Job, JobContext, JobError, WorkerPool, WorkerPoolConfig};
+use arcana_repository::WebhookRepository;
+use arcana_security::{sign_webhook_payload, WEBHOOK_SIGNATURE_HEADER};
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, error, warn};
+use uuid::Uuid;
+
+/// Job queue that carries webhook deliveries.
+pub const WEBHOOK_QUEUE: &str = "webhooks";
+
+/// Header naming the type of the delivered event.
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Arcana-Event";
+
+/// Header carrying the event ID, the same for every attempt.
+pub const WEBHOOK_DELIVERY_HEADER: &str = "X-Arcana-Delivery";
+
+/// Number of deliveries the webhook worker runs concurrently.
+const WEBHOOK_WORKER_CONCURRENCY: usize = 4;
+
+/// Time an endpoint has to answer.
+const WEBHOOK_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Job that posts one event to one webhook subscription.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliverWebhookJob {
+    /// Subscription the event is posted to.
+    pub subscription_id: WebhookSubscriptionId,
+    /// ID of the event.
+    pub event_id: Uuid,
+    /// Type of the event.
+    pub event_type: String,
+    /// JSON body, signed and posted as is.
+    pub payload: String,
+}
+
+#[async_trait]
+impl Job for DeliverWebhookJob {
+    const NAME: &'static str = "deliver_webhook";
+    const QUEUE: &'static str = WEBHOOK_QUEUE;
+    const MAX_RETRIES: u32 = 5;
+    const TIMEOUT_SECS: u64 = 30;
+
+    /// Fails: delivery needs the handler that [`spawn_webhook_worker`]
+    /// registers with a [`WebhookDeliverer`].
+    async fn execute(&self, _ctx: JobContext) -> Result<(), JobError> {
+        Err(JobError::ExecutionFailed("No webhook deliverer registered".to_string()))
+    }
+}
+
+/// Posts signed webhook payloads and records each attempt in the delivery log.
+pub struct WebhookDeliverer {
+    repository: Arc<dyn WebhookRepository>,
+    client: reqwest::Client,
+}
+
+impl WebhookDeliverer {
+    /// Creates a deliverer reading subscriptions from `repository`.
+    #[must_use]
+    pub fn new(repository: Arc<dyn WebhookRepository>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(WEBHOOK_REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self { repository, client }
+    }
+
+    /// Makes one delivery attempt.
+    ///
+    /// Succeeds if the endpoint answers with a 2xx status, or if the
+    /// subscription was deleted or paused in the meantime. `last_attempt`
+    /// marks a failure as dead-lettered in the delivery log.
+    pub async fn deliver(&self, job: &DeliverWebhookJob, attempt: u32, last_attempt: bool) -> Result<(), JobError> {
+        let subscription = match self.repository.find_by_id(job.subscription_id).await {
+            Ok(Some(subscription)) if subscription.active => subscription,
+            Ok(_) => {
+                debug!("Skipping {} for inactive webhook {}", job.event_type, job.subscription_id);
+                return Ok(());
+            }
+            Err(e) => return Err(JobError::ExecutionFailed(e.to_string())),
+        };
+
+        let signature = sign_webhook_payload(&subscription.secret, Utc::now().timestamp(), job.payload.as_bytes());
+        let attempted_at = Utc::now();
+        let started = Instant::now();
+        let response = self
+            .client
+            .post(&subscription.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_EVENT_HEADER, &job.event_type)
+            .header(WEBHOOK_DELIVERY_HEADER, job.event_id.to_string())
+            .header(WEBHOOK_SIGNATURE_HEADER, signature)
+            .body(job.payload.clone())
+            .send()
+            .await;
+        let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        let (response_status, error) = match response {
+            Ok(response) if response.status().is_success() => (Some(response.status().as_u16()), None),
+            Ok(response) => (
+                Some(response.status().as_u16()),
+                Some(format!("Endpoint answered {}", response.status())),
+            ),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        let status = match (&error, last_attempt) {
+            (None, _) => WebhookDeliveryStatus::Succeeded,
+            (Some(_), false) => WebhookDeliveryStatus::Failed,
+            (Some(_), true) => WebhookDeliveryStatus::DeadLettered,
+        };
+
+        let delivery = WebhookDelivery {
+            id: WebhookDeliveryId::new(),
+            subscription_id: subscription.id,
+            event_id: job.event_id,
+            event_type: job.event_type.clone(),
+            attempt,
+            status,
+            response_status,
+            error: error.clone(),
+            duration_ms,
+            attempted_at,
+        };
+        if let Err(e) = self.repository.save_delivery(&delivery).await {
+            warn!(
+                target: "arcana::webhooks",
+                subscription_id = %subscription.id,
+                event_id = %job.event_id,
+                "Failed to log webhook delivery: {}",
+                e
+            );
+        }
+
+        match error {
+            None => Ok(()),
+            Some(error) => {
+                warn!(
+                    target: "arcana::webhooks",
+                    subscription_id = %subscription.id,
+                    event_id = %job.event_id,
+                    attempt,
+                    %status,
+                    "Webhook delivery failed: {}",
+                    error
+                );
+                Err(JobError::ExecutionFailed(error))
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for WebhookDeliverer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookDeliverer").finish_non_exhaustive()
+    }
+}
+
+/// Starts a worker that delivers queued webhooks through `deliverer`.
+///
+/// Returns the worker pool so it can be stopped on shutdown.
+pub fn spawn_webhook_worker(
+    jobs: Arc<RedisJobQueue>,
+    deliverer: Arc<WebhookDeliverer>,
+) -> Arc<WorkerPool<RedisJobQueue>> {
+    let pool = Arc::new(WorkerPool::new(
+        jobs,
+        WorkerPoolConfig {
+            concurrency: WEBHOOK_WORKER_CONCURRENCY,
+            queues: vec![WEBHOOK_QUEUE.to_string()],
+            ..WorkerPoolConfig::default()
+        },
+    ));
+
+    pool.register::<DeliverWebhookJob>(move |job, ctx| {
+        let deliverer = deliverer.clone();
+        Box::pin(async move { deliverer.deliver(&job, ctx.attempt, ctx.is_last_attempt()).await })
+    });
+
+    let worker = pool.clone();
+    tokio::spawn(async move {
+        if let Err(e) = worker.start().await {
+            error!("Webhook worker stopped: {}", e);
+        }
+    });
+
+    pool
+}
@@ -0,0 +1,145 @@
+//! Turning domain events into webhook deliveries.
+
+use super::{DeliverWebhookJob, WebhookDeliverer};
+use arcana_core::{ArcanaResult, DomainEvent, EventHandler};
+use arcana_jobs::redis::RedisJobQueue;
+use arcana_jobs::{Job, JobQueue, RetryPolicy};
+use arcana_repository::WebhookRepository;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+use tracing::{debug, error};
+use uuid::Uuid;
+
+/// Where deliveries are handed to.
+enum Delivery {
+    /// Enqueued on the job queue.
+    Queued(Arc<RedisJobQueue>),
+    /// Delivered by a background task of this process.
+    Inline {
+        deliverer: Arc<WebhookDeliverer>,
+        retry_policy: RetryPolicy,
+    },
+}
+
+/// Event handler that creates a delivery for every subscription matching a
+/// published event.
+///
+/// Subscribe it to every event with
+/// [`EventBusExt::subscribe_all`](crate::EventBusExt::subscribe_all).
+pub struct WebhookDispatcher {
+    repository: Arc<dyn WebhookRepository>,
+    delivery: Delivery,
+}
+
+impl WebhookDispatcher {
+    /// Creates a dispatcher that enqueues a [`DeliverWebhookJob`] per delivery.
+    #[must_use]
+    pub fn queued(repository: Arc<dyn WebhookRepository>, jobs: Arc<RedisJobQueue>) -> Self {
+        Self {
+            repository,
+            delivery: Delivery::Queued(jobs),
+        }
+    }
+
+    /// Creates a dispatcher that delivers in background tasks (Redis disabled).
+    ///
+    /// Failed deliveries are retried with the retry policy of
+    /// [`DeliverWebhookJob`] but do not survive a restart.
+    #[must_use]
+    pub fn inline(repository: Arc<dyn WebhookRepository>, deliverer: Arc<WebhookDeliverer>) -> Self {
+        Self {
+            repository,
+            delivery: Delivery::Inline {
+                deliverer,
+                retry_policy: RetryPolicy::exponential(DeliverWebhookJob::MAX_RETRIES),
+            },
+        }
+    }
+
+    /// Overrides the retry policy of inline deliveries.
+    #[must_use]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        if let Delivery::Inline { retry_policy, .. } = &mut self.delivery {
+            *retry_policy = policy;
+        }
+        self
+    }
+}
+
+/// Builds the JSON body posted for an event.
+fn payload(event_id: Uuid, event: &dyn DomainEvent) -> ArcanaResult<String> {
+    let data: serde_json::Value = serde_json::from_str(&event.to_json()?)?;
+    Ok(json!({
+        "id": event_id,
+        "type": event.event_type(),
+        "aggregate_id": event.aggregate_id(),
+        "occurred_at": event.timestamp(),
+        "data": data,
+    })
+    .to_string())
+}
+
+/// Delivers a job, retrying failures until the policy is exhausted.
+async fn deliver_with_retries(deliverer: &WebhookDeliverer, job: &DeliverWebhookJob, policy: &RetryPolicy) {
+    let mut attempt = 1;
+    loop {
+        let retry = policy.should_retry(attempt);
+        if deliverer.deliver(job, attempt, !retry).await.is_ok() || !retry {
+            return;
+        }
+        tokio::time::sleep(policy.delay_for_attempt(attempt)).await;
+        attempt += 1;
+    }
+}
+
+#[async_trait]
+impl EventHandler<dyn DomainEvent> for WebhookDispatcher {
+    async fn handle(&self, event: &dyn DomainEvent) -> ArcanaResult<()> {
+        let subscriptions = self.repository.find_subscribed(event.event_type()).await?;
+        if subscriptions.is_empty() {
+            return Ok(());
+        }
+
+        let event_id = Uuid::now_v7();
+        let payload = payload(event_id, event)?;
+        debug!("Dispatching {} {} to {} webhooks", event.event_type(), event_id, subscriptions.len());
+
+        for subscription in subscriptions {
+            let job = DeliverWebhookJob {
+                subscription_id: subscription.id,
+                event_id,
+                event_type: event.event_type().to_string(),
+                payload: payload.clone(),
+            };
+
+            match &self.delivery {
+                Delivery::Queued(jobs) => {
+                    if let Err(e) = jobs.enqueue(job).await {
+                        error!(
+                            target: "arcana::webhooks",
+                            subscription_id = %subscription.id,
+                            %event_id,
+                            "Failed to queue webhook delivery: {}",
+                            e
+                        );
+                    }
+                }
+                Delivery::Inline { deliverer, retry_policy } => {
+                    let deliverer = deliverer.clone();
+                    let retry_policy = retry_policy.clone();
+                    tokio::spawn(async move { deliver_with_retries(&deliverer, &job, &retry_policy).await });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for WebhookDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookDispatcher")
+            .field("queued", &matches!(self.delivery, Delivery::Queued(_)))
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,338 @@
+//! Outbound webhooks.
+//!
+//! The [`WebhookDispatcher`] is subscribed to the event bus. For every
+//! published domain event it looks up the matching webhook subscriptions and
+//! hands one [`DeliverWebhookJob`] per subscription to the job queue, where
+//! the worker started by [`spawn_webhook_worker`] posts it with the
+//! [`WebhookDeliverer`]. Failed deliveries are retried with the backoff of
+//! the job's retry policy and dead-lettered once it is exhausted; every
+//! attempt is written to the delivery log of the subscription.
+//!
+//! Payloads are signed with the subscription secret (see
+//! [`arcana_security::sign_webhook_payload`]) so that receivers can verify
+//! their origin.
+
+mod delivery;
+mod dispatcher;
+
+pub use delivery::{
+    spawn_webhook_worker, DeliverWebhookJob, WebhookDeliverer, WEBHOOK_DELIVERY_HEADER, WEBHOOK_EVENT_HEADER,
+    WEBHOOK_QUEUE,
+};
+pub use dispatcher::WebhookDispatcher;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcana_core::{
+        ArcanaError, ArcanaResult, EventHandler, Page, PageRequest, UserCreated, UserId, WebhookDelivery,
+        WebhookDeliveryStatus, WebhookSubscription, WebhookSubscriptionId,
+    };
+    use arcana_jobs::{Job, JobError, RetryPolicy};
+    use arcana_repository::WebhookRepository;
+    use arcana_security::{verify_webhook_signature, WEBHOOK_SIGNATURE_HEADER};
+    use async_trait::async_trait;
+    use axum::extract::State;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    const SECRET: &str = "whsec_0123456789abcdef";
+
+    #[derive(Default)]
+    struct InMemoryWebhookRepository {
+        subscriptions: Mutex<Vec<WebhookSubscription>>,
+        deliveries: Mutex<Vec<WebhookDelivery>>,
+    }
+
+    impl std::fmt::Debug for InMemoryWebhookRepository {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("InMemoryWebhookRepository").finish_non_exhaustive()
+        }
+    }
+
+    impl InMemoryWebhookRepository {
+        fn statuses(&self) -> Vec<WebhookDeliveryStatus> {
+            self.deliveries.lock().unwrap().iter().map(|d| d.status).collect()
+        }
+    }
+
+    #[async_trait]
+    impl WebhookRepository for InMemoryWebhookRepository {
+        async fn find_by_id(&self, id: WebhookSubscriptionId) -> ArcanaResult<Option<WebhookSubscription>> {
+            Ok(self.subscriptions.lock().unwrap().iter().find(|s| s.id == id).cloned())
+        }
+
+        async fn find_all(&self, page: PageRequest) -> ArcanaResult<Page<WebhookSubscription>> {
+            let subscriptions = self.subscriptions.lock().unwrap().clone();
+            let total = subscriptions.len() as u64;
+            Ok(Page::new(subscriptions, page.page, page.size, total))
+        }
+
+        async fn find_subscribed(&self, event_type: &str) -> ArcanaResult<Vec<WebhookSubscription>> {
+            Ok(self.subscriptions.lock().unwrap().iter().filter(|s| s.matches(event_type)).cloned().collect())
+        }
+
+        async fn save(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription> {
+            self.subscriptions.lock().unwrap().push(subscription.clone());
+            Ok(subscription.clone())
+        }
+
+        async fn update(&self, subscription: &WebhookSubscription) -> ArcanaResult<WebhookSubscription> {
+            Err(ArcanaError::not_found("Webhook", subscription.id))
+        }
+
+        async fn delete(&self, _id: WebhookSubscriptionId) -> ArcanaResult<bool> {
+            Ok(false)
+        }
+
+        async fn save_delivery(&self, delivery: &WebhookDelivery) -> ArcanaResult<()> {
+            self.deliveries.lock().unwrap().push(delivery.clone());
+            Ok(())
+        }
+
+        async fn find_deliveries(
+            &self,
+            _subscription_id: WebhookSubscriptionId,
+            page: PageRequest,
+        ) -> ArcanaResult<Page<WebhookDelivery>> {
+            Ok(Page::empty(page.page, page.size))
+        }
+    }
+
+    /// A request the stand-in receiver got.
+    struct Received {
+        headers: HeaderMap,
+        body: String,
+    }
+
+    /// Local endpoint standing in for a webhook receiver.
+    #[derive(Clone, Default)]
+    struct Receiver {
+        received: Arc<Mutex<Vec<Received>>>,
+        /// Statuses to answer with, in order; 200 once they are used up.
+        statuses: Arc<Mutex<VecDeque<u16>>>,
+    }
+
+    async fn receive(State(receiver): State<Receiver>, headers: HeaderMap, body: String) -> StatusCode {
+        receiver.received.lock().unwrap().push(Received { headers, body });
+        let status = receiver.statuses.lock().unwrap().pop_front().unwrap_or(200);
+        StatusCode::from_u16(status).unwrap()
+    }
+
+    /// Starts a receiver answering with `statuses`, returning its URL.
+    async fn start_receiver(statuses: &[u16]) -> (String, Receiver) {
+        let receiver = Receiver {
+            statuses: Arc::new(Mutex::new(statuses.iter().copied().collect())),
+            ..Default::default()
+        };
+        let app = Router::new().route("/hooks", post(receive)).with_state(receiver.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (url, receiver)
+    }
+
+    async fn subscribe(repository: &InMemoryWebhookRepository, url: &str, event_types: &[&str]) -> WebhookSubscription {
+        let subscription = WebhookSubscription::new(
+            url.to_string(),
+            event_types.iter().map(|t| t.to_string()).collect(),
+            SECRET.to_string(),
+            None,
+        );
+        repository.save(&subscription).await.unwrap()
+    }
+
+    fn job(subscription: &WebhookSubscription) -> DeliverWebhookJob {
+        DeliverWebhookJob {
+            subscription_id: subscription.id,
+            event_id: Uuid::now_v7(),
+            event_type: "user.created".to_string(),
+            payload: r#"{"type":"user.created"}"#.to_string(),
+        }
+    }
+
+    /// Waits until `count` attempts are logged.
+    async fn wait_for_deliveries(repository: &InMemoryWebhookRepository, count: usize) {
+        for _ in 0..500 {
+            if repository.deliveries.lock().unwrap().len() >= count {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("expected {} deliveries, got {}", count, repository.deliveries.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_delivery_is_signed_and_logged() {
+        let (url, receiver) = start_receiver(&[]).await;
+        let repository = Arc::new(InMemoryWebhookRepository::default());
+        let subscription = subscribe(&repository, &url, &["user.created"]).await;
+        let deliverer = WebhookDeliverer::new(repository.clone());
+        let job = job(&subscription);
+
+        deliverer.deliver(&job, 1, false).await.unwrap();
+
+        let received = receiver.received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        let request = &received[0];
+        assert_eq!(request.body, job.payload);
+        assert_eq!(request.headers[WEBHOOK_EVENT_HEADER], "user.created");
+        assert_eq!(request.headers[WEBHOOK_DELIVERY_HEADER], job.event_id.to_string().as_str());
+        let signature = request.headers[WEBHOOK_SIGNATURE_HEADER].to_str().unwrap();
+        assert!(verify_webhook_signature(SECRET, signature, request.body.as_bytes(), 300));
+        assert!(!verify_webhook_signature("another-secret-value", signature, request.body.as_bytes(), 300));
+
+        let deliveries = repository.deliveries.lock().unwrap();
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Succeeded);
+        assert_eq!(deliveries[0].response_status, Some(200));
+        assert_eq!(deliveries[0].event_id, job.event_id);
+        assert_eq!(deliveries[0].attempt, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_delivery_is_logged() {
+        let (url, _receiver) = start_receiver(&[500, 503]).await;
+        let repository = Arc::new(InMemoryWebhookRepository::default());
+        let subscription = subscribe(&repository, &url, &["*"]).await;
+        let deliverer = WebhookDeliverer::new(repository.clone());
+        let job = job(&subscription);
+
+        assert!(matches!(deliverer.deliver(&job, 1, false).await, Err(JobError::ExecutionFailed(_))));
+        assert!(deliverer.deliver(&job, 6, true).await.is_err());
+
+        let deliveries = repository.deliveries.lock().unwrap();
+        assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Failed);
+        assert_eq!(deliveries[0].response_status, Some(500));
+        assert!(deliveries[0].error.as_deref().unwrap().contains("500"));
+        assert_eq!(deliveries[1].status, WebhookDeliveryStatus::DeadLettered);
+        assert_eq!(deliveries[1].response_status, Some(503));
+        assert_eq!(deliveries[1].attempt, 6);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_endpoint_is_logged() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+        drop(listener);
+        let repository = Arc::new(InMemoryWebhookRepository::default());
+        let subscription = subscribe(&repository, &url, &["*"]).await;
+
+        assert!(WebhookDeliverer::new(repository.clone()).deliver(&job(&subscription), 1, false).await.is_err());
+
+        let deliveries = repository.deliveries.lock().unwrap();
+        assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Failed);
+        assert_eq!(deliveries[0].response_status, None);
+        assert!(deliveries[0].error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_inactive_subscription_is_skipped() {
+        let (url, receiver) = start_receiver(&[]).await;
+        let repository = Arc::new(InMemoryWebhookRepository::default());
+        let mut subscription = WebhookSubscription::new(url, vec!["*".to_string()], SECRET.to_string(), None);
+        subscription.active = false;
+        repository.save(&subscription).await.unwrap();
+        let deliverer = WebhookDeliverer::new(repository.clone());
+
+        deliverer.deliver(&job(&subscription), 1, false).await.unwrap();
+        let deleted = WebhookSubscription::new("http://127.0.0.1:9/hooks".to_string(), vec![], SECRET.to_string(), None);
+        deliverer.deliver(&job(&deleted), 1, false).await.unwrap();
+
+        assert!(receiver.received.lock().unwrap().is_empty());
+        assert!(repository.deliveries.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatcher_delivers_to_matching_subscriptions() {
+        let (url, receiver) = start_receiver(&[]).await;
+        let repository = Arc::new(InMemoryWebhookRepository::default());
+        let created = subscribe(&repository, &url, &["user.created"]).await;
+        subscribe(&repository, &url, &["user.deleted"]).await;
+        let dispatcher = WebhookDispatcher::inline(repository.clone(), Arc::new(WebhookDeliverer::new(repository.clone())));
+
+        let user_id = UserId::new();
+        dispatcher
+            .handle(&UserCreated::new(user_id, "alice".to_string(), "alice@example.com".to_string()))
+            .await
+            .unwrap();
+        wait_for_deliveries(&repository, 1).await;
+
+        assert_eq!(repository.deliveries.lock().unwrap()[0].subscription_id, created.id);
+        let received = receiver.received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        let payload: serde_json::Value = serde_json::from_str(&received[0].body).unwrap();
+        assert_eq!(payload["type"], "user.created");
+        assert_eq!(payload["aggregate_id"], user_id.to_string());
+        assert_eq!(payload["data"]["username"], "alice");
+        assert_eq!(payload["id"], received[0].headers[WEBHOOK_DELIVERY_HEADER].to_str().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_inline_delivery_retries_until_success() {
+        let (url, receiver) = start_receiver(&[500, 502]).await;
+        let repository = Arc::new(InMemoryWebhookRepository::default());
+        subscribe(&repository, &url, &["*"]).await;
+        let dispatcher = WebhookDispatcher::inline(repository.clone(), Arc::new(WebhookDeliverer::new(repository.clone())))
+            .with_retry_policy(RetryPolicy::fixed(3, 10));
+
+        dispatcher
+            .handle(&UserCreated::new(UserId::new(), "alice".to_string(), "alice@example.com".to_string()))
+            .await
+            .unwrap();
+        wait_for_deliveries(&repository, 3).await;
+
+        assert_eq!(
+            repository.statuses(),
+            vec![WebhookDeliveryStatus::Failed, WebhookDeliveryStatus::Failed, WebhookDeliveryStatus::Succeeded]
+        );
+        let attempts: Vec<_> = repository.deliveries.lock().unwrap().iter().map(|d| d.attempt).collect();
+        assert_eq!(attempts, vec![1, 2, 3]);
+
+        // Every attempt carries the same event ID
+        let received = receiver.received.lock().unwrap();
+        assert!(received.iter().all(|r| r.headers[WEBHOOK_DELIVERY_HEADER] == received[0].headers[WEBHOOK_DELIVERY_HEADER]));
+    }
+
+    #[tokio::test]
+    async fn test_inline_delivery_dead_letters_when_retries_are_exhausted() {
+        let (url, receiver) = start_receiver(&[500, 500, 500, 500]).await;
+        let repository = Arc::new(InMemoryWebhookRepository::default());
+        subscribe(&repository, &url, &["*"]).await;
+        let dispatcher = WebhookDispatcher::inline(repository.clone(), Arc::new(WebhookDeliverer::new(repository.clone())))
+            .with_retry_policy(RetryPolicy::fixed(2, 10));
+
+        dispatcher
+            .handle(&UserCreated::new(UserId::new(), "alice".to_string(), "alice@example.com".to_string()))
+            .await
+            .unwrap();
+        wait_for_deliveries(&repository, 3).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(
+            repository.statuses(),
+            vec![WebhookDeliveryStatus::Failed, WebhookDeliveryStatus::Failed, WebhookDeliveryStatus::DeadLettered]
+        );
+        assert_eq!(receiver.received.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_job_uses_exponential_backoff() {
+        let job = DeliverWebhookJob {
+            subscription_id: WebhookSubscriptionId::new(),
+            event_id: Uuid::now_v7(),
+            event_type: "user.created".to_string(),
+            payload: "{}".to_string(),
+        };
+        let policy = job.retry_policy();
+        assert_eq!(DeliverWebhookJob::QUEUE, WEBHOOK_QUEUE);
+        assert_eq!(policy.max_retries, DeliverWebhookJob::MAX_RETRIES);
+        assert!(policy.should_retry(DeliverWebhookJob::MAX_RETRIES));
+        assert!(!policy.should_retry(DeliverWebhookJob::MAX_RETRIES + 1));
+    }
+}
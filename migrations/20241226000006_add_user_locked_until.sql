@@ -0,0 +1,2 @@
+-- Track when an automatic account lock expires (MySQL)
+ALTER TABLE users ADD COLUMN locked_until DATETIME AFTER last_login_at;
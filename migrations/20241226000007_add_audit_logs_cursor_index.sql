@@ -0,0 +1,2 @@
+-- Index for paging through audit logs newest first (MySQL)
+CREATE INDEX idx_audit_logs_timestamp_id ON audit_logs(timestamp DESC, id DESC);
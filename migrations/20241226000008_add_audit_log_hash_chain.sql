@@ -0,0 +1,18 @@
+-- Chain audit log entries into a tamper-evident hash chain (MySQL)
+-- Entries keep the ID of a deleted user; nulling it would break their hashes.
+ALTER TABLE audit_logs
+    DROP FOREIGN KEY fk_audit_logs_user_id,
+    ADD COLUMN sequence BIGINT UNSIGNED NULL,
+    ADD COLUMN prev_hash CHAR(64) NULL,
+    ADD COLUMN hash CHAR(64) NULL;
+
+CREATE UNIQUE INDEX idx_audit_logs_sequence ON audit_logs(sequence);
+
+-- Head of the chain; appends lock this row so that they are chained one at a time
+CREATE TABLE IF NOT EXISTS audit_log_chain (
+    id TINYINT PRIMARY KEY,
+    last_sequence BIGINT UNSIGNED NOT NULL,
+    last_hash CHAR(64)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+INSERT INTO audit_log_chain (id, last_sequence, last_hash) VALUES (1, 0, NULL);
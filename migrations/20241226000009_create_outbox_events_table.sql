@@ -0,0 +1,18 @@
+-- Create transactional outbox of domain events (MySQL)
+-- Rows are written in the same transaction as the change that raised the
+-- event and marked dispatched once the outbox relay has published them.
+CREATE TABLE IF NOT EXISTS outbox_events (
+    id CHAR(36) PRIMARY KEY,
+    event_type VARCHAR(128) NOT NULL,
+    aggregate_id VARCHAR(255) NOT NULL,
+    payload LONGTEXT NOT NULL,
+    occurred_at DATETIME(6) NOT NULL,
+    dispatched_at DATETIME(6),
+    attempts INT UNSIGNED NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- Create indexes
+CREATE INDEX idx_outbox_events_pending ON outbox_events(dispatched_at, occurred_at);
+CREATE INDEX idx_outbox_events_aggregate_id ON outbox_events(aggregate_id);
@@ -0,0 +1,31 @@
+-- Create webhook subscriptions and their delivery log (MySQL)
+CREATE TABLE IF NOT EXISTS webhook_subscriptions (
+    id CHAR(36) PRIMARY KEY,
+    url VARCHAR(2048) NOT NULL,
+    event_types JSON NOT NULL,
+    secret VARCHAR(255) NOT NULL,
+    description VARCHAR(500),
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at DATETIME(6) NOT NULL,
+    updated_at DATETIME(6) NOT NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- One row per delivery attempt; removed together with the subscription
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id CHAR(36) PRIMARY KEY,
+    subscription_id CHAR(36) NOT NULL,
+    event_id CHAR(36) NOT NULL,
+    event_type VARCHAR(128) NOT NULL,
+    attempt INT UNSIGNED NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    response_status SMALLINT UNSIGNED,
+    error TEXT,
+    duration_ms BIGINT UNSIGNED NOT NULL,
+    attempted_at DATETIME(6) NOT NULL,
+    CONSTRAINT fk_webhook_deliveries_subscription_id FOREIGN KEY (subscription_id) REFERENCES webhook_subscriptions(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- Create indexes
+CREATE INDEX idx_webhook_subscriptions_active ON webhook_subscriptions(active);
+CREATE INDEX idx_webhook_deliveries_subscription ON webhook_deliveries(subscription_id, attempted_at);
+CREATE INDEX idx_webhook_deliveries_event_id ON webhook_deliveries(event_id);
@@ -0,0 +1,39 @@
+-- Create roles with their permission grants, role assignments and direct grants (MySQL)
+CREATE TABLE IF NOT EXISTS roles (
+    id CHAR(36) PRIMARY KEY,
+    name VARCHAR(64) NOT NULL UNIQUE,
+    description VARCHAR(500),
+    permissions JSON NOT NULL,
+    built_in BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at DATETIME(6) NOT NULL,
+    updated_at DATETIME(6) NOT NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- Custom roles assigned to users on top of the built-in role of their user role
+CREATE TABLE IF NOT EXISTS user_role_assignments (
+    user_id CHAR(36) NOT NULL,
+    role_id CHAR(36) NOT NULL,
+    assigned_at DATETIME(6) NOT NULL,
+    PRIMARY KEY (user_id, role_id),
+    CONSTRAINT fk_user_role_assignments_user_id FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_user_role_assignments_role_id FOREIGN KEY (role_id) REFERENCES roles(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- Permissions granted to individual users
+CREATE TABLE IF NOT EXISTS user_permissions (
+    user_id CHAR(36) NOT NULL,
+    permission VARCHAR(64) NOT NULL,
+    granted_at DATETIME(6) NOT NULL,
+    PRIMARY KEY (user_id, permission),
+    CONSTRAINT fk_user_permissions_user_id FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- Create indexes
+CREATE INDEX idx_user_role_assignments_role_id ON user_role_assignments(role_id);
+
+-- Built-in roles with the grants of the former fixed role levels
+INSERT INTO roles (id, name, description, permissions, built_in, created_at, updated_at) VALUES
+    ('00000000-0000-7000-8000-000000000001', 'user', 'Built-in role of every user', '["content:create", "content:read", "content:update", "plugin:read", "user:read"]', TRUE, UTC_TIMESTAMP(6), UTC_TIMESTAMP(6)),
+    ('00000000-0000-7000-8000-000000000002', 'moderator', 'Built-in role of moderators', '["content:create", "content:delete", "content:moderate", "content:read", "content:update", "plugin:read", "system:monitor", "user:create", "user:read", "user:update"]', TRUE, UTC_TIMESTAMP(6), UTC_TIMESTAMP(6)),
+    ('00000000-0000-7000-8000-000000000003', 'admin', 'Built-in role of administrators', '["content:create", "content:delete", "content:moderate", "content:read", "content:update", "plugin:configure", "plugin:install", "plugin:read", "plugin:uninstall", "system:monitor", "user:create", "user:delete", "user:manage_roles", "user:read", "user:update"]', TRUE, UTC_TIMESTAMP(6), UTC_TIMESTAMP(6)),
+    ('00000000-0000-7000-8000-000000000004', 'superadmin', 'Built-in role of super administrators', '["content:create", "content:delete", "content:moderate", "content:read", "content:update", "plugin:configure", "plugin:install", "plugin:read", "plugin:uninstall", "system:admin", "system:config", "system:monitor", "user:create", "user:delete", "user:manage_roles", "user:read", "user:update"]', TRUE, UTC_TIMESTAMP(6), UTC_TIMESTAMP(6));
@@ -0,0 +1,25 @@
+-- Create tenants and scope users to them (MySQL)
+CREATE TABLE IF NOT EXISTS tenants (
+    id CHAR(36) PRIMARY KEY,
+    slug VARCHAR(63) NOT NULL UNIQUE,
+    name VARCHAR(255) NOT NULL,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at DATETIME(6) NOT NULL,
+    updated_at DATETIME(6) NOT NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- Default tenant, holding every existing user
+INSERT INTO tenants (id, slug, name, active, created_at, updated_at) VALUES
+    ('00000000-0000-7000-8000-000000000001', 'default', 'Default', TRUE, UTC_TIMESTAMP(6), UTC_TIMESTAMP(6));
+
+ALTER TABLE users
+    ADD COLUMN tenant_id CHAR(36) NOT NULL DEFAULT '00000000-0000-7000-8000-000000000001' AFTER id,
+    ADD CONSTRAINT fk_users_tenant_id FOREIGN KEY (tenant_id) REFERENCES tenants(id);
+
+-- Usernames and emails are unique within a tenant
+ALTER TABLE users DROP INDEX username, DROP INDEX email;
+DROP INDEX idx_users_username ON users;
+DROP INDEX idx_users_email ON users;
+CREATE UNIQUE INDEX uq_users_tenant_username ON users(tenant_id, username);
+CREATE UNIQUE INDEX uq_users_tenant_email ON users(tenant_id, email);
+CREATE INDEX idx_users_tenant_created_at ON users(tenant_id, created_at DESC);
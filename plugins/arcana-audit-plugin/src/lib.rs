@@ -2,6 +2,12 @@
 //!
 //! Sample audit plugin demonstrating the plugin API.
 //! This plugin logs all platform events for auditing purposes.
+//!
+//! Build the module the platform loads with:
+//!
+//! ```bash
+//! cargo build --target wasm32-wasip1 --release -p arcana-audit-plugin
+//! ```
 
 use arcana_plugin_api::{
     extensions::{EventListenerExtension, EventSubscription, PluginEvent},
@@ -91,6 +97,8 @@ impl EventListenerExtension for AuditPlugin {
     }
 }
 
+arcana_plugin_api::export_plugin!(AuditPlugin, extensions: [event_listener]);
+
 #[cfg(test)]
 mod tests {
     use super::*;